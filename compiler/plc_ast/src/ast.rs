@@ -10,8 +10,8 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     control_statements::{
-        AstControlStatement, CaseStatement, ConditionalBlock, ForLoopStatement, IfStatement, LoopStatement,
-        ReturnStatement,
+        AstControlStatement, CaseStatement, CatchBlock, ConditionalBlock, ForLoopStatement, IfStatement,
+        LoopStatement, ReturnStatement, TryStatement,
     },
     literals::{AstLiteral, StringValue},
     pre_processor,
@@ -28,7 +28,16 @@ pub struct GenericBinding {
     pub nature: TypeNature,
 }
 
-#[derive(PartialEq)]
+/// A type argument of an instantiated generic POU, e.g. `REAL` for `T` in `fifo : FB_Fifo<REAL>`
+#[derive(Clone, Debug, PartialEq)]
+pub struct GenericArgument {
+    pub binding: GenericBinding,
+    pub type_name: String,
+    /// The location of the instantiation, e.g. `FB_Fifo<REAL>`
+    pub location: SourceLocation,
+}
+
+#[derive(Clone, PartialEq)]
 pub struct Pou {
     pub name: String,
     pub kind: PouType,
@@ -40,30 +49,179 @@ pub struct Pou {
     pub name_location: SourceLocation,
     pub poly_mode: Option<PolymorphismMode>,
     pub generics: Vec<GenericBinding>,
+    /// The type arguments this POU was instantiated with, if it is an instance of a generic POU
+    pub generic_arguments: Vec<GenericArgument>,
     pub linkage: LinkageType,
     pub super_class: Option<String>,
     /// A list of interfaces this POU implements
     pub interfaces: Vec<InterfaceIdentifier>,
+    /// A list of properties declared in this POU
+    pub properties: Vec<PropertyBlock>,
     pub is_const: bool,
+    /// The attribute pragmas declared in front of this POU
+    pub attributes: Vec<Attribute>,
+    /// The sequential function chart forming the body of this POU, lowered into statements before indexing
+    pub sfc: Option<Sfc>,
 }
 
 #[derive(Debug, PartialEq)]
 pub struct Interface {
     pub name: String,
     pub methods: Vec<Pou>,
+    pub properties: Vec<PropertyBlock>,
     pub location: SourceLocation,
     pub location_name: SourceLocation,
 }
 
+/// A property declared in a function block, class or interface, e.g.
+/// `PROPERTY foo : INT GET ... END_GET SET ... END_SET END_PROPERTY`
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertyBlock {
+    pub name: String,
+    pub name_location: SourceLocation,
+    pub datatype: DataTypeDeclaration,
+    pub access: Option<AccessModifier>,
+    /// The `GET` and `SET` accessors of this property. The accessors are moved into methods once the
+    /// property has been lowered, leaving this list empty
+    pub accessors: Vec<PropertyAccessor>,
+    pub location: SourceLocation,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertyAccessor {
+    pub kind: PropertyKind,
+    pub variable_blocks: Vec<VariableBlock>,
+    pub statements: Vec<AstNode>,
+    pub location: SourceLocation,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PropertyKind {
+    Get,
+    Set,
+}
+
+impl PropertyKind {
+    /// Returns the name of the method implementing this accessor for the given property, e.g. `__get_foo`
+    pub fn get_method_name(&self, property: &str) -> String {
+        match self {
+            PropertyKind::Get => format!("__get_{property}"),
+            PropertyKind::Set => format!("__set_{property}"),
+        }
+    }
+}
+
+impl Display for PropertyKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PropertyKind::Get => write!(f, "GET"),
+            PropertyKind::Set => write!(f, "SET"),
+        }
+    }
+}
+
+/// A textual sequential function chart (SFC), made up of the steps and transitions of one or more
+/// networks. Each network starts with an `INITIAL_STEP`, e.g.
+/// ```iecst
+/// INITIAL_STEP idle : END_STEP
+/// STEP fill : open_valve(N); END_STEP
+/// TRANSITION FROM idle TO fill := start; END_TRANSITION
+/// ```
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Sfc {
+    pub steps: Vec<SfcStep>,
+    pub transitions: Vec<SfcTransition>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct SfcStep {
+    pub name: String,
+    /// Whether this is the `INITIAL_STEP` of a network
+    pub initial: bool,
+    pub actions: Vec<ActionAssociation>,
+    pub location: SourceLocation,
+    pub name_location: SourceLocation,
+}
+
+/// The association of an action with a step, e.g. `open_valve(L, T#5s)`
+#[derive(Debug, PartialEq, Clone)]
+pub struct ActionAssociation {
+    pub action: String,
+    pub qualifier: ActionQualifier,
+    /// The duration of a time-limited (`L`) or time-delayed (`D`) action
+    pub duration: Option<AstNode>,
+    pub location: SourceLocation,
+}
+
+/// Controls when an action associated with a step is executed
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ActionQualifier {
+    /// `N`, executed while the step is active
+    NonStored,
+    /// `S`, executed from the step's activation until the action is reset
+    Set,
+    /// `R`, stops the execution of a set action
+    Reset,
+    /// `P`, executed once when the step is activated
+    Pulse,
+    /// `L`, executed while the step is active, for at most the given duration
+    TimeLimited,
+    /// `D`, executed while the step is active, once the given duration passed
+    TimeDelayed,
+}
+
+impl ActionQualifier {
+    pub fn from_name(name: &str) -> Option<ActionQualifier> {
+        match name.to_uppercase().as_str() {
+            "N" => Some(ActionQualifier::NonStored),
+            "S" => Some(ActionQualifier::Set),
+            "R" => Some(ActionQualifier::Reset),
+            "P" => Some(ActionQualifier::Pulse),
+            "L" => Some(ActionQualifier::TimeLimited),
+            "D" => Some(ActionQualifier::TimeDelayed),
+            _ => None,
+        }
+    }
+
+    /// Whether the qualifier requires a duration, e.g. `open_valve(L, T#5s)`
+    pub fn is_timed(&self) -> bool {
+        matches!(self, ActionQualifier::TimeLimited | ActionQualifier::TimeDelayed)
+    }
+}
+
+impl Display for ActionQualifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ActionQualifier::NonStored => write!(f, "N"),
+            ActionQualifier::Set => write!(f, "S"),
+            ActionQualifier::Reset => write!(f, "R"),
+            ActionQualifier::Pulse => write!(f, "P"),
+            ActionQualifier::TimeLimited => write!(f, "L"),
+            ActionQualifier::TimeDelayed => write!(f, "D"),
+        }
+    }
+}
+
+/// A transition deactivating its preceding steps and activating its succeeding steps once its condition
+/// holds, e.g. `TRANSITION FROM (a, b) TO c := x > 10; END_TRANSITION`
+#[derive(Debug, PartialEq, Clone)]
+pub struct SfcTransition {
+    pub name: Option<String>,
+    pub from: Vec<(String, SourceLocation)>,
+    pub to: Vec<(String, SourceLocation)>,
+    pub condition: AstNode,
+    pub location: SourceLocation,
+}
+
 /// Helper struct for [`Pou`] to get the location of the interface without relying on [`Interface`] which
 /// only exists if the interface is actually defined. Mostly needed for user-friendly validation messages.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct InterfaceIdentifier {
     pub name: String,
     pub location: SourceLocation,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 pub enum PolymorphismMode {
     None,
     Abstract,
@@ -226,6 +384,18 @@ impl Debug for Pou {
         if !self.generics.is_empty() {
             str.field("generics", &self.generics);
         }
+        if !self.generic_arguments.is_empty() {
+            str.field("generic_arguments", &self.generic_arguments);
+        }
+        if !self.properties.is_empty() {
+            str.field("properties", &self.properties);
+        }
+        if !self.attributes.is_empty() {
+            str.field("attributes", &self.attributes);
+        }
+        if let Some(sfc) = &self.sfc {
+            str.field("sfc", sfc);
+        }
         str.finish()
     }
 }
@@ -248,7 +418,7 @@ impl Pou {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Implementation {
     pub name: String,
     pub type_name: String,
@@ -269,14 +439,25 @@ pub enum LinkageType {
     BuiltIn,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
 pub enum AccessModifier {
     Private,
     Public,
-    Protected, // default
+    Protected,
     Internal,
 }
 
+impl Display for AccessModifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AccessModifier::Private => write!(f, "PRIVATE"),
+            AccessModifier::Public => write!(f, "PUBLIC"),
+            AccessModifier::Protected => write!(f, "PROTECTED"),
+            AccessModifier::Internal => write!(f, "INTERNAL"),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
 pub enum PouType {
     Program,
@@ -341,28 +522,127 @@ impl ConfigVariable {
     }
 }
 
+/// A `VAR_ACCESS` declaration publishing a variable under a name for external communication, e.g.
+/// `speed : prog1.fb.speed : REAL READ_ONLY;`
+#[derive(Debug, PartialEq, Clone)]
+pub struct AccessVariable {
+    pub name: String,
+    /// The access path of the published variable
+    pub reference: AstNode,
+    pub data_type: DataTypeDeclaration,
+    pub access: AccessMode,
+    pub location: SourceLocation,
+    pub name_location: SourceLocation,
+}
+
+/// Whether an access path may be written, `READ_ONLY` unless declared otherwise
+#[derive(Debug, Default, Copy, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AccessMode {
+    #[default]
+    ReadOnly,
+    ReadWrite,
+}
+
+impl Display for AccessMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AccessMode::ReadOnly => write!(f, "READ_ONLY"),
+            AccessMode::ReadWrite => write!(f, "READ_WRITE"),
+        }
+    }
+}
+
+/// A `CONFIGURATION` assigning program instances to the tasks of its resources, e.g.
+/// `CONFIGURATION cfg RESOURCE cpu ON PLC ... END_RESOURCE END_CONFIGURATION`
+#[derive(Debug, PartialEq, Clone)]
+pub struct Configuration {
+    pub name: String,
+    /// The resources of the configuration. A configuration declaring its tasks and programs
+    /// directly holds a single resource named after the configuration
+    pub resources: Vec<Resource>,
+    pub location: SourceLocation,
+    pub name_location: SourceLocation,
+}
+
+/// A `RESOURCE` of a configuration, declaring tasks and the programs they execute
+#[derive(Debug, PartialEq, Clone)]
+pub struct Resource {
+    pub name: String,
+    /// The type of processing unit named after `ON`
+    pub processor: Option<String>,
+    pub tasks: Vec<Task>,
+    pub programs: Vec<ProgramConfiguration>,
+    pub location: SourceLocation,
+    pub name_location: SourceLocation,
+}
+
+/// A `TASK fast (INTERVAL := T#10ms, PRIORITY := 1);` declaration
+#[derive(Debug, PartialEq, Clone)]
+pub struct Task {
+    pub name: String,
+    /// The cycle time of a periodic task
+    pub interval: Option<AstNode>,
+    /// The variable whose rising edge triggers an event-driven task
+    pub single: Option<AstNode>,
+    pub priority: Option<AstNode>,
+    pub location: SourceLocation,
+    pub name_location: SourceLocation,
+}
+
+/// A `PROGRAM instance WITH task : MyProgram;` declaration
+#[derive(Debug, PartialEq, Clone)]
+pub struct ProgramConfiguration {
+    pub instance_name: String,
+    /// The task executing the program, and its location
+    pub task: Option<(String, SourceLocation)>,
+    pub program: String,
+    pub program_location: SourceLocation,
+    pub location: SourceLocation,
+    pub name_location: SourceLocation,
+}
+
 #[derive(Debug, PartialEq)]
 pub struct CompilationUnit {
     pub global_vars: Vec<VariableBlock>,
     pub var_config: Vec<ConfigVariable>,
+    /// List of the access paths declared in `VAR_ACCESS` blocks
+    pub var_access: Vec<AccessVariable>,
     /// List of POU definitions (signature and some additional metadata)
     pub units: Vec<Pou>,
     /// List of statements within a POU body
     pub implementations: Vec<Implementation>,
     pub interfaces: Vec<Interface>,
     pub user_types: Vec<UserTypeDeclaration>,
+    /// List of `USING` directives importing namespaces into this unit
+    pub usings: Vec<UsingDirective>,
+    pub configurations: Vec<Configuration>,
     pub file_name: String,
 }
 
+/// A `USING a.b;` directive, making the declarations of namespace `a::b` accessible without qualification
+#[derive(Debug, PartialEq, Clone)]
+pub struct UsingDirective {
+    /// The qualified name of the imported namespace, e.g. `a::b`
+    pub namespace: String,
+    /// The qualified name of the namespace or POU the directive was declared in, or `None` if it applies to
+    /// the whole unit
+    pub scope: Option<String>,
+    pub location: SourceLocation,
+}
+
 impl CompilationUnit {
     pub fn new(file_name: &str) -> Self {
         CompilationUnit {
             global_vars: Vec::new(),
             var_config: Vec::new(),
+            var_access: Vec::new(),
             units: Vec::new(),
             implementations: Vec::new(),
             interfaces: Vec::new(),
             user_types: Vec::new(),
+            usings: Vec::new(),
+            configurations: Vec::new(),
             file_name: file_name.to_string(),
         }
     }
@@ -383,6 +663,8 @@ impl CompilationUnit {
         self.units.extend(other.units);
         self.implementations.extend(other.implementations);
         self.user_types.extend(other.user_types);
+        self.usings.extend(other.usings);
+        self.configurations.extend(other.configurations);
     }
 }
 
@@ -417,15 +699,18 @@ pub enum ArgumentProperty {
     ByRef,
 }
 
-#[derive(PartialEq)]
+#[derive(Clone, PartialEq)]
 pub struct VariableBlock {
-    pub access: AccessModifier,
+    /// The declared access modifier, members of a block without one are not restricted
+    pub access: Option<AccessModifier>,
     pub constant: bool,
     pub retain: bool,
+    pub persistent: bool,
     pub variables: Vec<Variable>,
     pub variable_block_type: VariableBlockType,
     pub linkage: LinkageType,
     pub location: SourceLocation,
+    pub attributes: Vec<Attribute>,
 }
 
 impl VariableBlock {
@@ -443,23 +728,27 @@ impl VariableBlock {
 impl Default for VariableBlock {
     fn default() -> Self {
         VariableBlock {
-            access: AccessModifier::Internal,
+            access: None,
             constant: false,
             retain: false,
+            persistent: false,
             variables: vec![],
             variable_block_type: VariableBlockType::Local,
             linkage: LinkageType::Internal,
             location: SourceLocation::internal(),
+            attributes: vec![],
         }
     }
 }
 
 impl Debug for VariableBlock {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("VariableBlock")
-            .field("variables", &self.variables)
-            .field("variable_block_type", &self.variable_block_type)
-            .finish()
+        let mut str = f.debug_struct("VariableBlock");
+        str.field("variables", &self.variables).field("variable_block_type", &self.variable_block_type);
+        if !self.attributes.is_empty() {
+            str.field("attributes", &self.attributes);
+        }
+        str.finish()
     }
 }
 
@@ -469,7 +758,10 @@ pub struct Variable {
     pub data_type_declaration: DataTypeDeclaration,
     pub initializer: Option<AstNode>,
     pub address: Option<AstNode>,
+    /// arguments passed to the `FB_INIT` method of an instance, e.g. `fb : MyFb(nAxis := 3)`
+    pub init_arguments: Option<AstNode>,
     pub location: SourceLocation,
+    pub attributes: Vec<Attribute>,
 }
 
 impl PartialEq for Variable {
@@ -488,6 +780,12 @@ impl Debug for Variable {
         if self.address.is_some() {
             var.field("address", &self.address);
         }
+        if self.init_arguments.is_some() {
+            var.field("init_arguments", &self.init_arguments);
+        }
+        if !self.attributes.is_empty() {
+            var.field("attributes", &self.attributes);
+        }
         var.finish()
     }
 }
@@ -586,15 +884,46 @@ pub struct UserTypeDeclaration {
     pub location: SourceLocation,
     /// stores the original scope for compiler-generated types
     pub scope: Option<String>,
+    pub attributes: Vec<Attribute>,
 }
 
 impl Debug for UserTypeDeclaration {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("UserTypeDeclaration")
-            .field("data_type", &self.data_type)
+        let mut str = f.debug_struct("UserTypeDeclaration");
+        str.field("data_type", &self.data_type)
             .field("initializer", &self.initializer)
-            .field("scope", &self.scope)
-            .finish()
+            .field("scope", &self.scope);
+        if !self.attributes.is_empty() {
+            str.field("attributes", &self.attributes);
+        }
+        str.finish()
+    }
+}
+
+/// An attribute pragma attached to a declaration, e.g. `{attribute 'hide'}` or
+/// `{attribute 'monitoring' := 'call'}`
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Attribute {
+    pub name: String,
+    pub value: Option<String>,
+    pub location: SourceLocation,
+}
+
+impl Debug for Attribute {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut str = f.debug_struct("Attribute");
+        str.field("name", &self.name);
+        if let Some(value) = &self.value {
+            str.field("value", value);
+        }
+        str.finish()
+    }
+}
+
+impl Attribute {
+    /// returns the attribute with the given name (case-insensitive) from the given list
+    pub fn find<'a>(attributes: &'a [Attribute], name: &str) -> Option<&'a Attribute> {
+        attributes.iter().find(|it| it.name.eq_ignore_ascii_case(name))
     }
 }
 
@@ -603,6 +932,13 @@ pub enum DataType {
     StructType {
         name: Option<String>, //maybe None for inline structs
         variables: Vec<Variable>,
+        /// the struct this struct `EXTENDS`, its members are laid out first
+        base: Option<String>,
+    },
+    /// A struct-like type whose members all share the same storage
+    UnionType {
+        name: Option<String>,
+        variables: Vec<Variable>,
     },
     EnumType {
         name: Option<String>, //maybe empty for inline enums
@@ -657,6 +993,7 @@ impl DataType {
     pub fn set_name(&mut self, new_name: String) {
         match self {
             DataType::StructType { name, .. }
+            | DataType::UnionType { name, .. }
             | DataType::EnumType { name, .. }
             | DataType::SubRangeType { name, .. }
             | DataType::ArrayType { name, .. }
@@ -670,6 +1007,7 @@ impl DataType {
     pub fn get_name(&self) -> Option<&str> {
         match &self {
             DataType::StructType { name, .. }
+            | DataType::UnionType { name, .. }
             | DataType::EnumType { name, .. }
             | DataType::ArrayType { name, .. }
             | DataType::PointerType { name, .. }
@@ -771,6 +1109,9 @@ pub enum AstStatement {
     ParenExpression(Box<AstNode>),
     RangeStatement(RangeStatement),
     VlaRangeStatement,
+    // `THIS` and `SUPER`, pointers to the current instance of a FUNCTION_BLOCK or CLASS
+    This,
+    Super,
 
     // TODO: Merge these variants with a `kind` field?
     //       Update: Tried that, pattern matching becomes a pain in the ass; will probably be easier if we
@@ -850,6 +1191,8 @@ impl Debug for AstNode {
                 f.debug_struct("RangeStatement").field("start", start).field("end", end).finish()
             }
             AstStatement::VlaRangeStatement => f.debug_struct("VlaRangeStatement").finish(),
+            AstStatement::This => f.debug_struct("This").finish(),
+            AstStatement::Super => f.debug_struct("Super").finish(),
             AstStatement::Assignment(Assignment { left, right }) => {
                 f.debug_struct("Assignment").field("left", left).field("right", right).finish()
             }
@@ -909,6 +1252,16 @@ impl Debug for AstNode {
                 .field("case_blocks", case_blocks)
                 .field("else_block", else_block)
                 .finish(),
+            AstStatement::ControlStatement(AstControlStatement::Try(TryStatement {
+                body,
+                catch_block,
+                finally_block,
+            })) => f
+                .debug_struct("TryStatement")
+                .field("body", body)
+                .field("catch_block", catch_block)
+                .field("finally_block", finally_block)
+                .finish(),
             AstStatement::DirectAccess(DirectAccess { access, index }) => {
                 f.debug_struct("DirectAccess").field("access", access).field("index", index).finish()
             }
@@ -1350,6 +1703,14 @@ impl AstFactory {
         AstNode { stmt: AstStatement::VlaRangeStatement, id, location }
     }
 
+    pub fn create_this(location: SourceLocation, id: AstId) -> AstNode {
+        AstNode { stmt: AstStatement::This, id, location }
+    }
+
+    pub fn create_super(location: SourceLocation, id: AstId) -> AstNode {
+        AstNode { stmt: AstStatement::Super, id, location }
+    }
+
     pub fn create_literal(kind: AstLiteral, location: SourceLocation, id: AstId) -> AstNode {
         AstNode { stmt: AstStatement::Literal(kind), id, location }
     }
@@ -1470,6 +1831,25 @@ impl AstFactory {
         }
     }
 
+    /// creates a new try-statement
+    pub fn create_try_statement(
+        body: Vec<AstNode>,
+        catch_block: Option<CatchBlock>,
+        finally_block: Vec<AstNode>,
+        location: SourceLocation,
+        id: AstId,
+    ) -> AstNode {
+        AstNode {
+            stmt: AstStatement::ControlStatement(AstControlStatement::Try(TryStatement {
+                body,
+                catch_block,
+                finally_block,
+            })),
+            id,
+            location,
+        }
+    }
+
     /// creates an or-expression
     pub fn create_or_expression(left: AstNode, right: AstNode) -> AstNode {
         let id = left.get_id();
@@ -31,6 +31,22 @@ pub struct CaseStatement {
     pub else_block: Vec<AstNode>,
 }
 
+/// `__TRY body __CATCH(exception) catch_block __FINALLY finally_block __ENDTRY`
+#[derive(Debug, Clone, PartialEq)]
+pub struct TryStatement {
+    pub body: Vec<AstNode>,
+    /// The `__CATCH` clause, `None` if the exception is only intercepted by the `__FINALLY` clause
+    pub catch_block: Option<CatchBlock>,
+    pub finally_block: Vec<AstNode>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CatchBlock {
+    /// The variable receiving the exception code, e.g. `exc` in `__CATCH(exc)`
+    pub exception: Option<Box<AstNode>>,
+    pub body: Vec<AstNode>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum AstControlStatement {
     If(IfStatement),
@@ -38,6 +54,7 @@ pub enum AstControlStatement {
     WhileLoop(LoopStatement),
     RepeatLoop(LoopStatement),
     Case(CaseStatement),
+    Try(TryStatement),
 }
 
 #[derive(Debug, Clone, PartialEq)]
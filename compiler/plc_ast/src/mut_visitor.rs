@@ -149,6 +149,10 @@ pub trait AstVisitorMut: Sized {
 
     fn visit_vla_range_statement(&mut self, _node: &mut AstNode) {}
 
+    fn visit_this(&mut self, _node: &mut AstNode) {}
+
+    fn visit_super(&mut self, _node: &mut AstNode) {}
+
     fn visit_assignment(&mut self, node: &mut AstNode) {
         let stmt = try_from_mut!(node, Assignment).expect("Assignment");
         stmt.walk(self)
@@ -346,6 +350,14 @@ impl WalkerMut for AstControlStatement {
                 stmt.case_blocks.walk(visitor);
                 visit_all_nodes_mut!(visitor, &mut stmt.else_block);
             }
+            AstControlStatement::Try(stmt) => {
+                visit_all_nodes_mut!(visitor, &mut stmt.body);
+                if let Some(catch_block) = &mut stmt.catch_block {
+                    visit_all_nodes_mut!(visitor, &mut catch_block.exception);
+                    visit_all_nodes_mut!(visitor, &mut catch_block.body);
+                }
+                visit_all_nodes_mut!(visitor, &mut stmt.finally_block);
+            }
         }
     }
 }
@@ -388,6 +400,8 @@ impl WalkerMut for AstNode {
             AstStatement::ParenExpression(_) => visitor.visit_paren_expression(self),
             AstStatement::RangeStatement(_) => visitor.visit_range_statement(self),
             AstStatement::VlaRangeStatement => visitor.visit_vla_range_statement(self),
+            AstStatement::This => visitor.visit_this(self),
+            AstStatement::Super => visitor.visit_super(self),
             AstStatement::Assignment(_) => visitor.visit_assignment(self),
             AstStatement::OutputAssignment(_) => visitor.visit_output_assignment(self),
             AstStatement::RefAssignment(_) => visitor.visit_ref_assignment(self),
@@ -465,7 +479,7 @@ impl WalkerMut for DataType {
         V: AstVisitorMut,
     {
         match self {
-            DataType::StructType { variables, .. } => {
+            DataType::StructType { variables, .. } | DataType::UnionType { variables, .. } => {
                 for v in variables.iter_mut() {
                     visitor.visit_variable(v);
                 }
@@ -6,9 +6,9 @@ use plc_util::convention::internal_type_name;
 
 use crate::{
     ast::{
-        flatten_expression_list, Assignment, AstFactory, AstNode, AstStatement, CompilationUnit,
-        ConfigVariable, DataType, DataTypeDeclaration, Operator, Pou, UserTypeDeclaration, Variable,
-        VariableBlock, VariableBlockType,
+        flatten_expression_list, AccessVariable, Assignment, AstFactory, AstNode, AstStatement,
+        CompilationUnit, ConfigVariable, DataType, DataTypeDeclaration, Operator, Pou, UserTypeDeclaration,
+        Variable, VariableBlock, VariableBlockType,
     },
     literals::AstLiteral,
     provider::IdProvider,
@@ -40,13 +40,15 @@ pub fn pre_process(unit: &mut CompilationUnit, mut id_provider: IdProvider) {
     //process all variables from GVLs
     process_global_variables(unit, &mut id_provider);
     process_var_config_variables(unit);
+    process_access_variables(unit);
 
     //process all variables in dataTypes
     let mut new_types = vec![];
     for dt in unit.user_types.iter_mut() {
         {
             match &mut dt.data_type {
-                DataType::StructType { name, variables, .. } => {
+                DataType::StructType { name, variables, .. }
+                | DataType::UnionType { name, variables, .. } => {
                     let name: &str = name.as_ref().map(|it| it.as_str()).unwrap_or("undefined");
                     variables
                         .iter_mut()
@@ -70,7 +72,13 @@ pub fn pre_process(unit: &mut CompilationUnit, mut id_provider: IdProvider) {
                     {
                         data_type.set_name(type_name);
                         add_nested_datatypes(name, &mut data_type, &mut new_types, &location);
-                        let data_type = UserTypeDeclaration { data_type, initializer: None, location, scope };
+                        let data_type = UserTypeDeclaration {
+                            data_type,
+                            initializer: None,
+                            location,
+                            scope,
+                            attributes: vec![],
+                        };
                         new_types.push(data_type);
                     }
                 }
@@ -179,7 +187,9 @@ fn process_global_variables(unit: &mut CompilationUnit, id_provider: &mut IdProv
                     data_type_declaration: ref_ty.unwrap_or(global_var.data_type_declaration.clone()),
                     initializer: None,
                     address: None,
+                    init_arguments: None,
                     location: node.location.clone(),
+                    attributes: vec![],
                 };
                 mangled_globals.push(internal_mangled_var);
             }
@@ -210,13 +220,43 @@ fn process_var_config_variables(unit: &mut CompilationUnit) {
             data_type_declaration: data_type.get_inner_pointer_ty().unwrap_or(data_type.clone()),
             initializer: None,
             address: None,
+            init_arguments: None,
             location: address.get_location(),
+            attributes: vec![],
         })
     });
 
     update_generated_globals(unit, variables.collect())
 }
 
+/// Declares the types defined inline in `VAR_ACCESS` blocks, e.g. `name : prg.arr : ARRAY[0..2] OF INT;`
+fn process_access_variables(unit: &mut CompilationUnit) {
+    for AccessVariable { name, data_type, .. } in unit.var_access.iter_mut() {
+        if !should_generate_implicit(data_type) {
+            continue;
+        }
+
+        let new_type_name = internal_type_name("access_", name);
+        let new_data_type = DataTypeDeclaration::DataTypeReference {
+            referenced_type: new_type_name.clone(),
+            location: data_type.get_location(),
+        };
+        if let DataTypeDeclaration::DataTypeDefinition { mut data_type, location, scope } =
+            std::mem::replace(data_type, new_data_type)
+        {
+            add_nested_datatypes(new_type_name.as_str(), &mut data_type, &mut unit.user_types, &location);
+            data_type.set_name(new_type_name);
+            unit.user_types.push(UserTypeDeclaration {
+                data_type,
+                initializer: None,
+                location,
+                scope,
+                attributes: vec![],
+            });
+        }
+    }
+}
+
 fn update_generated_globals(unit: &mut CompilationUnit, mangled_globals: Vec<Variable>) {
     let mut block = if let Some(index) = unit.global_vars.iter().position(|block| {
         block.variable_block_type == VariableBlockType::Global && block.location.is_internal()
@@ -284,6 +324,7 @@ fn preprocess_generic_structs(pou: &mut Pou) -> Vec<UserTypeDeclaration> {
             initializer: None,
             scope: Some(pou.name.clone()),
             location: pou.location.clone(),
+            attributes: vec![],
         };
         types.push(data_type);
         generic_types.insert(binding.name.clone(), new_name);
@@ -310,7 +351,8 @@ fn preprocess_return_type(pou: &mut Pou, types: &mut Vec<UserTypeDeclaration>) {
             {
                 data_type.set_name(type_name);
                 add_nested_datatypes(pou.name.as_str(), &mut data_type, types, &location);
-                let data_type = UserTypeDeclaration { data_type, initializer: None, location, scope };
+                let data_type =
+                    UserTypeDeclaration { data_type, initializer: None, location, scope, attributes: vec![] };
                 types.push(data_type);
             }
         }
@@ -341,7 +383,7 @@ fn pre_process_variable_data_type(
         // create index entry
         add_nested_datatypes(new_type_name.as_str(), &mut data_type, types, &location);
         data_type.set_name(new_type_name);
-        types.push(UserTypeDeclaration { data_type, initializer: None, location, scope });
+        types.push(UserTypeDeclaration { data_type, initializer: None, location, scope, attributes: vec![] });
     }
     //make sure it gets generated
 }
@@ -358,7 +400,13 @@ fn add_nested_datatypes(
     {
         data_type.set_name(new_type_name.clone());
         add_nested_datatypes(new_type_name.as_str(), &mut data_type, types, &inner_location);
-        types.push(UserTypeDeclaration { data_type, initializer: None, location: location.clone(), scope });
+        types.push(UserTypeDeclaration {
+            data_type,
+            initializer: None,
+            location: location.clone(),
+            scope,
+            attributes: vec![],
+        });
     }
 }
 
@@ -2,11 +2,11 @@
 //! The `AstVisitor` trait provides a set of methods for traversing and visiting ASTs
 
 use crate::ast::{
-    flatten_expression_list, Allocation, Assignment, AstNode, AstStatement, BinaryExpression, CallStatement,
-    CompilationUnit, ConfigVariable, DataType, DataTypeDeclaration, DefaultValue, DirectAccess,
-    EmptyStatement, HardwareAccess, Implementation, Interface, JumpStatement, LabelStatement,
-    MultipliedStatement, Pou, RangeStatement, ReferenceAccess, ReferenceExpr, UnaryExpression,
-    UserTypeDeclaration, Variable, VariableBlock,
+    flatten_expression_list, AccessVariable, Allocation, Assignment, AstNode, AstStatement, BinaryExpression,
+    CallStatement, CompilationUnit, ConfigVariable, Configuration, DataType, DataTypeDeclaration,
+    DefaultValue, DirectAccess, EmptyStatement, HardwareAccess, Implementation, Interface, JumpStatement,
+    LabelStatement, MultipliedStatement, Pou, RangeStatement, ReferenceAccess, ReferenceExpr,
+    UnaryExpression, UserTypeDeclaration, Variable, VariableBlock,
 };
 use crate::control_statements::{AstControlStatement, ConditionalBlock, ReturnStatement};
 use crate::literals::AstLiteral;
@@ -158,6 +158,22 @@ pub trait AstVisitor: Sized {
         config_variable.walk(self);
     }
 
+    /// Visits an `AccessVariable` node.
+    /// Make sure to call `walk` on the `AccessVariable` node to visit its children.
+    /// # Arguments
+    /// * `access_variable` - The unwraped, typed `AccessVariable` node to visit.
+    fn visit_access_variable(&mut self, access_variable: &AccessVariable) {
+        access_variable.walk(self);
+    }
+
+    /// Visits a `Configuration` node.
+    /// Make sure to call `walk` on the `Configuration` node to visit its children.
+    /// # Arguments
+    /// * `configuration` - The unwraped, typed `Configuration` node to visit.
+    fn visit_configuration(&mut self, configuration: &Configuration) {
+        configuration.walk(self);
+    }
+
     /// Visits a `Interface`.
     /// Make sure to call `walk` on the `Interface` to visit its children.
     /// # Arguments
@@ -320,6 +336,16 @@ pub trait AstVisitor: Sized {
     /// * `node` - The wrapped `AstNode` node to visit. Offers access to location information and AstId
     fn visit_vla_range_statement(&mut self, _node: &AstNode) {}
 
+    /// Visits a `This` node.
+    /// # Arguments
+    /// * `node` - The wrapped `AstNode` node to visit. Offers access to location information and AstId
+    fn visit_this(&mut self, _node: &AstNode) {}
+
+    /// Visits a `Super` node.
+    /// # Arguments
+    /// * `node` - The wrapped `AstNode` node to visit. Offers access to location information and AstId
+    fn visit_super(&mut self, _node: &AstNode) {}
+
     /// Visits an `Assignment` node.
     /// Make sure to call `walk` on the `Assignment` node to visit its children.
     /// # Arguments
@@ -552,6 +578,14 @@ impl Walker for AstControlStatement {
                 walk_conditional_blocks(visitor, &stmt.case_blocks);
                 visit_all_nodes!(visitor, &stmt.else_block);
             }
+            AstControlStatement::Try(stmt) => {
+                visit_all_nodes!(visitor, &stmt.body);
+                if let Some(catch_block) = &stmt.catch_block {
+                    visit_all_nodes!(visitor, &catch_block.exception);
+                    visit_all_nodes!(visitor, &catch_block.body);
+                }
+                visit_all_nodes!(visitor, &stmt.finally_block);
+            }
         }
     }
 }
@@ -595,6 +629,8 @@ impl Walker for AstNode {
             AstStatement::ParenExpression(stmt) => visitor.visit_paren_expression(stmt, node),
             AstStatement::RangeStatement(stmt) => visitor.visit_range_statement(stmt, node),
             AstStatement::VlaRangeStatement => visitor.visit_vla_range_statement(node),
+            AstStatement::This => visitor.visit_this(node),
+            AstStatement::Super => visitor.visit_super(node),
             AstStatement::Assignment(stmt) => visitor.visit_assignment(stmt, node),
             AstStatement::OutputAssignment(stmt) => visitor.visit_output_assignment(stmt, node),
             AstStatement::RefAssignment(stmt) => visitor.visit_ref_assignment(stmt, node),
@@ -636,9 +672,17 @@ impl Walker for CompilationUnit {
             visitor.visit_config_variable(config_variable);
         }
 
+        for access_variable in &self.var_access {
+            visitor.visit_access_variable(access_variable);
+        }
+
         for interface in &self.interfaces {
             visitor.visit_interface(interface);
         }
+
+        for configuration in &self.configurations {
+            visitor.visit_configuration(configuration);
+        }
     }
 }
 
@@ -683,6 +727,28 @@ impl Walker for ConfigVariable {
     }
 }
 
+impl Walker for AccessVariable {
+    fn walk<V>(&self, _visitor: &mut V)
+    where
+        V: AstVisitor,
+    {
+        // do nothing
+    }
+}
+
+impl Walker for Configuration {
+    fn walk<V>(&self, visitor: &mut V)
+    where
+        V: AstVisitor,
+    {
+        for task in self.resources.iter().flat_map(|it| it.tasks.iter()) {
+            visit_all_nodes!(visitor, &task.interval);
+            visit_all_nodes!(visitor, &task.single);
+            visit_all_nodes!(visitor, &task.priority);
+        }
+    }
+}
+
 impl Walker for Interface {
     fn walk<V>(&self, _visitor: &mut V)
     where
@@ -698,7 +764,7 @@ impl Walker for DataType {
         V: AstVisitor,
     {
         match self {
-            DataType::StructType { variables, .. } => {
+            DataType::StructType { variables, .. } | DataType::UnionType { variables, .. } => {
                 for v in variables.iter() {
                     visitor.visit_variable(v);
                 }
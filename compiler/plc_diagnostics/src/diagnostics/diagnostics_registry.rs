@@ -207,7 +207,7 @@ lazy_static! {
         E103,   Error,      include_str!("./error_codes/E103.md"),  // Immutable Hardware Binding
         E104,   Error,      include_str!("./error_codes/E104.md"),  // Config Variable With Incomplete Address
         E105,   Error,      include_str!("./error_codes/E105.md"),  // CONSTANT keyword in POU
-        E106,   Warning,    include_str!("./error_codes/E106.md"),  // VAR_EXTERNAL have no effect
+        E106,   Warning,    include_str!("./error_codes/E106.md"),  // VAR_EXTERNAL have no effect (retired, no longer reported)
         E107,   Error,      include_str!("./error_codes/E107.md"),  // Missing configuration for template variable
         E108,   Error,      include_str!("./error_codes/E108.md"),  // Template variable is configured multiple times
         E109,   Error,      include_str!("./error_codes/E109.md"),  // Stateful pointer variable initialized with temporary value
@@ -215,6 +215,43 @@ lazy_static! {
         E111,   Error,      include_str!("./error_codes/E111.md"),  // Duplicate interface methods with different signatures
         E112,   Error,      include_str!("./error_codes/E112.md"),  // Incomplete interface implementation
         E113,   Warning,    include_str!("./error_codes/E113.md"),  // Interface default method implementation
+        E114,   Error,      include_str!("./error_codes/E114.md"),  // Property declared outside of a function block, class or program
+        E115,   Error,      include_str!("./error_codes/E115.md"),  // Missing property accessor
+        E116,   Error,      include_str!("./error_codes/E116.md"),  // OVERRIDE without an overridden method
+        E117,   Warning,    include_str!("./error_codes/E117.md"),  // Missing OVERRIDE
+        E118,   Error,      include_str!("./error_codes/E118.md"),  // Override signature mismatch
+        E119,   Error,      include_str!("./error_codes/E119.md"),  // Invalid use of THIS or SUPER
+        E120,   Error,      include_str!("./error_codes/E120.md"),  // Unknown namespace
+        E121,   Error,      include_str!("./error_codes/E121.md"),  // Union initializer
+        E122,   Error,      include_str!("./error_codes/E122.md"),  // Conditional compilation pragma
+        E123,   Error,      include_str!("./error_codes/E123.md"),  // Invalid task declaration
+        E124,   Error,      include_str!("./error_codes/E124.md"),  // Invalid program configuration
+        E125,   Error,      include_str!("./error_codes/E125.md"),  // Invalid sequential function chart
+        E126,   Error,      include_str!("./error_codes/E126.md"),  // Unsafe sequential function chart
+        E127,   Warning,    include_str!("./error_codes/E127.md"),  // Unreachable step
+        E128,   Error,      include_str!("./error_codes/E128.md"),  // Invalid instruction list
+        E129,   Error,      include_str!("./error_codes/E129.md"),  // Invalid dynamic memory operation
+        E130,   Error,      include_str!("./error_codes/E130.md"),  // Invalid exception handling
+        E131,   Error,      include_str!("./error_codes/E131.md"),  // Invalid VAR_ACCESS declaration
+        E132,   Error,      include_str!("./error_codes/E132.md"),  // Invalid RETAIN or PERSISTENT declaration
+        E133,   Warning,    include_str!("./error_codes/E133.md"),  // Retained pointer
+        E134,   Error,      include_str!("./error_codes/E134.md"),  // Illegal access to a restricted member
+        E135,   Error,      include_str!("./error_codes/E135.md"),  // Invalid access modifier
+        E136,   Error,      include_str!("./error_codes/E136.md"),  // Instance of an ABSTRACT POU
+        E137,   Error,      include_str!("./error_codes/E137.md"),  // ABSTRACT method with an implementation
+        E138,   Error,      include_str!("./error_codes/E138.md"),  // ABSTRACT method not implemented
+        E139,   Error,      include_str!("./error_codes/E139.md"),  // Extending a FINAL POU
+        E140,   Error,      include_str!("./error_codes/E140.md"),  // Overriding a FINAL method
+        E141,   Error,      include_str!("./error_codes/E141.md"),  // Init arguments without an FB_INIT method
+        E142,   Warning,    include_str!("./error_codes/E142.md"),  // Lifecycle method outside of a FUNCTION_BLOCK or CLASS
+        E143,   Error,      include_str!("./error_codes/E143.md"),  // STRUCT extends something other than a STRUCT
+        E144,   Error,      include_str!("./error_codes/E144.md"),  // Type information requested for a type without members
+        E145,   Error,      include_str!("./error_codes/E145.md"),  // Property with a default value
+        E146,   Error,      include_str!("./error_codes/E146.md"),  // Duplicate property accessor
+        E147,   Error,      include_str!("./error_codes/E147.md"),  // Property without accessors
+        E148,   Error,      include_str!("./error_codes/E148.md"),  // Exception handling not supported for target
+        E149,   Error,      include_str!("./error_codes/E149.md"),  // Invalid VAR_EXTERNAL declaration
+        E150,   Error,      include_str!("./error_codes/E150.md"),  // Edge-detecting contact in a FUNCTION
     );
 }
 
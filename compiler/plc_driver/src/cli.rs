@@ -99,6 +99,15 @@ pub struct CompileParameters {
     #[clap(name = "include", long, short = 'i', help = "Include source files for external functions")]
     pub includes: Vec<String>,
 
+    #[clap(
+        name = "define",
+        long,
+        short = 'D',
+        global = true,
+        help = "Define a symbol (NAME or NAME=value) for conditional compilation pragmas like {IF defined(NAME)}"
+    )]
+    pub defines: Vec<String>,
+
     #[clap(
         name = "script",
         long,
@@ -128,6 +137,17 @@ pub struct CompileParameters {
     ) ]
     pub hardware_config: Option<String>,
 
+    #[clap(
+        name = "task-conf",
+        long,
+        global = true,
+        help = "Generate the task configuration of the project's CONFIGURATION to the given location.
+    Format is detected by extension.
+    Supported formats : json, toml",
+    parse(try_from_str = validate_config)
+    ) ]
+    pub task_config: Option<String>,
+
     #[clap(
         name = "got-layout-file",
         long,
@@ -242,6 +262,13 @@ pub struct CompileParameters {
     )]
     pub online_change: bool,
 
+    #[clap(
+        long,
+        help = "Raise an exception on integer divisions by zero and dereferences of null pointers",
+        global = true
+    )]
+    pub runtime_checks: bool,
+
     #[clap(subcommand)]
     pub commands: Option<SubCommands>,
 }
@@ -418,6 +445,10 @@ impl CompileParameters {
         self.hardware_config.as_deref().and_then(get_config_format)
     }
 
+    pub fn task_config_format(&self) -> Option<ConfigFormat> {
+        self.task_config.as_deref().and_then(get_config_format)
+    }
+
     pub fn got_layout_format(&self) -> ConfigFormat {
         // It is safe to unwrap here, since the provided argument to `--got-online-change` has been checked with `validate_config`
         get_config_format(&self.got_layout_file).unwrap()
@@ -873,6 +904,26 @@ mod cli_tests {
         assert_eq!(parameters.includes, vec!["include1", "include2", "include3"]);
     }
 
+    #[test]
+    fn defines_added() {
+        let parameters = CompileParameters::parse(vec_of_strings!(
+            "input.st",
+            "-D",
+            "VARIANT_A",
+            "--define",
+            "AXES=2",
+            "-DX"
+        ))
+        .unwrap();
+        assert_eq!(parameters.defines, vec!["VARIANT_A", "AXES=2", "X"]);
+    }
+
+    #[test]
+    fn defines_added_to_build_subcommand() {
+        let parameters = CompileParameters::parse(vec_of_strings!("build", "-D", "VARIANT_A")).unwrap();
+        assert_eq!(parameters.defines, vec!["VARIANT_A"]);
+    }
+
     #[test]
     fn config_option_set() {
         let parameters =
@@ -889,6 +940,18 @@ mod cli_tests {
         expect_argument_error(vec_of_strings!("foo", "--hardware-conf=conf.xml"), ErrorKind::ValueValidation);
     }
 
+    #[test]
+    fn task_config_option_set() {
+        let parameters = CompileParameters::parse(vec_of_strings!("foo", "--task-conf=tasks.json")).unwrap();
+        assert_eq!(parameters.task_config, Some("tasks.json".to_string()));
+        assert_eq!(parameters.task_config_format().unwrap(), ConfigFormat::JSON);
+        let parameters = CompileParameters::parse(vec_of_strings!("foo", "--task-conf=tasks.toml")).unwrap();
+        assert_eq!(parameters.task_config, Some("tasks.toml".to_string()));
+        assert_eq!(parameters.task_config_format().unwrap(), ConfigFormat::TOML);
+        assert_eq!(parameters.config_format(), None);
+        expect_argument_error(vec_of_strings!("foo", "--task-conf=tasks.xml"), ErrorKind::ValueValidation);
+    }
+
     #[test]
     fn error_format_default_set() {
         // make sure the default error format is set
@@ -56,6 +56,8 @@ pub struct CompileOptions {
     pub debug_level: DebugLevel,
     pub single_module: bool,
     pub online_change: OnlineChange,
+    /// Whether integer divisions and pointer dereferences are checked at runtime
+    pub runtime_checks: bool,
 }
 
 impl Default for CompileOptions {
@@ -70,6 +72,7 @@ impl Default for CompileOptions {
             debug_level: DebugLevel::None,
             single_module: false,
             online_change: OnlineChange::Disabled,
+            runtime_checks: false,
         }
     }
 }
@@ -279,6 +282,7 @@ fn get_project(compile_parameters: &CompileParameters) -> Result<Project<PathBuf
             }
         })
         .map(|proj| proj.with_output_name(compile_parameters.output.clone()))
+        .map(|proj| proj.with_defines(compile_parameters.defines.clone()))
 }
 
 fn get_config(root: &Path) -> PathBuf {
@@ -23,6 +23,7 @@ use participant::{PipelineParticipant, PipelineParticipantMut};
 use plc::{
     codegen::{CodegenContext, GeneratedModule},
     index::{indexer, FxIndexSet, Index},
+    lexer::Defines,
     linker::LinkerType,
     lowering::InitVisitor,
     output::FormatOption,
@@ -171,6 +172,7 @@ impl<T: SourceContainer> BuildPipeline<T> {
                 } else {
                     OnlineChange::Disabled
                 },
+                runtime_checks: params.runtime_checks,
             }
         })
     }
@@ -248,13 +250,35 @@ impl<T: SourceContainer> BuildPipeline<T> {
     /// Register all default participants (excluding codegen/linking)
     pub fn register_default_participants(&mut self) {
         use participant::InitParticipant;
-        use plc::lowering::calls::AggregateTypeLowerer;
+        use plc::lowering::{
+            calls::AggregateTypeLowerer, enums::EnumConversionLowerer, generics::GenericsLowerer,
+            inheritance::InheritanceLowerer, namespaces::NamespaceLowerer, property::PropertyLowerer,
+            sfc::SfcLowerer,
+        };
 
         // XXX: should we use a static array of participants?
         let init_participant =
             InitParticipant::new(&self.project.get_init_symbol_name(), self.context.provider());
         self.register_mut_participant(Box::new(init_participant));
 
+        // generic function blocks and classes are instantiated first, so their instances are lowered like
+        // any other pou
+        let generics_participant = GenericsLowerer::new(self.context.provider());
+        self.register_mut_participant(Box::new(generics_participant));
+
+        // charts are lowered next, so the references in the generated statements are resolved as well
+        let sfc_participant = SfcLowerer::new(self.context.provider());
+        self.register_mut_participant(Box::new(sfc_participant));
+
+        self.register_mut_participant(Box::new(NamespaceLowerer));
+        self.register_mut_participant(Box::new(InheritanceLowerer));
+
+        let property_participant = PropertyLowerer::new(self.context.provider());
+        self.register_mut_participant(Box::new(property_participant));
+
+        // enums are declared with their final names, so their conversions are named after them
+        self.register_mut_participant(Box::new(EnumConversionLowerer));
+
         let aggregate_return_participant = AggregateTypeLowerer::new(self.context.provider());
         self.register_mut_participant(Box::new(aggregate_return_participant));
     }
@@ -306,6 +330,15 @@ impl<T: SourceContainer> Pipeline for BuildPipeline<T> {
             annotated_project.generate_hardware_information(format, location)?;
         }
 
+        if let Some((location, format)) = self
+            .compile_parameters
+            .as_ref()
+            .and_then(|it| it.task_config.as_ref())
+            .zip(self.compile_parameters.as_ref().and_then(CompileParameters::task_config_format))
+        {
+            annotated_project.generate_task_information(format, location)?;
+        }
+
         // 5 : Codegen
         if !self.compile_parameters.as_ref().map(CompileParameters::is_check).unwrap_or_default() {
             let context = CodegenContext::create();
@@ -453,6 +486,7 @@ impl ParsedProject {
         //TODO in parallel
         //Parse the source files
         let mut units = vec![];
+        let defines = Defines::from_definitions(project.get_defines());
 
         let sources = project
             .get_sources()
@@ -461,12 +495,12 @@ impl ParsedProject {
                 let source = ctxt.get(it.get_location_str()).expect("All sources should've been read");
 
                 let parse_func = match source.get_type() {
-                    source_code::SourceType::Text => parse_file,
+                    source_code::SourceType::Text | source_code::SourceType::Il => parse_file,
                     source_code::SourceType::Xml => cfc::xml_parser::parse_file,
                     source_code::SourceType::Unknown => unreachable!(),
                 };
 
-                parse_func(source, LinkageType::Internal, ctxt.provider(), diagnostician)
+                parse_func(source, LinkageType::Internal, ctxt.provider(), &defines, diagnostician)
             })
             .collect::<Vec<_>>();
 
@@ -478,7 +512,7 @@ impl ParsedProject {
             .iter()
             .map(|it| {
                 let source = ctxt.get(it.get_location_str()).expect("All sources should've been read");
-                parse_file(source, LinkageType::External, ctxt.provider(), diagnostician)
+                parse_file(source, LinkageType::External, ctxt.provider(), &defines, diagnostician)
             })
             .collect::<Vec<_>>();
         units.extend(includes);
@@ -490,7 +524,7 @@ impl ParsedProject {
             .flat_map(LibraryInformation::get_includes)
             .map(|it| {
                 let source = ctxt.get(it.get_location_str()).expect("All sources should've been read");
-                parse_file(source, LinkageType::External, ctxt.provider(), diagnostician)
+                parse_file(source, LinkageType::External, ctxt.provider(), &defines, diagnostician)
             })
             .collect::<Vec<_>>();
         units.extend(lib_includes);
@@ -708,6 +742,7 @@ impl AnnotatedProject {
             compile_options.debug_level,
             //FIXME don't clone here
             compile_options.online_change.clone(),
+            compile_options.runtime_checks,
         );
         //Create a types codegen, this contains all the type declarations
         //Associate the index type with LLVM types
@@ -784,6 +819,15 @@ impl AnnotatedProject {
         })?;
         Ok(())
     }
+
+    pub fn generate_task_information(&self, format: ConfigFormat, location: &str) -> Result<(), Diagnostic> {
+        let task_conf = plc::task_configuration::collect_task_configuration(&self.index);
+        let generated_conf = plc::task_configuration::generate_task_configuration(&task_conf, format)?;
+        File::create(location).and_then(|mut it| it.write_all(generated_conf.as_bytes())).map_err(|it| {
+            Diagnostic::new(it.to_string()).with_internal_error(it.into()).with_error_code("E002")
+        })?;
+        Ok(())
+    }
 }
 
 /// Ensures the directores for the various targets have been created
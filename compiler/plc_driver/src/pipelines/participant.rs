@@ -13,8 +13,14 @@ use std::{
 
 use ast::provider::IdProvider;
 use plc::{
-    codegen::GeneratedModule, lowering::calls::AggregateTypeLowerer, output::FormatOption, ConfigFormat,
-    OnlineChange, Target,
+    codegen::GeneratedModule,
+    lowering::{
+        calls::AggregateTypeLowerer, enums::EnumConversionLowerer, generics::GenericsLowerer,
+        inheritance::InheritanceLowerer, namespaces::NamespaceLowerer, property::PropertyLowerer,
+        sfc::SfcLowerer,
+    },
+    output::FormatOption,
+    ConfigFormat, OnlineChange, Target,
 };
 use plc_diagnostics::diagnostics::Diagnostic;
 use project::{object::Object, project::LibraryInformation};
@@ -224,6 +230,79 @@ impl PipelineParticipantMut for InitParticipant {
     }
 }
 
+impl PipelineParticipantMut for GenericsLowerer {
+    fn pre_index(&mut self, parsed_project: ParsedProject) -> ParsedProject {
+        let ParsedProject { mut units } = parsed_project;
+        self.lower_generics(&mut units);
+        ParsedProject { units }
+    }
+}
+
+impl PipelineParticipantMut for SfcLowerer {
+    fn pre_index(&mut self, parsed_project: ParsedProject) -> ParsedProject {
+        let ParsedProject { mut units } = parsed_project;
+        self.lower_sfcs(&mut units);
+        ParsedProject { units }
+    }
+}
+
+impl PipelineParticipantMut for NamespaceLowerer {
+    fn pre_index(&mut self, parsed_project: ParsedProject) -> ParsedProject {
+        let ParsedProject { mut units } = parsed_project;
+        self.lower_namespaces(&mut units);
+        ParsedProject { units }
+    }
+}
+
+impl PipelineParticipantMut for InheritanceLowerer {
+    fn pre_index(&mut self, parsed_project: ParsedProject) -> ParsedProject {
+        let ParsedProject { mut units } = parsed_project;
+        self.lower_inheritance(&mut units);
+        ParsedProject { units }
+    }
+}
+
+impl PipelineParticipantMut for EnumConversionLowerer {
+    fn pre_index(&mut self, parsed_project: ParsedProject) -> ParsedProject {
+        let ParsedProject { mut units } = parsed_project;
+        self.lower_enums(&mut units);
+        ParsedProject { units }
+    }
+}
+
+impl PipelineParticipantMut for PropertyLowerer {
+    fn pre_index(&mut self, parsed_project: ParsedProject) -> ParsedProject {
+        let ParsedProject { mut units } = parsed_project;
+        units.iter_mut().for_each(|unit| self.lower_properties(unit));
+        ParsedProject { units }
+    }
+
+    fn post_annotate(&mut self, annotated_project: AnnotatedProject) -> AnnotatedProject {
+        if annotated_project.index.get_properties().is_empty() {
+            // nothing to lower, spare us from annotating the project again
+            return annotated_project;
+        }
+
+        let AnnotatedProject { units, index, annotations } = annotated_project;
+        self.index = Some(index);
+        self.annotation = Some(Box::new(annotations));
+
+        let units = units
+            .into_iter()
+            .map(|AnnotatedUnit { mut unit, .. }| {
+                self.lower_references(&mut unit);
+                unit
+            })
+            .collect();
+        let indexed_project = IndexedProject {
+            project: ParsedProject { units },
+            index: self.index.take().expect("Index"),
+            unresolvables: vec![],
+        };
+        indexed_project.annotate(self.id_provider.clone())
+    }
+}
+
 impl PipelineParticipantMut for AggregateTypeLowerer {
     fn post_index(&mut self, indexed_project: IndexedProject) -> IndexedProject {
         let IndexedProject { mut project, index, .. } = indexed_project;
@@ -27,6 +27,23 @@ impl Default for MainType {
 /// An implementation is also provided for `Vec<SourceContainer>`
 ///
 pub fn compile<T: Compilable>(codegen_context: &CodegenContext, source: T) -> GeneratedModule<'_> {
+    let compile_options = CompileOptions {
+        optimization: plc::OptimizationLevel::None,
+        debug_level: plc::DebugLevel::None,
+        ..Default::default()
+    };
+
+    compile_with_options(codegen_context, source, &compile_options)
+}
+
+///
+/// Compiles the given source into an executable Module using the given compile options
+///
+pub fn compile_with_options<'ctx, T: Compilable>(
+    codegen_context: &'ctx CodegenContext,
+    source: T,
+    compile_options: &CompileOptions,
+) -> GeneratedModule<'ctx> {
     let source = source.containers();
     let project = Project::new("TestProject".to_string()).with_sources(source);
     let context = GlobalContext::new().with_source(project.get_sources(), None).unwrap();
@@ -47,13 +64,7 @@ pub fn compile<T: Compilable>(codegen_context: &CodegenContext, source: T) -> Ge
     let project = pipeline.index(project).unwrap();
     let project = pipeline.annotate(project).unwrap();
 
-    let compile_options = CompileOptions {
-        optimization: plc::OptimizationLevel::None,
-        debug_level: plc::DebugLevel::None,
-        ..Default::default()
-    };
-
-    match project.generate_single_module(codegen_context, &compile_options) {
+    match project.generate_single_module(codegen_context, compile_options) {
         Ok(res) => res.unwrap(),
         Err(e) => panic!("{e}"),
     }
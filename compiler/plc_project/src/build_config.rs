@@ -51,6 +51,9 @@ pub struct ProjectConfig {
     pub libraries: Vec<LibraryConfig>,
     #[serde(default)]
     pub package_commands: Vec<String>,
+    /// Symbols for conditional compilation, in the form of `NAME` or `NAME=value`
+    #[serde(default)]
+    pub defines: Vec<String>,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub version: Option<String>,
@@ -259,7 +262,8 @@ mod tests {
             "file.st"
             ],
         "compile_type" : "Shared",
-        "output": "proj.so"
+        "output": "proj.so",
+        "defines": ["VARIANT_A", "AXES=2"]
     }
 "#;
 
@@ -303,6 +307,7 @@ mod tests {
             package_commands: vec![],
             version: None,
             format_version: None,
+            defines: vec![],
         };
         let proj = ProjectConfig::try_parse(SIMPLE_PROGRAM.into()).unwrap();
 
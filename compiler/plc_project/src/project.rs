@@ -83,6 +83,8 @@ pub struct Project<T: SourceContainer> {
     format: FormatOption,
     /// Output Name
     output: Option<String>,
+    /// Symbols for conditional compilation, in the form of `NAME` or `NAME=value`
+    defines: Vec<String>,
 }
 
 impl<T: SourceContainer> LibraryInformation<T> {
@@ -170,6 +172,7 @@ impl Project<PathBuf> {
             includes: vec![],
             objects: vec![],
             library_paths: vec![],
+            defines: project_config.defines,
         })
     }
 
@@ -206,6 +209,7 @@ impl<S: SourceContainer> Project<S> {
             library_paths: vec![],
             format: FormatOption::default(),
             output: None,
+            defines: vec![],
         }
     }
 
@@ -250,6 +254,14 @@ impl<S: SourceContainer> Project<S> {
         proj
     }
 
+    /// Adds symbols for conditional compilation, definitions of already defined symbols replace the
+    /// existing ones
+    pub fn with_defines(self, defines: Vec<String>) -> Self {
+        let mut proj = self;
+        proj.defines.extend(defines);
+        proj
+    }
+
     pub fn get_library_paths(&self) -> &[PathBuf] {
         &self.library_paths
     }
@@ -273,6 +285,10 @@ impl<S: SourceContainer> Project<S> {
         &self.objects
     }
 
+    pub fn get_defines(&self) -> &[String] {
+        &self.defines
+    }
+
     pub fn get_name(&self) -> &str {
         &self.name
     }
@@ -17,6 +17,9 @@ pub enum SourceType {
     /// An xml file, probably cfc
     Xml,
 
+    /// A text file whose POU bodies are written in instruction list (IL)
+    Il,
+
     /// Unknown type, probably a binary
     Unknown,
 }
@@ -37,6 +40,7 @@ pub trait SourceContainer: Sync + Send {
                 Some("o") | Some("so") | Some("exe") => SourceType::Unknown,
                 //XXX: file ending vs first line? (<?xml ...)
                 Some("cfc") | Some("fbd") | Some("xml") => SourceType::Xml,
+                Some("il") => SourceType::Il,
                 _ => SourceType::Text,
             }
         } else {
@@ -91,7 +95,7 @@ impl SourceCodeFactory for &str {
 impl<T: AsRef<Path> + Sync + Send> SourceContainer for T {
     fn load_source(&self, encoding: Option<&'static Encoding>) -> Result<SourceCode, String> {
         let source_type = self.get_type();
-        if matches!(source_type, SourceType::Text | SourceType::Xml) {
+        if matches!(source_type, SourceType::Text | SourceType::Xml | SourceType::Il) {
             let mut file = File::open(self).map_err(|err| err.to_string())?;
             let source = create_source_code(&mut file, encoding)?;
 
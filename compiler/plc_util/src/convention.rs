@@ -7,6 +7,27 @@ pub fn qualified_name<T: AsRef<str> + Display>(qualifier: T, name: T) -> String
     format!("{qualifier}.{name}")
 }
 
+/// The separator between the segments of a namespace-qualified name, e.g. `a::b::motor` for `a.b.motor`.
+pub const NAMESPACE_SEPARATOR: &str = "::";
+
+/// Returns a namespace-qualified name in the form of `<namespace>::<name>`.
+pub fn namespaced_name<T: AsRef<str> + Display>(namespace: T, name: T) -> String {
+    format!("{namespace}{NAMESPACE_SEPARATOR}{name}")
+}
+
+/// Returns the namespace of the given namespace-qualified name, e.g. `a::b` for `a::b::motor.start`.
+pub fn get_namespace(name: &str) -> Option<&str> {
+    // a method's or action's name is qualified with its container, which holds the namespace
+    let container = name.split('.').next().unwrap_or(name);
+    container.rsplit_once(NAMESPACE_SEPARATOR).map(|(namespace, _)| namespace)
+}
+
+/// The runtime function raising an exception with the given code, i.e. `void __plc_raise(int32_t code)`.
+pub const RAISE_FUNCTION_NAME: &str = "__plc_raise";
+
+/// The thread local variable pointing to the innermost exception frame pushed by a `__TRY` statement.
+pub const EXCEPTION_FRAME_NAME: &str = "__plc_exception_frame";
+
 /// Returns a name for internally created types in the form of `__<prefix><original_type_name>`.
 pub fn internal_type_name<T: AsRef<str> + Display>(prefix: T, original_type_name: T) -> String {
     format!("__{prefix}{original_type_name}")
@@ -19,6 +40,18 @@ mod tests {
         assert_eq!(super::qualified_name("main", "foo"), "main.foo".to_string());
     }
 
+    #[test]
+    fn namespaced_name() {
+        assert_eq!(super::namespaced_name("a::b", "motor"), "a::b::motor".to_string());
+    }
+
+    #[test]
+    fn get_namespace() {
+        assert_eq!(super::get_namespace("a::b::motor"), Some("a::b"));
+        assert_eq!(super::get_namespace("a::b::motor.start"), Some("a::b"));
+        assert_eq!(super::get_namespace("motor.start"), None);
+    }
+
     #[test]
     fn internal_type_name() {
         assert_eq!(super::internal_type_name("POINTER_TO_", "foo"), "__POINTER_TO_foo");
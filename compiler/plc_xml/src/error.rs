@@ -14,6 +14,9 @@ pub enum Error {
     /// `id` does not exist.
     MissingAttribute(String),
 
+    /// Indicates that a required child element is missing, e.g. an `<array>` without a `<baseType>`.
+    MissingElement(&'static str),
+
     /// Indicates that reading the next line of the current XML file failed.
     ReadEvent(quick_xml::Error),
 
@@ -53,6 +56,7 @@ impl std::fmt::Debug for Error {
                 )
             }
             Self::MissingAttribute(key) => write!(f, "Failed to find attribute '{key}'"),
+            Self::MissingElement(name) => write!(f, "Failed to find element '{name}'"),
             Self::ReadEvent(why) => write!(f, "Failed to read XML; {why}"),
             Self::UnexpectedElement(element) => write!(f, "Found an unexpected element '{element}'"),
             Self::Encoding(why) => write!(f, "{why:#?}"),
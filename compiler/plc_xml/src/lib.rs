@@ -8,12 +8,16 @@ pub(crate) mod model {
     pub mod action;
     pub mod block;
     pub mod body;
+    pub mod configuration;
     pub mod connector;
     pub mod control;
+    pub mod declaration;
     pub mod fbd;
     pub mod interface;
+    pub mod ladder;
     pub mod pou;
     pub mod project;
+    pub mod sfc;
     pub mod variables;
 }
 mod reader;
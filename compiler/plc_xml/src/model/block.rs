@@ -31,6 +31,12 @@ impl Block<'_> {
             variables,
         })
     }
+
+    /// Returns the `EN` input of the block, which is connected to the power flow if the block is placed on
+    /// the rung of a ladder diagram
+    pub fn enable(&self) -> Option<&BlockVariable> {
+        self.variables.iter().find(|it| it.is_enable())
+    }
 }
 
 impl Parseable for Block<'_> {
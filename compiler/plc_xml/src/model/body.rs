@@ -1,20 +1,39 @@
 use quick_xml::events::{BytesStart, Event};
 
-use super::fbd::FunctionBlockDiagram;
-use crate::{error::Error, reader::Reader, xml_parser::Parseable};
+use super::{fbd::FunctionBlockDiagram, sfc::SequentialFunctionChart};
+use crate::{error::Error, extensions::TryToString, reader::Reader, xml_parser::Parseable};
 
 #[derive(Debug, Default)]
 pub(crate) struct Body<'xml> {
+    /// the network of a graphical body, i.e. `<FBD>` or `<LD>`
     pub function_block_diagram: FunctionBlockDiagram<'xml>,
+    /// the content of a textual body, i.e. `<ST><xhtml>...</xhtml></ST>`
+    pub structured_text: Option<String>,
+    pub sequential_function_chart: Option<SequentialFunctionChart<'xml>>,
 }
 
 impl<'xml> Body<'xml> {
     fn new(fbd: FunctionBlockDiagram<'xml>) -> Result<Self, Error> {
-        Ok(Self { function_block_diagram: fbd })
+        Ok(Self { function_block_diagram: fbd, ..Default::default() })
     }
 
     fn empty() -> Result<Self, Error> {
-        Ok(Self { function_block_diagram: FunctionBlockDiagram::default() })
+        Ok(Self::default())
+    }
+
+    pub(crate) fn visit_structured_text(reader: &mut Reader) -> Result<String, Error> {
+        let mut content = String::new();
+        loop {
+            match reader.read_event().map_err(Error::ReadEvent)? {
+                Event::Text(text) => content.push_str(&text.unescape()?),
+                Event::CData(text) => content.push_str(&text.into_inner().try_to_string()?),
+                Event::End(tag) if tag.name().as_ref() == b"ST" => break,
+                Event::Eof => return Err(Error::UnexpectedEndOfFile(vec![b"ST"])),
+                _ => {}
+            }
+        }
+
+        Ok(content)
     }
 }
 
@@ -23,9 +42,15 @@ impl Parseable for Body<'_> {
         let mut body = Body::default();
         loop {
             match reader.read_event().map_err(Error::ReadEvent)? {
-                Event::Start(tag) if tag.name().as_ref() == b"FBD" => {
+                Event::Start(tag) if matches!(tag.name().as_ref(), b"FBD" | b"LD") => {
                     body.function_block_diagram = FunctionBlockDiagram::visit(reader, Some(tag))?
                 }
+                Event::Start(tag) if tag.name().as_ref() == b"SFC" => {
+                    body.sequential_function_chart = Some(SequentialFunctionChart::visit(reader, Some(tag))?)
+                }
+                Event::Start(tag) if tag.name().as_ref() == b"ST" => {
+                    body.structured_text = Some(Body::visit_structured_text(reader)?)
+                }
                 Event::End(tag) if tag.name().as_ref() == b"body" => break,
                 Event::Eof => return Err(Error::UnexpectedEndOfFile(vec![b"body"])),
                 _ => {}
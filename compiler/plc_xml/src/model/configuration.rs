@@ -0,0 +1,172 @@
+//! The `<instances>` of a project, i.e. its configurations with their resources, tasks and program
+//! instances. They are rendered as ST `CONFIGURATION`s.
+
+use std::fmt::Display;
+
+use quick_xml::events::{BytesStart, Event};
+
+use crate::{
+    error::Error,
+    extensions::GetOrErr,
+    reader::Reader,
+    xml_parser::{get_attributes, Parseable},
+};
+
+use super::declaration::VariableList;
+
+#[derive(Debug, Default)]
+pub(crate) struct Configuration {
+    pub name: String,
+    pub global_vars: Vec<VariableList>,
+    pub resources: Vec<Resource>,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct Resource {
+    pub name: String,
+    pub global_vars: Vec<VariableList>,
+    pub tasks: Vec<Task>,
+    /// program instances which are not associated with a task
+    pub instances: Vec<PouInstance>,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct Task {
+    pub name: String,
+    pub interval: Option<String>,
+    pub single: Option<String>,
+    pub priority: String,
+    pub instances: Vec<PouInstance>,
+}
+
+/// A program instance, e.g. `<pouInstance name="main" typeName="MainPrg"/>`
+#[derive(Debug, Default)]
+pub(crate) struct PouInstance {
+    pub name: String,
+    pub type_name: String,
+}
+
+impl Parseable for Configuration {
+    fn visit(reader: &mut Reader, tag: Option<BytesStart>) -> Result<Self, Error> {
+        let Some(tag) = tag else { unreachable!() };
+        let attributes = get_attributes(tag.attributes())?;
+        let mut configuration = Configuration { name: attributes.get_or_err("name")?, ..Default::default() };
+        loop {
+            match reader.read_event()? {
+                Event::Start(tag) if tag.name().as_ref() == b"resource" => {
+                    configuration.resources.push(Resource::visit(reader, Some(tag))?)
+                }
+                Event::Start(tag) if tag.name().as_ref() == b"globalVars" => {
+                    configuration.global_vars.push(VariableList::visit(reader, Some(tag))?)
+                }
+                Event::Start(tag) => {
+                    reader.read_to_end(tag.name())?;
+                }
+                Event::End(tag) if tag.name().as_ref() == b"configuration" => break,
+                Event::Eof => return Err(Error::UnexpectedEndOfFile(vec![b"configuration"])),
+                _ => {}
+            }
+        }
+
+        Ok(configuration)
+    }
+}
+
+impl Parseable for Resource {
+    fn visit(reader: &mut Reader, tag: Option<BytesStart>) -> Result<Self, Error> {
+        let Some(tag) = tag else { unreachable!() };
+        let attributes = get_attributes(tag.attributes())?;
+        let mut resource = Resource { name: attributes.get_or_err("name")?, ..Default::default() };
+        loop {
+            match reader.read_event()? {
+                Event::Start(tag) if tag.name().as_ref() == b"task" => {
+                    resource.tasks.push(Task::visit(reader, Some(tag))?)
+                }
+                Event::Start(tag) if tag.name().as_ref() == b"pouInstance" => {
+                    resource.instances.push(PouInstance::visit(reader, Some(tag))?)
+                }
+                Event::Start(tag) if tag.name().as_ref() == b"globalVars" => {
+                    resource.global_vars.push(VariableList::visit(reader, Some(tag))?)
+                }
+                Event::Start(tag) => {
+                    reader.read_to_end(tag.name())?;
+                }
+                Event::End(tag) if tag.name().as_ref() == b"resource" => break,
+                Event::Eof => return Err(Error::UnexpectedEndOfFile(vec![b"resource"])),
+                _ => {}
+            }
+        }
+
+        Ok(resource)
+    }
+}
+
+impl Parseable for Task {
+    fn visit(reader: &mut Reader, tag: Option<BytesStart>) -> Result<Self, Error> {
+        let Some(tag) = tag else { unreachable!() };
+        let attributes = get_attributes(tag.attributes())?;
+        let mut task = Task {
+            name: attributes.get_or_err("name")?,
+            interval: attributes.get("interval").cloned(),
+            single: attributes.get("single").cloned(),
+            priority: attributes.get_or_err("priority")?,
+            instances: vec![],
+        };
+        loop {
+            match reader.read_event()? {
+                Event::Start(tag) if tag.name().as_ref() == b"pouInstance" => {
+                    task.instances.push(PouInstance::visit(reader, Some(tag))?)
+                }
+                Event::Start(tag) => {
+                    reader.read_to_end(tag.name())?;
+                }
+                Event::End(tag) if tag.name().as_ref() == b"task" => break,
+                Event::Eof => return Err(Error::UnexpectedEndOfFile(vec![b"task"])),
+                _ => {}
+            }
+        }
+
+        Ok(task)
+    }
+}
+
+impl Parseable for PouInstance {
+    fn visit(reader: &mut Reader, tag: Option<BytesStart>) -> Result<Self, Error> {
+        let Some(tag) = tag else { unreachable!() };
+        let attributes = get_attributes(tag.attributes())?;
+        reader.read_to_end(tag.name())?;
+
+        Ok(PouInstance { name: attributes.get_or_err("name")?, type_name: attributes.get_or_err("typeName")? })
+    }
+}
+
+impl Display for Configuration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "CONFIGURATION {}", self.name)?;
+        self.global_vars.iter().try_for_each(|it| writeln!(f, "{it}"))?;
+        self.resources.iter().try_for_each(|it| writeln!(f, "{it}"))?;
+        write!(f, "END_CONFIGURATION")
+    }
+}
+
+impl Display for Resource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "RESOURCE {}", self.name)?;
+        self.global_vars.iter().try_for_each(|it| writeln!(f, "{it}"))?;
+        for task in &self.tasks {
+            let properties = [("INTERVAL", &task.interval), ("SINGLE", &task.single)]
+                .into_iter()
+                .filter_map(|(name, value)| value.as_ref().map(|value| format!("{name} := {value}")))
+                .chain(std::iter::once(format!("PRIORITY := {}", task.priority)))
+                .collect::<Vec<_>>();
+            writeln!(f, "TASK {} ({});", task.name, properties.join(", "))?;
+        }
+        for task in &self.tasks {
+            task.instances
+                .iter()
+                .try_for_each(|it| writeln!(f, "PROGRAM {} WITH {} : {};", it.name, task.name, it.type_name))?;
+        }
+        self.instances.iter().try_for_each(|it| writeln!(f, "PROGRAM {} : {};", it.name, it.type_name))?;
+        write!(f, "END_RESOURCE")
+    }
+}
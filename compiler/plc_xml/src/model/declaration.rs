@@ -0,0 +1,457 @@
+//! Structured declarations of the PLCopen schema, i.e. types, variables and their initial values. They are
+//! rendered as their ST equivalent, which is then parsed alongside the text declarations of the project.
+
+use std::fmt::Display;
+
+use quick_xml::events::{BytesStart, Event};
+
+use crate::{
+    error::Error,
+    extensions::{GetOrErr, TryToString},
+    reader::Reader,
+    xml_parser::{get_attributes, Parseable},
+};
+
+/// The type of a variable or the base type of a data type, e.g. `<type><INT/></type>`
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum TypeReference {
+    /// An elementary type, e.g. `<INT/>`
+    Elementary(String),
+    /// A string with an optional length, e.g. `<string length="20"/>`
+    String { wide: bool, length: Option<String> },
+    /// A user defined type, e.g. `<derived name="Point"/>`
+    Derived(String),
+    Array { dimensions: Vec<(String, String)>, base_type: Box<TypeReference> },
+    Pointer(Box<TypeReference>),
+    Subrange { lower: String, upper: String, base_type: Box<TypeReference> },
+    Enum { values: Vec<(String, Option<String>)>, base_type: Option<Box<TypeReference>> },
+    Struct(Vec<VariableDeclaration>),
+}
+
+impl TypeReference {
+    /// Visits the type contained in the given element, e.g. `<type>` or `<baseType>`
+    pub(crate) fn visit_contained(reader: &mut Reader, container: &'static [u8]) -> Result<Self, Error> {
+        let mut reference = None;
+        loop {
+            match reader.read_event()? {
+                Event::Start(tag) if reference.is_none() => reference = Some(Self::visit(reader, Some(tag))?),
+                Event::End(tag) if tag.name().as_ref() == container => break,
+                Event::Eof => return Err(Error::UnexpectedEndOfFile(vec![container])),
+                _ => {}
+            }
+        }
+
+        reference.ok_or(Error::MissingElement("type"))
+    }
+
+    fn visit_array(reader: &mut Reader) -> Result<Self, Error> {
+        let mut dimensions = vec![];
+        let mut base_type = None;
+        loop {
+            match reader.read_event()? {
+                Event::Start(tag) if tag.name().as_ref() == b"dimension" => {
+                    let attributes = get_attributes(tag.attributes())?;
+                    dimensions.push((attributes.get_or_err("lower")?, attributes.get_or_err("upper")?));
+                }
+                Event::Start(tag) if tag.name().as_ref() == b"baseType" => {
+                    base_type = Some(Self::visit_contained(reader, b"baseType")?)
+                }
+                Event::End(tag) if tag.name().as_ref() == b"array" => break,
+                Event::Eof => return Err(Error::UnexpectedEndOfFile(vec![b"array"])),
+                _ => {}
+            }
+        }
+
+        let base_type = Box::new(base_type.ok_or(Error::MissingElement("baseType"))?);
+        Ok(TypeReference::Array { dimensions, base_type })
+    }
+
+    fn visit_subrange(reader: &mut Reader, end: &'static [u8]) -> Result<Self, Error> {
+        let mut range = None;
+        let mut base_type = None;
+        loop {
+            match reader.read_event()? {
+                Event::Start(tag) if tag.name().as_ref() == b"range" => {
+                    let attributes = get_attributes(tag.attributes())?;
+                    range = Some((attributes.get_or_err("lower")?, attributes.get_or_err("upper")?));
+                }
+                Event::Start(tag) if tag.name().as_ref() == b"baseType" => {
+                    base_type = Some(Self::visit_contained(reader, b"baseType")?)
+                }
+                Event::End(tag) if tag.name().as_ref() == end => break,
+                Event::Eof => return Err(Error::UnexpectedEndOfFile(vec![end])),
+                _ => {}
+            }
+        }
+
+        let (lower, upper) = range.ok_or(Error::MissingElement("range"))?;
+        let base_type = Box::new(base_type.ok_or(Error::MissingElement("baseType"))?);
+        Ok(TypeReference::Subrange { lower, upper, base_type })
+    }
+
+    fn visit_enum(reader: &mut Reader) -> Result<Self, Error> {
+        let mut values = vec![];
+        let mut base_type = None;
+        loop {
+            match reader.read_event()? {
+                Event::Start(tag) if tag.name().as_ref() == b"value" => {
+                    let attributes = get_attributes(tag.attributes())?;
+                    values.push((attributes.get_or_err("name")?, attributes.get("value").cloned()));
+                }
+                Event::Start(tag) if tag.name().as_ref() == b"baseType" => {
+                    base_type = Some(Box::new(Self::visit_contained(reader, b"baseType")?))
+                }
+                Event::End(tag) if tag.name().as_ref() == b"enum" => break,
+                Event::Eof => return Err(Error::UnexpectedEndOfFile(vec![b"enum"])),
+                _ => {}
+            }
+        }
+
+        Ok(TypeReference::Enum { values, base_type })
+    }
+
+    fn visit_struct(reader: &mut Reader) -> Result<Self, Error> {
+        let mut members = vec![];
+        loop {
+            match reader.read_event()? {
+                Event::Start(tag) if tag.name().as_ref() == b"variable" => {
+                    members.push(VariableDeclaration::visit(reader, Some(tag))?)
+                }
+                Event::End(tag) if tag.name().as_ref() == b"struct" => break,
+                Event::Eof => return Err(Error::UnexpectedEndOfFile(vec![b"struct"])),
+                _ => {}
+            }
+        }
+
+        Ok(TypeReference::Struct(members))
+    }
+}
+
+impl Parseable for TypeReference {
+    fn visit(reader: &mut Reader, tag: Option<BytesStart>) -> Result<Self, Error> {
+        let Some(tag) = tag else { unreachable!() };
+        let attributes = get_attributes(tag.attributes())?;
+
+        let reference = match tag.name().as_ref() {
+            b"array" => return Self::visit_array(reader),
+            b"enum" => return Self::visit_enum(reader),
+            b"struct" => return Self::visit_struct(reader),
+            b"subrangeSigned" => return Self::visit_subrange(reader, b"subrangeSigned"),
+            b"subrangeUnsigned" => return Self::visit_subrange(reader, b"subrangeUnsigned"),
+            b"pointer" => {
+                let mut base_type = None;
+                loop {
+                    match reader.read_event()? {
+                        Event::Start(tag) if tag.name().as_ref() == b"baseType" => {
+                            base_type = Some(Self::visit_contained(reader, b"baseType")?)
+                        }
+                        Event::End(tag) if tag.name().as_ref() == b"pointer" => break,
+                        Event::Eof => return Err(Error::UnexpectedEndOfFile(vec![b"pointer"])),
+                        _ => {}
+                    }
+                }
+                return Ok(TypeReference::Pointer(Box::new(base_type.ok_or(Error::MissingElement("baseType"))?)));
+            }
+            b"derived" => TypeReference::Derived(attributes.get_or_err("name")?),
+            b"string" => TypeReference::String { wide: false, length: attributes.get("length").cloned() },
+            b"wstring" => TypeReference::String { wide: true, length: attributes.get("length").cloned() },
+            name => TypeReference::Elementary(name.try_to_string()?),
+        };
+
+        // skip the remaining content of the element, e.g. the `addData` of a derived type
+        reader.read_to_end(tag.name())?;
+        Ok(reference)
+    }
+}
+
+impl Display for TypeReference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TypeReference::Elementary(name) | TypeReference::Derived(name) => write!(f, "{name}"),
+            TypeReference::String { wide, length } => {
+                write!(f, "{}", if *wide { "WSTRING" } else { "STRING" })?;
+                length.iter().try_for_each(|length| write!(f, "[{length}]"))
+            }
+            TypeReference::Array { dimensions, base_type } => {
+                let dimensions =
+                    dimensions.iter().map(|(lower, upper)| format!("{lower}..{upper}")).collect::<Vec<_>>();
+                write!(f, "ARRAY[{}] OF {base_type}", dimensions.join(", "))
+            }
+            TypeReference::Pointer(base_type) => write!(f, "REF_TO {base_type}"),
+            TypeReference::Subrange { lower, upper, base_type } => write!(f, "{base_type}({lower}..{upper})"),
+            TypeReference::Enum { values, base_type } => {
+                let values = values
+                    .iter()
+                    .map(|(name, value)| match value {
+                        Some(value) => format!("{name} := {value}"),
+                        None => name.to_string(),
+                    })
+                    .collect::<Vec<_>>();
+                base_type.iter().try_for_each(|base_type| write!(f, "{base_type} "))?;
+                write!(f, "({})", values.join(", "))
+            }
+            TypeReference::Struct(members) => {
+                writeln!(f, "STRUCT")?;
+                members.iter().try_for_each(|member| writeln!(f, "{member}"))?;
+                write!(f, "END_STRUCT")
+            }
+        }
+    }
+}
+
+/// A variable declared in a variable list or a struct, e.g. `<variable name="a"><type><INT/></type></variable>`
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct VariableDeclaration {
+    pub name: String,
+    pub address: Option<String>,
+    pub type_reference: TypeReference,
+    pub initial_value: Option<String>,
+}
+
+impl Parseable for VariableDeclaration {
+    fn visit(reader: &mut Reader, tag: Option<BytesStart>) -> Result<Self, Error> {
+        let Some(tag) = tag else { unreachable!() };
+        let attributes = get_attributes(tag.attributes())?;
+
+        let mut type_reference = None;
+        let mut initial_value = None;
+        loop {
+            match reader.read_event()? {
+                Event::Start(tag) if tag.name().as_ref() == b"type" => {
+                    type_reference = Some(TypeReference::visit_contained(reader, b"type")?)
+                }
+                Event::Start(tag) if tag.name().as_ref() == b"initialValue" => {
+                    initial_value = Some(visit_value(reader, b"initialValue")?)
+                }
+                Event::Start(tag) => {
+                    // documentation and vendor specific data
+                    reader.read_to_end(tag.name())?;
+                }
+                Event::End(tag) if tag.name().as_ref() == b"variable" => break,
+                Event::Eof => return Err(Error::UnexpectedEndOfFile(vec![b"variable"])),
+                _ => {}
+            }
+        }
+
+        Ok(VariableDeclaration {
+            name: attributes.get_or_err("name")?,
+            address: attributes.get("address").cloned(),
+            type_reference: type_reference.ok_or(Error::MissingElement("type"))?,
+            initial_value,
+        })
+    }
+}
+
+impl Display for VariableDeclaration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)?;
+        self.address.iter().try_for_each(|address| write!(f, " AT {address}"))?;
+        write!(f, " : {}", self.type_reference)?;
+        self.initial_value.iter().try_for_each(|value| write!(f, " := {value}"))?;
+        write!(f, ";")
+    }
+}
+
+/// Visits the value contained in the given element and returns it as an ST literal, e.g. `[1, 2(0)]` for
+/// an `<arrayValue>` or `(x := 1, y := 2)` for a `<structValue>`
+fn visit_value(reader: &mut Reader, end: &'static [u8]) -> Result<String, Error> {
+    let mut value = String::new();
+    loop {
+        match reader.read_event()? {
+            Event::Start(tag) if tag.name().as_ref() == b"simpleValue" => {
+                value = get_attributes(tag.attributes())?.get_or_err("value")?;
+                reader.read_to_end(tag.name())?;
+            }
+            Event::Start(tag) if tag.name().as_ref() == b"arrayValue" => {
+                value = format!("[{}]", visit_elements(reader, b"arrayValue")?.join(", "))
+            }
+            Event::Start(tag) if tag.name().as_ref() == b"structValue" => {
+                value = format!("({})", visit_elements(reader, b"structValue")?.join(", "))
+            }
+            Event::End(tag) if tag.name().as_ref() == end => break,
+            Event::Eof => return Err(Error::UnexpectedEndOfFile(vec![end])),
+            _ => {}
+        }
+    }
+
+    Ok(value)
+}
+
+/// Visits the `<value>` elements of an array or struct value
+fn visit_elements(reader: &mut Reader, end: &'static [u8]) -> Result<Vec<String>, Error> {
+    let mut elements = vec![];
+    loop {
+        match reader.read_event()? {
+            Event::Start(tag) if tag.name().as_ref() == b"value" => {
+                let attributes = get_attributes(tag.attributes())?;
+                let value = visit_value(reader, b"value")?;
+                elements.push(match (attributes.get("member"), attributes.get("repetitionValue")) {
+                    (Some(member), _) => format!("{member} := {value}"),
+                    (None, Some(repetitions)) => format!("{repetitions}({value})"),
+                    (None, None) => value,
+                });
+            }
+            Event::End(tag) if tag.name().as_ref() == end => break,
+            Event::Eof => return Err(Error::UnexpectedEndOfFile(vec![end])),
+            _ => {}
+        }
+    }
+
+    Ok(elements)
+}
+
+/// The kind of a variable list, e.g. `<inputVars>`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum VariableListKind {
+    Input,
+    Output,
+    InOut,
+    Local,
+    Temp,
+    External,
+    Global,
+}
+
+const VARIABLE_LIST_TAGS: [(&[u8], VariableListKind); 7] = [
+    (b"inputVars", VariableListKind::Input),
+    (b"outputVars", VariableListKind::Output),
+    (b"inOutVars", VariableListKind::InOut),
+    (b"localVars", VariableListKind::Local),
+    (b"tempVars", VariableListKind::Temp),
+    (b"externalVars", VariableListKind::External),
+    (b"globalVars", VariableListKind::Global),
+];
+
+impl VariableListKind {
+    pub fn from_tag(name: &[u8]) -> Option<Self> {
+        VARIABLE_LIST_TAGS.iter().find(|(tag, _)| *tag == name).map(|(_, kind)| *kind)
+    }
+
+    fn tag(self) -> &'static [u8] {
+        VARIABLE_LIST_TAGS.iter().find(|(_, kind)| *kind == self).map(|(tag, _)| *tag).unwrap_or_default()
+    }
+}
+
+impl Display for VariableListKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VariableListKind::Input => write!(f, "VAR_INPUT"),
+            VariableListKind::Output => write!(f, "VAR_OUTPUT"),
+            VariableListKind::InOut => write!(f, "VAR_IN_OUT"),
+            VariableListKind::Local => write!(f, "VAR"),
+            VariableListKind::Temp => write!(f, "VAR_TEMP"),
+            VariableListKind::External => write!(f, "VAR_EXTERNAL"),
+            VariableListKind::Global => write!(f, "VAR_GLOBAL"),
+        }
+    }
+}
+
+/// A list of variables, e.g. `<localVars constant="true">...</localVars>`
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct VariableList {
+    pub kind: VariableListKind,
+    pub constant: bool,
+    pub retain: bool,
+    pub persistent: bool,
+    pub variables: Vec<VariableDeclaration>,
+}
+
+impl Parseable for VariableList {
+    fn visit(reader: &mut Reader, tag: Option<BytesStart>) -> Result<Self, Error> {
+        let Some(tag) = tag else { unreachable!() };
+        let Some(kind) = VariableListKind::from_tag(tag.name().as_ref()) else {
+            return Err(Error::UnexpectedElement(tag.name().try_to_string()?));
+        };
+        let attributes = get_attributes(tag.attributes())?;
+        let is_set = |key: &str| attributes.get(key).is_some_and(|it| it == "true" || it == "1");
+
+        let mut list = VariableList {
+            kind,
+            constant: is_set("constant"),
+            retain: is_set("retain"),
+            persistent: is_set("persistent"),
+            variables: vec![],
+        };
+        loop {
+            match reader.read_event()? {
+                Event::Start(tag) if tag.name().as_ref() == b"variable" => {
+                    list.variables.push(VariableDeclaration::visit(reader, Some(tag))?)
+                }
+                Event::Start(tag) => {
+                    reader.read_to_end(tag.name())?;
+                }
+                Event::End(tag) if tag.name().as_ref() == kind.tag() => break,
+                Event::Eof => return Err(Error::UnexpectedEndOfFile(vec![kind.tag()])),
+                _ => {}
+            }
+        }
+
+        Ok(list)
+    }
+}
+
+impl Display for VariableList {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.kind)?;
+        if self.constant {
+            write!(f, " CONSTANT")?;
+        }
+        if self.retain {
+            write!(f, " RETAIN")?;
+        }
+        if self.persistent {
+            write!(f, " PERSISTENT")?;
+        }
+        writeln!(f)?;
+        self.variables.iter().try_for_each(|variable| writeln!(f, "    {variable}"))?;
+        write!(f, "END_VAR")
+    }
+}
+
+/// A user defined data type, e.g. `<dataType name="Point"><baseType><struct>...</struct></baseType></dataType>`
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct DataType {
+    pub name: String,
+    pub base_type: TypeReference,
+    pub initial_value: Option<String>,
+}
+
+impl Parseable for DataType {
+    fn visit(reader: &mut Reader, tag: Option<BytesStart>) -> Result<Self, Error> {
+        let Some(tag) = tag else { unreachable!() };
+        let attributes = get_attributes(tag.attributes())?;
+
+        let mut base_type = None;
+        let mut initial_value = None;
+        loop {
+            match reader.read_event()? {
+                Event::Start(tag) if tag.name().as_ref() == b"baseType" => {
+                    base_type = Some(TypeReference::visit_contained(reader, b"baseType")?)
+                }
+                Event::Start(tag) if tag.name().as_ref() == b"initialValue" => {
+                    initial_value = Some(visit_value(reader, b"initialValue")?)
+                }
+                Event::Start(tag) => {
+                    reader.read_to_end(tag.name())?;
+                }
+                Event::End(tag) if tag.name().as_ref() == b"dataType" => break,
+                Event::Eof => return Err(Error::UnexpectedEndOfFile(vec![b"dataType"])),
+                _ => {}
+            }
+        }
+
+        Ok(DataType {
+            name: attributes.get_or_err("name")?,
+            base_type: base_type.ok_or(Error::MissingElement("baseType"))?,
+            initial_value,
+        })
+    }
+}
+
+impl Display for DataType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TYPE {} : {}", self.name, self.base_type)?;
+        self.initial_value.iter().try_for_each(|value| write!(f, " := {value}"))?;
+        write!(f, ";\nEND_TYPE")
+    }
+}
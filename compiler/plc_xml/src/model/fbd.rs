@@ -11,6 +11,7 @@ use super::{
     block::Block,
     connector::{Connector, ConnectorKind},
     control::Control,
+    ladder::{LadderElement, PowerRail},
     variables::FunctionBlockVariable,
 };
 
@@ -31,6 +32,22 @@ impl FunctionBlockDiagram<'_> {
         self.nodes.desugar_connection_points(source_location_factory)?;
         Ok(())
     }
+
+    /// Assigns a memory variable, named by the given prefix and its local id, to each edge-detecting contact.
+    /// Returns the names of the memory variables, which need to be declared by the enclosing POU
+    pub(crate) fn assign_edge_memories(&mut self, prefix: &str) -> Vec<String> {
+        self.nodes
+            .values_mut()
+            .filter_map(|node| match node {
+                Node::LadderElement(element) if element.edge.is_some() && !element.is_coil() => {
+                    let name = format!("{prefix}{}", element.local_id);
+                    element.edge_memory = Some(name.clone());
+                    Some(name)
+                }
+                _ => None,
+            })
+            .collect()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Hash)]
@@ -39,6 +56,8 @@ pub(crate) enum Node<'xml> {
     FunctionBlockVariable(FunctionBlockVariable<'xml>),
     Control(Control<'xml>),
     Connector(Connector<'xml>),
+    PowerRail(PowerRail<'xml>),
+    LadderElement(LadderElement<'xml>),
 }
 
 impl PartialOrd for Node<'_> {
@@ -61,6 +80,7 @@ impl Node<'_> {
             Node::Block(val) => val.execution_order_id,
             Node::FunctionBlockVariable(val) => val.execution_order_id,
             Node::Control(val) => val.execution_order_id,
+            Node::LadderElement(val) => val.execution_order_id,
             _ => None,
         }
     }
@@ -71,6 +91,8 @@ impl Node<'_> {
             Node::FunctionBlockVariable(val) => val.local_id,
             Node::Control(val) => val.local_id,
             Node::Connector(val) => val.local_id,
+            Node::PowerRail(val) => val.local_id,
+            Node::LadderElement(val) => val.local_id,
         }
     }
 
@@ -80,6 +102,8 @@ impl Node<'_> {
             Node::FunctionBlockVariable(val) => vec![val.ref_local_id],
             Node::Control(val) => vec![val.ref_local_id],
             Node::Connector(val) => vec![val.ref_local_id],
+            Node::PowerRail(val) => val.connections.iter().map(|it| it.ref_local_id).collect(),
+            Node::LadderElement(val) => val.connections.iter().map(|it| it.ref_local_id).collect(),
         }
     }
 
@@ -90,6 +114,7 @@ impl Node<'_> {
                 &val.type_name
             }
             Node::Connector(val) => &val.name,
+            Node::LadderElement(val) => &val.variable,
             _ => "",
         }
     }
@@ -104,12 +129,24 @@ impl Node<'_> {
             Node::FunctionBlockVariable(var) => var.ref_local_id = ref_local_id,
             Node::Control(control) => control.ref_local_id = ref_local_id,
             Node::Connector(connector) => connector.ref_local_id = ref_local_id,
+            Node::PowerRail(PowerRail { connections, .. })
+            | Node::LadderElement(LadderElement { connections, .. }) => {
+                if let Some(connection) = connections.get_mut(param_idx) {
+                    connection.ref_local_id = ref_local_id;
+                }
+            }
         }
     }
 
     fn is_connector(&self) -> bool {
         matches!(self, Node::Connector(_))
     }
+
+    /// Returns true if the node is transformed into a statement of its own. Besides the nodes with an
+    /// execution order, this is the case for coils, which are usually exported without one
+    pub(crate) fn is_statement(&self) -> bool {
+        self.get_exec_id().is_some() || matches!(self, Node::LadderElement(element) if element.is_coil())
+    }
 }
 
 impl Parseable for FunctionBlockDiagram<'_> {
@@ -135,10 +172,18 @@ impl Parseable for FunctionBlockDiagram<'_> {
                         let node = Connector::visit(reader, Some(tag))?;
                         nodes.insert(node.local_id, Node::Connector(node));
                     }
+                    b"leftPowerRail" | b"rightPowerRail" => {
+                        let node = PowerRail::visit(reader, Some(tag))?;
+                        nodes.insert(node.local_id, Node::PowerRail(node));
+                    }
+                    b"contact" | b"coil" => {
+                        let node = LadderElement::visit(reader, Some(tag))?;
+                        nodes.insert(node.local_id, Node::LadderElement(node));
+                    }
                     _ => {}
                 },
 
-                Event::End(tag) if tag.name().as_ref() == b"FBD" => {
+                Event::End(tag) if matches!(tag.name().as_ref(), b"FBD" | b"LD") => {
                     break;
                 }
                 _ => {}
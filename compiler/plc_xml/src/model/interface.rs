@@ -6,22 +6,23 @@ use crate::error::Error;
 use crate::reader::Reader;
 use crate::xml_parser::Parseable;
 
+use super::declaration::{TypeReference, VariableList, VariableListKind};
 use super::pou::PouType;
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub(crate) struct Interface {
-    //TODO: return type, variables, etc..
-    // we only care about the addData field for now, as it contains our text-declaration
-
-    // the addData field is be application-specific and can be implemented/extended as needed
+    // the addData field is be application-specific and can be implemented/extended as needed. it contains
+    // our text-declaration, which takes precedence over the structured declaration below
     add_data: Option<Data>,
+    return_type: Option<TypeReference>,
+    variable_lists: Vec<VariableList>,
 }
 
 // XXX: this implementation is very specific to our own format. we might want to make it generic/provide an interface via traits
 // so it can be easily modified/extended for other purposes
 impl Interface {
     pub fn new(content: &str) -> Self {
-        Interface { add_data: Some(Data::new_implementation(content)) }
+        Interface { add_data: Some(Data::new_implementation(content)), ..Default::default() }
     }
 
     pub fn get_data_content(&self) -> Option<&str> {
@@ -30,6 +31,35 @@ impl Interface {
         Some(&data.content)
     }
 
+    /// Renders the structured declaration as the text declaration, unless the interface already has one
+    pub fn with_text_declaration(self, name: &str, pou_type: &PouType) -> Self {
+        if self.add_data.is_some() {
+            return self;
+        }
+
+        let mut content = format!("{pou_type} {name}");
+        if let Some(return_type) = &self.return_type {
+            content = format!("{content} : {return_type}");
+        }
+        for list in &self.variable_lists {
+            content = format!("{content}\n{list}");
+        }
+
+        Interface { add_data: Some(Data::new_implementation(&content)), ..self }
+    }
+
+    /// Declares the memory variables of the edge-detecting contacts in the POU's ladder diagrams. Each
+    /// contact keeps the detected edge and the previous state of its variable
+    pub fn with_edge_memories(self, names: &[String]) -> Self {
+        let Some(old_data) = self.add_data.as_ref().filter(|_| !names.is_empty()) else {
+            return self;
+        };
+
+        let variables = names.iter().map(|it| format!("{it}, {it}_prev")).collect::<Vec<_>>().join(", ");
+        let content = format!("{}\nVAR\n    {variables} : BOOL;\nEND_VAR", old_data.content);
+        Interface { add_data: Some(Data::new_implementation(&content)), ..self }
+    }
+
     // We have to append a END_... to the declaration, as it is missing in our text declaration
     pub fn append_end_keyword(self, pou_type: &PouType) -> Self {
         let Some(old_data) = self.add_data else {
@@ -39,20 +69,28 @@ impl Interface {
 
         Interface {
             add_data: Some(Data::new_implementation(&format!("{}\nEND_{}", old_data.content, pou_type))),
+            ..self
         }
     }
 }
 
 impl Parseable for Interface {
     fn visit(reader: &mut Reader, _tag: Option<BytesStart>) -> Result<Self, Error> {
-        let mut interface = Interface { add_data: None };
+        let mut interface = Interface::default();
         loop {
             match reader.read_event().map_err(Error::ReadEvent)? {
                 Event::End(tag) if tag.name().as_ref() == b"interface" => break,
+                Event::Start(tag) if tag.name().as_ref() == b"returnType" => {
+                    interface.return_type = Some(TypeReference::visit_contained(reader, b"returnType")?)
+                }
+                Event::Start(tag) if VariableListKind::from_tag(tag.name().as_ref()).is_some() => {
+                    interface.variable_lists.push(VariableList::visit(reader, Some(tag))?)
+                }
                 Event::Text(text) => {
                     interface.add_data =
                         Some(Data::new_implementation(text.unescape().map_err(Error::ReadEvent)?.borrow()))
                 }
+                Event::Eof => return Err(Error::UnexpectedEndOfFile(vec![b"interface"])),
                 _ => {}
             }
         }
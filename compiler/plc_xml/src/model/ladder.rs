@@ -0,0 +1,214 @@
+//! The elements of a ladder diagram (LD), i.e. power rails, contacts and coils. They are part of the same
+//! node-index as the elements of a function block diagram, so blocks, connectors and jumps can be placed on
+//! rungs as well.
+
+use std::borrow::Cow;
+
+use quick_xml::events::{BytesStart, Event};
+use rustc_hash::FxHashMap;
+
+use crate::{
+    error::Error,
+    extensions::{GetOrErr, TryToString},
+    reader::Reader,
+    xml_parser::{get_attributes, Parseable},
+};
+
+use super::{
+    fbd::NodeId,
+    variables::{Edge, Storage},
+};
+
+/// A connection of a ladder element to the element on its left, e.g. `<connection refLocalId="1"/>`.
+/// Multiple connections of a single element are parallel branches of the rung.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(crate) struct Connection<'xml> {
+    pub ref_local_id: Option<NodeId>,
+    /// the output of a block this connection originates from, e.g. `ENO`
+    pub formal_parameter: Option<Cow<'xml, str>>,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub(crate) enum PowerRailKind {
+    Left,
+    Right,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(crate) struct PowerRail<'xml> {
+    pub kind: PowerRailKind,
+    pub local_id: NodeId,
+    pub connections: Vec<Connection<'xml>>,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub(crate) enum LadderElementKind {
+    Contact,
+    Coil,
+}
+
+/// A contact or a coil, e.g. `<contact localId="2" negated="false"><variable>a</variable></contact>`
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(crate) struct LadderElement<'xml> {
+    pub kind: LadderElementKind,
+    pub local_id: NodeId,
+    pub variable: Cow<'xml, str>,
+    pub negated: bool,
+    pub edge: Option<Edge>,
+    pub storage: Option<Storage>,
+    pub execution_order_id: Option<NodeId>,
+    pub connections: Vec<Connection<'xml>>,
+    /// the variable remembering the previous state of an edge-detecting contact
+    pub edge_memory: Option<String>,
+}
+
+impl<'xml> LadderElement<'xml> {
+    pub fn new(
+        hm: FxHashMap<String, String>,
+        kind: LadderElementKind,
+        connections: Vec<Connection<'xml>>,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            kind,
+            local_id: hm.get_or_err("localId").map(|it| it.parse())??,
+            variable: Cow::from(hm.get_or_err("variable")?),
+            negated: hm.get("negated").is_some_and(|it| it == "true"),
+            edge: hm.get("edge").filter(|it| *it != "none").map(|it| it.parse()).transpose()?,
+            storage: hm.get("storage").filter(|it| *it != "none").map(|it| it.parse()).transpose()?,
+            execution_order_id: hm.get("executionOrderId").map(|it| it.parse()).transpose()?,
+            connections,
+            edge_memory: None,
+        })
+    }
+
+    pub fn is_coil(&self) -> bool {
+        self.kind == LadderElementKind::Coil
+    }
+}
+
+/// Visits the `<connection>`s of the given element. The attributes of the element and the content of its
+/// `<variable>` are collected into the returned map
+fn visit_connections<'xml>(
+    reader: &mut Reader,
+    tag: BytesStart,
+) -> Result<(FxHashMap<String, String>, Vec<Connection<'xml>>), Error> {
+    let mut attributes = get_attributes(tag.attributes())?;
+    let mut connections = vec![];
+    loop {
+        match reader.read_event().map_err(Error::ReadEvent)? {
+            Event::Start(it) | Event::Empty(it) if it.name().as_ref() == b"connection" => {
+                let mut connection = get_attributes(it.attributes())?;
+                connections.push(Connection {
+                    ref_local_id: connection.get("refLocalId").map(|it| it.parse()).transpose()?,
+                    formal_parameter: connection.remove("formalParameter").map(Cow::from),
+                });
+            }
+            Event::Start(it) if it.name().as_ref() == b"variable" => {
+                let mut variable = String::new();
+                loop {
+                    match reader.read_event().map_err(Error::ReadEvent)? {
+                        Event::Text(text) => variable.push_str(&text.unescape()?),
+                        Event::End(end) if end.name() == it.name() => break,
+                        Event::Eof => return Err(Error::UnexpectedEndOfFile(vec![b"variable"])),
+                        _ => {}
+                    }
+                }
+                attributes.insert("variable".into(), variable);
+            }
+            Event::End(it) if it.name() == tag.name() => break,
+            Event::Eof => {
+                return Err(Error::UnexpectedEndOfFile(vec![
+                    b"contact",
+                    b"coil",
+                    b"leftPowerRail",
+                    b"rightPowerRail",
+                ]))
+            }
+            _ => {}
+        }
+    }
+
+    Ok((attributes, connections))
+}
+
+impl Parseable for PowerRail<'_> {
+    fn visit(reader: &mut Reader, tag: Option<BytesStart>) -> Result<Self, Error> {
+        let Some(tag) = tag else { unreachable!() };
+        let kind = match tag.name().as_ref() {
+            b"leftPowerRail" => PowerRailKind::Left,
+            b"rightPowerRail" => PowerRailKind::Right,
+            _ => return Err(Error::UnexpectedElement(tag.name().try_to_string()?)),
+        };
+
+        let (attributes, connections) = visit_connections(reader, tag)?;
+        Ok(PowerRail { kind, local_id: attributes.get_or_err("localId").map(|it| it.parse())??, connections })
+    }
+}
+
+impl Parseable for LadderElement<'_> {
+    fn visit(reader: &mut Reader, tag: Option<BytesStart>) -> Result<Self, Error> {
+        let Some(tag) = tag else { unreachable!() };
+        let kind = match tag.name().as_ref() {
+            b"contact" => LadderElementKind::Contact,
+            b"coil" => LadderElementKind::Coil,
+            _ => return Err(Error::UnexpectedElement(tag.name().try_to_string()?)),
+        };
+
+        let (attributes, connections) = visit_connections(reader, tag)?;
+        LadderElement::new(attributes, kind, connections)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_debug_snapshot;
+
+    use crate::{
+        model::ladder::{LadderElement, PowerRail},
+        reader::{get_start_tag, Reader},
+        xml_parser::Parseable,
+    };
+
+    #[test]
+    fn rising_edge_contact_with_parallel_connections() {
+        let content = r#"
+            <contact localId="3" negated="false" edge="rising" storage="none">
+                <position x="0" y="0"/>
+                <connectionPointIn>
+                    <connection refLocalId="1"/>
+                    <connection refLocalId="2" formalParameter="ENO"/>
+                </connectionPointIn>
+                <connectionPointOut/>
+                <variable>start</variable>
+            </contact>
+        "#;
+
+        let mut reader = Reader::new(content);
+        let tag = get_start_tag(reader.read_event().unwrap());
+        assert_debug_snapshot!(LadderElement::visit(&mut reader, tag).unwrap());
+    }
+
+    #[test]
+    fn set_coil_and_power_rail() {
+        let content = r#"
+            <coil localId="4" negated="false" storage="set">
+                <connectionPointIn>
+                    <connection refLocalId="3"/>
+                </connectionPointIn>
+                <variable>motor</variable>
+            </coil>
+            <rightPowerRail localId="5">
+                <connectionPointIn>
+                    <connection refLocalId="4"/>
+                </connectionPointIn>
+            </rightPowerRail>
+        "#;
+
+        let mut reader = Reader::new(content);
+        let tag = get_start_tag(reader.read_event().unwrap());
+        let coil = LadderElement::visit(&mut reader, tag).unwrap();
+        let tag = get_start_tag(reader.read_event().unwrap());
+        let rail = PowerRail::visit(&mut reader, tag).unwrap();
+        assert_debug_snapshot!((coil, rail));
+    }
+}
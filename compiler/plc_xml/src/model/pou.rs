@@ -11,7 +11,7 @@ use crate::{
     xml_parser::{get_attributes, Parseable},
 };
 
-use super::{action::Action, body::Body, interface::Interface};
+use super::{action::Action, body::Body, interface::Interface, sfc::TransitionDeclaration};
 
 #[derive(Debug, Default)]
 pub(crate) struct Pou<'xml> {
@@ -19,6 +19,8 @@ pub(crate) struct Pou<'xml> {
     pub pou_type: PouType,
     pub body: Body<'xml>,
     pub actions: Vec<Action<'xml>>,
+    /// the named transition conditions of a sequential function chart body
+    pub transitions: Vec<TransitionDeclaration<'xml>>,
     pub interface: Option<Interface>,
 }
 
@@ -29,6 +31,7 @@ impl Pou<'_> {
             pou_type: attributes.get_or_err("pouType").map(|it| it.parse())??,
             body: self.body,
             actions: self.actions,
+            transitions: self.transitions,
             interface: self.interface,
         })
     }
@@ -46,12 +49,12 @@ impl Parseable for Pou<'_> {
         let Some(tag) = tag else { unreachable!() };
         let attributes = get_attributes(tag.attributes())?;
         let mut pou = Pou::default().with_attributes(attributes)?;
+        let mut edge_memories = vec![];
         loop {
             match reader.read_event()? {
                 // XXX: this is very specific to our own xml schema, but does not adhere to the plc open standard
                 Event::Start(tag) if tag.name().as_ref() == b"interface" => {
-                    pou.interface =
-                        Some(Interface::visit(reader, Some(tag))?.append_end_keyword(&pou.pou_type))
+                    pou.interface = Some(Interface::visit(reader, Some(tag))?)
                 }
                 Event::Start(tag) if tag.name().as_ref() == b"body" => {
                     pou.body = Body::visit(reader, Some(tag))?
@@ -61,15 +64,34 @@ impl Parseable for Pou<'_> {
                     for mut action in actions.into_iter() {
                         // Copy the action type names
                         action.type_name.clone_from(&pou.name);
+                        edge_memories.extend(
+                            action
+                                .body
+                                .function_block_diagram
+                                .assign_edge_memories(&format!("__{}_ld_edge", action.name)),
+                        );
                         pou.actions.push(action);
                     }
                 }
+                Event::Start(tag) if tag.name().as_ref() == b"transitions" => {
+                    pou.transitions = Parseable::visit(reader, Some(tag))?
+                }
                 Event::End(tag) if tag.name().as_ref() == b"pou" => break,
                 Event::Eof => return Err(Error::UnexpectedEndOfFile(vec![b"pou"])),
 
                 _ => {}
             }
         }
+
+        edge_memories.extend(pou.body.function_block_diagram.assign_edge_memories("__ld_edge"));
+
+        let interface = pou.interface.take().unwrap_or_default();
+        pou.interface = Some(
+            interface
+                .with_text_declaration(&pou.name, &pou.pou_type)
+                .with_edge_memories(&edge_memories)
+                .append_end_keyword(&pou.pou_type),
+        );
         Ok(pou)
     }
 }
@@ -1,19 +1,26 @@
 use plc_diagnostics::diagnostics::Diagnostic;
+use quick_xml::events::Event;
 
 use crate::{reader::Reader, xml_parser::Parseable};
 
-use super::pou::Pou;
+use super::{
+    configuration::Configuration,
+    declaration::{DataType, VariableList},
+    pou::Pou,
+};
 
 /// The Project root as specified in the official XSD
 #[derive(Debug, Default)]
 pub(crate) struct Project<'xml> {
     pub pous: Vec<Pou<'xml>>,
+    pub data_types: Vec<DataType>,
+    /// global variables declared outside of a configuration, e.g. in vendor specific data
+    pub global_vars: Vec<VariableList>,
+    pub configurations: Vec<Configuration>,
     /*
     attributes,
-    dataTypes,
     fileHeader,
     contentHeader,
-    instances,
     addData,
     documentation
     */
@@ -21,14 +28,49 @@ pub(crate) struct Project<'xml> {
 
 impl Parseable for Project<'_> {
     fn visit(
-        _reader: &mut Reader,
+        reader: &mut Reader,
         _tag: Option<quick_xml::events::BytesStart>,
     ) -> Result<Self, crate::error::Error> {
-        unimplemented!()
+        let mut project = Project::default();
+        // a file either contains a whole project or a single POU, so the elements are collected
+        // independent of their parent element
+        loop {
+            match reader.read_event()? {
+                Event::Start(tag) if tag.name().as_ref() == b"pou" => {
+                    project.pous.push(Pou::visit(reader, Some(tag))?)
+                }
+                Event::Start(tag) if tag.name().as_ref() == b"dataType" => {
+                    project.data_types.push(DataType::visit(reader, Some(tag))?)
+                }
+                Event::Start(tag) if tag.name().as_ref() == b"globalVars" => {
+                    project.global_vars.push(VariableList::visit(reader, Some(tag))?)
+                }
+                Event::Start(tag) if tag.name().as_ref() == b"configuration" => {
+                    project.configurations.push(Configuration::visit(reader, Some(tag))?)
+                }
+                Event::End(tag) if tag.name().as_ref() == b"project" => break,
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+        Ok(project)
     }
 }
 
 impl Project<'_> {
+    /// Returns the text declarations of all data types, global variables, POUs and configurations
+    pub(crate) fn text_declarations(&self) -> String {
+        let data_types = self.data_types.iter().map(ToString::to_string);
+        let global_vars = self.global_vars.iter().map(ToString::to_string);
+        let pous = self
+            .pous
+            .iter()
+            .filter_map(|pou| pou.interface.as_ref().and_then(|it| it.get_data_content()).map(str::to_string));
+        let configurations = self.configurations.iter().map(ToString::to_string);
+
+        data_types.chain(global_vars).chain(pous).chain(configurations).collect::<Vec<_>>().join("\n")
+    }
+
     pub(crate) fn desugar(
         &mut self,
         source_location_factory: &plc_source::source_location::SourceLocationFactory,
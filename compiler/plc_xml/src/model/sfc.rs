@@ -0,0 +1,397 @@
+//! The elements of a sequential function chart (SFC), i.e. steps, transitions, divergences, convergences, jumps
+//! and action blocks. Like the nodes of a function block diagram, they reference their predecessors by id.
+
+use std::borrow::Cow;
+
+use plc::index::FxIndexMap;
+use quick_xml::events::{BytesStart, Event};
+use rustc_hash::FxHashMap;
+
+use crate::{
+    error::Error,
+    extensions::{GetOrErr, TryToString},
+    reader::Reader,
+    xml_parser::{get_attributes, Parseable},
+};
+
+use super::{body::Body, fbd::NodeId};
+
+#[derive(Debug, Default)]
+pub(crate) struct SequentialFunctionChart<'xml> {
+    pub nodes: FxIndexMap<NodeId, SfcNode<'xml>>,
+}
+
+#[derive(Debug)]
+pub(crate) enum SfcNode<'xml> {
+    Step(Step<'xml>),
+    Transition(Transition<'xml>),
+    Branch(Branch),
+    JumpStep(JumpStep<'xml>),
+    ActionBlock(ActionBlock<'xml>),
+}
+
+impl SfcNode<'_> {
+    pub(crate) fn get_id(&self) -> NodeId {
+        match self {
+            SfcNode::Step(it) => it.local_id,
+            SfcNode::Transition(it) => it.local_id,
+            SfcNode::Branch(it) => it.local_id,
+            SfcNode::JumpStep(it) => it.local_id,
+            SfcNode::ActionBlock(it) => it.local_id,
+        }
+    }
+
+    /// Returns the ids of the nodes preceding this node
+    pub(crate) fn get_connections(&self) -> &[NodeId] {
+        match self {
+            SfcNode::Step(it) => &it.connections,
+            SfcNode::Transition(it) => &it.connections,
+            SfcNode::Branch(it) => &it.connections,
+            SfcNode::JumpStep(it) => &it.connections,
+            SfcNode::ActionBlock(it) => &it.connections,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Step<'xml> {
+    pub local_id: NodeId,
+    pub name: Cow<'xml, str>,
+    pub initial: bool,
+    pub connections: Vec<NodeId>,
+}
+
+#[derive(Debug)]
+pub(crate) struct Transition<'xml> {
+    pub local_id: NodeId,
+    /// transitions leaving the same step are evaluated in the order of their priority, lowest first
+    pub priority: Option<usize>,
+    pub condition: Option<TransitionCondition<'xml>>,
+    pub connections: Vec<NodeId>,
+}
+
+#[derive(Debug)]
+pub(crate) enum TransitionCondition<'xml> {
+    /// A condition declared in the `<transitions>` of the POU or a boolean variable,
+    /// e.g. `<reference name="is_full"/>`
+    Reference(Cow<'xml, str>),
+    /// An ST expression, e.g. `<inline><ST><xhtml>level > 10</xhtml></ST></inline>`
+    Inline(String),
+    /// A condition computed by a graphical network connected to the transition
+    Connection,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum BranchKind {
+    SelectionDivergence,
+    SelectionConvergence,
+    SimultaneousDivergence,
+    SimultaneousConvergence,
+}
+
+/// A divergence or convergence of the sequence, e.g. `<selectionDivergence>`
+#[derive(Debug)]
+pub(crate) struct Branch {
+    pub kind: BranchKind,
+    pub local_id: NodeId,
+    pub connections: Vec<NodeId>,
+}
+
+/// A jump to the step with the given name, e.g. `<jumpStep targetName="idle">`
+#[derive(Debug)]
+pub(crate) struct JumpStep<'xml> {
+    pub local_id: NodeId,
+    pub target_name: Cow<'xml, str>,
+    pub connections: Vec<NodeId>,
+}
+
+/// The actions associated with the connected step
+#[derive(Debug)]
+pub(crate) struct ActionBlock<'xml> {
+    pub local_id: NodeId,
+    pub actions: Vec<StepAction<'xml>>,
+    pub connections: Vec<NodeId>,
+}
+
+/// An action associated with a step, e.g. `<action qualifier="L" duration="T#5s"><reference name="stir"/></action>`
+#[derive(Debug)]
+pub(crate) struct StepAction<'xml> {
+    pub local_id: NodeId,
+    pub qualifier: Cow<'xml, str>,
+    pub duration: Option<Cow<'xml, str>>,
+    pub body: StepActionBody<'xml>,
+}
+
+#[derive(Debug)]
+pub(crate) enum StepActionBody<'xml> {
+    /// An action of the POU or a boolean variable
+    Reference(Cow<'xml, str>),
+    /// The statements of an anonymous action
+    Inline(String),
+}
+
+/// A transition condition declared by name in the `<transitions>` of a POU
+#[derive(Debug)]
+pub(crate) struct TransitionDeclaration<'xml> {
+    pub name: Cow<'xml, str>,
+    pub body: Body<'xml>,
+}
+
+impl BranchKind {
+    fn from_tag(tag: &[u8]) -> Option<Self> {
+        match tag {
+            b"selectionDivergence" => Some(BranchKind::SelectionDivergence),
+            b"selectionConvergence" => Some(BranchKind::SelectionConvergence),
+            b"simultaneousDivergence" => Some(BranchKind::SimultaneousDivergence),
+            b"simultaneousConvergence" => Some(BranchKind::SimultaneousConvergence),
+            _ => None,
+        }
+    }
+}
+
+fn parse_id(attributes: &FxHashMap<String, String>) -> Result<NodeId, Error> {
+    Ok(attributes.get_or_err("localId")?.parse()?)
+}
+
+/// Visits the `<inline><ST>...</ST></inline>` body of a condition or action
+fn visit_inline(reader: &mut Reader) -> Result<String, Error> {
+    let mut content = String::new();
+    loop {
+        match reader.read_event()? {
+            Event::Start(tag) if tag.name().as_ref() == b"ST" => {
+                content = Body::visit_structured_text(reader)?
+            }
+            Event::End(tag) if tag.name().as_ref() == b"inline" => break,
+            Event::Eof => return Err(Error::UnexpectedEndOfFile(vec![b"inline"])),
+            _ => {}
+        }
+    }
+
+    Ok(content)
+}
+
+impl Parseable for SequentialFunctionChart<'_> {
+    fn visit(reader: &mut Reader, _tag: Option<BytesStart>) -> Result<Self, Error> {
+        let mut nodes = FxIndexMap::default();
+        loop {
+            match reader.read_event()? {
+                Event::Start(tag) => match tag.name().as_ref() {
+                    b"step"
+                    | b"transition"
+                    | b"jumpStep"
+                    | b"actionBlock"
+                    | b"selectionDivergence"
+                    | b"selectionConvergence"
+                    | b"simultaneousDivergence"
+                    | b"simultaneousConvergence" => {
+                        let node = SfcNode::visit(reader, Some(tag))?;
+                        nodes.insert(node.get_id(), node);
+                    }
+                    b"macroStep" => return Err(Error::UnexpectedElement("macroStep".into())),
+                    _ => {
+                        reader.read_to_end(tag.name())?;
+                    }
+                },
+                Event::End(tag) if tag.name().as_ref() == b"SFC" => break,
+                Event::Eof => return Err(Error::UnexpectedEndOfFile(vec![b"SFC"])),
+                _ => {}
+            }
+        }
+
+        Ok(SequentialFunctionChart { nodes })
+    }
+}
+
+impl Parseable for SfcNode<'_> {
+    fn visit(reader: &mut Reader, tag: Option<BytesStart>) -> Result<Self, Error> {
+        let Some(tag) = tag else { unreachable!() };
+        let attributes = get_attributes(tag.attributes())?;
+
+        let mut connections = vec![];
+        let mut condition = None;
+        let mut actions = vec![];
+        loop {
+            match reader.read_event()? {
+                Event::Start(it) if it.name().as_ref() == b"connection" => {
+                    connections.push(get_attributes(it.attributes())?.get_or_err("refLocalId")?.parse()?);
+                    reader.read_to_end(it.name())?;
+                }
+                Event::Start(it) if it.name().as_ref() == b"condition" => {
+                    condition = Some(TransitionCondition::visit(reader, Some(it))?)
+                }
+                Event::Start(it) if it.name().as_ref() == b"action" => {
+                    actions.push(StepAction::visit(reader, Some(it))?)
+                }
+                Event::End(it) if it.name() == tag.name() => break,
+                Event::Eof => return Err(Error::UnexpectedEndOfFile(vec![b"step", b"transition"])),
+                _ => {}
+            }
+        }
+
+        let local_id = parse_id(&attributes)?;
+        let node = match tag.name().as_ref() {
+            b"step" => SfcNode::Step(Step {
+                local_id,
+                name: Cow::from(attributes.get_or_err("name")?),
+                initial: attributes.get("initialStep").is_some_and(|it| it == "true"),
+                connections,
+            }),
+            b"transition" => SfcNode::Transition(Transition {
+                local_id,
+                priority: attributes.get("priority").map(|it| it.parse()).transpose()?,
+                condition,
+                connections,
+            }),
+            b"jumpStep" => SfcNode::JumpStep(JumpStep {
+                local_id,
+                target_name: Cow::from(attributes.get_or_err("targetName")?),
+                connections,
+            }),
+            b"actionBlock" => SfcNode::ActionBlock(ActionBlock { local_id, actions, connections }),
+            name => match BranchKind::from_tag(name) {
+                Some(kind) => SfcNode::Branch(Branch { kind, local_id, connections }),
+                None => return Err(Error::UnexpectedElement(name.try_to_string()?)),
+            },
+        };
+
+        Ok(node)
+    }
+}
+
+impl Parseable for TransitionCondition<'_> {
+    fn visit(reader: &mut Reader, _tag: Option<BytesStart>) -> Result<Self, Error> {
+        let mut condition = None;
+        loop {
+            match reader.read_event()? {
+                Event::Start(tag) if tag.name().as_ref() == b"reference" => {
+                    let attributes = get_attributes(tag.attributes())?;
+                    condition =
+                        Some(TransitionCondition::Reference(Cow::from(attributes.get_or_err("name")?)));
+                    reader.read_to_end(tag.name())?;
+                }
+                Event::Start(tag) if tag.name().as_ref() == b"inline" => {
+                    condition = Some(TransitionCondition::Inline(visit_inline(reader)?))
+                }
+                Event::Start(tag) if tag.name().as_ref() == b"connectionPointIn" => {
+                    condition = Some(TransitionCondition::Connection);
+                    reader.read_to_end(tag.name())?;
+                }
+                Event::End(tag) if tag.name().as_ref() == b"condition" => break,
+                Event::Eof => return Err(Error::UnexpectedEndOfFile(vec![b"condition"])),
+                _ => {}
+            }
+        }
+
+        condition.ok_or(Error::MissingElement("condition"))
+    }
+}
+
+impl Parseable for StepAction<'_> {
+    fn visit(reader: &mut Reader, tag: Option<BytesStart>) -> Result<Self, Error> {
+        let Some(tag) = tag else { unreachable!() };
+        let mut attributes = get_attributes(tag.attributes())?;
+
+        let mut body = None;
+        loop {
+            match reader.read_event()? {
+                Event::Start(tag) if tag.name().as_ref() == b"reference" => {
+                    let attributes = get_attributes(tag.attributes())?;
+                    body = Some(StepActionBody::Reference(Cow::from(attributes.get_or_err("name")?)));
+                    reader.read_to_end(tag.name())?;
+                }
+                Event::Start(tag) if tag.name().as_ref() == b"inline" => {
+                    body = Some(StepActionBody::Inline(visit_inline(reader)?))
+                }
+                Event::End(tag) if tag.name().as_ref() == b"action" => break,
+                Event::Eof => return Err(Error::UnexpectedEndOfFile(vec![b"action"])),
+                _ => {}
+            }
+        }
+
+        Ok(StepAction {
+            local_id: parse_id(&attributes)?,
+            qualifier: Cow::from(attributes.remove("qualifier").unwrap_or_else(|| "N".into())),
+            duration: attributes.remove("duration").filter(|it| !it.is_empty()).map(Cow::from),
+            body: body.ok_or(Error::MissingElement("reference"))?,
+        })
+    }
+}
+
+impl Parseable for Vec<TransitionDeclaration<'_>> {
+    fn visit(reader: &mut Reader, _tag: Option<BytesStart>) -> Result<Self, Error> {
+        let mut transitions = vec![];
+        loop {
+            match reader.read_event()? {
+                Event::Start(tag) if tag.name().as_ref() == b"transition" => {
+                    let attributes = get_attributes(tag.attributes())?;
+                    let mut body = Body::default();
+                    loop {
+                        match reader.read_event()? {
+                            Event::Start(tag) if tag.name().as_ref() == b"body" => {
+                                body = Body::visit(reader, Some(tag))?
+                            }
+                            Event::End(tag) if tag.name().as_ref() == b"transition" => break,
+                            Event::Eof => return Err(Error::UnexpectedEndOfFile(vec![b"transition"])),
+                            _ => {}
+                        }
+                    }
+                    transitions.push(TransitionDeclaration {
+                        name: Cow::from(attributes.get_or_err("name")?),
+                        body,
+                    });
+                }
+                Event::End(tag) if tag.name().as_ref() == b"transitions" => break,
+                Event::Eof => return Err(Error::UnexpectedEndOfFile(vec![b"transitions"])),
+                _ => {}
+            }
+        }
+
+        Ok(transitions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_debug_snapshot;
+
+    use crate::{
+        model::sfc::SequentialFunctionChart,
+        reader::{get_start_tag, Reader},
+        xml_parser::Parseable,
+    };
+
+    #[test]
+    fn sfc_with_selection_and_actions() {
+        let content = r#"
+            <SFC>
+                <step localId="1" name="idle" initialStep="true"/>
+                <actionBlock localId="2">
+                    <connectionPointIn><connection refLocalId="1"/></connectionPointIn>
+                    <action localId="0" qualifier="L" duration="T#5s"><reference name="stir"/></action>
+                    <action localId="1"><inline><ST><xhtml>count := count + 1;</xhtml></ST></inline></action>
+                </actionBlock>
+                <selectionDivergence localId="3">
+                    <connectionPointIn><connection refLocalId="1"/></connectionPointIn>
+                </selectionDivergence>
+                <transition localId="4" priority="1">
+                    <connectionPointIn><connection refLocalId="3"/></connectionPointIn>
+                    <condition><inline name="t"><ST><xhtml>start</xhtml></ST></inline></condition>
+                </transition>
+                <transition localId="5">
+                    <connectionPointIn><connection refLocalId="3"/></connectionPointIn>
+                    <condition><reference name="abort"/></condition>
+                </transition>
+                <step localId="6" name="fill">
+                    <connectionPointIn><connection refLocalId="4"/></connectionPointIn>
+                </step>
+                <jumpStep localId="7" targetName="idle">
+                    <connectionPointIn><connection refLocalId="5"/></connectionPointIn>
+                </jumpStep>
+            </SFC>
+        "#;
+
+        let mut reader = Reader::new(content);
+        let tag = get_start_tag(reader.read_event().unwrap());
+        assert_debug_snapshot!(SequentialFunctionChart::visit(&mut reader, tag).unwrap());
+    }
+}
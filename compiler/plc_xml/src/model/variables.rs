@@ -54,6 +54,10 @@ impl BlockVariable {
         self.kind = kind;
         self
     }
+
+    pub fn is_enable(&self) -> bool {
+        self.kind == VariableKind::Input && self.formal_parameter.eq_ignore_ascii_case("EN")
+    }
 }
 
 #[derive(Default, Debug, PartialEq, Eq, Hash, Copy, Clone)]
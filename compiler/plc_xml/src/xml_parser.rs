@@ -2,7 +2,10 @@ use ast::{
     ast::{AstId, AstNode, CompilationUnit, Implementation, LinkageType, PouType as AstPouType},
     provider::IdProvider,
 };
-use plc::{lexer, parser::expressions_parser::parse_expression};
+use plc::{
+    lexer::{self, Defines},
+    parser::expressions_parser::parse_expression,
+};
 use plc_diagnostics::{
     diagnostician::Diagnostician,
     diagnostics::{Diagnostic, Severity},
@@ -12,16 +15,13 @@ use plc_source::{
     source_location::{SourceLocation, SourceLocationFactory},
     SourceCode, SourceContainer,
 };
-use quick_xml::events::{attributes::Attributes, BytesStart, Event};
+use quick_xml::events::{attributes::Attributes, BytesStart};
 use rustc_hash::FxHashMap;
 
 use crate::{
     error::Error,
     extensions::TryToString,
-    model::{
-        pou::{Pou, PouType},
-        project::Project,
-    },
+    model::{pou::PouType, project::Project},
     reader::Reader,
 };
 
@@ -29,11 +29,15 @@ mod action;
 mod block;
 mod control;
 mod fbd;
+mod ladder;
 mod pou;
+mod sfc;
 #[cfg(test)]
 mod tests;
 mod variables;
 
+use sfc::Charts;
+
 pub(crate) fn get_attributes(attributes: Attributes) -> Result<FxHashMap<String, String>, Error> {
     attributes
         .flatten()
@@ -51,31 +55,17 @@ where
 pub(crate) fn visit(content: &str) -> Result<Project, Error> {
     let mut reader = Reader::new(content);
     reader.trim_text(true).expand_empty_elements(true);
-    let mut project = Project::default();
-
-    loop {
-        match reader.read_event()? {
-            Event::Start(tag) if tag.name().as_ref() == b"pou" => {
-                project.pous.push(Pou::visit(&mut reader, Some(tag))?)
-            }
-            Event::Start(tag) if tag.name().as_ref() == b"project" || tag.name().as_ref() == b"pous" => {
-                todo!("Project support comming in #977")
-            }
-            Event::End(tag) if tag.name().as_ref() == b"project" || tag.name().as_ref() == b"pous" => break,
-            Event::Eof => break,
-            _ => {}
-        }
-    }
-    Ok(project)
+    Project::visit(&mut reader, None)
 }
 
 pub fn parse_file(
     source: &SourceCode,
     linkage: LinkageType,
     id_provider: IdProvider,
+    defines: &Defines,
     diagnostician: &mut Diagnostician,
 ) -> Result<CompilationUnit, Diagnostic> {
-    let (unit, errors) = parse(source, linkage, id_provider);
+    let (unit, errors) = parse_with_defines(source, linkage, id_provider, defines);
     //Register the source file with the diagnostician
     diagnostician.register_file(source.get_location_str().to_string(), source.source.clone()); // TODO: Remove clone here, generally passing the GlobalContext instead of the actual source here or in the handle method should be sufficient
     if diagnostician.handle(&errors) == Severity::Error {
@@ -89,6 +79,15 @@ fn parse(
     source: &SourceCode,
     linkage: LinkageType,
     id_provider: IdProvider,
+) -> (CompilationUnit, Vec<Diagnostic>) {
+    parse_with_defines(source, linkage, id_provider, &Defines::default())
+}
+
+fn parse_with_defines(
+    source: &SourceCode,
+    linkage: LinkageType,
+    id_provider: IdProvider,
+    defines: &Defines,
 ) -> (CompilationUnit, Vec<Diagnostic>) {
     let source_location_factory = SourceLocationFactory::for_source(source);
     // Transform the xml file to a data model.
@@ -102,19 +101,31 @@ fn parse(
     let _ = project.desugar(&source_location_factory).map_err(|e| diagnostics.extend(e));
 
     // Create a new parse session
-    let parser =
-        ParseSession::new(&project, source.get_location_str(), id_provider, linkage, source_location_factory);
-    // Parse the declaration data field
-    let Some((unit, declaration_diagnostics)) = parser.try_parse_declaration() else {
-        unimplemented!("XML schemas without text declarations are not yet supported")
-    };
+    let parser = ParseSession::new(
+        &project,
+        source.get_location_str(),
+        id_provider,
+        linkage,
+        source_location_factory,
+        defines.clone(),
+    );
+    // Parse the declarations of all data types, global variables, POUs and configurations
+    let (unit, declaration_diagnostics) = parser.parse_declarations();
     diagnostics.extend(declaration_diagnostics);
 
     // Transform the data-model into an AST
-    let (implementations, parser_diagnostics) = parser.parse_model();
+    let (implementations, mut charts, parser_diagnostics) = parser.parse_model();
     diagnostics.extend(parser_diagnostics);
 
-    (unit.with_implementations(implementations), diagnostics)
+    // sequential function charts are lowered into state machines together with the textual ones
+    let mut unit = unit.with_implementations(implementations);
+    for pou in unit.units.iter_mut() {
+        if let Some(sfc) = charts.remove(&pou.name) {
+            pou.sfc = Some(sfc);
+        }
+    }
+
+    (unit, diagnostics)
 }
 
 pub(crate) struct ParseSession<'parse, 'xml> {
@@ -123,6 +134,7 @@ pub(crate) struct ParseSession<'parse, 'xml> {
     linkage: LinkageType,
     file_name: &'static str,
     range_factory: SourceLocationFactory,
+    defines: Defines,
     diagnostics: Vec<Diagnostic>,
 }
 
@@ -133,25 +145,44 @@ impl<'parse, 'xml> ParseSession<'parse, 'xml> {
         id_provider: IdProvider,
         linkage: LinkageType,
         range_factory: SourceLocationFactory,
+        defines: Defines,
     ) -> Self {
-        ParseSession { project, id_provider, linkage, file_name, range_factory, diagnostics: Vec::new() }
+        ParseSession {
+            project,
+            id_provider,
+            linkage,
+            file_name,
+            range_factory,
+            defines,
+            diagnostics: Vec::new(),
+        }
     }
 
-    /// parse the compilation unit from the addData field
-    fn try_parse_declaration(&self) -> Option<(CompilationUnit, Vec<Diagnostic>)> {
-        let content = self
-            .project
-            .pous
-            .first()
-            .and_then(|it| it.interface.as_ref().and_then(|it| it.get_data_content()))?;
-
-        //TODO: if our ST parser returns a diagnostic here, we might not have a text declaration and need to rely on the XML to provide us with
-        // the necessary data. for now, we will assume to always have a text declaration
-        Some(plc::parser::parse(
-            lexer::lex_with_ids(content, self.id_provider.clone(), self.range_factory.clone()),
+    /// parse the compilation unit from the text declarations of the project. POUs without an addData field
+    /// are declared by the text rendered from their structured interface
+    fn parse_declarations(&self) -> (CompilationUnit, Vec<Diagnostic>) {
+        plc::parser::parse(
+            lexer::lex_with_defines(
+                &self.project.text_declarations(),
+                self.id_provider.clone(),
+                self.range_factory.clone(),
+                self.defines.clone(),
+            ),
             self.linkage,
             self.file_name,
-        ))
+        )
+    }
+
+    /// parse the statements of a textual body, i.e. `<ST>`
+    fn parse_statements(&mut self, content: &str) -> Vec<AstNode> {
+        let (statements, diagnostics) = plc::parser::parse_statements(lexer::lex_with_defines(
+            content,
+            self.id_provider.clone(),
+            self.range_factory.clone(),
+            self.defines.clone(),
+        ));
+        self.diagnostics.extend(diagnostics);
+        statements
     }
 
     fn parse_expression(&self, expr: &str, local_id: usize, execution_order: Option<usize>) -> AstNode {
@@ -165,9 +196,16 @@ impl<'parse, 'xml> ParseSession<'parse, 'xml> {
         exp
     }
 
-    fn parse_model(mut self) -> (Vec<Implementation>, Vec<Diagnostic>) {
+    fn parse_model(mut self) -> (Vec<Implementation>, Charts, Vec<Diagnostic>) {
         let mut implementations = vec![];
+        let mut charts = Charts::default();
         for pou in &self.project.pous {
+            // transform sequential function charts, their inline actions become actions of the POU
+            if let Some(chart) = &pou.body.sequential_function_chart {
+                let (sfc, actions) = chart.transform(pou, &mut self);
+                charts.insert(pou.name.to_string(), sfc);
+                implementations.extend(actions);
+            }
             // transform body
             implementations.push(pou.build_implementation(&mut self));
             // transform actions
@@ -176,7 +214,7 @@ impl<'parse, 'xml> ParseSession<'parse, 'xml> {
                 .for_each(|action| implementations.push(action.build_implementation(&mut self)));
         }
 
-        (implementations, self.diagnostics)
+        (implementations, charts, self.diagnostics)
     }
 
     fn next_id(&self) -> AstId {
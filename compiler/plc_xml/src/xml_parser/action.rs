@@ -6,6 +6,10 @@ use super::ParseSession;
 
 impl Action<'_> {
     pub(crate) fn transform(&self, session: &mut ParseSession) -> Vec<AstNode> {
+        if let Some(content) = &self.body.structured_text {
+            return session.parse_statements(content);
+        }
+
         let fbd = &self.body.function_block_diagram;
 
         if cfg!(feature = "debug") {
@@ -2,13 +2,15 @@ use ast::ast::{AstFactory, AstNode};
 
 use crate::model::{block::Block, fbd::NodeIndex};
 
-use super::ParseSession;
+use super::{ladder, ParseSession};
 
 impl Block<'_> {
     pub(crate) fn transform(&self, session: &ParseSession, index: &NodeIndex) -> AstNode {
         let parameters = self
             .variables
             .iter()
+            // the EN input is not a parameter, it decides whether the block is called at all
+            .filter(|var| !var.is_enable())
             .filter_map(|var| {
                 // try to transform the element this block variable points to
                 var.transform(session, index)
@@ -23,4 +25,19 @@ impl Block<'_> {
             &session.create_block_location(self.local_id, self.execution_order_id),
         )
     }
+
+    /// Wraps the given statement into an `IF`, if the block is enabled by the power flow of a rung
+    pub(crate) fn transform_enable(
+        &self,
+        statement: AstNode,
+        session: &ParseSession,
+        index: &NodeIndex,
+    ) -> AstNode {
+        let Some(enable) = self.enable() else {
+            return statement;
+        };
+
+        let condition = ladder::transform_power_flow(enable.ref_local_id, None, session, index);
+        ladder::create_conditional(condition, statement, session)
+    }
 }
@@ -6,7 +6,7 @@ use crate::model::{
     fbd::{Node, NodeIndex},
 };
 
-use super::ParseSession;
+use super::{ladder, ParseSession};
 
 impl Control<'_> {
     pub(crate) fn transform(&self, session: &ParseSession, index: &NodeIndex) -> Result<AstNode, Diagnostic> {
@@ -44,6 +44,9 @@ fn get_connection(
     match node {
         Node::FunctionBlockVariable(variable) => Ok(variable.transform(session)),
         Node::Block(block) => Ok(block.transform(session, index)),
+        Node::PowerRail(_) | Node::LadderElement(_) => {
+            Ok(ladder::transform_power_flow(Some(ref_local_id), None, session, index))
+        }
 
         _ => {
             let location_control =
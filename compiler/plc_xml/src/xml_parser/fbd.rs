@@ -6,7 +6,11 @@ use ast::{
 use plc::index::FxIndexMap;
 use plc_source::source_location::SourceLocation;
 
-use crate::model::fbd::{FunctionBlockDiagram, Node, NodeId};
+use crate::model::{
+    block::Block,
+    fbd::{FunctionBlockDiagram, Node, NodeId},
+    ladder::{Connection, LadderElement},
+};
 
 use super::ParseSession;
 
@@ -28,12 +32,58 @@ impl FunctionBlockDiagram<'_> {
             ast_association.insert(*id, insert);
         });
 
+        // edge-detecting contacts sample their variables before any other statement is executed
+        let edge_detection = self.nodes.values().flat_map(|node| match node {
+            Node::LadderElement(element) => element.transform_edge_detection(session),
+            _ => vec![],
+        });
+
         // filter the map for each statement belonging to a node with an execution id or a temp-var, discard the rest -> these have no impact
-        ast_association
+        let statements = ast_association
             .into_iter()
-            .filter(|(key, _)| self.nodes.get(key).is_some_and(|node| node.get_exec_id().is_some()))
-            .map(|(_, value)| value)
-            .collect()
+            .filter(|(key, _)| {
+                self.nodes.get(key).is_some_and(|node| node.is_statement()) && !self.is_assigned_to_coil(*key)
+            })
+            .map(|(key, value)| match self.nodes.get(&key) {
+                Some(Node::Block(block)) => block.transform_enable(value, session, &self.nodes),
+                Some(Node::FunctionBlockVariable(variable)) => {
+                    // an assignment of a block's result is only executed if the block is enabled
+                    match variable.ref_local_id.and_then(|id| self.nodes.get(&id)) {
+                        Some(Node::Block(block)) => block.transform_enable(value, session, &self.nodes),
+                        _ => value,
+                    }
+                }
+                _ => value,
+            })
+            .collect::<Vec<_>>();
+
+        edge_detection.chain(statements).collect()
+    }
+
+    /// Returns the block whose result is stored by the given coil, i.e. the coil's only connection is an
+    /// output of the block other than `ENO`
+    fn get_assigned_block(&self, coil: &LadderElement) -> Option<(NodeId, &Block<'_>)> {
+        let [Connection { ref_local_id: Some(ref_id), formal_parameter }] = coil.connections.as_slice()
+        else {
+            return None;
+        };
+
+        match self.nodes.get(ref_id) {
+            Some(Node::Block(block))
+                if !formal_parameter.as_ref().is_some_and(|it| it.eq_ignore_ascii_case("ENO")) =>
+            {
+                Some((*ref_id, block))
+            }
+            _ => None,
+        }
+    }
+
+    /// A block whose result is stored by a coil is called as part of the coil's statement
+    fn is_assigned_to_coil(&self, id: NodeId) -> bool {
+        self.nodes.values().any(|node| {
+            matches!(node, Node::LadderElement(element)
+                if element.is_coil() && self.get_assigned_block(element).is_some_and(|(block, _)| block == id))
+        })
     }
 
     fn transform_node(
@@ -74,6 +124,24 @@ impl FunctionBlockDiagram<'_> {
                     (AstFactory::create_empty_statement(SourceLocation::undefined(), session.next_id()), None)
                 }
             },
+            Node::LadderElement(element) if element.is_coil() => {
+                let statement = match self.get_assigned_block(element) {
+                    Some((_, block)) => block.transform_enable(
+                        element.transform_coil(block.transform(session, &self.nodes), session),
+                        session,
+                        &self.nodes,
+                    ),
+                    None => {
+                        element.transform_coil(element.transform_power_flow(session, &self.nodes), session)
+                    }
+                };
+
+                (statement, None)
+            }
+            Node::LadderElement(element) => (element.transform_power_flow(session, &self.nodes), None),
+            Node::PowerRail(_) => {
+                (AstFactory::create_empty_statement(SourceLocation::undefined(), session.next_id()), None)
+            }
             Node::Connector(_) => todo!(),
         }
     }
@@ -0,0 +1,162 @@
+use ast::{
+    ast::{AstFactory, AstNode, AstStatement, Operator},
+    control_statements::ConditionalBlock,
+    literals::AstLiteral,
+};
+use plc_source::source_location::SourceLocation;
+
+use crate::model::{
+    fbd::{Node, NodeId, NodeIndex},
+    ladder::{Connection, LadderElement, PowerRailKind},
+    variables::{Edge, Storage},
+};
+
+use super::ParseSession;
+
+impl LadderElement<'_> {
+    /// Transforms the power flow to the right of a contact or coil. A contact passes the power flow on if its
+    /// variable is set, a coil passes it on unchanged
+    pub(crate) fn transform_power_flow(&self, session: &ParseSession, index: &NodeIndex) -> AstNode {
+        let location = session.create_block_location(self.local_id, self.execution_order_id);
+        let power = transform_connections(&self.connections, session, index, &location);
+        if self.is_coil() {
+            return power;
+        }
+
+        let state = match &self.edge_memory {
+            Some(memory) => session.parse_expression(memory, self.local_id, self.execution_order_id),
+            None => {
+                let variable =
+                    session.parse_expression(&self.variable, self.local_id, self.execution_order_id);
+                if self.negated {
+                    AstFactory::create_not_expression(variable, location, session.next_id())
+                } else {
+                    variable
+                }
+            }
+        };
+
+        create_and(power, state, session)
+    }
+
+    /// Transforms a coil into the statement storing the given power flow in its variable, i.e.
+    /// `x := power`, `x := NOT power` or `IF power THEN x := TRUE/FALSE; END_IF` for set- and reset-coils
+    pub(crate) fn transform_coil(&self, power: AstNode, session: &ParseSession) -> AstNode {
+        let location = session.create_block_location(self.local_id, self.execution_order_id);
+        let variable = session.parse_expression(&self.variable, self.local_id, self.execution_order_id);
+
+        let Some(storage) = self.storage else {
+            let value = if self.negated {
+                AstFactory::create_not_expression(power, location, session.next_id())
+            } else {
+                power
+            };
+
+            return AstFactory::create_assignment(variable, value, session.next_id());
+        };
+
+        let value = create_bool(storage == Storage::Set, &location, session);
+        create_conditional(power, AstFactory::create_assignment(variable, value, session.next_id()), session)
+    }
+
+    /// Transforms an edge-detecting contact into the statements sampling its variable at the start of the
+    /// body, i.e. `memory := x AND NOT memory_prev; memory_prev := x;` for a rising edge
+    pub(crate) fn transform_edge_detection(&self, session: &ParseSession) -> Vec<AstNode> {
+        let (Some(memory), Some(edge)) = (&self.edge_memory, self.edge) else {
+            return vec![];
+        };
+
+        let location = session.create_block_location(self.local_id, self.execution_order_id);
+        let parse =
+            |expression: &str| session.parse_expression(expression, self.local_id, self.execution_order_id);
+        let previous = format!("{memory}_prev");
+
+        let (current, last) = match edge {
+            Edge::Rising => (
+                parse(&self.variable),
+                AstFactory::create_not_expression(parse(&previous), location, session.next_id()),
+            ),
+            Edge::Falling => (
+                AstFactory::create_not_expression(parse(&self.variable), location, session.next_id()),
+                parse(&previous),
+            ),
+        };
+
+        vec![
+            AstFactory::create_assignment(
+                parse(memory),
+                AstFactory::create_binary_expression(current, Operator::And, last, session.next_id()),
+                session.next_id(),
+            ),
+            AstFactory::create_assignment(parse(&previous), parse(&self.variable), session.next_id()),
+        ]
+    }
+}
+
+/// Transforms the power flow arriving at an element. Parallel connections are combined with `OR`, an element
+/// without any connection receives no power
+pub(crate) fn transform_connections(
+    connections: &[Connection],
+    session: &ParseSession,
+    index: &NodeIndex,
+    location: &SourceLocation,
+) -> AstNode {
+    connections
+        .iter()
+        .map(|it| transform_power_flow(it.ref_local_id, it.formal_parameter.as_deref(), session, index))
+        .reduce(|left, right| {
+            AstFactory::create_binary_expression(left, Operator::Or, right, session.next_id())
+        })
+        .unwrap_or_else(|| create_bool(false, location, session))
+}
+
+/// Transforms the power flow leaving the element with the given id
+pub(crate) fn transform_power_flow(
+    ref_local_id: Option<NodeId>,
+    formal_parameter: Option<&str>,
+    session: &ParseSession,
+    index: &NodeIndex,
+) -> AstNode {
+    let location = ref_local_id
+        .map(|id| session.create_block_location(id, None))
+        .unwrap_or_else(|| session.create_file_only_location());
+
+    match ref_local_id.and_then(|id| index.get(&id)) {
+        Some(Node::PowerRail(rail)) => create_bool(rail.kind == PowerRailKind::Left, &location, session),
+        Some(Node::LadderElement(element)) => element.transform_power_flow(session, index),
+        // the ENO output of a block passes on the power flow of its EN input
+        Some(Node::Block(block)) if formal_parameter.is_some_and(|it| it.eq_ignore_ascii_case("ENO")) => {
+            match block.enable() {
+                Some(enable) => transform_power_flow(enable.ref_local_id, None, session, index),
+                None => create_bool(true, &location, session),
+            }
+        }
+        Some(Node::Block(block)) => block.transform(session, index),
+        Some(Node::FunctionBlockVariable(variable)) => variable.transform(session),
+        Some(Node::Control(_) | Node::Connector(_)) | None => create_bool(false, &location, session),
+    }
+}
+
+/// Wraps the given statement into `IF condition THEN statement; END_IF`
+pub(crate) fn create_conditional(condition: AstNode, statement: AstNode, session: &ParseSession) -> AstNode {
+    let location = condition.get_location().span(&statement.get_location());
+    AstFactory::create_if_statement(
+        vec![ConditionalBlock { condition: Box::new(condition), body: vec![statement] }],
+        vec![],
+        location,
+        session.next_id(),
+    )
+}
+
+/// Creates `left AND right`, omitting the left side if it is the power flow of the left power rail
+fn create_and(left: AstNode, right: AstNode, session: &ParseSession) -> AstNode {
+    if matches!(left.get_stmt(), AstStatement::Literal(AstLiteral::Bool(true))) {
+        return right;
+    }
+
+    AstFactory::create_binary_expression(left, Operator::And, right, session.next_id())
+}
+
+fn create_bool(value: bool, location: &SourceLocation, session: &ParseSession) -> AstNode {
+    AstFactory::create_literal(AstLiteral::new_bool(value), location.clone(), session.next_id())
+}
@@ -1,13 +1,41 @@
 use ast::ast::{AstNode, Implementation};
+use plc_diagnostics::diagnostics::Diagnostic;
 
-use crate::model::pou::Pou;
+use crate::model::{
+    fbd::Node,
+    pou::{Pou, PouType},
+};
 
 use super::ParseSession;
 
 impl Pou<'_> {
     fn transform(&self, session: &mut ParseSession) -> Vec<AstNode> {
+        if let Some(content) = &self.body.structured_text {
+            return session.parse_statements(content);
+        }
+
         let fbd = &self.body.function_block_diagram;
 
+        // the variables of a FUNCTION are temporary, so an edge-detecting contact cannot keep the previous state
+        // of its variable between calls
+        if matches!(self.pou_type, PouType::Function) {
+            for node in fbd.nodes.values() {
+                let Node::LadderElement(element) = node else { continue };
+                if element.edge_memory.is_some() {
+                    session.diagnostics.push(
+                        Diagnostic::new(format!(
+                            "Edge-detecting contact on `{}` is not supported in FUNCTION `{}`",
+                            element.variable, self.name
+                        ))
+                        .with_error_code("E150")
+                        .with_location(
+                            session.create_block_location(element.local_id, element.execution_order_id),
+                        ),
+                    );
+                }
+            }
+        }
+
         if cfg!(feature = "debug") {
             let statements = fbd.transform(session);
             println!("{statements:#?}");
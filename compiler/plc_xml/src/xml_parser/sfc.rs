@@ -0,0 +1,282 @@
+use ast::ast::{
+    ActionAssociation, ActionQualifier, AstFactory, AstNode, Implementation, PouType as AstPouType, Sfc,
+    SfcStep, SfcTransition,
+};
+use ast::literals::AstLiteral;
+use plc_diagnostics::diagnostics::Diagnostic;
+use plc_source::source_location::SourceLocation;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::model::{
+    fbd::NodeId,
+    pou::Pou,
+    sfc::{
+        ActionBlock, BranchKind, SequentialFunctionChart, SfcNode, StepAction, StepActionBody, Transition,
+        TransitionCondition,
+    },
+};
+
+use super::ParseSession;
+
+impl SequentialFunctionChart<'_> {
+    /// Transforms the chart into the steps and transitions of a [`Sfc`], which is lowered into a state machine
+    /// just like a textual chart. The inline actions of the chart are returned as actions of the given POU
+    pub(crate) fn transform(&self, pou: &Pou, session: &mut ParseSession) -> (Sfc, Vec<Implementation>) {
+        let mut implementations = vec![];
+        let mut steps = vec![];
+        for node in self.nodes.values() {
+            let SfcNode::Step(step) = node else { continue };
+            let location = session.create_block_location(step.local_id, None);
+
+            let mut actions = vec![];
+            // the actions are numbered across all action blocks of the step, so inline actions get unique names
+            let step_actions = self
+                .get_action_blocks(step.local_id)
+                .flat_map(|it| it.actions.iter().map(move |action| (it, action)));
+            for (index, (block, action)) in step_actions.enumerate() {
+                let name = match &action.body {
+                    StepActionBody::Reference(name) => name.to_string(),
+                    StepActionBody::Inline(content) => {
+                        let name = format!("__{}_{index}", step.name);
+                        implementations.push(create_action(pou, &name, content, session));
+                        name
+                    }
+                };
+
+                if let Some(association) = transform_action(block, action, name, session) {
+                    actions.push(association);
+                }
+            }
+
+            steps.push(SfcStep {
+                name: step.name.to_string(),
+                initial: step.initial,
+                actions,
+                location: location.clone(),
+                name_location: location,
+            });
+        }
+
+        for node in self.nodes.values() {
+            if let SfcNode::ActionBlock(block) = node {
+                let is_connected_to_step = block
+                    .connections
+                    .first()
+                    .is_some_and(|id| matches!(self.nodes.get(id), Some(SfcNode::Step(_))));
+                if !is_connected_to_step {
+                    session.diagnostics.push(
+                        Diagnostic::new("Action block is not connected to a step")
+                            .with_error_code("E125")
+                            .with_location(session.create_block_location(block.local_id, None)),
+                    );
+                }
+            }
+        }
+
+        // transitions leaving the same step are fired in the order they are lowered, i.e. by their priority
+        let mut transitions = self
+            .nodes
+            .values()
+            .filter_map(|it| if let SfcNode::Transition(it) = it { Some(it) } else { None })
+            .collect::<Vec<_>>();
+        transitions.sort_by_key(|it| it.priority.unwrap_or(usize::MAX));
+
+        let transitions =
+            transitions.into_iter().filter_map(|it| self.transform_transition(it, pou, session)).collect();
+
+        (Sfc { steps, transitions }, implementations)
+    }
+
+    fn transform_transition(
+        &self,
+        transition: &Transition,
+        pou: &Pou,
+        session: &mut ParseSession,
+    ) -> Option<SfcTransition> {
+        let location = session.create_block_location(transition.local_id, None);
+        let mut visited = FxHashSet::default();
+        let from = transition
+            .connections
+            .iter()
+            .flat_map(|id| self.get_preceding_steps(*id, &mut visited, session))
+            .collect::<Vec<_>>();
+        let to = self.get_succeeding_steps(transition.local_id, &mut FxHashSet::default(), session);
+
+        if from.is_empty() || to.is_empty() {
+            let direction = if from.is_empty() { "preceding" } else { "succeeding" };
+            session.diagnostics.push(
+                Diagnostic::new(format!("Transition is not connected to a {direction} step"))
+                    .with_error_code("E125")
+                    .with_location(&location),
+            );
+            return None;
+        }
+
+        let (name, condition) = match &transition.condition {
+            Some(TransitionCondition::Inline(content)) => {
+                (None, parse_condition(content, transition.local_id, session))
+            }
+            Some(TransitionCondition::Reference(name)) => {
+                let declaration = pou
+                    .transitions
+                    .iter()
+                    .find(|it| it.name.eq_ignore_ascii_case(name))
+                    .and_then(|it| it.body.structured_text.as_deref());
+                // a reference to an undeclared transition is the name of a boolean variable
+                let condition = parse_condition(declaration.unwrap_or(name), transition.local_id, session);
+                (Some(name.to_string()), condition)
+            }
+            Some(TransitionCondition::Connection) | None => {
+                session.diagnostics.push(
+                    Diagnostic::new("Transitions require a textual condition, either inline or by reference")
+                        .with_error_code("E125")
+                        .with_location(&location),
+                );
+                (
+                    None,
+                    AstFactory::create_literal(
+                        AstLiteral::new_bool(false),
+                        location.clone(),
+                        session.next_id(),
+                    ),
+                )
+            }
+        };
+
+        Some(SfcTransition { name, from, to, condition, location })
+    }
+
+    /// Returns the action blocks connected to the step with the given id
+    fn get_action_blocks(&self, step: NodeId) -> impl Iterator<Item = &ActionBlock<'_>> {
+        self.nodes.values().filter_map(move |it| match it {
+            SfcNode::ActionBlock(block) if block.connections.first() == Some(&step) => Some(block),
+            _ => None,
+        })
+    }
+
+    /// Returns the steps a transition connected to the node with the given id deactivates, following
+    /// selection-divergences and simultaneous-convergences
+    fn get_preceding_steps(
+        &self,
+        id: NodeId,
+        visited: &mut FxHashSet<NodeId>,
+        session: &ParseSession,
+    ) -> Vec<(String, SourceLocation)> {
+        if !visited.insert(id) {
+            return vec![];
+        }
+
+        match self.nodes.get(&id) {
+            Some(SfcNode::Step(step)) => {
+                vec![(step.name.to_string(), session.create_block_location(id, None))]
+            }
+            Some(SfcNode::Branch(branch))
+                if matches!(
+                    branch.kind,
+                    BranchKind::SelectionDivergence | BranchKind::SimultaneousConvergence
+                ) =>
+            {
+                branch
+                    .connections
+                    .iter()
+                    .flat_map(|it| self.get_preceding_steps(*it, visited, session))
+                    .collect()
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Returns the steps the transition with the given id activates, following selection-convergences,
+    /// simultaneous-divergences and jumps
+    fn get_succeeding_steps(
+        &self,
+        id: NodeId,
+        visited: &mut FxHashSet<NodeId>,
+        session: &ParseSession,
+    ) -> Vec<(String, SourceLocation)> {
+        if !visited.insert(id) {
+            return vec![];
+        }
+
+        let successors = self
+            .nodes
+            .values()
+            .filter(|it| !matches!(it, SfcNode::ActionBlock(_)) && it.get_connections().contains(&id))
+            .collect::<Vec<_>>();
+
+        let mut steps = vec![];
+        for successor in successors {
+            let location = session.create_block_location(successor.get_id(), None);
+            match successor {
+                SfcNode::Step(step) => steps.push((step.name.to_string(), location)),
+                SfcNode::JumpStep(jump) => steps.push((jump.target_name.to_string(), location)),
+                SfcNode::Branch(branch)
+                    if matches!(
+                        branch.kind,
+                        BranchKind::SelectionConvergence | BranchKind::SimultaneousDivergence
+                    ) =>
+                {
+                    steps.extend(self.get_succeeding_steps(branch.local_id, visited, session))
+                }
+                _ => {}
+            }
+        }
+
+        steps
+    }
+}
+
+fn transform_action(
+    block: &ActionBlock,
+    action: &StepAction,
+    name: String,
+    session: &mut ParseSession,
+) -> Option<ActionAssociation> {
+    let location = session.create_block_location(block.local_id, None);
+    let Some(qualifier) = ActionQualifier::from_name(&action.qualifier) else {
+        session.diagnostics.push(
+            Diagnostic::new(format!("Unknown action qualifier '{}'", action.qualifier))
+                .with_error_code("E125")
+                .with_location(&location),
+        );
+        return None;
+    };
+
+    let duration = action.duration.as_ref().map(|it| session.parse_expression(it, block.local_id, None));
+    if qualifier.is_timed() != duration.is_some() {
+        let message = if qualifier.is_timed() {
+            format!("Action qualifier '{qualifier}' requires a duration")
+        } else {
+            format!("Action qualifier '{qualifier}' does not accept a duration")
+        };
+        session.diagnostics.push(Diagnostic::new(message).with_error_code("E125").with_location(&location));
+        return None;
+    }
+
+    Some(ActionAssociation { action: name, qualifier, duration, location })
+}
+
+/// Parses the expression of a transition condition, which may be terminated by a semicolon
+fn parse_condition(content: &str, local_id: NodeId, session: &ParseSession) -> AstNode {
+    session.parse_expression(content.trim().trim_end_matches(';'), local_id, None)
+}
+
+/// Creates the implementation of an inline action as an action of the given POU
+fn create_action(pou: &Pou, name: &str, content: &str, session: &mut ParseSession) -> Implementation {
+    let statements = session.parse_statements(content);
+    Implementation {
+        name: format!("{}.{name}", pou.name),
+        type_name: pou.name.to_string(),
+        linkage: session.linkage,
+        pou_type: AstPouType::Action,
+        statements,
+        location: session.create_file_only_location(),
+        name_location: session.create_file_only_location(),
+        overriding: false,
+        generic: false,
+        access: None,
+    }
+}
+
+/// Maps the names of all charts to the POU they implement
+pub(crate) type Charts = FxHashMap<String, Sfc>;
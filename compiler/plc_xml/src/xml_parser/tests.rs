@@ -5,7 +5,7 @@ use ast::{
     },
     provider::IdProvider,
 };
-use insta::assert_debug_snapshot;
+use insta::{assert_debug_snapshot, assert_snapshot};
 use plc_diagnostics::diagnostics::Diagnostic;
 use plc_source::{source_location::SourceLocationFactory, SourceCode, SourceCodeFactory};
 
@@ -375,6 +375,153 @@ fn ast_diagnostic_locations() {
     todo!("Validation in tests not yet done")
 }
 
+#[test]
+fn project_declarations_are_rendered_as_text() {
+    let project = visit(content::PROJECT).unwrap();
+
+    assert_snapshot!(project.text_declarations(), @r###"
+    TYPE Mode : (manual, automatic := 5);
+    END_TYPE
+    TYPE Percent : UINT(0..100) := 50;
+    END_TYPE
+    TYPE Recipe : STRUCT
+    name : STRING[20] := 'water';
+    steps : ARRAY[1..3, 0..1] OF INT := [2(1), 0];
+    next : REF_TO Recipe;
+    END_STRUCT;
+    END_TYPE
+    VAR_GLOBAL
+        alarm AT %IX0.0 : BOOL;
+    END_VAR
+    FUNCTION scale : LREAL
+    VAR_INPUT
+        value : Percent;
+    END_VAR
+    END_FUNCTION
+    PROGRAM mixer
+    VAR RETAIN
+        recipe : Recipe := (name := 'milk');
+    END_VAR
+    END_PROGRAM
+    CONFIGURATION plant
+    VAR_GLOBAL CONSTANT
+        mode : Mode := automatic;
+    END_VAR
+    RESOURCE cpu
+    TASK fast (INTERVAL := T#10ms, PRIORITY := 1);
+    TASK onAlarm (SINGLE := alarm, PRIORITY := 0);
+    PROGRAM mixing WITH fast : mixer;
+    PROGRAM background : mixer;
+    END_RESOURCE
+    END_CONFIGURATION
+    "###);
+}
+
+#[test]
+fn project_pous_with_textual_bodies_are_parsed() {
+    let (unit, diagnostics) = parse(content::PROJECT);
+
+    assert!(diagnostics.is_empty(), "Expected no diagnostics but got {diagnostics:#?}");
+    assert_eq!(unit.units.iter().map(|it| it.name.as_str()).collect::<Vec<_>>(), vec!["scale", "mixer"]);
+    assert_eq!(unit.user_types.len(), 3);
+    assert_eq!(unit.global_vars.iter().map(|it| it.variables.len()).sum::<usize>(), 2);
+    assert_eq!(unit.configurations[0].resources[0].programs.len(), 2);
+
+    let statements =
+        unit.implementations.iter().map(|it| (it.name.as_str(), it.statements.len())).collect::<Vec<_>>();
+    assert_eq!(statements, vec![("scale", 1), ("mixer", 2), ("mixer.clean", 1)]);
+}
+
+#[test]
+fn ladder_diagram_rungs_are_transformed_into_assignments() {
+    let project = visit(content::LADDER).unwrap();
+    // the edge-detecting contact remembers the state of its variable in a declared variable
+    assert_snapshot!(project.text_declarations(), @r###"
+    PROGRAM interlock
+    VAR
+        start, stop, manual, lamp, motor : BOOL;
+    END_VAR
+    VAR
+        __ld_edge2, __ld_edge2_prev : BOOL;
+    END_VAR
+    END_PROGRAM
+    "###);
+
+    let (unit, diagnostics) = parse(content::LADDER);
+    assert!(diagnostics.is_empty(), "Expected no diagnostics but got {diagnostics:#?}");
+    assert_debug_snapshot!(unit.implementations[0].statements);
+}
+
+#[test]
+fn edge_detecting_contacts_are_reported_in_functions() {
+    let content = content::LADDER
+        .replace(r#"pouType="program""#, r#"pouType="function""#)
+        .replace("PROGRAM interlock", "FUNCTION interlock");
+    let (_, diagnostics) = parse(&content);
+
+    let diagnostics =
+        diagnostics.iter().map(|it| (it.get_error_code(), it.get_message())).collect::<Vec<_>>();
+    assert_eq!(
+        diagnostics,
+        vec![("E150", "Edge-detecting contact on `start` is not supported in FUNCTION `interlock`")]
+    );
+}
+
+#[test]
+fn sequential_function_chart_is_transformed_into_steps_and_transitions() {
+    let (unit, diagnostics) = parse(content::SFC);
+    assert!(diagnostics.is_empty(), "Expected no diagnostics but got {diagnostics:#?}");
+
+    let sfc = unit.units[0].sfc.as_ref().unwrap();
+    let steps = sfc
+        .steps
+        .iter()
+        .map(|it| (it.name.as_str(), it.initial, it.actions.iter().map(|it| it.action.as_str()).collect()))
+        .collect::<Vec<(_, _, Vec<_>)>>();
+    assert_eq!(
+        steps,
+        vec![("idle", true, vec![]), ("fill", false, vec!["__fill_0", "valve"]), ("stir", false, vec![])]
+    );
+
+    // the transitions are ordered by their priority, branches are resolved to the steps they connect
+    let names = |steps: &[(String, _)]| steps.iter().map(|(name, _)| name.clone()).collect::<Vec<_>>();
+    let transitions = sfc
+        .transitions
+        .iter()
+        .map(|it| (it.name.clone(), names(&it.from), names(&it.to), it.condition.get_flat_reference_name()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        transitions,
+        vec![
+            (None, vec!["idle".to_string()], vec!["fill".to_string(), "stir".to_string()], Some("start")),
+            (
+                Some("done".to_string()),
+                vec!["fill".to_string(), "stir".to_string()],
+                vec!["idle".to_string()],
+                None
+            ),
+            (None, vec!["idle".to_string()], vec!["idle".to_string()], Some("abort")),
+        ]
+    );
+
+    // inline actions become actions of the POU
+    let actions = unit.implementations.iter().map(|it| it.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(actions, vec!["mixer.__fill_0", "mixer"]);
+}
+
+#[test]
+fn inline_actions_of_several_action_blocks_on_one_step_are_named_uniquely() {
+    let (unit, diagnostics) = parse(content::SFC_ACTION_BLOCKS);
+    assert!(diagnostics.is_empty(), "Expected no diagnostics but got {diagnostics:#?}");
+
+    let step = &unit.units[0].sfc.as_ref().unwrap().steps[0];
+    let names = step.actions.iter().map(|it| it.action.as_str()).collect::<Vec<_>>();
+    assert_eq!(names, vec!["__run_0", "__run_1", "valve"]);
+
+    let actions = unit.implementations.iter().map(|it| it.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(actions, vec!["counter.__run_0", "counter.__run_1", "counter"]);
+}
+
 mod content {
     pub(super) const BLOCK_SOURCE_TO_MULTI_SINK: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
     <pou xmlns="http://www.plcopen.org/xml/tc6_0201" name="myConnection" pouType="function">
@@ -586,4 +733,337 @@ END_VAR</content>
     </body>
 </pou>
     "###;
+
+    pub(super) const PROJECT: &str = r###"<?xml version="1.0" encoding="UTF-8"?>
+<project xmlns="http://www.plcopen.org/xml/tc6_0201">
+    <fileHeader companyName="" productName="" productVersion="" creationDateTime="2024-01-01T00:00:00"/>
+    <types>
+        <dataTypes>
+            <dataType name="Mode">
+                <baseType>
+                    <enum>
+                        <values>
+                            <value name="manual"/>
+                            <value name="automatic" value="5"/>
+                        </values>
+                    </enum>
+                </baseType>
+            </dataType>
+            <dataType name="Percent">
+                <baseType>
+                    <subrangeUnsigned>
+                        <range lower="0" upper="100"/>
+                        <baseType>
+                            <UINT/>
+                        </baseType>
+                    </subrangeUnsigned>
+                </baseType>
+                <initialValue>
+                    <simpleValue value="50"/>
+                </initialValue>
+            </dataType>
+            <dataType name="Recipe">
+                <baseType>
+                    <struct>
+                        <variable name="name">
+                            <type>
+                                <string length="20"/>
+                            </type>
+                            <initialValue>
+                                <simpleValue value="'water'"/>
+                            </initialValue>
+                            <documentation>
+                                <xhtml xmlns="http://www.w3.org/1999/xhtml">the name of the recipe</xhtml>
+                            </documentation>
+                        </variable>
+                        <variable name="steps">
+                            <type>
+                                <array>
+                                    <dimension lower="1" upper="3"/>
+                                    <dimension lower="0" upper="1"/>
+                                    <baseType>
+                                        <INT/>
+                                    </baseType>
+                                </array>
+                            </type>
+                            <initialValue>
+                                <arrayValue>
+                                    <value repetitionValue="2">
+                                        <simpleValue value="1"/>
+                                    </value>
+                                    <value>
+                                        <simpleValue value="0"/>
+                                    </value>
+                                </arrayValue>
+                            </initialValue>
+                        </variable>
+                        <variable name="next">
+                            <type>
+                                <pointer>
+                                    <baseType>
+                                        <derived name="Recipe"/>
+                                    </baseType>
+                                </pointer>
+                            </type>
+                        </variable>
+                    </struct>
+                </baseType>
+            </dataType>
+        </dataTypes>
+        <pous>
+            <pou name="scale" pouType="function">
+                <interface>
+                    <returnType>
+                        <LREAL/>
+                    </returnType>
+                    <inputVars>
+                        <variable name="value">
+                            <type>
+                                <derived name="Percent"/>
+                            </type>
+                        </variable>
+                    </inputVars>
+                </interface>
+                <body>
+                    <ST>
+                        <xhtml xmlns="http://www.w3.org/1999/xhtml">scale := value / 100.0;</xhtml>
+                    </ST>
+                </body>
+            </pou>
+            <pou name="mixer" pouType="program">
+                <interface>
+                    <localVars retain="true">
+                        <variable name="recipe">
+                            <type>
+                                <derived name="Recipe"/>
+                            </type>
+                            <initialValue>
+                                <structValue>
+                                    <value member="name">
+                                        <simpleValue value="'milk'"/>
+                                    </value>
+                                </structValue>
+                            </initialValue>
+                        </variable>
+                    </localVars>
+                </interface>
+                <actions>
+                    <action name="clean">
+                        <body>
+                            <ST>
+                                <xhtml xmlns="http://www.w3.org/1999/xhtml">recipe.steps[1, 0] := 0;</xhtml>
+                            </ST>
+                        </body>
+                    </action>
+                </actions>
+                <body>
+                    <ST>
+                        <xhtml xmlns="http://www.w3.org/1999/xhtml">
+IF recipe.steps[1, 0] &gt; 0 THEN
+    clean();
+END_IF
+recipe.name := 'done';
+                        </xhtml>
+                    </ST>
+                </body>
+            </pou>
+        </pous>
+    </types>
+    <instances>
+        <configurations>
+            <configuration name="plant">
+                <resource name="cpu">
+                    <task name="fast" interval="T#10ms" priority="1">
+                        <pouInstance name="mixing" typeName="mixer"/>
+                    </task>
+                    <task name="onAlarm" single="alarm" priority="0"/>
+                    <pouInstance name="background" typeName="mixer"/>
+                </resource>
+                <globalVars constant="true">
+                    <variable name="mode">
+                        <type>
+                            <derived name="Mode"/>
+                        </type>
+                        <initialValue>
+                            <simpleValue value="automatic"/>
+                        </initialValue>
+                    </variable>
+                </globalVars>
+            </configuration>
+        </configurations>
+    </instances>
+    <addData>
+        <data name="globalvars" handleUnknown="implementation">
+            <globalVars name="io">
+                <variable name="alarm" address="%IX0.0">
+                    <type>
+                        <BOOL/>
+                    </type>
+                </variable>
+            </globalVars>
+        </data>
+    </addData>
+</project>
+    "###;
+
+    pub(super) const LADDER: &str = r###"<?xml version="1.0" encoding="UTF-8"?>
+<pou xmlns="http://www.plcopen.org/xml/tc6_0201" name="interlock" pouType="program">
+    <interface>
+        <localVars>
+            <variable name="start"><type><BOOL/></type></variable>
+        </localVars>
+        <addData>
+            <data name="www.bachmann.at/plc/plcopenxml" handleUnknown="implementation">
+                <textDeclaration>
+                    <content>
+PROGRAM interlock
+VAR
+    start, stop, manual, lamp, motor : BOOL;
+END_VAR
+                    </content>
+                </textDeclaration>
+            </data>
+        </addData>
+    </interface>
+    <body>
+        <LD>
+            <leftPowerRail localId="1">
+                <connectionPointOut formalParameter="none"/>
+            </leftPowerRail>
+            <contact localId="2" negated="false" edge="rising">
+                <connectionPointIn><connection refLocalId="1"/></connectionPointIn>
+                <variable>start</variable>
+            </contact>
+            <contact localId="3" negated="true">
+                <connectionPointIn><connection refLocalId="2"/></connectionPointIn>
+                <variable>stop</variable>
+            </contact>
+            <contact localId="4" negated="false">
+                <connectionPointIn><connection refLocalId="1"/></connectionPointIn>
+                <variable>manual</variable>
+            </contact>
+            <coil localId="5" negated="false" storage="none">
+                <connectionPointIn>
+                    <connection refLocalId="3"/>
+                    <connection refLocalId="4"/>
+                </connectionPointIn>
+                <variable>lamp</variable>
+            </coil>
+            <coil localId="6" negated="false" storage="reset">
+                <connectionPointIn><connection refLocalId="4"/></connectionPointIn>
+                <variable>motor</variable>
+            </coil>
+            <rightPowerRail localId="7">
+                <connectionPointIn>
+                    <connection refLocalId="5"/>
+                    <connection refLocalId="6"/>
+                </connectionPointIn>
+            </rightPowerRail>
+        </LD>
+    </body>
+</pou>
+"###;
+
+    pub(super) const SFC: &str = r###"<?xml version="1.0" encoding="UTF-8"?>
+<pou xmlns="http://www.plcopen.org/xml/tc6_0201" name="mixer" pouType="program">
+    <interface>
+        <addData>
+            <data name="www.bachmann.at/plc/plcopenxml" handleUnknown="implementation">
+                <textDeclaration>
+                    <content>
+PROGRAM mixer
+VAR
+    start, abort, full, valve : BOOL;
+    count : DINT;
+END_VAR
+                    </content>
+                </textDeclaration>
+            </data>
+        </addData>
+    </interface>
+    <transitions>
+        <transition name="done">
+            <body><ST><xhtml>full AND count &gt; 10</xhtml></ST></body>
+        </transition>
+    </transitions>
+    <body>
+        <SFC>
+            <step localId="1" name="idle" initialStep="true"/>
+            <selectionDivergence localId="2">
+                <connectionPointIn><connection refLocalId="1"/></connectionPointIn>
+            </selectionDivergence>
+            <transition localId="3" priority="2">
+                <connectionPointIn><connection refLocalId="2"/></connectionPointIn>
+                <condition><inline name="t3"><ST><xhtml>abort</xhtml></ST></inline></condition>
+            </transition>
+            <jumpStep localId="4" targetName="idle">
+                <connectionPointIn><connection refLocalId="3"/></connectionPointIn>
+            </jumpStep>
+            <transition localId="5" priority="0">
+                <connectionPointIn><connection refLocalId="2"/></connectionPointIn>
+                <condition><inline name="t5"><ST><xhtml>start;</xhtml></ST></inline></condition>
+            </transition>
+            <simultaneousDivergence localId="6">
+                <connectionPointIn><connection refLocalId="5"/></connectionPointIn>
+            </simultaneousDivergence>
+            <step localId="7" name="fill">
+                <connectionPointIn><connection refLocalId="6"/></connectionPointIn>
+            </step>
+            <actionBlock localId="8">
+                <connectionPointIn><connection refLocalId="7"/></connectionPointIn>
+                <action localId="0"><inline><ST><xhtml>count := count + 1;</xhtml></ST></inline></action>
+                <action localId="1" qualifier="S"><reference name="valve"/></action>
+            </actionBlock>
+            <step localId="9" name="stir">
+                <connectionPointIn><connection refLocalId="6"/></connectionPointIn>
+            </step>
+            <simultaneousConvergence localId="10">
+                <connectionPointIn><connection refLocalId="7"/></connectionPointIn>
+                <connectionPointIn><connection refLocalId="9"/></connectionPointIn>
+            </simultaneousConvergence>
+            <transition localId="11" priority="1">
+                <connectionPointIn><connection refLocalId="10"/></connectionPointIn>
+                <condition><reference name="done"/></condition>
+            </transition>
+            <jumpStep localId="12" targetName="idle">
+                <connectionPointIn><connection refLocalId="11"/></connectionPointIn>
+            </jumpStep>
+        </SFC>
+    </body>
+</pou>
+"###;
+
+    pub(super) const SFC_ACTION_BLOCKS: &str = r###"<?xml version="1.0" encoding="UTF-8"?>
+<pou xmlns="http://www.plcopen.org/xml/tc6_0201" name="counter" pouType="program">
+    <interface>
+        <addData>
+            <data name="www.bachmann.at/plc/plcopenxml" handleUnknown="implementation">
+                <textDeclaration>
+                    <content>
+PROGRAM counter
+VAR
+    valve : BOOL;
+    count : DINT;
+END_VAR
+                    </content>
+                </textDeclaration>
+            </data>
+        </addData>
+    </interface>
+    <body>
+        <SFC>
+            <step localId="1" name="run" initialStep="true"/>
+            <actionBlock localId="2">
+                <connectionPointIn><connection refLocalId="1"/></connectionPointIn>
+                <action localId="0"><inline><ST><xhtml>count := count + 1;</xhtml></ST></inline></action>
+            </actionBlock>
+            <actionBlock localId="3">
+                <connectionPointIn><connection refLocalId="1"/></connectionPointIn>
+                <action localId="0"><inline><ST><xhtml>count := 0;</xhtml></ST></inline></action>
+                <action localId="1" qualifier="S"><reference name="valve"/></action>
+            </actionBlock>
+        </SFC>
+    </body>
+</pou>
+"###;
 }
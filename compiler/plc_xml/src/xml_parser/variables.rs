@@ -5,7 +5,7 @@ use crate::model::{
     variables::{BlockVariable, FunctionBlockVariable},
 };
 
-use super::ParseSession;
+use super::{ladder, ParseSession};
 
 impl BlockVariable {
     pub(crate) fn transform(&self, session: &ParseSession, index: &NodeIndex) -> Option<AstNode> {
@@ -18,6 +18,9 @@ impl BlockVariable {
         match index.get(ref_id) {
             Some(Node::Block(block)) => Some(block.transform(session, index)),
             Some(Node::FunctionBlockVariable(var)) => Some(var.transform(session)),
+            Some(Node::PowerRail(_) | Node::LadderElement(_)) => {
+                Some(ladder::transform_power_flow(Some(*ref_id), None, session, index))
+            }
             Some(Node::Control(_)) => todo!(),
             Some(Node::Connector(_)) => unreachable!(),
             None => unreachable!(),
@@ -3,10 +3,10 @@ use crate::{FunctionArgument, SectionMangler, StringEncoding, Type};
 use std::str;
 
 use nom::branch::alt;
-use nom::bytes::complete::{tag, take_until1};
+use nom::bytes::complete::{is_not, tag};
 use nom::character::complete::{char, digit1};
-use nom::combinator::map_res;
-use nom::multi::{many0, many_m_n};
+use nom::combinator::{map_res, recognize};
+use nom::multi::{many0, many1, many_m_n};
 use nom::sequence::delimited;
 use nom::{IResult, Parser};
 
@@ -29,7 +29,9 @@ fn parse_prefix(input: &str) -> ParseResult<Prefix> {
 }
 
 fn parse_entity_name(input: &str) -> ParseResult<&str> {
-    delimited(char('-'), take_until1(":"), char(':'))(input)
+    // names of namespaced entities contain `::` separators, e.g. `a::b::motor`
+    let name = recognize(many1(alt((is_not(":"), tag("::")))));
+    delimited(char('-'), name, char(':'))(input)
 }
 
 fn type_void(input: &str) -> ParseResult<Type> {
@@ -158,6 +160,7 @@ mod tests {
     #[test]
     fn parse_name_valid() {
         assert_eq!(parse_entity_name("-foo:").unwrap().1, "foo");
+        assert_eq!(parse_entity_name("-a::b::foo:v").unwrap().1, "a::b::foo");
 
         // empty name
         assert!(parse_entity_name("-:").is_err());
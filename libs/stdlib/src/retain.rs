@@ -0,0 +1,406 @@
+//! Runtime helpers storing the retain image generated by the compiler in a file and restoring it after a
+//! restart. A runtime passes the compiler's `__retain_info` descriptor, e.g.
+//! ```c
+//! extern RetainInfo __retain_info;
+//!
+//! __retain_read(&__retain_info, "plc.retain");  // after the initializers ran
+//! ...
+//! __retain_write(&__retain_info, "plc.retain"); // periodically or before shutting down
+//! ```
+//! The file stores the hash of the image's layout and every entry with its name and the hash of its type's
+//! layout. If the layout changed since the file was written, e.g. because the program was recompiled with
+//! additional retained variables, only the `PERSISTENT` variables whose type did not change are restored.
+use std::{
+    ffi::{c_char, CStr},
+    fs::{self, File},
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::{Path, PathBuf},
+};
+
+/// Identifies a retain file and the version of its format
+const MAGIC: &[u8; 8] = b"PLCRETN\x01";
+
+/// The maximum length of an entry's name in a retain file, longer names are considered invalid
+const MAX_NAME_LEN: u32 = 4096;
+
+/// The flag of an entry declared `PERSISTENT`, see [`RetainEntry::flags`]
+pub const PERSISTENT_FLAG: u32 = 2;
+
+/// The retain file was written or all retained variables were restored
+pub const RETAIN_OK: i32 = 0;
+/// `__retain_read` restored the `PERSISTENT` variables of a file with a different layout
+pub const RETAIN_LAYOUT_CHANGED: i32 = 1;
+/// The retain file could not be read or written, no variables were restored
+pub const RETAIN_ERROR: i32 = -1;
+
+/// The descriptor of a retained variable in the retain image
+#[repr(C)]
+#[derive(Debug)]
+pub struct RetainEntry {
+    /// the null-terminated path of the variable, e.g. `mainProg.counter`
+    pub name: *const c_char,
+    /// the hash of the layout of the variable's type
+    pub hash: u64,
+    /// the offset of the variable in the retain image
+    pub offset: u64,
+    /// the size of the variable in bytes
+    pub size: u64,
+    /// `1` for `RETAIN` variables, `2` for `PERSISTENT` variables, `3` for both
+    pub flags: u32,
+}
+
+/// The descriptor of the retain image, generated by the compiler as `__retain_info`
+#[repr(C)]
+#[derive(Debug)]
+pub struct RetainInfo {
+    /// the hash of the layout of the retain image
+    pub hash: u64,
+    /// the size of the retain image in bytes
+    pub size: u64,
+    /// the retain image
+    pub image: *mut u8,
+    /// copies the retained variables into the retain image
+    pub save: extern "C" fn(),
+    /// copies the retain image into the retained variables
+    pub restore: extern "C" fn(),
+    /// the number of entries following the descriptor
+    pub count: u32,
+    entries: [RetainEntry; 0],
+}
+
+impl RetainInfo {
+    pub fn entries(&self) -> &[RetainEntry] {
+        // SAFETY: the compiler generates `count` entries directly after the descriptor
+        unsafe { std::slice::from_raw_parts(self.entries.as_ptr(), self.count as usize) }
+    }
+
+    /// Returns the bytes of the given entry in the retain image
+    fn get_data(&self, entry: &RetainEntry) -> &[u8] {
+        // SAFETY: the entries describe the members of the image
+        unsafe { std::slice::from_raw_parts(self.image.add(entry.offset as usize), entry.size as usize) }
+    }
+
+    /// Overwrites the bytes of the given entry in the retain image
+    fn set_data(&self, entry: &RetainEntry, data: &[u8]) {
+        // SAFETY: the entries describe the members of the image, the data has the size of the entry
+        unsafe {
+            std::ptr::copy_nonoverlapping(data.as_ptr(), self.image.add(entry.offset as usize), data.len())
+        }
+    }
+}
+
+impl RetainEntry {
+    pub fn get_name(&self) -> &str {
+        // SAFETY: the compiler generates a null-terminated name for every entry
+        unsafe { CStr::from_ptr(self.name) }.to_str().unwrap_or_default()
+    }
+
+    pub fn is_persistent(&self) -> bool {
+        self.flags & PERSISTENT_FLAG != 0
+    }
+}
+
+/// The outcome of restoring the retained variables from a file
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum RetainStatus {
+    /// all retained variables were restored
+    Restored,
+    /// the layout changed since the file was written, only unchanged `PERSISTENT` variables were restored
+    LayoutChanged,
+}
+
+/// Copies the retained variables into the retain image and writes the image to the given file. The image is
+/// written to a temporary file first, which then replaces the given file, so a power loss while writing
+/// does not corrupt a previously written file
+pub fn write_retain_file(info: &RetainInfo, path: &Path) -> io::Result<()> {
+    (info.save)();
+
+    let mut temp_path = PathBuf::from(path).into_os_string();
+    temp_path.push(".tmp");
+    let mut writer = BufWriter::new(File::create(&temp_path)?);
+    writer.write_all(MAGIC)?;
+    writer.write_all(&info.hash.to_le_bytes())?;
+    writer.write_all(&info.count.to_le_bytes())?;
+    for entry in info.entries() {
+        let name = entry.get_name().as_bytes();
+        writer.write_all(&(name.len() as u32).to_le_bytes())?;
+        writer.write_all(name)?;
+        writer.write_all(&entry.hash.to_le_bytes())?;
+        writer.write_all(&entry.flags.to_le_bytes())?;
+        writer.write_all(&entry.size.to_le_bytes())?;
+        writer.write_all(info.get_data(entry))?;
+    }
+    writer.into_inner().map_err(|it| it.into_error())?.sync_all()?;
+    fs::rename(temp_path, path)
+}
+
+/// Reads the retain image from the given file and copies it into the retained variables. If the layout of the
+/// image changed since the file was written, a warning is logged and only the `PERSISTENT` variables whose
+/// type did not change are restored, all other variables keep their initial values
+pub fn read_retain_file(info: &RetainInfo, path: &Path) -> io::Result<RetainStatus> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let mut magic = [0; 8];
+    reader.read_exact(&mut magic)?;
+    if &magic != MAGIC {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "not a retain file"));
+    }
+
+    let hash = read_u64(&mut reader)?;
+    let count = read_u32(&mut reader)?;
+    let layout_changed = hash != info.hash;
+    if layout_changed {
+        log::warn!(
+            "The layout of the retained variables changed since `{}` was written, only PERSISTENT variables are restored",
+            path.display()
+        );
+    }
+
+    // start from the current values, the file may not contain all variables
+    (info.save)();
+    let mut restored = vec![false; info.entries().len()];
+    for _ in 0..count {
+        let name_len = read_u32(&mut reader)?;
+        if name_len > MAX_NAME_LEN {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "invalid retain file entry"));
+        }
+        let mut name = vec![0; name_len as usize];
+        reader.read_exact(&mut name)?;
+        let name = String::from_utf8_lossy(&name);
+        let entry_hash = read_u64(&mut reader)?;
+        let _flags = read_u32(&mut reader)?;
+        let size = read_u64(&mut reader)?;
+
+        // the data is only read for known entries of the same size, all other data is skipped
+        let entry = info.entries().iter().enumerate().find(|(_, it)| it.get_name() == name);
+        let restorable = match entry {
+            Some((_, it)) if it.hash != entry_hash || it.size != size => {
+                log::warn!("The type of retained variable `{name}` changed, its value is not restored");
+                None
+            }
+            Some((_, it)) if layout_changed && !it.is_persistent() => None,
+            entry => entry,
+        };
+        let Some((idx, entry)) = restorable else {
+            if io::copy(&mut reader.by_ref().take(size), &mut io::sink())? != size {
+                return Err(io::ErrorKind::UnexpectedEof.into());
+            }
+            continue;
+        };
+
+        let mut data = vec![0; entry.size as usize];
+        reader.read_exact(&mut data)?;
+        info.set_data(entry, &data);
+        restored[idx] = true;
+    }
+
+    for (entry, _) in
+        info.entries().iter().zip(restored).filter(|(it, restored)| it.is_persistent() && !restored)
+    {
+        log::warn!("Persistent variable `{}` is not restored", entry.get_name());
+    }
+
+    (info.restore)();
+    Ok(if layout_changed { RetainStatus::LayoutChanged } else { RetainStatus::Restored })
+}
+
+fn read_u32(reader: &mut impl Read) -> io::Result<u32> {
+    let mut bytes = [0; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_u64(reader: &mut impl Read) -> io::Result<u64> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+/// Writes the retained variables described by `info` to the file at `path`.
+/// Returns `RETAIN_OK` on success or `RETAIN_ERROR` if the file could not be written.
+///
+/// # Safety
+///
+/// `info` must point to the `__retain_info` descriptor and `path` to a null-terminated string
+#[allow(non_snake_case)]
+#[no_mangle]
+pub unsafe extern "C" fn __retain_write(info: *const RetainInfo, path: *const c_char) -> i32 {
+    let path = CStr::from_ptr(path).to_string_lossy();
+    match write_retain_file(&*info, Path::new(path.as_ref())) {
+        Ok(()) => RETAIN_OK,
+        Err(error) => {
+            log::warn!("Cannot write retained variables to `{path}`: {error}");
+            RETAIN_ERROR
+        }
+    }
+}
+
+/// Restores the retained variables described by `info` from the file at `path`.
+/// Returns `RETAIN_OK` if all variables were restored, `RETAIN_LAYOUT_CHANGED` if only the `PERSISTENT`
+/// variables were restored or `RETAIN_ERROR` if the file could not be read.
+///
+/// # Safety
+///
+/// `info` must point to the `__retain_info` descriptor and `path` to a null-terminated string
+#[allow(non_snake_case)]
+#[no_mangle]
+pub unsafe extern "C" fn __retain_read(info: *const RetainInfo, path: *const c_char) -> i32 {
+    let path = CStr::from_ptr(path).to_string_lossy();
+    match read_retain_file(&*info, Path::new(path.as_ref())) {
+        Ok(RetainStatus::Restored) => RETAIN_OK,
+        Ok(RetainStatus::LayoutChanged) => RETAIN_LAYOUT_CHANGED,
+        Err(error) => {
+            log::warn!("Cannot restore retained variables from `{path}`: {error}");
+            RETAIN_ERROR
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::{Cell, UnsafeCell};
+
+    use super::*;
+
+    // the retained variables and the image of a program with a `RETAIN` and a `PERSISTENT` variable
+    thread_local! {
+        static COUNTER: Cell<u32> = const { Cell::new(0) };
+        static TOTAL: Cell<u64> = const { Cell::new(0) };
+        static IMAGE: UnsafeCell<[u8; 16]> = const { UnsafeCell::new([0; 16]) };
+    }
+
+    extern "C" fn save() {
+        IMAGE.with(|image| {
+            let image = unsafe { &mut *image.get() };
+            image[0..4].copy_from_slice(&COUNTER.get().to_le_bytes());
+            image[8..16].copy_from_slice(&TOTAL.get().to_le_bytes());
+        })
+    }
+
+    extern "C" fn restore() {
+        IMAGE.with(|image| {
+            let image = unsafe { &*image.get() };
+            COUNTER.set(u32::from_le_bytes(image[0..4].try_into().unwrap()));
+            TOTAL.set(u64::from_le_bytes(image[8..16].try_into().unwrap()));
+        })
+    }
+
+    #[repr(C)]
+    struct TestInfo {
+        info: RetainInfo,
+        entries: [RetainEntry; 2],
+    }
+
+    fn create_info(hash: u64, total_hash: u64) -> TestInfo {
+        TestInfo {
+            info: RetainInfo {
+                hash,
+                size: 16,
+                image: IMAGE.with(|it| it.get() as *mut u8),
+                save,
+                restore,
+                count: 2,
+                entries: [],
+            },
+            entries: [
+                RetainEntry { name: c"counter".as_ptr(), hash: 1, offset: 0, size: 4, flags: 1 },
+                RetainEntry { name: c"total".as_ptr(), hash: total_hash, offset: 8, size: 8, flags: 2 },
+            ],
+        }
+    }
+
+    fn temp_file(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("{name}_{}.retain", std::process::id()))
+    }
+
+    #[test]
+    fn retained_variables_are_restored_from_a_file() {
+        let path = temp_file("restored");
+        let info = create_info(10, 2);
+        COUNTER.set(42);
+        TOTAL.set(7);
+        write_retain_file(&info.info, &path).unwrap();
+
+        COUNTER.set(0);
+        TOTAL.set(0);
+        assert_eq!(read_retain_file(&info.info, &path).unwrap(), RetainStatus::Restored);
+        assert_eq!((COUNTER.get(), TOTAL.get()), (42, 7));
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn only_persistent_variables_are_restored_after_a_layout_change() {
+        let path = temp_file("layout_changed");
+        COUNTER.set(42);
+        TOTAL.set(7);
+        write_retain_file(&create_info(10, 2).info, &path).unwrap();
+
+        COUNTER.set(1);
+        TOTAL.set(1);
+        assert_eq!(read_retain_file(&create_info(11, 2).info, &path).unwrap(), RetainStatus::LayoutChanged);
+        assert_eq!((COUNTER.get(), TOTAL.get()), (1, 7));
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn variables_with_a_changed_type_are_not_restored() {
+        let path = temp_file("type_changed");
+        COUNTER.set(42);
+        TOTAL.set(7);
+        write_retain_file(&create_info(10, 2).info, &path).unwrap();
+
+        COUNTER.set(1);
+        TOTAL.set(1);
+        assert_eq!(read_retain_file(&create_info(11, 3).info, &path).unwrap(), RetainStatus::LayoutChanged);
+        assert_eq!((COUNTER.get(), TOTAL.get()), (1, 1));
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn invalid_files_are_not_restored() {
+        let path = temp_file("invalid");
+        fs::write(&path, b"not a retain file").unwrap();
+
+        COUNTER.set(1);
+        let path_name = std::ffi::CString::new(path.to_str().unwrap()).unwrap();
+        let result = unsafe { __retain_read(&create_info(10, 2).info, path_name.as_ptr()) };
+        assert_eq!(result, RETAIN_ERROR);
+        assert_eq!(COUNTER.get(), 1);
+        fs::remove_file(path).unwrap();
+    }
+
+    /// Returns the header of a retain file followed by an entry without its data
+    fn create_file_header(name: &[u8], name_len: u32, size: u64) -> Vec<u8> {
+        let mut content = MAGIC.to_vec();
+        content.extend(10u64.to_le_bytes());
+        content.extend(1u32.to_le_bytes());
+        content.extend(name_len.to_le_bytes());
+        content.extend(name);
+        content.extend(1u64.to_le_bytes());
+        content.extend(1u32.to_le_bytes());
+        content.extend(size.to_le_bytes());
+        content
+    }
+
+    #[test]
+    fn sizes_read_from_a_file_are_checked_before_allocating() {
+        let path = temp_file("oversized");
+        COUNTER.set(1);
+
+        // an unknown entry claiming more data than the file holds is skipped until the end of the file
+        fs::write(&path, create_file_header(b"unknown", 7, u64::MAX)).unwrap();
+        let error = read_retain_file(&create_info(10, 2).info, &path).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+
+        // a known entry with a different size is skipped as well
+        fs::write(&path, create_file_header(b"counter", 7, u64::MAX)).unwrap();
+        let error = read_retain_file(&create_info(10, 2).info, &path).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+
+        fs::write(&path, create_file_header(b"", u32::MAX, 4)).unwrap();
+        let error = read_retain_file(&create_info(10, 2).info, &path).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+        assert_eq!(COUNTER.get(), 1);
+        fs::remove_file(path).unwrap();
+    }
+}
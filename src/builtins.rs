@@ -1,7 +1,8 @@
 use inkwell::{
     basic_block::BasicBlock,
     types::BasicType,
-    values::{BasicValue, IntValue},
+    values::{BasicValue, FunctionValue, IntValue, PointerValue},
+    AddressSpace, IntPredicate,
 };
 use lazy_static::lazy_static;
 use plc_ast::{
@@ -14,21 +15,35 @@ use plc_ast::{
 };
 use plc_diagnostics::diagnostics::Diagnostic;
 use plc_source::source_location::{SourceLocation, SourceLocationFactory};
+use plc_util::convention::RAISE_FUNCTION_NAME;
 use rustc_hash::FxHashMap;
 
 use crate::{
-    codegen::generators::expression_generator::{self, ExpressionCodeGenerator, ExpressionValue},
-    index::Index,
+    codegen::generators::{
+        allocator_generator::{ALLOCATE_FUNCTION_NAME, FREE_FUNCTION_NAME},
+        expression_generator::{self, ExpressionCodeGenerator, ExpressionValue},
+        type_info_generator::{
+            get_reflected_members, get_type_class, get_type_id, get_type_info_name, get_type_name,
+            has_type_info,
+        },
+        ADDRESS_SPACE_GENERIC,
+    },
+    index::{get_init_fn_name, Index},
     lexer, parser,
     resolver::{
         self,
         generics::{generic_name_resolver, no_generic_name_resolver, GenericType},
         AnnotationMap, StatementAnnotation, TypeAnnotator, VisitorContext,
     },
-    typesystem::{self, get_bigger_type, get_literal_actual_signed_type_name, DataTypeInformationProvider},
+    typesystem::{
+        self, get_bigger_type, get_literal_actual_signed_type_name, DataType, DataTypeInformationProvider,
+    },
     validation::{statement::validate_type_compatibility, Validator, Validators},
 };
 
+/// The file name of the compilation unit declaring the builtins
+pub(crate) const BUILTIN_UNIT_NAME: &str = "<builtin>";
+
 // Defines a set of functions that are always included in a compiled application
 lazy_static! {
     static ref BUILTIN: FxHashMap<&'static str, BuiltIn> = FxHashMap::from_iter([
@@ -273,6 +288,157 @@ lazy_static! {
                 }
             }
         ),
+        (
+            "__NEW",
+            BuiltIn {
+                decl: "FUNCTION __NEW<U: ANY> : LWORD
+                VAR_INPUT
+                    data_type : U;
+                    count : ULINT;
+                END_VAR
+                END_FUNCTION",
+                annotation: Some(|annotator, _, operator, parameters, _| {
+                    // invalid parameters are reported during validation
+                    let Some(params) = parameters else { return; };
+                    let params = flatten_expression_list(params);
+                    let Some(StatementAnnotation::Type { type_name }) =
+                        params.first().and_then(|it| annotator.annotation_map.get(it)).cloned()
+                    else {
+                        return;
+                    };
+
+                    // the instances are initialized by the type's init-function, which is not called in the AST
+                    annotator.add_call_dependency(&get_init_fn_name(&type_name));
+                    annotator.add_call_dependency(ALLOCATE_FUNCTION_NAME);
+                    let ptr_type = resolver::add_pointer_type(&mut annotator.annotation_map.new_index, type_name);
+                    annotator.annotate(
+                        operator, StatementAnnotation::Function {
+                            return_type: ptr_type, qualified_name: "__NEW".to_string(), generic_name: None, call_name: None
+                        }
+                    );
+                }),
+                validation: Some(|validator, operator, parameters, annotations, index| {
+                    validate_allocation(validator, operator, parameters, annotations, index)
+                }),
+                generic_name_resolver: no_generic_name_resolver,
+                code: |generator, params, location| {
+                    match params {
+                        [data_type] => generate_allocation(generator, data_type, None, location),
+                        [data_type, count] => generate_allocation(generator, data_type, Some(count), location),
+                        _ => Err(Diagnostic::codegen_error(
+                            "Expected a data type and an optional count for __NEW",
+                            location,
+                        )),
+                    }
+                }
+            }
+        ),
+        (
+            "__DELETE",
+            BuiltIn {
+                decl: "FUNCTION __DELETE<U: ANY>
+                VAR_IN_OUT
+                    ptr : U;
+                END_VAR
+                END_FUNCTION",
+                annotation: Some(|annotator, _, operator, parameters, ctx| {
+                    annotator.annotate_call_statement(operator, parameters, &ctx);
+                    annotator.add_call_dependency(FREE_FUNCTION_NAME);
+                }),
+                validation: Some(|validator, operator, parameters, annotations, index| {
+                    validate_argument_count(validator, operator, &parameters, 1);
+                    let params = parameters.map(flatten_expression_list).unwrap_or_default();
+                    let Some(pointer) = params.first() else { return; };
+
+                    let pointer_type = annotations.get_type_or_void(pointer, index);
+                    if !pointer_type.get_type_information().is_pointer() {
+                        validator.push_diagnostic(
+                            Diagnostic::new(format!(
+                                "Expected a pointer to release, found {}",
+                                pointer_type.get_name()
+                            ))
+                            .with_error_code("E129")
+                            .with_location(*pointer),
+                        );
+                    }
+                }),
+                generic_name_resolver: no_generic_name_resolver,
+                code: |generator, params, location| {
+                    let [pointer] = params else {
+                        return Err(Diagnostic::codegen_error(
+                            "Expected exactly one parameter for __DELETE",
+                            location,
+                        ));
+                    };
+                    let builder = &generator.llvm.builder;
+                    let free = find_allocator_function(generator, FREE_FUNCTION_NAME, &location)?;
+
+                    // release the memory and reset the pointer, so it cannot be released twice
+                    let reference = generator.generate_lvalue(pointer)?;
+                    let memory = generator.llvm.load_pointer(&reference, "").into_pointer_value();
+                    let argument = free.get_type().get_param_types()[0].into_pointer_type();
+                    builder.build_call(free, &[builder.build_pointer_cast(memory, argument, "").into()], "");
+                    builder.build_store(reference, memory.get_type().const_null());
+
+                    Ok(ExpressionValue::RValue(memory.get_type().const_null().as_basic_value_enum()))
+                }
+            }
+        ),
+        (
+            "__RAISE",
+            BuiltIn {
+                decl: "TYPE __EXCEPTION_CODE : DINT (
+                    EXCEPTION_NONE := 0,
+                    EXCEPTION_DIVIDE_BY_ZERO := 1,
+                    EXCEPTION_NULL_POINTER := 2,
+                    EXCEPTION_INVALID_CONVERSION := 3
+                );
+                END_TYPE
+
+                FUNCTION __RAISE
+                VAR_INPUT
+                    code : DINT;
+                END_VAR
+                END_FUNCTION",
+                annotation: Some(|annotator, _, operator, parameters, ctx| {
+                    annotator.annotate_call_statement(operator, parameters, &ctx);
+                    annotator.add_call_dependency(RAISE_FUNCTION_NAME);
+                }),
+                validation: Some(|validator, operator, parameters, _, _| {
+                    validate_argument_count(validator, operator, &parameters, 1);
+                }),
+                generic_name_resolver: no_generic_name_resolver,
+                code: |generator, params, location| {
+                    let [code] = params else {
+                        return Err(Diagnostic::codegen_error(
+                            "Expected exactly one parameter for __RAISE",
+                            location,
+                        ));
+                    };
+                    let raise =
+                        generator.llvm_index.find_associated_implementation(RAISE_FUNCTION_NAME).ok_or_else(|| {
+                            Diagnostic::codegen_error("No function associated to __plc_raise", location.clone())
+                        })?;
+
+                    // the raise function does not return, it resumes the innermost __TRY statement
+                    let builder = &generator.llvm.builder;
+                    let code = generator.generate_expression(code)?.into_int_value();
+                    let code = builder.build_int_cast(code, generator.llvm.i32_type(), "");
+                    builder.build_call(raise, &[code.into()], "");
+                    builder.build_unreachable();
+
+                    // any code following the call is dead, but still needs a block to be generated into
+                    let current_block = builder.get_insert_block().ok_or_else(|| {
+                        Diagnostic::codegen_error("Cannot raise outside of a function", location.clone())
+                    })?;
+                    let continue_block =
+                        generator.llvm.context.insert_basic_block_after(current_block, "after_raise");
+                    builder.position_at_end(continue_block);
+
+                    Ok(ExpressionValue::RValue(generator.llvm.i32_type().const_zero().as_basic_value_enum()))
+                }
+            }
+        ),
         (
             "LOWER_BOUND",
             BuiltIn {
@@ -319,6 +485,193 @@ lazy_static! {
                 }
             }
         ),
+        // Reflection functions
+        (
+            "__TYPEOF",
+            BuiltIn {
+                decl: "FUNCTION __TYPEOF<U: ANY> : STRING
+                VAR_INPUT
+                    in : U;
+                END_VAR
+                END_FUNCTION",
+                annotation: Some(|annotator, statement, operator, parameters, ctx| {
+                    annotate_type_reflection(annotator, statement, operator, parameters, ctx, None, |data_type, index| {
+                        AstLiteral::new_string(get_type_name(data_type, index), false)
+                    })
+                }),
+                validation: Some(|validator, operator, parameters, _, _| {
+                    validate_argument_count(validator, operator, &parameters, 1);
+                }),
+                generic_name_resolver: no_generic_name_resolver,
+                code: |_, _, location| {
+                    Err(Diagnostic::codegen_error("Cannot determine the type of the argument of __TYPEOF", location))
+                }
+            }
+        ),
+        (
+            "__TYPECLASS",
+            BuiltIn {
+                decl: "FUNCTION __TYPECLASS<U: ANY> : STRING
+                VAR_INPUT
+                    in : U;
+                END_VAR
+                END_FUNCTION",
+                annotation: Some(|annotator, statement, operator, parameters, ctx| {
+                    annotate_type_reflection(annotator, statement, operator, parameters, ctx, None, |data_type, index| {
+                        AstLiteral::new_string(get_type_class(data_type, index).to_string(), false)
+                    })
+                }),
+                validation: Some(|validator, operator, parameters, _, _| {
+                    validate_argument_count(validator, operator, &parameters, 1);
+                }),
+                generic_name_resolver: no_generic_name_resolver,
+                code: |_, _, location| {
+                    Err(Diagnostic::codegen_error("Cannot determine the type of the argument of __TYPECLASS", location))
+                }
+            }
+        ),
+        (
+            "__TYPEID",
+            BuiltIn {
+                decl: "FUNCTION __TYPEID<U: ANY> : UDINT
+                VAR_INPUT
+                    in : U;
+                END_VAR
+                END_FUNCTION",
+                annotation: Some(|annotator, statement, operator, parameters, ctx| {
+                    annotate_type_reflection(annotator, statement, operator, parameters, ctx, Some(typesystem::UDINT_TYPE), |data_type, index| {
+                        AstLiteral::new_integer(get_type_id(data_type, index).into())
+                    })
+                }),
+                validation: Some(|validator, operator, parameters, _, _| {
+                    validate_argument_count(validator, operator, &parameters, 1);
+                }),
+                generic_name_resolver: no_generic_name_resolver,
+                code: |_, _, location| {
+                    Err(Diagnostic::codegen_error("Cannot determine the type of the argument of __TYPEID", location))
+                }
+            }
+        ),
+        (
+            "__MEMBER_COUNT",
+            BuiltIn {
+                decl: "FUNCTION __MEMBER_COUNT<U: ANY> : DINT
+                VAR_INPUT
+                    in : U;
+                END_VAR
+                END_FUNCTION",
+                annotation: Some(|annotator, statement, operator, parameters, ctx| {
+                    annotate_type_reflection(annotator, statement, operator, parameters, ctx, Some(typesystem::DINT_TYPE), |data_type, index| {
+                        AstLiteral::new_integer(get_reflected_members(data_type, index).len() as i128)
+                    })
+                }),
+                validation: Some(|validator, operator, parameters, _, _| {
+                    validate_argument_count(validator, operator, &parameters, 1);
+                }),
+                generic_name_resolver: no_generic_name_resolver,
+                code: |_, _, location| {
+                    Err(Diagnostic::codegen_error("Cannot determine the type of the argument of __MEMBER_COUNT", location))
+                }
+            }
+        ),
+        (
+            "__TYPEINFO",
+            BuiltIn {
+                decl: "TYPE __TYPE_INFO : STRUCT
+                    name : STRING;
+                    type_id : UDINT;
+                    size : ULINT;
+                    member_count : DINT;
+                END_STRUCT
+                END_TYPE
+
+                TYPE __TYPE_MEMBER : STRUCT
+                    name : STRING;
+                    offset : ULINT;
+                    type_id : UDINT;
+                    size : ULINT;
+                END_STRUCT
+                END_TYPE
+
+                FUNCTION __TYPEINFO<U: ANY> : REF_TO __TYPE_INFO
+                VAR_INPUT
+                    in : U;
+                END_VAR
+                END_FUNCTION",
+                annotation: Some(|annotator, _, operator, parameters, ctx| {
+                    annotator.annotate_call_statement(operator, parameters, &ctx);
+                    annotate_type_info_dependency(annotator, parameters);
+                }),
+                validation: Some(|validator, operator, parameters, annotations, index| {
+                    validate_argument_count(validator, operator, &parameters, 1);
+                    validate_type_info(validator, parameters, annotations, index);
+                }),
+                generic_name_resolver: no_generic_name_resolver,
+                code: |generator, params, location| {
+                    let [argument] = params else {
+                        return Err(Diagnostic::codegen_error(
+                            "Expected exactly one parameter for __TYPEINFO",
+                            location,
+                        ));
+                    };
+                    let (table, _) = find_type_info(generator, argument, &location)?;
+                    let i32_type = generator.llvm.i32_type();
+                    // SAFETY: the table starts with the header describing the type
+                    let header = unsafe { table.const_in_bounds_gep(&[i32_type.const_zero(), i32_type.const_zero()]) };
+
+                    Ok(ExpressionValue::RValue(header.as_basic_value_enum()))
+                }
+            }
+        ),
+        (
+            "__MEMBER_INFO",
+            BuiltIn {
+                decl: "FUNCTION __MEMBER_INFO<U: ANY, T: ANY_INT> : REF_TO __TYPE_MEMBER
+                VAR_INPUT
+                    in : U;
+                    idx : T;
+                END_VAR
+                END_FUNCTION",
+                annotation: Some(|annotator, _, operator, parameters, ctx| {
+                    annotator.annotate_call_statement(operator, parameters, &ctx);
+                    annotate_type_info_dependency(annotator, parameters);
+                }),
+                validation: Some(|validator, operator, parameters, annotations, index| {
+                    validate_argument_count(validator, operator, &parameters, 2);
+                    validate_type_info(validator, parameters, annotations, index);
+                }),
+                generic_name_resolver: no_generic_name_resolver,
+                code: |generator, params, location| {
+                    let [argument, position] = params else {
+                        return Err(Diagnostic::codegen_error(
+                            "Expected exactly two parameters for __MEMBER_INFO",
+                            location,
+                        ));
+                    };
+                    let (table, data_type) = find_type_info(generator, argument, &location)?;
+                    let count = get_reflected_members(data_type, generator.index).len() as u64;
+
+                    // the members are numbered from 1, any other position results in NULL
+                    let builder = &generator.llvm.builder;
+                    let i32_type = generator.llvm.i32_type();
+                    let size_type = generator.llvm.context.i64_type();
+                    let position = generator.generate_expression(position)?.into_int_value();
+                    let member = builder.build_int_sub(
+                        builder.build_int_cast(position, size_type, ""),
+                        size_type.const_int(1, false),
+                        "",
+                    );
+                    let is_member = builder.build_int_compare(IntPredicate::ULT, member, size_type.const_int(count, false), "");
+                    let member = builder.build_select(is_member, member, size_type.const_zero(), "").into_int_value();
+                    let entry = unsafe {
+                        builder.build_in_bounds_gep(table, &[i32_type.const_zero(), i32_type.const_int(1, false), member], "")
+                    };
+                    let entry = builder.build_select(is_member, entry, entry.get_type().const_null(), "");
+
+                    Ok(ExpressionValue::RValue(entry))
+                }
+            }
+        ),
         // Arithmetic functions
         (
             "ADD",
@@ -808,6 +1161,118 @@ fn validate_variable_length_array_bound_function(
     }
 }
 
+/// Replaces the call of a reflection function with the literal describing the type of its argument, which
+/// is either a value or the name of a type, e.g. `__TYPEOF(x)` or `__TYPEOF(Point)`
+fn annotate_type_reflection(
+    annotator: &mut TypeAnnotator,
+    statement: &AstNode,
+    operator: &AstNode,
+    parameters: Option<&AstNode>,
+    ctx: VisitorContext,
+    result_type: Option<&str>,
+    reflect: fn(&DataType, &Index) -> AstLiteral,
+) {
+    let params = parameters.map(flatten_expression_list).unwrap_or_default();
+    let literal = match params.as_slice() {
+        [argument] => annotator
+            .annotation_map
+            .get_type(argument, annotator.index)
+            .or_else(|| annotator.annotation_map.get_type(argument, &annotator.annotation_map.new_index))
+            .filter(|it| !it.is_void())
+            .map(|it| reflect(it, annotator.index)),
+        _ => None,
+    };
+    let Some(literal) = literal else {
+        // invalid arguments are reported during validation
+        annotator.annotate_call_statement(operator, parameters, &ctx);
+        return;
+    };
+
+    let mut ctx = ctx;
+    let new_statement =
+        AstFactory::create_literal(literal, statement.get_location(), ctx.id_provider.next_id());
+    annotator.visit_statement(&ctx, &new_statement);
+    // string literals keep the type of their length, they would be copied beyond their end as a `STRING`
+    if let Some(result_type) = result_type {
+        annotator.update_expected_types(annotator.index.get_type_or_panic(result_type), &new_statement);
+    }
+    annotator.annotate(statement, StatementAnnotation::ReplacementAst { statement: new_statement });
+    if let Some(result_type) = result_type {
+        annotator.update_expected_types(annotator.index.get_type_or_panic(result_type), statement);
+    }
+}
+
+/// Returns the type whose type information is requested by the first argument of `__TYPEINFO` or
+/// `__MEMBER_INFO`, if there is one
+fn get_type_with_type_info<'idx>(
+    argument: &AstNode,
+    annotations: &'idx dyn AnnotationMap,
+    index: &'idx Index,
+) -> Option<&'idx DataType> {
+    annotations
+        .get_type(argument, index)
+        .and_then(|it| index.find_effective_type(it))
+        .filter(|it| has_type_info(it, index))
+}
+
+fn annotate_type_info_dependency(annotator: &mut TypeAnnotator, parameters: Option<&AstNode>) {
+    let params = parameters.map(flatten_expression_list).unwrap_or_default();
+    let Some(type_name) = params
+        .first()
+        .and_then(|it| get_type_with_type_info(it, &annotator.annotation_map, annotator.index))
+        .map(|it| it.get_name().to_string())
+    else {
+        // types without type information are reported during validation
+        return;
+    };
+
+    annotator.add_type_info_dependency(&type_name);
+}
+
+fn validate_type_info(
+    validator: &mut Validator,
+    parameters: Option<&AstNode>,
+    annotations: &dyn AnnotationMap,
+    index: &Index,
+) {
+    let params = parameters.map(flatten_expression_list).unwrap_or_default();
+    let Some(argument) = params.first() else { return };
+
+    if get_type_with_type_info(argument, annotations, index).is_none() {
+        validator.push_diagnostic(
+            Diagnostic::new(format!(
+                "`{}` has no type information, expected a struct, union, function block, class or program",
+                annotations.get_type_or_void(argument, index).get_name()
+            ))
+            .with_error_code("E144")
+            .with_location(*argument),
+        );
+    }
+}
+
+/// Returns the type information table of the type of the given argument of `__TYPEINFO` or `__MEMBER_INFO`
+fn find_type_info<'ink, 'b>(
+    generator: &'b ExpressionCodeGenerator<'ink, 'b>,
+    argument: &AstNode,
+    location: &SourceLocation,
+) -> Result<(PointerValue<'ink>, &'b DataType), Diagnostic> {
+    let data_type =
+        get_type_with_type_info(argument, generator.annotations, generator.index).ok_or_else(|| {
+            Diagnostic::codegen_error("Cannot determine the type information of the argument", location)
+        })?;
+    let table = generator
+        .llvm_index
+        .find_global_value(&get_type_info_name(data_type.get_name()))
+        .ok_or_else(|| {
+            Diagnostic::codegen_error(
+                format!("No type information associated to {}", data_type.get_name()),
+                location,
+            )
+        })?;
+
+    Ok((table.as_pointer_value(), data_type))
+}
+
 fn validate_argument_count(
     validator: &mut Validator,
     operator: &AstNode,
@@ -826,6 +1291,159 @@ fn validate_argument_count(
     }
 }
 
+fn validate_allocation(
+    validator: &mut Validator,
+    operator: &AstNode,
+    parameters: Option<&AstNode>,
+    annotations: &dyn AnnotationMap,
+    index: &Index,
+) {
+    let params = parameters.map(flatten_expression_list).unwrap_or_default();
+    let Some(data_type) = params.first() else {
+        validator.push_diagnostic(Diagnostic::invalid_argument_count(1, 0, operator));
+        return;
+    };
+
+    if params.len() > 2 {
+        validator.push_diagnostic(Diagnostic::invalid_argument_count(2, params.len(), operator));
+    }
+
+    if !matches!(annotations.get(data_type), Some(StatementAnnotation::Type { .. })) {
+        validator.push_diagnostic(
+            Diagnostic::new("Expected the data type of the allocated instances")
+                .with_error_code("E129")
+                .with_location(*data_type),
+        );
+    }
+
+    if let Some(count) = params.get(1) {
+        let count_type = annotations.get_type_or_void(count, index);
+        if !count_type.has_nature(TypeNature::Int, index) {
+            validator.push_diagnostic(
+                Diagnostic::new(format!(
+                    "Invalid type nature for generic argument. {} is no {}",
+                    count_type.get_name(),
+                    TypeNature::Int
+                ))
+                .with_error_code("E062")
+                .with_location(*count),
+            )
+        }
+    }
+}
+
+/// Returns the allocator function with the given name, see `allocator_generator::generate_allocator`
+fn find_allocator_function<'ink>(
+    generator: &ExpressionCodeGenerator<'ink, '_>,
+    name: &str,
+    location: &SourceLocation,
+) -> Result<FunctionValue<'ink>, Diagnostic> {
+    generator.llvm_index.find_associated_implementation(name).ok_or_else(|| {
+        Diagnostic::codegen_error(format!("No allocator function associated to {name:?}"), location)
+    })
+}
+
+/// Generates the code for the __NEW built-in function: the memory for `count` instances of the given data type
+/// is allocated through the allocator and every instance is initialized like a variable of that type. Returns
+/// a pointer to the first instance, which is `NULL` if the allocation failed or the size of the instances
+/// overflows.
+fn generate_allocation<'ink>(
+    generator: &ExpressionCodeGenerator<'ink, '_>,
+    data_type: &AstNode,
+    count: Option<&&AstNode>,
+    location: SourceLocation,
+) -> Result<ExpressionValue<'ink>, Diagnostic> {
+    let llvm = generator.llvm;
+    let builder = &llvm.builder;
+    let Some(StatementAnnotation::Type { type_name }) = generator.annotations.get(data_type) else {
+        return Err(Diagnostic::codegen_error("Expected a data type for __NEW", location));
+    };
+    let type_name = generator.index.get_effective_type_or_void_by_name(type_name).get_name();
+    let llvm_type = generator.llvm_index.get_associated_type(type_name)?;
+    let allocate = find_allocator_function(generator, ALLOCATE_FUNCTION_NAME, &location)?;
+
+    let size_type = allocate.get_type().get_param_types()[0].into_int_type();
+    let count = match count {
+        Some(count) => {
+            builder.build_int_cast(generator.generate_expression(count)?.into_int_value(), size_type, "")
+        }
+        None => size_type.const_int(1, false),
+    };
+    let size = llvm_type
+        .size_of()
+        .map(|it| builder.build_int_cast(it, size_type, ""))
+        .ok_or_else(|| Diagnostic::codegen_error("Data type is not sized.", &location))?;
+
+    // the allocator is not called if the size of all instances does not fit into its parameter
+    let function = generator.get_function_context(data_type)?.function;
+    let entry_block = builder.get_insert_block().expect("Builder should have a block at this point");
+    let allocate_block = llvm.context.append_basic_block(function, "allocate");
+    let init_block = llvm.context.append_basic_block(function, "init");
+    let continue_block = llvm.context.append_basic_block(function, "continue");
+    let divisor = builder.build_select(
+        builder.build_int_compare(IntPredicate::EQ, size, size_type.const_zero(), ""),
+        size_type.const_int(1, false),
+        size,
+        "",
+    );
+    let max_count = builder.build_int_unsigned_div(size_type.const_all_ones(), divisor.into_int_value(), "");
+    let overflows = builder.build_int_compare(IntPredicate::UGT, count, max_count, "");
+    builder.build_conditional_branch(overflows, continue_block, allocate_block);
+
+    builder.position_at_end(allocate_block);
+    let memory = builder
+        .build_call(allocate, &[builder.build_int_mul(size, count, "").into()], "")
+        .try_as_basic_value()
+        .left()
+        .ok_or_else(|| Diagnostic::codegen_error("The allocator does not return a pointer", &location))?
+        .into_pointer_value();
+    let pointer_type = llvm_type.ptr_type(AddressSpace::from(ADDRESS_SPACE_GENERIC));
+    let instances = builder.build_pointer_cast(memory, pointer_type, "");
+
+    // initialize the instances one after another, unless the allocation failed
+    let is_empty = builder.build_or(
+        builder.build_is_null(memory, ""),
+        builder.build_int_compare(IntPredicate::EQ, count, size_type.const_zero(), ""),
+        "",
+    );
+    builder.build_conditional_branch(is_empty, continue_block, init_block);
+
+    builder.position_at_end(init_block);
+    let position = builder.build_phi(size_type, "");
+    position.add_incoming(&[(&size_type.const_zero(), allocate_block)]);
+    let position_value = position.as_basic_value().into_int_value();
+    let instance = unsafe { builder.build_in_bounds_gep(instances, &[position_value], "") };
+    llvm.generate_variable_initializer(
+        generator.llvm_index,
+        generator.index,
+        (type_name, type_name, &location),
+        instance,
+        None,
+        generator,
+    )?;
+    if let Some(init) = generator.llvm_index.find_associated_implementation(&get_init_fn_name(type_name)) {
+        let argument = init.get_type().get_param_types()[0].into_pointer_type();
+        builder.build_call(init, &[builder.build_pointer_cast(instance, argument, "").into()], "");
+    }
+    let next = builder.build_int_add(position_value, size_type.const_int(1, false), "");
+    let last_init_block = builder.get_insert_block().expect("Builder should have a block");
+    position.add_incoming(&[(&next, last_init_block)]);
+    builder.build_conditional_branch(
+        builder.build_int_compare(IntPredicate::ULT, next, count, ""),
+        init_block,
+        continue_block,
+    );
+
+    builder.position_at_end(continue_block);
+    let result = builder.build_phi(pointer_type, "");
+    result.add_incoming(&[
+        (&pointer_type.const_null(), entry_block),
+        (&instances, allocate_block),
+        (&instances, last_init_block),
+    ]);
+    Ok(ExpressionValue::RValue(result.as_basic_value()))
+}
+
 /// Generates the code for the LOWER- AND UPPER_BOUND built-in functions, returning an error if the function
 /// arguments are incorrect.
 fn generate_variable_length_array_bound_function<'ink>(
@@ -943,11 +1561,27 @@ pub fn parse_built_ins(id_provider: IdProvider) -> CompilationUnit {
     let mut unit = parser::parse(
         lexer::lex_with_ids(&src, id_provider.clone(), SourceLocationFactory::internal(&src)),
         LinkageType::BuiltIn,
-        "<builtin>",
+        BUILTIN_UNIT_NAME,
     )
     .0;
 
     pre_process(&mut unit, id_provider);
+
+    // the variants of builtin enums and the initializers of builtin structs are constants propagated into the
+    // units referring to them, none of these units declares them
+    for user_type in &mut unit.user_types {
+        let location = SourceLocation::internal_in_unit(Some(BUILTIN_UNIT_NAME));
+        match &mut user_type.data_type {
+            ast::DataType::EnumType { elements, .. } => match elements.get_stmt_mut() {
+                AstStatement::ExpressionList(variants) => {
+                    variants.iter_mut().for_each(|it| it.set_location(location.clone()))
+                }
+                _ => elements.set_location(location),
+            },
+            ast::DataType::StructType { .. } => user_type.location = location,
+            _ => {}
+        }
+    }
     unit
 }
 
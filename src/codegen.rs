@@ -11,9 +11,10 @@ use std::{
 use self::{
     debug::{Debug, DebugBuilderEnum},
     generators::{
-        data_type_generator,
+        allocator_generator, data_type_generator, enum_generator, exception_generator,
         llvm::{GlobalValueExt, Llvm},
         pou_generator::{self, PouGenerator},
+        retain_generator, task_generator, type_info_generator,
         variable_generator::VariableGenerator,
     },
     llvm_index::LlvmTypedIndex,
@@ -77,6 +78,8 @@ pub struct CodeGen<'ink> {
     pub debug: DebugBuilderEnum<'ink>,
     /// Whether we are generating a hot-reloadable binary or not
     pub online_change: OnlineChange,
+    /// Whether integer divisions and pointer dereferences raise an exception if they fail
+    pub runtime_checks: bool,
 
     pub module_location: String,
 }
@@ -99,11 +102,12 @@ impl<'ink> CodeGen<'ink> {
         optimization_level: OptimizationLevel,
         debug_level: DebugLevel,
         online_change: OnlineChange,
+        runtime_checks: bool,
     ) -> CodeGen<'ink> {
         let module = context.create_module(module_location);
         module.set_source_file_name(module_location);
         let debug = debug::DebugBuilderEnum::new(context, &module, root, optimization_level, debug_level);
-        CodeGen { module, debug, module_location: module_location.to_string(), online_change }
+        CodeGen { module, debug, module_location: module_location.to_string(), online_change, runtime_checks }
     }
 
     pub fn generate_llvm_index(
@@ -119,6 +123,7 @@ impl<'ink> CodeGen<'ink> {
         let mut index = LlvmTypedIndex::default();
         //Generate types index, and any global variables associated with them.
         let llvm_type_index = data_type_generator::generate_data_types(
+            &self.module,
             &llvm,
             &mut self.debug,
             dependencies,
@@ -248,6 +253,35 @@ impl<'ink> CodeGen<'ink> {
             &self.module_location,
         )?;
         index.merge(llvm_values_index);
+        let llvm_vtable_index = pou_generator::generate_vtables(
+            &self.module,
+            &llvm,
+            dependencies,
+            global_index,
+            &index,
+            &self.module_location,
+        )?;
+        index.merge(llvm_vtable_index);
+        let llvm_allocator_index =
+            allocator_generator::generate_allocator(&self.module, &llvm, dependencies, &index)?;
+        index.merge(llvm_allocator_index);
+        let llvm_exception_index = exception_generator::generate_exception_runtime(
+            &self.module,
+            &llvm,
+            dependencies,
+            &index,
+            self.runtime_checks,
+        )?;
+        index.merge(llvm_exception_index);
+        let llvm_type_info_index = type_info_generator::generate_type_infos(
+            &self.module,
+            &llvm,
+            dependencies,
+            global_index,
+            &index,
+        )?;
+        index.merge(llvm_type_info_index);
+        enum_generator::generate_enum_conversions(&self.module, &llvm, dependencies, global_index, &index)?;
 
         //Generate constants for string-literal
         //generate literals but first sort, so we get reproducable builds
@@ -293,12 +327,13 @@ impl<'ink> CodeGen<'ink> {
         unit: &CompilationUnit,
         annotations: &AstAnnotations,
         global_index: &Index,
-        llvm_index: LlvmTypedIndex,
+        llvm_index: LlvmTypedIndex<'ink>,
     ) -> Result<GeneratedModule<'ink>, Diagnostic> {
         //generate all pous
         let llvm = Llvm::new(context, context.create_builder());
         let pou_generator =
-            PouGenerator::new(llvm, global_index, annotations, &llvm_index, &self.online_change);
+            PouGenerator::new(llvm, global_index, annotations, &llvm_index, &self.online_change)
+                .with_runtime_checks(self.runtime_checks);
 
         //Generate the POU stubs in the first go to make sure they can be referenced.
         for implementation in &unit.implementations {
@@ -310,6 +345,13 @@ impl<'ink> CodeGen<'ink> {
             }
         }
 
+        //Generate the task table of the configuration declared in this unit
+        let llvm = Llvm::new(context, context.create_builder());
+        task_generator::generate_task_table(&self.module, &llvm, unit, global_index, &llvm_index)?;
+
+        //Generate the image of the retained variables if this unit declares any
+        retain_generator::generate_retain_image(&self.module, &llvm, unit, global_index, &llvm_index)?;
+
         let location = PathBuf::from(&unit.file_name);
 
         self.debug.finalize();
@@ -423,6 +465,16 @@ impl<'ink> GeneratedModule<'ink> {
 
         let triple = target.get_target_triple();
 
+        let triple_name = triple.as_str().to_string_lossy();
+        if self.module.get_function(exception_generator::SETJMP_FUNCTION_NAME).is_some()
+            && !exception_generator::is_supported_target(&triple_name)
+        {
+            return Err(Diagnostic::new(format!(
+                "Exception handling is not supported for target '{triple_name}'"
+            ))
+            .with_error_code("E148"));
+        }
+
         let target = inkwell::targets::Target::from_triple(&triple).map_err(|it| {
             Diagnostic::codegen_error(
                 format!("Invalid target-tripple '{triple}' - {it:?}"),
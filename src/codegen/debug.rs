@@ -275,16 +275,20 @@ impl<'ink> DebugBuilder<'ink> {
             .map(|it| self.get_or_create_debug_file(it))
             .unwrap_or_else(|| self.compile_unit.get_file());
 
+        let struct_dt = index.get_type_information_or_void(name);
+        let is_union = struct_dt.is_union();
+
         let mut types = vec![];
         let mut running_offset = MemoryLocation::new(0);
         for (member_name, dt, location) in index_types.into_iter() {
             let di_type = self.get_or_create_debug_type(dt, index)?;
 
-            //Adjust the offset based on the field alignment
+            //Adjust the offset based on the field alignment, union members all start at offset 0
             let type_info = dt.get_type_information();
             let alignment = type_info.get_alignment(index);
             let size = type_info.get_size(index);
-            running_offset = running_offset.align_to(alignment);
+            running_offset =
+                if is_union { MemoryLocation::new(0) } else { running_offset.align_to(alignment) };
 
             types.push(
                 self.debug_info
@@ -304,7 +308,22 @@ impl<'ink> DebugBuilder<'ink> {
             running_offset += size;
         }
 
-        let struct_dt = index.get_type_information_or_void(name);
+        if is_union {
+            let union_type = self.debug_info.create_union_type(
+                file.as_debug_info_scope(),
+                name,
+                file,
+                location.get_line_plus_one() as u32,
+                struct_dt.get_size(index).bits().into(),
+                struct_dt.get_alignment(index).bits(),
+                DIFlags::PUBLIC,
+                types.as_slice(),
+                0,
+                name,
+            );
+            self.register_concrete_type(name, DebugType::Composite(union_type));
+            return Ok(());
+        }
 
         //Create a struct type
         let struct_type = self.debug_info.create_struct_type(
@@ -1,9 +1,15 @@
+pub mod allocator_generator;
 pub mod data_type_generator;
+pub mod enum_generator;
+pub mod exception_generator;
 pub mod expression_generator;
 pub mod llvm;
 pub mod pou_generator;
+pub mod retain_generator;
 pub mod section_names;
 pub mod statement_generator;
+pub mod task_generator;
+pub mod type_info_generator;
 pub mod variable_generator;
 
 // See
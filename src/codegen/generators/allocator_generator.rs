@@ -0,0 +1,69 @@
+use inkwell::{
+    module::{Linkage, Module},
+    values::BasicValue,
+    AddressSpace,
+};
+use plc_diagnostics::diagnostics::Diagnostic;
+
+use super::{llvm::Llvm, ADDRESS_SPACE_GENERIC};
+use crate::{codegen::llvm_index::LlvmTypedIndex, index::FxIndexSet, resolver::Dependency};
+
+/// The symbol of the function allocating the memory of `__NEW`, i.e. `void* __plc_alloc(uint64_t size)`
+pub const ALLOCATE_FUNCTION_NAME: &str = "__plc_alloc";
+
+/// The symbol of the function releasing the memory of `__DELETE`, i.e. `void __plc_free(void* memory)`
+pub const FREE_FUNCTION_NAME: &str = "__plc_free";
+
+/// Associates the allocator with the module if the unit calls `__NEW` or `__DELETE`. An allocator declared in
+/// the project, e.g. as an `{external}` function, is called as is. Otherwise a weak default forwarding to
+/// `malloc` and `free` is generated, which is replaced at link time by any other definition of the symbol,
+/// e.g. the memory pool of a bare-metal target:
+/// ```llvm
+/// define weak i8* @__plc_alloc(i64 %0) {
+/// entry:
+///   %1 = call i8* @malloc(i64 %0)
+///   ret i8* %1
+/// }
+/// ```
+pub fn generate_allocator<'ink>(
+    module: &Module<'ink>,
+    llvm: &Llvm<'ink>,
+    dependencies: &FxIndexSet<Dependency>,
+    llvm_index: &LlvmTypedIndex<'ink>,
+) -> Result<LlvmTypedIndex<'ink>, Diagnostic> {
+    let mut index = LlvmTypedIndex::default();
+    let is_called = |name: &str| dependencies.contains(&Dependency::Call(name.to_string()));
+    if !is_called("__NEW") && !is_called("__DELETE") {
+        return Ok(index);
+    }
+
+    let context = llvm.context;
+    let pointer_type = context.i8_type().ptr_type(AddressSpace::from(ADDRESS_SPACE_GENERIC));
+    let size_type = context.i64_type();
+    let allocate = pointer_type.fn_type(&[size_type.into()], false);
+    let free = context.void_type().fn_type(&[pointer_type.into()], false);
+
+    for (name, target, function_type) in
+        [(ALLOCATE_FUNCTION_NAME, "malloc", allocate), (FREE_FUNCTION_NAME, "free", free)]
+    {
+        if llvm_index.find_associated_implementation(name).is_some() {
+            continue;
+        }
+
+        let function = module.get_function(name).unwrap_or_else(|| {
+            let function = module.add_function(name, function_type, Some(Linkage::WeakAny));
+            let target = module
+                .get_function(target)
+                .unwrap_or_else(|| module.add_function(target, function_type, Some(Linkage::External)));
+
+            llvm.builder.position_at_end(context.append_basic_block(function, "entry"));
+            let arguments = function.get_param_iter().map(Into::into).collect::<Vec<_>>();
+            let result = llvm.builder.build_call(target, &arguments, "").try_as_basic_value().left();
+            llvm.builder.build_return(result.as_ref().map(|it| it as &dyn BasicValue));
+            function
+        });
+        index.associate_implementation(name, function)?;
+    }
+
+    Ok(index)
+}
@@ -1,6 +1,9 @@
 // Copyright (c) 2020 Ghaith Hachem and Mathias Rieder
 use crate::codegen::debug::Debug;
-use crate::index::{FxIndexSet, Index, VariableIndexEntry, VariableType};
+use crate::index::{
+    get_class_vtable_name, FxIndexSet, Index, VariableIndexEntry, VariableType, BASE_MEMBER_NAME,
+    VTABLE_MEMBER_NAME,
+};
 use crate::resolver::{AstAnnotations, Dependency};
 use crate::typesystem::{self, DataTypeInformation, Dimension, StringEncoding, StructSource};
 use crate::{
@@ -13,11 +16,12 @@ use crate::{
 };
 
 use inkwell::{
+    module::Module,
     types::{BasicType, BasicTypeEnum},
     values::{BasicValue, BasicValueEnum},
     AddressSpace,
 };
-use plc_ast::ast::{AstNode, AstStatement};
+use plc_ast::ast::{AstNode, AstStatement, PouType};
 use plc_ast::literals::AstLiteral;
 use plc_diagnostics::diagnostics::Diagnostic;
 use plc_source::source_location::SourceLocation;
@@ -34,6 +38,7 @@ use super::ADDRESS_SPACE_GENERIC;
 use super::{expression_generator::ExpressionCodeGenerator, llvm::Llvm};
 
 pub struct DataTypeGenerator<'ink, 'b> {
+    module: &'b Module<'ink>,
     llvm: &'b Llvm<'ink>,
     debug: &'b mut DebugBuilderEnum<'ink>,
     index: &'b Index,
@@ -49,6 +54,7 @@ pub struct DataTypeGenerator<'ink, 'b> {
 /// - Array type for arrays
 /// - array type for sized Strings
 pub fn generate_data_types<'ink>(
+    module: &Module<'ink>,
     llvm: &Llvm<'ink>,
     debug: &mut DebugBuilderEnum<'ink>,
     dependencies: &FxIndexSet<Dependency>,
@@ -79,7 +85,7 @@ pub fn generate_data_types<'ink>(
     }
 
     let mut generator =
-        DataTypeGenerator { llvm, debug, index, annotations, types_index: LlvmTypedIndex::default() };
+        DataTypeGenerator { module, llvm, debug, index, annotations, types_index: LlvmTypedIndex::default() };
 
     // first create all STUBs for struct types (empty structs)
     // and associate them in the llvm index
@@ -174,16 +180,19 @@ impl<'ink> DataTypeGenerator<'ink, '_> {
     /// generates the members of an opaque struct and associates its initial values
     fn expand_opaque_types(&mut self, data_type: &DataType) -> Result<(), Diagnostic> {
         let information = data_type.get_type_information();
-        if let DataTypeInformation::Struct { source, members, .. } = information {
+        if information.is_union() {
+            let union_type = self.types_index.get_associated_type(data_type.get_name())?.into_struct_type();
+            union_type.set_body(&[self.create_union_storage_type(information)], false);
+        } else if let DataTypeInformation::Struct { source, members, .. } = information {
             let members = members
                 .iter()
-                .filter(|it| !(it.is_temp() || it.is_return() || it.is_var_external()))
+                .filter(|it| it.is_stored_in_struct())
                 .map(|m| self.types_index.get_associated_type(m.get_type_name()))
                 .collect::<Result<Vec<BasicTypeEnum>, Diagnostic>>()?;
 
             let struct_type = match source {
                 StructSource::Pou(..) => self.types_index.get_associated_pou_type(data_type.get_name()),
-                StructSource::OriginalDeclaration | StructSource::Internal(_) => {
+                StructSource::OriginalDeclaration | StructSource::Union | StructSource::Internal(_) => {
                     self.types_index.get_associated_type(data_type.get_name())
                 }
             }
@@ -194,6 +203,17 @@ impl<'ink> DataTypeGenerator<'ink, '_> {
         Ok(())
     }
 
+    /// Creates the storage shared by all members of a union. The storage is an array of integers as wide as
+    /// the union's alignment, so the llvm type has the same size and alignment as the union in the index.
+    /// Members are accessed by casting a pointer to the union into a pointer to the member's type.
+    fn create_union_storage_type(&self, union: &DataTypeInformation) -> BasicTypeEnum<'ink> {
+        let alignment = union.get_alignment(self.index).value().max(1);
+        let size = union.get_size(self.index).value();
+        get_llvm_int_type(self.llvm.context, alignment * 8, union.get_name())
+            .array_type(size / alignment)
+            .as_basic_type_enum()
+    }
+
     /// Creates an llvm type to be associated with the given data type.
     /// Generates only an opaque type for structs.
     /// Eagerly generates but does not associate nested array and referenced aliased types
@@ -203,7 +223,7 @@ impl<'ink> DataTypeGenerator<'ink, '_> {
         match information {
             DataTypeInformation::Struct { source, .. } => match source {
                 StructSource::Pou(..) => self.types_index.get_associated_pou_type(data_type.get_name()),
-                StructSource::OriginalDeclaration => {
+                StructSource::OriginalDeclaration | StructSource::Union => {
                     self.types_index.get_associated_type(data_type.get_name())
                 }
                 StructSource::Internal(_) => self.types_index.get_associated_type(data_type.get_name()),
@@ -276,44 +296,27 @@ impl<'ink> DataTypeGenerator<'ink, '_> {
     ) -> Result<Option<BasicValueEnum<'ink>>, Diagnostic> {
         let information = data_type.get_type_information();
         match information {
-            DataTypeInformation::Struct { source, members, .. } => {
-                let member_names_and_initializers = members
-                    .iter()
-                    .filter(|it| it.get_variable_type() != VariableType::Temp)
-                    .map(|it| {
-                        self.generate_initial_value_for_variable(it).and_then(|v| match v {
-                            Some(v) => Ok((it.get_qualified_name(), v)),
-                            None => self
-                                .types_index
-                                .get_associated_type(it.get_type_name())
-                                .map(get_default_for)
-                                .map(|v| (it.get_qualified_name(), v)),
-                        })
-                    })
-                    .collect::<Result<Vec<(&str, BasicValueEnum)>, Diagnostic>>()?;
-
-                let mut member_values: Vec<BasicValueEnum> = Vec::new();
+            // unions cannot be initialized, they are always zeroed
+            DataTypeInformation::Struct { source: StructSource::Union, .. } => Ok(None),
+            DataTypeInformation::Struct { members, .. } => {
+                let vtable = self.find_vtable(data_type);
+                let member_names_and_initializers = self.generate_member_initial_values(members, vtable)?;
                 for (name, v) in &member_names_and_initializers {
                     self.types_index.associate_initial_value(name, *v)?;
-                    member_values.push(*v);
                 }
 
-                let struct_type = match source {
-                    StructSource::Pou(..) => self.types_index.get_associated_pou_type(data_type.get_name()),
-                    StructSource::OriginalDeclaration => {
-                        self.types_index.get_associated_type(data_type.get_name())
-                    }
-                    StructSource::Internal(_) => self.types_index.get_associated_type(data_type.get_name()),
-                }?
-                .into_struct_type();
-
-                Ok(Some(struct_type.const_named_struct(&member_values).as_basic_value_enum()))
+                self.create_struct_value(data_type, member_names_and_initializers).map(Some)
+            }
+            DataTypeInformation::Array { inner_type_name, .. } => {
+                match self.generate_array_initializer(
+                    data_type,
+                    |stmt| matches!(stmt.stmt, AstStatement::Literal(AstLiteral::Array { .. })),
+                    "LiteralArray",
+                )? {
+                    Some(value) => Ok(Some(value)),
+                    None => self.generate_instance_array_initializer(data_type, inner_type_name),
+                }
             }
-            DataTypeInformation::Array { .. } => self.generate_array_initializer(
-                data_type,
-                |stmt| matches!(stmt.stmt, AstStatement::Literal(AstLiteral::Array { .. })),
-                "LiteralArray",
-            ),
             DataTypeInformation::String { .. } => self.generate_array_initializer(
                 data_type,
                 |stmt| matches!(stmt.stmt, AstStatement::Literal(AstLiteral::String { .. })),
@@ -330,6 +333,108 @@ impl<'ink> DataTypeGenerator<'ink, '_> {
         }
     }
 
+    /// generates the initial values of the given struct members. If a `vtable` is given, it is stored into
+    /// the instance's vtable-member, which is declared by the (possibly nested) `__BASE` instance
+    fn generate_member_initial_values<'m>(
+        &mut self,
+        members: &'m [VariableIndexEntry],
+        vtable: Option<BasicValueEnum<'ink>>,
+    ) -> Result<Vec<(&'m str, BasicValueEnum<'ink>)>, Diagnostic> {
+        members
+            .iter()
+            .filter(|it| it.get_variable_type() != VariableType::Temp)
+            .map(|it| match (it.get_name(), vtable) {
+                (VTABLE_MEMBER_NAME, Some(vtable)) => Ok((it.get_qualified_name(), vtable)),
+                (BASE_MEMBER_NAME, Some(vtable)) => {
+                    let base = self.index.get_type(it.get_type_name())?;
+                    let base_members =
+                        self.generate_member_initial_values(base.get_members(), Some(vtable))?;
+                    self.create_struct_value(base, base_members).map(|v| (it.get_qualified_name(), v))
+                }
+                _ => self.generate_initial_value_for_variable(it).and_then(|v| match v {
+                    Some(v) => Ok((it.get_qualified_name(), v)),
+                    None => self
+                        .types_index
+                        .get_associated_type(it.get_type_name())
+                        .map(get_default_for)
+                        .map(|v| (it.get_qualified_name(), v)),
+                }),
+            })
+            .collect()
+    }
+
+    fn create_struct_value(
+        &self,
+        data_type: &DataType,
+        member_values: Vec<(&str, BasicValueEnum<'ink>)>,
+    ) -> Result<BasicValueEnum<'ink>, Diagnostic> {
+        let struct_type = match data_type.get_type_information() {
+            DataTypeInformation::Struct { source: StructSource::Pou(..), .. } => {
+                self.types_index.get_associated_pou_type(data_type.get_name())
+            }
+            _ => self.types_index.get_associated_type(data_type.get_name()),
+        }?
+        .into_struct_type();
+
+        let member_values = member_values.into_iter().map(|(_, v)| v).collect::<Vec<_>>();
+        Ok(struct_type.const_named_struct(&member_values).as_basic_value_enum())
+    }
+
+    /// returns a pointer to the dispatch table of the given POU if it is part of an `EXTENDS` hierarchy.
+    /// The table itself is generated together with the POU's implementation.
+    fn find_vtable(&self, data_type: &DataType) -> Option<BasicValueEnum<'ink>> {
+        let DataTypeInformation::Struct {
+            source: StructSource::Pou(PouType::FunctionBlock | PouType::Class),
+            ..
+        } = data_type.get_type_information()
+        else {
+            return None;
+        };
+        self.index.find_member(data_type.get_name(), VTABLE_MEMBER_NAME)?;
+
+        let name = get_class_vtable_name(data_type.get_name());
+        let slot_type = self.llvm.context.i8_type().ptr_type(AddressSpace::from(ADDRESS_SPACE_GENERIC));
+        let vtable = self.module.get_global(&name).unwrap_or_else(|| {
+            let slots = self.index.get_virtual_methods(data_type.get_name()).len() as u32;
+            self.llvm.create_global_variable(self.module, &name, slot_type.array_type(slots).into())
+        });
+        Some(vtable.as_pointer_value().const_cast(slot_type).as_basic_value_enum())
+    }
+
+    /// generates the initial value of an array of FUNCTION_BLOCK or CLASS instances without an initializer,
+    /// if the instances need to be initialized with their vtable
+    fn generate_instance_array_initializer(
+        &mut self,
+        data_type: &DataType,
+        inner_type_name: &str,
+    ) -> Result<Option<BasicValueEnum<'ink>>, Diagnostic> {
+        let inner_type = self.index.get_effective_type_or_void_by_name(inner_type_name);
+        let needs_vtable = match inner_type.get_type_information() {
+            DataTypeInformation::Array { .. } => true,
+            _ => self.find_vtable(inner_type).is_some(),
+        };
+        if !needs_vtable {
+            return Ok(None);
+        }
+
+        let Some(inner_value) = self.generate_initial_value(inner_type)? else {
+            return Ok(None);
+        };
+        let length = self.types_index.get_associated_type(data_type.get_name())?.into_array_type().len();
+        let values = std::iter::repeat_n(inner_value, length as usize);
+        let value = match inner_value {
+            BasicValueEnum::StructValue(it) => {
+                it.get_type().const_array(&values.map(BasicValueEnum::into_struct_value).collect::<Vec<_>>())
+            }
+            BasicValueEnum::ArrayValue(it) => {
+                it.get_type().const_array(&values.map(BasicValueEnum::into_array_value).collect::<Vec<_>>())
+            }
+            _ => return Ok(None),
+        };
+
+        Ok(Some(value.as_basic_value_enum()))
+    }
+
     /// generates and returns an optional inital value at the given declared variable
     /// if no initial value is defined, it returns the initial value of the variable's
     /// datatype or Ok(None) if the type also has no declared default value
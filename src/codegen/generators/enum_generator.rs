@@ -0,0 +1,348 @@
+use inkwell::{
+    module::{Linkage, Module},
+    types::{BasicTypeEnum, IntType},
+    values::{FunctionValue, GlobalValue, IntValue, PointerValue},
+    AddressSpace, IntPredicate,
+};
+use plc_diagnostics::diagnostics::Diagnostic;
+use plc_source::source_location::SourceLocation;
+use plc_util::convention::RAISE_FUNCTION_NAME;
+
+use super::{
+    exception_generator::EXCEPTION_INVALID_CONVERSION,
+    llvm::{GlobalValueExt, Llvm},
+    ADDRESS_SPACE_GENERIC,
+};
+use crate::{
+    codegen::llvm_index::LlvmTypedIndex,
+    index::{FxIndexSet, Index},
+    lowering::enums::is_enum_conversion,
+    resolver::Dependency,
+    typesystem::{DataType, DEFAULT_STRING_LEN},
+};
+
+/// The prefix of the name tables of the enums, e.g. `__enum_names_Color` for `STRING`s and
+/// `__enum_wnames_Color` for `WSTRING`s
+pub const ENUM_NAMES_PREFIX: &str = "__enum_names_";
+pub const ENUM_WIDE_NAMES_PREFIX: &str = "__enum_wnames_";
+
+/// Generates the bodies of the enum conversions called in the unit. A conversion looks up its argument in
+/// the name table of the enum, which lists the value and the name of each element, followed by an empty
+/// name for values which are not an element of the enum:
+/// ```llvm
+/// @__enum_names_Color = private constant [4 x { i32, [6 x i8] }] [
+///   { i32, [6 x i8] } { i32 0, [6 x i8] c"Red\00\00\00" },
+///   { i32, [6 x i8] } { i32 1, [6 x i8] c"Green\00" },
+///   { i32, [6 x i8] } { i32 2, [6 x i8] c"Blue\00\00" },
+///   { i32, [6 x i8] } zeroinitializer
+/// ]
+/// ```
+/// The names are compared case-insensitively, a name which is not in the table raises
+/// `EXCEPTION_INVALID_CONVERSION`. Every module calling a conversion defines it, the linker keeps one of them.
+pub fn generate_enum_conversions<'ink>(
+    module: &Module<'ink>,
+    llvm: &Llvm<'ink>,
+    dependencies: &FxIndexSet<Dependency>,
+    index: &Index,
+    llvm_index: &LlvmTypedIndex<'ink>,
+) -> Result<(), Diagnostic> {
+    let conversions = dependencies
+        .iter()
+        .filter_map(|it| match it {
+            Dependency::Call(name) => index.find_pou(name),
+            _ => None,
+        })
+        .filter(|it| is_enum_conversion(it));
+
+    for pou in conversions {
+        let error = || {
+            Diagnostic::codegen_error(
+                format!("Cannot generate the enum conversion `{}`", pou.get_name()),
+                SourceLocation::internal(),
+            )
+        };
+        let function = llvm_index.find_associated_implementation(pou.get_name()).ok_or_else(error)?;
+        if function.count_basic_blocks() > 0 {
+            continue;
+        }
+
+        // the enum is either converted to a string or parsed from the string passed as `in`
+        let input = index.find_member(pou.get_name(), "in").ok_or_else(error)?;
+        let input_type = index.get_effective_type_or_void_by_name(input.get_type_name());
+        let (enum_type, is_parser) = if input_type.is_enum() {
+            (input_type, false)
+        } else {
+            (index.get_effective_type_or_void_by_name(pou.get_return_type().unwrap_or_default()), true)
+        };
+        let value_type = llvm_index.find_associated_type(enum_type.get_name()).ok_or_else(error)?;
+        let BasicTypeEnum::IntType(value_type) = value_type else { return Err(error()) };
+
+        // the first parameter is either the string to parse or the (by-ref) result of the conversion
+        let string = function
+            .get_first_param()
+            .filter(|it| it.is_pointer_value())
+            .ok_or_else(error)?
+            .into_pointer_value();
+        let char_type = get_char_type(string);
+
+        let table = get_name_table(module, llvm, enum_type, value_type, char_type, index)?;
+        let generator = ConversionGenerator { llvm, function, table, char_type };
+        if is_parser {
+            // the resolver adds `__plc_raise` to the dependencies of every unit parsing an enum
+            let raise = llvm_index.find_associated_implementation(RAISE_FUNCTION_NAME).ok_or_else(error)?;
+            generator.generate_parser(string, raise);
+        } else {
+            let value = function.get_nth_param(1).ok_or_else(error)?.into_int_value();
+            generator.generate_formatter(value, string)?;
+        }
+        function.set_linkage(Linkage::LinkOnceODR);
+    }
+
+    Ok(())
+}
+
+/// Returns the type of the characters the given string parameter points to
+fn get_char_type(string: PointerValue) -> IntType {
+    match string.get_type().get_element_type() {
+        inkwell::types::AnyTypeEnum::ArrayType(it) => it.get_element_type().into_int_type(),
+        it => it.into_int_type(),
+    }
+}
+
+/// Returns the name table of the given enum, the names are stored with the given character type
+fn get_name_table<'ink>(
+    module: &Module<'ink>,
+    llvm: &Llvm<'ink>,
+    enum_type: &DataType,
+    value_type: IntType<'ink>,
+    char_type: IntType<'ink>,
+    index: &Index,
+) -> Result<GlobalValue<'ink>, Diagnostic> {
+    let is_wide = char_type.get_bit_width() == 16;
+    let prefix = if is_wide { ENUM_WIDE_NAMES_PREFIX } else { ENUM_NAMES_PREFIX };
+    let name = format!("{prefix}{}", enum_type.get_name());
+    if let Some(table) = module.get_global(&name) {
+        return Ok(table);
+    }
+
+    let variants = enum_type.get_enum_variants().map(Vec::as_slice).unwrap_or_default();
+    let elements = variants
+        .iter()
+        .map(|it| {
+            let value = it
+                .initial_value
+                .ok_or_else(|| format!("Missing value of the enum element `{}`", it.get_name()))
+                .and_then(|id| index.get_const_expressions().get_constant_int_statement_value(&id))
+                .map_err(|err| Diagnostic::codegen_error(err, it.source_location.clone()))?;
+            // the names are converted to a `STRING`, i.e. they are truncated to 80 characters
+            let name = it.get_name().chars().take(DEFAULT_STRING_LEN as usize).collect::<String>();
+            Ok((value, name))
+        })
+        .collect::<Result<Vec<_>, Diagnostic>>()?;
+
+    // every name is followed by at least one terminator
+    let length = elements.iter().map(|(_, name)| name.len()).max().unwrap_or_default() + 1;
+    let name_type = char_type.array_type(length as u32);
+    let entry_type = llvm.context.struct_type(&[value_type.into(), name_type.into()], false);
+    let entries = elements
+        .iter()
+        .map(|(value, name)| {
+            let name = if is_wide {
+                llvm.create_const_utf16_string(name, length)
+            } else {
+                llvm.create_const_utf8_string(name, length)
+            }?;
+            Ok(entry_type.const_named_struct(&[value_type.const_int(*value as u64, true).into(), name]))
+        })
+        .chain(std::iter::once(Ok(entry_type.const_zero())))
+        .collect::<Result<Vec<_>, Diagnostic>>()?;
+
+    let table_type = entry_type.array_type(entries.len() as u32);
+    let table = llvm.create_global_variable(module, &name, table_type.into()).make_constant();
+    table.set_linkage(Linkage::Private);
+    table.set_initializer(&entry_type.const_array(&entries));
+    Ok(table)
+}
+
+struct ConversionGenerator<'a, 'ink> {
+    llvm: &'a Llvm<'ink>,
+    function: FunctionValue<'ink>,
+    table: GlobalValue<'ink>,
+    char_type: IntType<'ink>,
+}
+
+impl<'ink> ConversionGenerator<'_, 'ink> {
+    /// the number of elements of the enum, the table ends with an additional entry for unknown values
+    fn get_element_count(&self) -> IntValue<'ink> {
+        let entries = self.table.as_pointer_value().get_type().get_element_type().into_array_type().len();
+        self.llvm.i32_type().const_int(entries as u64 - 1, false)
+    }
+
+    /// returns a pointer to the given field of the table entry at the given position
+    fn get_entry_field(&self, entry: IntValue<'ink>, field: u64, name: &str) -> PointerValue<'ink> {
+        let i32_type = self.llvm.i32_type();
+        let indices = [i32_type.const_zero(), entry, i32_type.const_int(field, false)];
+        // SAFETY: the entry is checked against the length of the table
+        unsafe { self.llvm.builder.build_in_bounds_gep(self.table.as_pointer_value(), &indices, name) }
+    }
+
+    /// returns a pointer to the given character of the name of the table entry at the given position
+    fn get_name_character(&self, entry: IntValue<'ink>, character: IntValue<'ink>) -> PointerValue<'ink> {
+        let i32_type = self.llvm.i32_type();
+        let indices = [i32_type.const_zero(), entry, i32_type.const_int(1, false), character];
+        // SAFETY: the comparison stops at the terminator of the name
+        unsafe { self.llvm.builder.build_in_bounds_gep(self.table.as_pointer_value(), &indices, "") }
+    }
+
+    /// creates a counter starting at zero, which is loaded with `load_counter`
+    fn create_counter(&self, name: &str) -> PointerValue<'ink> {
+        let counter = self.llvm.builder.build_alloca(self.llvm.i32_type(), name);
+        self.llvm.builder.build_store(counter, self.llvm.i32_type().const_zero());
+        counter
+    }
+
+    fn load_counter(&self, counter: PointerValue<'ink>) -> IntValue<'ink> {
+        self.llvm.builder.build_load(counter, "").into_int_value()
+    }
+
+    fn increment_counter(&self, counter: PointerValue<'ink>) {
+        let builder = &self.llvm.builder;
+        let next =
+            builder.build_int_add(self.load_counter(counter), self.llvm.i32_type().const_int(1, false), "");
+        builder.build_store(counter, next);
+    }
+
+    /// copies the name of the element with the given value into the result, or an empty name if the value
+    /// is not an element of the enum
+    fn generate_formatter(
+        &self,
+        value: IntValue<'ink>,
+        result: PointerValue<'ink>,
+    ) -> Result<(), Diagnostic> {
+        let context = self.llvm.context;
+        let builder = &self.llvm.builder;
+        let entry = context.append_basic_block(self.function, "entry");
+        let search = context.append_basic_block(self.function, "search");
+        let compare = context.append_basic_block(self.function, "compare");
+        let next = context.append_basic_block(self.function, "next");
+        let found = context.append_basic_block(self.function, "found");
+
+        builder.position_at_end(entry);
+        let counter = self.create_counter("element");
+        builder.build_unconditional_branch(search);
+
+        // the entry of unknown values comes last, so the search stops at it
+        builder.position_at_end(search);
+        let element = self.load_counter(counter);
+        let is_element = builder.build_int_compare(IntPredicate::ULT, element, self.get_element_count(), "");
+        builder.build_conditional_branch(is_element, compare, found);
+
+        builder.position_at_end(compare);
+        let element_value =
+            builder.build_load(self.get_entry_field(element, 0, "value"), "").into_int_value();
+        let is_match = builder.build_int_compare(IntPredicate::EQ, element_value, value, "");
+        builder.build_conditional_branch(is_match, found, next);
+
+        builder.position_at_end(next);
+        self.increment_counter(counter);
+        builder.build_unconditional_branch(search);
+
+        builder.position_at_end(found);
+        let name = self.get_entry_field(self.load_counter(counter), 1, "name");
+        let name_type = name.get_type().get_element_type().into_array_type();
+        let alignment = self.char_type.get_bit_width() / 8;
+        let size = name_type.size_of().ok_or_else(|| {
+            Diagnostic::codegen_error("Cannot determine the size of an enum name", SourceLocation::internal())
+        })?;
+        let pointer_type = self.char_type.ptr_type(AddressSpace::from(ADDRESS_SPACE_GENERIC));
+        let target = builder.build_pointer_cast(result, pointer_type, "");
+        let source = builder.build_pointer_cast(name, pointer_type, "");
+        builder
+            .build_memcpy(target, alignment, source, alignment, size)
+            .map_err(|err| Diagnostic::codegen_error(err, SourceLocation::internal()))?;
+        builder.build_return(None);
+        Ok(())
+    }
+
+    /// returns the value of the element with the given name. A name which is not an element of the enum
+    /// always raises `EXCEPTION_INVALID_CONVERSION` through the given `__plc_raise`
+    fn generate_parser(&self, name: PointerValue<'ink>, raise: FunctionValue<'ink>) {
+        let context = self.llvm.context;
+        let builder = &self.llvm.builder;
+        let entry = context.append_basic_block(self.function, "entry");
+        let search = context.append_basic_block(self.function, "search");
+        let start = context.append_basic_block(self.function, "start");
+        let compare = context.append_basic_block(self.function, "compare");
+        let matches = context.append_basic_block(self.function, "matches");
+        let next_character = context.append_basic_block(self.function, "next_character");
+        let next_element = context.append_basic_block(self.function, "next_element");
+        let found = context.append_basic_block(self.function, "found");
+        let unknown = context.append_basic_block(self.function, "unknown");
+
+        builder.position_at_end(entry);
+        let pointer_type = self.char_type.ptr_type(AddressSpace::from(ADDRESS_SPACE_GENERIC));
+        let name = builder.build_pointer_cast(name, pointer_type, "");
+        let element_counter = self.create_counter("element");
+        let character_counter = self.create_counter("character");
+        builder.build_unconditional_branch(search);
+
+        builder.position_at_end(search);
+        let element = self.load_counter(element_counter);
+        let is_element = builder.build_int_compare(IntPredicate::ULT, element, self.get_element_count(), "");
+        builder.build_conditional_branch(is_element, start, unknown);
+
+        builder.position_at_end(start);
+        builder.build_store(character_counter, self.llvm.i32_type().const_zero());
+        builder.build_unconditional_branch(compare);
+
+        // the names are compared up to the terminator of the element's name, a mismatch stops the
+        // comparison before reading past the terminator of the given name
+        builder.position_at_end(compare);
+        let element = self.load_counter(element_counter);
+        let character = self.load_counter(character_counter);
+        // SAFETY: the comparison stops at the terminator of the given name
+        let given = unsafe { builder.build_in_bounds_gep(name, &[character], "") };
+        let given = builder.build_load(given, "").into_int_value();
+        let expected = builder.build_load(self.get_name_character(element, character), "").into_int_value();
+        let is_same = builder.build_int_compare(
+            IntPredicate::EQ,
+            self.to_lowercase(given),
+            self.to_lowercase(expected),
+            "",
+        );
+        builder.build_conditional_branch(is_same, matches, next_element);
+
+        builder.position_at_end(matches);
+        let is_end = builder.build_int_compare(IntPredicate::EQ, expected, self.char_type.const_zero(), "");
+        builder.build_conditional_branch(is_end, found, next_character);
+
+        builder.position_at_end(next_character);
+        self.increment_counter(character_counter);
+        builder.build_unconditional_branch(compare);
+
+        builder.position_at_end(next_element);
+        self.increment_counter(element_counter);
+        builder.build_unconditional_branch(search);
+
+        builder.position_at_end(found);
+        let value =
+            builder.build_load(self.get_entry_field(self.load_counter(element_counter), 0, "value"), "");
+        builder.build_return(Some(&value));
+
+        builder.position_at_end(unknown);
+        let code = self.llvm.i32_type().const_int(EXCEPTION_INVALID_CONVERSION, false);
+        builder.build_call(raise, &[code.into()], "");
+        builder.build_unreachable();
+    }
+
+    /// converts the given ASCII character to lowercase
+    fn to_lowercase(&self, character: IntValue<'ink>) -> IntValue<'ink> {
+        let builder = &self.llvm.builder;
+        let char_type = self.char_type;
+        let offset = builder.build_int_sub(character, char_type.const_int(b'A' as u64, false), "");
+        let is_uppercase =
+            builder.build_int_compare(IntPredicate::ULT, offset, char_type.const_int(26, false), "");
+        let lowercase = builder.build_or(character, char_type.const_int(0x20, false), "");
+        builder.build_select(is_uppercase, lowercase, character, "").into_int_value()
+    }
+}
@@ -0,0 +1,176 @@
+use inkwell::{
+    attributes::{Attribute, AttributeLoc},
+    context::Context,
+    module::{Linkage, Module},
+    types::StructType,
+    values::{FunctionValue, PointerValue},
+    AddressSpace,
+};
+use plc_diagnostics::diagnostics::Diagnostic;
+use plc_util::convention::{EXCEPTION_FRAME_NAME, RAISE_FUNCTION_NAME};
+
+use super::{llvm::Llvm, ADDRESS_SPACE_GENERIC};
+use crate::{codegen::llvm_index::LlvmTypedIndex, index::FxIndexSet, resolver::Dependency};
+
+/// The function saving the execution context of an exception frame, i.e. `int _setjmp(jmp_buf env)`
+pub const SETJMP_FUNCTION_NAME: &str = "_setjmp";
+
+const LONGJMP_FUNCTION_NAME: &str = "_longjmp";
+const ABORT_FUNCTION_NAME: &str = "abort";
+
+/// The codes of the exceptions raised by the runtime checks, see `__EXCEPTION_CODE`
+pub const EXCEPTION_DIVIDE_BY_ZERO: u64 = 1;
+pub const EXCEPTION_NULL_POINTER: u64 = 2;
+pub const EXCEPTION_INVALID_CONVERSION: u64 = 3;
+
+/// The alignment of an exception frame, as required by the `jmp_buf` of most targets
+pub const EXCEPTION_FRAME_ALIGNMENT: u32 = 16;
+
+/// Returns whether exception frames can be generated for the given target triple.
+///
+/// The runtime calls `_setjmp`/`_longjmp` and reserves 512 bytes for the `jmp_buf`, which holds for the
+/// Linux C libraries (glibc, musl) on x86, ARM and RISC-V as well as for the Apple platforms. Other targets,
+/// e.g. Windows, declare these functions differently or need a larger `jmp_buf`.
+pub fn is_supported_target(triple: &str) -> bool {
+    let mut parts = triple.split('-');
+    let arch = parts.next().unwrap_or_default();
+    let rest = parts.collect::<Vec<_>>();
+    let is_linux = rest.contains(&"linux");
+    let is_apple =
+        rest.contains(&"apple") || rest.iter().any(|it| it.starts_with("darwin") || it.starts_with("macos"));
+
+    if is_linux {
+        matches!(arch, "x86_64" | "i386" | "i586" | "i686" | "aarch64" | "riscv64")
+            || arch.starts_with("arm")
+            || arch.starts_with("thumb")
+    } else if is_apple {
+        matches!(arch, "x86_64" | "aarch64" | "arm64")
+    } else {
+        false
+    }
+}
+
+/// Returns the layout of the exception frame pushed by a `__TRY` statement:
+/// ```llvm
+/// { [64 x i64], i8*, i32 }
+/// ```
+/// The `jmp_buf` comes first so a pointer to the frame is a pointer to its `jmp_buf`. It is followed by the
+/// previously active frame and the code of the raised exception.
+pub fn get_exception_frame_type(context: &Context) -> StructType<'_> {
+    let pointer_type = context.i8_type().ptr_type(AddressSpace::from(ADDRESS_SPACE_GENERIC));
+    context.struct_type(
+        &[context.i64_type().array_type(64).into(), pointer_type.into(), context.i32_type().into()],
+        false,
+    )
+}
+
+/// Associates the exception runtime with the module if the unit contains a `__TRY` statement, may raise an
+/// exception or is generated with runtime checks. Unless the project declares its own `__plc_raise`, a weak default is generated which
+/// resumes the innermost exception frame or aborts the program if there is none:
+/// ```llvm
+/// define weak void @__plc_raise(i32 %0) #0 {
+/// entry:
+///   %1 = load i8*, i8** @__plc_exception_frame, align 8
+///   %2 = icmp eq i8* %1, null
+///   br i1 %2, label %unhandled, label %handled
+/// ...
+/// ```
+pub fn generate_exception_runtime<'ink>(
+    module: &Module<'ink>,
+    llvm: &Llvm<'ink>,
+    dependencies: &FxIndexSet<Dependency>,
+    llvm_index: &LlvmTypedIndex<'ink>,
+    runtime_checks: bool,
+) -> Result<LlvmTypedIndex<'ink>, Diagnostic> {
+    let mut index = LlvmTypedIndex::default();
+    if !runtime_checks && !dependencies.contains(&Dependency::Call(RAISE_FUNCTION_NAME.to_string())) {
+        return Ok(index);
+    }
+
+    let context = llvm.context;
+    let pointer_type = context.i8_type().ptr_type(AddressSpace::from(ADDRESS_SPACE_GENERIC));
+
+    // every module defines the same frame variable, the linker merges them into one
+    let frames = module.get_global(EXCEPTION_FRAME_NAME).unwrap_or_else(|| {
+        let frames = module.add_global(pointer_type, None, EXCEPTION_FRAME_NAME);
+        frames.set_linkage(Linkage::WeakAny);
+        frames.set_thread_local(true);
+        frames.set_initializer(&pointer_type.const_null());
+        frames
+    });
+    index.associate_global(EXCEPTION_FRAME_NAME, frames)?;
+
+    let setjmp = module.get_function(SETJMP_FUNCTION_NAME).unwrap_or_else(|| {
+        let function_type = context.i32_type().fn_type(&[pointer_type.into()], false);
+        let setjmp = module.add_function(SETJMP_FUNCTION_NAME, function_type, Some(Linkage::External));
+        setjmp.add_attribute(AttributeLoc::Function, create_attribute(context, "returns_twice"));
+        setjmp
+    });
+    index.associate_implementation(SETJMP_FUNCTION_NAME, setjmp)?;
+
+    if llvm_index.find_associated_implementation(RAISE_FUNCTION_NAME).is_none() {
+        let raise = module
+            .get_function(RAISE_FUNCTION_NAME)
+            .unwrap_or_else(|| generate_raise_function(module, llvm, frames.as_pointer_value()));
+        index.associate_implementation(RAISE_FUNCTION_NAME, raise)?;
+    }
+
+    Ok(index)
+}
+
+fn generate_raise_function<'ink>(
+    module: &Module<'ink>,
+    llvm: &Llvm<'ink>,
+    frames: PointerValue<'ink>,
+) -> FunctionValue<'ink> {
+    let context = llvm.context;
+    let builder = &llvm.builder;
+    let pointer_type = context.i8_type().ptr_type(AddressSpace::from(ADDRESS_SPACE_GENERIC));
+    let noreturn = create_attribute(context, "noreturn");
+
+    let raise_type = context.void_type().fn_type(&[context.i32_type().into()], false);
+    let raise = module.add_function(RAISE_FUNCTION_NAME, raise_type, Some(Linkage::WeakAny));
+    raise.add_attribute(AttributeLoc::Function, noreturn);
+
+    let declare = |name: &str, function_type| {
+        module.get_function(name).unwrap_or_else(|| {
+            let function = module.add_function(name, function_type, Some(Linkage::External));
+            function.add_attribute(AttributeLoc::Function, noreturn);
+            function
+        })
+    };
+    let abort = declare(ABORT_FUNCTION_NAME, context.void_type().fn_type(&[], false));
+    let longjmp = declare(
+        LONGJMP_FUNCTION_NAME,
+        context.void_type().fn_type(&[pointer_type.into(), context.i32_type().into()], false),
+    );
+
+    let entry = context.append_basic_block(raise, "entry");
+    let unhandled = context.append_basic_block(raise, "unhandled");
+    let handled = context.append_basic_block(raise, "handled");
+
+    builder.position_at_end(entry);
+    let frame = builder.build_load(frames, "").into_pointer_value();
+    builder.build_conditional_branch(builder.build_is_null(frame, ""), unhandled, handled);
+
+    // nobody catches the exception
+    builder.position_at_end(unhandled);
+    builder.build_call(abort, &[], "");
+    builder.build_unreachable();
+
+    // resume the `__TRY` statement of the innermost frame with the exception's code
+    builder.position_at_end(handled);
+    let frame_type = get_exception_frame_type(context);
+    let typed_frame =
+        builder.build_pointer_cast(frame, frame_type.ptr_type(AddressSpace::from(ADDRESS_SPACE_GENERIC)), "");
+    let code = builder.build_struct_gep(typed_frame, 2, "").expect("exception frame has a code");
+    builder.build_store(code, raise.get_nth_param(0).expect("__plc_raise has a code"));
+    builder.build_call(longjmp, &[frame.into(), context.i32_type().const_int(1, false).into()], "");
+    builder.build_unreachable();
+
+    raise
+}
+
+fn create_attribute(context: &Context, name: &str) -> Attribute {
+    context.create_enum_attribute(Attribute::get_named_enum_kind_id(name), 0)
+}
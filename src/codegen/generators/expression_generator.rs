@@ -9,7 +9,7 @@ use inkwell::{
     },
     AddressSpace, FloatPredicate, IntPredicate,
 };
-use rustc_hash::FxHashSet;
+use rustc_hash::FxHashMap;
 
 use plc_ast::{
     ast::{
@@ -21,7 +21,7 @@ use plc_ast::{
 };
 use plc_diagnostics::diagnostics::{Diagnostic, INTERNAL_LLVM_ERROR};
 use plc_source::source_location::SourceLocation;
-use plc_util::convention::qualified_name;
+use plc_util::convention::{qualified_name, RAISE_FUNCTION_NAME};
 
 use crate::{
     codegen::{
@@ -30,18 +30,23 @@ use crate::{
         llvm_typesystem::{cast_if_needed, get_llvm_int_type},
     },
     index::{
-        const_expressions::ConstId, ArgumentType, ImplementationIndexEntry, Index, PouIndexEntry,
-        VariableIndexEntry, VariableType,
+        const_expressions::ConstId, get_vtable_name, ArgumentType, ImplementationIndexEntry, Index,
+        InterfaceIndexEntry, PouIndexEntry, VariableIndexEntry, VariableType, BASE_MEMBER_NAME,
     },
     resolver::{AnnotationMap, AstAnnotations, StatementAnnotation},
     typesystem,
     typesystem::{
         is_same_type_class, DataType, DataTypeInformation, DataTypeInformationProvider, Dimension,
-        StringEncoding, VarArgs, DINT_TYPE, INT_SIZE, INT_TYPE, LINT_TYPE,
+        StringEncoding, StructSource, VarArgs, BYTE_POINTER_TYPE, DINT_TYPE, INT_SIZE, INT_TYPE, LINT_TYPE,
     },
 };
 
-use super::{llvm::Llvm, statement_generator::FunctionContext, ADDRESS_SPACE_CONST, ADDRESS_SPACE_GENERIC};
+use super::{
+    exception_generator::{EXCEPTION_DIVIDE_BY_ZERO, EXCEPTION_NULL_POINTER},
+    llvm::Llvm,
+    statement_generator::FunctionContext,
+    ADDRESS_SPACE_CONST, ADDRESS_SPACE_GENERIC,
+};
 
 /// the generator for expressions
 pub struct ExpressionCodeGenerator<'a, 'b> {
@@ -200,6 +205,11 @@ impl<'ink, 'b> ExpressionCodeGenerator<'ink, 'b> {
         &self,
         expression: &AstNode,
     ) -> Result<ExpressionValue<'ink>, Diagnostic> {
+        // aggregate values, e.g. strings, are generated as values, so their replacement is generated here
+        if let Some(StatementAnnotation::ReplacementAst { statement }) = self.annotations.get(expression) {
+            return self.generate_expression_value(statement);
+        }
+
         //see if this is a constant - maybe we can short curcuit this codegen
         if let Some(StatementAnnotation::Variable {
             qualified_name, constant: true, resulting_type, ..
@@ -249,6 +259,9 @@ impl<'ink, 'b> ExpressionCodeGenerator<'ink, 'b> {
                 self.generate_unary_expression(&data.operator, &data.value).map(ExpressionValue::RValue)
             }
             AstStatement::ParenExpression(expr) => self.generate_expression_value(expr),
+            AstStatement::This | AstStatement::Super => {
+                self.generate_instance_pointer(expression).map(ExpressionValue::RValue)
+            }
             //fallback
             _ => self.generate_literal(expression),
         }
@@ -368,11 +381,12 @@ impl<'ink, 'b> ExpressionCodeGenerator<'ink, 'b> {
             return self.generate_bool_binary_expression(operator, left, right);
         }
         if ltype.is_int() && rtype.is_int() {
-            Ok(self.create_llvm_int_binary_expression(
-                operator,
-                self.generate_expression(left)?,
-                self.generate_expression(right)?,
-            ))
+            let left_value = self.generate_expression(left)?;
+            let right_value = self.generate_expression(right)?;
+            if matches!(operator, Operator::Division | Operator::Modulo) {
+                self.generate_division_by_zero_check(right_value.into_int_value());
+            }
+            Ok(self.create_llvm_int_binary_expression(operator, left_value, right_value))
         } else if ltype.is_float() && rtype.is_float() {
             Ok(self.create_llvm_float_binary_expression(
                 operator,
@@ -491,6 +505,14 @@ impl<'ink, 'b> ExpressionCodeGenerator<'ink, 'b> {
             )
             .ok_or_else(|| Diagnostic::cannot_generate_call_statement(operator))?;
 
+        if let Some(interface) = pou.get_interface(self.index) {
+            return self.generate_interface_method_call(interface, pou, operator, parameters);
+        }
+
+        if let Some((slot, declaration)) = self.find_virtual_method_slot(pou, operator) {
+            return self.generate_virtual_method_call(slot, declaration, operator, parameters);
+        }
+
         // find corresponding implementation
         let implementation = pou
             .find_implementation(self.index)
@@ -539,21 +561,7 @@ impl<'ink, 'b> ExpressionCodeGenerator<'ink, 'b> {
 
         // if the target is a function, declare the struct locally
         // assign all parameters into the struct values
-
-        // so grab either:
-        // - the call's return value
-        // - or a null-ptr
-        let value = call
-            .try_as_basic_value()
-            .either(Ok, |_| {
-                // we return an uninitialized int pointer for void methods :-/
-                // dont deref it!!
-                Ok(get_llvm_int_type(self.llvm.context, INT_SIZE, INT_TYPE)
-                    .ptr_type(AddressSpace::from(ADDRESS_SPACE_CONST))
-                    .const_null()
-                    .as_basic_value_enum())
-            })
-            .map(ExpressionValue::RValue);
+        let value = self.get_call_result(call);
 
         // after the call we need to copy the values for assigned outputs
         // this is only necessary for outputs defined as `rusty::index::ArgumentType::ByVal` (PROGRAM, FUNCTION_BLOCK)
@@ -570,6 +578,171 @@ impl<'ink, 'b> ExpressionCodeGenerator<'ink, 'b> {
         value
     }
 
+    /// returns either the call's return value or a null-ptr for calls without a return value
+    fn get_call_result(&self, call: CallSiteValue<'ink>) -> Result<ExpressionValue<'ink>, Diagnostic> {
+        call.try_as_basic_value()
+            .either(Ok, |_| {
+                // we return an uninitialized int pointer for void methods :-/
+                // dont deref it!!
+                Ok(get_llvm_int_type(self.llvm.context, INT_SIZE, INT_TYPE)
+                    .ptr_type(AddressSpace::from(ADDRESS_SPACE_CONST))
+                    .const_null()
+                    .as_basic_value_enum())
+            })
+            .map(ExpressionValue::RValue)
+    }
+
+    /// generates a call to the interface method `method` (e.g. `itf.foo(x)`), which is dispatched through
+    /// the vtable of the instance referenced by `itf`. The parameters are passed in the method's parameter
+    /// struct, see [`crate::codegen::generators::pou_generator::generate_vtables`]
+    fn generate_interface_method_call(
+        &self,
+        interface: &InterfaceIndexEntry,
+        method: &PouIndexEntry,
+        operator: &AstNode,
+        parameters: Option<&AstNode>,
+    ) -> Result<ExpressionValue<'ink>, Diagnostic> {
+        let Some(ReferenceExpr { base: Some(base), .. }) = try_from!(operator, ReferenceExpr) else {
+            return Err(Diagnostic::cannot_generate_call_statement(operator));
+        };
+        let slot = interface
+            .methods
+            .iter()
+            .position(|it| it.eq_ignore_ascii_case(method.get_name()))
+            .ok_or_else(|| Diagnostic::cannot_generate_call_statement(operator))?;
+
+        let location = operator.get_location();
+        let reference = self.generate_lvalue(base)?;
+        let instance = self.llvm.get_member_pointer_from_struct(reference, 0, "__data", &location)?;
+        let instance = self.llvm.load_pointer(&instance, "").into_pointer_value();
+        let vtable = self.llvm.get_member_pointer_from_struct(reference, 1, "__vtable", &location)?;
+        let vtable = self.llvm.load_pointer(&vtable, "").into_pointer_value();
+
+        self.generate_dynamic_method_call(method, instance, vtable, slot, operator, parameters)
+    }
+
+    /// returns the dispatch table slot of the given method and the method's first declaration within its
+    /// `EXTENDS` hierarchy, if calls to it need to be dispatched virtually. Calls through `SUPER^` are
+    /// always bound statically.
+    fn find_virtual_method_slot(
+        &self,
+        method: &PouIndexEntry,
+        operator: &AstNode,
+    ) -> Option<(usize, &'b PouIndexEntry)> {
+        let PouIndexEntry::Method { parent_pou_name, .. } = method else {
+            return None;
+        };
+
+        if let Some(ReferenceExpr { base: Some(base), .. }) = try_from!(operator, ReferenceExpr) {
+            if let Some(ReferenceExpr { access: ReferenceAccess::Deref, base: Some(base) }) =
+                try_from!(base, ReferenceExpr)
+            {
+                if matches!(base.get_stmt(), AstStatement::Super) {
+                    return None;
+                }
+            }
+        }
+
+        self.index
+            .get_virtual_methods(parent_pou_name)
+            .into_iter()
+            .enumerate()
+            .find(|(_, it)| it.get_flat_name().eq_ignore_ascii_case(method.get_flat_name()))
+    }
+
+    /// generates a call to an overridable method (e.g. `fb.foo(x)`), which is dispatched through the vtable
+    /// referenced by the instance's `__vtable` member. The parameters are passed in the parameter struct of
+    /// the method's first `declaration`, see [`crate::codegen::generators::pou_generator::generate_vtables`]
+    fn generate_virtual_method_call(
+        &self,
+        slot: usize,
+        declaration: &PouIndexEntry,
+        operator: &AstNode,
+        parameters: Option<&AstNode>,
+    ) -> Result<ExpressionValue<'ink>, Diagnostic> {
+        let instance = self.generate_lvalue(operator).or_else(|_| {
+            // this might be a local method
+            self.get_function_context(operator)?
+                .function
+                .get_first_param()
+                .map(BasicValueEnum::into_pointer_value)
+                .ok_or_else(|| Diagnostic::cannot_generate_call_statement(operator))
+        })?;
+
+        // the `__vtable` is the first member of every instance within the hierarchy
+        let byte_pointer = self.llvm.context.i8_type().ptr_type(AddressSpace::from(ADDRESS_SPACE_GENERIC));
+        let instance = self.llvm.builder.build_pointer_cast(instance, byte_pointer, "");
+        let vtable = self.llvm.builder.build_pointer_cast(
+            instance,
+            byte_pointer.ptr_type(AddressSpace::from(ADDRESS_SPACE_GENERIC)),
+            "",
+        );
+        let vtable = self.llvm.load_pointer(&vtable, "").into_pointer_value();
+
+        self.generate_dynamic_method_call(declaration, instance, vtable, slot, operator, parameters)
+    }
+
+    /// generates a call to the function stored in the given `slot` of the `vtable`, passing the `instance`
+    /// and a newly allocated parameter struct of the called `method`
+    fn generate_dynamic_method_call(
+        &self,
+        method: &PouIndexEntry,
+        instance: PointerValue<'ink>,
+        vtable: PointerValue<'ink>,
+        slot: usize,
+        operator: &AstNode,
+        parameters: Option<&AstNode>,
+    ) -> Result<ExpressionValue<'ink>, Diagnostic> {
+        let slot_type = instance.get_type();
+        let vtable = self.llvm.builder.build_pointer_cast(
+            vtable,
+            slot_type.ptr_type(AddressSpace::from(ADDRESS_SPACE_GENERIC)),
+            "",
+        );
+        let slot = self.llvm.load_array_element(
+            vtable,
+            &[self.llvm.i32_type().const_int(slot as u64, false)],
+            "",
+        )?;
+        let function = self.llvm.load_pointer(&slot, "").into_pointer_value();
+
+        let parameters_list = parameters.map(flatten_expression_list).unwrap_or_default();
+        let parameter_struct = self.allocate_function_struct_instance(method.get_name(), operator)?;
+        let arguments_list = self.generate_stateful_pou_arguments(
+            method.get_name(),
+            Some(instance),
+            parameter_struct,
+            parameters_list.as_slice(),
+        )?;
+
+        let parameter_types = [slot_type.into(), parameter_struct.get_type().into()];
+        let function_type = match self
+            .index
+            .find_return_type(method.get_name())
+            .and_then(|it| self.index.find_effective_type(it))
+        {
+            Some(return_type) => {
+                self.llvm_index.get_associated_type(return_type.get_name())?.fn_type(&parameter_types, false)
+            }
+            None => self.llvm.context.void_type().fn_type(&parameter_types, false),
+        };
+        let function = self.llvm.builder.build_pointer_cast(
+            function,
+            function_type.ptr_type(AddressSpace::from(ADDRESS_SPACE_GENERIC)),
+            "",
+        );
+        let callable = CallableValue::try_from(function)
+            .map_err(|_| Diagnostic::codegen_error("Pointer was not a function pointer", operator))?;
+
+        self.register_debug_location(operator);
+        let call = self.llvm.builder.build_call(callable, &arguments_list, "call");
+        let value = self.get_call_result(call);
+
+        self.assign_output_values(parameter_struct, method.get_name(), parameters_list)?;
+
+        value
+    }
+
     /// copies the output values to the assigned output variables
     /// - `parameter_struct` a pointer to a struct-instance that holds all function-parameters
     /// - `function_name` the name of the callable
@@ -590,7 +763,7 @@ impl<'ink, 'b> ExpressionCodeGenerator<'ink, 'b> {
                 self.assign_output_value(&CallParameterAssignment {
                     assignment: assignment_statement,
                     function_name,
-                    index: index as u32,
+                    index: self.index.get_positional_parameter_location(function_name, index),
                     parameter_struct,
                 })?
             }
@@ -849,6 +1022,8 @@ impl<'ink, 'b> ExpressionCodeGenerator<'ink, 'b> {
                             .map(|class_ptr| class_ptr.into_pointer_value())
                             .ok_or_else(|| Diagnostic::cannot_generate_call_statement(operator))
                     })?;
+                    // the method may be inherited from a base class
+                    let class_ptr = self.cast_to_instance_of(class_ptr, pou.get_parent_pou_name())?;
                     let call_ptr =
                         self.allocate_function_struct_instance(implementation.get_call_name(), operator)?;
                     (Some(class_ptr), call_ptr)
@@ -999,6 +1174,23 @@ impl<'ink, 'b> ExpressionCodeGenerator<'ink, 'b> {
             return Ok(ptr_value.into());
         }
 
+        // interfaces are passed as a temporary value pointing to the passed instance
+        if let Some(hint) = self.annotations.get_type_hint(argument, self.index) {
+            let actual_type = self.annotations.get_type_or_void(argument, self.index);
+            if hint.get_type_information().is_interface()
+                && !actual_type.get_type_information().is_interface()
+            {
+                let value = self.generate_interface_value(
+                    hint.get_type_information(),
+                    actual_type.get_type_information(),
+                    argument,
+                )?;
+                let argument = self.llvm.builder.build_alloca(value.get_type(), "");
+                self.llvm.builder.build_store(argument, value);
+                return Ok(argument.into());
+            }
+        }
+
         // Generate the element pointer, then...
         let value = {
             let value = self.generate_expression_value(argument)?;
@@ -1042,6 +1234,11 @@ impl<'ink, 'b> ExpressionCodeGenerator<'ink, 'b> {
                     "",
                 ));
             }
+
+            // ...a derived instance passed for one of its base classes is passed as its base instance
+            if self.index.is_derived_from(actual_type_info.get_name(), target_type_info.get_name()) {
+                return Ok(self.cast_to_instance_of(value, target_type_info.get_name())?.into());
+            }
         }
 
         // ...check if we can bitcast an array to a pointer, i.e. `[81 x i8]*` should be passed as a `i8*`
@@ -1185,7 +1382,7 @@ impl<'ink, 'b> ExpressionCodeGenerator<'ink, 'b> {
             let parameter = self.generate_call_struct_argument_assignment(&CallParameterAssignment {
                 assignment: stmt,
                 function_name: pou_name,
-                index: i as u32,
+                index: self.index.get_positional_parameter_location(pou_name, i),
                 parameter_struct,
             })?;
             if let Some(parameter) = parameter {
@@ -1397,17 +1594,49 @@ impl<'ink, 'b> ExpressionCodeGenerator<'ink, 'b> {
                     }
                 }
                 Some(StatementAnnotation::Variable { qualified_name, .. }) => {
-                    let member_location = self
+                    let member = self
                         .index
                         .find_fully_qualified_variable(qualified_name)
-                        .map(VariableIndexEntry::get_location_in_parent)
                         .ok_or_else(|| Diagnostic::unresolved_reference(qualified_name, offset))?;
-                    let gep: PointerValue<'_> = self.llvm.get_member_pointer_from_struct(
-                        *qualifier,
-                        member_location,
-                        name,
-                        offset,
-                    )?;
+                    let member_location = member.get_location_in_parent();
+                    let qualifier = match qualified_name.rsplit_once('.') {
+                        // all members of a union are stored at its start, the qualifier is reinterpreted as
+                        // a pointer to the member
+                        Some((container, _))
+                            if self
+                                .index
+                                .find_effective_type_info(container)
+                                .is_some_and(DataTypeInformation::is_union) =>
+                        {
+                            let member_type = self.llvm_index.get_associated_type(member.get_type_name())?;
+                            return Ok(self.llvm.builder.build_pointer_cast(
+                                *qualifier,
+                                member_type.ptr_type(AddressSpace::from(ADDRESS_SPACE_GENERIC)),
+                                name,
+                            ));
+                        }
+                        // a member inherited from a base class or struct, the qualifier points to a derived instance
+                        Some((container, _))
+                            if self
+                                .index
+                                .find_pou(container)
+                                .is_some_and(|it| it.is_function_block() || it.is_class())
+                                || self.index.find_effective_type_info(container).is_some_and(|it| {
+                                    matches!(
+                                        it,
+                                        DataTypeInformation::Struct {
+                                            source: StructSource::OriginalDeclaration,
+                                            ..
+                                        }
+                                    )
+                                }) =>
+                        {
+                            self.cast_to_instance_of(*qualifier, container)?
+                        }
+                        _ => *qualifier,
+                    };
+                    let gep: PointerValue<'_> =
+                        self.llvm.get_member_pointer_from_struct(qualifier, member_location, name, offset)?;
 
                     return Ok(gep);
                 }
@@ -1439,6 +1668,36 @@ impl<'ink, 'b> ExpressionCodeGenerator<'ink, 'b> {
         }
     }
 
+    /// generates the pointer to the current instance referenced by `THIS`, or to its base instance referenced
+    /// by `SUPER`. Both are passed as the first parameter of the implementation.
+    fn generate_instance_pointer(&self, expression: &AstNode) -> Result<BasicValueEnum<'ink>, Diagnostic> {
+        let instance = self
+            .get_function_context(expression)?
+            .function
+            .get_first_param()
+            .map(BasicValueEnum::into_pointer_value)
+            .ok_or_else(|| Diagnostic::codegen_error("Cannot find the current instance", expression))?;
+        let pointer_type =
+            self.llvm_index.get_associated_type(self.get_type_hint_for(expression)?.get_name())?;
+
+        Ok(self.llvm.builder.build_pointer_cast(instance, pointer_type.into_pointer_type(), "").into())
+    }
+
+    /// casts a pointer to an instance of a FUNCTION_BLOCK or CLASS into a pointer to the given `pou`. Since
+    /// every derived instance starts with its `__BASE` instance, this is valid for all of `pou`'s subclasses.
+    fn cast_to_instance_of(
+        &self,
+        instance: PointerValue<'ink>,
+        pou: &str,
+    ) -> Result<PointerValue<'ink>, Diagnostic> {
+        let pou_type = self.llvm_index.get_associated_type(pou)?;
+        Ok(self.llvm.builder.build_pointer_cast(
+            instance,
+            pou_type.ptr_type(AddressSpace::from(ADDRESS_SPACE_GENERIC)),
+            "",
+        ))
+    }
+
     fn deref(&self, accessor_ptr: PointerValue<'ink>) -> PointerValue<'ink> {
         self.llvm.load_pointer(&accessor_ptr, "deref").into_pointer_value()
     }
@@ -1471,8 +1730,14 @@ impl<'ink, 'b> ExpressionCodeGenerator<'ink, 'b> {
         accessor_ptr: PointerValue<'ink>,
         statement: &AstNode,
     ) -> PointerValue<'ink> {
-        if self.annotations.get(statement).is_some_and(|opt| opt.is_auto_deref()) {
-            self.deref(accessor_ptr)
+        let annotation = self.annotations.get(statement);
+        if annotation.is_some_and(|opt| opt.is_auto_deref()) {
+            let value = self.deref(accessor_ptr);
+            // a `REFERENCE TO` may be unassigned, just like an explicitly dereferenced pointer
+            if annotation.is_some_and(StatementAnnotation::is_reference_to) {
+                self.generate_null_pointer_check(value);
+            }
+            value
         } else {
             accessor_ptr
         }
@@ -1752,6 +2017,56 @@ impl<'ink, 'b> ExpressionCodeGenerator<'ink, 'b> {
         result
     }
 
+    /// raises `EXCEPTION_DIVIDE_BY_ZERO` if the given divisor is zero, so the division can be caught by `__TRY`
+    fn generate_division_by_zero_check(&self, divisor: IntValue<'ink>) {
+        // divisions by a constant other than zero cannot fail
+        if divisor.get_zero_extended_constant().is_some_and(|it| it != 0) {
+            return;
+        }
+        let is_zero = |builder: &Builder<'ink>| {
+            builder.build_int_compare(IntPredicate::EQ, divisor, divisor.get_type().const_zero(), "")
+        };
+        self.generate_exception_check(is_zero, EXCEPTION_DIVIDE_BY_ZERO, "division_by_zero", "division");
+    }
+
+    /// raises `EXCEPTION_NULL_POINTER` if the given pointer is null, so the dereference can be caught by `__TRY`
+    fn generate_null_pointer_check(&self, pointer: PointerValue<'ink>) {
+        let is_null = |builder: &Builder<'ink>| builder.build_is_null(pointer, "");
+        self.generate_exception_check(is_null, EXCEPTION_NULL_POINTER, "null_pointer", "dereference");
+    }
+
+    /// raises the exception with the given code if the given condition holds and continues in a new block
+    /// otherwise. Nothing is generated unless the POU is generated with runtime checks or if there is no
+    /// insert block, e.g. in a global initializer.
+    fn generate_exception_check(
+        &self,
+        condition: impl Fn(&Builder<'ink>) -> IntValue<'ink>,
+        code: u64,
+        raise_block_name: &str,
+        continue_block_name: &str,
+    ) {
+        if !self.function_context.is_some_and(|it| it.runtime_checks) {
+            return;
+        }
+        let (Some(raise), Some(current_block)) = (
+            self.llvm_index.find_associated_implementation(RAISE_FUNCTION_NAME),
+            self.llvm.builder.get_insert_block(),
+        ) else {
+            return;
+        };
+
+        let builder = &self.llvm.builder;
+        let context = self.llvm.context;
+        let continue_block = context.insert_basic_block_after(current_block, continue_block_name);
+        let raise_block = context.insert_basic_block_after(current_block, raise_block_name);
+        builder.build_conditional_branch(condition(builder), raise_block, continue_block);
+
+        builder.position_at_end(raise_block);
+        builder.build_call(raise, &[context.i32_type().const_int(code, false).into()], "");
+        builder.build_unreachable();
+        builder.position_at_end(continue_block);
+    }
+
     /// if the given `value` is a pointer value, it converts the pointer into an int_value to access the pointer's
     /// address, if the given `value` is already an IntValue it is returned as is
     pub fn convert_to_int_value_if_pointer(&self, value: BasicValueEnum<'ink>) -> IntValue<'ink> {
@@ -2067,11 +2382,10 @@ impl<'ink, 'b> ExpressionCodeGenerator<'ink, 'b> {
 
     /// generates a struct literal value with the given value assignments (ExpressionList)
     fn generate_literal_struct(&self, assignments: &AstNode) -> Result<ExpressionValue<'ink>, Diagnostic> {
-        if let DataTypeInformation::Struct { name: struct_name, members, .. } =
+        if let DataTypeInformation::Struct { name: struct_name, .. } =
             self.get_type_hint_info_for(assignments)?
         {
-            let mut uninitialized_members: FxHashSet<&VariableIndexEntry> = FxHashSet::from_iter(members);
-            let mut member_values: Vec<(u32, BasicValueEnum<'ink>)> = Vec::new();
+            let mut member_values: FxHashMap<&str, BasicValueEnum<'ink>> = FxHashMap::default();
             for assignment in flatten_expression_list(assignments) {
                 if let AstStatement::Assignment(data) = assignment.get_stmt() {
                     if let Some(StatementAnnotation::Variable { qualified_name, .. }) =
@@ -2082,11 +2396,8 @@ impl<'ink, 'b> ExpressionCodeGenerator<'ink, 'b> {
                                 Diagnostic::unresolved_reference(qualified_name, data.left.as_ref())
                             })?;
 
-                        let index_in_parent = member.get_location_in_parent();
                         let value = self.generate_expression(data.right.as_ref())?;
-
-                        uninitialized_members.remove(member);
-                        member_values.push((index_in_parent, value));
+                        member_values.insert(member.get_qualified_name(), value);
                     } else {
                         return Err(Diagnostic::codegen_error(
                             "struct member lvalue required as left operand of assignment",
@@ -2101,39 +2412,7 @@ impl<'ink, 'b> ExpressionCodeGenerator<'ink, 'b> {
                 }
             }
 
-            //fill the struct with fields we didnt mention yet
-            for member in uninitialized_members {
-                let initial_value = self
-                    .llvm_index
-                    .find_associated_variable_value(member.get_qualified_name())
-                    // .or_else(|| self.index.find_associated_variable_value(name))
-                    .or_else(|| self.llvm_index.find_associated_initial_value(member.get_type_name()))
-                    .ok_or_else(|| {
-                        Diagnostic::cannot_generate_initializer(member.get_qualified_name(), assignments)
-                    })?;
-
-                member_values.push((member.get_location_in_parent(), initial_value));
-            }
-            let struct_type = self.llvm_index.get_associated_type(struct_name)?.into_struct_type();
-            if member_values.len() == struct_type.count_fields() as usize {
-                member_values.sort_by(|(a, _), (b, _)| a.cmp(b));
-                let ordered_values: Vec<BasicValueEnum<'ink>> =
-                    member_values.iter().map(|(_, v)| *v).collect();
-
-                Ok(ExpressionValue::RValue(
-                    struct_type.const_named_struct(ordered_values.as_slice()).as_basic_value_enum(),
-                ))
-            } else {
-                Err(Diagnostic::codegen_error(
-                    format!(
-                        "Expected {} fields for Struct {}, but found {}.",
-                        struct_type.count_fields(),
-                        struct_name,
-                        member_values.len()
-                    ),
-                    assignments,
-                ))
-            }
+            self.generate_struct_value(struct_name, &member_values, assignments).map(ExpressionValue::RValue)
         } else {
             Err(Diagnostic::codegen_error(
                 format!("Expected Struct-literal, got {assignments:#?}"),
@@ -2142,6 +2421,58 @@ impl<'ink, 'b> ExpressionCodeGenerator<'ink, 'b> {
         }
     }
 
+    /// generates a constant value of the given struct out of the given member values, members without a value
+    /// are set to their initial value. The inherited members of a struct extending another struct are stored
+    /// in its nested `__BASE` value
+    fn generate_struct_value(
+        &self,
+        struct_name: &str,
+        member_values: &FxHashMap<&str, BasicValueEnum<'ink>>,
+        location: &AstNode,
+    ) -> Result<BasicValueEnum<'ink>, Diagnostic> {
+        let members = self.index.get_type(struct_name)?.get_members();
+        let ordered_values = members
+            .iter()
+            .filter(|it| it.is_stored_in_struct())
+            .map(|member| {
+                if let Some(value) = member_values.get(member.get_qualified_name()) {
+                    return Ok(*value);
+                }
+
+                let base = member.get_name() == BASE_MEMBER_NAME
+                    && member_values
+                        .keys()
+                        .filter_map(|it| it.rsplit_once('.'))
+                        .any(|(container, _)| self.index.is_derived_from(struct_name, container));
+                if base {
+                    return self.generate_struct_value(member.get_type_name(), member_values, location);
+                }
+
+                self.llvm_index
+                    .find_associated_variable_value(member.get_qualified_name())
+                    .or_else(|| self.llvm_index.find_associated_initial_value(member.get_type_name()))
+                    .ok_or_else(|| {
+                        Diagnostic::cannot_generate_initializer(member.get_qualified_name(), location)
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let struct_type = self.llvm_index.get_associated_type(struct_name)?.into_struct_type();
+        if ordered_values.len() != struct_type.count_fields() as usize {
+            return Err(Diagnostic::codegen_error(
+                format!(
+                    "Expected {} fields for Struct {}, but found {}.",
+                    struct_type.count_fields(),
+                    struct_name,
+                    ordered_values.len()
+                ),
+                location,
+            ));
+        }
+
+        Ok(struct_type.const_named_struct(ordered_values.as_slice()).as_basic_value_enum())
+    }
+
     /// generates an array literal with the given optional elements (represented as an ExpressionList)
     pub fn generate_literal_array(&self, initializer: &AstNode) -> Result<BasicValueEnum<'ink>, Diagnostic> {
         let array_value = self.generate_literal_array_value(
@@ -2381,8 +2712,11 @@ impl<'ink, 'b> ExpressionCodeGenerator<'ink, 'b> {
         let right_type =
             self.annotations.get_type_or_void(right_statement, self.index).get_type_information();
 
-        // redirect aggregate types
-        if left_type.is_aggregate() && right_type.is_aggregate() {
+        if left_type.is_interface() && !right_type.is_interface() {
+            let value = self.generate_interface_value(left_type, right_type, right_statement)?;
+            self.llvm.builder.build_store(left, value);
+        } else if left_type.is_aggregate() && right_type.is_aggregate() {
+            // redirect aggregate types
             let right =
                 self.generate_expression_value(right_statement)?.get_basic_value_enum().into_pointer_value();
             self.build_memcpy(
@@ -2400,6 +2734,33 @@ impl<'ink, 'b> ExpressionCodeGenerator<'ink, 'b> {
         Ok(())
     }
 
+    /// converts the instance referenced by `instance` into a value of the interface type `interface`,
+    /// consisting of a pointer to the instance and a pointer to the vtable of the instance's POU
+    fn generate_interface_value(
+        &self,
+        interface: &DataTypeInformation,
+        instance_type: &DataTypeInformation,
+        instance: &AstNode,
+    ) -> Result<StructValue<'ink>, Diagnostic> {
+        let byte_pointer = self.llvm_index.get_associated_type(BYTE_POINTER_TYPE)?.into_pointer_type();
+        let data = self.generate_lvalue(instance)?;
+        let vtable_name = get_vtable_name(instance_type.get_name(), interface.get_name());
+        let vtable = self.llvm_index.find_global_value(&vtable_name).ok_or_else(|| {
+            Diagnostic::codegen_error(format!("Cannot find vtable {vtable_name}"), instance)
+        })?;
+
+        let interface_type = self.llvm_index.get_associated_type(interface.get_name())?.into_struct_type();
+        let data = self.llvm.builder.build_pointer_cast(data, byte_pointer, "");
+        let vtable = self.llvm.builder.build_pointer_cast(vtable.as_pointer_value(), byte_pointer, "");
+        let value = self
+            .llvm
+            .builder
+            .build_insert_value(interface_type.get_undef(), data, 0, "")
+            .and_then(|it| self.llvm.builder.build_insert_value(it, vtable, 1, ""))
+            .ok_or_else(|| Diagnostic::codegen_error("Cannot create interface value", instance))?;
+        Ok(value.into_struct_value())
+    }
+
     fn build_memcpy(
         &self,
         left: inkwell::values::PointerValue<'ink>,
@@ -2605,14 +2966,20 @@ impl<'ink, 'b> ExpressionCodeGenerator<'ink, 'b> {
                 }
             }
 
+            // THIS^ or SUPER^, the pointer is not stored in a variable
+            (ReferenceAccess::Deref, Some(base)) if matches!(base.get_stmt(), AstStatement::This | AstStatement::Super) => {
+                self.generate_instance_pointer(base).map(|it| ExpressionValue::LValue(it.into_pointer_value()))
+            }
+
             // base^
             (ReferenceAccess::Deref, Some(base)) => {
                 let ptr = self.generate_expression_value(base)?;
-                Ok(ExpressionValue::LValue(
-                    self.llvm
-                        .load_pointer(&ptr.get_basic_value_enum().into_pointer_value(), "deref")
-                        .into_pointer_value(),
-                ))
+                let value = self
+                    .llvm
+                    .load_pointer(&ptr.get_basic_value_enum().into_pointer_value(), "deref")
+                    .into_pointer_value();
+                self.generate_null_pointer_check(value);
+                Ok(ExpressionValue::LValue(value))
             }
 
             // &base
@@ -378,6 +378,10 @@ impl<'a> Llvm<'a> {
                         type_size?,
                     )
                     .map(|_| ())
+            } else if value.is_array_value() {
+                // a constant array, e.g. of instances which are initialized with their vtable
+                self.builder.build_store(variable_to_initialize, value);
+                Ok(())
             } else {
                 unreachable!("initializing an array should be memcpy-able or memset-able");
             };
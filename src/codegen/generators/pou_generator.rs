@@ -23,7 +23,10 @@ use crate::{
 /// - generates a struct-datatype for the POU's members
 /// - generates a function for the pou
 /// - declares a global instance if the POU is a PROGRAM
-use crate::index::{ArgumentType, FxIndexMap, FxIndexSet, ImplementationIndexEntry, VariableIndexEntry};
+use crate::index::{
+    ArgumentType, FxIndexMap, FxIndexSet, ImplementationIndexEntry, PouIndexEntry, VariableIndexEntry,
+    BASE_MEMBER_NAME,
+};
 
 use crate::index::Index;
 use index::VariableType;
@@ -31,7 +34,7 @@ use index::VariableType;
 use inkwell::{
     module::{Linkage, Module},
     types::{BasicMetadataTypeEnum, BasicTypeEnum, FunctionType},
-    values::{BasicValue, BasicValueEnum, FunctionValue},
+    values::{BasicValue, BasicValueEnum, FunctionValue, GlobalValue},
     AddressSpace,
 };
 use inkwell::{
@@ -41,6 +44,7 @@ use inkwell::{
 use plc_ast::ast::{AstNode, Implementation, PouType};
 use plc_diagnostics::diagnostics::{Diagnostic, INTERNAL_LLVM_ERROR};
 use plc_source::source_location::SourceLocation;
+use plc_util::convention::qualified_name;
 use rustc_hash::FxHashMap;
 use section_mangler::{FunctionArgument, SectionMangler};
 
@@ -50,6 +54,7 @@ pub struct PouGenerator<'ink, 'cg> {
     annotations: &'cg AstAnnotations,
     llvm_index: &'cg LlvmTypedIndex<'ink>,
     online_change: &'cg OnlineChange,
+    runtime_checks: bool,
 }
 
 /// Creates opaque implementations for all callable items in the index
@@ -79,7 +84,9 @@ pub fn generate_implementation_stubs<'ink>(
         })
         .collect::<FxIndexMap<_, _>>();
     for (name, implementation) in implementations {
-        if !implementation.is_generic() {
+        // builtins are generated inline at their call site
+        let is_builtin = index.find_pou(name).is_some_and(PouIndexEntry::is_builtin);
+        if !implementation.is_generic() && !is_builtin {
             let curr_f =
                 pou_generator.generate_implementation_stub(implementation, module, debug, &mut llvm_index)?;
             llvm_index.associate_implementation(name, curr_f)?;
@@ -149,6 +156,184 @@ pub fn generate_global_constants_for_pou_members<'ink>(
     Ok(local_llvm_index)
 }
 
+/// Generates the dispatch tables of all POUs implementing an interface or taking part in an `EXTENDS`
+/// hierarchy. The dispatch table of a POU for an interface holds a thunk for every method of the interface
+/// (in declaration order), which forwards a call to the POU's implementation of the method. A thunk takes
+/// the interface method's parameter struct, so a caller does not need to know the POU of the instance it is
+/// calling. Likewise, the dispatch table of a POU for its class hierarchy holds a thunk for every method
+/// declared within the hierarchy (see [`Index::get_virtual_methods`]), taking the parameter struct of the
+/// method's first declaration.
+/// Dispatch tables of POUs declared in other units are only declared.
+pub fn generate_vtables<'ink>(
+    module: &Module<'ink>,
+    llvm: &Llvm<'ink>,
+    dependencies: &FxIndexSet<Dependency>,
+    index: &Index,
+    llvm_index: &LlvmTypedIndex<'ink>,
+    location: &str,
+) -> Result<LlvmTypedIndex<'ink>, Diagnostic> {
+    let mut local_llvm_index = LlvmTypedIndex::default();
+    let pous = dependencies
+        .iter()
+        .filter_map(|it| if let Dependency::Datatype(name) = it { index.find_pou(name) } else { None })
+        .filter(|it| it.is_function_block() || it.is_class());
+
+    for pou in pous {
+        let is_in_unit =
+            index.find_implementation_by_name(pou.get_name()).is_some_and(|it| it.is_in_unit(location));
+        let mut vtables = vec![];
+        if index.find_member(pou.get_name(), index::VTABLE_MEMBER_NAME).is_some() {
+            vtables.push((
+                index::get_class_vtable_name(pou.get_name()),
+                index.get_virtual_methods(pou.get_name()),
+            ));
+        }
+        for interface in index.get_implemented_interfaces(pou.get_name()) {
+            vtables.push((
+                index::get_vtable_name(pou.get_name(), &interface.name),
+                interface.get_methods(index),
+            ));
+        }
+
+        for (name, methods) in vtables {
+            let vtable = generate_vtable(module, llvm, index, llvm_index, pou, &name, methods, is_in_unit)?;
+            local_llvm_index.associate_global(&name, vtable)?;
+        }
+    }
+
+    Ok(local_llvm_index)
+}
+
+/// Generates the dispatch table with the given `name`, holding a thunk for each of the given `methods`. The
+/// table may already have been declared when generating the initial value of the `pou`.
+#[allow(clippy::too_many_arguments)]
+fn generate_vtable<'ink>(
+    module: &Module<'ink>,
+    llvm: &Llvm<'ink>,
+    index: &Index,
+    llvm_index: &LlvmTypedIndex<'ink>,
+    pou: &PouIndexEntry,
+    name: &str,
+    methods: Vec<&PouIndexEntry>,
+    is_in_unit: bool,
+) -> Result<GlobalValue<'ink>, Diagnostic> {
+    let slot_type = llvm.context.i8_type().ptr_type(AddressSpace::from(ADDRESS_SPACE_GENERIC));
+    let vtable_type = slot_type.array_type(methods.len() as u32);
+    let vtable = module
+        .get_global(name)
+        .unwrap_or_else(|| llvm.create_global_variable(module, name, vtable_type.into()))
+        .make_constant();
+
+    if is_in_unit {
+        let slots = methods
+            .into_iter()
+            .map(|method| {
+                let thunk = generate_vtable_thunk(module, llvm, index, llvm_index, pou, method, name)?;
+                Ok(thunk.as_global_value().as_pointer_value().const_cast(slot_type))
+            })
+            .collect::<Result<Vec<_>, Diagnostic>>()?;
+        vtable.set_initializer(&slot_type.const_array(&slots));
+    } else {
+        vtable.set_linkage(Linkage::External);
+    }
+
+    Ok(vtable)
+}
+
+/// Generates a function which calls the given `pou`'s implementation of the interface `method`:
+/// ```llvm
+/// define i32 @__vtable_fb_IA.foo(%fb* %0, %IA.foo* %1) {
+///   %fb.foo_instance = alloca %fb.foo
+///   ; copy the arguments from %1 into %fb.foo_instance
+///   %call = call i32 @fb.foo(%fb* %0, %fb.foo* %fb.foo_instance)
+///   ; copy the outputs from %fb.foo_instance back into %1
+///   ret i32 %call
+/// }
+/// ```
+fn generate_vtable_thunk<'ink>(
+    module: &Module<'ink>,
+    llvm: &Llvm<'ink>,
+    index: &Index,
+    llvm_index: &LlvmTypedIndex<'ink>,
+    pou: &PouIndexEntry,
+    method: &PouIndexEntry,
+    vtable_name: &str,
+) -> Result<FunctionValue<'ink>, Diagnostic> {
+    let implementation = index
+        .find_method(pou.get_name(), method.get_flat_name())
+        .and_then(|it| llvm_index.find_associated_implementation(it.get_name()).zip(Some(it)))
+        .ok_or_else(|| {
+            Diagnostic::codegen_error(
+                format!("No implementation of `{}` found in `{}`", method.get_name(), pou.get_name()),
+                pou.get_location(),
+            )
+        })?;
+    let (function, implementation) = implementation;
+
+    let self_type =
+        llvm_index.get_associated_type(pou.get_name())?.ptr_type(AddressSpace::from(ADDRESS_SPACE_GENERIC));
+    let parameters_type = llvm_index
+        .get_associated_pou_type(method.get_name())?
+        .ptr_type(AddressSpace::from(ADDRESS_SPACE_GENERIC));
+    let thunk_type = match function.get_type().get_return_type() {
+        Some(return_type) => return_type.fn_type(&[self_type.into(), parameters_type.into()], false),
+        None => llvm.context.void_type().fn_type(&[self_type.into(), parameters_type.into()], false),
+    };
+    let thunk = module.add_function(
+        &qualified_name(vtable_name, method.get_flat_name()),
+        thunk_type,
+        Some(Linkage::Internal),
+    );
+    llvm.builder.position_at_end(llvm.context.append_basic_block(thunk, "entry"));
+
+    let (Some(this), Some(parameters)) = (thunk.get_nth_param(0), thunk.get_nth_param(1)) else {
+        unreachable!("thunks always take two parameters")
+    };
+    let parameters = parameters.into_pointer_value();
+    let instance = llvm.create_local_variable(
+        &format!("{}_instance", implementation.get_name()),
+        &llvm_index.get_associated_pou_type(implementation.get_name())?,
+    );
+
+    // the interface's parameters are matched with the implementation's parameters by name, their types are
+    // guaranteed to be the same by the validation
+    let declared_parameters = index
+        .get_declared_parameters(method.get_name())
+        .into_iter()
+        .filter_map(|it| {
+            index.find_member(implementation.get_name(), it.get_name()).map(|member| (it, member))
+        })
+        .collect::<Vec<_>>();
+    let copy = |from: PointerValue<'ink>, from_index: u32, to: PointerValue<'ink>, to_index: u32| {
+        let location = SourceLocation::internal();
+        let source = llvm.get_member_pointer_from_struct(from, from_index, "", &location)?;
+        let target = llvm.get_member_pointer_from_struct(to, to_index, "", &location)?;
+        llvm.builder.build_store(target, llvm.load_pointer(&source, ""));
+        Ok::<_, Diagnostic>(())
+    };
+
+    for (parameter, member) in
+        declared_parameters.iter().filter(|(it, _)| !it.get_variable_type().is_output())
+    {
+        copy(parameters, parameter.get_location_in_parent(), instance, member.get_location_in_parent())?;
+    }
+
+    let this = llvm.builder.build_bitcast(this, function.get_type().get_param_types()[0], "");
+    let call = llvm.builder.build_call(function, &[this.into(), instance.into()], "call");
+
+    for (parameter, member) in declared_parameters.iter().filter(|(it, _)| it.get_variable_type().is_output())
+    {
+        copy(instance, member.get_location_in_parent(), parameters, parameter.get_location_in_parent())?;
+    }
+
+    match call.try_as_basic_value().left() {
+        Some(value) => llvm.builder.build_return(Some(&value)),
+        None => llvm.builder.build_return(None),
+    };
+
+    Ok(thunk)
+}
+
 impl<'ink, 'cg> PouGenerator<'ink, 'cg> {
     /// creates a new PouGenerator
     ///
@@ -160,7 +345,13 @@ impl<'ink, 'cg> PouGenerator<'ink, 'cg> {
         llvm_index: &'cg LlvmTypedIndex<'ink>,
         online_change: &'cg OnlineChange,
     ) -> PouGenerator<'ink, 'cg> {
-        PouGenerator { llvm, index, annotations, llvm_index, online_change }
+        PouGenerator { llvm, index, annotations, llvm_index, online_change, runtime_checks: false }
+    }
+
+    /// generates runtime checks raising an exception on integer divisions by zero and null pointer dereferences
+    pub fn with_runtime_checks(mut self, runtime_checks: bool) -> Self {
+        self.runtime_checks = runtime_checks;
+        self
     }
 
     fn mangle_function(&self, implementation: &ImplementationIndexEntry) -> Result<String, Diagnostic> {
@@ -429,6 +620,7 @@ impl<'ink, 'cg> PouGenerator<'ink, 'cg> {
                 },
             )?,
             function: current_function,
+            runtime_checks: self.runtime_checks,
             blocks,
         };
 
@@ -695,6 +887,40 @@ impl<'ink, 'cg> PouGenerator<'ink, 'cg> {
             index.associate_loaded_local_variable(type_name, name, variable)?;
         }
 
+        self.generate_inherited_variable_accessors(index, type_name)
+    }
+
+    /// generates accessors for the members inherited from the super classes of the given pou, which
+    /// are reached through the (nested) `__BASE` instances
+    fn generate_inherited_variable_accessors(
+        &self,
+        index: &mut LlvmTypedIndex<'ink>,
+        type_name: &str,
+    ) -> Result<(), Diagnostic> {
+        let mut current = type_name;
+        while let Some(super_class) = self.index.find_pou(current).and_then(PouIndexEntry::get_super_class) {
+            let Some(base) =
+                index.find_loaded_associated_variable_value(&qualified_name(current, BASE_MEMBER_NAME))
+            else {
+                break;
+            };
+
+            let members = self
+                .index
+                .get_pou_members(super_class)
+                .iter()
+                .filter(|it| !(it.is_var_external() || it.is_temp() || it.is_return()));
+            for (member_index, member) in members.enumerate() {
+                let ptr = self
+                    .llvm
+                    .builder
+                    .build_struct_gep(base, member_index as u32, member.get_name())
+                    .expect(INTERNAL_LLVM_ERROR);
+                index.associate_loaded_local_variable(super_class, member.get_name(), ptr)?;
+            }
+            current = super_class;
+        }
+
         Ok(())
     }
 
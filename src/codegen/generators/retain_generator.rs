@@ -0,0 +1,405 @@
+use inkwell::{
+    context::Context,
+    module::{Linkage, Module},
+    types::{BasicType, BasicTypeEnum},
+    values::{BasicValueEnum, FunctionValue, PointerValue},
+    AddressSpace,
+};
+use plc_ast::ast::CompilationUnit;
+use plc_diagnostics::diagnostics::Diagnostic;
+use plc_source::source_location::SourceLocation;
+
+use super::{
+    llvm::{GlobalValueExt, Llvm},
+    ADDRESS_SPACE_GENERIC,
+};
+use crate::{
+    codegen::llvm_index::LlvmTypedIndex,
+    expression_path::{get_element_index, ExpressionPath, ExpressionPathElement},
+    index::{Index, VariableIndexEntry, VTABLE_MEMBER_NAME},
+    typesystem::DataTypeInformation,
+};
+
+/// The name of the exported retain image holding a copy of all retained variables
+pub const RETAIN_IMAGE_NAME: &str = "__retain_image";
+
+/// The name of the exported descriptor of the retain image's layout
+pub const RETAIN_INFO_NAME: &str = "__retain_info";
+
+/// The function copying the retained variables into the retain image, i.e. `void __retain_save()`
+pub const RETAIN_SAVE_FUNCTION_NAME: &str = "__retain_save";
+
+/// The function copying the retain image back into the retained variables, i.e. `void __retain_restore()`
+pub const RETAIN_RESTORE_FUNCTION_NAME: &str = "__retain_restore";
+
+/// The flags of a retain image entry declared `RETAIN` and `PERSISTENT` respectively
+pub const RETAIN_FLAG: u64 = 1;
+pub const PERSISTENT_FLAG: u64 = 2;
+
+/// A variable declared in a `RETAIN` or `PERSISTENT` block, referenced by its path from a global variable or
+/// a program instance, e.g. `mainProg.counters[1].value`
+#[derive(Debug)]
+pub struct RetainedVariable<'idx> {
+    pub name: String,
+    pub path: ExpressionPath<'idx>,
+    pub variable: &'idx VariableIndexEntry,
+    /// the hash of the variable's type layout, see [`get_layout_hash`]
+    pub hash: u64,
+}
+
+/// Collects all retained variables of the project in a stable order. A retained variable is stored as a
+/// whole, the variables of a retained function block instance are not collected separately. Variables in
+/// arrays of function block instances are collected once per element
+pub fn collect_retained_variables(index: &Index) -> Vec<RetainedVariable<'_>> {
+    index
+        .filter_instances(|it, _| !it.is_retained() && it.is_stored_in_struct())
+        .filter(|(_, it)| it.is_retained() && it.is_stored_in_struct() && !it.is_constant())
+        .flat_map(|(path, variable)| {
+            let hash = get_layout_hash(variable.get_type_name(), index);
+            path.expand_elements(index).into_iter().filter_map(move |path| {
+                let name = path.expand(index).pop()?;
+                Some(RetainedVariable { name, path, variable, hash })
+            })
+        })
+        .collect()
+}
+
+/// Returns whether the unit declares a `RETAIN` or `PERSISTENT` variable block. The retain image is generated
+/// into the modules of these units, so they depend on all variables referenced by the image
+pub fn declares_retained_variables(unit: &CompilationUnit) -> bool {
+    unit.global_vars
+        .iter()
+        .chain(unit.units.iter().flat_map(|it| it.variable_blocks.iter()))
+        .any(|it| it.retain || it.persistent)
+}
+
+/// Returns a hash of the memory layout of the given type. The hash changes if a member is added, removed,
+/// renamed or reordered or if the type of a member changes
+pub fn get_layout_hash(type_name: &str, index: &Index) -> u64 {
+    let mut layout = String::new();
+    describe_layout(type_name, index, &mut layout);
+    fnv_hash(layout.as_bytes())
+}
+
+fn describe_layout(type_name: &str, index: &Index, layout: &mut String) {
+    let type_info = index.get_effective_type_or_void_by_name(type_name).get_type_information();
+    match type_info {
+        DataTypeInformation::Struct { name, members, .. } => {
+            layout.push_str(name);
+            layout.push('{');
+            for member in members.iter().filter(|it| it.is_stored_in_struct()) {
+                layout.push_str(member.get_name());
+                layout.push(':');
+                describe_layout(member.get_type_name(), index, layout);
+                layout.push(';');
+            }
+            layout.push('}');
+        }
+        DataTypeInformation::Array { inner_type_name, dimensions, .. } => {
+            let dimensions = dimensions
+                .iter()
+                .map(|it| match it.get_range_inclusive(index) {
+                    Ok(range) => format!("{}..{}", range.start(), range.end()),
+                    Err(_) => "*".to_string(),
+                })
+                .collect::<Vec<_>>();
+            layout.push_str(&format!("[{}]", dimensions.join(",")));
+            describe_layout(inner_type_name, index, layout);
+        }
+        _ => layout.push_str(&format!("{}:{}", type_info.get_name(), type_info.get_size(index).value())),
+    }
+}
+
+/// A 64-bit FNV-1a hash, which unlike the hashers of the standard library is stable across builds
+pub(crate) fn fnv_hash(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| (hash ^ *byte as u64).wrapping_mul(0x100_0000_01b3))
+}
+
+/// Generates the retain image of the project's `RETAIN` and `PERSISTENT` variables, so a runtime can store
+/// them and restore them after a restart. The image is a struct with a member per retained variable, the
+/// generated `__retain_save` and `__retain_restore` functions copy the variables into the image and back:
+/// ```llvm
+/// %__retain_image = type { i32, %myFb }
+/// %__retain_entry = type { i8*, i64, i64, i64, i32 }
+/// @__retain_image = weak global %__retain_image zeroinitializer
+/// @__retain_info = weak constant { i64, i64, i8*, void ()*, void ()*, i32, [2 x %__retain_entry] } {
+///   i64 -5843241581624873491,
+///   i64 ptrtoint (%__retain_image* getelementptr (%__retain_image, %__retain_image* null, i32 1) to i64),
+///   i8* bitcast (%__retain_image* @__retain_image to i8*),
+///   void ()* @__retain_save,
+///   void ()* @__retain_restore,
+///   i32 2,
+///   [2 x %__retain_entry] [...]
+/// }
+/// ```
+/// The descriptor `__retain_info` holds the hash of the image's layout, the image's size, the image, the
+/// copy functions and the number of entries, followed by an entry per retained variable with its name, the
+/// hash of its type's layout, its offset and size in the image and its flags (`1` for `RETAIN`, `2` for
+/// `PERSISTENT`). Restoring the image keeps the `__vtable` members of retained instances, they point to the
+/// vtables of the running program. Every module of a unit declaring retained variables defines the same
+/// image, the linker keeps one of them. No image is generated if the unit does not declare any retained
+/// variables.
+pub fn generate_retain_image<'ink>(
+    module: &Module<'ink>,
+    llvm: &Llvm<'ink>,
+    unit: &CompilationUnit,
+    index: &Index,
+    llvm_index: &LlvmTypedIndex<'ink>,
+) -> Result<(), Diagnostic> {
+    if !declares_retained_variables(unit) {
+        return Ok(());
+    }
+
+    let variables = collect_retained_variables(index);
+    if variables.is_empty() {
+        return Ok(());
+    }
+
+    let context = llvm.context;
+    let pointers = variables
+        .iter()
+        .map(|it| {
+            get_variable_pointer(context, &it.path, index, llvm_index).ok_or_else(|| {
+                Diagnostic::codegen_error(
+                    format!("Cannot generate retain image entry, `{}` is not declared", it.name),
+                    &it.variable.source_location,
+                )
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let field_types = pointers
+        .iter()
+        .map(|it| BasicTypeEnum::try_from(it.get_type().get_element_type()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| {
+            Diagnostic::codegen_error(
+                "Invalid type of a retained variable",
+                &variables[0].variable.source_location,
+            )
+        })?;
+
+    let image_type = context.opaque_struct_type(RETAIN_IMAGE_NAME);
+    image_type.set_body(&field_types, false);
+    let image = llvm.create_global_variable(module, RETAIN_IMAGE_NAME, image_type.into());
+    image.set_linkage(Linkage::WeakAny);
+    image.set_initializer(&image_type.const_zero());
+    let image = image.as_pointer_value();
+
+    let fields = (0..variables.len() as u32).map(|idx| get_element(context, image, idx)).collect::<Vec<_>>();
+    let vtables = variables
+        .iter()
+        .map(|it| get_vtable_positions(it.variable.get_type_name(), index))
+        .collect::<Vec<_>>();
+    let save = generate_copy_function(module, llvm, RETAIN_SAVE_FUNCTION_NAME, &fields, &pointers, &[])?;
+    let restore =
+        generate_copy_function(module, llvm, RETAIN_RESTORE_FUNCTION_NAME, &pointers, &fields, &vtables)?;
+
+    let pointer_type = context.i8_type().ptr_type(AddressSpace::from(ADDRESS_SPACE_GENERIC));
+    let size_type = context.i64_type();
+    let entry_type = context.opaque_struct_type("__retain_entry");
+    entry_type.set_body(
+        &[
+            pointer_type.into(),
+            size_type.into(),
+            size_type.into(),
+            size_type.into(),
+            context.i32_type().into(),
+        ],
+        false,
+    );
+
+    let null_image = image_type.ptr_type(AddressSpace::from(ADDRESS_SPACE_GENERIC)).const_null();
+    let mut layout = String::new();
+    let mut entries = vec![];
+    for (idx, (variable, field_type)) in variables.iter().zip(&field_types).enumerate() {
+        layout.push_str(&format!("{}={};", variable.name, variable.hash));
+
+        let name = generate_entry_name(module, llvm, idx, &variable.name)?;
+        let offset = get_element(context, null_image, idx as u32).const_to_int(size_type);
+        let size = field_type.size_of().ok_or_else(|| {
+            Diagnostic::codegen_error(
+                format!("Cannot determine the size of retained variable `{}`", variable.name),
+                &variable.variable.source_location,
+            )
+        })?;
+        let flags = (variable.variable.is_retain() as u64 * RETAIN_FLAG)
+            | (variable.variable.is_persistent() as u64 * PERSISTENT_FLAG);
+
+        let fields: [BasicValueEnum; 5] = [
+            name.const_cast(pointer_type).into(),
+            size_type.const_int(variable.hash, false).into(),
+            offset.into(),
+            size.into(),
+            context.i32_type().const_int(flags, false).into(),
+        ];
+        entries.push(entry_type.const_named_struct(&fields));
+    }
+
+    let entries = entry_type.const_array(&entries);
+    let function_type = save.get_type().ptr_type(AddressSpace::from(ADDRESS_SPACE_GENERIC));
+    let fields: [BasicValueEnum; 7] = [
+        size_type.const_int(fnv_hash(layout.as_bytes()), false).into(),
+        image_type.size_of().expect("the retain image has a size").into(),
+        image.const_cast(pointer_type).into(),
+        save.as_global_value().as_pointer_value().const_cast(function_type).into(),
+        restore.as_global_value().as_pointer_value().const_cast(function_type).into(),
+        context.i32_type().const_int(variables.len() as u64, false).into(),
+        entries.into(),
+    ];
+    let info_type = context.struct_type(&fields.map(|it| it.get_type()), false);
+    let info = llvm.create_global_variable(module, RETAIN_INFO_NAME, info_type.into()).make_constant();
+    info.set_linkage(Linkage::WeakAny);
+    info.set_initializer(&context.const_struct(&fields, false));
+    Ok(())
+}
+
+/// Returns a pointer to the variable referenced by the given path, starting at a global variable or a
+/// program instance
+fn get_variable_pointer<'ink>(
+    context: &'ink Context,
+    path: &ExpressionPath,
+    index: &Index,
+    llvm_index: &LlvmTypedIndex<'ink>,
+) -> Option<PointerValue<'ink>> {
+    let (ExpressionPathElement::Name(root), segments) = path.get_segments().split_first()? else {
+        return None;
+    };
+
+    let mut pointer = llvm_index.find_global_value(root)?.as_pointer_value();
+    let root_type_name = index.find_global_variable(root).map(|it| it.get_type_name()).or_else(|| {
+        index.find_pou(root).filter(|it| it.is_program()).and_then(|it| it.get_instance_struct_type_name())
+    })?;
+    let mut type_info = index.find_effective_type_info(root_type_name)?;
+    for segment in segments {
+        let (pointer_to_inner, inner_type_name) = match (segment, type_info) {
+            (ExpressionPathElement::Name(member), DataTypeInformation::Struct { name, .. }) => {
+                let variable = index.find_member(name, member).filter(|it| it.is_stored_in_struct())?;
+                let member_pointer = if type_info.is_union() {
+                    // all members of a union are stored at its start
+                    let member_type = llvm_index.find_associated_type(variable.get_type_name())?;
+                    pointer.const_cast(member_type.ptr_type(AddressSpace::from(ADDRESS_SPACE_GENERIC)))
+                } else {
+                    get_element(context, pointer, variable.get_location_in_parent())
+                };
+                (member_pointer, variable.get_type_name())
+            }
+            (
+                ExpressionPathElement::ArrayAccess(indices),
+                DataTypeInformation::Array { inner_type_name, dimensions, .. },
+            ) => {
+                let element = get_element_index(indices, dimensions, index)?;
+                (get_element(context, pointer, element), inner_type_name.as_str())
+            }
+            _ => return None,
+        };
+        pointer = pointer_to_inner;
+        type_info = index.find_effective_type_info(inner_type_name)?;
+    }
+    Some(pointer)
+}
+
+/// Returns a constant pointer to the member or element at the given position of the pointed to aggregate
+fn get_element<'ink>(context: &'ink Context, pointer: PointerValue<'ink>, idx: u32) -> PointerValue<'ink> {
+    let i32_type = context.i32_type();
+    // SAFETY: the positions are taken from the index, which declares the aggregate types
+    unsafe { pointer.const_in_bounds_gep(&[i32_type.const_zero(), i32_type.const_int(idx as u64, false)]) }
+}
+
+/// Returns the positions of all `__vtable` members within a value of the given type, e.g. `[0]` for an
+/// instance of a FUNCTION_BLOCK or `[1, 2, 0]` for the third instance of an array stored in the second member
+fn get_vtable_positions(type_name: &str, index: &Index) -> Vec<Vec<u32>> {
+    match index.get_effective_type_or_void_by_name(type_name).get_type_information() {
+        type_info @ DataTypeInformation::Struct { members, .. } if !type_info.is_union() => members
+            .iter()
+            .filter(|it| it.is_stored_in_struct())
+            .flat_map(|member| {
+                let position = member.get_location_in_parent();
+                if member.get_name() == VTABLE_MEMBER_NAME {
+                    return vec![vec![position]];
+                }
+                get_vtable_positions(member.get_type_name(), index)
+                    .into_iter()
+                    .map(|it| std::iter::once(position).chain(it).collect())
+                    .collect()
+            })
+            .collect(),
+        DataTypeInformation::Array { inner_type_name, dimensions, .. } => {
+            let inner = get_vtable_positions(inner_type_name, index);
+            if inner.is_empty() {
+                return vec![];
+            }
+            let length =
+                dimensions.iter().map(|it| it.get_length(index).unwrap_or_default()).product::<u32>();
+            (0..length)
+                .flat_map(|element| {
+                    inner.iter().map(move |it| std::iter::once(element).chain(it.iter().copied()))
+                })
+                .map(Iterator::collect)
+                .collect()
+        }
+        _ => vec![],
+    }
+}
+
+/// Generates a function copying each of the `sources` into the corresponding `destinations`. The members
+/// at the given `kept` positions of a destination, i.e. its `__vtable` members, keep their value since they
+/// hold addresses of the running program
+fn generate_copy_function<'ink>(
+    module: &Module<'ink>,
+    llvm: &Llvm<'ink>,
+    name: &str,
+    destinations: &[PointerValue<'ink>],
+    sources: &[PointerValue<'ink>],
+    kept: &[Vec<Vec<u32>>],
+) -> Result<FunctionValue<'ink>, Diagnostic> {
+    let context = llvm.context;
+    // the project may declare the function as `{external}` to call it
+    let function = module
+        .get_function(name)
+        .filter(|it| it.count_basic_blocks() == 0)
+        .unwrap_or_else(|| module.add_function(name, context.void_type().fn_type(&[], false), None));
+    function.set_linkage(Linkage::WeakAny);
+    llvm.builder.position_at_end(context.append_basic_block(function, "entry"));
+    for (idx, (destination, source)) in destinations.iter().zip(sources).enumerate() {
+        let kept_values = kept
+            .get(idx)
+            .into_iter()
+            .flatten()
+            .map(|position| {
+                let pointer = position.iter().fold(*destination, |it, idx| get_element(context, it, *idx));
+                (pointer, llvm.builder.build_load(pointer, ""))
+            })
+            .collect::<Vec<_>>();
+        let size = source.get_type().get_element_type().size_of().ok_or_else(|| {
+            Diagnostic::codegen_error(
+                "Cannot determine the size of a retained variable",
+                SourceLocation::internal(),
+            )
+        })?;
+        llvm.builder
+            .build_memcpy(*destination, 1, *source, 1, size)
+            .map_err(|it| Diagnostic::codegen_error(it, SourceLocation::internal()))?;
+        for (pointer, value) in kept_values {
+            llvm.builder.build_store(pointer, value);
+        }
+    }
+    llvm.builder.build_return(None);
+    Ok(function)
+}
+
+/// Generates a private constant holding the null-terminated name of a retain image entry
+fn generate_entry_name<'ink>(
+    module: &Module<'ink>,
+    llvm: &Llvm<'ink>,
+    idx: usize,
+    name: &str,
+) -> Result<PointerValue<'ink>, Diagnostic> {
+    let len = name.len() + 1;
+    let global = llvm.create_global_variable(
+        module,
+        &format!("{RETAIN_INFO_NAME}.{idx}"),
+        llvm.context.i8_type().array_type(len as u32).into(),
+    );
+    global.make_constant().make_private().set_initializer(&llvm.create_const_utf8_string(name, len)?);
+    Ok(global.as_pointer_value())
+}
@@ -1,5 +1,6 @@
 // Copyright (c) 2020 Ghaith Hachem and Mathias Rieder
 use super::{
+    exception_generator::{get_exception_frame_type, EXCEPTION_FRAME_ALIGNMENT, SETJMP_FUNCTION_NAME},
     expression_generator::{to_i1, ExpressionCodeGenerator, ExpressionValue},
     llvm::Llvm,
 };
@@ -18,16 +19,18 @@ use inkwell::{
     builder::Builder,
     context::Context,
     values::{FunctionValue, PointerValue},
+    IntPredicate,
 };
 use plc_ast::{
     ast::{
         flatten_expression_list, Allocation, AstNode, AstStatement, JumpStatement, LabelStatement, Operator,
         ReferenceAccess, ReferenceExpr,
     },
-    control_statements::{AstControlStatement, ConditionalBlock, ReturnStatement},
+    control_statements::{AstControlStatement, CatchBlock, ConditionalBlock, ReturnStatement, TryStatement},
 };
 use plc_diagnostics::diagnostics::{Diagnostic, INTERNAL_LLVM_ERROR};
 use plc_source::source_location::SourceLocation;
+use plc_util::convention::{EXCEPTION_FRAME_NAME, RAISE_FUNCTION_NAME};
 use rustc_hash::FxHashMap;
 
 /// the full context when generating statements inside a POU
@@ -38,6 +41,8 @@ pub struct FunctionContext<'ink, 'b> {
     pub function: FunctionValue<'ink>,
     /// The blocks/labels this function can use
     pub blocks: FxHashMap<String, BasicBlock<'ink>>,
+    /// whether integer divisions and dereferences raise an exception if they fail
+    pub runtime_checks: bool,
 }
 
 /// the StatementCodeGenerator is used to generate statements (For, If, etc.) or expressions (references, literals, etc.)
@@ -269,6 +274,7 @@ impl<'a, 'b> StatementCodeGenerator<'a, 'b> {
             AstControlStatement::Case(stmt) => {
                 self.generate_case_statement(llvm_index, &stmt.selector, &stmt.case_blocks, &stmt.else_block)
             }
+            AstControlStatement::Try(stmt) => self.generate_try_statement(llvm_index, stmt),
         }
     }
 
@@ -828,6 +834,116 @@ impl<'a, 'b> StatementCodeGenerator<'a, 'b> {
         Ok(())
     }
 
+    /// generates a try-statement by pushing an exception frame, which `__plc_raise` resumes with `_longjmp`
+    ///
+    /// __TRY `body` __CATCH(`exception`) `catch_block` __FINALLY `finally_block` __ENDTRY
+    ///
+    /// The frame is popped before the `__CATCH` and `__FINALLY` clauses, so exceptions raised there propagate to
+    /// the enclosing frame. Without a `__CATCH` clause the exception is raised again after the `__FINALLY` clause.
+    fn generate_try_statement(
+        &self,
+        llvm_index: &'a LlvmTypedIndex<'b>,
+        stmt: &TryStatement,
+    ) -> Result<(), Diagnostic> {
+        let (builder, current_function, context) = self.get_llvm_deps();
+        let (Some(frames), Some(setjmp), Some(raise)) = (
+            llvm_index.find_global_value(EXCEPTION_FRAME_NAME),
+            llvm_index.find_associated_implementation(SETJMP_FUNCTION_NAME),
+            llvm_index.find_associated_implementation(RAISE_FUNCTION_NAME),
+        ) else {
+            return Err(Diagnostic::codegen_error(
+                "Cannot generate __TRY without the exception runtime",
+                SourceLocation::internal(),
+            ));
+        };
+        let frames = frames.as_pointer_value();
+
+        // allocate the frame in the entry block, so a __TRY inside a loop does not grow the stack
+        let current_block = builder.get_insert_block().expect(INTERNAL_LLVM_ERROR);
+        let entry_block = current_function.get_first_basic_block().expect(INTERNAL_LLVM_ERROR);
+        match entry_block.get_first_instruction() {
+            Some(instruction) => builder.position_before(&instruction),
+            None => builder.position_at_end(entry_block),
+        }
+        let frame = builder.build_alloca(get_exception_frame_type(context), "exception_frame");
+        frame
+            .as_instruction()
+            .expect(INTERNAL_LLVM_ERROR)
+            .set_alignment(EXCEPTION_FRAME_ALIGNMENT)
+            .map_err(|it| Diagnostic::codegen_error(it, SourceLocation::internal()))?;
+        builder.position_at_end(current_block);
+
+        // push the frame and save the execution context
+        let frame_pointer =
+            builder.build_pointer_cast(frame, frames.get_type().get_element_type().into_pointer_type(), "");
+        let previous = builder.build_struct_gep(frame, 1, "previous_frame").expect(INTERNAL_LLVM_ERROR);
+        builder.build_store(previous, builder.build_load(frames, ""));
+        builder.build_store(frames, frame_pointer);
+        let context_saved = builder
+            .build_call(setjmp, &[frame_pointer.into()], "")
+            .try_as_basic_value()
+            .left()
+            .expect(INTERNAL_LLVM_ERROR)
+            .into_int_value();
+        let code = builder.build_struct_gep(frame, 2, "code").expect(INTERNAL_LLVM_ERROR);
+        let raised = builder.build_int_compare(
+            IntPredicate::NE,
+            context_saved,
+            context_saved.get_type().const_zero(),
+            "raised",
+        );
+
+        let try_block = context.append_basic_block(current_function, "try");
+        let catch_block = context.append_basic_block(current_function, "catch");
+        let finally_block = context.append_basic_block(current_function, "finally");
+        let continue_block = context.append_basic_block(current_function, "continue");
+        builder.build_conditional_branch(raised, catch_block, try_block);
+        let pop_frame = || builder.build_store(frames, builder.build_load(previous, ""));
+
+        builder.position_at_end(try_block);
+        let body_generator = StatementCodeGenerator {
+            current_loop_exit: None,
+            current_loop_continue: None,
+            load_prefix: self.load_prefix.clone(),
+            load_suffix: self.load_suffix.clone(),
+            ..*self
+        };
+        body_generator.generate_body(&stmt.body)?;
+        pop_frame();
+        builder.build_unconditional_branch(finally_block);
+
+        builder.position_at_end(catch_block);
+        pop_frame();
+        if let Some(CatchBlock { exception, body }) = &stmt.catch_block {
+            if let Some(exception) = exception {
+                let exception = self.create_expr_generator(llvm_index).generate_lvalue(exception)?;
+                let exception_type = exception.get_type().get_element_type().into_int_type();
+                let value =
+                    builder.build_int_cast(builder.build_load(code, "").into_int_value(), exception_type, "");
+                builder.build_store(exception, value);
+            }
+            self.generate_body(body)?;
+        }
+        builder.build_unconditional_branch(finally_block);
+
+        builder.position_at_end(finally_block);
+        self.generate_body(&stmt.finally_block)?;
+        if stmt.catch_block.is_some() {
+            builder.build_unconditional_branch(continue_block);
+        } else {
+            // nobody caught the exception, pass it on to the enclosing frame
+            let rethrow_block = context
+                .insert_basic_block_after(builder.get_insert_block().expect(INTERNAL_LLVM_ERROR), "rethrow");
+            builder.build_conditional_branch(raised, rethrow_block, continue_block);
+            builder.position_at_end(rethrow_block);
+            builder.build_call(raise, &[builder.build_load(code, "").into()], "");
+            builder.build_unreachable();
+        }
+
+        builder.position_at_end(continue_block);
+        Ok(())
+    }
+
     fn get_llvm_deps(&self) -> (&Builder, FunctionValue, &Context) {
         (&self.llvm.builder, self.function_context.function, self.llvm.context)
     }
@@ -0,0 +1,143 @@
+use inkwell::{
+    module::Module,
+    values::{BasicValueEnum, PointerValue},
+    AddressSpace,
+};
+use plc_ast::ast::CompilationUnit;
+use plc_diagnostics::diagnostics::Diagnostic;
+use plc_source::source_location::SourceLocation;
+use plc_util::convention::qualified_name;
+use rustc_hash::FxHashMap;
+
+use super::{
+    llvm::{GlobalValueExt, Llvm},
+    ADDRESS_SPACE_GENERIC,
+};
+use crate::{codegen::llvm_index::LlvmTypedIndex, index::Index};
+
+/// The name of the exported task table
+pub const TASK_TABLE_NAME: &str = "__task_table";
+
+/// Generates the task table for the program instances configured in the given unit, so a runtime can
+/// schedule the programs from the compiled object:
+/// ```llvm
+/// %entry = type { i8*, i64, i32, i8*, i8*, i8* }
+/// @__task_table = constant { i32, [1 x %entry] } {
+///   i32 1,
+///   [1 x %entry] [%entry { i8* @__task_table.fast, i64 10000000, i32 1, i8* null, i8* @mainProg, i8* @mainProg_instance }]
+/// }
+/// ```
+/// The table holds the number of entries, followed by an entry per program instance with the name of its
+/// task, the task's interval in nanoseconds (`0` for event-driven tasks), the task's priority, the `BOOL`
+/// variable triggering the task (or `null` for periodic tasks), the program's function and the program's
+/// instance. No table is generated if the unit does not declare a configuration.
+pub fn generate_task_table<'ink>(
+    module: &Module<'ink>,
+    llvm: &Llvm<'ink>,
+    unit: &CompilationUnit,
+    index: &Index,
+    llvm_index: &LlvmTypedIndex<'ink>,
+) -> Result<(), Diagnostic> {
+    if unit.configurations.is_empty() {
+        return Ok(());
+    }
+
+    let context = llvm.context;
+    let pointer_type = context.i8_type().ptr_type(AddressSpace::from(ADDRESS_SPACE_GENERIC));
+    let entry_type = context.struct_type(
+        &[
+            pointer_type.into(),
+            context.i64_type().into(),
+            context.i32_type().into(),
+            pointer_type.into(),
+            pointer_type.into(),
+            pointer_type.into(),
+        ],
+        false,
+    );
+
+    let mut task_names: FxHashMap<String, PointerValue<'ink>> = FxHashMap::default();
+    let mut entries = vec![];
+    for resource in unit.configurations.iter().flat_map(|it| it.resources.iter()) {
+        for program in &resource.programs {
+            let Some(task) = index
+                .get_configured_programs()
+                .get(&qualified_name(&resource.name, &program.instance_name).to_lowercase())
+                .and_then(|it| it.find_task(index))
+            else {
+                return Err(missing_value(
+                    &format!("task of `{}`", program.instance_name),
+                    &program.location,
+                ));
+            };
+
+            let task_name = match task_names.get(&task.qualified_name) {
+                Some(name) => *name,
+                None => {
+                    let name = generate_task_name(module, llvm, &task.qualified_name, &task.name)?;
+                    task_names.insert(task.qualified_name.clone(), name);
+                    name
+                }
+            };
+
+            let single = match &task.single {
+                Some(single) => llvm_index
+                    .find_global_value(single)
+                    .ok_or_else(|| missing_value(single, &task.location))?
+                    .as_pointer_value()
+                    .const_cast(pointer_type),
+                None => pointer_type.const_null(),
+            };
+            let function = llvm_index
+                .find_associated_implementation(&program.program)
+                .ok_or_else(|| missing_value(&program.program, &program.program_location))?;
+            let instance = llvm_index
+                .find_global_value(&program.program)
+                .ok_or_else(|| missing_value(&program.program, &program.program_location))?;
+
+            let fields: [BasicValueEnum; 6] = [
+                task_name.const_cast(pointer_type).into(),
+                context
+                    .i64_type()
+                    .const_int(task.get_interval_value(index).unwrap_or_default() as u64, true)
+                    .into(),
+                context
+                    .i32_type()
+                    .const_int(task.get_priority_value(index).unwrap_or_default() as u64, false)
+                    .into(),
+                single.into(),
+                function.as_global_value().as_pointer_value().const_cast(pointer_type).into(),
+                instance.as_pointer_value().const_cast(pointer_type).into(),
+            ];
+            entries.push(entry_type.const_named_struct(&fields));
+        }
+    }
+
+    let entries = entry_type.const_array(&entries);
+    let count = context.i32_type().const_int(entries.get_type().len() as u64, false);
+    let table_type = context.struct_type(&[count.get_type().into(), entries.get_type().into()], false);
+    let table = llvm.create_global_variable(module, TASK_TABLE_NAME, table_type.into()).make_constant();
+    table.set_initializer(&context.const_struct(&[count.into(), entries.into()], false));
+    Ok(())
+}
+
+/// Generates a private constant holding the null-terminated name of a task
+fn generate_task_name<'ink>(
+    module: &Module<'ink>,
+    llvm: &Llvm<'ink>,
+    qualified_name: &str,
+    name: &str,
+) -> Result<PointerValue<'ink>, Diagnostic> {
+    let len = name.len() + 1;
+    let global = llvm.create_global_variable(
+        module,
+        &format!("{TASK_TABLE_NAME}.{qualified_name}"),
+        llvm.context.i8_type().array_type(len as u32).into(),
+    );
+    global.make_constant().make_private().set_initializer(&llvm.create_const_utf8_string(name, len)?);
+    Ok(global.as_pointer_value())
+}
+
+fn missing_value(name: &str, location: &SourceLocation) -> Diagnostic {
+    Diagnostic::codegen_error(format!("Cannot generate task table entry, `{name}` is not declared"), location)
+}
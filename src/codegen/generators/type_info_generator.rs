@@ -0,0 +1,293 @@
+use inkwell::{
+    context::Context,
+    module::{Linkage, Module},
+    types::BasicType,
+    values::{BasicValueEnum, IntValue, PointerValue},
+    AddressSpace,
+};
+use plc_ast::ast::{PouType, TypeNature};
+use plc_diagnostics::diagnostics::Diagnostic;
+use plc_source::source_location::SourceLocation;
+
+use super::{
+    llvm::{GlobalValueExt, Llvm},
+    retain_generator::fnv_hash,
+    ADDRESS_SPACE_GENERIC,
+};
+use crate::{
+    codegen::llvm_index::LlvmTypedIndex,
+    index::{FxIndexSet, Index, VariableIndexEntry, BASE_MEMBER_NAME},
+    resolver::Dependency,
+    typesystem::{
+        DataType, DataTypeInformation, InternalType, StringEncoding, StructSource, DEFAULT_STRING_LEN,
+    },
+};
+
+/// The prefix of the symbols holding the type information of a type, e.g. `__type_info_Point`
+pub const TYPE_INFO_PREFIX: &str = "__type_info_";
+
+/// The builtin struct describing a type, the header of every type information table
+pub const TYPE_INFO_TYPE: &str = "__TYPE_INFO";
+
+/// The builtin struct describing a member of a type, following the header of a type information table
+pub const TYPE_MEMBER_TYPE: &str = "__TYPE_MEMBER";
+
+/// A member listed in the type information of a struct, function block, class or program. The members of
+/// a base are listed before the members of the derived type
+pub struct ReflectedMember<'idx> {
+    pub name: &'idx str,
+    pub variable: &'idx VariableIndexEntry,
+    /// the positions of the member and the bases containing it, starting at the reflected type
+    path: Vec<u32>,
+}
+
+/// Returns the name of the symbol holding the type information of the given type
+pub fn get_type_info_name(type_name: &str) -> String {
+    format!("{TYPE_INFO_PREFIX}{type_name}")
+}
+
+/// Returns the name of the given type as it would be declared, e.g. `STRING[10]` or `ARRAY[1..3] OF INT`
+/// for the internal types of inline declarations
+pub fn get_type_name(data_type: &DataType, index: &Index) -> String {
+    if !data_type.get_name().starts_with("__") {
+        return data_type.get_name().to_string();
+    }
+
+    let get_inner_name =
+        |name: &str| index.find_type(name).map(|it| get_type_name(it, index)).unwrap_or(name.to_string());
+    match data_type.get_type_information() {
+        DataTypeInformation::String { size, encoding } => {
+            let keyword = match encoding {
+                StringEncoding::Utf8 => "STRING",
+                StringEncoding::Utf16 => "WSTRING",
+            };
+            size.as_int_value(index)
+                .map(|it| format!("{keyword}[{}]", it - 1))
+                .unwrap_or_else(|_| keyword.to_string())
+        }
+        DataTypeInformation::Array { inner_type_name, dimensions, .. } => {
+            let dimensions = dimensions
+                .iter()
+                .map(|it| match it.get_range_inclusive(index) {
+                    Ok(range) => format!("{}..{}", range.start(), range.end()),
+                    Err(_) => "*".to_string(),
+                })
+                .collect::<Vec<_>>();
+            format!("ARRAY[{}] OF {}", dimensions.join(", "), get_inner_name(inner_type_name))
+        }
+        DataTypeInformation::Struct {
+            source: StructSource::Internal(InternalType::VariableLengthArray { inner_type_name, ndims }),
+            ..
+        } => format!("ARRAY[{}] OF {}", vec!["*"; *ndims].join(", "), get_inner_name(inner_type_name)),
+        DataTypeInformation::Pointer { inner_type_name, .. } => {
+            format!("REF_TO {}", get_inner_name(inner_type_name))
+        }
+        _ => data_type.get_name().to_string(),
+    }
+}
+
+/// Returns the id of the given type, the 32-bit hash of its case-insensitive name (see [`get_type_name`]).
+/// The id is the same for every build and every unit of a project
+pub fn get_type_id(data_type: &DataType, index: &Index) -> u32 {
+    let hash = fnv_hash(get_type_name(data_type, index).to_lowercase().as_bytes());
+    (hash ^ (hash >> 32)) as u32
+}
+
+/// Returns the class of the given type, i.e. the generic type nature of an elementary type (e.g. `ANY_INT`)
+/// or the kind of a derived type (e.g. `STRUCT`)
+pub fn get_type_class(data_type: &DataType, index: &Index) -> &'static str {
+    let data_type = index.find_effective_type(data_type).unwrap_or(data_type);
+    match data_type.get_type_information() {
+        DataTypeInformation::Enum { .. } => return "ENUM",
+        DataTypeInformation::Array { .. } => return "ARRAY",
+        DataTypeInformation::Pointer { .. } => return "POINTER",
+        DataTypeInformation::Struct { source, .. } => {
+            return match source {
+                StructSource::OriginalDeclaration => "STRUCT",
+                StructSource::Union => "UNION",
+                StructSource::Pou(PouType::FunctionBlock) => "FUNCTION_BLOCK",
+                StructSource::Pou(PouType::Class) => "CLASS",
+                StructSource::Pou(PouType::Program) => "PROGRAM",
+                StructSource::Internal(InternalType::VariableLengthArray { .. }) => "ARRAY",
+                StructSource::Internal(InternalType::Interface) => "INTERFACE",
+                _ => "ANY",
+            }
+        }
+        _ => {}
+    }
+
+    let nature = data_type.get_nature();
+    [
+        ("ANY_INT", TypeNature::Int),
+        ("ANY_REAL", TypeNature::Real),
+        ("ANY_BIT", TypeNature::Bit),
+        ("ANY_STRING", TypeNature::String),
+        ("ANY_CHAR", TypeNature::Char),
+        ("ANY_DURATION", TypeNature::Duration),
+        ("ANY_DATE", TypeNature::Date),
+    ]
+    .into_iter()
+    .find(|(_, it)| nature.derives_from(*it))
+    .map(|(name, _)| name)
+    .unwrap_or("ANY")
+}
+
+/// Returns true if a type information table can be generated for the given type, i.e. if it is a struct,
+/// a union, a function block, a class or a program
+pub fn has_type_info(data_type: &DataType, index: &Index) -> bool {
+    let data_type = index.find_effective_type(data_type).unwrap_or(data_type);
+    matches!(
+        data_type.get_type_information(),
+        DataTypeInformation::Struct {
+            source: StructSource::OriginalDeclaration
+                | StructSource::Union
+                | StructSource::Pou(PouType::FunctionBlock | PouType::Class | PouType::Program),
+            ..
+        }
+    )
+}
+
+/// Returns the members listed in the type information of the given type, the members of its bases are
+/// listed first. Internal members like the dispatch table of a function block are not listed. Types
+/// without type information have no members
+pub fn get_reflected_members<'idx>(
+    data_type: &'idx DataType,
+    index: &'idx Index,
+) -> Vec<ReflectedMember<'idx>> {
+    let mut members = vec![];
+    if has_type_info(data_type, index) {
+        let data_type = index.find_effective_type(data_type).unwrap_or(data_type);
+        collect_members(data_type, index, vec![], &mut members);
+    }
+    members
+}
+
+fn collect_members<'idx>(
+    data_type: &'idx DataType,
+    index: &'idx Index,
+    path: Vec<u32>,
+    members: &mut Vec<ReflectedMember<'idx>>,
+) {
+    let is_union = data_type.get_type_information().is_union();
+    for variable in data_type.get_members().iter().filter(|it| it.is_stored_in_struct()) {
+        let mut path = path.clone();
+        // all members of a union are stored at its start
+        if !is_union {
+            path.push(variable.get_location_in_parent());
+        }
+
+        if variable.get_name().eq_ignore_ascii_case(BASE_MEMBER_NAME) {
+            if let Some(base) = index.find_effective_type_by_name(variable.get_type_name()) {
+                collect_members(base, index, path, members);
+            }
+        } else if !variable.get_name().starts_with("__") {
+            members.push(ReflectedMember { name: variable.get_name(), variable, path });
+        }
+    }
+}
+
+/// Generates the type information tables of the types queried with `__TYPEINFO` or `__MEMBER_INFO` in the
+/// unit. A table is a read-only header describing the type, followed by an entry per member of the type
+/// (see [`get_reflected_members`]) with its name, its offset in the type, its type id and its size:
+/// ```llvm
+/// @__type_info_Point = weak constant { %__TYPE_INFO, [2 x %__TYPE_MEMBER] } {
+///   %__TYPE_INFO { [81 x i8] c"Point\00...", i32 1203472587, i64 8, i32 2 },
+///   [2 x %__TYPE_MEMBER] [
+///     %__TYPE_MEMBER { [81 x i8] c"x\00...", i64 0, i32 ..., i64 4 },
+///     %__TYPE_MEMBER { [81 x i8] c"y\00...", i64 4, i32 ..., i64 4 }
+///   ]
+/// }
+/// ```
+/// Every module querying a type defines the same table, the linker keeps one of them.
+pub fn generate_type_infos<'ink>(
+    module: &Module<'ink>,
+    llvm: &Llvm<'ink>,
+    dependencies: &FxIndexSet<Dependency>,
+    index: &Index,
+    llvm_index: &LlvmTypedIndex<'ink>,
+) -> Result<LlvmTypedIndex<'ink>, Diagnostic> {
+    let mut type_infos = LlvmTypedIndex::default();
+    let queried_types = dependencies
+        .iter()
+        .filter_map(|it| match it {
+            Dependency::Variable(name) => name.strip_prefix(TYPE_INFO_PREFIX),
+            _ => None,
+        })
+        .filter_map(|it| index.find_effective_type_by_name(it));
+
+    for data_type in queried_types {
+        let name = get_type_info_name(data_type.get_name());
+        let get_type = |type_name: &str| {
+            llvm_index.find_associated_type(type_name).ok_or_else(|| {
+                Diagnostic::codegen_error(
+                    format!("Cannot generate the type information of `{}`", data_type.get_name()),
+                    SourceLocation::internal(),
+                )
+            })
+        };
+        let header_type = get_type(TYPE_INFO_TYPE)?.into_struct_type();
+        let member_type = get_type(TYPE_MEMBER_TYPE)?.into_struct_type();
+        let reflected_type = get_type(data_type.get_name())?;
+
+        let context = llvm.context;
+        let size_type = context.i64_type();
+        let null = reflected_type.ptr_type(AddressSpace::from(ADDRESS_SPACE_GENERIC)).const_null();
+        let members = get_reflected_members(data_type, index)
+            .iter()
+            .map(|member| {
+                let member_type = get_type(member.variable.get_type_name())?;
+                let offset = if member.path.is_empty() {
+                    size_type.const_zero()
+                } else {
+                    get_offset(context, null, &member.path).const_to_int(size_type)
+                };
+                let fields: [BasicValueEnum; 4] = [
+                    get_type_name_field(llvm, member.name)?,
+                    offset.into(),
+                    get_type_id_field(context, member.variable.get_type_name(), index),
+                    member_type.size_of().unwrap_or(size_type.const_zero()).into(),
+                ];
+                Ok(fields)
+            })
+            .collect::<Result<Vec<_>, Diagnostic>>()?
+            .iter()
+            .map(|it| member_type.const_named_struct(it))
+            .collect::<Vec<_>>();
+
+        let fields: [BasicValueEnum; 4] = [
+            get_type_name_field(llvm, &get_type_name(data_type, index))?,
+            context.i32_type().const_int(get_type_id(data_type, index) as u64, false).into(),
+            reflected_type.size_of().unwrap_or(size_type.const_zero()).into(),
+            context.i32_type().const_int(members.len() as u64, false).into(),
+        ];
+        let fields: [BasicValueEnum; 2] =
+            [header_type.const_named_struct(&fields).into(), member_type.const_array(&members).into()];
+        let table_type = context.struct_type(&fields.map(|it| it.get_type()), false);
+        let table = llvm.create_global_variable(module, &name, table_type.into()).make_constant();
+        table.set_linkage(Linkage::WeakAny);
+        table.set_initializer(&context.const_struct(&fields, false));
+        type_infos.associate_global(&name, table)?;
+    }
+
+    Ok(type_infos)
+}
+
+/// Returns the offset of the member at the given path as a constant pointer into a type stored at `null`
+fn get_offset<'ink>(context: &'ink Context, null: PointerValue<'ink>, path: &[u32]) -> PointerValue<'ink> {
+    let i32_type = context.i32_type();
+    let indices = std::iter::once(i32_type.const_zero())
+        .chain(path.iter().map(|it| i32_type.const_int(*it as u64, false)))
+        .collect::<Vec<IntValue>>();
+    // SAFETY: the positions are taken from the index, which declares the aggregate types
+    unsafe { null.const_in_bounds_gep(&indices) }
+}
+
+fn get_type_name_field<'ink>(llvm: &Llvm<'ink>, name: &str) -> Result<BasicValueEnum<'ink>, Diagnostic> {
+    // the name is stored in a `STRING`, i.e. it is truncated to 80 characters
+    llvm.create_const_utf8_string(name, DEFAULT_STRING_LEN as usize + 1)
+}
+
+fn get_type_id_field<'ink>(context: &'ink Context, type_name: &str, index: &Index) -> BasicValueEnum<'ink> {
+    let id = index.find_type(type_name).map(|it| get_type_id(it, index)).unwrap_or_default();
+    context.i32_type().const_int(id as u64, false).into()
+}
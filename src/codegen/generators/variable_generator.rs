@@ -2,6 +2,7 @@
 
 /// offers operations to generate global variables
 use crate::{
+    builtins::BUILTIN_UNIT_NAME,
     codegen::{
         const_expressions::{ConstExpression, UnresolvableKind},
         debug::Debug,
@@ -12,7 +13,10 @@ use crate::{
     resolver::{AnnotationMap, AstAnnotations, Dependency},
     OnlineChange,
 };
-use inkwell::{module::Module, values::GlobalValue};
+use inkwell::{
+    module::{Linkage, Module},
+    values::GlobalValue,
+};
 use plc_ast::ast::LinkageType;
 use plc_diagnostics::diagnostics::Diagnostic;
 use section_mangler::SectionMangler;
@@ -85,8 +89,13 @@ impl<'ctx, 'b> VariableGenerator<'ctx, 'b> {
         });
 
         for (name, variable) in &globals {
-            let linkage =
-                if !variable.is_in_unit(location) { LinkageType::External } else { variable.get_linkage() };
+            // builtin constants are not declared by any unit, every unit referencing them defines its own copy
+            let is_builtin = variable.source_location.get_file_name() == Some(BUILTIN_UNIT_NAME);
+            let linkage = if !is_builtin && !variable.is_in_unit(location) {
+                LinkageType::External
+            } else {
+                variable.get_linkage()
+            };
             let global_variable = self.generate_global_variable(variable, linkage).map_err(|err| {
                 match err.get_error_code() {
                     //If we encounter a missing function or an invalid reference, we wrap it in a more generic issue
@@ -98,6 +107,9 @@ impl<'ctx, 'b> VariableGenerator<'ctx, 'b> {
                     _ => err,
                 }
             })?;
+            if is_builtin {
+                global_variable.set_linkage(Linkage::LinkOnceODR);
+            }
             index.associate_global(name, global_variable)?;
             //generate debug info
             self.debug.create_global_variable(
@@ -1,6 +1,6 @@
 // Copyright (c) 2020 Ghaith Hachem and Mathias Rieder
 
-use crate::test_utils::tests::{codegen, generate_with_empty_program};
+use crate::test_utils::tests::{codegen, codegen_with_runtime_checks, generate_with_empty_program};
 
 #[test]
 fn program_with_variables_and_references_generates_void_function_and_struct_and_body() {
@@ -917,6 +917,39 @@ fn class_method_in_pou() {
     insta::assert_snapshot!(result)
 }
 
+#[test]
+fn fb_property_accesses_are_generated_as_accessor_calls() {
+    let result = codegen(
+        "
+        FUNCTION_BLOCK fb
+            VAR
+                value : DINT;
+            END_VAR
+
+            PROPERTY prop : DINT
+                GET
+                    prop := value;
+                END_GET
+                SET
+                    value := prop;
+                END_SET
+            END_PROPERTY
+        END_FUNCTION_BLOCK
+
+        PROGRAM prg
+        VAR
+          instance : fb;
+          x : DINT;
+        END_VAR
+        instance.prop := 5;
+        x := instance.prop;
+        END_PROGRAM
+        ",
+    );
+
+    insta::assert_snapshot!(result)
+}
+
 #[test]
 fn fb_method_in_pou() {
     let result = codegen(
@@ -951,6 +984,79 @@ fn fb_method_in_pou() {
     insta::assert_snapshot!(result)
 }
 
+#[test]
+fn interface_method_is_called_through_vtable() {
+    let result = codegen(
+        "
+        INTERFACE ICounter
+            METHOD increment : INT
+                VAR_INPUT step : INT; END_VAR
+            END_METHOD
+        END_INTERFACE
+
+        FUNCTION_BLOCK Counter IMPLEMENTS ICounter
+            VAR
+                count : INT;
+            END_VAR
+
+            METHOD increment : INT
+                VAR_INPUT step : INT; END_VAR
+                count := count + step;
+                increment := count;
+            END_METHOD
+        END_FUNCTION_BLOCK
+
+        PROGRAM prg
+        VAR
+          counter : Counter;
+          itf : ICounter;
+          x : INT;
+        END_VAR
+        itf := counter;
+        x := itf.increment(2);
+        END_PROGRAM
+        ",
+    );
+
+    insta::assert_snapshot!(result)
+}
+
+#[test]
+fn overridden_method_is_called_through_vtable() {
+    let result = codegen(
+        "
+        FUNCTION_BLOCK Base
+            VAR
+                count : INT;
+            END_VAR
+
+            METHOD step : INT
+                step := 1;
+            END_METHOD
+
+            METHOD run
+                count := count + step();
+            END_METHOD
+        END_FUNCTION_BLOCK
+
+        FUNCTION_BLOCK Derived EXTENDS Base
+            METHOD OVERRIDE step : INT
+                step := SUPER^.step() + THIS^.count;
+            END_METHOD
+        END_FUNCTION_BLOCK
+
+        FUNCTION main
+        VAR
+          instance : Derived;
+        END_VAR
+        instance.run();
+        END_FUNCTION
+        ",
+    );
+
+    insta::assert_snapshot!(result)
+}
+
 #[test]
 fn fb_method_called_locally() {
     let result = codegen(
@@ -984,24 +1090,34 @@ fn fb_method_called_locally() {
     ; ModuleID = '<internal>'
     source_filename = "<internal>"
 
-    %foo = type { i32 }
+    %foo = type { i8*, i32 }
     %foo.addToBar = type { i16 }
 
-    @__foo__init = unnamed_addr constant %foo { i32 42 }
+    @__vtable_foo = unnamed_addr constant [1 x i8*] [i8* bitcast (i32 (%foo*, %foo.addToBar*)* @__vtable_foo.addToBar to i8*)]
+    @__foo__init = unnamed_addr constant %foo { i8* bitcast ([1 x i8*]* @__vtable_foo to i8*), i32 42 }
 
     define void @foo(%foo* %0) {
     entry:
-      %bar = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %bar = getelementptr inbounds %foo, %foo* %0, i32 0, i32 1
+      %1 = bitcast %foo* %0 to i8*
+      %2 = bitcast i8* %1 to i8**
+      %3 = load i8*, i8** %2, align 8
+      %4 = bitcast i8* %3 to i8**
+      %5 = getelementptr inbounds i8*, i8** %4, i32 0
+      %6 = load i8*, i8** %5, align 8
       %foo.addToBar_instance = alloca %foo.addToBar, align 8
-      %1 = getelementptr inbounds %foo.addToBar, %foo.addToBar* %foo.addToBar_instance, i32 0, i32 0
-      store i16 42, i16* %1, align 2
-      %call = call i32 @foo.addToBar(%foo* %0, %foo.addToBar* %foo.addToBar_instance)
+      %7 = getelementptr inbounds %foo.addToBar, %foo.addToBar* %foo.addToBar_instance, i32 0, i32 0
+      store i16 42, i16* %7, align 2
+      %8 = bitcast i8* %6 to i32 (i8*, %foo.addToBar*)*
+      %call = call i32 %8(i8* %1, %foo.addToBar* %foo.addToBar_instance)
       ret void
     }
 
     define i32 @foo.addToBar(%foo* %0, %foo.addToBar* %1) {
     entry:
-      %bar = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %bar = getelementptr inbounds %foo, %foo* %0, i32 0, i32 1
       %in = getelementptr inbounds %foo.addToBar, %foo.addToBar* %1, i32 0, i32 0
       %addToBar = alloca i32, align 4
       store i32 0, i32* %addToBar, align 4
@@ -1024,16 +1140,34 @@ fn fb_method_called_locally() {
       call void @llvm.memcpy.p0i8.p0i8.i64(i8* align 1 %0, i8* align 1 bitcast (%foo* @__foo__init to i8*), i64 ptrtoint (%foo* getelementptr (%foo, %foo* null, i32 1) to i64), i1 false)
       store i32 0, i32* %x, align 4
       call void @__init_foo(%foo* %fb)
+      %1 = bitcast %foo* %fb to i8*
+      %2 = bitcast i8* %1 to i8**
+      %3 = load i8*, i8** %2, align 8
+      %4 = bitcast i8* %3 to i8**
+      %5 = getelementptr inbounds i8*, i8** %4, i32 0
+      %6 = load i8*, i8** %5, align 8
       %foo.addToBar_instance = alloca %foo.addToBar, align 8
-      %1 = getelementptr inbounds %foo.addToBar, %foo.addToBar* %foo.addToBar_instance, i32 0, i32 0
-      store i16 3, i16* %1, align 2
-      %call = call i32 @foo.addToBar(%foo* %fb, %foo.addToBar* %foo.addToBar_instance)
+      %7 = getelementptr inbounds %foo.addToBar, %foo.addToBar* %foo.addToBar_instance, i32 0, i32 0
+      store i16 3, i16* %7, align 2
+      %8 = bitcast i8* %6 to i32 (i8*, %foo.addToBar*)*
+      %call = call i32 %8(i8* %1, %foo.addToBar* %foo.addToBar_instance)
       store i32 %call, i32* %x, align 4
       ret void
     }
 
     declare void @__init_foo(%foo*)
 
+    define internal i32 @__vtable_foo.addToBar(%foo* %0, %foo.addToBar* %1) {
+    entry:
+      %foo.addToBar_instance = alloca %foo.addToBar, align 8
+      %2 = getelementptr inbounds %foo.addToBar, %foo.addToBar* %1, i32 0, i32 0
+      %3 = getelementptr inbounds %foo.addToBar, %foo.addToBar* %foo.addToBar_instance, i32 0, i32 0
+      %4 = load i16, i16* %2, align 2
+      store i16 %4, i16* %3, align 2
+      %call = call i32 @foo.addToBar(%foo* %0, %foo.addToBar* %foo.addToBar_instance)
+      ret i32 %call
+    }
+
     ; Function Attrs: argmemonly nofree nounwind willreturn
     declare void @llvm.memcpy.p0i8.p0i8.i64(i8* noalias nocapture writeonly, i8* noalias nocapture readonly, i64, i1 immarg) #0
 
@@ -1041,8 +1175,9 @@ fn fb_method_called_locally() {
     ; ModuleID = '__initializers'
     source_filename = "__initializers"
 
-    %foo = type { i32 }
+    %foo = type { i8*, i32 }
 
+    @__vtable_foo = external unnamed_addr constant [1 x i8*]
     @__foo__init = external global %foo
 
     define void @__init_foo(%foo* %0) {
@@ -1100,24 +1235,34 @@ fn fb_local_method_var_shadows_parent_var() {
     ; ModuleID = '<internal>'
     source_filename = "<internal>"
 
-    %foo = type { i32 }
+    %foo = type { i8*, i32 }
     %foo.addToBar = type { i16, i32 }
 
-    @__foo__init = unnamed_addr constant %foo { i32 42 }
+    @__vtable_foo = unnamed_addr constant [1 x i8*] [i8* bitcast (i32 (%foo*, %foo.addToBar*)* @__vtable_foo.addToBar to i8*)]
+    @__foo__init = unnamed_addr constant %foo { i8* bitcast ([1 x i8*]* @__vtable_foo to i8*), i32 42 }
 
     define void @foo(%foo* %0) {
     entry:
-      %bar = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %bar = getelementptr inbounds %foo, %foo* %0, i32 0, i32 1
+      %1 = bitcast %foo* %0 to i8*
+      %2 = bitcast i8* %1 to i8**
+      %3 = load i8*, i8** %2, align 8
+      %4 = bitcast i8* %3 to i8**
+      %5 = getelementptr inbounds i8*, i8** %4, i32 0
+      %6 = load i8*, i8** %5, align 8
       %foo.addToBar_instance = alloca %foo.addToBar, align 8
-      %1 = getelementptr inbounds %foo.addToBar, %foo.addToBar* %foo.addToBar_instance, i32 0, i32 0
-      store i16 42, i16* %1, align 2
-      %call = call i32 @foo.addToBar(%foo* %0, %foo.addToBar* %foo.addToBar_instance)
+      %7 = getelementptr inbounds %foo.addToBar, %foo.addToBar* %foo.addToBar_instance, i32 0, i32 0
+      store i16 42, i16* %7, align 2
+      %8 = bitcast i8* %6 to i32 (i8*, %foo.addToBar*)*
+      %call = call i32 %8(i8* %1, %foo.addToBar* %foo.addToBar_instance)
       ret void
     }
 
     define i32 @foo.addToBar(%foo* %0, %foo.addToBar* %1) {
     entry:
-      %bar = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %bar = getelementptr inbounds %foo, %foo* %0, i32 0, i32 1
       %in = getelementptr inbounds %foo.addToBar, %foo.addToBar* %1, i32 0, i32 0
       %bar1 = getelementptr inbounds %foo.addToBar, %foo.addToBar* %1, i32 0, i32 1
       %addToBar = alloca i32, align 4
@@ -1142,16 +1287,34 @@ fn fb_local_method_var_shadows_parent_var() {
       call void @llvm.memcpy.p0i8.p0i8.i64(i8* align 1 %0, i8* align 1 bitcast (%foo* @__foo__init to i8*), i64 ptrtoint (%foo* getelementptr (%foo, %foo* null, i32 1) to i64), i1 false)
       store i32 0, i32* %x, align 4
       call void @__init_foo(%foo* %fb)
+      %1 = bitcast %foo* %fb to i8*
+      %2 = bitcast i8* %1 to i8**
+      %3 = load i8*, i8** %2, align 8
+      %4 = bitcast i8* %3 to i8**
+      %5 = getelementptr inbounds i8*, i8** %4, i32 0
+      %6 = load i8*, i8** %5, align 8
       %foo.addToBar_instance = alloca %foo.addToBar, align 8
-      %1 = getelementptr inbounds %foo.addToBar, %foo.addToBar* %foo.addToBar_instance, i32 0, i32 0
-      store i16 3, i16* %1, align 2
-      %call = call i32 @foo.addToBar(%foo* %fb, %foo.addToBar* %foo.addToBar_instance)
+      %7 = getelementptr inbounds %foo.addToBar, %foo.addToBar* %foo.addToBar_instance, i32 0, i32 0
+      store i16 3, i16* %7, align 2
+      %8 = bitcast i8* %6 to i32 (i8*, %foo.addToBar*)*
+      %call = call i32 %8(i8* %1, %foo.addToBar* %foo.addToBar_instance)
       store i32 %call, i32* %x, align 4
       ret void
     }
 
     declare void @__init_foo(%foo*)
 
+    define internal i32 @__vtable_foo.addToBar(%foo* %0, %foo.addToBar* %1) {
+    entry:
+      %foo.addToBar_instance = alloca %foo.addToBar, align 8
+      %2 = getelementptr inbounds %foo.addToBar, %foo.addToBar* %1, i32 0, i32 0
+      %3 = getelementptr inbounds %foo.addToBar, %foo.addToBar* %foo.addToBar_instance, i32 0, i32 0
+      %4 = load i16, i16* %2, align 2
+      store i16 %4, i16* %3, align 2
+      %call = call i32 @foo.addToBar(%foo* %0, %foo.addToBar* %foo.addToBar_instance)
+      ret i32 %call
+    }
+
     ; Function Attrs: argmemonly nofree nounwind willreturn
     declare void @llvm.memcpy.p0i8.p0i8.i64(i8* noalias nocapture writeonly, i8* noalias nocapture readonly, i64, i1 immarg) #0
 
@@ -1159,8 +1322,9 @@ fn fb_local_method_var_shadows_parent_var() {
     ; ModuleID = '__initializers'
     source_filename = "__initializers"
 
-    %foo = type { i32 }
+    %foo = type { i8*, i32 }
 
+    @__vtable_foo = external unnamed_addr constant [1 x i8*]
     @__foo__init = external global %foo
 
     define void @__init_foo(%foo* %0) {
@@ -2749,6 +2913,48 @@ fn structs_members_can_be_referenced() {
     insta::assert_snapshot!(result);
 }
 
+#[test]
+fn union_members_are_accessed_through_pointer_casts() {
+    let result = codegen(
+        "
+        TYPE MyUnion: UNION
+          a: DINT;
+          b: LREAL;
+          c: ARRAY[0..1] OF BYTE;
+        END_UNION
+        END_TYPE
+
+        PROGRAM MainProg
+        VAR
+          u: MyUnion;
+          x: DINT;
+        END_VAR
+          u.b := 1.5;
+          x := u.a;
+          u.c[1] := 2;
+        END_PROGRAM
+        ",
+    );
+
+    insta::assert_snapshot!(result);
+}
+
+#[test]
+fn inline_unions_are_generated() {
+    let result = codegen(
+        "
+        VAR_GLOBAL
+         x: UNION
+              a: BYTE;
+              b: INT;
+            END_UNION
+        END_VAR
+        ",
+    );
+
+    insta::assert_snapshot!(result);
+}
+
 #[test]
 fn enums_are_generated() {
     let result = codegen(
@@ -2765,6 +2971,27 @@ fn enums_are_generated() {
     insta::assert_snapshot!(result)
 }
 
+#[test]
+fn enum_conversions_are_generated_by_the_calling_unit() {
+    let result = codegen(
+        "
+        TYPE MyEnum: BYTE(red, yellow := 5, green);
+        END_TYPE
+
+        FUNCTION main : DINT
+        VAR
+            s : STRING;
+            e : MyEnum;
+        END_VAR
+            s := MyEnum_TO_STRING(e);
+            e := STRING_TO_MyEnum(s);
+        END_FUNCTION
+        ",
+    );
+
+    insta::assert_snapshot!(result)
+}
+
 #[test]
 fn typed_enums_are_generated() {
     let result = codegen(
@@ -3974,7 +4201,7 @@ fn array_of_struct_as_member_of_another_struct_and_variable_declaration_is_initi
 }
 
 #[test]
-// XXX: this behaviour might change in future, for now `VAR_EXTERNAL` variables are ignored
+// `VAR_EXTERNAL` variables refer to the global variable, no local copy is generated
 fn variables_in_var_external_block_are_not_generated() {
     let res = codegen(
         "
@@ -4008,18 +4235,20 @@ fn variables_in_var_external_block_are_not_generated() {
         ",
     );
 
-    insta::assert_snapshot!(res, @r###"
+    insta::assert_snapshot!(res, @r#"
     ; ModuleID = '<internal>'
     source_filename = "<internal>"
 
-    %bar = type {}
+    %bar = type { i8* }
     %baz = type {}
-    %qux = type {}
+    %qux = type { i8* }
 
+    @__vtable_bar = unnamed_addr constant [0 x i8*] zeroinitializer
+    @__vtable_qux = unnamed_addr constant [0 x i8*] zeroinitializer
     @arr = global [101 x i16] zeroinitializer
-    @__bar__init = unnamed_addr constant %bar zeroinitializer
+    @__bar__init = unnamed_addr constant %bar { i8* bitcast ([0 x i8*]* @__vtable_bar to i8*), [101 x i16] zeroinitializer }
     @baz_instance = global %baz zeroinitializer
-    @__qux__init = unnamed_addr constant %qux zeroinitializer
+    @__qux__init = unnamed_addr constant %qux { i8* bitcast ([0 x i8*]* @__vtable_qux to i8*), [101 x i16] zeroinitializer }
 
     define void @foo() {
     entry:
@@ -4028,6 +4257,7 @@ fn variables_in_var_external_block_are_not_generated() {
 
     define void @bar(%bar* %0) {
     entry:
+      %__vtable = getelementptr inbounds %bar, %bar* %0, i32 0, i32 0
       ret void
     }
 
@@ -4038,15 +4268,18 @@ fn variables_in_var_external_block_are_not_generated() {
 
     define void @qux(%qux* %0) {
     entry:
+      %__vtable = getelementptr inbounds %qux, %qux* %0, i32 0, i32 0
       ret void
     }
     ; ModuleID = '__initializers'
     source_filename = "__initializers"
 
     %baz = type {}
-    %bar = type {}
-    %qux = type {}
+    %bar = type { i8* }
+    %qux = type { i8* }
 
+    @__vtable_bar = external unnamed_addr constant [0 x i8*]
+    @__vtable_qux = external unnamed_addr constant [0 x i8*]
     @baz_instance = external global %baz
     @__bar__init = external global %bar
     @__qux__init = external global %qux
@@ -4094,7 +4327,7 @@ fn variables_in_var_external_block_are_not_generated() {
     declare void @__init_baz(%baz*)
 
     declare void @baz(%baz*)
-    "###);
+    "#);
 }
 
 #[test]
@@ -4116,3 +4349,228 @@ fn function_with_array_string_return() {
     insta::assert_snapshot!(res, @r###"
         "###);
 }
+
+#[test]
+fn configured_program_instances_generate_a_task_table() {
+    let result = codegen(
+        "
+        PROGRAM MainPrg END_PROGRAM
+        PROGRAM AlarmPrg END_PROGRAM
+
+        CONFIGURATION Plant
+            VAR_GLOBAL
+                alarm : BOOL;
+            END_VAR
+            RESOURCE Cpu ON PLC
+                TASK fast (INTERVAL := T#10ms, PRIORITY := 1);
+                TASK onAlarm (SINGLE := alarm, PRIORITY := 0);
+                PROGRAM main WITH fast : MainPrg;
+                PROGRAM alarms WITH onAlarm : AlarmPrg;
+            END_RESOURCE
+        END_CONFIGURATION
+        ",
+    );
+    insta::assert_snapshot!(result);
+}
+
+#[test]
+fn new_and_delete_allocate_through_a_weak_default_allocator() {
+    let result = codegen(
+        "
+        TYPE Point : STRUCT
+            x : DINT := 3;
+            y : DINT := 4;
+        END_STRUCT END_TYPE
+
+        FUNCTION main : DINT
+        VAR
+            points : REF_TO Point;
+        END_VAR
+            points := __NEW(Point, 2);
+            __DELETE(points);
+        END_FUNCTION
+        ",
+    );
+    insta::assert_snapshot!(result);
+}
+
+#[test]
+fn a_declared_allocator_replaces_the_default_allocator() {
+    let result = codegen(
+        "
+        {external}
+        FUNCTION __plc_alloc : REF_TO BYTE
+        VAR_INPUT
+            size : ULINT;
+        END_VAR
+        END_FUNCTION
+
+        FUNCTION main : DINT
+        VAR
+            value : REF_TO DINT;
+        END_VAR
+            value := __NEW(DINT);
+            __DELETE(value);
+        END_FUNCTION
+        ",
+    );
+    insta::assert_snapshot!(result);
+}
+
+#[test]
+fn try_statement_pushes_an_exception_frame() {
+    let result = codegen(
+        "
+        FUNCTION main : DINT
+        VAR
+            x : DINT;
+            code : __EXCEPTION_CODE;
+        END_VAR
+            __TRY
+                x := 10 / x;
+            __CATCH(code)
+                x := -1;
+            __FINALLY
+                x := x + 1;
+            __ENDTRY
+        END_FUNCTION
+        ",
+    );
+    insta::assert_snapshot!(result);
+}
+
+#[test]
+fn try_statement_without_catch_clause_rethrows_after_finally() {
+    let result = codegen(
+        "
+        FUNCTION main : DINT
+        VAR
+            x : DINT;
+        END_VAR
+            __TRY
+                __RAISE(EXCEPTION_NULL_POINTER);
+            __FINALLY
+                x := 1;
+            __ENDTRY
+        END_FUNCTION
+        ",
+    );
+    insta::assert_snapshot!(result);
+}
+
+#[test]
+fn runtime_checks_raise_exceptions_on_divisions_by_zero_and_null_pointers() {
+    let result = codegen_with_runtime_checks(
+        "
+        FUNCTION main : DINT
+        VAR
+            x : DINT;
+            p : REF_TO DINT;
+        END_VAR
+            x := 10 / x;
+            x := x MOD 3;
+            main := p^;
+        END_FUNCTION
+        ",
+    );
+    insta::assert_snapshot!(result);
+}
+
+#[test]
+fn var_external_accesses_the_global_variable() {
+    let result = codegen(
+        "
+        VAR_GLOBAL
+            counter : DINT;
+        END_VAR
+
+        FUNCTION_BLOCK foo
+        VAR
+            counter_copy : DINT;
+        END_VAR
+        VAR_EXTERNAL
+            counter : DINT;
+        END_VAR
+            counter := counter + 1;
+            counter_copy := counter;
+        END_FUNCTION_BLOCK
+        ",
+    );
+    insta::assert_snapshot!(result);
+}
+
+#[test]
+fn var_external_of_an_external_global_is_declared_for_the_linker() {
+    let result = codegen(
+        "
+        {external}
+        VAR_GLOBAL
+            counter : DINT;
+        END_VAR
+
+        FUNCTION increment : DINT
+        VAR_EXTERNAL
+            counter : DINT;
+        END_VAR
+            counter := counter + 1;
+            increment := counter;
+        END_FUNCTION
+        ",
+    );
+    insta::assert_snapshot!(result);
+}
+
+#[test]
+fn retained_variables_generate_a_retain_image() {
+    let result = codegen(
+        "
+        VAR_GLOBAL RETAIN
+            counter : DINT;
+        END_VAR
+
+        VAR_GLOBAL
+            plain : DINT;
+        END_VAR
+
+        FUNCTION_BLOCK foo
+        VAR PERSISTENT
+            total : LREAL;
+        END_VAR
+        VAR
+            scratch : INT;
+        END_VAR
+        END_FUNCTION_BLOCK
+
+        PROGRAM mainProg
+        VAR RETAIN PERSISTENT
+            recipe : ARRAY[1..3] OF INT;
+        END_VAR
+        VAR
+            foos : ARRAY[0..1] OF foo;
+        END_VAR
+        END_PROGRAM
+        ",
+    );
+    insta::assert_snapshot!(result);
+}
+
+#[test]
+fn restoring_the_retain_image_keeps_the_vtables_of_retained_instances() {
+    let result = codegen(
+        "
+        FUNCTION_BLOCK foo
+        VAR
+            total : LREAL;
+        END_VAR
+        END_FUNCTION_BLOCK
+
+        PROGRAM mainProg
+        VAR RETAIN
+            single : foo;
+            foos : ARRAY[0..1] OF foo;
+        END_VAR
+        END_PROGRAM
+        ",
+    );
+    insta::assert_snapshot!(result);
+}
@@ -132,6 +132,25 @@ fn test_global_var_struct_added_to_debug_info() {
     assert_snapshot!(codegen)
 }
 
+#[test]
+fn test_global_var_union_added_to_debug_info() {
+    let codegen = codegen(
+        r#"
+    TYPE myUnion : UNION
+        a : DINT;
+        b : LREAL;
+        c : ARRAY[0..10] OF BYTE;
+    END_UNION
+    END_TYPE
+
+    VAR_GLOBAL
+        gUnion : myUnion;
+    END_VAR
+    "#,
+    );
+    assert_snapshot!(codegen)
+}
+
 #[test]
 fn test_global_var_nested_struct_added_to_debug_info() {
     let codegen = codegen(
@@ -156,6 +175,29 @@ fn test_global_var_nested_struct_added_to_debug_info() {
     assert_snapshot!(codegen)
 }
 
+#[test]
+fn test_global_var_derived_struct_added_to_debug_info() {
+    let codegen = codegen(
+        r#"
+    TYPE header : STRUCT
+        id : INT;
+        length : DINT;
+    END_STRUCT
+    END_TYPE
+
+    TYPE telegram : STRUCT EXTENDS header
+        payload : LREAL;
+    END_STRUCT
+    END_TYPE
+
+    VAR_GLOBAL
+        gTelegram : telegram;
+    END_VAR
+    "#,
+    );
+    assert_snapshot!(codegen)
+}
+
 #[test]
 fn test_global_alias_type() {
     let codegen = codegen(
@@ -363,31 +405,41 @@ fn dbg_declare_has_valid_metadata_references_for_methods() {
     );
 
     // We want to make sure the `dbg.declare` for the method `foo` references a non-empty metadata field, i.e.
-    // `!<number>` should not be `!<number> = {}`. Concretely, `!17` should be non-empty
-    assert!(codegen.contains(r#"call void @llvm.dbg.declare(metadata %fb.foo* %1, metadata !17, metadata !DIExpression()), !dbg !16"#));
+    // `!<number>` should not be `!<number> = {}`. Concretely, `!21` should be non-empty
+    assert!(codegen.contains(r#"call void @llvm.dbg.declare(metadata %fb.foo* %1, metadata !21, metadata !DIExpression()), !dbg !20"#));
     assert!(codegen
-        .contains(r#"!17 = !DILocalVariable(name: "fb.foo", scope: !15, file: !2, line: 3, type: !18)"#));
+        .contains(r#"!21 = !DILocalVariable(name: "fb.foo", scope: !19, file: !2, line: 3, type: !22)"#));
 
     assert_snapshot!(codegen, @r#"
     ; ModuleID = '<internal>'
     source_filename = "<internal>"
 
-    %fb = type {}
+    %fb = type { i8* }
     %fb.foo = type {}
 
-    @__fb__init = unnamed_addr constant %fb zeroinitializer, !dbg !0
+    @__vtable_fb = unnamed_addr constant [1 x i8*] [i8* bitcast (void (%fb*, %fb.foo*)* @__vtable_fb.foo to i8*)]
+    @__fb__init = unnamed_addr constant %fb { i8* bitcast ([1 x i8*]* @__vtable_fb to i8*) }, !dbg !0
+
+    define void @fb(%fb* %0) !dbg !13 {
+    entry:
+      call void @llvm.dbg.declare(metadata %fb* %0, metadata !17, metadata !DIExpression()), !dbg !18
+      %__vtable = getelementptr inbounds %fb, %fb* %0, i32 0, i32 0, !dbg !18
+      ret void, !dbg !18
+    }
 
-    define void @fb(%fb* %0) !dbg !10 {
+    define void @fb.foo(%fb* %0, %fb.foo* %1) !dbg !19 {
     entry:
-      call void @llvm.dbg.declare(metadata %fb* %0, metadata !13, metadata !DIExpression()), !dbg !14
-      ret void, !dbg !14
+      call void @llvm.dbg.declare(metadata %fb* %0, metadata !17, metadata !DIExpression()), !dbg !20
+      %__vtable = getelementptr inbounds %fb, %fb* %0, i32 0, i32 0, !dbg !20
+      call void @llvm.dbg.declare(metadata %fb.foo* %1, metadata !21, metadata !DIExpression()), !dbg !20
+      ret void, !dbg !20
     }
 
-    define void @fb.foo(%fb* %0, %fb.foo* %1) !dbg !15 {
+    define internal void @__vtable_fb.foo(%fb* %0, %fb.foo* %1) {
     entry:
-      call void @llvm.dbg.declare(metadata %fb* %0, metadata !13, metadata !DIExpression()), !dbg !16
-      call void @llvm.dbg.declare(metadata %fb.foo* %1, metadata !17, metadata !DIExpression()), !dbg !16
-      ret void, !dbg !16
+      %fb.foo_instance = alloca %fb.foo, align 8
+      call void @fb.foo(%fb* %0, %fb.foo* %fb.foo_instance)
+      ret void
     }
 
     ; Function Attrs: nofree nosync nounwind readnone speculatable willreturn
@@ -395,72 +447,81 @@ fn dbg_declare_has_valid_metadata_references_for_methods() {
 
     attributes #0 = { nofree nosync nounwind readnone speculatable willreturn }
 
-    !llvm.module.flags = !{!5, !6}
-    !llvm.dbg.cu = !{!7}
+    !llvm.module.flags = !{!8, !9}
+    !llvm.dbg.cu = !{!10}
 
     !0 = !DIGlobalVariableExpression(var: !1, expr: !DIExpression())
     !1 = distinct !DIGlobalVariable(name: "__fb__init", scope: !2, file: !2, line: 2, type: !3, isLocal: false, isDefinition: true)
     !2 = !DIFile(filename: "<internal>", directory: "")
-    !3 = !DICompositeType(tag: DW_TAG_structure_type, name: "fb", scope: !2, file: !2, line: 2, flags: DIFlagPublic, elements: !4, identifier: "fb")
-    !4 = !{}
-    !5 = !{i32 2, !"Dwarf Version", i32 5}
-    !6 = !{i32 2, !"Debug Info Version", i32 3}
-    !7 = distinct !DICompileUnit(language: DW_LANG_C, file: !8, producer: "RuSTy Structured text Compiler", isOptimized: false, runtimeVersion: 0, emissionKind: FullDebug, globals: !9, splitDebugInlining: false)
-    !8 = !DIFile(filename: "<internal>", directory: "src")
-    !9 = !{!0}
-    !10 = distinct !DISubprogram(name: "fb", linkageName: "fb", scope: !2, file: !2, line: 2, type: !11, scopeLine: 5, flags: DIFlagPublic, spFlags: DISPFlagDefinition, unit: !7, retainedNodes: !4)
-    !11 = !DISubroutineType(flags: DIFlagPublic, types: !12)
-    !12 = !{null}
-    !13 = !DILocalVariable(name: "fb", scope: !10, file: !2, line: 2, type: !3)
-    !14 = !DILocation(line: 5, column: 8, scope: !10)
-    !15 = distinct !DISubprogram(name: "fb.foo", linkageName: "fb.foo", scope: !2, file: !2, line: 3, type: !11, scopeLine: 4, flags: DIFlagPublic, spFlags: DISPFlagDefinition, unit: !7, retainedNodes: !4)
-    !16 = !DILocation(line: 4, column: 8, scope: !15)
-    !17 = !DILocalVariable(name: "fb.foo", scope: !15, file: !2, line: 3, type: !18)
-    !18 = !DICompositeType(tag: DW_TAG_structure_type, name: "fb.foo", scope: !2, file: !2, line: 3, flags: DIFlagPublic, elements: !4, identifier: "fb.foo")
+    !3 = !DICompositeType(tag: DW_TAG_structure_type, name: "fb", scope: !2, file: !2, line: 2, size: 64, align: 64, flags: DIFlagPublic, elements: !4, identifier: "fb")
+    !4 = !{!5}
+    !5 = !DIDerivedType(tag: DW_TAG_member, name: "__vtable", scope: !2, file: !2, line: 2, baseType: !6, size: 64, align: 64, flags: DIFlagPublic)
+    !6 = !DIDerivedType(tag: DW_TAG_pointer_type, name: "__BYTE_POINTER", baseType: !7, size: 64, align: 64, dwarfAddressSpace: 1)
+    !7 = !DIBasicType(name: "BYTE", size: 8, encoding: DW_ATE_unsigned, flags: DIFlagPublic)
+    !8 = !{i32 2, !"Dwarf Version", i32 5}
+    !9 = !{i32 2, !"Debug Info Version", i32 3}
+    !10 = distinct !DICompileUnit(language: DW_LANG_C, file: !11, producer: "RuSTy Structured text Compiler", isOptimized: false, runtimeVersion: 0, emissionKind: FullDebug, globals: !12, splitDebugInlining: false)
+    !11 = !DIFile(filename: "<internal>", directory: "src")
+    !12 = !{!0}
+    !13 = distinct !DISubprogram(name: "fb", linkageName: "fb", scope: !2, file: !2, line: 2, type: !14, scopeLine: 5, flags: DIFlagPublic, spFlags: DISPFlagDefinition, unit: !10, retainedNodes: !16)
+    !14 = !DISubroutineType(flags: DIFlagPublic, types: !15)
+    !15 = !{null}
+    !16 = !{}
+    !17 = !DILocalVariable(name: "fb", scope: !13, file: !2, line: 2, type: !3)
+    !18 = !DILocation(line: 5, column: 8, scope: !13)
+    !19 = distinct !DISubprogram(name: "fb.foo", linkageName: "fb.foo", scope: !2, file: !2, line: 3, type: !14, scopeLine: 4, flags: DIFlagPublic, spFlags: DISPFlagDefinition, unit: !10, retainedNodes: !16)
+    !20 = !DILocation(line: 4, column: 8, scope: !19)
+    !21 = !DILocalVariable(name: "fb.foo", scope: !19, file: !2, line: 3, type: !22)
+    !22 = !DICompositeType(tag: DW_TAG_structure_type, name: "fb.foo", scope: !2, file: !2, line: 3, flags: DIFlagPublic, elements: !16, identifier: "fb.foo")
     ; ModuleID = '__initializers'
     source_filename = "__initializers"
 
-    %fb = type {}
+    %fb = type { i8* }
 
+    @__vtable_fb = external unnamed_addr constant [1 x i8*]
     @__fb__init = external global %fb, !dbg !0
 
-    define void @__init_fb(%fb* %0) !dbg !10 {
+    define void @__init_fb(%fb* %0) !dbg !13 {
     entry:
-      %self = alloca %fb*, align 8, !dbg !14
-      call void @llvm.dbg.declare(metadata %fb** %self, metadata !15, metadata !DIExpression()), !dbg !14
-      store %fb* %0, %fb** %self, align 8, !dbg !14
-      ret void, !dbg !14
+      %self = alloca %fb*, align 8, !dbg !18
+      call void @llvm.dbg.declare(metadata %fb** %self, metadata !19, metadata !DIExpression()), !dbg !18
+      store %fb* %0, %fb** %self, align 8, !dbg !18
+      ret void, !dbg !18
     }
 
-    declare !dbg !16 void @fb(%fb*)
+    declare !dbg !20 void @fb(%fb*)
 
     ; Function Attrs: nofree nosync nounwind readnone speculatable willreturn
     declare void @llvm.dbg.declare(metadata, metadata, metadata) #0
 
     attributes #0 = { nofree nosync nounwind readnone speculatable willreturn }
 
-    !llvm.module.flags = !{!5, !6}
-    !llvm.dbg.cu = !{!7}
+    !llvm.module.flags = !{!8, !9}
+    !llvm.dbg.cu = !{!10}
 
     !0 = !DIGlobalVariableExpression(var: !1, expr: !DIExpression())
     !1 = distinct !DIGlobalVariable(name: "__fb__init", scope: !2, file: !2, line: 2, type: !3, isLocal: false, isDefinition: true)
     !2 = !DIFile(filename: "<internal>", directory: "")
-    !3 = !DICompositeType(tag: DW_TAG_structure_type, name: "fb", scope: !2, file: !2, line: 2, flags: DIFlagPublic, elements: !4, identifier: "fb")
-    !4 = !{}
-    !5 = !{i32 2, !"Dwarf Version", i32 5}
-    !6 = !{i32 2, !"Debug Info Version", i32 3}
-    !7 = distinct !DICompileUnit(language: DW_LANG_C, file: !8, producer: "RuSTy Structured text Compiler", isOptimized: false, runtimeVersion: 0, emissionKind: FullDebug, globals: !9, splitDebugInlining: false)
-    !8 = !DIFile(filename: "__initializers", directory: "src")
-    !9 = !{!0}
-    !10 = distinct !DISubprogram(name: "__init_fb", linkageName: "__init_fb", scope: !2, file: !2, line: 2, type: !11, scopeLine: 2, flags: DIFlagPublic, spFlags: DISPFlagDefinition, unit: !7, retainedNodes: !4)
-    !11 = !DISubroutineType(flags: DIFlagPublic, types: !12)
-    !12 = !{null, !13}
-    !13 = !DIDerivedType(tag: DW_TAG_pointer_type, name: "__auto_pointer_to_fb", baseType: !3, size: 64, align: 64, dwarfAddressSpace: 1)
-    !14 = !DILocation(line: 2, column: 23, scope: !10)
-    !15 = !DILocalVariable(name: "self", scope: !10, file: !2, line: 2, type: !13)
-    !16 = distinct !DISubprogram(name: "fb", linkageName: "fb", scope: !2, file: !2, line: 2, type: !17, scopeLine: 5, flags: DIFlagPublic, spFlags: DISPFlagDefinition, unit: !7, retainedNodes: !4)
-    !17 = !DISubroutineType(flags: DIFlagPublic, types: !18)
-    !18 = !{null}
+    !3 = !DICompositeType(tag: DW_TAG_structure_type, name: "fb", scope: !2, file: !2, line: 2, size: 64, align: 64, flags: DIFlagPublic, elements: !4, identifier: "fb")
+    !4 = !{!5}
+    !5 = !DIDerivedType(tag: DW_TAG_member, name: "__vtable", scope: !2, file: !2, line: 2, baseType: !6, size: 64, align: 64, flags: DIFlagPublic)
+    !6 = !DIDerivedType(tag: DW_TAG_pointer_type, name: "__BYTE_POINTER", baseType: !7, size: 64, align: 64, dwarfAddressSpace: 1)
+    !7 = !DIBasicType(name: "BYTE", size: 8, encoding: DW_ATE_unsigned, flags: DIFlagPublic)
+    !8 = !{i32 2, !"Dwarf Version", i32 5}
+    !9 = !{i32 2, !"Debug Info Version", i32 3}
+    !10 = distinct !DICompileUnit(language: DW_LANG_C, file: !11, producer: "RuSTy Structured text Compiler", isOptimized: false, runtimeVersion: 0, emissionKind: FullDebug, globals: !12, splitDebugInlining: false)
+    !11 = !DIFile(filename: "__initializers", directory: "src")
+    !12 = !{!0}
+    !13 = distinct !DISubprogram(name: "__init_fb", linkageName: "__init_fb", scope: !2, file: !2, line: 2, type: !14, scopeLine: 2, flags: DIFlagPublic, spFlags: DISPFlagDefinition, unit: !10, retainedNodes: !17)
+    !14 = !DISubroutineType(flags: DIFlagPublic, types: !15)
+    !15 = !{null, !16}
+    !16 = !DIDerivedType(tag: DW_TAG_pointer_type, name: "__auto_pointer_to_fb", baseType: !3, size: 64, align: 64, dwarfAddressSpace: 1)
+    !17 = !{}
+    !18 = !DILocation(line: 2, column: 23, scope: !13)
+    !19 = !DILocalVariable(name: "self", scope: !13, file: !2, line: 2, type: !16)
+    !20 = distinct !DISubprogram(name: "fb", linkageName: "fb", scope: !2, file: !2, line: 2, type: !21, scopeLine: 5, flags: DIFlagPublic, spFlags: DISPFlagDefinition, unit: !10, retainedNodes: !17)
+    !21 = !DISubroutineType(flags: DIFlagPublic, types: !22)
+    !22 = !{null}
     ; ModuleID = '__init___testproject'
     source_filename = "__init___testproject"
 
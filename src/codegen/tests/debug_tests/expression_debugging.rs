@@ -362,13 +362,13 @@ fn zero_sized_types_do_not_have_alignments() {
         END_PROGRAM
 
         PROGRAM         zeroB /* empty */ END_PROGRAM
-        FUNCTION_BLOCK  zeroA /* empty */ END_FUNCTION_BLOCK
+        FUNCTION_BLOCK FINAL zeroA /* empty, FINAL to omit the __vtable */ END_FUNCTION_BLOCK
         ",
     );
 
     // Debugging variables with a zero-sized type between two non-zero sized types will yield incorrect
     // data when displaying their content if they are given a debug-alignment. This test verifies that zero-sized types do not have an alignment in the debug information.
     // The result should have neither a `size` nor a `aligmnent` here:
-    assert!(result.contains(r#"!DICompositeType(tag: DW_TAG_structure_type, name: "zeroA", scope: !2, file: !2, line: 15, flags: DIFlagPublic, elements: !15, identifier: "zeroA")"#));
-    assert!(result.contains(r#"!DICompositeType(tag: DW_TAG_structure_type, name: "zeroB", scope: !2, file: !2, line: 14, flags: DIFlagPublic, elements: !15, identifier: "zeroB")"#));
+    assert!(result.contains(r#"!DICompositeType(tag: DW_TAG_structure_type, name: "zeroA", scope: !2, file: !2, line: 15, flags: DIFlagPublic, elements: !17, identifier: "zeroA")"#));
+    assert!(result.contains(r#"!DICompositeType(tag: DW_TAG_structure_type, name: "zeroB", scope: !2, file: !2, line: 14, flags: DIFlagPublic, elements: !17, identifier: "zeroB")"#));
 }
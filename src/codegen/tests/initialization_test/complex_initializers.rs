@@ -252,25 +252,28 @@ fn init_functions_generated_for_function_blocks() {
         "#,
     );
 
-    insta::assert_snapshot!(result, @r###"
+    insta::assert_snapshot!(result, @r#"
     ; ModuleID = '<internal>'
     source_filename = "<internal>"
 
-    %foo = type { [81 x i8]* }
+    %foo = type { i8*, [81 x i8]* }
 
+    @__vtable_foo = unnamed_addr constant [0 x i8*] zeroinitializer
     @s = global [81 x i8] zeroinitializer
-    @__foo__init = unnamed_addr constant %foo zeroinitializer
+    @__foo__init = unnamed_addr constant %foo { i8* bitcast ([0 x i8*]* @__vtable_foo to i8*), [81 x i8]* null }
 
     define void @foo(%foo* %0) {
     entry:
-      %to_init = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %to_init = getelementptr inbounds %foo, %foo* %0, i32 0, i32 1
       ret void
     }
     ; ModuleID = '__initializers'
     source_filename = "__initializers"
 
-    %foo = type { [81 x i8]* }
+    %foo = type { i8*, [81 x i8]* }
 
+    @__vtable_foo = external unnamed_addr constant [0 x i8*]
     @__foo__init = external global %foo
     @s = external global [81 x i8]
 
@@ -279,7 +282,7 @@ fn init_functions_generated_for_function_blocks() {
       %self = alloca %foo*, align 8
       store %foo* %0, %foo** %self, align 8
       %deref = load %foo*, %foo** %self, align 8
-      %to_init = getelementptr inbounds %foo, %foo* %deref, i32 0, i32 0
+      %to_init = getelementptr inbounds %foo, %foo* %deref, i32 0, i32 1
       store [81 x i8]* @s, [81 x i8]** %to_init, align 8
       ret void
     }
@@ -294,7 +297,7 @@ fn init_functions_generated_for_function_blocks() {
     entry:
       ret void
     }
-    "###);
+    "#);
 }
 
 #[test]
@@ -358,27 +361,31 @@ fn nested_initializer_pous() {
         "#,
     );
 
-    insta::assert_snapshot!(result, @r###"
+    insta::assert_snapshot!(result, @r#"
     ; ModuleID = '<internal>'
     source_filename = "<internal>"
 
-    %foo = type { [81 x i8]*, %bar }
-    %bar = type { %baz }
-    %baz = type { [81 x i8]* }
+    %foo = type { i8*, [81 x i8]*, %bar }
+    %bar = type { i8*, %baz }
+    %baz = type { i8*, [81 x i8]* }
     %mainProg = type { [81 x i8]*, %foo }
     %sideProg = type { [81 x i8]*, %foo }
 
+    @__vtable_foo = unnamed_addr constant [0 x i8*] zeroinitializer
+    @__vtable_bar = unnamed_addr constant [0 x i8*] zeroinitializer
+    @__vtable_baz = unnamed_addr constant [0 x i8*] zeroinitializer
     @str = global [81 x i8] c"hello\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00"
-    @__foo__init = unnamed_addr constant %foo zeroinitializer
-    @__bar__init = unnamed_addr constant %bar zeroinitializer
-    @__baz__init = unnamed_addr constant %baz zeroinitializer
-    @mainProg_instance = global %mainProg zeroinitializer
-    @sideProg_instance = global %sideProg zeroinitializer
+    @__foo__init = unnamed_addr constant %foo { i8* bitcast ([0 x i8*]* @__vtable_foo to i8*), [81 x i8]* null, %bar { i8* bitcast ([0 x i8*]* @__vtable_bar to i8*), %baz { i8* bitcast ([0 x i8*]* @__vtable_baz to i8*), [81 x i8]* null } } }
+    @__bar__init = unnamed_addr constant %bar { i8* bitcast ([0 x i8*]* @__vtable_bar to i8*), %baz { i8* bitcast ([0 x i8*]* @__vtable_baz to i8*), [81 x i8]* null } }
+    @__baz__init = unnamed_addr constant %baz { i8* bitcast ([0 x i8*]* @__vtable_baz to i8*), [81 x i8]* null }
+    @mainProg_instance = global %mainProg { [81 x i8]* null, %foo { i8* bitcast ([0 x i8*]* @__vtable_foo to i8*), [81 x i8]* null, %bar { i8* bitcast ([0 x i8*]* @__vtable_bar to i8*), %baz { i8* bitcast ([0 x i8*]* @__vtable_baz to i8*), [81 x i8]* null } } } }
+    @sideProg_instance = global %sideProg { [81 x i8]* null, %foo { i8* bitcast ([0 x i8*]* @__vtable_foo to i8*), [81 x i8]* null, %bar { i8* bitcast ([0 x i8*]* @__vtable_bar to i8*), %baz { i8* bitcast ([0 x i8*]* @__vtable_baz to i8*), [81 x i8]* null } } } }
 
     define void @foo(%foo* %0) {
     entry:
-      %str_ref = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
-      %b = getelementptr inbounds %foo, %foo* %0, i32 0, i32 1
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %str_ref = getelementptr inbounds %foo, %foo* %0, i32 0, i32 1
+      %b = getelementptr inbounds %foo, %foo* %0, i32 0, i32 2
       call void @bar.print(%bar* %b)
       call void @bar(%bar* %b)
       ret void
@@ -386,14 +393,16 @@ fn nested_initializer_pous() {
 
     define void @bar(%bar* %0) {
     entry:
-      %b = getelementptr inbounds %bar, %bar* %0, i32 0, i32 0
+      %__vtable = getelementptr inbounds %bar, %bar* %0, i32 0, i32 0
+      %b = getelementptr inbounds %bar, %bar* %0, i32 0, i32 1
       call void @baz.print(%baz* %b)
       ret void
     }
 
     define void @baz(%baz* %0) {
     entry:
-      %str_ref = getelementptr inbounds %baz, %baz* %0, i32 0, i32 0
+      %__vtable = getelementptr inbounds %baz, %baz* %0, i32 0, i32 0
+      %str_ref = getelementptr inbounds %baz, %baz* %0, i32 0, i32 1
       ret void
     }
 
@@ -415,31 +424,37 @@ fn nested_initializer_pous() {
 
     define void @bar.print(%bar* %0) {
     entry:
-      %b = getelementptr inbounds %bar, %bar* %0, i32 0, i32 0
+      %__vtable = getelementptr inbounds %bar, %bar* %0, i32 0, i32 0
+      %b = getelementptr inbounds %bar, %bar* %0, i32 0, i32 1
       ret void
     }
 
     define void @foo.print(%foo* %0) {
     entry:
-      %str_ref = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
-      %b = getelementptr inbounds %foo, %foo* %0, i32 0, i32 1
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %str_ref = getelementptr inbounds %foo, %foo* %0, i32 0, i32 1
+      %b = getelementptr inbounds %foo, %foo* %0, i32 0, i32 2
       ret void
     }
 
     define void @baz.print(%baz* %0) {
     entry:
-      %str_ref = getelementptr inbounds %baz, %baz* %0, i32 0, i32 0
+      %__vtable = getelementptr inbounds %baz, %baz* %0, i32 0, i32 0
+      %str_ref = getelementptr inbounds %baz, %baz* %0, i32 0, i32 1
       ret void
     }
     ; ModuleID = '__initializers'
     source_filename = "__initializers"
 
-    %foo = type { [81 x i8]*, %bar }
-    %bar = type { %baz }
-    %baz = type { [81 x i8]* }
+    %foo = type { i8*, [81 x i8]*, %bar }
+    %bar = type { i8*, %baz }
+    %baz = type { i8*, [81 x i8]* }
     %mainProg = type { [81 x i8]*, %foo }
     %sideProg = type { [81 x i8]*, %foo }
 
+    @__vtable_foo = external unnamed_addr constant [0 x i8*]
+    @__vtable_bar = external unnamed_addr constant [0 x i8*]
+    @__vtable_baz = external unnamed_addr constant [0 x i8*]
     @__foo__init = external global %foo
     @__bar__init = external global %bar
     @__baz__init = external global %baz
@@ -452,10 +467,10 @@ fn nested_initializer_pous() {
       %self = alloca %foo*, align 8
       store %foo* %0, %foo** %self, align 8
       %deref = load %foo*, %foo** %self, align 8
-      %str_ref = getelementptr inbounds %foo, %foo* %deref, i32 0, i32 0
+      %str_ref = getelementptr inbounds %foo, %foo* %deref, i32 0, i32 1
       store [81 x i8]* @str, [81 x i8]** %str_ref, align 8
       %deref1 = load %foo*, %foo** %self, align 8
-      %b = getelementptr inbounds %foo, %foo* %deref1, i32 0, i32 1
+      %b = getelementptr inbounds %foo, %foo* %deref1, i32 0, i32 2
       call void @__init_bar(%bar* %b)
       ret void
     }
@@ -471,7 +486,7 @@ fn nested_initializer_pous() {
       %self = alloca %bar*, align 8
       store %bar* %0, %bar** %self, align 8
       %deref = load %bar*, %bar** %self, align 8
-      %b = getelementptr inbounds %bar, %bar* %deref, i32 0, i32 0
+      %b = getelementptr inbounds %bar, %bar* %deref, i32 0, i32 1
       call void @__init_baz(%baz* %b)
       ret void
     }
@@ -481,7 +496,7 @@ fn nested_initializer_pous() {
       %self = alloca %baz*, align 8
       store %baz* %0, %baz** %self, align 8
       %deref = load %baz*, %baz** %self, align 8
-      %str_ref = getelementptr inbounds %baz, %baz* %deref, i32 0, i32 0
+      %str_ref = getelementptr inbounds %baz, %baz* %deref, i32 0, i32 1
       store [81 x i8]* @str, [81 x i8]** %str_ref, align 8
       ret void
     }
@@ -519,11 +534,14 @@ fn nested_initializer_pous() {
     source_filename = "__init___testproject"
 
     %mainProg = type { [81 x i8]*, %foo }
-    %foo = type { [81 x i8]*, %bar }
-    %bar = type { %baz }
-    %baz = type { [81 x i8]* }
+    %foo = type { i8*, [81 x i8]*, %bar }
+    %bar = type { i8*, %baz }
+    %baz = type { i8*, [81 x i8]* }
     %sideProg = type { [81 x i8]*, %foo }
 
+    @__vtable_foo = external unnamed_addr constant [0 x i8*]
+    @__vtable_bar = external unnamed_addr constant [0 x i8*]
+    @__vtable_baz = external unnamed_addr constant [0 x i8*]
     @mainProg_instance = external global %mainProg
     @__foo__init = external global %foo
     @__bar__init = external global %bar
@@ -551,7 +569,7 @@ fn nested_initializer_pous() {
     declare void @__init_sideprog(%sideProg*)
 
     declare void @sideProg(%sideProg*)
-    "###);
+    "#);
 }
 
 #[test]
@@ -787,15 +805,17 @@ fn stateful_pous_methods_and_structs_get_init_functions() {
     source_filename = "<internal>"
 
     %prog = type {}
-    %foo = type {}
-    %cl = type {}
+    %foo = type { i8* }
+    %cl = type { i8* }
     %myStruct = type {}
     %foo.m = type {}
     %cl.m = type {}
 
+    @__vtable_foo = unnamed_addr constant [1 x i8*] [i8* bitcast (void (%foo*, %foo.m*)* @__vtable_foo.m to i8*)]
+    @__vtable_cl = unnamed_addr constant [1 x i8*] [i8* bitcast (void (%cl*, %cl.m*)* @__vtable_cl.m to i8*)]
     @prog_instance = global %prog zeroinitializer
-    @__foo__init = unnamed_addr constant %foo zeroinitializer
-    @__cl__init = unnamed_addr constant %cl zeroinitializer
+    @__foo__init = unnamed_addr constant %foo { i8* bitcast ([1 x i8*]* @__vtable_foo to i8*) }
+    @__cl__init = unnamed_addr constant %cl { i8* bitcast ([1 x i8*]* @__vtable_cl to i8*) }
     @__myStruct__init = unnamed_addr constant %myStruct zeroinitializer
 
     define void @prog(%prog* %0) {
@@ -805,36 +825,57 @@ fn stateful_pous_methods_and_structs_get_init_functions() {
 
     define void @foo(%foo* %0) {
     entry:
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
       ret void
     }
 
     define void @foo.m(%foo* %0, %foo.m* %1) {
     entry:
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
       ret void
     }
 
     define void @cl(%cl* %0) {
     entry:
+      %__vtable = getelementptr inbounds %cl, %cl* %0, i32 0, i32 0
       ret void
     }
 
     define void @cl.m(%cl* %0, %cl.m* %1) {
     entry:
+      %__vtable = getelementptr inbounds %cl, %cl* %0, i32 0, i32 0
       ret void
     }
 
     define void @foo.act(%foo* %0) {
     entry:
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      ret void
+    }
+
+    define internal void @__vtable_foo.m(%foo* %0, %foo.m* %1) {
+    entry:
+      %foo.m_instance = alloca %foo.m, align 8
+      call void @foo.m(%foo* %0, %foo.m* %foo.m_instance)
+      ret void
+    }
+
+    define internal void @__vtable_cl.m(%cl* %0, %cl.m* %1) {
+    entry:
+      %cl.m_instance = alloca %cl.m, align 8
+      call void @cl.m(%cl* %0, %cl.m* %cl.m_instance)
       ret void
     }
     ; ModuleID = '__initializers'
     source_filename = "__initializers"
 
     %myStruct = type {}
-    %foo = type {}
+    %foo = type { i8* }
     %prog = type {}
-    %cl = type {}
+    %cl = type { i8* }
 
+    @__vtable_foo = external unnamed_addr constant [1 x i8*]
+    @__vtable_cl = external unnamed_addr constant [1 x i8*]
     @__myStruct__init = external global %myStruct
     @__foo__init = external global %foo
     @prog_instance = external global %prog
@@ -914,21 +955,23 @@ fn global_instance() {
       "#,
     );
 
-    insta::assert_snapshot!(res, @r###"
+    insta::assert_snapshot!(res, @r#"
     ; ModuleID = '<internal>'
     source_filename = "<internal>"
 
-    %foo = type { [81 x i8]* }
+    %foo = type { i8*, [81 x i8]* }
     %prog = type {}
 
+    @__vtable_foo = unnamed_addr constant [0 x i8*] zeroinitializer
     @ps = global [81 x i8] zeroinitializer
-    @fb = global %foo zeroinitializer
-    @__foo__init = unnamed_addr constant %foo zeroinitializer
+    @fb = global %foo { i8* bitcast ([0 x i8*]* @__vtable_foo to i8*), [81 x i8]* null }
+    @__foo__init = unnamed_addr constant %foo { i8* bitcast ([0 x i8*]* @__vtable_foo to i8*), [81 x i8]* null }
     @prog_instance = global %prog zeroinitializer
 
     define void @foo(%foo* %0) {
     entry:
-      %s = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %s = getelementptr inbounds %foo, %foo* %0, i32 0, i32 1
       ret void
     }
 
@@ -940,9 +983,10 @@ fn global_instance() {
     ; ModuleID = '__initializers'
     source_filename = "__initializers"
 
-    %foo = type { [81 x i8]* }
+    %foo = type { i8*, [81 x i8]* }
     %prog = type {}
 
+    @__vtable_foo = external unnamed_addr constant [0 x i8*]
     @__foo__init = external global %foo
     @prog_instance = external global %prog
     @ps = external global [81 x i8]
@@ -952,7 +996,7 @@ fn global_instance() {
       %self = alloca %foo*, align 8
       store %foo* %0, %foo** %self, align 8
       %deref = load %foo*, %foo** %self, align 8
-      %s = getelementptr inbounds %foo, %foo* %deref, i32 0, i32 0
+      %s = getelementptr inbounds %foo, %foo* %deref, i32 0, i32 1
       store [81 x i8]* @ps, [81 x i8]** %s, align 8
       ret void
     }
@@ -971,8 +1015,9 @@ fn global_instance() {
     source_filename = "__init___testproject"
 
     %prog = type {}
-    %foo = type { [81 x i8]* }
+    %foo = type { i8*, [81 x i8]* }
 
+    @__vtable_foo = external unnamed_addr constant [0 x i8*]
     @prog_instance = external global %prog
     @__foo__init = external global %foo
     @fb = external global %foo
@@ -992,7 +1037,7 @@ fn global_instance() {
     declare void @__init_foo(%foo*)
 
     declare void @foo(%foo*)
-    "###);
+    "#);
 }
 
 #[test]
@@ -1021,21 +1066,23 @@ fn aliased_types() {
       "#,
     );
 
-    insta::assert_snapshot!(res, @r###"
+    insta::assert_snapshot!(res, @r#"
     ; ModuleID = '<internal>'
     source_filename = "<internal>"
 
-    %foo = type { [81 x i8]* }
+    %foo = type { i8*, [81 x i8]* }
     %prog = type { %foo }
 
+    @__vtable_foo = unnamed_addr constant [0 x i8*] zeroinitializer
     @ps = global [81 x i8] zeroinitializer
-    @global_alias = global %foo zeroinitializer
-    @__foo__init = unnamed_addr constant %foo zeroinitializer
-    @prog_instance = global %prog zeroinitializer
+    @global_alias = global %foo { i8* bitcast ([0 x i8*]* @__vtable_foo to i8*), [81 x i8]* null }
+    @__foo__init = unnamed_addr constant %foo { i8* bitcast ([0 x i8*]* @__vtable_foo to i8*), [81 x i8]* null }
+    @prog_instance = global %prog { %foo { i8* bitcast ([0 x i8*]* @__vtable_foo to i8*), [81 x i8]* null } }
 
     define void @foo(%foo* %0) {
     entry:
-      %s = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %s = getelementptr inbounds %foo, %foo* %0, i32 0, i32 1
       ret void
     }
 
@@ -1048,9 +1095,10 @@ fn aliased_types() {
     ; ModuleID = '__initializers'
     source_filename = "__initializers"
 
-    %foo = type { [81 x i8]* }
+    %foo = type { i8*, [81 x i8]* }
     %prog = type { %foo }
 
+    @__vtable_foo = external unnamed_addr constant [0 x i8*]
     @__foo__init = external global %foo
     @prog_instance = external global %prog
     @ps = external global [81 x i8]
@@ -1060,7 +1108,7 @@ fn aliased_types() {
       %self = alloca %foo*, align 8
       store %foo* %0, %foo** %self, align 8
       %deref = load %foo*, %foo** %self, align 8
-      %s = getelementptr inbounds %foo, %foo* %deref, i32 0, i32 0
+      %s = getelementptr inbounds %foo, %foo* %deref, i32 0, i32 1
       store [81 x i8]* @ps, [81 x i8]** %s, align 8
       ret void
     }
@@ -1082,8 +1130,9 @@ fn aliased_types() {
     source_filename = "__init___testproject"
 
     %prog = type { %foo }
-    %foo = type { [81 x i8]* }
+    %foo = type { i8*, [81 x i8]* }
 
+    @__vtable_foo = external unnamed_addr constant [0 x i8*]
     @prog_instance = external global %prog
     @__foo__init = external global %foo
     @global_alias = external global %foo
@@ -1103,7 +1152,7 @@ fn aliased_types() {
     declare void @foo(%foo*)
 
     declare void @__init_foo(%foo*)
-    "###);
+    "#);
 }
 
 #[test]
@@ -1189,21 +1238,23 @@ fn var_config_aliased_variables_initialized() {
         ",
     );
 
-    insta::assert_snapshot!(res, @r###"
+    insta::assert_snapshot!(res, @r#"
     ; ModuleID = '<internal>'
     source_filename = "<internal>"
 
-    %FB = type { i32* }
+    %FB = type { i8*, i32* }
     %prog = type { %FB, %FB }
 
+    @__vtable_FB = unnamed_addr constant [0 x i8*] zeroinitializer
     @__PI_1_2_1 = global i32 0
     @__PI_1_2_2 = global i32 0
-    @__FB__init = unnamed_addr constant %FB zeroinitializer
-    @prog_instance = global %prog zeroinitializer
+    @__FB__init = unnamed_addr constant %FB { i8* bitcast ([0 x i8*]* @__vtable_FB to i8*), i32* null }
+    @prog_instance = global %prog { %FB { i8* bitcast ([0 x i8*]* @__vtable_FB to i8*), i32* null }, %FB { i8* bitcast ([0 x i8*]* @__vtable_FB to i8*), i32* null } }
 
     define void @FB(%FB* %0) {
     entry:
-      %foo = getelementptr inbounds %FB, %FB* %0, i32 0, i32 0
+      %__vtable = getelementptr inbounds %FB, %FB* %0, i32 0, i32 0
+      %foo = getelementptr inbounds %FB, %FB* %0, i32 0, i32 1
       ret void
     }
 
@@ -1216,9 +1267,10 @@ fn var_config_aliased_variables_initialized() {
     ; ModuleID = '__initializers'
     source_filename = "__initializers"
 
-    %FB = type { i32* }
+    %FB = type { i8*, i32* }
     %prog = type { %FB, %FB }
 
+    @__vtable_FB = external unnamed_addr constant [0 x i8*]
     @__FB__init = external global %FB
     @prog_instance = external global %prog
 
@@ -1249,8 +1301,9 @@ fn var_config_aliased_variables_initialized() {
     source_filename = "__init___testproject"
 
     %prog = type { %FB, %FB }
-    %FB = type { i32* }
+    %FB = type { i8*, i32* }
 
+    @__vtable_FB = external unnamed_addr constant [0 x i8*]
     @prog_instance = external global %prog
     @__FB__init = external global %FB
     @__PI_1_2_1 = external global i32
@@ -1266,8 +1319,8 @@ fn var_config_aliased_variables_initialized() {
 
     define void @__init___var_config() {
     entry:
-      store i32* @__PI_1_2_1, i32** getelementptr inbounds (%prog, %prog* @prog_instance, i32 0, i32 0, i32 0), align 8
-      store i32* @__PI_1_2_2, i32** getelementptr inbounds (%prog, %prog* @prog_instance, i32 0, i32 1, i32 0), align 8
+      store i32* @__PI_1_2_1, i32** getelementptr inbounds (%prog, %prog* @prog_instance, i32 0, i32 0, i32 1), align 8
+      store i32* @__PI_1_2_2, i32** getelementptr inbounds (%prog, %prog* @prog_instance, i32 0, i32 1, i32 1), align 8
       ret void
     }
 
@@ -1276,7 +1329,7 @@ fn var_config_aliased_variables_initialized() {
     declare void @prog(%prog*)
 
     declare void @FB(%FB*)
-    "###);
+    "#);
 }
 
 #[test]
@@ -1302,18 +1355,20 @@ fn var_external_blocks_are_ignored_in_init_functions() {
         ",
     );
 
-    insta::assert_snapshot!(res, @r###"
+    insta::assert_snapshot!(res, @r#"
     ; ModuleID = '<internal>'
     source_filename = "<internal>"
 
-    %foo = type {}
+    %foo = type { i8* }
 
+    @__vtable_foo = unnamed_addr constant [0 x i8*] zeroinitializer
     @s = global [81 x i8] zeroinitializer
     @refString = global [81 x i8]* null
-    @__foo__init = unnamed_addr constant %foo zeroinitializer
+    @__foo__init = unnamed_addr constant %foo { i8* bitcast ([0 x i8*]* @__vtable_foo to i8*), [81 x i8] zeroinitializer }
 
     define void @foo(%foo* %0) {
     entry:
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
       ret void
     }
 
@@ -1324,8 +1379,9 @@ fn var_external_blocks_are_ignored_in_init_functions() {
     ; ModuleID = '__initializers'
     source_filename = "__initializers"
 
-    %foo = type {}
+    %foo = type { i8* }
 
+    @__vtable_foo = external unnamed_addr constant [0 x i8*]
     @__foo__init = external global %foo
 
     define void @__init_foo(%foo* %0) {
@@ -1348,7 +1404,7 @@ fn var_external_blocks_are_ignored_in_init_functions() {
       store [81 x i8]* @s, [81 x i8]** @refString, align 8
       ret void
     }
-    "###)
+    "#)
 }
 
 #[test]
@@ -1370,23 +1426,26 @@ fn ref_to_local_member() {
     ; ModuleID = '<internal>'
     source_filename = "<internal>"
 
-    %foo = type { [81 x i8], [81 x i8]*, [81 x i8]*, [81 x i8]* }
+    %foo = type { i8*, [81 x i8], [81 x i8]*, [81 x i8]*, [81 x i8]* }
 
-    @__foo__init = unnamed_addr constant %foo zeroinitializer
+    @__vtable_foo = unnamed_addr constant [0 x i8*] zeroinitializer
+    @__foo__init = unnamed_addr constant %foo { i8* bitcast ([0 x i8*]* @__vtable_foo to i8*), [81 x i8] zeroinitializer, [81 x i8]* null, [81 x i8]* null, [81 x i8]* null }
 
     define void @foo(%foo* %0) {
     entry:
-      %s = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
-      %ptr = getelementptr inbounds %foo, %foo* %0, i32 0, i32 1
-      %alias = getelementptr inbounds %foo, %foo* %0, i32 0, i32 2
-      %reference_to = getelementptr inbounds %foo, %foo* %0, i32 0, i32 3
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %s = getelementptr inbounds %foo, %foo* %0, i32 0, i32 1
+      %ptr = getelementptr inbounds %foo, %foo* %0, i32 0, i32 2
+      %alias = getelementptr inbounds %foo, %foo* %0, i32 0, i32 3
+      %reference_to = getelementptr inbounds %foo, %foo* %0, i32 0, i32 4
       ret void
     }
     ; ModuleID = '__initializers'
     source_filename = "__initializers"
 
-    %foo = type { [81 x i8], [81 x i8]*, [81 x i8]*, [81 x i8]* }
+    %foo = type { i8*, [81 x i8], [81 x i8]*, [81 x i8]*, [81 x i8]* }
 
+    @__vtable_foo = external unnamed_addr constant [0 x i8*]
     @__foo__init = external global %foo
 
     define void @__init_foo(%foo* %0) {
@@ -1394,19 +1453,19 @@ fn ref_to_local_member() {
       %self = alloca %foo*, align 8
       store %foo* %0, %foo** %self, align 8
       %deref = load %foo*, %foo** %self, align 8
-      %ptr = getelementptr inbounds %foo, %foo* %deref, i32 0, i32 1
+      %ptr = getelementptr inbounds %foo, %foo* %deref, i32 0, i32 2
       %deref1 = load %foo*, %foo** %self, align 8
-      %s = getelementptr inbounds %foo, %foo* %deref1, i32 0, i32 0
+      %s = getelementptr inbounds %foo, %foo* %deref1, i32 0, i32 1
       store [81 x i8]* %s, [81 x i8]** %ptr, align 8
       %deref2 = load %foo*, %foo** %self, align 8
-      %alias = getelementptr inbounds %foo, %foo* %deref2, i32 0, i32 2
+      %alias = getelementptr inbounds %foo, %foo* %deref2, i32 0, i32 3
       %deref3 = load %foo*, %foo** %self, align 8
-      %s4 = getelementptr inbounds %foo, %foo* %deref3, i32 0, i32 0
+      %s4 = getelementptr inbounds %foo, %foo* %deref3, i32 0, i32 1
       store [81 x i8]* %s4, [81 x i8]** %alias, align 8
       %deref5 = load %foo*, %foo** %self, align 8
-      %reference_to = getelementptr inbounds %foo, %foo* %deref5, i32 0, i32 3
+      %reference_to = getelementptr inbounds %foo, %foo* %deref5, i32 0, i32 4
       %deref6 = load %foo*, %foo** %self, align 8
-      %s7 = getelementptr inbounds %foo, %foo* %deref6, i32 0, i32 0
+      %s7 = getelementptr inbounds %foo, %foo* %deref6, i32 0, i32 1
       store [81 x i8]* %s7, [81 x i8]** %reference_to, align 8
       ret void
     }
@@ -1447,24 +1506,27 @@ fn ref_to_local_member_shadows_global() {
     ; ModuleID = '<internal>'
     source_filename = "<internal>"
 
-    %foo = type { [81 x i8], [81 x i8]*, [81 x i8]*, [81 x i8]* }
+    %foo = type { i8*, [81 x i8], [81 x i8]*, [81 x i8]*, [81 x i8]* }
 
+    @__vtable_foo = unnamed_addr constant [0 x i8*] zeroinitializer
     @s = global [81 x i8] zeroinitializer
-    @__foo__init = unnamed_addr constant %foo zeroinitializer
+    @__foo__init = unnamed_addr constant %foo { i8* bitcast ([0 x i8*]* @__vtable_foo to i8*), [81 x i8] zeroinitializer, [81 x i8]* null, [81 x i8]* null, [81 x i8]* null }
 
     define void @foo(%foo* %0) {
     entry:
-      %s = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
-      %ptr = getelementptr inbounds %foo, %foo* %0, i32 0, i32 1
-      %alias = getelementptr inbounds %foo, %foo* %0, i32 0, i32 2
-      %reference_to = getelementptr inbounds %foo, %foo* %0, i32 0, i32 3
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %s = getelementptr inbounds %foo, %foo* %0, i32 0, i32 1
+      %ptr = getelementptr inbounds %foo, %foo* %0, i32 0, i32 2
+      %alias = getelementptr inbounds %foo, %foo* %0, i32 0, i32 3
+      %reference_to = getelementptr inbounds %foo, %foo* %0, i32 0, i32 4
       ret void
     }
     ; ModuleID = '__initializers'
     source_filename = "__initializers"
 
-    %foo = type { [81 x i8], [81 x i8]*, [81 x i8]*, [81 x i8]* }
+    %foo = type { i8*, [81 x i8], [81 x i8]*, [81 x i8]*, [81 x i8]* }
 
+    @__vtable_foo = external unnamed_addr constant [0 x i8*]
     @__foo__init = external global %foo
 
     define void @__init_foo(%foo* %0) {
@@ -1472,19 +1534,19 @@ fn ref_to_local_member_shadows_global() {
       %self = alloca %foo*, align 8
       store %foo* %0, %foo** %self, align 8
       %deref = load %foo*, %foo** %self, align 8
-      %ptr = getelementptr inbounds %foo, %foo* %deref, i32 0, i32 1
+      %ptr = getelementptr inbounds %foo, %foo* %deref, i32 0, i32 2
       %deref1 = load %foo*, %foo** %self, align 8
-      %s = getelementptr inbounds %foo, %foo* %deref1, i32 0, i32 0
+      %s = getelementptr inbounds %foo, %foo* %deref1, i32 0, i32 1
       store [81 x i8]* %s, [81 x i8]** %ptr, align 8
       %deref2 = load %foo*, %foo** %self, align 8
-      %alias = getelementptr inbounds %foo, %foo* %deref2, i32 0, i32 2
+      %alias = getelementptr inbounds %foo, %foo* %deref2, i32 0, i32 3
       %deref3 = load %foo*, %foo** %self, align 8
-      %s4 = getelementptr inbounds %foo, %foo* %deref3, i32 0, i32 0
+      %s4 = getelementptr inbounds %foo, %foo* %deref3, i32 0, i32 1
       store [81 x i8]* %s4, [81 x i8]** %alias, align 8
       %deref5 = load %foo*, %foo** %self, align 8
-      %reference_to = getelementptr inbounds %foo, %foo* %deref5, i32 0, i32 3
+      %reference_to = getelementptr inbounds %foo, %foo* %deref5, i32 0, i32 4
       %deref6 = load %foo*, %foo** %self, align 8
-      %s7 = getelementptr inbounds %foo, %foo* %deref6, i32 0, i32 0
+      %s7 = getelementptr inbounds %foo, %foo* %deref6, i32 0, i32 1
       store [81 x i8]* %s7, [81 x i8]** %reference_to, align 8
       ret void
     }
@@ -1523,13 +1585,15 @@ fn temporary_variable_ref_to_local_member() {
     ; ModuleID = '<internal>'
     source_filename = "<internal>"
 
-    %foo = type { [81 x i8] }
+    %foo = type { i8*, [81 x i8] }
 
-    @__foo__init = unnamed_addr constant %foo zeroinitializer
+    @__vtable_foo = unnamed_addr constant [0 x i8*] zeroinitializer
+    @__foo__init = unnamed_addr constant %foo { i8* bitcast ([0 x i8*]* @__vtable_foo to i8*), [81 x i8] zeroinitializer }
 
     define void @foo(%foo* %0) {
     entry:
-      %s = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %s = getelementptr inbounds %foo, %foo* %0, i32 0, i32 1
       %ptr = alloca [81 x i8]*, align 8
       %alias = alloca [81 x i8]*, align 8
       %reference_to = alloca [81 x i8]*, align 8
@@ -1544,8 +1608,9 @@ fn temporary_variable_ref_to_local_member() {
     ; ModuleID = '__initializers'
     source_filename = "__initializers"
 
-    %foo = type { [81 x i8] }
+    %foo = type { i8*, [81 x i8] }
 
+    @__vtable_foo = external unnamed_addr constant [0 x i8*]
     @__foo__init = external global %foo
 
     define void @__init_foo(%foo* %0) {
@@ -1640,18 +1705,21 @@ fn initializing_method_variables_with_refs() {
     ; ModuleID = '<internal>'
     source_filename = "<internal>"
 
-    %foo = type {}
+    %foo = type { i8* }
     %foo.bar = type { i32, i32* }
 
-    @__foo__init = unnamed_addr constant %foo zeroinitializer
+    @__vtable_foo = unnamed_addr constant [1 x i8*] [i8* bitcast (void (%foo*, %foo.bar*)* @__vtable_foo.bar to i8*)]
+    @__foo__init = unnamed_addr constant %foo { i8* bitcast ([1 x i8*]* @__vtable_foo to i8*) }
 
     define void @foo(%foo* %0) {
     entry:
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
       ret void
     }
 
     define void @foo.bar(%foo* %0, %foo.bar* %1) {
     entry:
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
       %x = getelementptr inbounds %foo.bar, %foo.bar* %1, i32 0, i32 0
       %px = getelementptr inbounds %foo.bar, %foo.bar* %1, i32 0, i32 1
       store i32 10, i32* %x, align 4
@@ -1659,11 +1727,19 @@ fn initializing_method_variables_with_refs() {
       store i32* %x, i32** %px, align 8
       ret void
     }
+
+    define internal void @__vtable_foo.bar(%foo* %0, %foo.bar* %1) {
+    entry:
+      %foo.bar_instance = alloca %foo.bar, align 8
+      call void @foo.bar(%foo* %0, %foo.bar* %foo.bar_instance)
+      ret void
+    }
     ; ModuleID = '__initializers'
     source_filename = "__initializers"
 
-    %foo = type {}
+    %foo = type { i8* }
 
+    @__vtable_foo = external unnamed_addr constant [1 x i8*]
     @__foo__init = external global %foo
 
     define void @__init_foo(%foo* %0) {
@@ -1706,30 +1782,41 @@ fn initializing_method_variables_with_refs_referencing_parent_pou_variable() {
     ; ModuleID = '<internal>'
     source_filename = "<internal>"
 
-    %foo = type { i32 }
+    %foo = type { i8*, i32 }
     %foo.bar = type { i32* }
 
-    @__foo__init = unnamed_addr constant %foo { i32 5 }
+    @__vtable_foo = unnamed_addr constant [1 x i8*] [i8* bitcast (void (%foo*, %foo.bar*)* @__vtable_foo.bar to i8*)]
+    @__foo__init = unnamed_addr constant %foo { i8* bitcast ([1 x i8*]* @__vtable_foo to i8*), i32 5 }
 
     define void @foo(%foo* %0) {
     entry:
-      %x = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %x = getelementptr inbounds %foo, %foo* %0, i32 0, i32 1
       ret void
     }
 
     define void @foo.bar(%foo* %0, %foo.bar* %1) {
     entry:
-      %x = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %x = getelementptr inbounds %foo, %foo* %0, i32 0, i32 1
       %px = getelementptr inbounds %foo.bar, %foo.bar* %1, i32 0, i32 0
       store i32* %x, i32** %px, align 8
       store i32* %x, i32** %px, align 8
       ret void
     }
+
+    define internal void @__vtable_foo.bar(%foo* %0, %foo.bar* %1) {
+    entry:
+      %foo.bar_instance = alloca %foo.bar, align 8
+      call void @foo.bar(%foo* %0, %foo.bar* %foo.bar_instance)
+      ret void
+    }
     ; ModuleID = '__initializers'
     source_filename = "__initializers"
 
-    %foo = type { i32 }
+    %foo = type { i8*, i32 }
 
+    @__vtable_foo = external unnamed_addr constant [1 x i8*]
     @__foo__init = external global %foo
 
     define void @__init_foo(%foo* %0) {
@@ -1772,29 +1859,40 @@ fn initializing_method_variables_with_refs_referencing_global_variable() {
     ; ModuleID = '<internal>'
     source_filename = "<internal>"
 
-    %foo = type {}
+    %foo = type { i8* }
     %foo.bar = type { i32* }
 
+    @__vtable_foo = unnamed_addr constant [1 x i8*] [i8* bitcast (void (%foo*, %foo.bar*)* @__vtable_foo.bar to i8*)]
     @x = global i32 0
-    @__foo__init = unnamed_addr constant %foo zeroinitializer
+    @__foo__init = unnamed_addr constant %foo { i8* bitcast ([1 x i8*]* @__vtable_foo to i8*) }
 
     define void @foo(%foo* %0) {
     entry:
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
       ret void
     }
 
     define void @foo.bar(%foo* %0, %foo.bar* %1) {
     entry:
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
       %px = getelementptr inbounds %foo.bar, %foo.bar* %1, i32 0, i32 0
       store i32* @x, i32** %px, align 8
       store i32* @x, i32** %px, align 8
       ret void
     }
+
+    define internal void @__vtable_foo.bar(%foo* %0, %foo.bar* %1) {
+    entry:
+      %foo.bar_instance = alloca %foo.bar, align 8
+      call void @foo.bar(%foo* %0, %foo.bar* %foo.bar_instance)
+      ret void
+    }
     ; ModuleID = '__initializers'
     source_filename = "__initializers"
 
-    %foo = type {}
+    %foo = type { i8* }
 
+    @__vtable_foo = external unnamed_addr constant [1 x i8*]
     @__foo__init = external global %foo
 
     define void @__init_foo(%foo* %0) {
@@ -1838,19 +1936,22 @@ fn initializing_method_variables_with_refs_shadowing() {
     ; ModuleID = '<internal>'
     source_filename = "<internal>"
 
-    %foo = type {}
+    %foo = type { i8* }
     %foo.bar = type { i32, i32* }
 
+    @__vtable_foo = unnamed_addr constant [1 x i8*] [i8* bitcast (void (%foo*, %foo.bar*)* @__vtable_foo.bar to i8*)]
     @x = global i32 0
-    @__foo__init = unnamed_addr constant %foo zeroinitializer
+    @__foo__init = unnamed_addr constant %foo { i8* bitcast ([1 x i8*]* @__vtable_foo to i8*) }
 
     define void @foo(%foo* %0) {
     entry:
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
       ret void
     }
 
     define void @foo.bar(%foo* %0, %foo.bar* %1) {
     entry:
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
       %x = getelementptr inbounds %foo.bar, %foo.bar* %1, i32 0, i32 0
       %px = getelementptr inbounds %foo.bar, %foo.bar* %1, i32 0, i32 1
       store i32 0, i32* %x, align 4
@@ -1858,11 +1959,19 @@ fn initializing_method_variables_with_refs_shadowing() {
       store i32* %x, i32** %px, align 8
       ret void
     }
+
+    define internal void @__vtable_foo.bar(%foo* %0, %foo.bar* %1) {
+    entry:
+      %foo.bar_instance = alloca %foo.bar, align 8
+      call void @foo.bar(%foo* %0, %foo.bar* %foo.bar_instance)
+      ret void
+    }
     ; ModuleID = '__initializers'
     source_filename = "__initializers"
 
-    %foo = type {}
+    %foo = type { i8* }
 
+    @__vtable_foo = external unnamed_addr constant [1 x i8*]
     @__foo__init = external global %foo
 
     define void @__init_foo(%foo* %0) {
@@ -1902,18 +2011,21 @@ fn initializing_method_variables_with_alias() {
     ; ModuleID = '<internal>'
     source_filename = "<internal>"
 
-    %foo = type {}
+    %foo = type { i8* }
     %foo.bar = type { i32, i32* }
 
-    @__foo__init = unnamed_addr constant %foo zeroinitializer
+    @__vtable_foo = unnamed_addr constant [1 x i8*] [i8* bitcast (void (%foo*, %foo.bar*)* @__vtable_foo.bar to i8*)]
+    @__foo__init = unnamed_addr constant %foo { i8* bitcast ([1 x i8*]* @__vtable_foo to i8*) }
 
     define void @foo(%foo* %0) {
     entry:
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
       ret void
     }
 
     define void @foo.bar(%foo* %0, %foo.bar* %1) {
     entry:
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
       %x = getelementptr inbounds %foo.bar, %foo.bar* %1, i32 0, i32 0
       %px = getelementptr inbounds %foo.bar, %foo.bar* %1, i32 0, i32 1
       store i32 0, i32* %x, align 4
@@ -1921,11 +2033,19 @@ fn initializing_method_variables_with_alias() {
       store i32* %x, i32** %px, align 8
       ret void
     }
+
+    define internal void @__vtable_foo.bar(%foo* %0, %foo.bar* %1) {
+    entry:
+      %foo.bar_instance = alloca %foo.bar, align 8
+      call void @foo.bar(%foo* %0, %foo.bar* %foo.bar_instance)
+      ret void
+    }
     ; ModuleID = '__initializers'
     source_filename = "__initializers"
 
-    %foo = type {}
+    %foo = type { i8* }
 
+    @__vtable_foo = external unnamed_addr constant [1 x i8*]
     @__foo__init = external global %foo
 
     define void @__init_foo(%foo* %0) {
@@ -1965,18 +2085,21 @@ fn initializing_method_variables_with_reference_to() {
     ; ModuleID = '<internal>'
     source_filename = "<internal>"
 
-    %foo = type {}
+    %foo = type { i8* }
     %foo.bar = type { i32, i32* }
 
-    @__foo__init = unnamed_addr constant %foo zeroinitializer
+    @__vtable_foo = unnamed_addr constant [1 x i8*] [i8* bitcast (void (%foo*, %foo.bar*)* @__vtable_foo.bar to i8*)]
+    @__foo__init = unnamed_addr constant %foo { i8* bitcast ([1 x i8*]* @__vtable_foo to i8*) }
 
     define void @foo(%foo* %0) {
     entry:
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
       ret void
     }
 
     define void @foo.bar(%foo* %0, %foo.bar* %1) {
     entry:
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
       %x = getelementptr inbounds %foo.bar, %foo.bar* %1, i32 0, i32 0
       %px = getelementptr inbounds %foo.bar, %foo.bar* %1, i32 0, i32 1
       store i32 0, i32* %x, align 4
@@ -1984,11 +2107,19 @@ fn initializing_method_variables_with_reference_to() {
       store i32* %x, i32** %px, align 8
       ret void
     }
+
+    define internal void @__vtable_foo.bar(%foo* %0, %foo.bar* %1) {
+    entry:
+      %foo.bar_instance = alloca %foo.bar, align 8
+      call void @foo.bar(%foo* %0, %foo.bar* %foo.bar_instance)
+      ret void
+    }
     ; ModuleID = '__initializers'
     source_filename = "__initializers"
 
-    %foo = type {}
+    %foo = type { i8* }
 
+    @__vtable_foo = external unnamed_addr constant [1 x i8*]
     @__foo__init = external global %foo
 
     define void @__init_foo(%foo* %0) {
@@ -2035,27 +2166,32 @@ fn methods_call_init_functions_for_their_members() {
     ; ModuleID = '<internal>'
     source_filename = "<internal>"
 
-    %foo = type { i32, i32* }
-    %bar = type {}
+    %foo = type { i8*, i32, i32* }
+    %bar = type { i8* }
     %bar.baz = type { %foo }
 
-    @__foo__init = unnamed_addr constant %foo zeroinitializer
-    @__bar__init = unnamed_addr constant %bar zeroinitializer
+    @__vtable_foo = unnamed_addr constant [0 x i8*] zeroinitializer
+    @__vtable_bar = unnamed_addr constant [1 x i8*] [i8* bitcast (void (%bar*, %bar.baz*)* @__vtable_bar.baz to i8*)]
+    @__foo__init = unnamed_addr constant %foo { i8* bitcast ([0 x i8*]* @__vtable_foo to i8*), i32 0, i32* null }
+    @__bar__init = unnamed_addr constant %bar { i8* bitcast ([1 x i8*]* @__vtable_bar to i8*) }
 
     define void @foo(%foo* %0) {
     entry:
-      %x = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
-      %y = getelementptr inbounds %foo, %foo* %0, i32 0, i32 1
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %x = getelementptr inbounds %foo, %foo* %0, i32 0, i32 1
+      %y = getelementptr inbounds %foo, %foo* %0, i32 0, i32 2
       ret void
     }
 
     define void @bar(%bar* %0) {
     entry:
+      %__vtable = getelementptr inbounds %bar, %bar* %0, i32 0, i32 0
       ret void
     }
 
     define void @bar.baz(%bar* %0, %bar.baz* %1) {
     entry:
+      %__vtable = getelementptr inbounds %bar, %bar* %0, i32 0, i32 0
       %fb = getelementptr inbounds %bar.baz, %bar.baz* %1, i32 0, i32 0
       %2 = bitcast %foo* %fb to i8*
       call void @llvm.memcpy.p0i8.p0i8.i64(i8* align 1 %2, i8* align 1 bitcast (%foo* @__foo__init to i8*), i64 ptrtoint (%foo* getelementptr (%foo, %foo* null, i32 1) to i64), i1 false)
@@ -2065,6 +2201,13 @@ fn methods_call_init_functions_for_their_members() {
 
     declare void @__init_foo(%foo*)
 
+    define internal void @__vtable_bar.baz(%bar* %0, %bar.baz* %1) {
+    entry:
+      %bar.baz_instance = alloca %bar.baz, align 8
+      call void @bar.baz(%bar* %0, %bar.baz* %bar.baz_instance)
+      ret void
+    }
+
     ; Function Attrs: argmemonly nofree nounwind willreturn
     declare void @llvm.memcpy.p0i8.p0i8.i64(i8* noalias nocapture writeonly, i8* noalias nocapture readonly, i64, i1 immarg) #0
 
@@ -2072,9 +2215,11 @@ fn methods_call_init_functions_for_their_members() {
     ; ModuleID = '__initializers'
     source_filename = "__initializers"
 
-    %foo = type { i32, i32* }
-    %bar = type {}
+    %foo = type { i8*, i32, i32* }
+    %bar = type { i8* }
 
+    @__vtable_foo = external unnamed_addr constant [0 x i8*]
+    @__vtable_bar = external unnamed_addr constant [1 x i8*]
     @__foo__init = external global %foo
     @__bar__init = external global %bar
 
@@ -2083,9 +2228,9 @@ fn methods_call_init_functions_for_their_members() {
       %self = alloca %foo*, align 8
       store %foo* %0, %foo** %self, align 8
       %deref = load %foo*, %foo** %self, align 8
-      %y = getelementptr inbounds %foo, %foo* %deref, i32 0, i32 1
+      %y = getelementptr inbounds %foo, %foo* %deref, i32 0, i32 2
       %deref1 = load %foo*, %foo** %self, align 8
-      %x = getelementptr inbounds %foo, %foo* %deref1, i32 0, i32 0
+      %x = getelementptr inbounds %foo, %foo* %deref1, i32 0, i32 1
       store i32* %x, i32** %y, align 8
       ret void
     }
@@ -163,20 +163,21 @@ fn external_pous_get_external_initializers() {
         ",
     );
 
-    insta::assert_snapshot!(result, @r###"
+    insta::assert_snapshot!(result, @r#"
     ; ModuleID = '<internal>'
     source_filename = "<internal>"
 
-    %ext_fb = type {}
+    %ext_fb = type { i8* }
     %ext_prog = type {}
 
+    @__vtable_ext_fb = unnamed_addr constant [0 x i8*] zeroinitializer
     @__ext_fb__init = external global %ext_fb
     @ext_prog_instance = external global %ext_prog
 
     declare void @ext_fb(%ext_fb*)
 
     declare void @ext_prog(%ext_prog*)
-    "###);
+    "#);
 }
 
 #[test]
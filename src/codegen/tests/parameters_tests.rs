@@ -1114,13 +1114,14 @@ fn by_value_fb_arg_aggregates_are_memcopied() {
         "#,
     );
 
-    assert_snapshot!(result, @r###"
+    assert_snapshot!(result, @r#"
     ; ModuleID = '<internal>'
     source_filename = "<internal>"
 
-    %FOO = type { [65537 x i8], [1024 x i32] }
+    %FOO = type { i8*, [65537 x i8], [1024 x i32] }
 
-    @__FOO__init = unnamed_addr constant %FOO zeroinitializer
+    @__vtable_FOO = unnamed_addr constant [0 x i8*] zeroinitializer
+    @__FOO__init = unnamed_addr constant %FOO { i8* bitcast ([0 x i8*]* @__vtable_FOO to i8*), [65537 x i8] zeroinitializer, [1024 x i32] zeroinitializer }
 
     define i32 @main() {
     entry:
@@ -1133,14 +1134,14 @@ fn by_value_fb_arg_aggregates_are_memcopied() {
       %1 = bitcast [1024 x i32]* %arr to i8*
       call void @llvm.memset.p0i8.i64(i8* align 1 %1, i8 0, i64 ptrtoint ([1024 x i32]* getelementptr ([1024 x i32], [1024 x i32]* null, i32 1) to i64), i1 false)
       %2 = bitcast %FOO* %fb to i8*
-      call void @llvm.memcpy.p0i8.p0i8.i64(i8* align 1 %2, i8* align 1 getelementptr inbounds (%FOO, %FOO* @__FOO__init, i32 0, i32 0, i32 0), i64 ptrtoint (%FOO* getelementptr (%FOO, %FOO* null, i32 1) to i64), i1 false)
+      call void @llvm.memcpy.p0i8.p0i8.i64(i8* align 1 %2, i8* align 1 bitcast (%FOO* @__FOO__init to i8*), i64 ptrtoint (%FOO* getelementptr (%FOO, %FOO* null, i32 1) to i64), i1 false)
       store i32 0, i32* %main, align 4
       call void @__init_foo(%FOO* %fb)
-      %3 = getelementptr inbounds %FOO, %FOO* %fb, i32 0, i32 0
+      %3 = getelementptr inbounds %FOO, %FOO* %fb, i32 0, i32 1
       %4 = bitcast [65537 x i8]* %3 to i8*
       %5 = bitcast [65537 x i8]* %str to i8*
       call void @llvm.memcpy.p0i8.p0i8.i32(i8* align 1 %4, i8* align 1 %5, i32 65536, i1 false)
-      %6 = getelementptr inbounds %FOO, %FOO* %fb, i32 0, i32 1
+      %6 = getelementptr inbounds %FOO, %FOO* %fb, i32 0, i32 2
       %7 = bitcast [1024 x i32]* %6 to i8*
       %8 = bitcast [1024 x i32]* %arr to i8*
       call void @llvm.memcpy.p0i8.p0i8.i64(i8* align 1 %7, i8* align 1 %8, i64 ptrtoint ([1024 x i32]* getelementptr ([1024 x i32], [1024 x i32]* null, i32 1) to i64), i1 false)
@@ -1151,8 +1152,9 @@ fn by_value_fb_arg_aggregates_are_memcopied() {
 
     define void @FOO(%FOO* %0) {
     entry:
-      %val = getelementptr inbounds %FOO, %FOO* %0, i32 0, i32 0
-      %field = getelementptr inbounds %FOO, %FOO* %0, i32 0, i32 1
+      %__vtable = getelementptr inbounds %FOO, %FOO* %0, i32 0, i32 0
+      %val = getelementptr inbounds %FOO, %FOO* %0, i32 0, i32 1
+      %field = getelementptr inbounds %FOO, %FOO* %0, i32 0, i32 2
       ret void
     }
 
@@ -1172,8 +1174,9 @@ fn by_value_fb_arg_aggregates_are_memcopied() {
     ; ModuleID = '__initializers'
     source_filename = "__initializers"
 
-    %FOO = type { [65537 x i8], [1024 x i32] }
+    %FOO = type { i8*, [65537 x i8], [1024 x i32] }
 
+    @__vtable_FOO = external unnamed_addr constant [0 x i8*]
     @__FOO__init = external global %FOO
 
     define void @__init_foo(%FOO* %0) {
@@ -1193,7 +1196,7 @@ fn by_value_fb_arg_aggregates_are_memcopied() {
     entry:
       ret void
     }
-    "###);
+    "#);
 }
 
 #[test]
@@ -1229,25 +1232,27 @@ fn var_output_aggregate_types_are_memcopied() {
         "#,
     );
 
-    assert_snapshot!(result, @r###"
+    assert_snapshot!(result, @r#"
     ; ModuleID = '<internal>'
     source_filename = "<internal>"
 
-    %FB = type { %OUT_TYPE, [11 x i32], [11 x %OUT_TYPE], [81 x i8], [81 x i16] }
+    %FB = type { i8*, %OUT_TYPE, [11 x i32], [11 x %OUT_TYPE], [81 x i8], [81 x i16] }
     %OUT_TYPE = type { i8 }
     %PRG = type { %OUT_TYPE, [11 x i32], [11 x %OUT_TYPE], [81 x i8], [81 x i16], %FB }
 
-    @__FB__init = unnamed_addr constant %FB zeroinitializer
+    @__vtable_FB = unnamed_addr constant [0 x i8*] zeroinitializer
+    @__FB__init = unnamed_addr constant %FB { i8* bitcast ([0 x i8*]* @__vtable_FB to i8*), %OUT_TYPE zeroinitializer, [11 x i32] zeroinitializer, [11 x %OUT_TYPE] zeroinitializer, [81 x i8] zeroinitializer, [81 x i16] zeroinitializer }
     @__OUT_TYPE__init = unnamed_addr constant %OUT_TYPE zeroinitializer
-    @PRG_instance = global %PRG zeroinitializer
+    @PRG_instance = global %PRG { %OUT_TYPE zeroinitializer, [11 x i32] zeroinitializer, [11 x %OUT_TYPE] zeroinitializer, [81 x i8] zeroinitializer, [81 x i16] zeroinitializer, %FB { i8* bitcast ([0 x i8*]* @__vtable_FB to i8*), %OUT_TYPE zeroinitializer, [11 x i32] zeroinitializer, [11 x %OUT_TYPE] zeroinitializer, [81 x i8] zeroinitializer, [81 x i16] zeroinitializer } }
 
     define void @FB(%FB* %0) {
     entry:
-      %output = getelementptr inbounds %FB, %FB* %0, i32 0, i32 0
-      %output2 = getelementptr inbounds %FB, %FB* %0, i32 0, i32 1
-      %output3 = getelementptr inbounds %FB, %FB* %0, i32 0, i32 2
-      %output4 = getelementptr inbounds %FB, %FB* %0, i32 0, i32 3
-      %output5 = getelementptr inbounds %FB, %FB* %0, i32 0, i32 4
+      %__vtable = getelementptr inbounds %FB, %FB* %0, i32 0, i32 0
+      %output = getelementptr inbounds %FB, %FB* %0, i32 0, i32 1
+      %output2 = getelementptr inbounds %FB, %FB* %0, i32 0, i32 2
+      %output3 = getelementptr inbounds %FB, %FB* %0, i32 0, i32 3
+      %output4 = getelementptr inbounds %FB, %FB* %0, i32 0, i32 4
+      %output5 = getelementptr inbounds %FB, %FB* %0, i32 0, i32 5
       ret void
     }
 
@@ -1260,23 +1265,23 @@ fn var_output_aggregate_types_are_memcopied() {
       %out5 = getelementptr inbounds %PRG, %PRG* %0, i32 0, i32 4
       %station = getelementptr inbounds %PRG, %PRG* %0, i32 0, i32 5
       call void @FB(%FB* %station)
-      %1 = getelementptr inbounds %FB, %FB* %station, i32 0, i32 0
+      %1 = getelementptr inbounds %FB, %FB* %station, i32 0, i32 1
       %2 = bitcast %OUT_TYPE* %out to i8*
       %3 = bitcast %OUT_TYPE* %1 to i8*
       call void @llvm.memcpy.p0i8.p0i8.i64(i8* align 1 %2, i8* align 1 %3, i64 ptrtoint (%OUT_TYPE* getelementptr (%OUT_TYPE, %OUT_TYPE* null, i32 1) to i64), i1 false)
-      %4 = getelementptr inbounds %FB, %FB* %station, i32 0, i32 1
+      %4 = getelementptr inbounds %FB, %FB* %station, i32 0, i32 2
       %5 = bitcast [11 x i32]* %out2 to i8*
       %6 = bitcast [11 x i32]* %4 to i8*
       call void @llvm.memcpy.p0i8.p0i8.i64(i8* align 1 %5, i8* align 1 %6, i64 ptrtoint ([11 x i32]* getelementptr ([11 x i32], [11 x i32]* null, i32 1) to i64), i1 false)
-      %7 = getelementptr inbounds %FB, %FB* %station, i32 0, i32 2
+      %7 = getelementptr inbounds %FB, %FB* %station, i32 0, i32 3
       %8 = bitcast [11 x %OUT_TYPE]* %out3 to i8*
       %9 = bitcast [11 x %OUT_TYPE]* %7 to i8*
       call void @llvm.memcpy.p0i8.p0i8.i64(i8* align 1 %8, i8* align 1 %9, i64 ptrtoint ([11 x %OUT_TYPE]* getelementptr ([11 x %OUT_TYPE], [11 x %OUT_TYPE]* null, i32 1) to i64), i1 false)
-      %10 = getelementptr inbounds %FB, %FB* %station, i32 0, i32 3
+      %10 = getelementptr inbounds %FB, %FB* %station, i32 0, i32 4
       %11 = bitcast [81 x i8]* %out4 to i8*
       %12 = bitcast [81 x i8]* %10 to i8*
       call void @llvm.memcpy.p0i8.p0i8.i32(i8* align 1 %11, i8* align 1 %12, i32 80, i1 false)
-      %13 = getelementptr inbounds %FB, %FB* %station, i32 0, i32 4
+      %13 = getelementptr inbounds %FB, %FB* %station, i32 0, i32 5
       %14 = bitcast [81 x i16]* %out5 to i8*
       %15 = bitcast [81 x i16]* %13 to i8*
       call void @llvm.memcpy.p0i8.p0i8.i32(i8* align 2 %14, i8* align 2 %15, i32 160, i1 false)
@@ -1294,9 +1299,10 @@ fn var_output_aggregate_types_are_memcopied() {
     source_filename = "__initializers"
 
     %OUT_TYPE = type { i8 }
-    %FB = type { %OUT_TYPE, [11 x i32], [11 x %OUT_TYPE], [81 x i8], [81 x i16] }
+    %FB = type { i8*, %OUT_TYPE, [11 x i32], [11 x %OUT_TYPE], [81 x i8], [81 x i16] }
     %PRG = type { %OUT_TYPE, [11 x i32], [11 x %OUT_TYPE], [81 x i8], [81 x i16], %FB }
 
+    @__vtable_FB = external unnamed_addr constant [0 x i8*]
     @__OUT_TYPE__init = external global %OUT_TYPE
     @__FB__init = external global %FB
     @PRG_instance = external global %PRG
@@ -1313,7 +1319,7 @@ fn var_output_aggregate_types_are_memcopied() {
       %self = alloca %FB*, align 8
       store %FB* %0, %FB** %self, align 8
       %deref = load %FB*, %FB** %self, align 8
-      %output = getelementptr inbounds %FB, %FB* %deref, i32 0, i32 0
+      %output = getelementptr inbounds %FB, %FB* %deref, i32 0, i32 1
       call void @__init_out_type(%OUT_TYPE* %output)
       ret void
     }
@@ -1339,8 +1345,9 @@ fn var_output_aggregate_types_are_memcopied() {
 
     %PRG = type { %OUT_TYPE, [11 x i32], [11 x %OUT_TYPE], [81 x i8], [81 x i16], %FB }
     %OUT_TYPE = type { i8 }
-    %FB = type { %OUT_TYPE, [11 x i32], [11 x %OUT_TYPE], [81 x i8], [81 x i16] }
+    %FB = type { i8*, %OUT_TYPE, [11 x i32], [11 x %OUT_TYPE], [81 x i8], [81 x i16] }
 
+    @__vtable_FB = external unnamed_addr constant [0 x i8*]
     @PRG_instance = external global %PRG
     @__OUT_TYPE__init = external global %OUT_TYPE
     @__FB__init = external global %FB
@@ -1357,5 +1364,5 @@ fn var_output_aggregate_types_are_memcopied() {
     declare void @PRG(%PRG*)
 
     declare void @FB(%FB*)
-    "###);
+    "#);
 }
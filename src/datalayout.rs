@@ -189,4 +189,36 @@ mod tests {
         assert_eq!(struct_type.get_size(&index).bits(), 192);
         assert_eq!(struct_type.get_alignment(&index), Bytes::new(8)) //Struct alignment is 64 by default
     }
+
+    #[test]
+    fn union_size_and_alignment_are_taken_from_its_members() {
+        //Given the default data layout
+        //When unions with different member sizes are created
+        let (_, index) = index(
+            "
+        TYPE SmallUnion : UNION
+            a : BYTE; //8bit
+            b : WORD; //16bit
+        END_UNION
+        END_TYPE
+
+        TYPE MyUnion : UNION
+            a : BYTE; //8bit
+            b : ARRAY[0..4] OF WORD; //80bit, 16bit aligned
+            c : DWORD; //32bit
+        END_UNION
+        END_TYPE
+        ",
+        );
+
+        // Then the union is as large as its largest member and aligned like its most strictly aligned member
+        let small_union = index.get_effective_type_by_name("SmallUnion").unwrap().get_type_information();
+        assert_eq!(small_union.get_size(&index).bits(), 16);
+        assert_eq!(small_union.get_alignment(&index), Bytes::new(2));
+
+        // And the size is padded to a multiple of the alignment
+        let union_type = index.get_effective_type_by_name("MyUnion").unwrap().get_type_information();
+        assert_eq!(union_type.get_size(&index).bits(), 96);
+        assert_eq!(union_type.get_alignment(&index), Bytes::new(4));
+    }
 }
@@ -1,9 +1,13 @@
 use std::{fmt::Display, vec};
 
-use plc_ast::ast::{AstNode, AstStatement, ConfigVariable, ReferenceAccess, ReferenceExpr};
+use plc_ast::ast::{AccessVariable, AstNode, AstStatement, ConfigVariable, ReferenceAccess, ReferenceExpr};
 use plc_diagnostics::diagnostics::Diagnostic;
 
-use crate::{index::Index, typesystem::Dimension};
+use crate::{
+    datalayout::Bytes,
+    index::Index,
+    typesystem::{DataTypeInformation, Dimension},
+};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ExpressionPathElement<'idx> {
@@ -44,6 +48,10 @@ impl<'idx> ExpressionPath<'idx> {
         self.names.append(&mut name.names)
     }
 
+    pub fn get_segments(&self) -> &[ExpressionPathElement<'idx>] {
+        &self.names
+    }
+
     pub fn append(&self, element: ExpressionPathElement<'idx>) -> ExpressionPath<'idx> {
         let mut res = self.clone();
         res.names.push(element);
@@ -105,34 +113,144 @@ impl<'idx> ExpressionPath<'idx> {
             res
         })
     }
+
+    /// Returns the offset of the referenced variable from the start of the path's first variable, a global
+    /// variable or a program instance. Returns `None` if the variable cannot be reached without following a
+    /// pointer, e.g. a `VAR_IN_OUT` or a hardware-bound variable, or if an array index is out of bounds
+    pub fn get_offset(&self, index: &Index) -> Option<Bytes> {
+        let (ExpressionPathElement::Name(root), segments) = self.names.split_first()? else {
+            return None;
+        };
+
+        let root_type_name = index.find_global_variable(root).map(|it| it.get_type_name()).or_else(|| {
+            index
+                .find_pou(root)
+                .filter(|it| it.is_program())
+                .and_then(|it| it.get_instance_struct_type_name())
+        })?;
+
+        let mut offset = Bytes::new(0);
+        let mut type_info = index.find_effective_type_info(root_type_name)?;
+        for segment in segments {
+            if type_info.is_auto_deref() {
+                return None;
+            }
+
+            let inner_type_name = match (segment, type_info) {
+                (ExpressionPathElement::Name(member), DataTypeInformation::Struct { name, .. }) => {
+                    let variable = index.find_member(name, member).filter(|it| it.is_stored_in_struct())?;
+                    offset += type_info.get_member_offset(member, index)?;
+                    variable.get_type_name()
+                }
+                (
+                    ExpressionPathElement::ArrayAccess(indices),
+                    DataTypeInformation::Array { inner_type_name, dimensions, .. },
+                ) => {
+                    let element = get_element_index(indices, dimensions, index)?;
+                    let element_size = index.get_type_information_or_void(inner_type_name).get_size(index);
+                    offset += Bytes::new(element * element_size.value());
+                    inner_type_name
+                }
+                _ => return None,
+            };
+            type_info = index.find_effective_type_info(inner_type_name)?;
+        }
+
+        (!type_info.is_auto_deref()).then_some(offset)
+    }
+
+    /// Expands the path into a path per referenced instance, every contained array is replaced by an
+    /// access to each of its elements in the order they are laid out in memory
+    pub fn expand_elements(&self, index: &Index) -> Vec<ExpressionPath<'idx>> {
+        self.names.iter().fold(vec![ExpressionPath::default()], |paths, segment| {
+            let ExpressionPathElement::ArrayDimensions(dimensions) = segment else {
+                return paths.into_iter().map(|it| it.append(segment.clone())).collect();
+            };
+
+            let elements = dimensions.iter().fold(vec![vec![]], |elements: Vec<Vec<i128>>, dimension| {
+                // an array with unresolvable dimensions has no elements
+                let range = dimension.get_range_inclusive(index).ok();
+                elements
+                    .into_iter()
+                    .flat_map(|element| {
+                        range.clone().into_iter().flatten().map(move |idx| {
+                            let mut element = element.clone();
+                            element.push(idx.into());
+                            element
+                        })
+                    })
+                    .collect()
+            });
+            paths
+                .into_iter()
+                .flat_map(|path| {
+                    elements
+                        .iter()
+                        .map(move |element| path.append(ExpressionPathElement::ArrayAccess(element.clone())))
+                })
+                .collect()
+        })
+    }
+}
+
+/// Returns the position of the element accessed with the given indices in an array with the given dimensions.
+/// The elements are laid out row by row, the last dimension changes fastest
+pub fn get_element_index(indices: &[i128], dimensions: &[Dimension], index: &Index) -> Option<u32> {
+    if indices.len() != dimensions.len() {
+        return None;
+    }
+
+    let mut element = 0;
+    for (dimension, idx) in dimensions.iter().zip(indices) {
+        let range = dimension.get_range_inclusive(index).ok()?;
+        if !range.contains(&i64::try_from(*idx).ok()?) {
+            return None;
+        }
+        element = element * dimension.get_length(index).ok()? + (*idx as i64 - range.start()) as u32;
+    }
+    Some(element)
 }
 
 impl<'a> TryFrom<&'a ConfigVariable> for ExpressionPath<'a> {
     type Error = Vec<Diagnostic>;
 
     fn try_from(value: &'a ConfigVariable) -> Result<Self, Self::Error> {
-        let mut names = get_expression_path_segments(&value.reference)?;
+        let mut names = get_expression_path_segments(&value.reference, "VAR_CONFIG")?;
         names.reverse();
         Ok(Self { names })
     }
 }
 
-// Transforms a `ConfigVariable`'s 'AstNode' into a collection of corresponding `ExpressionPathElement`s.
+impl<'a> TryFrom<&'a AccessVariable> for ExpressionPath<'a> {
+    type Error = Vec<Diagnostic>;
+
+    fn try_from(value: &'a AccessVariable) -> Result<Self, Self::Error> {
+        let mut names = get_expression_path_segments(&value.reference, "VAR_ACCESS")?;
+        names.reverse();
+        Ok(Self { names })
+    }
+}
+
+// Transforms a `ConfigVariable`'s or `AccessVariable`'s 'AstNode' into a collection of corresponding `ExpressionPathElement`s.
 // This function will traverse the AST top-to-bottom, collecting segments along the way, which means the order of the collection
 // needs to be reversed by the caller to match the written expression.
-fn get_expression_path_segments(node: &AstNode) -> Result<Vec<ExpressionPathElement>, Vec<Diagnostic>> {
+fn get_expression_path_segments<'a>(
+    node: &'a AstNode,
+    block: &str,
+) -> Result<Vec<ExpressionPathElement<'a>>, Vec<Diagnostic>> {
     let mut paths = vec![];
     let mut diagnostics = vec![];
     let mut add_diagnostic = |location| {
         diagnostics.push(
-            Diagnostic::new("VAR_CONFIG array access must be a literal integer").with_location(location),
+            Diagnostic::new(format!("{block} array access must be a literal integer"))
+                .with_location(location),
         );
     };
     match &node.stmt {
         AstStatement::ReferenceExpr(ReferenceExpr { access: ReferenceAccess::Member(reference), base }) => {
             paths.push(ExpressionPathElement::Name(reference.get_flat_reference_name().unwrap_or_default()));
             if let Some(base) = base {
-                match get_expression_path_segments(base) {
+                match get_expression_path_segments(base, block) {
                     Ok(v) => paths.extend(v),
                     Err(e) => diagnostics.extend(e),
                 };
@@ -161,7 +279,7 @@ fn get_expression_path_segments(node: &AstNode) -> Result<Vec<ExpressionPathElem
                 _ => add_diagnostic(&idx.location),
             }
             if let Some(base) = base {
-                match get_expression_path_segments(base) {
+                match get_expression_path_segments(base, block) {
                     Ok(v) => paths.extend(v),
                     Err(e) => diagnostics.extend(e),
                 };
@@ -300,4 +418,40 @@ mod tests {
             ]
         )
     }
+
+    #[test]
+    fn expand_elements_of_nested_arrays() {
+        let dims1 = vec![Dimension {
+            start_offset: TypeSize::LiteralInteger(0),
+            end_offset: TypeSize::LiteralInteger(1),
+        }];
+        let dims2 = vec![
+            Dimension { start_offset: TypeSize::LiteralInteger(1), end_offset: TypeSize::LiteralInteger(2) },
+            Dimension { start_offset: TypeSize::LiteralInteger(-1), end_offset: TypeSize::LiteralInteger(0) },
+        ];
+
+        let name = ExpressionPath {
+            names: vec![
+                ExpressionPathElement::Name("a"),
+                ExpressionPathElement::ArrayDimensions(&dims1),
+                ExpressionPathElement::Name("b"),
+                ExpressionPathElement::ArrayDimensions(&dims2),
+            ],
+        };
+        let index = Index::default();
+        let res = name.expand_elements(&index).iter().flat_map(|it| it.expand(&index)).collect::<Vec<_>>();
+        assert_eq!(
+            res,
+            vec![
+                "a[0].b[1,-1]".to_string(),
+                "a[0].b[1,0]".to_string(),
+                "a[0].b[2,-1]".to_string(),
+                "a[0].b[2,0]".to_string(),
+                "a[1].b[1,-1]".to_string(),
+                "a[1].b[1,0]".to_string(),
+                "a[1].b[2,-1]".to_string(),
+                "a[1].b[2,0]".to_string(),
+            ]
+        )
+    }
 }
@@ -1,4 +1,4 @@
-use plc_ast::ast::{DirectAccessType, HardwareAccessType};
+use plc_ast::ast::{AccessMode, DirectAccessType, HardwareAccessType};
 use plc_diagnostics::diagnostics::Diagnostic;
 use serde::{
     ser::{SerializeSeq, SerializeStruct},
@@ -32,6 +32,7 @@ where
 pub struct HardwareConfiguration<'idx> {
     index: &'idx Index,
     hardware_binding: Vec<HardwareBinding<'idx>>,
+    access_paths: Vec<AccessPath>,
 }
 
 struct WithContext<'a, T: SerializeWithContext> {
@@ -66,8 +67,13 @@ impl Serialize for HardwareConfiguration<'_> {
     {
         let bindings: Vec<WithContext<HardwareBinding>> =
             self.hardware_binding.iter().map(|it| WithContext::new(self.index, it)).collect();
-        let mut config = serializer.serialize_struct("Configuration", 1)?;
+        let mut config = serializer.serialize_struct("Configuration", 2)?;
         config.serialize_field("HardwareConfiguration", &bindings)?;
+        if self.access_paths.is_empty() {
+            config.skip_field("AccessPaths")?;
+        } else {
+            config.serialize_field("AccessPaths", &self.access_paths)?;
+        }
         config.end()
     }
 }
@@ -104,6 +110,18 @@ struct ExpandedHardwareBinding {
     address: Vec<String>,
 }
 
+/// An access path declared in a `VAR_ACCESS` block, published under its name for external communication
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct AccessPath {
+    name: String,
+    path: String,
+    #[serde(rename = "type")]
+    type_name: String,
+    /// The offset in bytes of the variable from the start of the path's global variable or program instance
+    offset: u32,
+    access: AccessMode,
+}
+
 impl SerializeWithContext for HardwareBinding<'_> {
     fn serialize<S>(&self, ctx: &Index, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -141,8 +159,31 @@ pub fn collect_hardware_configuration(index: &Index) -> Result<HardwareConfigura
         })
         .collect();
 
-    conf.map(|hardware_binding| HardwareConfiguration { index, hardware_binding })
-        .map_err(|message| Diagnostic::new(message).with_error_code("E002"))
+    conf.map(|hardware_binding| HardwareConfiguration {
+        index,
+        hardware_binding,
+        access_paths: collect_access_paths(index),
+    })
+    .map_err(|message| Diagnostic::new(message).with_error_code("E002"))
+}
+
+/// Retrieves the access paths declared in `VAR_ACCESS` blocks, the validation reports those that cannot
+/// be published
+fn collect_access_paths(index: &Index) -> Vec<AccessPath> {
+    index
+        .get_access_variables()
+        .iter()
+        .filter_map(|variable| {
+            let path = ExpressionPath::try_from(variable).ok()?;
+            Some(AccessPath {
+                name: variable.name.clone(),
+                path: path.expand(index).into_iter().next()?,
+                type_name: variable.data_type.get_name()?.to_string(),
+                offset: path.get_offset(index)?.value(),
+                access: variable.access,
+            })
+        })
+        .collect()
 }
 
 pub fn generate_hardware_configuration(
@@ -281,4 +322,95 @@ mod tests {
         let res = generate_hardware_configuration(&config, ConfigFormat::TOML).unwrap();
         insta::assert_snapshot!(res);
     }
+
+    #[test]
+    fn access_paths_printed() {
+        let (_, index) = index(
+            "
+        TYPE Axis : STRUCT
+            enabled : BOOL;
+            speed : REAL;
+            positions : ARRAY[1..3] OF LREAL;
+        END_STRUCT END_TYPE
+
+        FUNCTION_BLOCK fb
+        VAR
+            count : INT;
+            axis : Axis;
+        END_VAR
+        END_FUNCTION_BLOCK
+
+        PROGRAM prog
+        VAR
+            flag : BOOL;
+            drive : fb;
+        END_VAR
+        END_PROGRAM
+
+        VAR_GLOBAL
+            drives : ARRAY[0..1] OF fb;
+        END_VAR
+
+        VAR_ACCESS
+            speed : prog.drive.axis.speed : REAL READ_ONLY;
+            flag : prog.flag : BOOL READ_WRITE;
+            position : drives[1].axis.positions[3] : LREAL;
+        END_VAR",
+        );
+        let config = collect_hardware_configuration(&index).unwrap();
+        let res = generate_hardware_configuration(&config, ConfigFormat::JSON).unwrap();
+        insta::assert_snapshot!(res, @r#"
+        {
+          "HardwareConfiguration": [],
+          "AccessPaths": [
+            {
+              "name": "speed",
+              "path": "prog.drive.axis.speed",
+              "type": "REAL",
+              "offset": 28,
+              "access": "READ_ONLY"
+            },
+            {
+              "name": "flag",
+              "path": "prog.flag",
+              "type": "BOOL",
+              "offset": 0,
+              "access": "READ_WRITE"
+            },
+            {
+              "name": "position",
+              "path": "drives[1].axis.positions[3]",
+              "type": "LREAL",
+              "offset": 88,
+              "access": "READ_ONLY"
+            }
+          ]
+        }
+        "#);
+        let res = generate_hardware_configuration(&config, ConfigFormat::TOML).unwrap();
+        insta::assert_snapshot!(res, @r"
+        HardwareConfiguration = []
+
+        [[AccessPaths]]
+        name = 'speed'
+        path = 'prog.drive.axis.speed'
+        type = 'REAL'
+        offset = 28
+        access = 'READ_ONLY'
+
+        [[AccessPaths]]
+        name = 'flag'
+        path = 'prog.flag'
+        type = 'BOOL'
+        offset = 0
+        access = 'READ_WRITE'
+
+        [[AccessPaths]]
+        name = 'position'
+        path = 'drives[1].axis.positions[3]'
+        type = 'LREAL'
+        offset = 88
+        access = 'READ_ONLY'
+        ");
+    }
 }
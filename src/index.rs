@@ -6,12 +6,14 @@ use itertools::Itertools;
 use rustc_hash::{FxHashSet, FxHasher};
 
 use plc_ast::ast::{
-    AstId, AstNode, AstStatement, ConfigVariable, DirectAccessType, GenericBinding, HardwareAccessType,
-    Interface, LinkageType, PouType, TypeNature,
+    AccessModifier, AccessVariable, AstId, AstNode, AstStatement, Attribute, ConfigVariable,
+    DirectAccessType, GenericBinding, HardwareAccessType, Interface, LinkageType, PolymorphismMode, PouType,
+    PropertyKind, TypeNature,
 };
+use plc_ast::literals::AstLiteral;
 use plc_diagnostics::diagnostics::Diagnostic;
 use plc_source::source_location::SourceLocation;
-use plc_util::convention::qualified_name;
+use plc_util::convention::{namespaced_name, qualified_name};
 
 use crate::{
     builtins::{self, BuiltIn},
@@ -72,6 +74,12 @@ pub struct VariableIndexEntry {
     is_constant: bool,
     // true if this variable is in a 'VAR_EXTERNAL' block
     is_var_external: bool,
+    /// true if this variable is declared in a `RETAIN` block
+    is_retain: bool,
+    /// true if this variable is declared in a `PERSISTENT` block
+    is_persistent: bool,
+    /// the access modifier of the variable's block, `None` if it is not restricted
+    access: Option<AccessModifier>,
     /// the variable's datatype
     pub data_type_name: String,
     /// the index of the member-variable in it's container (e.g. struct). defautls to 0 (Single variables)
@@ -84,6 +92,8 @@ pub struct VariableIndexEntry {
     pub source_location: SourceLocation,
     /// Variadic information placeholder for the variable, if any
     varargs: Option<VarArgs>,
+    /// the attribute pragmas declared in front of the variable
+    attributes: Vec<Attribute>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
@@ -152,12 +162,16 @@ impl VariableIndexEntry {
             argument_type,
             is_constant: false,
             is_var_external: false,
+            is_retain: false,
+            is_persistent: false,
+            access: None,
             data_type_name: data_type_name.to_string(),
             location_in_parent,
             linkage: LinkageType::Internal,
             binding: None,
             source_location,
             varargs: None,
+            attributes: vec![],
         }
     }
 
@@ -174,12 +188,16 @@ impl VariableIndexEntry {
             argument_type: ArgumentType::ByVal(VariableType::Global),
             is_constant: false,
             is_var_external: false,
+            is_retain: false,
+            is_persistent: false,
+            access: None,
             data_type_name: data_type_name.to_string(),
             location_in_parent: 0,
             linkage: LinkageType::Internal,
             binding: None,
             source_location,
             varargs: None,
+            attributes: vec![],
         }
     }
 
@@ -213,6 +231,22 @@ impl VariableIndexEntry {
         self
     }
 
+    pub fn set_attributes(mut self, attributes: Vec<Attribute>) -> Self {
+        self.attributes = attributes;
+        self
+    }
+
+    pub fn set_retention(mut self, retain: bool, persistent: bool) -> Self {
+        self.is_retain = retain;
+        self.is_persistent = persistent;
+        self
+    }
+
+    pub fn set_access(mut self, access: Option<AccessModifier>) -> Self {
+        self.access = access;
+        self
+    }
+
     /// Creates a new VariableIndexEntry from the current entry with a new container and type
     /// This is used to create new entries from previously generic entries
     pub fn into_typed(&self, container: &str, new_type: &str) -> Self {
@@ -276,6 +310,29 @@ impl VariableIndexEntry {
         self.is_var_external
     }
 
+    pub fn is_retain(&self) -> bool {
+        self.is_retain
+    }
+
+    pub fn is_persistent(&self) -> bool {
+        self.is_persistent
+    }
+
+    pub fn get_access(&self) -> Option<&AccessModifier> {
+        self.access.as_ref()
+    }
+
+    /// Returns whether the variable's value is kept in the retain image, i.e. it is declared `RETAIN` or `PERSISTENT`
+    pub fn is_retained(&self) -> bool {
+        self.is_retain || self.is_persistent
+    }
+
+    /// Returns whether the variable is a member of its container's generated struct type, which
+    /// excludes temporary, return and `VAR_EXTERNAL` variables
+    pub fn is_stored_in_struct(&self) -> bool {
+        !(self.is_temp() || self.is_return() || self.is_var_external())
+    }
+
     pub fn get_declaration_type(&self) -> ArgumentType {
         self.argument_type
     }
@@ -324,6 +381,16 @@ impl VariableIndexEntry {
         self.varargs.as_ref()
     }
 
+    /// returns the attribute pragmas declared in front of this variable
+    pub fn get_attributes(&self) -> &[Attribute] {
+        &self.attributes
+    }
+
+    /// returns the attribute with the given name if it was declared for this variable
+    pub fn get_attribute(&self, name: &str) -> Option<&Attribute> {
+        Attribute::find(&self.attributes, name)
+    }
+
     fn has_parent(&self, context: &str) -> bool {
         let name = qualified_name(context, &self.name);
         self.qualified_name.eq_ignore_ascii_case(&name)
@@ -410,6 +477,8 @@ pub struct ImplementationIndexEntry {
     pub(crate) implementation_type: ImplementationType,
     pub(crate) generic: bool,
     pub(crate) location: SourceLocation,
+    /// The declared access modifier of a method, `None` if it is not restricted
+    pub(crate) access: Option<AccessModifier>,
 }
 
 impl ImplementationIndexEntry {
@@ -434,6 +503,14 @@ impl ImplementationIndexEntry {
         &self.location
     }
 
+    pub fn get_access(&self) -> Option<&AccessModifier> {
+        self.access.as_ref()
+    }
+
+    pub fn set_access(&mut self, access: Option<AccessModifier>) {
+        self.access = access;
+    }
+
     pub fn is_in_unit(&self, unit: impl AsRef<str>) -> bool {
         self.get_location().is_in_unit(unit)
     }
@@ -517,6 +594,113 @@ impl From<&Interface> for InterfaceIndexEntry {
     }
 }
 
+/// A property declared in a POU or interface. Its accessors are lowered into the methods
+/// `__get_<name>` and `__set_<name>` of the declaring container, see [`PropertyKind::get_method_name`]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PropertyIndexEntry {
+    /// The property name as declared
+    pub name: String,
+
+    /// The property name qualified by its container, e.g. `fb.foo`
+    pub qualified_name: String,
+
+    /// The name of the POU or interface declaring this property
+    pub parent_name: String,
+
+    /// The name of the property's datatype
+    pub datatype_name: String,
+
+    pub location: SourceLocation,
+}
+
+impl PropertyIndexEntry {
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_type_name(&self) -> &str {
+        &self.datatype_name
+    }
+
+    /// Returns the method implementing the given accessor, if the property defines it
+    pub fn find_accessor<'idx>(&self, index: &'idx Index, kind: PropertyKind) -> Option<&'idx PouIndexEntry> {
+        index.find_method(&self.parent_name, &kind.get_method_name(&self.name))
+    }
+}
+
+/// A task declared in a resource of a `CONFIGURATION`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TaskIndexEntry {
+    /// The task name as declared
+    pub name: String,
+
+    /// The task name qualified by its resource, e.g. `cpu.fast`
+    pub qualified_name: String,
+
+    /// The name of the configuration declaring the task
+    pub configuration: String,
+
+    /// The cycle time of a periodic task
+    pub interval: Option<ConstId>,
+
+    /// The name of the variable triggering an event-driven task
+    pub single: Option<String>,
+
+    pub priority: Option<ConstId>,
+
+    pub location: SourceLocation,
+}
+
+impl TaskIndexEntry {
+    /// Returns the cycle time in nanoseconds, if the task declares a constant `INTERVAL`
+    pub fn get_interval_value(&self, index: &Index) -> Option<i64> {
+        let interval = self.interval.as_ref()?;
+        match index.get_const_expressions().get_resolved_constant_statement(interval)?.get_stmt() {
+            AstStatement::Literal(AstLiteral::Time(time)) => Some(time.value()),
+            _ => None,
+        }
+    }
+
+    /// Returns the priority, if the task declares a constant `PRIORITY`
+    pub fn get_priority_value(&self, index: &Index) -> Option<u16> {
+        let priority = self.priority.as_ref()?;
+        match index.get_const_expressions().get_resolved_constant_statement(priority)?.get_stmt() {
+            AstStatement::Literal(AstLiteral::Integer(value)) => u16::try_from(*value).ok(),
+            _ => None,
+        }
+    }
+}
+
+/// A program instance assigned to a task by a `CONFIGURATION`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ProgramInstanceIndexEntry {
+    /// The instance name as declared
+    pub name: String,
+
+    /// The instance name qualified by its resource, e.g. `cpu.main`
+    pub qualified_name: String,
+
+    /// The name of the configuration declaring the instance
+    pub configuration: String,
+
+    /// The name of the instantiated program
+    pub program: String,
+
+    /// The qualified name of the task executing the program, e.g. `cpu.fast`
+    pub task: Option<String>,
+
+    pub location: SourceLocation,
+
+    pub program_location: SourceLocation,
+}
+
+impl ProgramInstanceIndexEntry {
+    /// Returns the task executing this program instance, if it is declared
+    pub fn find_task<'idx>(&self, index: &'idx Index) -> Option<&'idx TaskIndexEntry> {
+        self.task.as_deref().and_then(|it| index.find_task(it))
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
 pub enum PouIndexEntry {
     Program {
@@ -525,13 +709,17 @@ pub enum PouIndexEntry {
         instance_variable: Box<VariableIndexEntry>,
         linkage: LinkageType,
         location: SourceLocation,
+        attributes: Vec<Attribute>,
     },
     FunctionBlock {
         name: String,
         instance_struct_name: String,
         linkage: LinkageType,
         location: SourceLocation,
+        attributes: Vec<Attribute>,
+        poly_mode: PolymorphismMode,
         super_class: Option<String>,
+        interfaces: Vec<String>,
     },
     Function {
         name: String,
@@ -540,6 +728,7 @@ pub enum PouIndexEntry {
         linkage: LinkageType,
         is_variadic: bool,
         location: SourceLocation,
+        attributes: Vec<Attribute>,
         is_generated: bool, // true if this entry was added automatically (e.g. by generics)
         is_const: bool,
     },
@@ -548,7 +737,10 @@ pub enum PouIndexEntry {
         instance_struct_name: String,
         linkage: LinkageType,
         location: SourceLocation,
+        attributes: Vec<Attribute>,
+        poly_mode: PolymorphismMode,
         super_class: Option<String>,
+        interfaces: Vec<String>,
     },
     Method {
         name: String,
@@ -557,6 +749,8 @@ pub enum PouIndexEntry {
         instance_struct_name: String,
         linkage: LinkageType,
         location: SourceLocation,
+        attributes: Vec<Attribute>,
+        poly_mode: PolymorphismMode,
     },
     Action {
         name: String,
@@ -564,6 +758,7 @@ pub enum PouIndexEntry {
         instance_struct_name: String,
         linkage: LinkageType,
         location: SourceLocation,
+        attributes: Vec<Attribute>,
     },
 }
 
@@ -590,6 +785,7 @@ impl PouIndexEntry {
             instance_variable: Box::new(instance_variable),
             linkage,
             location,
+            attributes: vec![],
         }
     }
 
@@ -597,18 +793,23 @@ impl PouIndexEntry {
     /// # Arguments
     /// - `name` the name of the FunctionBlock
     /// - `linkage` the linkage type of the pou
+    /// - `interfaces` the names of the interfaces implemented by the FunctionBlock
     pub fn create_function_block_entry(
         pou_name: &str,
         linkage: LinkageType,
         location: SourceLocation,
         super_class: Option<&str>,
+        interfaces: Vec<String>,
     ) -> PouIndexEntry {
         PouIndexEntry::FunctionBlock {
             name: pou_name.into(),
             instance_struct_name: pou_name.into(),
             linkage,
             location,
+            attributes: vec![],
+            poly_mode: PolymorphismMode::None,
             super_class: super_class.map(|s| s.to_owned()),
+            interfaces,
         }
     }
 
@@ -629,6 +830,7 @@ impl PouIndexEntry {
             linkage,
             is_variadic,
             location,
+            attributes: vec![],
             is_generated: false,
             is_const,
         }
@@ -652,6 +854,7 @@ impl PouIndexEntry {
             linkage,
             is_variadic,
             location,
+            attributes: vec![],
             is_generated: true,
             is_const,
         }
@@ -673,6 +876,7 @@ impl PouIndexEntry {
             instance_struct_name: pou_name.into(),
             linkage,
             location,
+            attributes: vec![],
         }
     }
 
@@ -684,13 +888,17 @@ impl PouIndexEntry {
         linkage: LinkageType,
         location: SourceLocation,
         super_class: Option<String>,
+        interfaces: Vec<String>,
     ) -> PouIndexEntry {
         PouIndexEntry::Class {
             name: pou_name.into(),
             instance_struct_name: pou_name.into(),
             linkage,
             location,
+            attributes: vec![],
+            poly_mode: PolymorphismMode::None,
             super_class,
+            interfaces,
         }
     }
 
@@ -713,6 +921,8 @@ impl PouIndexEntry {
             return_type: return_type.into(),
             linkage,
             location,
+            attributes: vec![],
+            poly_mode: PolymorphismMode::None,
         }
     }
 
@@ -728,6 +938,19 @@ impl PouIndexEntry {
         }
     }
 
+    /// returns the name of this pou without the qualifier of its parent, e.g. `foo` for the method `fb.foo`
+    pub fn get_flat_name(&self) -> &str {
+        self.get_name().rsplit('.').next().unwrap_or_default()
+    }
+
+    /// returns the interface declaring this pou if it is an interface method
+    pub fn get_interface<'idx>(&self, index: &'idx Index) -> Option<&'idx InterfaceIndexEntry> {
+        match self {
+            PouIndexEntry::Method { parent_pou_name, .. } => index.find_interface(parent_pou_name),
+            _ => None,
+        }
+    }
+
     /// returns the super class of this pou if supported
     pub fn get_super_class(&self) -> Option<&str> {
         match self {
@@ -738,6 +961,16 @@ impl PouIndexEntry {
         }
     }
 
+    /// returns the names of the interfaces directly implemented by this pou
+    pub fn get_interfaces(&self) -> &[String] {
+        match self {
+            PouIndexEntry::Class { interfaces, .. } | PouIndexEntry::FunctionBlock { interfaces, .. } => {
+                interfaces
+            }
+            _ => &[],
+        }
+    }
+
     pub fn get_parent_pou_name(&self) -> &str {
         match self {
             PouIndexEntry::Method { parent_pou_name, .. } | PouIndexEntry::Action { parent_pou_name, .. } => {
@@ -808,6 +1041,11 @@ impl PouIndexEntry {
         }
     }
 
+    /// returns true if this pou is linked from outside of the project, e.g. declared in a library's header
+    pub fn is_external(&self) -> bool {
+        matches!(self.get_linkage(), LinkageType::External)
+    }
+
     /// returns true if this pou is a function with generic parameters, otherwise false
     pub fn is_generic(&self) -> bool {
         if let PouIndexEntry::Function { generics, .. } = self {
@@ -866,6 +1104,15 @@ impl PouIndexEntry {
         matches!(self, PouIndexEntry::Method { .. })
     }
 
+    /// Returns true if this is one of the lifecycle methods `FB_INIT`, `FB_REINIT` or `FB_EXIT`. They are called
+    /// implicitly by the generated initialization functions and are never dispatched virtually.
+    pub fn is_lifecycle_method(&self) -> bool {
+        self.is_method()
+            && [FB_INIT_METHOD_NAME, FB_REINIT_METHOD_NAME, FB_EXIT_METHOD_NAME]
+                .iter()
+                .any(|it| it.eq_ignore_ascii_case(self.get_flat_name()))
+    }
+
     pub fn is_stateful(&self) -> bool {
         matches!(
             self,
@@ -892,6 +1139,70 @@ impl PouIndexEntry {
         }
     }
 
+    /// sets the attribute pragmas declared in front of this pou
+    pub fn with_attributes(mut self, attributes: Vec<Attribute>) -> Self {
+        match &mut self {
+            PouIndexEntry::Program { attributes: it, .. }
+            | PouIndexEntry::FunctionBlock { attributes: it, .. }
+            | PouIndexEntry::Function { attributes: it, .. }
+            | PouIndexEntry::Method { attributes: it, .. }
+            | PouIndexEntry::Action { attributes: it, .. }
+            | PouIndexEntry::Class { attributes: it, .. } => *it = attributes,
+        }
+        self
+    }
+
+    /// sets the polymorphism mode (`ABSTRACT` or `FINAL`) of a function block, class or method
+    pub fn with_polymorphism_mode(mut self, mode: Option<PolymorphismMode>) -> Self {
+        if let (
+            PouIndexEntry::FunctionBlock { poly_mode, .. }
+            | PouIndexEntry::Class { poly_mode, .. }
+            | PouIndexEntry::Method { poly_mode, .. },
+            Some(mode),
+        ) = (&mut self, mode)
+        {
+            *poly_mode = mode;
+        }
+        self
+    }
+
+    /// returns true if this function block, class or method is declared `ABSTRACT`
+    pub fn is_abstract(&self) -> bool {
+        matches!(
+            self,
+            PouIndexEntry::FunctionBlock { poly_mode: PolymorphismMode::Abstract, .. }
+                | PouIndexEntry::Class { poly_mode: PolymorphismMode::Abstract, .. }
+                | PouIndexEntry::Method { poly_mode: PolymorphismMode::Abstract, .. }
+        )
+    }
+
+    /// returns true if this function block, class or method is declared `FINAL`
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            PouIndexEntry::FunctionBlock { poly_mode: PolymorphismMode::Final, .. }
+                | PouIndexEntry::Class { poly_mode: PolymorphismMode::Final, .. }
+                | PouIndexEntry::Method { poly_mode: PolymorphismMode::Final, .. }
+        )
+    }
+
+    /// returns the attribute pragmas declared in front of this pou
+    pub fn get_attributes(&self) -> &[Attribute] {
+        match self {
+            PouIndexEntry::Program { attributes, .. }
+            | PouIndexEntry::FunctionBlock { attributes, .. }
+            | PouIndexEntry::Function { attributes, .. }
+            | PouIndexEntry::Method { attributes, .. }
+            | PouIndexEntry::Action { attributes, .. }
+            | PouIndexEntry::Class { attributes, .. } => attributes,
+        }
+    }
+
+    /// returns the attribute with the given name if it was declared for this pou
+    pub fn get_attribute(&self, name: &str) -> Option<&Attribute> {
+        Attribute::find(self.get_attributes(), name)
+    }
+
     fn is_auto_generated_function(&self) -> bool {
         matches!(self, PouIndexEntry::Function { is_generated: true, .. })
     }
@@ -976,9 +1287,15 @@ pub struct Index {
     /// All pous,
     pous: SymbolMap<String, PouIndexEntry>,
 
+    /// The qualified names of the methods declared by each POU, registered by the POU's name
+    methods: SymbolMap<String, String>,
+
     /// All interface definitions
     interfaces: SymbolMap<String, InterfaceIndexEntry>,
 
+    /// All properties, registered by their qualified name
+    properties: SymbolMap<String, PropertyIndexEntry>,
+
     /// All implementations
     /// We keep an IndexMap for implementations since duplication issues regarding implementations
     /// is handled by the `pous` SymbolMap
@@ -996,6 +1313,15 @@ pub struct Index {
     labels: FxIndexMap<String, SymbolMap<String, Label>>,
 
     config_variables: Vec<ConfigVariable>,
+
+    /// All access paths declared in `VAR_ACCESS` blocks
+    access_variables: Vec<AccessVariable>,
+
+    /// All tasks declared in configurations, registered by their qualified name
+    tasks: SymbolMap<String, TaskIndexEntry>,
+
+    /// All program instances declared in configurations, registered by their qualified name
+    configured_programs: SymbolMap<String, ProgramInstanceIndexEntry>,
 }
 
 impl Index {
@@ -1097,12 +1423,15 @@ impl Index {
         // interfaces
         self.interfaces.extend(other.interfaces);
 
+        // properties
+        self.properties.extend(other.properties);
+
         //pous
         for (name, elements) in other.pous.drain(..) {
             for ele in elements {
                 // skip automatically generated pou's if they are already in the target index
                 if !ele.is_auto_generated_function() || !self.pous.contains_key(&name) {
-                    self.pous.insert(name.clone(), ele);
+                    self.insert_pou(name.clone(), ele);
                 }
             }
         }
@@ -1111,6 +1440,23 @@ impl Index {
         self.labels.extend(other.labels);
 
         self.config_variables.extend(other.config_variables);
+        self.access_variables.extend(other.access_variables);
+
+        //tasks
+        for (name, elements) in other.tasks.drain(..) {
+            let elements = elements
+                .into_iter()
+                .map(|mut task| {
+                    task.interval =
+                        self.maybe_import_const_expr(&mut other.constant_expressions, &task.interval);
+                    task.priority =
+                        self.maybe_import_const_expr(&mut other.constant_expressions, &task.priority);
+                    task
+                })
+                .collect::<Vec<_>>();
+            self.tasks.insert_many(name, elements);
+        }
+        self.configured_programs.extend(other.configured_programs);
 
         //Constant expressions are intentionally not imported
         // self.constant_expressions.import(other.constant_expressions)
@@ -1242,20 +1588,46 @@ impl Index {
     }
 
     /// Searches for variable name in the given container, if not found, attempts to search for it in super classes
+    /// or base structs
     pub fn find_member(&self, container_name: &str, variable_name: &str) -> Option<&VariableIndexEntry> {
-        // Find pou in index
-        self.find_local_member(container_name, variable_name)
-            .or_else(|| {
-                if let Some(class) = self.find_pou(container_name).and_then(|it| it.get_super_class()) {
-                    self.find_member(class, variable_name)
-                } else {
-                    None
-                }
-            })
-            .filter(|it| {
+        let mut visited = FxHashSet::default();
+        let mut current = Some(container_name);
+        // guard against cyclic hierarchies, they are reported by the validation
+        while let Some(container) = current.filter(|it| visited.insert(it.to_lowercase())) {
+            if let Some(member) = self.find_local_member(container, variable_name) {
                 // VAR_EXTERNAL variables are not local members
-                !it.is_var_external()
-            })
+                return Some(member).filter(|it| !it.is_var_external());
+            }
+            current = self.find_super_type(container);
+        }
+
+        None
+    }
+
+    /// Returns the name of the POU or STRUCT the given POU or STRUCT `EXTENDS`, if any
+    pub fn find_super_type(&self, name: &str) -> Option<&str> {
+        if let Some(pou) = self.find_pou(name) {
+            return pou.get_super_class();
+        }
+
+        match self.type_index.find_type(name).map(DataType::get_type_information) {
+            Some(DataTypeInformation::Struct {
+                source: StructSource::OriginalDeclaration, members, ..
+            }) => members
+                .first()
+                .filter(|it| it.get_name() == BASE_MEMBER_NAME)
+                .map(VariableIndexEntry::get_type_name),
+            _ => None,
+        }
+    }
+
+    /// Searches for a `VAR_EXTERNAL` declaration of the given name in the given POU or, for methods and actions,
+    /// in its container
+    pub fn find_var_external(&self, pou_name: &str, variable_name: &str) -> Option<&VariableIndexEntry> {
+        let find = |container: &str| {
+            self.find_local_member(container, variable_name).filter(|it| it.is_var_external())
+        };
+        find(pou_name).or_else(|| self.find_pou(pou_name).and_then(|pou| find(pou.get_container())))
     }
 
     /// Searches for method names in the given container, if not found, attempts to search for it in super class
@@ -1269,11 +1641,157 @@ impl Index {
         }
     }
 
+    /// Searches for a property in the given container, if not found, attempts to search for it in super classes
+    pub fn find_property(&self, container_name: &str, property_name: &str) -> Option<&PropertyIndexEntry> {
+        let mut visited = FxHashSet::default();
+        let mut current = Some(container_name);
+        // guard against cyclic hierarchies, they are reported by the validation
+        while let Some(container) = current.filter(|it| visited.insert(it.to_lowercase())) {
+            if let Some(property) =
+                self.properties.get(&qualified_name(container, property_name).to_lowercase())
+            {
+                return Some(property);
+            }
+            current = self.find_super_type(container);
+        }
+
+        None
+    }
+
+    /// Returns the property with the given qualified name (e.g. `fb.foo`) or None if it does not exist
+    pub fn find_qualified_property(&self, qualified_name: &str) -> Option<&PropertyIndexEntry> {
+        self.properties.get(&qualified_name.to_lowercase())
+    }
+
     /// Returns an interface with the given name or None if it does not exist
     pub fn find_interface(&self, name: &str) -> Option<&InterfaceIndexEntry> {
         self.interfaces.get(name)
     }
 
+    /// Returns all interfaces implemented by the given POU, including the ones implemented by its super classes
+    pub fn get_implemented_interfaces(&self, pou_name: &str) -> Vec<&InterfaceIndexEntry> {
+        let mut hierarchy = vec![];
+        let mut visited = FxHashSet::default();
+        let mut current = self.find_pou(pou_name);
+        // guard against cyclic inheritance, which is reported elsewhere
+        while let Some(pou) = current.filter(|it| visited.insert(it.get_name().to_lowercase())) {
+            hierarchy.push(pou);
+            current = pou.get_super_class().and_then(|it| self.find_pou(it));
+        }
+
+        // the interfaces of the super classes are listed first
+        let mut interfaces = vec![];
+        for pou in hierarchy.into_iter().rev() {
+            for interface in pou.get_interfaces().iter().filter_map(|it| self.find_interface(it)) {
+                if !interfaces.contains(&interface) {
+                    interfaces.push(interface);
+                }
+            }
+        }
+
+        interfaces
+    }
+
+    /// Returns the methods called through the dispatch table of the given POU in slot order, or an empty
+    /// list if the POU is not part of an `EXTENDS` hierarchy. Every method is listed with its first
+    /// declaration within the hierarchy, methods overriding it share its slot.
+    pub fn get_virtual_methods(&self, pou_name: &str) -> Vec<&PouIndexEntry> {
+        let is_instance_pou = |it: &&PouIndexEntry| it.is_function_block() || it.is_class();
+        let mut hierarchy = vec![];
+        let mut visited = FxHashSet::default();
+        let mut current = self.find_pou(pou_name).filter(is_instance_pou);
+        // guard against cyclic inheritance, which is reported elsewhere
+        while let Some(pou) = current.filter(|it| visited.insert(it.get_name().to_lowercase())) {
+            hierarchy.push(pou);
+            current = pou.get_super_class().and_then(|it| self.find_pou(it)).filter(is_instance_pou);
+        }
+
+        // only the root of a hierarchy holds the `__vtable`
+        let Some(root) = hierarchy.last() else { return vec![] };
+        if root.get_super_class().is_some()
+            || self.find_local_member(root.get_name(), VTABLE_MEMBER_NAME).is_none()
+        {
+            return vec![];
+        }
+
+        let mut methods: Vec<&PouIndexEntry> = vec![];
+        for pou in hierarchy.into_iter().rev() {
+            for method in self.get_declared_methods(pou.get_name()).filter(|it| !it.is_lifecycle_method()) {
+                if !methods.iter().any(|it| it.get_flat_name().eq_ignore_ascii_case(method.get_flat_name())) {
+                    methods.push(method);
+                }
+            }
+        }
+
+        methods
+    }
+
+    /// Returns the methods declared by the given POU, without the ones inherited from its super classes
+    pub fn get_declared_methods(&self, pou_name: &str) -> impl Iterator<Item = &PouIndexEntry> {
+        self.methods
+            .get_all(pou_name.to_lowercase().as_str())
+            .into_iter()
+            .flatten()
+            .filter_map(|it| self.find_pou(it))
+            .filter(|it| it.is_method())
+    }
+
+    /// Returns the methods available on the given POU, i.e. its own methods and the ones inherited from its super
+    /// classes. Methods overridden within the hierarchy are listed with their most derived implementation.
+    pub fn get_inherited_methods(&self, pou_name: &str) -> Vec<&PouIndexEntry> {
+        let mut methods: Vec<&PouIndexEntry> = vec![];
+        let mut visited = FxHashSet::default();
+        let mut current = self.find_pou(pou_name);
+        // guard against cyclic inheritance, which is reported elsewhere
+        while let Some(pou) = current.filter(|it| visited.insert(it.get_name().to_lowercase())) {
+            for method in self.get_declared_methods(pou.get_name()) {
+                if !methods.iter().any(|it| it.get_flat_name().eq_ignore_ascii_case(method.get_flat_name())) {
+                    methods.push(method);
+                }
+            }
+            current = pou.get_super_class().and_then(|it| self.find_pou(it));
+        }
+
+        methods
+    }
+
+    /// Returns true if the POU or STRUCT `derived` extends `base`, either directly or through its super types
+    pub fn is_derived_from(&self, derived: &str, base: &str) -> bool {
+        let mut visited = FxHashSet::default();
+        let mut current = self.find_super_type(derived);
+        while let Some(super_type) = current.filter(|it| visited.insert(it.to_lowercase())) {
+            if super_type.eq_ignore_ascii_case(base) {
+                return true;
+            }
+            current = self.find_super_type(super_type);
+        }
+
+        false
+    }
+
+    /// Returns true if a POU, type or global variable is declared in the given namespace or one nested in it
+    pub fn is_namespace(&self, namespace: &str) -> bool {
+        let prefix = namespaced_name(namespace.to_lowercase().as_str(), "");
+        self.pous
+            .keys()
+            .chain(self.interfaces.keys())
+            .chain(self.type_index.types.keys())
+            .chain(self.global_variables.keys())
+            .any(|name| name.starts_with(&prefix))
+    }
+
+    /// Returns the FUNCTION_BLOCK or CLASS whose instance `THIS` refers to within the given POU, i.e. the POU
+    /// itself or the POU declaring the given method or action
+    pub fn find_instance_pou(&self, pou_name: &str) -> Option<&PouIndexEntry> {
+        self.find_pou(pou_name)
+            .and_then(|it| match it {
+                PouIndexEntry::Method { parent_pou_name, .. }
+                | PouIndexEntry::Action { parent_pou_name, .. } => self.find_pou(parent_pou_name),
+                _ => Some(it),
+            })
+            .filter(|it| it.is_function_block() || it.is_class())
+    }
+
     /// return the `VariableIndexEntry` associated with the given fully qualified name using `.` as
     /// a delimiter. (e.g. "PLC_PRG.x", or "MyClass.MyMethod.x")
     pub fn find_fully_qualified_variable(&self, fully_qualified_name: &str) -> Option<&VariableIndexEntry> {
@@ -1368,6 +1886,16 @@ impl Index {
             .collect::<Vec<_>>()
     }
 
+    /// returns the location in the parent of the parameter passed at the given position of a call. The
+    /// parameters of a FUNCTION_BLOCK or CLASS may be preceded by the hidden `__vtable` or `__BASE` member,
+    /// arguments beyond the declared parameters (e.g. variadic ones) keep their position
+    pub fn get_positional_parameter_location(&self, pou_name: &str, position: usize) -> u32 {
+        self.get_declared_parameters(pou_name)
+            .get(position)
+            .map(|it| it.get_location_in_parent())
+            .unwrap_or(position as u32)
+    }
+
     pub fn has_variadic_parameter(&self, pou_name: &str) -> bool {
         self.get_pou_members(pou_name).iter().any(|member| member.is_parameter() && member.is_variadic())
     }
@@ -1541,6 +2069,11 @@ impl Index {
         &self.pous
     }
 
+    /// Returns the map of properties, registered by their qualified name
+    pub fn get_properties(&self) -> &SymbolMap<String, PropertyIndexEntry> {
+        &self.properties
+    }
+
     /// Returns a reference of the [`Index::interfaces`] field
     pub fn get_interfaces(&self) -> &SymbolMap<String, InterfaceIndexEntry> {
         &self.interfaces
@@ -1566,18 +2099,18 @@ impl Index {
         impl_type: ImplementationType,
         generic: bool,
         location: SourceLocation,
-    ) {
-        self.implementations.insert(
-            call_name.to_lowercase(),
-            ImplementationIndexEntry {
-                call_name: call_name.into(),
-                type_name: type_name.into(),
-                associated_class: associated_class_name.map(|str| str.into()),
-                implementation_type: impl_type,
-                generic,
-                location,
-            },
-        );
+    ) -> &mut ImplementationIndexEntry {
+        let entry = ImplementationIndexEntry {
+            call_name: call_name.into(),
+            type_name: type_name.into(),
+            associated_class: associated_class_name.map(|str| str.into()),
+            implementation_type: impl_type,
+            generic,
+            location,
+            access: None,
+        };
+        let (idx, _) = self.implementations.insert_full(call_name.to_lowercase(), entry);
+        &mut self.implementations[idx]
     }
 
     pub fn find_pou(&self, pou_name: &str) -> Option<&PouIndexEntry> {
@@ -1588,17 +2121,35 @@ impl Index {
         self.find_implementation_by_name(pou_name).map(|it| it.is_init()).unwrap_or_default()
     }
 
-    pub fn register_program(&mut self, name: &str, location: SourceLocation, linkage: LinkageType) {
+    pub fn register_program(
+        &mut self,
+        name: &str,
+        location: SourceLocation,
+        linkage: LinkageType,
+        attributes: Vec<Attribute>,
+    ) {
         let instance_variable =
             VariableIndexEntry::create_global(&format!("{}_instance", &name), name, name, location.clone()) // TODO: Naming convention (see plc_util/src/convention.rs)
                 .set_linkage(linkage);
         // self.register_global_variable(name, instance_variable.clone());
-        let entry = PouIndexEntry::create_program_entry(name, instance_variable, linkage, location);
-        self.pous.insert(entry.get_name().to_lowercase(), entry);
+        let entry = PouIndexEntry::create_program_entry(name, instance_variable, linkage, location)
+            .with_attributes(attributes);
+        self.insert_pou(entry.get_name().to_lowercase(), entry);
     }
 
     pub fn register_pou(&mut self, entry: PouIndexEntry) {
-        self.pous.insert(entry.get_name().to_lowercase(), entry);
+        self.insert_pou(entry.get_name().to_lowercase(), entry);
+    }
+
+    fn insert_pou(&mut self, name: String, entry: PouIndexEntry) {
+        if let PouIndexEntry::Method { parent_pou_name, .. } = &entry {
+            self.methods.insert(parent_pou_name.to_lowercase(), name.clone());
+        }
+        self.pous.insert(name, entry);
+    }
+
+    pub fn register_property(&mut self, entry: PropertyIndexEntry) {
+        self.properties.insert(entry.qualified_name.to_lowercase(), entry);
     }
 
     pub fn find_implementation_by_name(&self, call_name: &str) -> Option<&ImplementationIndexEntry> {
@@ -1817,6 +2368,31 @@ impl Index {
     pub fn get_config_variables(&self) -> &Vec<ConfigVariable> {
         &self.config_variables
     }
+
+    pub fn get_access_variables(&self) -> &Vec<AccessVariable> {
+        &self.access_variables
+    }
+
+    pub fn register_task(&mut self, entry: TaskIndexEntry) {
+        self.tasks.insert(entry.qualified_name.to_lowercase(), entry);
+    }
+
+    pub fn register_configured_program(&mut self, entry: ProgramInstanceIndexEntry) {
+        self.configured_programs.insert(entry.qualified_name.to_lowercase(), entry);
+    }
+
+    /// Returns the task with the given qualified name, e.g. `cpu.fast`
+    pub fn find_task(&self, qualified_name: &str) -> Option<&TaskIndexEntry> {
+        self.tasks.get(&qualified_name.to_lowercase())
+    }
+
+    pub fn get_tasks(&self) -> &SymbolMap<String, TaskIndexEntry> {
+        &self.tasks
+    }
+
+    pub fn get_configured_programs(&self) -> &SymbolMap<String, ProgramInstanceIndexEntry> {
+        &self.configured_programs
+    }
 }
 
 /// Returns a default initialization name for a variable or type
@@ -1824,6 +2400,39 @@ pub fn get_initializer_name(name: &str) -> String {
     format!("__{name}__init")
 }
 
+/// The member holding the instance of the base class within an instance of a derived class
+pub const BASE_MEMBER_NAME: &str = "__BASE";
+
+/// The method called after an instance of a function block or class was initialized
+pub const FB_INIT_METHOD_NAME: &str = "FB_INIT";
+
+/// The method called when the instances of a project are reinitialized
+pub const FB_REINIT_METHOD_NAME: &str = "FB_REINIT";
+
+/// The method called before an instance of a function block or class is torn down
+pub const FB_EXIT_METHOD_NAME: &str = "FB_EXIT";
+
+/// The member pointing to the dispatch table of an instance's class, declared by the root of a class hierarchy
+pub const VTABLE_MEMBER_NAME: &str = "__vtable";
+
+/// Returns the name of the dispatch table of the given POU for the given interface
+pub fn get_vtable_name(pou_name: &str, interface_name: &str) -> String {
+    format!("__vtable_{pou_name}_{interface_name}")
+}
+
+/// Returns the name of the dispatch table of the given POU for the methods of its class hierarchy
+pub fn get_class_vtable_name(pou_name: &str) -> String {
+    format!("__vtable_{pou_name}")
+}
+
 pub fn get_init_fn_name(name: &str) -> String {
     format!("__init_{name}").to_lowercase()
 }
+
+pub fn get_reinit_fn_name(name: &str) -> String {
+    format!("__reinit_{name}").to_lowercase()
+}
+
+pub fn get_deinit_fn_name(name: &str) -> String {
+    format!("__deinit_{name}").to_lowercase()
+}
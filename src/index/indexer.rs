@@ -1,13 +1,19 @@
 use global_var_indexer::VarGlobalIndexer;
 use implementation_indexer::ImplementationIndexer;
 use plc_ast::{
-    ast::{CompilationUnit, Implementation, Interface, VariableBlockType},
+    ast::{
+        CompilationUnit, Configuration, DataType, Implementation, Interface, PropertyBlock,
+        UserTypeDeclaration, VariableBlockType,
+    },
     visitor::{AstVisitor, Walker},
 };
 use pou_indexer::PouIndexer;
 use user_type_indexer::UserTypeIndexer;
 
-use super::{Index, InterfaceIndexEntry};
+use plc_util::convention::qualified_name;
+
+use super::{Index, InterfaceIndexEntry, ProgramInstanceIndexEntry, PropertyIndexEntry, TaskIndexEntry};
+use crate::typesystem::{TIME_TYPE, UINT_TYPE, VOID_TYPE};
 
 mod global_var_indexer;
 mod implementation_indexer;
@@ -28,13 +34,28 @@ pub struct SymbolIndexer {
     pub index: Index,
 }
 
+impl SymbolIndexer {
+    /// Registers the properties declared in the given container, their accessors are indexed as methods
+    fn index_properties(&mut self, container: &str, properties: &[PropertyBlock]) {
+        for property in properties {
+            self.index.register_property(PropertyIndexEntry {
+                name: property.name.clone(),
+                qualified_name: qualified_name(container, &property.name),
+                parent_name: container.to_string(),
+                datatype_name: property.datatype.get_name().unwrap_or(VOID_TYPE).to_string(),
+                location: property.name_location.clone(),
+            });
+        }
+    }
+}
+
 /// The SymbolIndexer is responsible for registering all delcared types and symbols in the index.
 impl AstVisitor for SymbolIndexer {
     /// Visits a VAR_GLOBAL VariableBlock and registers all variables as globals in the index
     fn visit_variable_block(&mut self, block: &plc_ast::ast::VariableBlock) {
         if block.variable_block_type == VariableBlockType::Global {
             // let the global var indexer handle the global variables
-            let mut indexer = VarGlobalIndexer::new(block.constant, block.linkage, &mut self.index);
+            let mut indexer = VarGlobalIndexer::new(block, &mut self.index);
             for var in &block.variables {
                 indexer.visit_variable(var);
             }
@@ -51,6 +72,7 @@ impl AstVisitor for SymbolIndexer {
     /// Also registers the pou's struct type in the index
     fn visit_pou(&mut self, pou: &plc_ast::ast::Pou) {
         PouIndexer::new(&mut self.index).visit_pou(pou);
+        self.index_properties(&pou.name, &pou.properties);
     }
 
     /// Visits an implementation and registers the implementation in the index
@@ -62,10 +84,68 @@ impl AstVisitor for SymbolIndexer {
         self.index.config_variables.push(config_variable.clone());
     }
 
+    fn visit_access_variable(&mut self, access_variable: &plc_ast::ast::AccessVariable) {
+        self.index.access_variables.push(access_variable.clone());
+    }
+
+    /// Registers the tasks and program instances of all resources of the configuration,
+    /// qualified by the name of their resource
+    fn visit_configuration(&mut self, configuration: &Configuration) {
+        for resource in &configuration.resources {
+            for task in &resource.tasks {
+                let constants = self.index.get_mut_const_expressions();
+                let interval =
+                    constants.maybe_add_constant_expression(task.interval.clone(), TIME_TYPE, None, None);
+                let priority =
+                    constants.maybe_add_constant_expression(task.priority.clone(), UINT_TYPE, None, None);
+                self.index.register_task(TaskIndexEntry {
+                    name: task.name.clone(),
+                    qualified_name: qualified_name(&resource.name, &task.name),
+                    configuration: configuration.name.clone(),
+                    interval,
+                    single: task
+                        .single
+                        .as_ref()
+                        .and_then(|it| it.get_flat_reference_name())
+                        .map(str::to_string),
+                    priority,
+                    location: task.name_location.clone(),
+                });
+            }
+
+            for program in &resource.programs {
+                self.index.register_configured_program(ProgramInstanceIndexEntry {
+                    name: program.instance_name.clone(),
+                    qualified_name: qualified_name(&resource.name, &program.instance_name),
+                    configuration: configuration.name.clone(),
+                    program: program.program.clone(),
+                    task: program.task.as_ref().map(|(task, _)| qualified_name(&resource.name, task)),
+                    location: program.name_location.clone(),
+                    program_location: program.program_location.clone(),
+                });
+            }
+        }
+    }
+
     fn visit_interface(&mut self, interface: &Interface) {
         for method in &interface.methods {
             self.visit_pou(method);
         }
+        self.index_properties(&interface.name, &interface.properties);
+
+        // variables can be declared with an interface as their type
+        let user_type = UserTypeDeclaration {
+            data_type: DataType::StructType {
+                name: Some(interface.name.clone()),
+                variables: vec![],
+                base: None,
+            },
+            initializer: None,
+            location: interface.location_name.clone(),
+            scope: None,
+            attributes: vec![],
+        };
+        UserTypeIndexer::new(&mut self.index, &user_type).index_interface_type(&interface.name);
 
         self.index.interfaces.insert(interface.name.clone(), InterfaceIndexEntry::from(interface));
     }
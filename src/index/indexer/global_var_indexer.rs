@@ -1,16 +1,24 @@
-use plc_ast::ast::LinkageType;
+use plc_ast::ast::{LinkageType, VariableBlock};
 
 use crate::index::{HardwareBinding, Index, VariableIndexEntry};
 
 pub struct VarGlobalIndexer<'i> {
     constant: bool,
+    retain: bool,
+    persistent: bool,
     linkage: LinkageType,
     index: &'i mut Index,
 }
 
 impl VarGlobalIndexer<'_> {
-    pub fn new(constant: bool, linkage: LinkageType, index: &mut Index) -> VarGlobalIndexer<'_> {
-        VarGlobalIndexer { constant, linkage, index }
+    pub fn new<'b>(block: &VariableBlock, index: &'b mut Index) -> VarGlobalIndexer<'b> {
+        VarGlobalIndexer {
+            constant: block.constant,
+            retain: block.retain,
+            persistent: block.persistent,
+            linkage: block.linkage,
+            index,
+        }
     }
 
     pub fn visit_variable(&mut self, var: &plc_ast::ast::Variable) {
@@ -29,10 +37,12 @@ impl VarGlobalIndexer<'_> {
         )
         .set_initial_value(initializer)
         .set_constant(self.constant)
+        .set_retention(self.retain, self.persistent)
         .set_linkage(self.linkage)
         .set_hardware_binding(
             var.address.as_ref().and_then(|it| HardwareBinding::from_statement(self.index, it, None)),
-        );
+        )
+        .set_attributes(var.attributes.clone());
         self.index.register_global_variable(&var.name, variable);
     }
 }
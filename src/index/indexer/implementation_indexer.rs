@@ -24,14 +24,16 @@ impl<'i> ImplementationIndexer<'i> {
             .or(Some(&implementation.location))
             .cloned()
             .unwrap();
-        self.index.register_implementation(
-            &implementation.name,
-            &implementation.type_name,
-            pou_type.get_optional_owner_class().as_ref(),
-            pou_type.into(),
-            implementation.generic,
-            start_location,
-        );
+        self.index
+            .register_implementation(
+                &implementation.name,
+                &implementation.type_name,
+                pou_type.get_optional_owner_class().as_ref(),
+                pou_type.into(),
+                implementation.generic,
+                start_location,
+            )
+            .set_access(implementation.access.clone());
         //if we are registering an action, also register a datatype for it
         if pou_type == &PouType::Action {
             let datatype = typesystem::DataType {
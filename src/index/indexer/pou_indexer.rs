@@ -83,7 +83,12 @@ impl<'i> PouIndexer<'i> {
 
     /// Registers a program in the index
     fn index_program(&mut self, pou: &plc_ast::ast::Pou, pou_struct_type: typesystem::DataType) {
-        self.index.register_program(&pou.name, pou.name_location.clone(), pou.linkage);
+        self.index.register_program(
+            &pou.name,
+            pou.name_location.clone(),
+            pou.linkage,
+            pou.attributes.clone(),
+        );
         self.index.register_pou_type(pou_struct_type);
     }
 
@@ -95,13 +100,17 @@ impl<'i> PouIndexer<'i> {
         owner_class: &str,
         pou_struct_type: typesystem::DataType,
     ) {
-        self.index.register_pou(PouIndexEntry::create_method_entry(
-            &pou.name,
-            return_type_name,
-            owner_class,
-            pou.linkage,
-            pou.name_location.clone(),
-        ));
+        self.index.register_pou(
+            PouIndexEntry::create_method_entry(
+                &pou.name,
+                return_type_name,
+                owner_class,
+                pou.linkage,
+                pou.name_location.clone(),
+            )
+            .with_attributes(pou.attributes.clone())
+            .with_polymorphism_mode(pou.poly_mode),
+        );
         self.index.register_pou_type(pou_struct_type);
     }
 
@@ -113,15 +122,18 @@ impl<'i> PouIndexer<'i> {
         member_varargs: Option<VarArgs>,
         pou_struct_type: typesystem::DataType,
     ) {
-        self.index.register_pou(PouIndexEntry::create_function_entry(
-            &pou.name,
-            return_type_name,
-            &pou.generics,
-            pou.linkage,
-            member_varargs.is_some(),
-            pou.name_location.clone(),
-            pou.is_const,
-        ));
+        self.index.register_pou(
+            PouIndexEntry::create_function_entry(
+                &pou.name,
+                return_type_name,
+                &pou.generics,
+                pou.linkage,
+                member_varargs.is_some(),
+                pou.name_location.clone(),
+                pou.is_const,
+            )
+            .with_attributes(pou.attributes.clone()),
+        );
         self.index.register_pou_type(pou_struct_type);
     }
 
@@ -137,12 +149,17 @@ impl<'i> PouIndexer<'i> {
         .set_constant(true)
         .set_linkage(pou.linkage);
         self.index.register_global_initializer(&global_struct_name, variable);
-        self.index.register_pou(PouIndexEntry::create_class_entry(
-            &pou.name,
-            pou.linkage,
-            pou.name_location.clone(),
-            pou.super_class.clone(),
-        ));
+        self.index.register_pou(
+            PouIndexEntry::create_class_entry(
+                &pou.name,
+                pou.linkage,
+                pou.name_location.clone(),
+                pou.super_class.clone(),
+                pou.interfaces.iter().map(|it| it.name.clone()).collect(),
+            )
+            .with_attributes(pou.attributes.clone())
+            .with_polymorphism_mode(pou.poly_mode),
+        );
         self.index.register_pou_type(pou_struct_type);
     }
 
@@ -158,12 +175,17 @@ impl<'i> PouIndexer<'i> {
         .set_constant(true)
         .set_linkage(pou.linkage);
         self.index.register_global_initializer(&global_struct_name, variable);
-        self.index.register_pou(PouIndexEntry::create_function_block_entry(
-            &pou.name,
-            pou.linkage,
-            pou.name_location.clone(),
-            pou.super_class.clone().as_deref(),
-        ));
+        self.index.register_pou(
+            PouIndexEntry::create_function_block_entry(
+                &pou.name,
+                pou.linkage,
+                pou.name_location.clone(),
+                pou.super_class.clone().as_deref(),
+                pou.interfaces.iter().map(|it| it.name.clone()).collect(),
+            )
+            .with_attributes(pou.attributes.clone())
+            .with_polymorphism_mode(pou.poly_mode),
+        );
         self.index.register_pou_type(pou_struct_type);
     }
 
@@ -217,21 +239,26 @@ impl<'i> PouIndexer<'i> {
                     .as_ref()
                     .and_then(|it| HardwareBinding::from_statement(self.index, it, Some(pou.name.clone())));
 
-                let entry = self.index.create_member_variable(
-                    MemberInfo {
-                        container_name: &pou.name,
-                        variable_name: &var.name,
-                        variable_linkage: block_type,
-                        variable_type_name: &type_name,
-                        is_constant: block.constant,
-                        is_var_external: matches!(block.variable_block_type, VariableBlockType::External),
-                        binding,
-                        varargs,
-                    },
-                    initial_value,
-                    var.location.clone(),
-                    count,
-                );
+                let entry = self
+                    .index
+                    .create_member_variable(
+                        MemberInfo {
+                            container_name: &pou.name,
+                            variable_name: &var.name,
+                            variable_linkage: block_type,
+                            variable_type_name: &type_name,
+                            is_constant: block.constant,
+                            is_var_external: matches!(block.variable_block_type, VariableBlockType::External),
+                            binding,
+                            varargs,
+                        },
+                        initial_value,
+                        var.location.clone(),
+                        count,
+                    )
+                    .set_retention(block.retain, block.persistent)
+                    .set_access(block.access.clone())
+                    .set_attributes(var.attributes.clone());
                 members.push(entry);
                 count += 1;
             }
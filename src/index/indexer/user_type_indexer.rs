@@ -16,8 +16,8 @@ use crate::{
         VariableType,
     },
     typesystem::{
-        self, DataTypeInformation, Dimension, StringEncoding, StructSource, TypeSize, DEFAULT_STRING_LEN,
-        DINT_TYPE,
+        self, DataTypeInformation, Dimension, StringEncoding, StructSource, TypeSize, BYTE_POINTER_TYPE,
+        DEFAULT_STRING_LEN, DINT_TYPE,
     },
 };
 
@@ -58,9 +58,12 @@ impl AstVisitor for UserTypeIndexer<'_, '_> {
 
     fn visit_data_type(&mut self, data_type: &DataType) {
         match &data_type {
-            DataType::StructType { name: Some(name), variables } => {
+            DataType::StructType { name: Some(name), variables, .. } => {
                 self.index_struct_type(name, variables, StructSource::OriginalDeclaration)
             }
+            DataType::UnionType { name: Some(name), variables } => {
+                self.index_struct_type(name, variables, StructSource::Union)
+            }
             DataType::EnumType { name: Some(name), numeric_type, elements } => {
                 self.index_enum_type(name, numeric_type, elements)
             }
@@ -95,6 +98,25 @@ impl UserTypeIndexer<'_, '_> {
         self.user_type.scope.clone()
     }
 
+    /// Registers the datatype of variables declared with an interface as their type, see
+    /// [`InternalType::Interface`](typesystem::InternalType::Interface)
+    pub fn index_interface_type(&mut self, name: &str) {
+        let variables = ["__data", "__vtable"].map(|member| Variable {
+            name: member.to_string(),
+            data_type_declaration: DataTypeDeclaration::DataTypeReference {
+                referenced_type: BYTE_POINTER_TYPE.to_string(),
+                location: SourceLocation::internal(),
+            },
+            initializer: None,
+            address: None,
+            init_arguments: None,
+            location: SourceLocation::internal(),
+            attributes: vec![],
+        });
+
+        self.index_struct_type(name, &variables, StructSource::Internal(typesystem::InternalType::Interface));
+    }
+
     fn index_vla_array(&mut self, name: &str, bounds: &AstNode, referenced_type: &DataTypeDeclaration) {
         let ndims = match bounds.get_stmt() {
             AstStatement::VlaRangeStatement => 1,
@@ -204,7 +226,9 @@ impl UserTypeIndexer<'_, '_> {
                 data_type_declaration: vla_arr_type_declaration,
                 initializer: None,
                 address: None,
+                init_arguments: None,
                 location: SourceLocation::internal(),
+                attributes: vec![],
             },
             // Dimensions Array
             Variable {
@@ -212,7 +236,9 @@ impl UserTypeIndexer<'_, '_> {
                 data_type_declaration: dim_arr_type_declaration,
                 initializer: None,
                 address: None,
+                init_arguments: None,
                 location: SourceLocation::internal(),
+                attributes: vec![],
             },
         ];
 
@@ -453,21 +479,23 @@ impl UserTypeIndexer<'_, '_> {
                     .as_ref()
                     .and_then(|it| HardwareBinding::from_statement(self.index, it, scope.clone()));
 
-                self.index.create_member_variable(
-                    MemberInfo {
-                        container_name: name,
-                        variable_name: &var.name,
-                        variable_linkage: ArgumentType::ByVal(VariableType::Input), // struct members act like VAR_INPUT in terms of visibility
-                        variable_type_name: member_type,
-                        is_constant: false, //struct members are not constants //TODO thats probably not true (you can define a struct in an CONST-block?!)
-                        is_var_external: false, // see above
-                        binding,
-                        varargs: None,
-                    },
-                    init,
-                    var.location.clone(),
-                    count as u32,
-                )
+                self.index
+                    .create_member_variable(
+                        MemberInfo {
+                            container_name: name,
+                            variable_name: &var.name,
+                            variable_linkage: ArgumentType::ByVal(VariableType::Input), // struct members act like VAR_INPUT in terms of visibility
+                            variable_type_name: member_type,
+                            is_constant: false, //struct members are not constants //TODO thats probably not true (you can define a struct in an CONST-block?!)
+                            is_var_external: false, // see above
+                            binding,
+                            varargs: None,
+                        },
+                        init,
+                        var.location.clone(),
+                        count as u32,
+                    )
+                    .set_attributes(var.attributes.clone())
             })
             .collect::<Vec<_>>();
 
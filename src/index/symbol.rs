@@ -90,6 +90,11 @@ where
         }
     }
 
+    /// returns `true` if the map contains no elements
+    pub fn is_empty(&self) -> bool {
+        self.inner_map.is_empty()
+    }
+
     /// return `true` if an equivalent to key exists in the map.
     pub fn contains_key(&self, key: &K) -> bool {
         self.inner_map.contains_key(key)
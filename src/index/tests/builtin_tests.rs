@@ -34,3 +34,10 @@ fn test_indexer_has_builtins() {
     assert!(index.find_implementation_by_name("SEL").is_some());
     assert!(index.find_implementation_by_name("MOVE").is_some());
 }
+
+#[test]
+fn exception_code_enum_added_to_index() {
+    let (_, index) = index("");
+    assert!(index.find_effective_type_by_name("__EXCEPTION_CODE").is_some());
+    assert!(index.find_enum_variant("__EXCEPTION_CODE", "EXCEPTION_DIVIDE_BY_ZERO").is_some());
+}
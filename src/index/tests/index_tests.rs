@@ -1,8 +1,8 @@
 // Copyright (c) 2020 Ghaith Hachem and Mathias Rieder
 use insta::assert_debug_snapshot;
 use plc_ast::ast::{
-    pre_process, AstFactory, AutoDerefType, DataType, GenericBinding, LinkageType, Operator, TypeNature,
-    UserTypeDeclaration,
+    pre_process, AstFactory, AutoDerefType, DataType, GenericBinding, LinkageType, Operator,
+    PolymorphismMode, PropertyKind, TypeNature, UserTypeDeclaration,
 };
 use plc_ast::provider::IdProvider;
 use plc_source::source_location::{SourceLocation, SourceLocationFactory};
@@ -861,6 +861,7 @@ fn pre_processing_generates_generic_types() {
         initializer: None,
         location: SourceLocation::internal(),
         scope: Some("myFunc".into()),
+        attributes: vec![],
     };
 
     assert_eq!(format!("{expected:?}"), format!("{:?}", ast.user_types[0]));
@@ -895,6 +896,7 @@ fn pre_processing_generates_nested_generic_types() {
         initializer: None,
         location: SourceLocation::internal(),
         scope: Some("myFunc".into()),
+        attributes: vec![],
     };
 
     assert_eq!(format!("{expected:?}"), format!("{:?}", ast.user_types[0]));
@@ -1360,13 +1362,18 @@ fn a_program_pou_is_indexed() {
                 argument_type: ArgumentType::ByVal(VariableType::Global),
                 is_constant: false,
                 is_var_external: false,
+                is_retain: false,
+                is_persistent: false,
+                access: None,
                 data_type_name: "myProgram".into(),
                 location_in_parent: 0,
                 linkage: LinkageType::Internal,
                 binding: None,
                 source_location: source_location_factory.create_range(17..26),
                 varargs: None,
-            })
+                attributes: vec![],
+            }),
+            attributes: vec![],
         }),
         index.find_pou("myProgram"),
     );
@@ -1381,6 +1388,7 @@ fn a_program_pou_is_indexed() {
             location: source_location_factory.create_range(65..75),
             is_generated: false,
             is_const: false,
+            attributes: vec![],
         }),
         index.find_pou("myFunction"),
     );
@@ -1392,6 +1400,9 @@ fn a_program_pou_is_indexed() {
             instance_struct_name: "myFunctionBlock".into(),
             location: source_location_factory.create_range(139..154),
             super_class: None,
+            interfaces: vec![],
+            attributes: vec![],
+            poly_mode: PolymorphismMode::None,
         }),
         index.find_pou("myFunctionBlock"),
     );
@@ -1403,6 +1414,9 @@ fn a_program_pou_is_indexed() {
             instance_struct_name: "myClass".into(),
             location: source_location_factory.create_range(197..204),
             super_class: None,
+            interfaces: vec![],
+            attributes: vec![],
+            poly_mode: PolymorphismMode::None,
         }),
         index.find_pou("myClass"),
     );
@@ -1414,6 +1428,7 @@ fn a_program_pou_is_indexed() {
             linkage: LinkageType::Internal,
             instance_struct_name: "myProgram".into(),
             location: source_location_factory.create_range(269..272),
+            attributes: vec![],
         }),
         index.find_pou("myProgram.act"),
     );
@@ -1471,7 +1486,8 @@ fn fb_parameters_variable_type() {
 
     // THEN the parameters should have the correct VariableType
     let members = index.get_container_members("fb");
-    assert_eq!(members.len(), 3);
+    // the parameters follow the `__vtable` member
+    assert_eq!(members.len(), 4);
 
     // INPUT => ByVal
     // OUTPUT => ByVal
@@ -1632,12 +1648,16 @@ fn internal_vla_struct_type_is_indexed_correctly() {
                     argument_type: ArgumentType::ByVal(VariableType::Input),
                     is_constant: false,
                     is_var_external: false,
+                    is_retain: false,
+                    is_persistent: false,
+                    access: None,
                     data_type_name: "__ptr_to___arr_vla_1_int".to_string(),
                     location_in_parent: 0,
                     linkage: LinkageType::Internal,
                     binding: None,
                     source_location: SourceLocation::internal(),
-                    varargs: None
+                    varargs: None,
+                    attributes: vec![],
                 },
                 VariableIndexEntry {
                     name: "dimensions".to_string(),
@@ -1646,12 +1666,16 @@ fn internal_vla_struct_type_is_indexed_correctly() {
                     argument_type: ArgumentType::ByVal(VariableType::Input),
                     is_constant: false,
                     is_var_external: false,
+                    is_retain: false,
+                    is_persistent: false,
+                    access: None,
                     data_type_name: "__bounds___arr_vla_1_int".to_string(),
                     location_in_parent: 1,
                     linkage: LinkageType::Internal,
                     binding: None,
                     source_location: SourceLocation::internal(),
-                    varargs: None
+                    varargs: None,
+                    attributes: vec![],
                 }
             ],
             source: StructSource::Internal(InternalType::VariableLengthArray {
@@ -1706,7 +1730,7 @@ fn aliased_hardware_access_variable_has_implicit_initial_value_declaration() {
         qualified_name: "foo",
         initial_value: Some(
             Index {
-                index: 0,
+                index: 4,
                 generation: 0,
             },
         ),
@@ -1715,6 +1739,9 @@ fn aliased_hardware_access_variable_has_implicit_initial_value_declaration() {
         ),
         is_constant: false,
         is_var_external: false,
+        is_retain: false,
+        is_persistent: false,
+        access: None,
         data_type_name: "__global_foo",
         location_in_parent: 0,
         linkage: Internal,
@@ -1724,19 +1751,19 @@ fn aliased_hardware_access_variable_has_implicit_initial_value_declaration() {
                 access: Bit,
                 entries: [
                     Index {
-                        index: 1,
+                        index: 5,
                         generation: 0,
                     },
                     Index {
-                        index: 2,
+                        index: 6,
                         generation: 0,
                     },
                     Index {
-                        index: 3,
+                        index: 7,
                         generation: 0,
                     },
                     Index {
-                        index: 4,
+                        index: 8,
                         generation: 0,
                     },
                 ],
@@ -1769,6 +1796,7 @@ fn aliased_hardware_access_variable_has_implicit_initial_value_declaration() {
             ),
         },
         varargs: None,
+        attributes: [],
     }
     "###);
 }
@@ -1796,6 +1824,9 @@ fn aliased_hardware_access_variable_creates_global_var_for_address() {
         ),
         is_constant: false,
         is_var_external: false,
+        is_retain: false,
+        is_persistent: false,
+        access: None,
         data_type_name: "BOOL",
         location_in_parent: 0,
         linkage: Internal,
@@ -1814,6 +1845,7 @@ fn aliased_hardware_access_variable_creates_global_var_for_address() {
             ),
         },
         varargs: None,
+        attributes: [],
     }
     "###);
 
@@ -1948,6 +1980,9 @@ fn address_used_in_2_aliases_only_created_once() {
             ),
             is_constant: false,
             is_var_external: false,
+            is_retain: false,
+            is_persistent: false,
+            access: None,
             data_type_name: "BOOL",
             location_in_parent: 0,
             linkage: Internal,
@@ -1966,6 +2001,7 @@ fn address_used_in_2_aliases_only_created_once() {
                 ),
             },
             varargs: None,
+            attributes: [],
         },
     )
     "###);
@@ -1998,6 +2034,9 @@ fn aliased_variable_with_in_or_out_directions_create_the_same_variable() {
             ),
             is_constant: false,
             is_var_external: false,
+            is_retain: false,
+            is_persistent: false,
+            access: None,
             data_type_name: "BOOL",
             location_in_parent: 0,
             linkage: Internal,
@@ -2016,6 +2055,7 @@ fn aliased_variable_with_in_or_out_directions_create_the_same_variable() {
                 ),
             },
             varargs: None,
+            attributes: [],
         },
     )
     "###);
@@ -2030,6 +2070,9 @@ fn aliased_variable_with_in_or_out_directions_create_the_same_variable() {
             ),
             is_constant: false,
             is_var_external: false,
+            is_retain: false,
+            is_persistent: false,
+            access: None,
             data_type_name: "WORD",
             location_in_parent: 0,
             linkage: Internal,
@@ -2048,6 +2091,7 @@ fn aliased_variable_with_in_or_out_directions_create_the_same_variable() {
                 ),
             },
             varargs: None,
+            attributes: [],
         },
     )
     "###);
@@ -2078,6 +2122,9 @@ fn if_two_aliased_var_of_different_types_use_the_same_address_the_first_wins() {
             ),
             is_constant: false,
             is_var_external: false,
+            is_retain: false,
+            is_persistent: false,
+            access: None,
             data_type_name: "BOOL",
             location_in_parent: 0,
             linkage: Internal,
@@ -2096,6 +2143,7 @@ fn if_two_aliased_var_of_different_types_use_the_same_address_the_first_wins() {
                 ),
             },
             varargs: None,
+            attributes: [],
         },
     )
     "###);
@@ -2123,6 +2171,9 @@ fn var_config_hardware_address_creates_global_variable() {
         ),
         is_constant: false,
         is_var_external: false,
+        is_retain: false,
+        is_persistent: false,
+        access: None,
         data_type_name: "BOOL",
         location_in_parent: 0,
         linkage: Internal,
@@ -2141,6 +2192,7 @@ fn var_config_hardware_address_creates_global_variable() {
             ),
         },
         varargs: None,
+        attributes: [],
     }
     "###);
 }
@@ -2188,3 +2240,222 @@ fn var_externals_constants_are_both_flagged_as_external_and_constant() {
     let external = &index.get_pou_members("foo")[0];
     assert!(external.is_var_external() && external.is_constant());
 }
+
+#[test]
+fn properties_are_indexed_with_their_accessors() {
+    let (_, index) = index(
+        "
+        FUNCTION_BLOCK fb
+            PROPERTY readOnly : INT
+                GET END_GET
+            END_PROPERTY
+
+            PROPERTY readWrite : ARRAY[0..1] OF BOOL
+                GET END_GET
+                SET END_SET
+            END_PROPERTY
+        END_FUNCTION_BLOCK
+
+        FUNCTION_BLOCK child EXTENDS fb
+        END_FUNCTION_BLOCK
+
+        INTERFACE interfaceA
+            PROPERTY writeOnly : DINT
+                SET END_SET
+            END_PROPERTY
+        END_INTERFACE
+        ",
+    );
+
+    let read_only = index.find_property("fb", "readOnly").unwrap();
+    assert_eq!(read_only.qualified_name, "fb.readOnly");
+    assert_eq!(read_only.get_type_name(), "INT");
+    assert_eq!(read_only.find_accessor(&index, PropertyKind::Get).unwrap().get_name(), "fb.__get_readOnly");
+    assert!(read_only.find_accessor(&index, PropertyKind::Set).is_none());
+
+    // inline datatypes are shared by the property and its accessors
+    let read_write = index.find_property("FB", "READWRITE").unwrap();
+    assert_eq!(read_write.get_type_name(), "__fb_readWrite");
+    assert_eq!(
+        index.find_member("fb.__set_readWrite", "readWrite").unwrap().get_type_name(),
+        read_write.get_type_name()
+    );
+
+    // properties are inherited
+    assert_eq!(index.find_property("child", "readOnly"), Some(read_only));
+    assert!(read_only.find_accessor(&index, PropertyKind::Get).is_some());
+
+    // interface accessors become interface methods
+    let write_only = index.find_property("interfaceA", "writeOnly").unwrap();
+    assert_eq!(
+        write_only.find_accessor(&index, PropertyKind::Set).unwrap().get_name(),
+        "interfaceA.__set_writeOnly"
+    );
+    assert_eq!(index.find_interface("interfaceA").unwrap().methods, vec!["interfaceA.__set_writeOnly"]);
+}
+
+#[test]
+fn properties_are_not_searched_in_cyclic_hierarchies_forever() {
+    let (_, index) = index(
+        "
+        FUNCTION_BLOCK a EXTENDS b
+            PROPERTY foo : INT
+                GET END_GET
+            END_PROPERTY
+        END_FUNCTION_BLOCK
+
+        FUNCTION_BLOCK b EXTENDS a
+        END_FUNCTION_BLOCK
+        ",
+    );
+
+    assert_eq!(index.find_property("b", "foo").map(|it| it.qualified_name.as_str()), Some("a.foo"));
+    assert!(index.find_property("a", "bar").is_none());
+}
+
+#[test]
+fn implemented_interfaces_are_collected_from_cyclic_hierarchies_once() {
+    let (_, index) = index(
+        "
+        INTERFACE foo END_INTERFACE
+        INTERFACE bar END_INTERFACE
+
+        FUNCTION_BLOCK a EXTENDS b IMPLEMENTS foo
+        END_FUNCTION_BLOCK
+
+        FUNCTION_BLOCK b EXTENDS a IMPLEMENTS bar, foo
+        END_FUNCTION_BLOCK
+        ",
+    );
+
+    let interfaces = index.get_implemented_interfaces("a");
+    assert_eq!(interfaces.iter().map(|it| it.name.as_str()).collect::<Vec<_>>(), vec!["bar", "foo"]);
+}
+
+#[test]
+fn virtual_methods_are_listed_in_slot_order() {
+    let (_, index) = index(
+        "
+        FUNCTION_BLOCK base
+            METHOD foo END_METHOD
+            METHOD bar END_METHOD
+        END_FUNCTION_BLOCK
+
+        FUNCTION_BLOCK derived EXTENDS base
+            METHOD baz END_METHOD
+            METHOD OVERRIDE bar END_METHOD
+        END_FUNCTION_BLOCK
+
+        FUNCTION_BLOCK FINAL leaf
+            METHOD foo END_METHOD
+        END_FUNCTION_BLOCK
+        ",
+    );
+
+    let names = |pou| index.get_virtual_methods(pou).iter().map(|it| it.get_name()).collect::<Vec<_>>();
+    assert_eq!(names("base"), vec!["base.foo", "base.bar"]);
+    assert_eq!(names("derived"), vec!["base.foo", "base.bar", "derived.baz"]);
+    // FINAL POUs without a super class have no dispatch table
+    assert!(names("leaf").is_empty());
+}
+
+#[test]
+fn methods_are_not_searched_in_cyclic_hierarchies_forever() {
+    let (_, index) = index(
+        "
+        FUNCTION_BLOCK a EXTENDS b
+            METHOD foo END_METHOD
+        END_FUNCTION_BLOCK
+
+        FUNCTION_BLOCK b EXTENDS a
+            METHOD bar END_METHOD
+        END_FUNCTION_BLOCK
+        ",
+    );
+
+    // a cyclic hierarchy has no root holding the `__vtable`
+    assert!(index.get_virtual_methods("a").is_empty());
+    let methods = index.get_inherited_methods("a");
+    assert_eq!(methods.iter().map(|it| it.get_name()).collect::<Vec<_>>(), vec!["a.foo", "b.bar"]);
+}
+
+#[test]
+fn attributes_are_carried_into_the_index() {
+    let (_, index) = index(
+        "
+        {attribute 'monitoring' := 'call'}
+        PROGRAM prg
+            VAR
+                {attribute 'hide'}
+                x : INT;
+            END_VAR
+        END_PROGRAM
+
+        {attribute 'reflection'}
+        FUNCTION_BLOCK fb
+            {attribute 'test'}
+            METHOD m END_METHOD
+        END_FUNCTION_BLOCK
+
+        {attribute 'test'}
+        FUNCTION foo : INT END_FUNCTION
+
+        VAR_GLOBAL
+            {attribute 'qualified_only'}
+            g : INT;
+        END_VAR
+
+        TYPE point : STRUCT
+            {attribute 'hide'}
+            x : INT;
+        END_STRUCT END_TYPE
+        ",
+    );
+
+    let monitoring = index.find_pou("prg").unwrap().get_attribute("MONITORING").unwrap();
+    assert_eq!(monitoring.value.as_deref(), Some("call"));
+    assert!(index.find_pou("fb").unwrap().get_attribute("reflection").is_some());
+    assert!(index.find_pou("fb.m").unwrap().get_attribute("test").is_some());
+    assert!(index.find_pou("foo").unwrap().get_attribute("test").is_some());
+    assert!(index.find_pou("foo").unwrap().get_attribute("hide").is_none());
+
+    assert!(index.find_member("prg", "x").unwrap().get_attribute("hide").is_some());
+    assert!(index.find_global_variable("g").unwrap().get_attribute("qualified_only").is_some());
+    assert!(index.find_member("point", "x").unwrap().get_attribute("hide").is_some());
+    assert!(index.find_member("foo", "foo").unwrap().get_attributes().is_empty());
+}
+
+#[test]
+fn tasks_and_configured_programs_are_indexed() {
+    let (_, index) = index(
+        "
+        PROGRAM MainPrg END_PROGRAM
+
+        CONFIGURATION Plant
+            VAR_GLOBAL
+                alarm : BOOL;
+            END_VAR
+            RESOURCE Cpu ON PLC
+                TASK fast (INTERVAL := T#10ms, PRIORITY := 1);
+                TASK onAlarm (SINGLE := alarm, PRIORITY := 0);
+                PROGRAM main WITH fast : MainPrg;
+            END_RESOURCE
+        END_CONFIGURATION
+        ",
+    );
+
+    let fast = index.find_task("Cpu.fast").unwrap();
+    assert_eq!(fast.name, "fast");
+    assert_eq!(fast.configuration, "Plant");
+    assert!(fast.interval.is_some());
+    assert!(fast.single.is_none());
+
+    let on_alarm = index.find_task("cpu.onalarm").unwrap();
+    assert_eq!(on_alarm.single.as_deref(), Some("alarm"));
+    assert!(on_alarm.interval.is_none());
+    assert!(index.find_global_variable("alarm").is_some());
+
+    let main = index.get_configured_programs().get("cpu.main").unwrap();
+    assert_eq!(main.program, "MainPrg");
+    assert_eq!(main.find_task(&index).map(|it| it.qualified_name.as_str()), Some("Cpu.fast"));
+}
@@ -61,6 +61,8 @@ fn interface_with_single_method() {
                 },
             ),
         },
+        attributes: [],
+        poly_mode: None,
     }
     "###);
 
@@ -75,6 +77,9 @@ fn interface_with_single_method() {
             ),
             is_constant: false,
             is_var_external: false,
+            is_retain: false,
+            is_persistent: false,
+            access: None,
             data_type_name: "INT",
             location_in_parent: 0,
             linkage: Internal,
@@ -93,6 +98,7 @@ fn interface_with_single_method() {
                 ),
             },
             varargs: None,
+            attributes: [],
         },
         VariableIndexEntry {
             name: "b",
@@ -103,6 +109,9 @@ fn interface_with_single_method() {
             ),
             is_constant: false,
             is_var_external: false,
+            is_retain: false,
+            is_persistent: false,
+            access: None,
             data_type_name: "INT",
             location_in_parent: 1,
             linkage: Internal,
@@ -121,6 +130,7 @@ fn interface_with_single_method() {
                 ),
             },
             varargs: None,
+            attributes: [],
         },
         VariableIndexEntry {
             name: "foo",
@@ -131,6 +141,9 @@ fn interface_with_single_method() {
             ),
             is_constant: false,
             is_var_external: false,
+            is_retain: false,
+            is_persistent: false,
+            access: None,
             data_type_name: "INT",
             location_in_parent: 2,
             linkage: Internal,
@@ -149,6 +162,7 @@ fn interface_with_single_method() {
                 ),
             },
             varargs: None,
+            attributes: [],
         },
     ]
     "###);
@@ -201,6 +215,8 @@ fn get_interface_methods() {
                     },
                 ),
             },
+            attributes: [],
+            poly_mode: None,
         },
         Method {
             name: "myInterface.bar",
@@ -221,6 +237,8 @@ fn get_interface_methods() {
                     },
                 ),
             },
+            attributes: [],
+            poly_mode: None,
         },
         Method {
             name: "myInterface.baz",
@@ -241,6 +259,8 @@ fn get_interface_methods() {
                     },
                 ),
             },
+            attributes: [],
+            poly_mode: None,
         },
     ]
     "###);
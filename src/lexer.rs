@@ -1,12 +1,15 @@
 // Copyright (c) 2020 Ghaith Hachem and Mathias Rieder
+pub use conditionals::Defines;
+use conditionals::LexerExtras;
 use core::ops::Range;
 use logos::{Filter, Lexer, Logos};
-use plc_ast::ast::{AstId, DirectAccessType, HardwareAccessType};
+use plc_ast::ast::{AstId, Attribute, DirectAccessType, HardwareAccessType};
 use plc_ast::provider::IdProvider;
 use plc_diagnostics::diagnostics::Diagnostic;
 use plc_source::source_location::{SourceLocation, SourceLocationFactory};
 pub use tokens::Token;
 
+mod conditionals;
 #[cfg(test)]
 mod tests;
 mod tokens;
@@ -24,6 +27,12 @@ pub struct ParseSession<'a> {
     pub id_provider: IdProvider,
     pub source_range_factory: SourceLocationFactory,
     pub scope: Option<String>,
+    /// the qualified name of the `NAMESPACE` currently parsed, e.g. `a::b`
+    pub namespace: Option<String>,
+    /// the attribute pragmas directly in front of the current token
+    attributes: Vec<Attribute>,
+    /// whether the bodies currently parsed are written in instruction list (IL) instead of structured text
+    pub instruction_list: bool,
 }
 
 #[macro_export]
@@ -56,7 +65,10 @@ impl<'a> ParseSession<'a> {
             parse_progress: 0,
             id_provider,
             scope: None,
+            namespace: None,
             source_range_factory,
+            attributes: vec![],
+            instruction_list: false,
         };
         lexer.advance();
         lexer
@@ -92,6 +104,40 @@ impl<'a> ParseSession<'a> {
         slice
     }
 
+    /// Returns whether the current token is directly followed by an identifier, e.g. `STEP fill`
+    pub fn is_followed_by_identifier(&self) -> bool {
+        self.lexer.remainder().trim_start().starts_with(|it: char| it.is_alphabetic() || it == '_')
+    }
+
+    pub fn is_followed_by(&self, text: &str) -> bool {
+        self.lexer.remainder().trim_start().starts_with(text)
+    }
+
+    /// Creates a session lexing the source from the current token up to the end of its line, e.g. to parse
+    /// the operand of an IL instruction which must not continue on the next line
+    pub fn lex_rest_of_line(&self) -> ParseSession<'a> {
+        let source = self.lexer.source();
+        let start = self.range().start;
+        let end = source[start..].find('\n').map_or(source.len(), |it| start + it);
+
+        let mut lexer =
+            Token::lexer_with_extras(&source[..end], LexerExtras::new(self.lexer.extras.defines.clone()));
+        lexer.bump(start);
+        let mut session =
+            ParseSession::new(lexer, self.id_provider.clone(), self.source_range_factory.clone());
+        session.scope = self.scope.clone();
+        session.namespace = self.namespace.clone();
+        session
+    }
+
+    /// Advances until the current token starts at or after the given offset, stopping at the end of the
+    /// open region
+    pub fn skip_to(&mut self, offset: usize) {
+        while self.range().start < offset && !self.closes_open_region(&self.token) {
+            self.advance();
+        }
+    }
+
     pub fn is_end_of_stream(&self) -> bool {
         self.token == Token::End || self.token == Token::Error
     }
@@ -104,6 +150,8 @@ impl<'a> ParseSession<'a> {
         self.last_range = self.range();
         self.last_token = std::mem::replace(&mut self.token, self.lexer.next().unwrap_or(Token::End));
         self.parse_progress += 1;
+        self.report_conditional_compilation_errors();
+        self.collect_attributes();
 
         match self.token {
             Token::KeywordVarInput
@@ -120,13 +168,19 @@ impl<'a> ParseSession<'a> {
             | Token::KeywordFunctionBlock
             | Token::KeywordEndFunctionBlock
             | Token::KeywordEndStruct
+            | Token::KeywordEndUnion
             | Token::KeywordEndAction
             | Token::KeywordEndActions
             | Token::KeywordEndIf
             | Token::KeywordEndFor
             | Token::KeywordEndRepeat
             | Token::KeywordEndMethod
-            | Token::KeywordEndClass => {
+            | Token::KeywordEndClass
+            | Token::KeywordEndNamespace
+            | Token::KeywordEndConfiguration
+            | Token::KeywordEndResource
+            | Token::KeywordEndStep
+            | Token::KeywordEndTransition => {
                 if !self.slice().to_string().contains('_') {
                     self.accept_diagnostic(
                         Diagnostic::new(format!(
@@ -142,6 +196,44 @@ impl<'a> ParseSession<'a> {
         }
     }
 
+    /// reports the problems the lexer found with `{IF}`, `{ELSIF}`, `{ELSE}` and `{END_IF}` pragmas
+    fn report_conditional_compilation_errors(&mut self) {
+        if self.token == Token::End {
+            for location in self.lexer.extras.take_unclosed_conditionals() {
+                self.lexer.extras.diagnostics.push(("Missing `{END_IF}` for `{IF}`".to_string(), location));
+            }
+        }
+
+        for (message, location) in std::mem::take(&mut self.lexer.extras.diagnostics) {
+            self.diagnostics.push(
+                Diagnostic::new(message)
+                    .with_error_code("E122")
+                    .with_location(self.source_range_factory.create_range(location)),
+            );
+        }
+    }
+
+    /// keeps the attribute pragmas in front of the current token. Attributes in front of a
+    /// `{external}`, `{constant}`, `{ref}` or `{sized}` pragma are kept as well, all others are dropped
+    fn collect_attributes(&mut self) {
+        if !matches!(
+            self.last_token,
+            Token::PropertyExternal | Token::PropertyConstant | Token::PropertyByRef | Token::PropertySized
+        ) {
+            self.attributes.clear();
+        }
+
+        for (name, value, location) in std::mem::take(&mut self.lexer.extras.attributes) {
+            let location = self.source_range_factory.create_range(location);
+            self.attributes.push(Attribute { name, value, location });
+        }
+    }
+
+    /// takes the attribute pragmas declared in front of the current token
+    pub fn take_attributes(&mut self) -> Vec<Attribute> {
+        std::mem::take(&mut self.attributes)
+    }
+
     pub fn slice(&self) -> &str {
         self.lexer.slice()
     }
@@ -229,17 +321,40 @@ impl<'a> ParseSession<'a> {
 }
 
 fn parse_pragma(lexer: &mut Lexer<Token>) -> Filter<()> {
-    let remainder = lexer.remainder();
-    let chars = remainder.chars();
-    let mut traversed = 0;
-    for c in chars {
-        traversed += c.len_utf8();
-        if c == '}' {
-            lexer.bump(traversed);
-            return Filter::Skip;
-        }
+    if conditionals::consume_pragma(lexer) {
+        Filter::Skip
+    } else {
+        Filter::Emit(())
     }
-    Filter::Emit(())
+}
+
+/// parses the content of an `{attribute 'name'}` or `{attribute 'name' := 'value'}` pragma,
+/// returns `None` for all other pragmas
+fn parse_attribute(content: &str) -> Option<(String, Option<String>)> {
+    fn parse_quoted(text: &str) -> Option<(String, &str)> {
+        let text = text.trim_start().strip_prefix('\'')?;
+        let end = text.find('\'')?;
+        Some((text[..end].to_string(), &text[end + 1..]))
+    }
+
+    let content = content.trim_start();
+    let keyword = content.get(.."attribute".len())?;
+    if !keyword.eq_ignore_ascii_case("attribute") {
+        return None;
+    }
+
+    let (name, remainder) = parse_quoted(&content[keyword.len()..])?;
+    if name.trim().is_empty() {
+        return None;
+    }
+
+    let remainder = remainder.trim();
+    if remainder.is_empty() {
+        return Some((name, None));
+    }
+
+    let (value, remainder) = parse_quoted(remainder.strip_prefix(":=")?)?;
+    remainder.trim().is_empty().then_some((name, Some(value)))
 }
 
 fn parse_comments(lexer: &mut Lexer<Token>) -> Filter<()> {
@@ -341,5 +456,19 @@ pub fn lex_with_ids(
     id_provider: IdProvider,
     location_factory: SourceLocationFactory,
 ) -> ParseSession {
-    ParseSession::new(Token::lexer(source), id_provider, location_factory)
+    lex_with_defines(source, id_provider, location_factory, Defines::default())
+}
+
+/// lexes the given source, evaluating conditional compilation pragmas against the given `defines`
+pub fn lex_with_defines(
+    source: &str,
+    id_provider: IdProvider,
+    location_factory: SourceLocationFactory,
+    defines: Defines,
+) -> ParseSession {
+    ParseSession::new(
+        Token::lexer_with_extras(source, LexerExtras::new(defines)),
+        id_provider,
+        location_factory,
+    )
 }
@@ -0,0 +1,424 @@
+// Copyright (c) 2020 Ghaith Hachem and Mathias Rieder
+//! Conditional compilation using `{IF ...}`, `{ELSIF ...}`, `{ELSE}` and `{END_IF}` pragmas.
+//!
+//! The conditions are evaluated against the symbols passed with `-D NAME[=value]` or via the `defines`
+//! entry of a `plc.json`. Regions that are excluded are skipped by the lexer, so they neither produce
+//! tokens nor diagnostics. Since the lexer only moves past the excluded text, the source locations of all
+//! remaining tokens are not affected.
+//!
+//! ```iecst
+//! {IF defined(VARIANT_A) AND NOT hasvalue(AXES, '2')}
+//!     ...
+//! {ELSIF defined(VARIANT_B)}
+//!     ...
+//! {ELSE}
+//!     ...
+//! {END_IF}
+//! ```
+use core::ops::Range;
+
+use logos::Lexer;
+use rustc_hash::FxHashMap;
+
+use super::Token;
+
+/// The symbols available to conditional compilation pragmas
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Defines {
+    /// the defined symbols (lowercase) and their optional value
+    symbols: FxHashMap<String, Option<String>>,
+}
+
+impl Defines {
+    /// creates the defines from definitions in the form of `NAME` or `NAME=value`,
+    /// later definitions of the same symbol replace earlier ones
+    pub fn from_definitions<T: AsRef<str>>(definitions: &[T]) -> Self {
+        let symbols = definitions
+            .iter()
+            .map(|it| match it.as_ref().split_once('=') {
+                Some((name, value)) => (name.trim().to_lowercase(), Some(value.trim().to_string())),
+                None => (it.as_ref().trim().to_lowercase(), None),
+            })
+            .filter(|(name, _)| !name.is_empty())
+            .collect();
+
+        Defines { symbols }
+    }
+
+    pub fn is_defined(&self, name: &str) -> bool {
+        self.symbols.contains_key(&name.to_lowercase())
+    }
+
+    pub fn has_value(&self, name: &str, value: &str) -> bool {
+        self.symbols.get(&name.to_lowercase()).is_some_and(|it| it.as_deref() == Some(value))
+    }
+}
+
+/// The state the lexer keeps about the pragmas of a file
+#[derive(Debug, Default)]
+pub struct LexerExtras {
+    pub defines: Defines,
+    /// the currently open `{IF}` blocks
+    conditionals: Vec<Conditional>,
+    /// problems with the pragmas, reported by the `ParseSession`
+    pub diagnostics: Vec<(String, Range<usize>)>,
+    /// the `{attribute ...}` pragmas (name, optional value and location) not yet picked up by the `ParseSession`
+    pub attributes: Vec<(String, Option<String>, Range<usize>)>,
+}
+
+impl LexerExtras {
+    pub fn new(defines: Defines) -> Self {
+        LexerExtras { defines, ..Default::default() }
+    }
+
+    /// returns the locations of all `{IF}` pragmas that were not closed, the blocks are considered closed
+    /// afterwards
+    pub fn take_unclosed_conditionals(&mut self) -> Vec<Range<usize>> {
+        self.conditionals.drain(..).map(|it| it.location).collect()
+    }
+}
+
+#[derive(Debug)]
+struct Conditional {
+    /// the location of the opening `{IF}` pragma
+    location: Range<usize>,
+    /// whether one of the branches was already included
+    taken: bool,
+}
+
+#[derive(Debug, PartialEq)]
+enum Directive<'a> {
+    If(&'a str),
+    Elsif(&'a str),
+    Else,
+    EndIf,
+}
+
+/// parses the content between the braces of a pragma into a conditional directive, returns `None` for
+/// all other pragmas
+fn parse_directive(content: &str) -> Option<Directive<'_>> {
+    let content = content.trim();
+    let keyword_end = content.find(|c: char| !(c.is_ascii_alphabetic() || c == '_')).unwrap_or(content.len());
+    let (keyword, condition) = content.split_at(keyword_end);
+    match keyword.to_uppercase().as_str() {
+        "IF" => Some(Directive::If(condition)),
+        "ELSIF" => Some(Directive::Elsif(condition)),
+        "ELSE" if condition.trim().is_empty() => Some(Directive::Else),
+        "END_IF" if condition.trim().is_empty() => Some(Directive::EndIf),
+        _ => None,
+    }
+}
+
+/// Handles a `{...}` pragma whose content is given in `content` and which spans `location`.
+/// Returns `true` if the lexer continues in an excluded region that needs to be skipped
+fn handle_directive(extras: &mut LexerExtras, content: &str, location: Range<usize>) -> bool {
+    match parse_directive(content) {
+        Some(Directive::If(condition)) => {
+            let taken = evaluate(extras, condition, &location);
+            extras.conditionals.push(Conditional { location, taken });
+            !taken
+        }
+        Some(directive @ (Directive::Elsif(_) | Directive::Else)) => {
+            if let Some(conditional) = extras.conditionals.last_mut() {
+                // we get here from an included branch, so all remaining branches are excluded
+                conditional.taken = true;
+                true
+            } else {
+                let name = if directive == Directive::Else { "ELSE" } else { "ELSIF" };
+                extras.diagnostics.push((format!("`{{{name}}}` without a matching `{{IF}}`"), location));
+                false
+            }
+        }
+        Some(Directive::EndIf) => {
+            if extras.conditionals.pop().is_none() {
+                extras.diagnostics.push(("`{END_IF}` without a matching `{IF}`".to_string(), location));
+            }
+            false
+        }
+        None => false,
+    }
+}
+
+/// Consumes the pragma starting at the current `{`, handles conditional compilation directives and
+/// collects attribute pragmas. Returns `false` if the pragma is not terminated.
+pub fn consume_pragma(lexer: &mut Lexer<Token>) -> bool {
+    let Some(end) = lexer.remainder().find('}') else {
+        return false;
+    };
+    let content = &lexer.remainder()[..end];
+    let start = lexer.span().start;
+    let location = start..lexer.span().end + end + 1;
+    lexer.bump(end + 1);
+
+    if let Some((name, value)) = super::parse_attribute(content) {
+        lexer.extras.attributes.push((name, value, location));
+    } else if handle_directive(&mut lexer.extras, content, location) {
+        skip_excluded_region(lexer);
+    }
+    true
+}
+
+/// Moves the lexer past an excluded region, i.e. to the next branch of the innermost `{IF}` block that
+/// is included or past its `{END_IF}`. Nested blocks, comments and strings inside of the excluded region
+/// are skipped without being evaluated.
+fn skip_excluded_region(lexer: &mut Lexer<Token>) {
+    let source = lexer.remainder();
+    let offset = lexer.span().end;
+    let bytes = source.as_bytes();
+    let mut depth = 0;
+    let mut index = 0;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'(' | b'/' if bytes.get(index + 1) == Some(&b'*') => {
+                index = skip_comment(source, index);
+            }
+            b'/' if bytes.get(index + 1) == Some(&b'/') => {
+                index = source[index..].find('\n').map(|it| index + it).unwrap_or(bytes.len());
+            }
+            quote @ (b'\'' | b'"') => {
+                index = skip_string(bytes, index, quote);
+            }
+            b'{' => {
+                let Some(end) = source[index..].find('}').map(|it| index + it) else {
+                    break;
+                };
+                let content = &source[index + 1..end];
+                let location = offset + index..offset + end + 1;
+                index = end + 1;
+                let resume = match parse_directive(content) {
+                    Some(Directive::If(_)) => {
+                        depth += 1;
+                        false
+                    }
+                    Some(Directive::EndIf) if depth > 0 => {
+                        depth -= 1;
+                        false
+                    }
+                    Some(Directive::EndIf) => {
+                        lexer.extras.conditionals.pop();
+                        true
+                    }
+                    Some(Directive::Elsif(condition)) if depth == 0 => {
+                        include_branch(&mut lexer.extras, |extras| evaluate(extras, condition, &location))
+                    }
+                    Some(Directive::Else) if depth == 0 => include_branch(&mut lexer.extras, |_| true),
+                    _ => false,
+                };
+
+                if resume {
+                    lexer.bump(index);
+                    return;
+                }
+            }
+            _ => index += 1,
+        }
+    }
+
+    // the block was never closed, skip the rest of the file
+    lexer.bump(bytes.len());
+}
+
+/// includes the next branch of the innermost `{IF}` block if none of its branches was included before
+/// and the given condition holds
+fn include_branch(extras: &mut LexerExtras, condition: impl FnOnce(&mut LexerExtras) -> bool) -> bool {
+    let taken = extras.conditionals.last().is_none_or(|it| it.taken);
+    if !taken && condition(extras) {
+        if let Some(conditional) = extras.conditionals.last_mut() {
+            conditional.taken = true;
+        }
+        return true;
+    }
+    false
+}
+
+/// returns the index after the (possibly nested) comment starting at `start`
+fn skip_comment(source: &str, start: usize) -> usize {
+    let bytes = source.as_bytes();
+    let (open, close) = if bytes[start] == b'(' { (b'(', b')') } else { (b'/', b'/') };
+    let mut unclosed = 0;
+    let mut index = start;
+    while index + 1 < bytes.len() {
+        if bytes[index] == open && bytes[index + 1] == b'*' {
+            unclosed += 1;
+            index += 2;
+        } else if bytes[index] == b'*' && bytes[index + 1] == close {
+            unclosed -= 1;
+            index += 2;
+            if unclosed == 0 {
+                return index;
+            }
+        } else {
+            index += 1;
+        }
+    }
+    bytes.len()
+}
+
+/// returns the index after the string literal starting at `start`, `$` escapes the next character
+fn skip_string(bytes: &[u8], start: usize, quote: u8) -> usize {
+    let mut index = start + 1;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'$' => index += 2,
+            it if it == quote => return index + 1,
+            _ => index += 1,
+        }
+    }
+    bytes.len()
+}
+
+/// evaluates the condition of an `{IF}` or `{ELSIF}` pragma, an invalid condition is reported and
+/// treated as `false`
+fn evaluate(extras: &mut LexerExtras, condition: &str, location: &Range<usize>) -> bool {
+    let mut parser =
+        ConditionParser { tokens: tokenize(condition).into_iter().peekable(), defines: &extras.defines };
+    let result = parser.parse_or().and_then(|value| match parser.tokens.next() {
+        None => Ok(value),
+        Some(token) => Err(format!("unexpected `{token}`")),
+    });
+
+    result.unwrap_or_else(|reason| {
+        extras
+            .diagnostics
+            .push((format!("Invalid condition `{}`: {reason}", condition.trim()), location.clone()));
+        false
+    })
+}
+
+fn tokenize(condition: &str) -> Vec<String> {
+    let mut tokens = vec![];
+    let mut chars = condition.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        if c.is_whitespace() {
+            continue;
+        }
+
+        let end = if c.is_alphanumeric() || c == '_' {
+            let mut end = start + c.len_utf8();
+            while let Some((index, c)) = chars.next_if(|(_, c)| c.is_alphanumeric() || *c == '_') {
+                end = index + c.len_utf8();
+            }
+            end
+        } else if c == '\'' || c == '"' {
+            let mut end = condition.len();
+            for (index, next) in chars.by_ref() {
+                if next == c {
+                    end = index + 1;
+                    break;
+                }
+            }
+            end
+        } else {
+            start + c.len_utf8()
+        };
+        tokens.push(condition[start..end].to_string());
+    }
+    tokens
+}
+
+/// a recursive descent parser for conditions, the operators bind like in Structured Text
+/// (`NOT` before `AND` before `XOR` before `OR`)
+struct ConditionParser<'a> {
+    tokens: std::iter::Peekable<std::vec::IntoIter<String>>,
+    defines: &'a Defines,
+}
+
+impl ConditionParser<'_> {
+    fn consume_keyword(&mut self, keyword: &str) -> bool {
+        self.tokens.next_if(|it| it.eq_ignore_ascii_case(keyword)).is_some()
+    }
+
+    fn expect(&mut self, expected: &str) -> Result<(), String> {
+        match self.tokens.next() {
+            Some(token) if token == expected => Ok(()),
+            Some(token) => Err(format!("expected `{expected}` but found `{token}`")),
+            None => Err(format!("expected `{expected}`")),
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<bool, String> {
+        let mut value = self.parse_xor()?;
+        while self.consume_keyword("OR") {
+            value |= self.parse_xor()?;
+        }
+        Ok(value)
+    }
+
+    fn parse_xor(&mut self) -> Result<bool, String> {
+        let mut value = self.parse_and()?;
+        while self.consume_keyword("XOR") {
+            value ^= self.parse_and()?;
+        }
+        Ok(value)
+    }
+
+    fn parse_and(&mut self) -> Result<bool, String> {
+        let mut value = self.parse_not()?;
+        while self.consume_keyword("AND") || self.consume_keyword("&") {
+            value &= self.parse_not()?;
+        }
+        Ok(value)
+    }
+
+    fn parse_not(&mut self) -> Result<bool, String> {
+        if self.consume_keyword("NOT") {
+            return self.parse_not().map(|it| !it);
+        }
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> Result<bool, String> {
+        let Some(token) = self.tokens.next() else {
+            return Err("expected a condition".to_string());
+        };
+
+        if token == "(" {
+            let value = self.parse_or()?;
+            self.expect(")")?;
+            Ok(value)
+        } else if token.eq_ignore_ascii_case("defined") {
+            self.expect("(")?;
+            let name = self.parse_symbol()?;
+            self.expect(")")?;
+            Ok(self.defines.is_defined(&name))
+        } else if token.eq_ignore_ascii_case("hasvalue") {
+            self.expect("(")?;
+            let name = self.parse_symbol()?;
+            self.expect(",")?;
+            let value = self.tokens.next().filter(|it| it.len() > 1 && it.starts_with(['\'', '"']));
+            let Some(value) = value else {
+                return Err("expected a string literal".to_string());
+            };
+            self.expect(")")?;
+            Ok(self.defines.has_value(&name, &value[1..value.len() - 1]))
+        } else {
+            Err(format!("unexpected `{token}`"))
+        }
+    }
+
+    fn parse_symbol(&mut self) -> Result<String, String> {
+        match self.tokens.next() {
+            Some(name) if name.starts_with(|c: char| c.is_alphabetic() || c == '_') => Ok(name),
+            Some(token) => Err(format!("expected a symbol but found `{token}`")),
+            None => Err("expected a symbol".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Defines;
+
+    #[test]
+    fn defines_are_parsed_from_definitions() {
+        let defines = Defines::from_definitions(&["VARIANT_A", "Axes=2", " speed = fast ", "axes=3", ""]);
+
+        assert!(defines.is_defined("variant_a"));
+        assert!(defines.is_defined("SPEED"));
+        assert!(!defines.is_defined("VARIANT_B"));
+        assert!(defines.has_value("AXES", "3"));
+        assert!(!defines.has_value("AXES", "2"));
+        assert!(defines.has_value("speed", "fast"));
+        assert!(!defines.has_value("variant_a", ""));
+    }
+}
@@ -1,3 +1,5 @@
 // Copyright (c) 2020 Ghaith Hachem and Mathias Rieder
 #[cfg(test)]
+mod conditional_compilation_tests;
+#[cfg(test)]
 mod lexer_tests;
@@ -0,0 +1,177 @@
+use core::ops::Range;
+
+use plc_ast::provider::IdProvider;
+use plc_source::source_location::SourceLocationFactory;
+use pretty_assertions::assert_eq;
+
+use crate::lexer::{lex_with_defines, Defines, ParseSession, Token::*};
+
+fn lex_defined<'a>(source: &'a str, defines: &[&str]) -> ParseSession<'a> {
+    lex_with_defines(
+        source,
+        IdProvider::default(),
+        SourceLocationFactory::internal(source),
+        Defines::from_definitions(defines),
+    )
+}
+
+/// returns the slices of all tokens the lexer produces
+fn slices(lexer: &mut ParseSession) -> Vec<String> {
+    let mut slices = vec![];
+    while lexer.token != End {
+        slices.push(lexer.slice_and_advance());
+    }
+    slices
+}
+
+/// returns the messages and ranges of all reported diagnostics
+fn diagnostics(lexer: &ParseSession) -> Vec<(String, Range<usize>)> {
+    lexer
+        .diagnostics
+        .iter()
+        .map(|it| (it.get_message().to_string(), it.get_location().to_range().unwrap()))
+        .collect()
+}
+
+#[test]
+fn included_regions_are_lexed_and_excluded_regions_are_skipped() {
+    let source = "a {IF defined(X)} b {ELSE} c {END_IF} d";
+
+    let mut lexer = lex_defined(source, &["X"]);
+    assert_eq!(slices(&mut lexer), vec!["a", "b", "d"]);
+    assert!(lexer.diagnostics.is_empty());
+
+    let mut lexer = lex_defined(source, &[]);
+    assert_eq!(slices(&mut lexer), vec!["a", "c", "d"]);
+    assert!(lexer.diagnostics.is_empty());
+}
+
+#[test]
+fn only_the_first_matching_branch_is_included() {
+    let source = "{IF defined(A)} a {ELSIF defined(B)} b {ELSIF defined(C)} c {ELSE} d {END_IF}";
+
+    assert_eq!(slices(&mut lex_defined(source, &["A", "B"])), vec!["a"]);
+    assert_eq!(slices(&mut lex_defined(source, &["B", "C"])), vec!["b"]);
+    assert_eq!(slices(&mut lex_defined(source, &["c"])), vec!["c"]);
+    assert_eq!(slices(&mut lex_defined(source, &[])), vec!["d"]);
+}
+
+#[test]
+fn pragmas_are_case_insensitive_and_may_span_lines() {
+    let source = "{if Defined(x)\n  and not DEFINED(y)} a {else} b {end_if}";
+
+    assert_eq!(slices(&mut lex_defined(source, &["X"])), vec!["a"]);
+    assert_eq!(slices(&mut lex_defined(source, &["X", "Y"])), vec!["b"]);
+}
+
+#[test]
+fn nested_blocks_are_evaluated() {
+    let source = "
+        {IF defined(OUTER)}
+            a
+            {IF defined(INNER)} b {ELSE} c {END_IF}
+            d
+        {ELSE}
+            {IF defined(INNER)} e {ELSE} f {END_IF}
+        {END_IF}
+        g";
+
+    assert_eq!(slices(&mut lex_defined(source, &["OUTER", "INNER"])), vec!["a", "b", "d", "g"]);
+    assert_eq!(slices(&mut lex_defined(source, &["OUTER"])), vec!["a", "c", "d", "g"]);
+    assert_eq!(slices(&mut lex_defined(source, &["INNER"])), vec!["e", "g"]);
+    assert_eq!(slices(&mut lex_defined(source, &[])), vec!["f", "g"]);
+}
+
+#[test]
+fn conditions_support_values_and_boolean_operators() {
+    let source = "{IF (defined(A) OR defined(B)) AND hasvalue(AXES, '2')} a {ELSIF A XOR B} b {END_IF}";
+    assert_eq!(slices(&mut lex_defined(source, &["B", "AXES=2"])), vec!["a"]);
+
+    let source = "{IF (defined(A) OR defined(B)) AND hasvalue(AXES, '2')} a {ELSE} b {END_IF}";
+    assert_eq!(slices(&mut lex_defined(source, &["A", "AXES=3"])), vec!["b"]);
+
+    let source = "{IF defined(A) XOR defined(B)} a {ELSE} b {END_IF}";
+    assert_eq!(slices(&mut lex_defined(source, &["A", "B"])), vec!["b"]);
+}
+
+#[test]
+fn excluded_regions_produce_no_diagnostics() {
+    let source = r#"
+        {IF defined(X)}
+            ENDVAR 'string with $' quote' {IF defined(Y} (* nested (* comment *) *) {END_IF} ???
+            (* {END_IF} *) /* {ELSE} */ // {END_IF}
+            "{END_IF}" '{ELSE}'
+        {END_IF}
+        a"#;
+
+    let mut lexer = lex_defined(source, &[]);
+    assert_eq!(slices(&mut lexer), vec!["a"]);
+    assert!(lexer.diagnostics.is_empty(), "{:#?}", lexer.diagnostics);
+}
+
+#[test]
+fn locations_in_included_regions_are_not_affected() {
+    let source = "{IF defined(X)}\n  excluded\n{ELSE}\n  included {END_IF} after";
+
+    let mut lexer = lex_defined(source, &[]);
+    assert_eq!(lexer.slice(), "included");
+    assert_eq!(lexer.range(), 36..44);
+    assert_eq!(lexer.location().get_line(), 3);
+
+    lexer.advance();
+    assert_eq!(lexer.slice(), "after");
+    assert_eq!(&source[lexer.range()], "after");
+}
+
+#[test]
+fn other_pragmas_are_still_ignored() {
+    let mut lexer = lex_defined("a {attribute 'hide'} {iffy} {ref} b", &[]);
+
+    assert_eq!(lexer.slice_and_advance(), "a");
+    assert_eq!(lexer.token, PropertyByRef);
+    lexer.advance();
+    assert_eq!(lexer.slice(), "b");
+}
+
+#[test]
+fn unmatched_pragmas_are_reported() {
+    let source = "a {ELSE} b {END_IF} c {ELSIF defined(X)} d {IF defined(X)} e";
+    let mut lexer = lex_defined(source, &["X"]);
+
+    assert_eq!(slices(&mut lexer), vec!["a", "b", "c", "d", "e"]);
+    assert_eq!(
+        diagnostics(&lexer),
+        vec![
+            ("`{ELSE}` without a matching `{IF}`".to_string(), 2..8),
+            ("`{END_IF}` without a matching `{IF}`".to_string(), 11..19),
+            ("`{ELSIF}` without a matching `{IF}`".to_string(), 22..40),
+            ("Missing `{END_IF}` for `{IF}`".to_string(), 43..58),
+        ]
+    );
+    assert!(lexer.diagnostics.iter().all(|it| it.get_error_code() == "E122"));
+}
+
+#[test]
+fn unclosed_excluded_block_is_reported() {
+    let mut lexer = lex_defined("a {IF defined(X)} b", &[]);
+
+    assert_eq!(slices(&mut lexer), vec!["a"]);
+    assert_eq!(diagnostics(&lexer), vec![("Missing `{END_IF}` for `{IF}`".to_string(), 2..17)]);
+}
+
+#[test]
+fn invalid_conditions_are_reported_and_evaluate_to_false() {
+    let source = "{IF defined(X} a {ELSIF X = 1} b {ELSIF hasvalue(X, 1)} c {ELSIF} d {ELSE} e {END_IF}";
+    let mut lexer = lex_defined(source, &["X"]);
+
+    assert_eq!(slices(&mut lexer), vec!["e"]);
+    assert_eq!(
+        diagnostics(&lexer),
+        vec![
+            ("Invalid condition `defined(X`: expected `)`".to_string(), 0..14),
+            ("Invalid condition `X = 1`: unexpected `X`".to_string(), 17..30),
+            ("Invalid condition `hasvalue(X, 1)`: expected a string literal".to_string(), 33..55),
+            ("Invalid condition ``: expected a condition".to_string(), 58..65),
+        ]
+    );
+}
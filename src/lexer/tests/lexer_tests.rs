@@ -150,6 +150,70 @@ fn action_tokens() {
     assert_eq!(lexer.token, KeywordEndActions);
 }
 
+#[test]
+fn namespace_tokens() {
+    let mut lexer = lex("NAMESPACE END_NAMESPACE ENDNAMESPACE USING");
+    assert_eq!(lexer.token, KeywordNamespace);
+    lexer.advance();
+    assert_eq!(lexer.token, KeywordEndNamespace);
+    lexer.advance();
+    assert_eq!(lexer.token, KeywordEndNamespace);
+    lexer.advance();
+    assert_eq!(lexer.token, KeywordUsing);
+}
+
+#[test]
+fn sfc_tokens() {
+    let mut lexer = lex("INITIAL_STEP END_STEP ENDSTEP TRANSITION END_TRANSITION ENDTRANSITION STEP FROM");
+    assert_eq!(lexer.token, KeywordInitialStep);
+    lexer.advance();
+    assert_eq!(lexer.token, KeywordEndStep);
+    lexer.advance();
+    assert_eq!(lexer.token, KeywordEndStep);
+    lexer.advance();
+    assert_eq!(lexer.token, KeywordTransition);
+    lexer.advance();
+    assert_eq!(lexer.token, KeywordEndTransition);
+    lexer.advance();
+    assert_eq!(lexer.token, KeywordEndTransition);
+    // `STEP` and `FROM` are only keywords within a chart
+    lexer.advance();
+    assert_eq!(lexer.token, Identifier);
+    lexer.advance();
+    assert_eq!(lexer.token, Identifier);
+}
+
+#[test]
+fn configuration_tokens() {
+    let mut lexer =
+        lex("CONFIGURATION END_CONFIGURATION ENDCONFIGURATION RESOURCE END_RESOURCE ENDRESOURCE TASK WITH");
+    assert_eq!(lexer.token, KeywordConfiguration);
+    lexer.advance();
+    assert_eq!(lexer.token, KeywordEndConfiguration);
+    lexer.advance();
+    assert_eq!(lexer.token, KeywordEndConfiguration);
+    lexer.advance();
+    assert_eq!(lexer.token, KeywordResource);
+    lexer.advance();
+    assert_eq!(lexer.token, KeywordEndResource);
+    lexer.advance();
+    assert_eq!(lexer.token, KeywordEndResource);
+    lexer.advance();
+    assert_eq!(lexer.token, KeywordTask);
+    lexer.advance();
+    assert_eq!(lexer.token, KeywordWith);
+}
+
+#[test]
+fn union_tokens() {
+    let mut lexer = lex("UNION END_UNION ENDUNION");
+    assert_eq!(lexer.token, KeywordUnion);
+    lexer.advance();
+    assert_eq!(lexer.token, KeywordEndUnion);
+    lexer.advance();
+    assert_eq!(lexer.token, KeywordEndUnion);
+}
+
 #[test]
 fn var_tokens() {
     let mut lexer = lex("VAR VAR_INPUT VAR_OUTPUT VAR_GLOBAL VAR_IN_OUT END_VAR");
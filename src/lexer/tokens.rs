@@ -3,6 +3,7 @@ use logos::Logos;
 use plc_ast::ast::{DirectAccessType, HardwareAccessType};
 
 #[derive(Debug, PartialEq, Eq, Logos, Clone, Copy)]
+#[logos(extras = super::conditionals::LexerExtras)]
 pub enum Token {
     #[error]
     #[regex(r"\(\*", |lex| super::parse_comments(lex))]
@@ -49,6 +50,36 @@ pub enum Token {
     #[token("ENDINTERFACE", ignore(case))]
     KeywordEndInterface,
 
+    #[token("NAMESPACE", ignore(case))]
+    KeywordNamespace,
+
+    #[token("END_NAMESPACE", ignore(case))]
+    #[token("ENDNAMESPACE", ignore(case))]
+    KeywordEndNamespace,
+
+    #[token("USING", ignore(case))]
+    KeywordUsing,
+
+    #[token("CONFIGURATION", ignore(case))]
+    KeywordConfiguration,
+
+    #[token("END_CONFIGURATION", ignore(case))]
+    #[token("ENDCONFIGURATION", ignore(case))]
+    KeywordEndConfiguration,
+
+    #[token("RESOURCE", ignore(case))]
+    KeywordResource,
+
+    #[token("END_RESOURCE", ignore(case))]
+    #[token("ENDRESOURCE", ignore(case))]
+    KeywordEndResource,
+
+    #[token("TASK", ignore(case))]
+    KeywordTask,
+
+    #[token("WITH", ignore(case))]
+    KeywordWith,
+
     #[token("PROPERTY", ignore(case))]
     KeywordProperty,
 
@@ -56,6 +87,12 @@ pub enum Token {
     #[token("ENDPROPERTY", ignore(case))]
     KeywordEndProperty,
 
+    #[token("END_GET", ignore(case))]
+    KeywordEndGet,
+
+    #[token("END_SET", ignore(case))]
+    KeywordEndSet,
+
     #[token("VAR_INPUT", ignore(case))]
     #[token("VARINPUT", ignore(case))]
     KeywordVarInput,
@@ -70,6 +107,9 @@ pub enum Token {
     #[token("VAR_CONFIG", ignore(case))]
     KeywordVarConfig,
 
+    #[token("VAR_ACCESS", ignore(case))]
+    KeywordVarAccess,
+
     #[token("ABSTRACT", ignore(case))]
     KeywordAbstract,
 
@@ -89,6 +129,9 @@ pub enum Token {
     #[token("NONRETAIN", ignore(case))]
     KeywordNonRetain,
 
+    #[token("PERSISTENT", ignore(case))]
+    KeywordPersistent,
+
     #[token("VAR_TEMP", ignore(case))]
     #[token("VARTEMP", ignore(case))]
     KeywordVarTemp,
@@ -112,6 +155,12 @@ pub enum Token {
     #[token("OVERRIDE", ignore(case))]
     KeywordOverride,
 
+    #[token("THIS", ignore(case))]
+    KeywordThis,
+
+    #[token("SUPER", ignore(case))]
+    KeywordSuper,
+
     #[token("VAR_GLOBAL", ignore(case))]
     #[token("VARGLOBAL", ignore(case))]
     KeywordVarGlobal,
@@ -160,6 +209,13 @@ pub enum Token {
     #[token("ENDSTRUCT", ignore(case))]
     KeywordEndStruct,
 
+    #[token("UNION", ignore(case))]
+    KeywordUnion,
+
+    #[token("END_UNION", ignore(case))]
+    #[token("ENDUNION", ignore(case))]
+    KeywordEndUnion,
+
     #[token("ACTIONS", ignore(case))]
     KeywordActions,
 
@@ -174,6 +230,20 @@ pub enum Token {
     #[token("ENDACTIONS", ignore(case))]
     KeywordEndActions,
 
+    #[token("INITIAL_STEP", ignore(case))]
+    KeywordInitialStep,
+
+    #[token("END_STEP", ignore(case))]
+    #[token("ENDSTEP", ignore(case))]
+    KeywordEndStep,
+
+    #[token("TRANSITION", ignore(case))]
+    KeywordTransition,
+
+    #[token("END_TRANSITION", ignore(case))]
+    #[token("ENDTRANSITION", ignore(case))]
+    KeywordEndTransition,
+
     #[token(":")]
     KeywordColon,
 
@@ -304,6 +374,18 @@ pub enum Token {
     #[token("ENDCASE", ignore(case))]
     KeywordEndCase,
 
+    #[token("__TRY", ignore(case))]
+    KeywordTry,
+
+    #[token("__CATCH", ignore(case))]
+    KeywordCatch,
+
+    #[token("__FINALLY", ignore(case))]
+    KeywordFinally,
+
+    #[token("__ENDTRY", ignore(case))]
+    KeywordEndTry,
+
     //Operators
     #[token("+")]
     OperatorPlus,
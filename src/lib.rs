@@ -41,6 +41,7 @@ pub mod lowering;
 pub mod output;
 pub mod parser;
 pub mod resolver;
+pub mod task_configuration;
 mod test_utils;
 
 pub mod typesystem;
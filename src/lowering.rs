@@ -1,5 +1,5 @@
 use crate::{
-    index::{get_init_fn_name, Index, PouIndexEntry, VariableIndexEntry},
+    index::{get_init_fn_name, Index, PouIndexEntry, VariableIndexEntry, FB_INIT_METHOD_NAME},
     resolver::const_evaluator::UnresolvableConstant,
 };
 use initializers::{Init, InitAssignments, Initializers, GLOBAL_SCOPE};
@@ -12,13 +12,22 @@ use plc_ast::{
     provider::IdProvider,
 };
 use plc_source::source_location::SourceLocation;
+use plc_util::convention::qualified_name;
 
 pub mod calls;
+pub mod enums;
+pub mod generics;
+pub mod inheritance;
 mod initializers;
+pub mod namespaces;
+pub mod property;
+pub mod sfc;
 
 pub struct InitVisitor {
     index: Index,
     unresolved_initializers: Initializers,
+    /// the arguments passed to the `FB_INIT` method of instances, keyed by their container and variable name
+    init_arguments: Initializers,
     var_config_initializers: Vec<AstNode>,
     ctxt: Context,
 }
@@ -48,6 +57,7 @@ impl InitVisitor {
         Self {
             index,
             unresolved_initializers: Initializers::new(&unresolved_initializers),
+            init_arguments: Initializers::default(),
             var_config_initializers: vec![],
             ctxt: Context::new(id_provider),
         }
@@ -213,7 +223,7 @@ impl InitVisitor {
         });
 
         // collect necessary call statements to init-functions
-        let delegated_calls = self
+        let instances = self
             .index
             .get_pou_members(&implementation.name)
             .iter()
@@ -221,21 +231,32 @@ impl InitVisitor {
             .filter_map(|var| {
                 let dti =
                     self.index.get_effective_type_or_void_by_name(var.get_type_name()).get_type_information();
-                if dti.is_struct() {
-                    Some(create_call_statement(
-                        &get_init_fn_name(dti.get_name()),
-                        var.get_name(),
-                        None,
-                        self.ctxt.get_id_provider(),
-                        &implementation.name_location,
-                    ))
-                } else {
-                    None
-                }
-            });
+                dti.is_struct().then(|| (var.get_name(), dti.get_name()))
+            })
+            .collect::<Vec<_>>();
+        let delegated_calls = instances.iter().map(|(var_name, type_name)| {
+            create_call_statement(
+                &get_init_fn_name(type_name),
+                var_name,
+                None,
+                self.ctxt.get_id_provider(),
+                &implementation.name_location,
+            )
+        });
+        // the instances are initialized before any of their `FB_INIT` methods is called
+        let fb_init_calls = instances.iter().filter_map(|(var_name, type_name)| {
+            self.create_fb_init_call(
+                &implementation.name,
+                var_name,
+                type_name,
+                None,
+                &implementation.name_location,
+            )
+        });
 
         let stmts = assignments
             .chain(delegated_calls)
+            .chain(fb_init_calls)
             .chain(std::mem::take(&mut implementation.statements))
             .collect::<Vec<_>>();
         implementation.statements = stmts;
@@ -246,7 +267,7 @@ impl InitVisitor {
     fn update_struct_initializers(&mut self, user_type: &mut plc_ast::ast::UserTypeDeclaration) {
         let effective_type =
             user_type.data_type.get_name().and_then(|it| self.index.find_effective_type_by_name(it));
-        if let DataType::StructType { .. } = &user_type.data_type {
+        if let DataType::StructType { .. } | DataType::UnionType { .. } = &user_type.data_type {
             let Some(ty) = effective_type else {
                 return user_type.walk(self);
             };
@@ -295,6 +316,38 @@ impl InitVisitor {
         self.unresolved_initializers.maybe_insert_initializer(GLOBAL_SCOPE, Some(variable.get_name()), &None);
     }
 
+    fn collect_init_arguments(&mut self, variable: &plc_ast::ast::Variable) {
+        if variable.init_arguments.is_none() {
+            return;
+        }
+
+        let scope = self.ctxt.get_scope().clone().unwrap_or(GLOBAL_SCOPE.to_string());
+        self.init_arguments.insert_initializer(&scope, Some(&variable.name), &variable.init_arguments);
+    }
+
+    /// Creates a call to the `FB_INIT` method of the instance `var_name` declared in `container`, passing the
+    /// arguments given with its declaration. Returns `None` if the instance's type does not declare `FB_INIT`
+    /// itself, inherited `FB_INIT` methods are called by the init-function of the derived type.
+    fn create_fb_init_call(
+        &self,
+        container: &str,
+        var_name: &str,
+        type_name: &str,
+        base_ident: Option<&str>,
+        location: &SourceLocation,
+    ) -> Option<AstNode> {
+        self.index.find_pou(&qualified_name(type_name, FB_INIT_METHOD_NAME))?;
+        let arguments = self.init_arguments.get(container).and_then(|it| it.get(var_name)).cloned().flatten();
+        Some(create_method_call(
+            FB_INIT_METHOD_NAME,
+            var_name,
+            base_ident,
+            arguments,
+            self.ctxt.get_id_provider(),
+            location,
+        ))
+    }
+
     fn collect_var_config_assignments(&mut self, var_config: &[ConfigVariable]) {
         let assignments = var_config.iter().map(|var| {
             AstFactory::create_assignment(var.reference.clone(), var.address.clone(), self.ctxt.next_id())
@@ -316,6 +369,7 @@ impl AstVisitorMut for InitVisitor {
 
     fn visit_variable(&mut self, variable: &mut plc_ast::ast::Variable) {
         self.maybe_add_global_instance_initializer(variable);
+        self.collect_init_arguments(variable);
         self.update_initializer(variable);
         variable.walk(self);
     }
@@ -326,7 +380,10 @@ impl AstVisitorMut for InitVisitor {
     }
 
     fn visit_data_type(&mut self, data_type: &mut DataType) {
-        if matches!(data_type, plc_ast::ast::DataType::StructType { .. }) {
+        if matches!(
+            data_type,
+            plc_ast::ast::DataType::StructType { .. } | plc_ast::ast::DataType::UnionType { .. }
+        ) {
             self.walk_with_scope(data_type, data_type.get_name().map(ToOwned::to_owned))
         } else {
             data_type.walk(self)
@@ -448,3 +505,21 @@ fn create_call_statement(
     );
     AstFactory::create_call_statement(op, Some(param), id_provider.next_id(), location.clone())
 }
+
+/// Creates a call to the method `method` of the instance `member_id`, e.g. `self.fb.FB_INIT(nAxis := 3)`
+fn create_method_call(
+    method: &str,
+    member_id: &str,
+    base_id: Option<&str>,
+    arguments: Option<AstNode>,
+    mut id_provider: IdProvider,
+    location: &SourceLocation,
+) -> AstNode {
+    let instance = create_member_reference(
+        member_id,
+        id_provider.clone(),
+        base_id.map(|it| create_member_reference(it, id_provider.clone(), None)),
+    );
+    let op = create_member_reference(method, id_provider.clone(), Some(instance));
+    AstFactory::create_call_statement(op, arguments, id_provider.next_id(), location.clone())
+}
@@ -6,8 +6,8 @@ use std::{borrow::BorrowMut, sync::atomic::AtomicI32};
 
 use plc_ast::{
     ast::{
-        flatten_expression_list, steal_expression_list, AccessModifier, Allocation, Assignment, AstFactory,
-        AstNode, AstStatement, CallStatement, CompilationUnit, LinkageType, Pou, Variable, VariableBlock,
+        flatten_expression_list, steal_expression_list, Allocation, Assignment, AstFactory, AstNode,
+        AstStatement, CallStatement, CompilationUnit, LinkageType, Pou, Variable, VariableBlock,
         VariableBlockType,
     },
     control_statements::{AstControlStatement, ConditionalBlock, LoopStatement},
@@ -137,6 +137,11 @@ impl AstVisitorMut for AggregateTypeLowerer {
             return;
         }
         unit.walk(self);
+
+        // interface methods need the same signature as their implementations
+        for method in unit.interfaces.iter_mut().flat_map(|it| it.methods.iter_mut()) {
+            self.visit_pou(method);
+        }
     }
     // Change the signature for functions/methods with aggregate returns
     fn visit_pou(&mut self, pou: &mut Pou) {
@@ -158,19 +163,23 @@ impl AstVisitorMut for AggregateTypeLowerer {
             if data_type.is_aggregate_type() {
                 //Insert a new in out var to the pou variable block declarations
                 let block = VariableBlock {
-                    access: AccessModifier::Public,
+                    access: None,
                     constant: false,
                     retain: false,
+                    persistent: false,
                     variables: vec![Variable {
                         name: pou.get_return_name().to_string(),
                         data_type_declaration: return_var,
                         initializer: None,
                         address: None,
+                        init_arguments: None,
                         location: pou.name_location.clone(),
+                        attributes: vec![],
                     }],
                     variable_block_type: VariableBlockType::InOut,
                     linkage: LinkageType::Internal,
                     location: SourceLocation::internal(),
+                    attributes: vec![],
                 };
                 pou.variable_blocks.insert(0, block)
             } else {
@@ -346,6 +355,13 @@ impl AstVisitorMut for AggregateTypeLowerer {
                 );
                 self.steal_and_walk_list(&mut stmt.else_block);
             }
+            AstControlStatement::Try(stmt) => {
+                self.steal_and_walk_list(&mut stmt.body);
+                if let Some(catch_block) = &mut stmt.catch_block {
+                    self.steal_and_walk_list(&mut catch_block.body);
+                }
+                self.steal_and_walk_list(&mut stmt.finally_block);
+            }
         }
     }
 }
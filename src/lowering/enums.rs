@@ -0,0 +1,164 @@
+//! Declares the string conversions of user-defined enums.
+//!
+//! Every enum declared in a `TYPE` block gets conversions from and to `STRING` and `WSTRING`, named after
+//! the conversion functions of the standard library:
+//! ```iecst
+//! TYPE Color : (Red, Green, Blue); END_TYPE
+//!
+//! FUNCTION Color_TO_STRING : STRING       // also Color_TO_WSTRING : WSTRING
+//! VAR_INPUT
+//!     in : Color;
+//! END_VAR
+//! END_FUNCTION
+//!
+//! FUNCTION TO_STRING__Color : STRING      // also TO_WSTRING__Color : WSTRING
+//! VAR_INPUT
+//!     in : Color;
+//! END_VAR
+//! END_FUNCTION
+//!
+//! FUNCTION STRING_TO_Color : Color        // also WSTRING_TO_Color
+//! VAR_INPUT
+//!     in : STRING;
+//! END_VAR
+//! END_FUNCTION
+//! ```
+//! `TO_STRING__Color` and `TO_WSTRING__Color` are the implementations of the generic `TO_STRING` and
+//! `TO_WSTRING` functions for the enum. The functions are declared without a body, their code is generated
+//! from the name table of the enum by every unit calling them. A conversion is not declared if the project
+//! already declares a POU of the same name.
+
+use plc_ast::ast::{
+    ArgumentProperty, Attribute, CompilationUnit, DataType, DataTypeDeclaration, Implementation, LinkageType,
+    Pou, PouType, Variable, VariableBlock, VariableBlockType,
+};
+use plc_source::source_location::SourceLocation;
+use rustc_hash::FxHashSet;
+
+use crate::{
+    index::PouIndexEntry,
+    typesystem::{STRING_TYPE, WSTRING_TYPE},
+};
+
+/// The attribute marking the generated conversions of an enum
+pub const ENUM_CONVERSION_ATTRIBUTE: &str = "__enum_conversion";
+
+/// Returns true if the given POU is one of the generated conversions of an enum
+pub fn is_enum_conversion(pou: &PouIndexEntry) -> bool {
+    pou.get_attribute(ENUM_CONVERSION_ATTRIBUTE).is_some()
+}
+
+#[derive(Default)]
+pub struct EnumConversionLowerer;
+
+impl EnumConversionLowerer {
+    /// Declares the conversions of the enums of the given units. All units of a project need to be lowered
+    /// together, since a conversion must not clash with a POU declared in another unit.
+    pub fn lower_enums(&mut self, units: &mut [CompilationUnit]) {
+        let mut declared = units
+            .iter()
+            .flat_map(|unit| unit.units.iter().map(|pou| pou.name.to_lowercase()))
+            .collect::<FxHashSet<_>>();
+
+        for unit in units.iter_mut() {
+            let enums = unit
+                .user_types
+                .iter()
+                .filter_map(|it| match &it.data_type {
+                    DataType::EnumType { name: Some(name), .. } if !name.starts_with("__") => {
+                        Some((name.clone(), it.location.clone()))
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+
+            for (name, location) in enums {
+                for (pou_name, input, output) in get_conversions(&name) {
+                    if !declared.insert(pou_name.to_lowercase()) {
+                        continue;
+                    }
+                    let (pou, implementation) = create_conversion(pou_name, input, output, &location);
+                    unit.units.push(pou);
+                    unit.implementations.push(implementation);
+                }
+            }
+        }
+    }
+}
+
+/// Returns the name, the input type and the return type of each conversion of the given enum
+fn get_conversions(enum_name: &str) -> [(String, &str, &str); 6] {
+    [
+        (format!("{enum_name}_TO_{STRING_TYPE}"), enum_name, STRING_TYPE),
+        (format!("{enum_name}_TO_{WSTRING_TYPE}"), enum_name, WSTRING_TYPE),
+        (format!("TO_{STRING_TYPE}__{enum_name}"), enum_name, STRING_TYPE),
+        (format!("TO_{WSTRING_TYPE}__{enum_name}"), enum_name, WSTRING_TYPE),
+        (format!("{STRING_TYPE}_TO_{enum_name}"), STRING_TYPE, enum_name),
+        (format!("{WSTRING_TYPE}_TO_{enum_name}"), WSTRING_TYPE, enum_name),
+    ]
+}
+
+fn create_conversion(
+    name: String,
+    input: &str,
+    output: &str,
+    location: &SourceLocation,
+) -> (Pou, Implementation) {
+    let type_reference = |referenced_type: &str| DataTypeDeclaration::DataTypeReference {
+        referenced_type: referenced_type.to_string(),
+        location: location.clone(),
+    };
+    let input = VariableBlock {
+        variables: vec![Variable {
+            name: "in".to_string(),
+            data_type_declaration: type_reference(input),
+            initializer: None,
+            address: None,
+            init_arguments: None,
+            location: location.clone(),
+            attributes: vec![],
+        }],
+        variable_block_type: VariableBlockType::Input(ArgumentProperty::ByVal),
+        linkage: LinkageType::External,
+        location: location.clone(),
+        ..Default::default()
+    };
+
+    let pou = Pou {
+        name: name.clone(),
+        kind: PouType::Function,
+        variable_blocks: vec![input],
+        return_type: Some(type_reference(output)),
+        location: location.clone(),
+        name_location: location.clone(),
+        poly_mode: None,
+        generics: vec![],
+        generic_arguments: vec![],
+        linkage: LinkageType::External,
+        super_class: None,
+        interfaces: vec![],
+        properties: vec![],
+        is_const: false,
+        attributes: vec![Attribute {
+            name: ENUM_CONVERSION_ATTRIBUTE.to_string(),
+            value: None,
+            location: location.clone(),
+        }],
+        sfc: None,
+    };
+
+    let implementation = Implementation {
+        name: name.clone(),
+        type_name: name,
+        linkage: LinkageType::External,
+        pou_type: PouType::Function,
+        statements: vec![],
+        location: location.clone(),
+        name_location: location.clone(),
+        overriding: false,
+        generic: false,
+        access: None,
+    };
+
+    (pou, implementation)
+}
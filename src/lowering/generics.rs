@@ -0,0 +1,518 @@
+//! Monomorphises generic `FUNCTION_BLOCK`s and `CLASS`es.
+//!
+//! A generic function block or class is a template, which is not compiled itself. Every distinct list of
+//! type arguments it is instantiated with creates a copy of the template, its methods and its actions, in
+//! which the generic symbols are replaced by the type arguments. The copy is named after the template and
+//! its type arguments, following the naming convention of generic function implementations:
+//! ```iecst
+//! FUNCTION_BLOCK FB_Fifo<T: ANY>
+//! VAR
+//!     buffer : ARRAY[0..9] OF T;
+//! END_VAR
+//!     METHOD push
+//!     VAR_INPUT
+//!         value : T;
+//!     END_VAR
+//!     END_METHOD
+//! END_FUNCTION_BLOCK
+//!
+//! PROGRAM main
+//! VAR
+//!     fifo : FB_Fifo<REAL>;   // becomes `fifo : FB_Fifo__REAL`
+//! END_VAR
+//! END_PROGRAM
+//! ```
+//! creates the function block `FB_Fifo__REAL` with a `buffer : ARRAY[0..9] OF REAL` and the method
+//! `FB_Fifo__REAL.push`. Instances may be nested, e.g. `FB_Fifo<FB_Pair<INT, REAL>>`, and a template may
+//! instantiate other templates with its own generic symbols. References to a template with the wrong
+//! number of type arguments are left untouched and reported as unknown types.
+
+use std::collections::VecDeque;
+
+use plc_ast::{
+    ast::{
+        CompilationUnit, DataType, DataTypeDeclaration, GenericArgument, Implementation, Pou, PouType,
+        PropertyBlock, Variable,
+    },
+    mut_visitor::{AstVisitorMut, WalkerMut},
+    provider::IdProvider,
+};
+use plc_source::source_location::SourceLocation;
+use plc_util::convention::NAMESPACE_SEPARATOR;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+pub struct GenericsLowerer {
+    pub id_provider: IdProvider,
+}
+
+impl GenericsLowerer {
+    pub fn new(id_provider: IdProvider) -> Self {
+        Self { id_provider }
+    }
+
+    /// Replaces all generic function blocks and classes of the given units by their instances. All units
+    /// of a project need to be lowered together, since a template may be instantiated in another unit.
+    pub fn lower_generics(&mut self, units: &mut [CompilationUnit]) {
+        let templates = collect_templates(units);
+        if templates.is_empty() {
+            return;
+        }
+
+        let mut resolver = TypeArgumentResolver::new(&templates);
+        units.iter_mut().for_each(|unit| resolver.resolve_unit(unit));
+
+        // instances may instantiate further templates, so we keep going until no new instances show up
+        while let Some(instantiation) = resolver.pending.pop_front() {
+            let template = &templates[instantiation.template];
+            let (mut pous, mut implementations) = template.instantiate(&instantiation, &self.id_provider);
+            pous.iter_mut().for_each(|pou| resolver.resolve_pou(pou));
+
+            let unit = &mut units[template.unit];
+            unit.units.append(&mut pous);
+            unit.implementations.append(&mut implementations);
+        }
+    }
+}
+
+/// A generic function block or class together with its methods and implementations
+struct Template {
+    /// The index of the unit the template was declared in
+    unit: usize,
+    pou: Pou,
+    methods: Vec<Pou>,
+    implementations: Vec<Implementation>,
+}
+
+/// Removes all generic function blocks and classes, their methods and implementations from the given units
+fn collect_templates(units: &mut [CompilationUnit]) -> Vec<Template> {
+    let mut templates = vec![];
+    for (index, unit) in units.iter_mut().enumerate() {
+        let (generic, pous): (Vec<_>, Vec<_>) =
+            std::mem::take(&mut unit.units).into_iter().partition(|pou| {
+                matches!(pou.kind, PouType::FunctionBlock | PouType::Class) && !pou.generics.is_empty()
+            });
+        unit.units = pous;
+
+        for pou in generic {
+            let is_member = |parent: &str| parent.eq_ignore_ascii_case(&pou.name);
+            let (methods, pous) = std::mem::take(&mut unit.units)
+                .into_iter()
+                .partition(|it| matches!(&it.kind, PouType::Method { parent } if is_member(parent)));
+            unit.units = pous;
+
+            let (implementations, others) =
+                std::mem::take(&mut unit.implementations).into_iter().partition(|it| {
+                    is_member(&it.type_name)
+                        || matches!(&it.pou_type, PouType::Method { parent } if is_member(parent))
+                });
+            unit.implementations = others;
+
+            templates.push(Template { unit: index, pou, methods, implementations });
+        }
+    }
+
+    templates
+}
+
+impl Template {
+    /// Creates a copy of this template, its methods and implementations for the given type arguments
+    fn instantiate(
+        &self,
+        instantiation: &Instantiation,
+        id_provider: &IdProvider,
+    ) -> (Vec<Pou>, Vec<Implementation>) {
+        let name = instance_name(&self.pou.name, &instantiation.arguments);
+        let rename = |it: &str| match it.get(..self.pou.name.len()) {
+            Some(prefix) if prefix.eq_ignore_ascii_case(&self.pou.name) => {
+                format!("{name}{}", &it[self.pou.name.len()..])
+            }
+            _ => it.to_string(),
+        };
+
+        let generic_arguments = self
+            .pou
+            .generics
+            .iter()
+            .zip(instantiation.arguments.iter())
+            .map(|(binding, type_name)| GenericArgument {
+                binding: binding.clone(),
+                type_name: type_name.clone(),
+                location: instantiation.location.clone(),
+            })
+            .collect::<Vec<_>>();
+
+        let mut instantiator = Instantiator {
+            id_provider: id_provider.clone(),
+            substitutions: generic_arguments
+                .iter()
+                .map(|it| (it.binding.name.to_lowercase(), it.type_name.clone()))
+                .collect(),
+            rename: &rename,
+        };
+
+        let mut pou = self.pou.clone();
+        pou.name = name.clone();
+        pou.generics.clear();
+        pou.generic_arguments = generic_arguments;
+        instantiator.instantiate_pou(&mut pou);
+
+        let mut pous = vec![pou];
+        for method in &self.methods {
+            let mut method = method.clone();
+            method.name = rename(&method.name);
+            method.kind = PouType::Method { parent: name.clone() };
+            instantiator.instantiate_pou(&mut method);
+            pous.push(method);
+        }
+
+        let implementations = self
+            .implementations
+            .iter()
+            .map(|implementation| {
+                let mut implementation = implementation.clone();
+                implementation.name = rename(&implementation.name);
+                implementation.type_name = rename(&implementation.type_name);
+                if let PouType::Method { parent } = &mut implementation.pou_type {
+                    *parent = name.clone();
+                }
+                implementation.generic = false;
+                instantiator.visit_implementation(&mut implementation);
+                implementation
+            })
+            .collect();
+
+        (pous, implementations)
+    }
+}
+
+/// Builds the name of a template's instance, e.g. `FB_Fifo__REAL` for `FB_Fifo<REAL>`
+fn instance_name(template: &str, arguments: &[String]) -> String {
+    arguments.iter().fold(template.to_string(), |name, argument| {
+        format!("{name}__{}", argument.replace(NAMESPACE_SEPARATOR, "_").replace('.', "_"))
+    })
+}
+
+/// Splits a reference to a template into its name and type arguments, e.g. `FB_Fifo<FB_Pair<INT,REAL>>`
+/// into `FB_Fifo` and `[FB_Pair<INT,REAL>]`
+fn split_type_arguments(reference: &str) -> Option<(&str, Vec<String>)> {
+    let (name, arguments) = reference.split_once('<')?;
+    let arguments = arguments.strip_suffix('>')?;
+
+    let mut result = vec![];
+    let mut current = String::new();
+    let mut depth = 0;
+    for c in arguments.chars() {
+        match c {
+            ',' if depth == 0 => result.push(std::mem::take(&mut current)),
+            '<' => depth += 1,
+            '>' => depth -= 1,
+            _ => {}
+        }
+        if c != ',' || depth != 0 {
+            current.push(c);
+        }
+    }
+    result.push(current);
+
+    Some((name, result))
+}
+
+/// A requested instance of a template
+struct Instantiation {
+    template: usize,
+    arguments: Vec<String>,
+    /// The location of the first reference requesting this instance
+    location: SourceLocation,
+}
+
+/// Rewrites references to templates, e.g. `FB_Fifo<REAL>`, to the names of their instances and collects
+/// the instances which need to be created
+struct TypeArgumentResolver<'a> {
+    templates: &'a [Template],
+    /// The templates, keyed by their lower case name and by the lower case name without namespace
+    names: FxHashMap<String, Vec<usize>>,
+    instances: FxHashSet<String>,
+    pending: VecDeque<Instantiation>,
+}
+
+impl<'a> TypeArgumentResolver<'a> {
+    fn new(templates: &'a [Template]) -> Self {
+        let mut names: FxHashMap<String, Vec<usize>> = FxHashMap::default();
+        for (index, template) in templates.iter().enumerate() {
+            let name = template.pou.name.to_lowercase();
+            if let Some((_, unqualified)) = name.rsplit_once(NAMESPACE_SEPARATOR) {
+                names.entry(unqualified.to_string()).or_default().push(index);
+            }
+            names.entry(name).or_default().push(index);
+        }
+
+        Self { templates, names, instances: FxHashSet::default(), pending: VecDeque::new() }
+    }
+
+    /// Rewrites the references to templates in all declarations of the given unit
+    fn resolve_unit(&mut self, unit: &mut CompilationUnit) {
+        unit.global_vars.iter_mut().for_each(|block| self.visit_variable_block(block));
+        unit.user_types.iter_mut().for_each(|user_type| self.visit_user_type_declaration(user_type));
+        unit.units.iter_mut().for_each(|pou| self.resolve_pou(pou));
+        for interface in unit.interfaces.iter_mut() {
+            interface.methods.iter_mut().for_each(|method| self.visit_pou(method));
+            interface.properties.iter_mut().for_each(|property| self.resolve_property(property));
+        }
+    }
+
+    fn resolve_pou(&mut self, pou: &mut Pou) {
+        self.visit_pou(pou);
+        pou.properties.iter_mut().for_each(|property| self.resolve_property(property));
+    }
+
+    fn resolve_property(&mut self, property: &mut PropertyBlock) {
+        self.visit_data_type_declaration(&mut property.datatype);
+        for accessor in property.accessors.iter_mut() {
+            accessor.variable_blocks.iter_mut().for_each(|block| self.visit_variable_block(block));
+        }
+    }
+
+    /// Returns the name of the instance the given reference refers to, requesting the instance if it was
+    /// not requested before. Returns `None` if the reference does not refer to a template.
+    fn resolve(&mut self, reference: &str, location: &SourceLocation) -> Option<String> {
+        let (name, arguments) = split_type_arguments(reference)?;
+        let arguments =
+            arguments.into_iter().map(|it| self.resolve(&it, location).unwrap_or(it)).collect::<Vec<_>>();
+        if arguments.iter().any(|it| it.is_empty() || it.contains('<')) {
+            return None;
+        }
+
+        let template = self.find_template(name)?;
+        if self.templates[template].pou.generics.len() != arguments.len() {
+            return None;
+        }
+
+        let instance = instance_name(&self.templates[template].pou.name, &arguments);
+        if self.instances.insert(instance.to_lowercase()) {
+            self.pending.push_back(Instantiation {
+                template,
+                arguments: arguments.clone(),
+                location: location.clone(),
+            });
+        }
+
+        // the reference keeps its qualifier, so it is resolved like any other reference into a namespace
+        Some(instance_name(name, &arguments))
+    }
+
+    /// Finds the template with the given name. A name without its namespace only refers to a template
+    /// if it is unambiguous.
+    fn find_template(&self, name: &str) -> Option<usize> {
+        let qualified = name.replace('.', NAMESPACE_SEPARATOR).to_lowercase();
+        let unqualified = qualified.rsplit(NAMESPACE_SEPARATOR).next().unwrap_or_default();
+
+        let template = [qualified.as_str(), unqualified].into_iter().find_map(|it| {
+            match self.names.get(it)?.as_slice() {
+                [template] => Some(*template),
+                _ => None,
+            }
+        });
+        template
+    }
+}
+
+impl AstVisitorMut for TypeArgumentResolver<'_> {
+    fn visit_data_type_declaration(&mut self, data_type_declaration: &mut DataTypeDeclaration) {
+        if let DataTypeDeclaration::DataTypeReference { referenced_type, location } = data_type_declaration {
+            if let Some(instance) = self.resolve(referenced_type, location) {
+                *referenced_type = instance;
+            }
+        } else {
+            data_type_declaration.walk(self);
+        }
+    }
+}
+
+/// Turns a copy of a template into an instance by replacing its generic symbols with the type arguments
+/// and assigning fresh ids to all of its nodes
+struct Instantiator<'a> {
+    id_provider: IdProvider,
+    /// The type arguments, keyed by the lower case name of their generic symbol
+    substitutions: FxHashMap<String, String>,
+    /// Renames the scopes of the template to the scopes of the instance
+    rename: &'a dyn Fn(&str) -> String,
+}
+
+impl Instantiator<'_> {
+    fn instantiate_pou(&mut self, pou: &mut Pou) {
+        self.visit_pou(pou);
+
+        for property in pou.properties.iter_mut() {
+            self.visit_data_type_declaration(&mut property.datatype);
+            for accessor in property.accessors.iter_mut() {
+                accessor.variable_blocks.iter_mut().for_each(|block| self.visit_variable_block(block));
+                accessor.statements.iter_mut().for_each(|statement| self.visit(statement));
+            }
+        }
+
+        if let Some(sfc) = pou.sfc.as_mut() {
+            sfc.steps
+                .iter_mut()
+                .flat_map(|step| step.actions.iter_mut())
+                .filter_map(|action| action.duration.as_mut())
+                .for_each(|duration| self.visit(duration));
+            sfc.transitions.iter_mut().for_each(|transition| self.visit(&mut transition.condition));
+        }
+    }
+
+    /// Replaces the generic symbols in the given type name, including the type arguments of references to
+    /// other templates, e.g. `FB_Fifo<T>`
+    fn substitute(&self, type_name: &str) -> String {
+        let mut result = String::new();
+        let mut symbol = String::new();
+        for c in type_name.chars().chain(std::iter::once(',')) {
+            if matches!(c, '<' | '>' | ',') {
+                let symbol = std::mem::take(&mut symbol);
+                let trimmed = symbol.trim();
+                result.push_str(
+                    self.substitutions.get(&trimmed.to_lowercase()).map_or(trimmed, String::as_str),
+                );
+                result.push(c);
+            } else {
+                symbol.push(c);
+            }
+        }
+        result.pop();
+        result
+    }
+}
+
+impl AstVisitorMut for Instantiator<'_> {
+    fn visit(&mut self, node: &mut plc_ast::ast::AstNode) {
+        node.id = self.id_provider.next_id();
+        node.walk(self)
+    }
+
+    fn visit_variable(&mut self, variable: &mut Variable) {
+        variable.walk(self);
+        if let Some(init_arguments) = variable.init_arguments.as_mut() {
+            self.visit(init_arguments);
+        }
+    }
+
+    fn visit_data_type_declaration(&mut self, data_type_declaration: &mut DataTypeDeclaration) {
+        match data_type_declaration {
+            DataTypeDeclaration::DataTypeReference { referenced_type, .. } => {
+                *referenced_type = self.substitute(referenced_type);
+            }
+            DataTypeDeclaration::DataTypeDefinition { scope, .. } => {
+                *scope = scope.as_deref().map(self.rename);
+                data_type_declaration.walk(self);
+            }
+            DataTypeDeclaration::Aggregate { .. } => {}
+        }
+    }
+
+    fn visit_data_type(&mut self, data_type: &mut DataType) {
+        match data_type {
+            // the walker only visits copies of the elements
+            DataType::EnumType { elements, .. } => self.visit(elements),
+            DataType::SubRangeType { referenced_type, .. } => {
+                *referenced_type = self.substitute(referenced_type);
+                data_type.walk(self);
+            }
+            _ => data_type.walk(self),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use plc_ast::provider::IdProvider;
+
+    use crate::test_utils::tests::index_and_lower;
+
+    #[test]
+    fn generic_function_blocks_are_instantiated_for_each_list_of_type_arguments() {
+        let (unit, ..) = index_and_lower(
+            "
+            FUNCTION_BLOCK FB_Pair<K: ANY, V: ANY>
+            VAR
+                key : K;
+                value : V;
+            END_VAR
+            END_FUNCTION_BLOCK
+
+            FUNCTION_BLOCK FB_Fifo<T: ANY>
+            VAR
+                items : ARRAY[0..9] OF T;
+                last : REF_TO T;
+            END_VAR
+                METHOD push
+                VAR_INPUT
+                    item : T;
+                END_VAR
+                END_METHOD
+            END_FUNCTION_BLOCK
+
+            PROGRAM main
+            VAR
+                a : FB_Fifo<REAL>;
+                b : FB_Fifo<real>;
+                c : FB_Fifo<FB_Pair<INT, STRING>>;
+            END_VAR
+            END_PROGRAM
+            ",
+            IdProvider::default(),
+        );
+
+        let pous = unit.units.iter().map(|it| it.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(
+            pous,
+            vec![
+                "main",
+                "FB_Fifo__REAL",
+                "FB_Fifo__REAL.push",
+                "FB_Pair__INT__STRING",
+                "FB_Fifo__FB_Pair__INT__STRING",
+                "FB_Fifo__FB_Pair__INT__STRING.push",
+            ]
+        );
+
+        let main = unit.units.iter().find(|it| it.name == "main").unwrap();
+        let types = main.variable_blocks[0]
+            .variables
+            .iter()
+            .map(|it| it.data_type_declaration.get_name().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(types, vec!["FB_Fifo__REAL", "FB_Fifo__real", "FB_Fifo__FB_Pair__INT__STRING"]);
+
+        let push = unit.units.iter().find(|it| it.name == "FB_Fifo__FB_Pair__INT__STRING.push").unwrap();
+        assert_eq!(
+            push.variable_blocks[0].variables[0].data_type_declaration.get_name(),
+            Some("FB_Pair__INT__STRING")
+        );
+
+        let implementations = unit.implementations.iter().map(|it| it.name.as_str()).collect::<Vec<_>>();
+        assert!(!implementations.iter().any(|it| it.starts_with("FB_Fifo.") || *it == "FB_Fifo"));
+        assert!(implementations.contains(&"FB_Fifo__REAL.push"));
+    }
+
+    #[test]
+    fn references_with_a_wrong_number_of_type_arguments_are_not_resolved() {
+        let (unit, ..) = index_and_lower(
+            "
+            FUNCTION_BLOCK FB_Fifo<T: ANY>
+            END_FUNCTION_BLOCK
+
+            PROGRAM main
+            VAR
+                a : FB_Fifo<REAL, INT>;
+            END_VAR
+            END_PROGRAM
+            ",
+            IdProvider::default(),
+        );
+
+        assert_eq!(unit.units.iter().map(|it| it.name.as_str()).collect::<Vec<_>>(), vec!["main"]);
+        assert_eq!(
+            unit.units[0].variable_blocks[0].variables[0].data_type_declaration.get_name(),
+            Some("FB_Fifo<REAL,INT>")
+        );
+    }
+}
@@ -0,0 +1,126 @@
+//! Lays out the instances of `FUNCTION_BLOCK`s and `CLASS`es taking part in an `EXTENDS` hierarchy.
+//!
+//! A derived POU embeds an instance of its base as its first member `__BASE`, so a pointer to a derived
+//! instance is also a valid pointer to an instance of each of its base classes. The root of a hierarchy
+//! additionally starts with a `__vtable` pointer to the dispatch table of the instance's actual POU, which
+//! is used to call overridden methods. Since a POU may be extended by a project compiled separately from
+//! the library declaring it, the `__vtable` is reserved by every `FUNCTION_BLOCK` and `CLASS` which is not
+//! declared `FINAL`, so its layout does not depend on the units it is compiled with:
+//! ```iecst
+//! FUNCTION_BLOCK base
+//! VAR
+//!     __vtable : __BYTE_POINTER; // added, since `base` is not FINAL
+//! END_VAR
+//! END_FUNCTION_BLOCK
+//!
+//! FUNCTION_BLOCK derived EXTENDS base
+//! VAR
+//!     __BASE : base; // added
+//! END_VAR
+//! END_FUNCTION_BLOCK
+//! ```
+//!
+//! A `STRUCT` extending another `STRUCT` embeds its base the same way, but without a `__vtable`:
+//! ```iecst
+//! TYPE derived : STRUCT EXTENDS base
+//!     __BASE : base; // added
+//! END_STRUCT END_TYPE
+//! ```
+
+use plc_ast::{
+    ast::{
+        CompilationUnit, DataType, DataTypeDeclaration, Implementation, LinkageType, PolymorphismMode,
+        PouType, UserTypeDeclaration, Variable, VariableBlock, VariableBlockType,
+    },
+    mut_visitor::{AstVisitorMut, WalkerMut},
+};
+use plc_source::source_location::SourceLocation;
+
+use crate::{
+    index::{BASE_MEMBER_NAME, VTABLE_MEMBER_NAME},
+    typesystem::BYTE_POINTER_TYPE,
+};
+
+#[derive(Default)]
+pub struct InheritanceLowerer;
+
+impl InheritanceLowerer {
+    /// Adds the `__BASE` members to all POUs and structs of the given units extending another one, and the
+    /// `__vtable` member to all roots of a (possible) `EXTENDS` hierarchy, i.e. every `FUNCTION_BLOCK` and
+    /// `CLASS` which neither extends another POU nor is declared `FINAL`.
+    pub fn lower_inheritance(&mut self, units: &mut [CompilationUnit]) {
+        units.iter_mut().for_each(|unit| self.visit_compilation_unit(unit));
+
+        for pou in units.iter_mut().flat_map(|unit| unit.units.iter_mut()) {
+            if !matches!(pou.kind, PouType::FunctionBlock | PouType::Class) {
+                continue;
+            }
+
+            let location = pou.name_location.clone();
+            let member = match &pou.super_class {
+                Some(super_class) => create_member(BASE_MEMBER_NAME, super_class, location),
+                None if pou.poly_mode != Some(PolymorphismMode::Final) => {
+                    create_member(VTABLE_MEMBER_NAME, BYTE_POINTER_TYPE, location)
+                }
+                None => continue,
+            };
+            pou.variable_blocks.insert(0, member);
+        }
+    }
+}
+
+impl AstVisitorMut for InheritanceLowerer {
+    fn visit_user_type_declaration(&mut self, user_type: &mut UserTypeDeclaration) {
+        add_base_member(&mut user_type.data_type, &user_type.location);
+        user_type.walk(self);
+    }
+
+    fn visit_variable(&mut self, variable: &mut Variable) {
+        // inline structs, e.g. `x : STRUCT EXTENDS base ... END_STRUCT`
+        if let DataTypeDeclaration::DataTypeDefinition { data_type, .. } = &mut variable.data_type_declaration
+        {
+            add_base_member(data_type, &variable.location);
+        }
+        variable.walk(self);
+    }
+
+    fn visit_implementation(&mut self, _implementation: &mut Implementation) {
+        // structs are only declared in variable blocks and type declarations
+    }
+}
+
+/// Adds the `__BASE` member to the given struct, if it extends another struct
+fn add_base_member(data_type: &mut DataType, location: &SourceLocation) {
+    if let DataType::StructType { base: Some(base), variables, .. } = data_type {
+        variables.insert(0, create_variable(BASE_MEMBER_NAME, base, location.clone()));
+    }
+}
+
+fn create_member(name: &str, type_name: &str, location: SourceLocation) -> VariableBlock {
+    VariableBlock {
+        access: None,
+        constant: false,
+        retain: false,
+        persistent: false,
+        variables: vec![create_variable(name, type_name, location.clone())],
+        variable_block_type: VariableBlockType::Local,
+        linkage: LinkageType::Internal,
+        location,
+        attributes: vec![],
+    }
+}
+
+fn create_variable(name: &str, type_name: &str, location: SourceLocation) -> Variable {
+    Variable {
+        name: name.into(),
+        data_type_declaration: DataTypeDeclaration::DataTypeReference {
+            referenced_type: type_name.into(),
+            location: location.clone(),
+        },
+        initializer: None,
+        address: None,
+        init_arguments: None,
+        location,
+        attributes: vec![],
+    }
+}
@@ -1,6 +1,11 @@
 use crate::{
-    index::{const_expressions::UnresolvableKind, get_init_fn_name, FxIndexMap, FxIndexSet},
-    lowering::{create_assignment_if_necessary, create_call_statement, create_member_reference},
+    index::{
+        const_expressions::UnresolvableKind, get_deinit_fn_name, get_init_fn_name, get_reinit_fn_name,
+        FxIndexMap, FxIndexSet, FB_EXIT_METHOD_NAME, FB_REINIT_METHOD_NAME,
+    },
+    lowering::{
+        create_assignment_if_necessary, create_call_statement, create_member_reference, create_method_call,
+    },
     resolver::const_evaluator::UnresolvableConstant,
 };
 use plc_ast::ast::{
@@ -8,6 +13,7 @@ use plc_ast::ast::{
     Variable, VariableBlock, VariableBlockType,
 };
 use plc_source::source_location::SourceLocation;
+use plc_util::convention::qualified_name;
 
 use super::InitVisitor;
 pub(crate) const GLOBAL_SCOPE: &str = "__global";
@@ -114,6 +120,12 @@ impl InitVisitor {
             units.push(init_unit);
         }
 
+        units.extend(
+            [Lifecycle::Reinit, Lifecycle::Deinit]
+                .into_iter()
+                .filter_map(|lifecycle| create_lifecycle_units(&self, lifecycle, init_symbol_name)),
+        );
+
         if let Some(global_init) = create_init_wrapper_function(&mut self, init_symbol_name) {
             units.push(global_init);
         }
@@ -122,6 +134,171 @@ impl InitVisitor {
     }
 }
 
+/// The lifecycle methods which are called for all instances of a project by a generated entry point, next to the
+/// `FB_INIT` methods called by the init-functions
+#[derive(Clone, Copy)]
+enum Lifecycle {
+    /// `FB_REINIT` runs for the members of an instance before the instance itself, starting with the base class
+    Reinit,
+    /// `FB_EXIT` runs for an instance before its members, which are torn down in reverse order
+    Deinit,
+}
+
+impl Lifecycle {
+    fn get_method_name(self) -> &'static str {
+        match self {
+            Lifecycle::Reinit => FB_REINIT_METHOD_NAME,
+            Lifecycle::Deinit => FB_EXIT_METHOD_NAME,
+        }
+    }
+
+    fn get_fn_name(self, type_name: &str) -> String {
+        match self {
+            Lifecycle::Reinit => get_reinit_fn_name(type_name),
+            Lifecycle::Deinit => get_deinit_fn_name(type_name),
+        }
+    }
+
+    /// Derives the symbol name of the project's entry point from the symbol of its init-function, e.g.
+    /// `__deinit___myproject` for `__init___myproject`
+    fn get_symbol_name(self, init_symbol_name: &str) -> String {
+        let project = init_symbol_name.strip_prefix("__init").unwrap_or(init_symbol_name);
+        match self {
+            Lifecycle::Reinit => format!("__reinit{project}"),
+            Lifecycle::Deinit => format!("__deinit{project}"),
+        }
+    }
+}
+
+/// Creates the `__reinit_<type>`/`__deinit_<type>` functions of all containers declaring the lifecycle method
+/// or containing members which do, as well as the project's entry point calling them for all programs and global
+/// instances. Returns `None` if no container declares the lifecycle method.
+fn create_lifecycle_units(
+    lowerer: &InitVisitor,
+    lifecycle: Lifecycle,
+    init_symbol_name: &str,
+) -> Option<CompilationUnit> {
+    let all_init_units =
+        lowerer.unresolved_initializers.keys().map(|it| it.as_str()).collect::<FxIndexSet<_>>();
+    let mut participants = FxIndexMap::default();
+    let containers = all_init_units
+        .iter()
+        .copied()
+        .filter(|it| *it != GLOBAL_SCOPE)
+        .filter(|it| lowerer.index.find_pou(it).is_none_or(|pou| !(pou.is_function() || pou.is_method())))
+        .filter(|it| participates_in_lifecycle(lowerer, lifecycle, it, &all_init_units, &mut participants))
+        .collect::<Vec<_>>();
+
+    if containers.is_empty() {
+        return None;
+    }
+
+    let id_provider = &lowerer.ctxt.id_provider;
+    let mut units = containers.iter().copied().map(|container_name| {
+        let fn_name = lifecycle.get_fn_name(container_name);
+        let location = lowerer
+            .index
+            .find_pou(container_name)
+            .map(|it| it.get_location())
+            .unwrap_or_else(|| &lowerer.index.get_type_or_panic(container_name).location);
+
+        let member_calls = get_initialized_members(lowerer, container_name, &all_init_units)
+            .into_iter()
+            .filter(|(_, type_name)| participants.get(&type_name.to_lowercase()).copied().unwrap_or_default())
+            .map(|(member_name, type_name)| {
+                create_call_statement(
+                    &lifecycle.get_fn_name(type_name),
+                    member_name,
+                    Some("self"),
+                    id_provider.clone(),
+                    location,
+                )
+            });
+        let own_call =
+            lowerer.index.find_pou(&qualified_name(container_name, lifecycle.get_method_name())).map(|_| {
+                create_method_call(
+                    lifecycle.get_method_name(),
+                    "self",
+                    None,
+                    None,
+                    id_provider.clone(),
+                    location,
+                )
+            });
+
+        let statements = match lifecycle {
+            Lifecycle::Reinit => member_calls.chain(own_call).collect(),
+            Lifecycle::Deinit => own_call.into_iter().chain(member_calls.rev()).collect(),
+        };
+
+        let pou = new_pou(&fn_name, new_self_parameter(container_name, location), PouType::Init, location);
+        let implementation = new_implementation(&fn_name, statements, PouType::Init, location);
+        new_unit(pou, implementation, INIT_COMPILATION_UNIT)
+    });
+
+    let mut lifecycle_unit = units.next()?;
+    units.for_each(|unit| lifecycle_unit.import(unit));
+
+    // the entry point visits the programs and global instances in the order they are initialized, or in reverse
+    // order when tearing them down
+    let programs = containers
+        .iter()
+        .copied()
+        .filter(|it| lowerer.index.find_pou(it).is_some_and(|pou| pou.is_program()))
+        .map(|it| (it, it));
+    let global_instances = get_global_instances(lowerer)
+        .into_iter()
+        .filter(|(type_name, _)| participants.get(&type_name.to_lowercase()).copied().unwrap_or_default());
+    let calls = programs.chain(global_instances).map(|(type_name, var_name)| {
+        create_call_statement(
+            &lifecycle.get_fn_name(type_name),
+            var_name,
+            None,
+            id_provider.clone(),
+            &SourceLocation::internal(),
+        )
+    });
+    let statements = match lifecycle {
+        Lifecycle::Reinit => calls.collect(),
+        Lifecycle::Deinit => calls.rev().collect(),
+    };
+
+    let symbol_name = lifecycle.get_symbol_name(init_symbol_name);
+    let location = SourceLocation::internal();
+    let pou = new_pou(&symbol_name, vec![], PouType::Init, &location);
+    let implementation = new_implementation(&symbol_name, statements, PouType::Init, &location);
+    lifecycle_unit.import(new_unit(pou, implementation, &symbol_name));
+
+    Some(lifecycle_unit)
+}
+
+/// Returns true if the given container declares the lifecycle method or contains members which do, recording the
+/// result for every visited type in `participants`
+fn participates_in_lifecycle(
+    lowerer: &InitVisitor,
+    lifecycle: Lifecycle,
+    container_name: &str,
+    all_init_units: &FxIndexSet<&str>,
+    participants: &mut FxIndexMap<String, bool>,
+) -> bool {
+    let key = container_name.to_lowercase();
+    if let Some(participates) = participants.get(&key) {
+        return *participates;
+    }
+
+    // guard against recursive types, which are reported elsewhere
+    participants.insert(key.clone(), false);
+    let participates =
+        lowerer.index.find_pou(&qualified_name(container_name, lifecycle.get_method_name())).is_some()
+            || get_initialized_members(lowerer, container_name, all_init_units).into_iter().any(
+                |(_, type_name)| {
+                    participates_in_lifecycle(lowerer, lifecycle, type_name, all_init_units, participants)
+                },
+            );
+    participants.insert(key, participates);
+    participates
+}
+
 fn create_var_config_init(statements: Vec<AstNode>) -> CompilationUnit {
     let loc = SourceLocation::internal_in_unit(Some(INIT_COMPILATION_UNIT));
     let pou = new_pou(VAR_CONFIG_INIT, vec![], PouType::Init, &loc); // this can probably just be internal
@@ -165,21 +342,7 @@ fn create_init_unit(
         return None;
     };
 
-    let (param, ident) = (
-        vec![VariableBlock::default().with_block_type(VariableBlockType::InOut).with_variables(vec![
-            Variable {
-                name: "self".into(),
-                data_type_declaration: DataTypeDeclaration::DataTypeReference {
-                    referenced_type: container_name.to_string(),
-                    location: location.clone(),
-                },
-                initializer: None,
-                address: None,
-                location: location.clone(),
-            },
-        ])],
-        "self".to_string(),
-    );
+    let (param, ident) = (new_self_parameter(container_name, location), "self".to_string());
 
     let init_pou = new_pou(&init_fn_name, param, PouType::Init, location);
 
@@ -190,7 +353,37 @@ fn create_init_unit(
         })
         .collect::<Vec<_>>();
 
-    let member_init_calls = lowerer
+    let members = get_initialized_members(lowerer, container_name, all_init_units);
+    let member_init_calls = members.iter().map(|(member_name, type_name)| {
+        create_call_statement(
+            &get_init_fn_name(type_name),
+            member_name,
+            Some("self"),
+            id_provider.clone(),
+            location,
+        )
+    });
+    statements.extend(member_init_calls);
+
+    // the `FB_INIT` methods of the members run after all members were initialized, starting with the base class
+    // which is the first member. the container's own `FB_INIT` is called by the owner of the instance
+    let fb_init_calls = members.iter().filter_map(|(member_name, type_name)| {
+        lowerer.create_fb_init_call(container_name, member_name, type_name, Some("self"), location)
+    });
+    statements.extend(fb_init_calls);
+    let implementation = new_implementation(&init_fn_name, statements, PouType::Init, location);
+
+    Some(new_unit(init_pou, implementation, INIT_COMPILATION_UNIT))
+}
+
+/// Returns the names and types of the members of the given container which are initialized by an init-function
+/// of their own
+fn get_initialized_members<'idx>(
+    lowerer: &'idx InitVisitor,
+    container_name: &str,
+    all_init_units: &FxIndexSet<&str>,
+) -> Vec<(&'idx str, &'idx str)> {
+    lowerer
         .index
         .get_container_members(container_name)
         .iter()
@@ -201,26 +394,29 @@ fn create_init_unit(
                 .get_effective_type_by_name(member_type_name)
                 .map(|it| it.get_type_information().get_name())
                 .unwrap_or(member_type_name);
-            let call_name = get_init_fn_name(type_name);
             // TODO: support temp accessors && external declarations
-            if !member.is_temp() && all_init_units.contains(type_name) {
-                Some(create_call_statement(
-                    &call_name,
-                    member.get_name(),
-                    Some("self"),
-                    id_provider.clone(),
-                    location,
-                ))
-            } else {
-                None
-            }
+            (!member.is_temp() && all_init_units.contains(type_name))
+                .then_some((member.get_name(), type_name))
         })
-        .collect::<Vec<_>>();
+        .collect()
+}
 
-    statements.extend(member_init_calls);
-    let implementation = new_implementation(&init_fn_name, statements, PouType::Init, location);
+/// Returns the types and names of the global instances which are initialized by the project's init-function
+fn get_global_instances(lowerer: &InitVisitor) -> Vec<(&str, &str)> {
+    let Some(globals) = lowerer.unresolved_initializers.get(GLOBAL_SCOPE) else {
+        return vec![];
+    };
 
-    Some(new_unit(init_pou, implementation, INIT_COMPILATION_UNIT))
+    globals
+        .keys()
+        .filter_map(|var_name| {
+            let variable = lowerer.index.find_variable(None, &[var_name])?;
+            let dt = lowerer.index.find_effective_type_by_name(variable.get_type_name())?;
+            dt.get_type_information()
+                .is_struct()
+                .then(|| (dt.get_type_information().get_name(), var_name.as_str()))
+        })
+        .collect()
 }
 
 fn create_init_wrapper_function(
@@ -235,29 +431,11 @@ fn create_init_wrapper_function(
     let mut id_provider = lowerer.ctxt.id_provider.clone();
     let init_pou = new_pou(init_symbol_name, vec![], PouType::ProjectInit, &SourceLocation::internal());
 
-    let global_instances = if let Some(global_instances) =
-        lowerer.unresolved_initializers.get(GLOBAL_SCOPE).map(|it| {
-            it.keys().filter_map(|var_name| {
-                lowerer.index.find_variable(None, &[var_name]).and_then(|it| {
-                    lowerer.index.find_effective_type_by_name(it.get_type_name()).and_then(|dt| {
-                        let name = dt.get_type_information().get_name();
-                        if dt.get_type_information().is_struct() {
-                            Some((get_init_fn_name(name), var_name))
-                        } else {
-                            None
-                        }
-                    })
-                })
-            })
-        }) {
-        global_instances.collect::<Vec<_>>()
-    } else {
-        vec![]
-    };
+    let global_instances = get_global_instances(lowerer);
 
     let programs = lowerer.unresolved_initializers.iter().filter_map(|(scope, _)| {
         if lowerer.index.find_pou(scope).is_some_and(|pou| pou.is_program()) {
-            Some((get_init_fn_name(scope), scope))
+            Some((get_init_fn_name(scope), scope.as_str()))
         } else {
             None
         }
@@ -274,7 +452,7 @@ fn create_init_wrapper_function(
         vec![]
     };
     let calls = programs
-        .chain(global_instances)
+        .chain(global_instances.iter().map(|(type_name, var_name)| (get_init_fn_name(type_name), *var_name)))
         .map(|(fn_name, param)| {
             let op = create_member_reference(&fn_name, id_provider.clone(), None);
             let param = create_member_reference(param, id_provider.clone(), None);
@@ -298,6 +476,12 @@ fn create_init_wrapper_function(
         ));
     };
 
+    // global instances are fully initialized, including their configured addresses, before their `FB_INIT` runs
+    let fb_init_calls = global_instances.iter().filter_map(|(type_name, var_name)| {
+        lowerer.create_fb_init_call(GLOBAL_SCOPE, var_name, type_name, None, &SourceLocation::internal())
+    });
+    assignments.extend(fb_init_calls.collect::<Vec<_>>());
+
     let implementation =
         new_implementation(init_symbol_name, assignments, PouType::ProjectInit, &SourceLocation::internal());
     let mut global_init = new_unit(init_pou, implementation, init_symbol_name);
@@ -311,6 +495,22 @@ fn create_init_wrapper_function(
     Some(global_init)
 }
 
+/// Creates the `VAR_IN_OUT` block declaring the `self` parameter of a generated function for the given container
+fn new_self_parameter(container_name: &str, location: &SourceLocation) -> Vec<VariableBlock> {
+    vec![VariableBlock::default().with_block_type(VariableBlockType::InOut).with_variables(vec![Variable {
+        name: "self".into(),
+        data_type_declaration: DataTypeDeclaration::DataTypeReference {
+            referenced_type: container_name.to_string(),
+            location: location.clone(),
+        },
+        initializer: None,
+        address: None,
+        init_arguments: None,
+        location: location.clone(),
+        attributes: vec![],
+    }])]
+}
+
 fn new_pou(name: &str, variable_blocks: Vec<VariableBlock>, kind: PouType, location: &SourceLocation) -> Pou {
     Pou {
         name: name.into(),
@@ -321,10 +521,14 @@ fn new_pou(name: &str, variable_blocks: Vec<VariableBlock>, kind: PouType, locat
         name_location: location.to_owned(),
         poly_mode: None,
         generics: vec![],
+        generic_arguments: vec![],
         linkage: LinkageType::Internal,
         super_class: None,
         interfaces: vec![],
+        properties: vec![],
         is_const: false,
+        attributes: vec![],
+        sfc: None,
     }
 }
 
@@ -352,10 +556,13 @@ fn new_unit(pou: Pou, implementation: Implementation, file_name: &str) -> Compil
     CompilationUnit {
         global_vars: vec![],
         var_config: Default::default(),
+        var_access: vec![],
         units: vec![pou],
         implementations: vec![implementation],
         interfaces: vec![],
         user_types: vec![],
+        usings: vec![],
+        configurations: vec![],
         file_name: file_name.into(),
     }
 }
@@ -0,0 +1,518 @@
+//! Resolves references to declarations made inside of `NAMESPACE` blocks.
+//!
+//! The parser qualifies the names of all POUs, types and global variables declared in a namespace with
+//! the namespace's name, e.g. `motor` declared in `NAMESPACE vendor.drives` becomes `vendor::drives::motor`.
+//! Since the index keys every declaration by this name, equally named declarations of different
+//! namespaces do not collide and end up as separate symbols at link time.
+//!
+//! Before indexing, all references to namespaced declarations are rewritten to their qualified names. A
+//! name is looked up in the namespace the reference appears in and its enclosing namespaces, then in the
+//! namespaces imported by `USING` directives (in declaration order) and finally as a fully qualified name.
+//! Local variables shadow namespaced declarations of the same name.
+//! ```iecst
+//! NAMESPACE app
+//!     USING vendor.drives;
+//!
+//!     PROGRAM main
+//!     VAR
+//!         a : motor;                  // becomes `vendor::drives::motor`
+//!         b : vendor.drives.motor;    // becomes `vendor::drives::motor`
+//!     END_VAR
+//!     END_PROGRAM
+//! END_NAMESPACE
+//! ```
+
+use plc_ast::{
+    ast::{
+        Assignment, AstFactory, AstNode, AstStatement, CallStatement, CompilationUnit, DataType,
+        DataTypeDeclaration, Implementation, Pou, ReferenceAccess, ReferenceExpr, UserTypeDeclaration,
+        UsingDirective, Variable,
+    },
+    mut_visitor::{AstVisitorMut, WalkerMut},
+    try_from_mut,
+};
+use plc_util::convention::{get_namespace, namespaced_name, NAMESPACE_SEPARATOR};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+#[derive(Default)]
+pub struct NamespaceLowerer;
+
+impl NamespaceLowerer {
+    /// Rewrites all references to namespaced declarations in the given units to their qualified names. All
+    /// units of a project need to be lowered together, since a namespace may span several units.
+    pub fn lower_namespaces(&mut self, units: &mut [CompilationUnit]) {
+        let symbols = collect_namespaced_symbols(units);
+        if symbols.is_empty() {
+            return;
+        }
+
+        let pous = units
+            .iter()
+            .flat_map(|unit| unit.units.iter().chain(unit.interfaces.iter().flat_map(|it| it.methods.iter())))
+            .map(|pou| (pou.name.to_lowercase(), PouInfo::new(pou)))
+            .collect::<FxHashMap<_, _>>();
+
+        for unit in units.iter_mut() {
+            let usings = unit.usings.clone();
+            let mut resolver = NamespaceResolver::new(&symbols, &pous, &usings);
+
+            for block in unit.global_vars.iter_mut() {
+                let name = block.variables.first().map(|it| it.name.clone()).unwrap_or_default();
+                resolver.enter(&name);
+                resolver.visit_variable_block(block);
+            }
+
+            for user_type in unit.user_types.iter_mut() {
+                resolver.visit_user_type_declaration(user_type);
+            }
+
+            for interface in unit.interfaces.iter_mut() {
+                interface.methods.iter_mut().for_each(|method| resolver.visit_pou(method));
+
+                resolver.enter(&interface.name);
+                for property in interface.properties.iter_mut() {
+                    resolver.visit_data_type_declaration(&mut property.datatype);
+                }
+            }
+
+            for pou in unit.units.iter_mut() {
+                resolver.visit_pou(pou);
+            }
+
+            for implementation in unit.implementations.iter_mut() {
+                resolver.visit_implementation(implementation);
+            }
+        }
+    }
+}
+
+/// Returns the lower-case names of all declarations made inside of a namespace
+fn collect_namespaced_symbols(units: &[CompilationUnit]) -> FxHashSet<String> {
+    let pous = units.iter().flat_map(|unit| unit.units.iter()).map(|it| it.name.as_str());
+    let interfaces = units.iter().flat_map(|unit| unit.interfaces.iter()).map(|it| it.name.as_str());
+    let types = units.iter().flat_map(|unit| unit.user_types.iter()).filter_map(|it| it.data_type.get_name());
+    let globals = units
+        .iter()
+        .flat_map(|unit| unit.global_vars.iter())
+        .flat_map(|block| block.variables.iter())
+        .map(|it| it.name.as_str());
+
+    pous.chain(interfaces)
+        .chain(types)
+        .chain(globals)
+        .filter(|name| get_namespace(name).is_some())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// The names a POU's body may refer to without qualification
+struct PouInfo {
+    variables: FxHashSet<String>,
+    super_class: Option<String>,
+}
+
+impl PouInfo {
+    fn new(pou: &Pou) -> Self {
+        let variables = pou
+            .variable_blocks
+            .iter()
+            .flat_map(|block| block.variables.iter())
+            .map(|it| it.name.to_lowercase())
+            .chain(pou.properties.iter().map(|it| it.name.to_lowercase()))
+            .collect();
+
+        PouInfo { variables, super_class: pou.super_class.clone() }
+    }
+}
+
+struct NamespaceResolver<'a> {
+    symbols: &'a FxHashSet<String>,
+    pous: &'a FxHashMap<String, PouInfo>,
+    usings: &'a [UsingDirective],
+    /// The namespaces searched for unqualified names, innermost first
+    search_path: Vec<String>,
+    /// The variables shadowing namespaced declarations in the current scope
+    locals: FxHashSet<String>,
+    /// Whether the left-hand side of assignments names a parameter or member rather than a variable
+    in_named_arguments: bool,
+}
+
+impl<'a> NamespaceResolver<'a> {
+    fn new(
+        symbols: &'a FxHashSet<String>,
+        pous: &'a FxHashMap<String, PouInfo>,
+        usings: &'a [UsingDirective],
+    ) -> Self {
+        NamespaceResolver {
+            symbols,
+            pous,
+            usings,
+            search_path: vec![],
+            locals: FxHashSet::default(),
+            in_named_arguments: false,
+        }
+    }
+
+    /// Prepares the lookup of names referenced in the declaration or POU with the given qualified name
+    fn enter(&mut self, name: &str) {
+        let namespace = get_namespace(name);
+
+        let mut search_path = vec![];
+        let mut current = namespace;
+        while let Some(namespace) = current {
+            search_path.push(namespace.to_string());
+            current = namespace.rsplit_once(NAMESPACE_SEPARATOR).map(|(parent, _)| parent);
+        }
+
+        let name = name.to_lowercase();
+        let namespace = namespace.map(str::to_lowercase);
+        let imported =
+            self.usings.iter().filter(|using| match using.scope.as_deref().map(str::to_lowercase) {
+                None => true,
+                Some(scope) => {
+                    // the directive was declared in a POU (and applies to its methods and actions as well) or in
+                    // one of the namespaces enclosing the current declaration
+                    name == scope
+                        || name.starts_with(&format!("{scope}."))
+                        || namespace.as_ref().is_some_and(|namespace| {
+                            *namespace == scope
+                                || namespace.starts_with(&format!("{scope}{NAMESPACE_SEPARATOR}"))
+                        })
+                }
+            });
+        search_path.extend(imported.map(|using| using.namespace.clone()));
+        self.search_path = search_path;
+
+        self.locals.clear();
+        let mut pou = Some(name.clone());
+        // a method's or action's body may refer to the variables of its container
+        let container = name.split_once('.').map(|(container, _)| container.to_string());
+        let mut pending = container.into_iter().collect::<Vec<_>>();
+        let mut visited = FxHashSet::default();
+        while let Some(current) = pou.take().or_else(|| pending.pop()) {
+            if !visited.insert(current.clone()) {
+                continue;
+            }
+
+            let Some(info) = self.pous.get(&current) else { continue };
+            self.locals.extend(info.variables.iter().cloned());
+            // inherited members are accessible as well
+            if let Some(super_class) = info.super_class.as_deref() {
+                pending.push(
+                    self.resolve(super_class).unwrap_or_else(|| super_class.to_string()).to_lowercase(),
+                );
+            }
+        }
+    }
+
+    /// Returns the qualified name of the namespaced declaration the given (possibly dot-qualified) name
+    /// refers to, if any
+    fn resolve(&self, name: &str) -> Option<String> {
+        let is_qualified = name.contains('.');
+        let name = name.split('.').collect::<Vec<_>>().join(NAMESPACE_SEPARATOR);
+
+        self.search_path
+            .iter()
+            .map(|namespace| namespaced_name(namespace.as_str(), name.as_str()))
+            .chain(is_qualified.then(|| name.clone()))
+            .find(|candidate| self.symbols.contains(&candidate.to_lowercase()))
+    }
+
+    fn resolve_reference(&self, node: &AstNode) -> Option<String> {
+        let segments = get_segments(node)?;
+        if self.locals.contains(&segments.first()?.to_lowercase()) {
+            return None;
+        }
+
+        self.resolve(&segments.join("."))
+    }
+
+    fn visit_named_arguments(&mut self, node: &mut AstNode) {
+        let in_named_arguments = std::mem::replace(&mut self.in_named_arguments, true);
+        self.visit(node);
+        self.in_named_arguments = in_named_arguments;
+    }
+}
+
+/// Returns the segments of a reference like `a.b.c`, or `None` if it is not a plain chain of identifiers
+fn get_segments(node: &AstNode) -> Option<Vec<&str>> {
+    let AstStatement::ReferenceExpr(ReferenceExpr { access: ReferenceAccess::Member(member), base }) =
+        &node.stmt
+    else {
+        return None;
+    };
+    let AstStatement::Identifier(name) = &member.stmt else { return None };
+
+    let mut segments = match base {
+        Some(base) => get_segments(base)?,
+        None => vec![],
+    };
+    segments.push(name);
+    Some(segments)
+}
+
+impl AstVisitorMut for NamespaceResolver<'_> {
+    fn visit_pou(&mut self, pou: &mut Pou) {
+        self.enter(&pou.name);
+
+        if let Some(super_class) = pou.super_class.as_mut() {
+            if let Some(name) = self.resolve(super_class) {
+                *super_class = name;
+            }
+        }
+
+        for interface in pou.interfaces.iter_mut() {
+            if let Some(name) = self.resolve(&interface.name) {
+                interface.name = name;
+            }
+        }
+
+        pou.walk(self);
+
+        for property in pou.properties.iter_mut() {
+            self.visit_data_type_declaration(&mut property.datatype);
+            for accessor in property.accessors.iter_mut() {
+                accessor.variable_blocks.iter_mut().for_each(|block| self.visit_variable_block(block));
+                accessor.statements.iter_mut().for_each(|statement| self.visit(statement));
+            }
+        }
+    }
+
+    fn visit_implementation(&mut self, implementation: &mut Implementation) {
+        self.enter(&implementation.name);
+        implementation.walk(self);
+    }
+
+    fn visit_user_type_declaration(&mut self, user_type: &mut UserTypeDeclaration) {
+        self.enter(user_type.data_type.get_name().unwrap_or_default());
+        self.visit_data_type(&mut user_type.data_type);
+        if let Some(initializer) = user_type.initializer.as_mut() {
+            self.visit_named_arguments(initializer);
+        }
+    }
+
+    fn visit_data_type(&mut self, data_type: &mut DataType) {
+        if let DataType::StructType { base: Some(base), .. } = data_type {
+            if let Some(name) = self.resolve(base) {
+                *base = name;
+            }
+        }
+        data_type.walk(self);
+    }
+
+    fn visit_variable(&mut self, variable: &mut Variable) {
+        self.visit_data_type_declaration(&mut variable.data_type_declaration);
+        if let Some(initializer) = variable.initializer.as_mut() {
+            self.visit_named_arguments(initializer);
+        }
+    }
+
+    fn visit_data_type_declaration(&mut self, data_type_declaration: &mut DataTypeDeclaration) {
+        if let DataTypeDeclaration::DataTypeReference { referenced_type, .. } = data_type_declaration {
+            if let Some(name) = self.resolve(referenced_type) {
+                *referenced_type = name;
+            }
+        } else {
+            data_type_declaration.walk(self);
+        }
+    }
+
+    fn visit_reference_expr(&mut self, node: &mut AstNode) {
+        let resolved = self.resolve_reference(node);
+        let stmt = try_from_mut!(node, ReferenceExpr).expect("ReferenceExpr");
+        let Some(name) = resolved else {
+            stmt.walk(self);
+            return;
+        };
+
+        // replace the whole chain `a.b.c` by a single reference to `a::b::c`
+        let ReferenceAccess::Member(member) = &stmt.access else { unreachable!("resolved a member chain") };
+        let member_id = member.get_id();
+        let member = AstFactory::create_identifier(&name, node.get_location(), member_id);
+        *node = AstFactory::create_member_reference(member, None, node.get_id());
+    }
+
+    fn visit_call_statement(&mut self, node: &mut AstNode) {
+        let stmt = try_from_mut!(node, CallStatement).expect("CallStatement");
+        self.visit(&mut stmt.operator);
+        if let Some(parameters) = stmt.parameters.as_mut() {
+            self.visit_named_arguments(parameters);
+        }
+    }
+
+    fn visit_assignment(&mut self, node: &mut AstNode) {
+        self.visit_any_assignment(node);
+    }
+
+    fn visit_output_assignment(&mut self, node: &mut AstNode) {
+        self.visit_any_assignment(node);
+    }
+
+    fn visit_ref_assignment(&mut self, node: &mut AstNode) {
+        self.visit_any_assignment(node);
+    }
+}
+
+impl NamespaceResolver<'_> {
+    fn visit_any_assignment(&mut self, node: &mut AstNode) {
+        let in_named_arguments = self.in_named_arguments;
+        let stmt = try_from_mut!(node, Assignment).expect("Assignment");
+        // the left-hand side of a named argument or member initializer is no reference into a namespace
+        if !in_named_arguments {
+            self.visit(&mut stmt.left);
+        }
+        self.visit(&mut stmt.right);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_debug_snapshot;
+    use plc_ast::{ast::DataType, provider::IdProvider};
+
+    use crate::test_utils::tests::index_and_lower;
+
+    #[test]
+    fn type_references_are_resolved_to_qualified_names() {
+        let (unit, ..) = index_and_lower(
+            "
+            NAMESPACE vendor
+                TYPE point : STRUCT x, y : DINT; END_STRUCT END_TYPE
+                FUNCTION_BLOCK base END_FUNCTION_BLOCK
+                INTERFACE IMotor END_INTERFACE
+
+                NAMESPACE drives
+                    FUNCTION_BLOCK motor EXTENDS base IMPLEMENTS vendor.IMotor
+                    VAR
+                        position : point;
+                    END_VAR
+                    END_FUNCTION_BLOCK
+                END_NAMESPACE
+            END_NAMESPACE
+
+            USING vendor.drives;
+
+            PROGRAM main
+            VAR
+                a : motor;
+                b : ARRAY[0..1] OF vendor.drives.motor;
+                c : REF_TO vendor.point;
+                d : DINT;
+            END_VAR
+            END_PROGRAM
+            ",
+            IdProvider::default(),
+        );
+
+        let motor = unit.units.iter().find(|it| it.name == "vendor::drives::motor").unwrap();
+        assert_eq!(motor.super_class.as_deref(), Some("vendor::base"));
+        assert_eq!(motor.interfaces[0].name, "vendor::IMotor");
+
+        let types = unit
+            .units
+            .iter()
+            .filter(|it| it.name == "vendor::drives::motor" || it.name == "main")
+            .flat_map(|it| it.variable_blocks.iter().flat_map(|block| block.variables.iter()))
+            .map(|it| (it.name.as_str(), it.data_type_declaration.get_name()))
+            .collect::<Vec<_>>();
+        assert_debug_snapshot!(types, @r###"
+        [
+            (
+                "__BASE",
+                Some(
+                    "vendor::base",
+                ),
+            ),
+            (
+                "position",
+                Some(
+                    "vendor::point",
+                ),
+            ),
+            (
+                "a",
+                Some(
+                    "vendor::drives::motor",
+                ),
+            ),
+            (
+                "b",
+                Some(
+                    "__main_b",
+                ),
+            ),
+            (
+                "c",
+                Some(
+                    "__main_c",
+                ),
+            ),
+            (
+                "d",
+                Some(
+                    "DINT",
+                ),
+            ),
+        ]
+        "###);
+
+        let referenced_types = unit
+            .user_types
+            .iter()
+            .filter_map(|it| match &it.data_type {
+                DataType::ArrayType { name: Some(name), referenced_type, .. }
+                | DataType::PointerType { name: Some(name), referenced_type, .. }
+                    if name.starts_with("__main") =>
+                {
+                    referenced_type.get_name()
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(referenced_types, vec!["vendor::drives::motor", "vendor::point"]);
+    }
+
+    #[test]
+    fn references_in_bodies_are_resolved_to_qualified_names() {
+        let (unit, ..) = index_and_lower(
+            "
+            NAMESPACE lib
+                VAR_GLOBAL
+                    counter : DINT;
+                END_VAR
+
+                FUNCTION double : DINT
+                VAR_INPUT
+                    value : DINT;
+                END_VAR
+                    double := value * 2;
+                END_FUNCTION
+
+                FUNCTION triple : DINT
+                VAR_INPUT
+                    value : DINT;
+                END_VAR
+                    triple := double(value := value) + value;
+                END_FUNCTION
+            END_NAMESPACE
+
+            FUNCTION main : DINT
+            USING lib;
+            VAR
+                value : DINT;
+                counter : DINT;
+            END_VAR
+                main := double(value) + lib.double(value := lib.counter) + counter;
+            END_FUNCTION
+            ",
+            IdProvider::default(),
+        );
+
+        let statements =
+            |name: &str| &unit.implementations.iter().find(|it| it.name == name).unwrap().statements;
+        // the named argument `value` is left untouched
+        assert_debug_snapshot!(statements("lib::triple"));
+        // the local `counter` shadows `lib::counter`
+        assert_debug_snapshot!(statements("main"));
+    }
+}
@@ -0,0 +1,394 @@
+//! Lowers `PROPERTY` declarations into accessor methods and property accesses into calls to them.
+//!
+//! A property `foo : INT` declared in a POU `fb` is turned into the methods `fb.__get_foo : INT` and
+//! `fb.__set_foo` (taking the new value as a `VAR_INPUT` named `foo`). Within the `GET` accessor, the
+//! property name refers to the getter's return value, within the `SET` accessor it refers to the
+//! passed value.
+//!
+//! Once the project has been annotated, accesses to a property are rewritten into calls to its accessors:
+//! ```iecst
+//! fb.foo := x;    // becomes fb.__set_foo(x);
+//! y := fb.foo;    // becomes y := fb.__get_foo();
+//! ```
+
+use plc_ast::{
+    ast::{
+        ArgumentProperty, Assignment, AstFactory, AstNode, AstStatement, CompilationUnit,
+        DataTypeDeclaration, Implementation, LinkageType, PolymorphismMode, Pou, PouType, PropertyAccessor,
+        PropertyBlock, PropertyKind, ReferenceAccess, ReferenceExpr, UserTypeDeclaration, Variable,
+        VariableBlock, VariableBlockType,
+    },
+    mut_visitor::{AstVisitorMut, WalkerMut},
+    provider::IdProvider,
+    try_from, try_from_mut,
+};
+use plc_source::source_location::SourceLocation;
+use plc_util::convention::{internal_type_name, qualified_name};
+
+use crate::{
+    index::{Index, PropertyIndexEntry},
+    resolver::{AnnotationMap, StatementAnnotation},
+};
+
+#[derive(Default)]
+pub struct PropertyLowerer {
+    pub index: Option<Index>,
+    pub annotation: Option<Box<dyn AnnotationMap>>,
+    pub id_provider: IdProvider,
+}
+
+impl PropertyLowerer {
+    pub fn new(id_provider: IdProvider) -> Self {
+        Self { id_provider, ..Default::default() }
+    }
+
+    /// Moves the accessors of all properties declared in the given unit into methods. The property
+    /// declarations themselves remain in the unit so they can be indexed.
+    pub fn lower_properties(&mut self, unit: &mut CompilationUnit) {
+        let mut methods = vec![];
+        for pou in unit.units.iter_mut() {
+            for property in pou.properties.iter_mut() {
+                extract_datatype(&pou.name, property, &mut unit.user_types);
+                for accessor in std::mem::take(&mut property.accessors) {
+                    methods.push(self.create_accessor_method(&pou.name, pou.linkage, property, accessor));
+                }
+            }
+        }
+
+        for (method, implementation) in methods {
+            unit.units.push(method);
+            unit.implementations.push(implementation);
+        }
+
+        // interface accessors only consist of their declaration
+        for interface in unit.interfaces.iter_mut() {
+            for property in interface.properties.iter_mut() {
+                extract_datatype(&interface.name, property, &mut unit.user_types);
+                for accessor in std::mem::take(&mut property.accessors) {
+                    let (method, _) = self.create_accessor_method(
+                        &interface.name,
+                        LinkageType::Internal,
+                        property,
+                        accessor,
+                    );
+                    interface.methods.push(method);
+                }
+            }
+        }
+    }
+
+    /// Rewrites all reads and writes of properties in the given unit into calls to their accessor
+    /// methods. Requires the unit to be annotated.
+    pub fn lower_references(&mut self, unit: &mut CompilationUnit) {
+        if self.index.is_none() || self.annotation.is_none() {
+            //don't walk if we have no index or annotations to use
+            return;
+        }
+
+        for implementation in unit.implementations.iter_mut() {
+            self.visit_implementation(implementation);
+        }
+    }
+
+    fn create_accessor_method(
+        &mut self,
+        parent: &str,
+        linkage: LinkageType,
+        property: &PropertyBlock,
+        accessor: PropertyAccessor,
+    ) -> (Pou, Implementation) {
+        let PropertyAccessor { kind, mut variable_blocks, mut statements, location } = accessor;
+        let name = qualified_name(parent, &kind.get_method_name(&property.name));
+
+        let return_type = match kind {
+            PropertyKind::Get => {
+                let mut renamer = ReturnVariableRenamer {
+                    property: &property.name,
+                    return_name: Pou::calc_return_name(&name),
+                };
+                statements.iter_mut().for_each(|it| renamer.visit(it));
+                Some(property.datatype.clone())
+            }
+            PropertyKind::Set => {
+                variable_blocks.insert(
+                    0,
+                    VariableBlock {
+                        access: None,
+                        constant: false,
+                        retain: false,
+                        persistent: false,
+                        variables: vec![Variable {
+                            name: property.name.clone(),
+                            data_type_declaration: property.datatype.clone(),
+                            initializer: None,
+                            address: None,
+                            init_arguments: None,
+                            location: property.name_location.clone(),
+                            attributes: vec![],
+                        }],
+                        variable_block_type: VariableBlockType::Input(ArgumentProperty::ByVal),
+                        linkage: LinkageType::Internal,
+                        location: property.name_location.clone(),
+                        attributes: vec![],
+                    },
+                );
+                None
+            }
+        };
+
+        let kind = PouType::Method { parent: parent.into() };
+        let method = Pou {
+            name: name.clone(),
+            kind: kind.clone(),
+            variable_blocks,
+            return_type,
+            location: location.clone(),
+            name_location: property.name_location.clone(),
+            poly_mode: Some(PolymorphismMode::None),
+            generics: vec![],
+            generic_arguments: vec![],
+            linkage,
+            super_class: None,
+            interfaces: vec![],
+            properties: vec![],
+            is_const: false,
+            attributes: vec![],
+            sfc: None,
+        };
+
+        let implementation = Implementation {
+            name: name.clone(),
+            type_name: name,
+            linkage,
+            pou_type: kind,
+            statements,
+            location,
+            name_location: property.name_location.clone(),
+            overriding: false,
+            generic: false,
+            access: property.access.clone(),
+        };
+
+        (method, implementation)
+    }
+
+    /// Returns the property referenced by the given node
+    fn get_property(&self, node: &AstNode) -> Option<&PropertyIndexEntry> {
+        let Some(StatementAnnotation::Property { qualified_name, .. }) =
+            self.annotation.as_ref().and_then(|it| it.get(node))
+        else {
+            return None;
+        };
+
+        self.index.as_ref().and_then(|it| it.find_qualified_property(qualified_name))
+    }
+
+    /// Returns whether the given node references a property which defines the given accessor. References
+    /// to missing accessors are left untouched and reported by the validation
+    fn has_accessor(&self, node: &AstNode, kind: PropertyKind) -> bool {
+        let (Some(property), Some(index)) = (self.get_property(node), self.index.as_ref()) else {
+            return false;
+        };
+
+        property.find_accessor(index, kind).is_some()
+    }
+
+    /// Creates a call to the accessor of the referenced property, e.g. `fb.foo` becomes `fb.__get_foo()`
+    fn create_accessor_call(
+        &mut self,
+        reference: &AstNode,
+        kind: PropertyKind,
+        parameter: Option<AstNode>,
+        location: SourceLocation,
+    ) -> Option<AstNode> {
+        let ReferenceExpr { access: ReferenceAccess::Member(member), base } =
+            try_from!(reference, ReferenceExpr)?
+        else {
+            return None;
+        };
+        let name = member.get_flat_reference_name()?;
+
+        let operator = AstFactory::create_member_reference(
+            AstFactory::create_identifier(
+                &kind.get_method_name(name),
+                member.get_location(),
+                self.id_provider.next_id(),
+            ),
+            base.as_deref().cloned(),
+            self.id_provider.next_id(),
+        );
+
+        Some(AstFactory::create_call_statement(operator, parameter, self.id_provider.next_id(), location))
+    }
+}
+
+impl AstVisitorMut for PropertyLowerer {
+    fn visit_assignment(&mut self, node: &mut AstNode) {
+        let Some(assignment) = try_from_mut!(node, Assignment) else {
+            return;
+        };
+
+        if self.get_property(&assignment.left).is_none() {
+            assignment.walk(self);
+            return;
+        }
+
+        // writing to a property, the left side must not be turned into a getter call
+        self.visit(&mut assignment.right);
+        if !self.has_accessor(&assignment.left, PropertyKind::Set) {
+            return;
+        }
+
+        let location = node.get_location();
+        let AstStatement::Assignment(assignment) = node.get_stmt() else { unreachable!() };
+        if let Some(call) = self.create_accessor_call(
+            &assignment.left,
+            PropertyKind::Set,
+            Some(assignment.right.as_ref().clone()),
+            location,
+        ) {
+            *node = call;
+        }
+    }
+
+    fn visit_reference_expr(&mut self, node: &mut AstNode) {
+        if let Some(reference) = try_from_mut!(node, ReferenceExpr) {
+            reference.walk(self);
+        }
+
+        if self.has_accessor(node, PropertyKind::Get) {
+            if let Some(call) = self.create_accessor_call(node, PropertyKind::Get, None, node.get_location())
+            {
+                *node = call;
+            }
+        }
+    }
+}
+
+/// Moves an inline datatype definition of a property (e.g. `PROPERTY foo : ARRAY[0..5] OF INT`) into its
+/// own user type, so the property and its accessors share the same type
+fn extract_datatype(
+    container: &str,
+    property: &mut PropertyBlock,
+    user_types: &mut Vec<UserTypeDeclaration>,
+) {
+    if !matches!(property.datatype, DataTypeDeclaration::DataTypeDefinition { .. }) {
+        return;
+    }
+
+    let type_name = internal_type_name(&format!("{container}_"), &property.name);
+    let reference = DataTypeDeclaration::DataTypeReference {
+        referenced_type: type_name.clone(),
+        location: property.datatype.get_location(),
+    };
+    if let DataTypeDeclaration::DataTypeDefinition { mut data_type, location, scope } =
+        std::mem::replace(&mut property.datatype, reference)
+    {
+        data_type.set_name(type_name);
+        user_types.push(UserTypeDeclaration {
+            data_type,
+            initializer: None,
+            location,
+            scope,
+            attributes: vec![],
+        });
+    }
+}
+
+/// Renames references to the property within its `GET` accessor to the getter's return variable
+struct ReturnVariableRenamer<'a> {
+    property: &'a str,
+    return_name: &'a str,
+}
+
+impl AstVisitorMut for ReturnVariableRenamer<'_> {
+    fn visit_reference_expr(&mut self, node: &mut AstNode) {
+        let Some(reference) = try_from_mut!(node, ReferenceExpr) else {
+            return;
+        };
+
+        match (&mut reference.access, &reference.base) {
+            (ReferenceAccess::Member(member), None) => {
+                if let AstStatement::Identifier(name) = member.get_stmt_mut() {
+                    if name.eq_ignore_ascii_case(self.property) {
+                        *name = self.return_name.to_string();
+                    }
+                }
+            }
+            _ => reference.walk(self),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_debug_snapshot;
+    use plc_ast::provider::IdProvider;
+
+    use crate::test_utils::tests::{annotate_and_lower_with_ids, index_and_lower};
+
+    #[test]
+    fn property_accessors_are_lowered_into_methods() {
+        let id_provider = IdProvider::default();
+        let (unit, ..) = index_and_lower(
+            "
+            FUNCTION_BLOCK fb
+            VAR
+                localPrivateVariable : DINT;
+            END_VAR
+                PROPERTY foo : DINT
+                    GET
+                        foo := localPrivateVariable;
+                    END_GET
+                    SET
+                        localPrivateVariable := foo;
+                    END_SET
+                END_PROPERTY
+            END_FUNCTION_BLOCK
+            ",
+            id_provider,
+        );
+
+        assert_debug_snapshot!(unit.units);
+        assert_debug_snapshot!(unit.implementations);
+    }
+
+    #[test]
+    fn property_accesses_are_lowered_into_calls() {
+        let id_provider = IdProvider::default();
+        let (unit, index, _) = index_and_lower(
+            "
+            FUNCTION_BLOCK fb
+            VAR
+                localPrivateVariable : DINT;
+            END_VAR
+                PROPERTY foo : DINT
+                    GET
+                        foo := localPrivateVariable;
+                    END_GET
+                    SET
+                        localPrivateVariable := foo;
+                    END_SET
+                END_PROPERTY
+
+                foo := foo + 1;
+            END_FUNCTION_BLOCK
+
+            FUNCTION main
+            VAR
+                instance : fb;
+                x : DINT;
+            END_VAR
+                instance.foo := 5;
+                x := instance.foo;
+            END_FUNCTION
+            ",
+            id_provider.clone(),
+        );
+        let (_, _, units) = annotate_and_lower_with_ids(unit, index, id_provider);
+
+        let implementations = &units[0].0.implementations;
+        assert_debug_snapshot!(implementations.iter().find(|it| it.name == "fb").unwrap().statements);
+        assert_debug_snapshot!(implementations.iter().find(|it| it.name == "main").unwrap().statements);
+    }
+}
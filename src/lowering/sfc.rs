@@ -0,0 +1,430 @@
+//! Lowers the sequential function charts (SFC) of `PROGRAM`s and `FUNCTION_BLOCK`s into a state machine.
+//!
+//! Every step `s` becomes a member `s : __SFC_STEP`, whose flags `s.X` (the step is active) and `s.T` (the
+//! time elapsed since the step was activated) can be accessed like any other variable. The body of the
+//! POU is replaced by statements which, on every call,
+//! 1. update the elapsed time of all steps using a hidden `TON` timer from the standard library,
+//! 2. execute the actions associated with the active steps according to their qualifiers and
+//! 3. fire the transitions whose preceding steps are active and whose condition holds.
+//!
+//! Since the transitions are evaluated against the steps active at the beginning of the cycle, a step
+//! stays active for at least one cycle and its actions are executed at least once:
+//! ```iecst
+//! INITIAL_STEP idle : END_STEP
+//! STEP fill : open_valve(N); END_STEP
+//! TRANSITION FROM idle TO fill := start; END_TRANSITION
+//! ```
+//! becomes
+//! ```iecst
+//! __idle_timer(IN := idle.X, PT := T#106751d); idle.T := __idle_timer.ET;
+//! __fill_timer(IN := fill.X, PT := T#106751d); fill.T := __fill_timer.ET;
+//! IF fill.X THEN open_valve(); END_IF
+//! __idle_active := idle.X; __fill_active := fill.X;
+//! IF __idle_active AND idle.X AND start THEN idle.X := FALSE; fill.X := TRUE; END_IF
+//! ```
+//! Actions associated with a `S` qualifier keep executing until they are reset by an `R` qualifier, which
+//! is tracked in a hidden `__<action>_stored` member. If a `BOOL` member of the POU is associated with a
+//! step instead of an action, it is assigned whether the association is currently active.
+
+use plc_ast::{
+    ast::{
+        ActionAssociation, ActionQualifier, AstFactory, AstNode, CompilationUnit, DataType,
+        DataTypeDeclaration, LinkageType, Operator, Pou, PouType, Sfc, SfcStep, UserTypeDeclaration,
+        Variable, VariableBlock, VariableBlockType,
+    },
+    control_statements::ConditionalBlock,
+    literals::{AstLiteral, Time},
+    provider::IdProvider,
+};
+use plc_source::source_location::SourceLocation;
+use rustc_hash::FxHashSet;
+
+use crate::typesystem::{BOOL_TYPE, TIME_TYPE};
+
+/// The type of the members representing the steps of a sequential function chart
+pub const SFC_STEP_TYPE: &str = "__SFC_STEP";
+
+/// The standard library's on-delay timer measuring the time a step is active
+const STEP_TIMER_TYPE: &str = "TON";
+
+/// The longest duration representable by a `TIME`, used as the preset of the step timers so they keep
+/// counting for as long as their step is active
+const MAX_TIME_DAYS: f64 = 106751.0;
+
+pub struct SfcLowerer {
+    pub id_provider: IdProvider,
+}
+
+impl SfcLowerer {
+    pub fn new(id_provider: IdProvider) -> Self {
+        Self { id_provider }
+    }
+
+    /// Replaces the bodies of all POUs implemented as a sequential function chart with the equivalent
+    /// state machine. The `__SFC_STEP` type is declared once in the first unit containing a chart.
+    pub fn lower_sfcs(&mut self, units: &mut [CompilationUnit]) {
+        let mut step_type_declared = false;
+        for unit in units.iter_mut() {
+            for pou in unit.units.iter_mut() {
+                // charts of other POUs are reported by the validation
+                let is_stateful = matches!(pou.kind, PouType::Program | PouType::FunctionBlock);
+                let Some(sfc) = pou.sfc.as_ref().filter(|_| is_stateful) else {
+                    continue;
+                };
+
+                let (variable_block, statements) = self.lower_sfc(pou, sfc);
+                pou.variable_blocks.push(variable_block);
+                if let Some(implementation) = unit.implementations.iter_mut().find(|it| it.name == pou.name) {
+                    implementation.statements = statements;
+                }
+
+                if !step_type_declared {
+                    unit.user_types.push(create_step_type(pou.name_location.clone()));
+                    step_type_declared = true;
+                }
+            }
+        }
+    }
+
+    /// Returns the members holding the state of the given chart and the statements executing it
+    fn lower_sfc(&mut self, pou: &Pou, sfc: &Sfc) -> (VariableBlock, Vec<AstNode>) {
+        // duplicate steps and transitions referring to unknown steps are reported by the validation
+        let mut known_steps = FxHashSet::default();
+        let steps =
+            sfc.steps.iter().filter(|it| known_steps.insert(it.name.to_lowercase())).collect::<Vec<_>>();
+
+        let mut variables = vec![];
+        let mut statements = vec![];
+        for step in steps.iter() {
+            let location = &step.name_location;
+            let initializer = step.initial.then(|| {
+                let flag = self.reference("X", location);
+                let value = self.literal(AstLiteral::new_bool(true), location);
+                let assignment = AstFactory::create_assignment(flag, value, self.id_provider.next_id());
+                AstFactory::create_paren_expression(assignment, location.clone(), self.id_provider.next_id())
+            });
+            variables.push(create_variable(&step.name, SFC_STEP_TYPE, initializer, location));
+            variables.push(create_variable(&timer_name(&step.name), STEP_TIMER_TYPE, None, location));
+            variables.push(create_variable(&active_name(&step.name), BOOL_TYPE, None, location));
+
+            statements.extend(self.update_elapsed_time(step));
+        }
+
+        for (action, associations) in group_by_action(&steps) {
+            let is_stored = associations.iter().any(|(_, it)| it.qualifier == ActionQualifier::Set);
+            if is_stored {
+                let location = &associations[0].1.location;
+                variables.push(create_variable(&stored_name(&action), BOOL_TYPE, None, location));
+            }
+            statements.extend(self.execute_action(pou, &action, &associations, is_stored));
+        }
+
+        // remember which steps were active before firing the transitions
+        for step in steps.iter() {
+            let location = &step.name_location;
+            let active = self.reference(&active_name(&step.name), location);
+            let flag = self.member(&step.name, "X", location);
+            statements.push(AstFactory::create_assignment(active, flag, self.id_provider.next_id()));
+        }
+
+        for transition in sfc.transitions.iter() {
+            let refers_to_known_steps = |names: &[(String, SourceLocation)]| {
+                !names.is_empty() && names.iter().all(|(name, _)| known_steps.contains(&name.to_lowercase()))
+            };
+            if !refers_to_known_steps(&transition.from) || !refers_to_known_steps(&transition.to) {
+                continue;
+            }
+
+            let mut condition = transition.condition.clone();
+            for (name, location) in transition.from.iter().rev() {
+                let flag = self.member(name, "X", location);
+                condition = self.binary(flag, Operator::And, condition);
+                let active = self.reference(&active_name(name), location);
+                condition = self.binary(active, Operator::And, condition);
+            }
+
+            let mut body = vec![];
+            for (names, value) in [(&transition.from, false), (&transition.to, true)] {
+                for (name, location) in names {
+                    let flag = self.member(name, "X", location);
+                    let value = self.literal(AstLiteral::new_bool(value), location);
+                    body.push(AstFactory::create_assignment(flag, value, self.id_provider.next_id()));
+                }
+            }
+            statements.push(self.if_statement(condition, body, &transition.location));
+        }
+
+        let variable_block = VariableBlock {
+            variables,
+            variable_block_type: VariableBlockType::Local,
+            linkage: LinkageType::Internal,
+            location: pou.name_location.clone(),
+            ..Default::default()
+        };
+        (variable_block, statements)
+    }
+
+    /// Creates `__s_timer(IN := s.X, PT := T#106751d); s.T := __s_timer.ET;`
+    fn update_elapsed_time(&mut self, step: &SfcStep) -> Vec<AstNode> {
+        let location = &step.name_location;
+        let timer = timer_name(&step.name);
+
+        let input = self.reference("IN", location);
+        let flag = self.member(&step.name, "X", location);
+        let preset = self.reference("PT", location);
+        let max_time = self.literal(
+            AstLiteral::Time(Time {
+                day: MAX_TIME_DAYS,
+                hour: 0.0,
+                min: 0.0,
+                sec: 0.0,
+                milli: 0.0,
+                micro: 0.0,
+                nano: 0,
+                negative: false,
+            }),
+            location,
+        );
+        let parameters = AstFactory::create_expression_list(
+            vec![
+                AstFactory::create_assignment(input, flag, self.id_provider.next_id()),
+                AstFactory::create_assignment(preset, max_time, self.id_provider.next_id()),
+            ],
+            location.clone(),
+            self.id_provider.next_id(),
+        );
+        let operator = self.reference(&timer, location);
+        let call = AstFactory::create_call_statement(
+            operator,
+            Some(parameters),
+            self.id_provider.next_id(),
+            location.clone(),
+        );
+
+        let elapsed_time = self.member(&step.name, "T", location);
+        let timer_output = self.member(&timer, "ET", location);
+        vec![call, AstFactory::create_assignment(elapsed_time, timer_output, self.id_provider.next_id())]
+    }
+
+    /// Creates the statements controlling the given action, e.g. `IF s.X OR __a_stored THEN a(); END_IF`
+    fn execute_action(
+        &mut self,
+        pou: &Pou,
+        action: &str,
+        associations: &[(&SfcStep, &ActionAssociation)],
+        is_stored: bool,
+    ) -> Vec<AstNode> {
+        let mut statements = vec![];
+        // the reset qualifier dominates, so all associations setting the action are handled first
+        if is_stored {
+            for qualifier in [ActionQualifier::Set, ActionQualifier::Reset] {
+                for (step, association) in associations.iter().filter(|(_, it)| it.qualifier == qualifier) {
+                    let location = &association.location;
+                    let stored = self.reference(&stored_name(action), location);
+                    let value =
+                        self.literal(AstLiteral::new_bool(qualifier == ActionQualifier::Set), location);
+                    let assignment = AstFactory::create_assignment(stored, value, self.id_provider.next_id());
+                    let flag = self.member(&step.name, "X", location);
+                    statements.push(self.if_statement(flag, vec![assignment], location));
+                }
+            }
+        }
+
+        let mut activity: Option<AstNode> = None;
+        let mut stored_added = false;
+        for (step, association) in associations {
+            let location = &association.location;
+            let term = match association.qualifier {
+                ActionQualifier::NonStored => self.member(&step.name, "X", location),
+                ActionQualifier::Set if !stored_added => {
+                    stored_added = true;
+                    self.reference(&stored_name(action), location)
+                }
+                ActionQualifier::Set | ActionQualifier::Reset => continue,
+                ActionQualifier::Pulse => {
+                    let flag = self.member(&step.name, "X", location);
+                    let active = self.reference(&active_name(&step.name), location);
+                    let not_active = AstFactory::create_not_expression(
+                        active,
+                        location.clone(),
+                        self.id_provider.next_id(),
+                    );
+                    self.binary(flag, Operator::And, not_active)
+                }
+                ActionQualifier::TimeLimited | ActionQualifier::TimeDelayed => {
+                    let Some(duration) = association.duration.clone() else {
+                        // reported by the parser
+                        continue;
+                    };
+                    let operator = if association.qualifier == ActionQualifier::TimeLimited {
+                        Operator::Less
+                    } else {
+                        Operator::GreaterOrEqual
+                    };
+                    let flag = self.member(&step.name, "X", location);
+                    let elapsed_time = self.member(&step.name, "T", location);
+                    let comparison = self.binary(elapsed_time, operator, duration);
+                    self.binary(flag, Operator::And, comparison)
+                }
+            };
+            activity = Some(match activity {
+                Some(activity) => self.binary(activity, Operator::Or, term),
+                None => term,
+            });
+        }
+
+        let Some(activity) = activity else {
+            return statements;
+        };
+
+        let location = &associations[0].1.location;
+        let is_variable = pou
+            .variable_blocks
+            .iter()
+            .flat_map(|it| it.variables.iter())
+            .any(|it| it.name.eq_ignore_ascii_case(action));
+        if is_variable {
+            let variable = self.reference(action, location);
+            statements.push(AstFactory::create_assignment(variable, activity, self.id_provider.next_id()));
+        } else {
+            let operator = self.reference(action, location);
+            let call = AstFactory::create_call_statement(
+                operator,
+                None,
+                self.id_provider.next_id(),
+                location.clone(),
+            );
+            statements.push(self.if_statement(activity, vec![call], location));
+        }
+        statements
+    }
+
+    fn reference(&mut self, name: &str, location: &SourceLocation) -> AstNode {
+        AstFactory::create_member_reference(
+            AstFactory::create_identifier(name, location, self.id_provider.next_id()),
+            None,
+            self.id_provider.next_id(),
+        )
+    }
+
+    /// Creates a reference to the given member of a step, e.g. `s.X`
+    fn member(&mut self, step: &str, member: &str, location: &SourceLocation) -> AstNode {
+        let base = self.reference(step, location);
+        AstFactory::create_member_reference(
+            AstFactory::create_identifier(member, location, self.id_provider.next_id()),
+            Some(base),
+            self.id_provider.next_id(),
+        )
+    }
+
+    fn literal(&mut self, literal: AstLiteral, location: &SourceLocation) -> AstNode {
+        AstFactory::create_literal(literal, location.clone(), self.id_provider.next_id())
+    }
+
+    fn binary(&mut self, left: AstNode, operator: Operator, right: AstNode) -> AstNode {
+        AstFactory::create_binary_expression(left, operator, right, self.id_provider.next_id())
+    }
+
+    fn if_statement(&mut self, condition: AstNode, body: Vec<AstNode>, location: &SourceLocation) -> AstNode {
+        AstFactory::create_if_statement(
+            vec![ConditionalBlock { condition: Box::new(condition), body }],
+            vec![],
+            location.clone(),
+            self.id_provider.next_id(),
+        )
+    }
+}
+
+/// Groups the action associations of the given steps by the associated action, in the order the actions
+/// are first associated
+fn group_by_action<'a>(steps: &[&'a SfcStep]) -> Vec<(String, Vec<(&'a SfcStep, &'a ActionAssociation)>)> {
+    let mut actions: Vec<(String, Vec<_>)> = vec![];
+    for step in steps {
+        for association in step.actions.iter() {
+            match actions.iter_mut().find(|(name, _)| name.eq_ignore_ascii_case(&association.action)) {
+                Some((_, associations)) => associations.push((*step, association)),
+                None => actions.push((association.action.clone(), vec![(*step, association)])),
+            }
+        }
+    }
+    actions
+}
+
+fn timer_name(step: &str) -> String {
+    format!("__{step}_timer")
+}
+
+fn active_name(step: &str) -> String {
+    format!("__{step}_active")
+}
+
+fn stored_name(action: &str) -> String {
+    format!("__{action}_stored")
+}
+
+fn create_variable(
+    name: &str,
+    type_name: &str,
+    initializer: Option<AstNode>,
+    location: &SourceLocation,
+) -> Variable {
+    Variable {
+        name: name.into(),
+        data_type_declaration: DataTypeDeclaration::DataTypeReference {
+            referenced_type: type_name.into(),
+            location: location.clone(),
+        },
+        initializer,
+        address: None,
+        init_arguments: None,
+        location: location.clone(),
+        attributes: vec![],
+    }
+}
+
+/// Creates the `__SFC_STEP` struct holding the `X` and `T` flags of a step
+fn create_step_type(location: SourceLocation) -> UserTypeDeclaration {
+    UserTypeDeclaration {
+        data_type: DataType::StructType {
+            name: Some(SFC_STEP_TYPE.into()),
+            variables: vec![
+                create_variable("X", BOOL_TYPE, None, &location),
+                create_variable("T", TIME_TYPE, None, &location),
+            ],
+            base: None,
+        },
+        initializer: None,
+        location,
+        scope: None,
+        attributes: vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_debug_snapshot;
+    use plc_ast::provider::IdProvider;
+
+    use crate::test_utils::tests::index_and_lower;
+
+    #[test]
+    fn sfc_is_lowered_into_statements() {
+        let id_provider = IdProvider::default();
+        let (unit, ..) = index_and_lower(
+            "
+            PROGRAM mixer
+            VAR
+                start : BOOL;
+            END_VAR
+                INITIAL_STEP idle : END_STEP
+                STEP fill : stir(P); stir(L, T#5s); END_STEP
+                TRANSITION FROM idle TO fill := start; END_TRANSITION
+            END_PROGRAM
+            ",
+            id_provider,
+        );
+
+        assert_debug_snapshot!(unit.units[0].variable_blocks[1]);
+        assert_debug_snapshot!(unit.implementations[0].statements);
+    }
+}
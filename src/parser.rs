@@ -4,11 +4,12 @@ use std::ops::Range;
 
 use plc_ast::{
     ast::{
-        AccessModifier, ArgumentProperty, AstFactory, AstNode, AstStatement, AutoDerefType, CompilationUnit,
-        ConfigVariable, DataType, DataTypeDeclaration, DirectAccessType, GenericBinding, HardwareAccessType,
-        Implementation, Interface, InterfaceIdentifier, LinkageType, PolymorphismMode, Pou, PouType,
-        ReferenceAccess, ReferenceExpr, TypeNature, UserTypeDeclaration, Variable, VariableBlock,
-        VariableBlockType,
+        AccessMode, AccessModifier, AccessVariable, ArgumentProperty, Assignment, AstFactory, AstNode,
+        AstStatement, Attribute, AutoDerefType, CompilationUnit, ConfigVariable, Configuration, DataType,
+        DataTypeDeclaration, DirectAccessType, GenericBinding, HardwareAccessType, Implementation, Interface,
+        InterfaceIdentifier, LinkageType, PolymorphismMode, Pou, PouType, ProgramConfiguration,
+        PropertyAccessor, PropertyBlock, PropertyKind, ReferenceAccess, ReferenceExpr, Resource, Task,
+        TypeNature, UserTypeDeclaration, UsingDirective, Variable, VariableBlock, VariableBlockType,
     },
     provider::IdProvider,
 };
@@ -18,17 +19,17 @@ use plc_diagnostics::{
 };
 use plc_source::{
     source_location::{SourceLocation, SourceLocationFactory},
-    SourceCode, SourceContainer,
+    SourceCode, SourceContainer, SourceType,
 };
-use plc_util::convention::qualified_name;
+use plc_util::convention::{namespaced_name, qualified_name, NAMESPACE_SEPARATOR};
 
 use crate::{
     expect_token,
     lexer::{
-        self, ParseSession,
+        self, Defines, ParseSession,
         Token::{self, *},
     },
-    typesystem::DINT_TYPE,
+    typesystem::{self, DINT_TYPE},
 };
 
 use self::{
@@ -38,6 +39,8 @@ use self::{
 
 mod control_parser;
 pub mod expressions_parser;
+mod il_parser;
+mod sfc_parser;
 
 #[cfg(test)]
 pub mod tests;
@@ -47,14 +50,13 @@ pub fn parse_file(
     source: &SourceCode,
     linkage: LinkageType,
     id_provider: IdProvider,
+    defines: &Defines,
     diagnostician: &mut Diagnostician,
 ) -> Result<CompilationUnit, Diagnostic> {
     let location_factory = SourceLocationFactory::for_source(source);
-    let (unit, errors) = parse(
-        lexer::lex_with_ids(&source.source, id_provider, location_factory),
-        linkage,
-        source.get_location_str(),
-    );
+    let mut lexer = lexer::lex_with_defines(&source.source, id_provider, location_factory, defines.clone());
+    lexer.instruction_list = matches!(source.get_type(), SourceType::Il);
+    let (unit, errors) = parse(lexer, linkage, source.get_location_str());
     //Register the source file with the diagnostician
     //TODO: We should reduce the clone here
     diagnostician.register_file(source.get_location_str().to_string(), source.source.clone()); // TODO: Remove clone here, generally passing the GlobalContext instead of the actual source here or in the handle method should be sufficient
@@ -65,11 +67,20 @@ pub fn parse_file(
     }
 }
 
+/// Parses a list of statements which are not part of a POU, e.g. the textual body of a POU declared in
+/// an XML file
+pub fn parse_statements(mut lexer: ParseSession) -> (Vec<AstNode>, Vec<Diagnostic>) {
+    let statements = parse_body_standalone(&mut lexer);
+    (statements, lexer.diagnostics)
+}
+
 pub fn parse(mut lexer: ParseSession, lnk: LinkageType, file_name: &str) -> ParsedAst {
     let mut unit = CompilationUnit::new(file_name);
 
     let mut linkage = lnk;
     let mut constant = false;
+    // the qualified names of the currently open `NAMESPACE` blocks, innermost last
+    let mut namespaces: Vec<String> = vec![];
     loop {
         match lexer.token {
             PropertyExternal => {
@@ -89,8 +100,39 @@ pub fn parse(mut lexer: ParseSession, lnk: LinkageType, file_name: &str) -> Pars
                 let (interfaces, _) = parse_interface(&mut lexer);
                 unit.interfaces.push(interfaces);
             }
-            KeywordVarGlobal => unit.global_vars.push(parse_variable_block(&mut lexer, linkage)),
+            KeywordNamespace => {
+                lexer.advance();
+                let (name, _) = parse_qualified_identifier(&mut lexer)
+                    .unwrap_or_else(|| ("".to_string(), SourceLocation::undefined()));
+                let namespace = qualify_with_namespace(&lexer, name.replace('.', NAMESPACE_SEPARATOR));
+                lexer.namespace = Some(namespace.clone());
+                namespaces.push(namespace);
+            }
+            KeywordEndNamespace => {
+                if namespaces.pop().is_none() {
+                    lexer.accept_diagnostic(Diagnostic::unexpected_token_found(
+                        "StartKeyword",
+                        lexer.slice(),
+                        lexer.location(),
+                    ));
+                }
+                lexer.namespace = namespaces.last().cloned();
+                lexer.advance();
+            }
+            KeywordUsing => unit.usings.append(&mut parse_using_directives(&mut lexer)),
+            KeywordVarGlobal => {
+                let mut block = parse_variable_block(&mut lexer, linkage);
+                for variable in block.variables.iter_mut() {
+                    variable.name = qualify_with_namespace(&lexer, std::mem::take(&mut variable.name));
+                }
+                unit.global_vars.push(block);
+            }
             KeywordVarConfig => unit.var_config.extend(parse_config_variables(&mut lexer)),
+            KeywordVarAccess => unit.var_access.extend(parse_access_variables(&mut lexer)),
+            KeywordConfiguration => {
+                let configuration = parse_configuration(&mut lexer, &mut unit, linkage);
+                unit.configurations.push(configuration);
+            }
 
             KeywordProgram | KeywordClass | KeywordFunction | KeywordFunctionBlock => {
                 let params = match lexer.token {
@@ -100,11 +142,12 @@ pub fn parse(mut lexer: ParseSession, lnk: LinkageType, file_name: &str) -> Pars
                     _ => (PouType::FunctionBlock, KeywordEndFunctionBlock),
                 };
 
-                let (mut pou, mut implementation) =
+                let (mut pou, mut implementation, mut usings) =
                     parse_pou(&mut lexer, params.0, linkage, params.1, constant);
 
                 unit.units.append(&mut pou);
                 unit.implementations.append(&mut implementation);
+                unit.usings.append(&mut usings);
 
                 // reset const pragma
                 constant = false;
@@ -125,7 +168,15 @@ pub fn parse(mut lexer: ParseSession, lnk: LinkageType, file_name: &str) -> Pars
                     unit.user_types.push(utype);
                 }
             }
-            KeywordEndActions | End => return (unit, lexer.diagnostics),
+            KeywordEndActions | End => {
+                if !namespaces.is_empty() {
+                    lexer.accept_diagnostic(Diagnostic::missing_token(
+                        "KeywordEndNamespace",
+                        lexer.location(),
+                    ));
+                }
+                return (unit, lexer.diagnostics);
+            }
             _ => {
                 lexer.accept_diagnostic(Diagnostic::unexpected_token_found(
                     "StartKeyword",
@@ -146,8 +197,12 @@ fn parse_actions(
 ) -> Vec<Implementation> {
     parse_any_in_region(lexer, vec![KeywordEndActions], |lexer| {
         lexer.advance();
-        let container =
-            if lexer.token == Identifier { lexer.slice_and_advance() } else { default_container.into() };
+        let container = if lexer.token == Identifier {
+            let container = lexer.slice_and_advance();
+            qualify_with_namespace(lexer, container)
+        } else {
+            default_container.into()
+        };
         let mut impls = vec![];
 
         //Go through each action
@@ -178,7 +233,10 @@ fn parse_interface(lexer: &mut ParseSession) -> (Interface, Vec<Implementation>)
     lexer.try_consume_or_report(KeywordInterface);
 
     let (name, location_name) = match lexer.token {
-        Token::Identifier => parse_identifier(lexer).expect("unreachable, already matched here"),
+        Token::Identifier => {
+            let (name, location) = parse_identifier(lexer).expect("unreachable, already matched here");
+            (qualify_with_namespace(lexer, name), location)
+        }
 
         _ => {
             lexer.accept_diagnostic(
@@ -194,6 +252,7 @@ fn parse_interface(lexer: &mut ParseSession) -> (Interface, Vec<Implementation>)
     };
 
     let mut methods = Vec::new();
+    let mut properties = Vec::new();
     let mut implementations = Vec::new();
     loop {
         match lexer.token {
@@ -213,7 +272,19 @@ fn parse_interface(lexer: &mut ParseSession) -> (Interface, Vec<Implementation>)
                 }
             }
 
-            KeywordProperty => unimplemented!("not yet supported"),
+            KeywordProperty => {
+                if let Some(property) = parse_property(lexer) {
+                    for accessor in property.accessors.iter().filter(|it| !it.statements.is_empty()) {
+                        lexer.accept_diagnostic(
+                            Diagnostic::new("Interfaces can not have a default implementations")
+                                .with_error_code("E113")
+                                .with_location(&accessor.location),
+                        );
+                    }
+
+                    properties.push(property);
+                }
+            }
 
             _ => break,
         }
@@ -226,6 +297,7 @@ fn parse_interface(lexer: &mut ParseSession) -> (Interface, Vec<Implementation>)
         Interface {
             name,
             methods,
+            properties,
             location: lexer.source_range_factory.create_range(location_start..location_end),
             location_name,
         },
@@ -248,13 +320,20 @@ fn parse_pou(
     linkage: LinkageType,
     expected_end_token: lexer::Token,
     constant: bool,
-) -> (Vec<Pou>, Vec<Implementation>) {
+) -> (Vec<Pou>, Vec<Implementation>, Vec<UsingDirective>) {
     if constant && !matches!(linkage, LinkageType::BuiltIn) {
         lexer.accept_diagnostic(Diagnostic::const_pragma_is_not_allowed(
             lexer.last_location().span(&lexer.location()),
         ));
     }
 
+    let attributes = lexer.take_attributes();
+    // the body of a POU is written in the language of its file, unless selected with
+    // `{attribute 'language' := 'IL'}` or `{attribute 'language' := 'ST'}`
+    let file_language = lexer.instruction_list;
+    if let Some(language) = attributes.iter().find(|it| it.name.eq_ignore_ascii_case("language")) {
+        lexer.instruction_list = language.value.as_deref().is_some_and(|it| it.eq_ignore_ascii_case("IL"));
+    }
     let start = lexer.range().start;
     lexer.advance(); //Consume ProgramKeyword
     let closing_tokens = vec![
@@ -272,11 +351,12 @@ fn parse_pou(
 
         let (name, name_location) =
             parse_identifier(lexer).unwrap_or_else(|| ("".to_string(), SourceLocation::undefined())); // parse POU name
+        let name = qualify_with_namespace(lexer, name);
 
         let generics = parse_generics(lexer);
 
         with_scope(lexer, name.clone(), |lexer| {
-            // TODO: Parse USING directives
+            let mut usings = parse_using_directives(lexer);
             let super_class = parse_super_class(lexer);
             let interfaces = parse_interface_declarations(lexer);
 
@@ -284,6 +364,9 @@ fn parse_pou(
             // classes do not have a return type (check in validator)
             let return_type = parse_return_type(lexer, &kind);
 
+            // the standard places the USING directives after the signature, we accept both positions
+            usings.append(&mut parse_using_directives(lexer));
+
             // parse variable declarations. note that var in/out/inout
             // blocks are not allowed inside of class declarations.
             let mut variable_blocks = vec![];
@@ -301,6 +384,7 @@ fn parse_pou(
 
             let mut impl_pous = vec![];
             let mut implementations = vec![];
+            let mut properties = vec![];
 
             // classes and function blocks can have methods. methods consist of a Pou part
             // and an implementation part. That's why we get another (Pou, Implementation)
@@ -308,16 +392,37 @@ fn parse_pou(
             // implementations. Note that function blocks have to start with the method
             // declarations before their implementation.
             // all other Pous need to be checked in the validator if they can have methods.
-            while matches!(lexer.token, KeywordMethod | PropertyConstant) {
+            // properties are kept on the pou and lowered into methods before indexing.
+            while matches!(lexer.token, KeywordMethod | KeywordProperty | PropertyConstant) {
                 if !matches!(kind, PouType::FunctionBlock | PouType::Class | PouType::Program) {
-                    let location = lexer.source_range_factory.create_range(lexer.last_range.clone());
+                    if lexer.token == KeywordProperty {
+                        lexer.accept_diagnostic(
+                            Diagnostic::new(format!(
+                                "Properties cannot be declared in a POU of type '{kind}'."
+                            ))
+                            .with_error_code("E114")
+                            .with_location(lexer.location()),
+                        );
+                        // the property is parsed but dropped, so we can continue with the pou's body
+                        parse_property(lexer);
+                        continue;
+                    }
 
+                    let location = lexer.source_range_factory.create_range(lexer.last_range.clone());
                     lexer.accept_diagnostic(
                         Diagnostic::new(format!("Methods cannot be declared in a POU of type '{kind}'."))
                             .with_location(location),
                     );
                     break;
                 }
+
+                if lexer.token == KeywordProperty {
+                    if let Some(property) = parse_property(lexer) {
+                        properties.push(property);
+                    }
+                    continue;
+                }
+
                 let const_method = lexer.try_consume(PropertyConstant);
                 if let Some((pou, implementation)) = parse_method(lexer, &name, linkage, const_method) {
                     impl_pous.push(pou);
@@ -325,9 +430,18 @@ fn parse_pou(
                 }
             }
 
+            // the body is either a sequential function chart, which is lowered into statements before
+            // indexing, or a list of statements
+            let body_start = lexer.range().start;
+            let sfc = sfc_parser::is_sfc_body(lexer).then(|| {
+                let (sfc, mut actions) = sfc_parser::parse_sfc(lexer, linkage, &name);
+                implementations.append(&mut actions);
+                sfc
+            });
+
             // a class may not contain an implementation
             // check in validator
-            implementations.push(parse_implementation(
+            let mut implementation = parse_implementation(
                 lexer,
                 linkage,
                 kind.clone(),
@@ -335,7 +449,12 @@ fn parse_pou(
                 &name,
                 !generics.is_empty(),
                 name_location.clone(),
-            ));
+            );
+            if sfc.is_some() {
+                implementation.location =
+                    lexer.source_range_factory.create_range(body_start..lexer.last_range.end);
+            }
+            implementations.push(implementation);
 
             let mut pous = vec![Pou {
                 name,
@@ -346,17 +465,23 @@ fn parse_pou(
                 name_location,
                 poly_mode,
                 generics,
+                generic_arguments: vec![],
                 linkage,
                 super_class,
                 interfaces,
+                properties,
                 is_const: constant,
+                attributes,
+                sfc,
             }];
             pous.append(&mut impl_pous);
 
-            (pous, implementations)
+            (pous, implementations, usings)
         })
     });
 
+    lexer.instruction_list = file_language;
+
     //check if we ended on the right end-keyword
     if closing_tokens.contains(&lexer.last_token) && lexer.last_token != expected_end_token {
         lexer.accept_diagnostic(Diagnostic::unexpected_token_found(
@@ -396,6 +521,40 @@ fn parse_generics(lexer: &mut ParseSession) -> Vec<GenericBinding> {
     }
 }
 
+/// Parses the type arguments of a generic POU's instance, e.g. `REAL` and `INT` in `FB_Pair<REAL, INT>`
+fn parse_type_arguments(lexer: &mut ParseSession) -> Vec<String> {
+    lexer.advance(); // consume `<`
+    parse_any_in_region(lexer, vec![OperatorGreater], |lexer| {
+        let mut arguments = vec![];
+        loop {
+            if !matches!(lexer.token, Identifier | KeywordString | KeywordWideString) {
+                lexer.accept_diagnostic(Diagnostic::unexpected_token_found(
+                    "Type",
+                    lexer.slice(),
+                    lexer.location(),
+                ));
+                break;
+            }
+
+            let mut argument = lexer.slice_and_advance();
+            while lexer.token == KeywordDot {
+                lexer.advance();
+                argument = qualified_name(argument, lexer.slice_and_advance());
+            }
+            if lexer.token == OperatorLess {
+                argument = format!("{argument}<{}>", parse_type_arguments(lexer).join(","));
+            }
+            arguments.push(argument);
+
+            if !lexer.try_consume(KeywordComma) {
+                break;
+            }
+        }
+
+        arguments
+    })
+}
+
 /// Parses the comma seperated identifiers after an `IMPLEMENTS` keyword, e.g. `bar` and `baz` in
 /// `INTERFACE foo IMPLEMENTS bar`
 fn parse_interface_declarations(lexer: &mut ParseSession) -> Vec<InterfaceIdentifier> {
@@ -420,7 +579,7 @@ fn parse_interface_declarations(lexer: &mut ParseSession) -> Vec<InterfaceIdenti
     loop {
         match lexer.token {
             Token::Identifier => {
-                let (name, location) = parse_identifier(lexer).expect("Identifier already matched");
+                let (name, location) = parse_qualified_identifier(lexer).expect("Identifier already matched");
                 declarations.push(InterfaceIdentifier { name, location });
             }
             Token::KeywordComma => lexer.advance(),
@@ -481,7 +640,7 @@ fn parse_polymorphism_mode(lexer: &mut ParseSession, pou_type: &PouType) -> Opti
 
 fn parse_super_class(lexer: &mut ParseSession) -> Option<String> {
     if lexer.try_consume(KeywordExtends) {
-        let (name, _) = parse_identifier(lexer)?;
+        let (name, _) = parse_qualified_identifier(lexer)?;
         Some(name)
     } else {
         None
@@ -513,7 +672,10 @@ fn parse_return_type(lexer: &mut ParseSession, pou_type: &PouType) -> Option<Dat
             }
 
             if let DataTypeDeclaration::DataTypeDefinition { data_type, .. } = &declaration {
-                if matches!(data_type, DataType::EnumType { .. } | DataType::StructType { .. }) {
+                if matches!(
+                    data_type,
+                    DataType::EnumType { .. } | DataType::StructType { .. } | DataType::UnionType { .. }
+                ) {
                     let datatype_name = declaration
                         .get_location()
                         .to_range()
@@ -564,10 +726,11 @@ fn parse_method(
             ));
         }
 
+        let attributes = lexer.take_attributes();
         let method_start = lexer.range().start;
         lexer.advance(); // eat METHOD keyword
 
-        let access = Some(parse_access_modifier(lexer));
+        let access = parse_access_modifier(lexer);
         let kind = PouType::Method { parent: parent.into() };
         let poly_mode = parse_polymorphism_mode(lexer, &kind);
         let overriding = lexer.try_consume(KeywordOverride);
@@ -611,27 +774,130 @@ fn parse_method(
                 name_location,
                 poly_mode,
                 generics,
+                generic_arguments: vec![],
                 linkage,
                 super_class: None,
                 interfaces: Vec::new(),
+                properties: Vec::new(),
                 is_const: constant,
+                attributes,
+                sfc: None,
             },
             implementation,
         ))
     })
 }
 
-fn parse_access_modifier(lexer: &mut ParseSession) -> AccessModifier {
+fn parse_property(lexer: &mut ParseSession) -> Option<PropertyBlock> {
+    parse_any_in_region(lexer, vec![KeywordEndProperty], |lexer| {
+        // Property declarations look like this:
+        // PROPERTY [AccessModifier] name : return_type
+        //    [GET ... END_GET]
+        //    [SET ... END_SET]
+        // END_PROPERTY
+        let property_start = lexer.range().start;
+        lexer.advance(); // eat PROPERTY keyword
+
+        let access = parse_access_modifier(lexer);
+        let (name, name_location) = parse_identifier(lexer)?;
+
+        if !lexer.try_consume(KeywordColon) {
+            lexer.accept_diagnostic(Diagnostic::missing_token("KeywordColon", lexer.location()));
+            return None;
+        }
+
+        let (datatype, initializer) = parse_data_type_definition(lexer, None)?;
+        if let Some(init) = initializer {
+            lexer.accept_diagnostic(
+                Diagnostic::new("Properties cannot have a default value")
+                    .with_error_code("E145")
+                    .with_location(init.get_location()),
+            );
+        }
+
+        let mut accessors: Vec<PropertyAccessor> = vec![];
+        while let Some(kind) = get_property_accessor_kind(lexer) {
+            let accessor = parse_property_accessor(lexer, kind);
+            if accessors.iter().any(|it| it.kind == kind) {
+                lexer.accept_diagnostic(
+                    Diagnostic::new(format!("Property `{name}` has more than one {kind} accessor"))
+                        .with_error_code("E146")
+                        .with_location(&accessor.location),
+                );
+                continue;
+            }
+            accessors.push(accessor);
+        }
+
+        if accessors.is_empty() {
+            lexer.accept_diagnostic(
+                Diagnostic::new(format!("Property `{name}` must define at least a GET or a SET accessor"))
+                    .with_error_code("E147")
+                    .with_location(&name_location),
+            );
+        }
+
+        Some(PropertyBlock {
+            name,
+            name_location,
+            datatype,
+            access,
+            accessors,
+            location: lexer.source_range_factory.create_range(property_start..lexer.range().end),
+        })
+    })
+}
+
+/// `GET` and `SET` are not reserved keywords (e.g. the standard `RS` block has an input named `SET`),
+/// hence they are only treated as accessors within a property declaration
+fn get_property_accessor_kind(lexer: &ParseSession) -> Option<PropertyKind> {
+    if lexer.token != Identifier {
+        return None;
+    }
+
+    match lexer.slice().to_uppercase().as_str() {
+        "GET" => Some(PropertyKind::Get),
+        "SET" => Some(PropertyKind::Set),
+        _ => None,
+    }
+}
+
+fn parse_property_accessor(lexer: &mut ParseSession, kind: PropertyKind) -> PropertyAccessor {
+    let start = lexer.range().start;
+    lexer.advance(); // eat GET/SET
+
+    let end_token = match kind {
+        PropertyKind::Get => KeywordEndGet,
+        PropertyKind::Set => KeywordEndSet,
+    };
+    parse_any_in_region(lexer, vec![end_token], |lexer| {
+        let mut variable_blocks = vec![];
+        while matches!(lexer.token, KeywordVar | KeywordVarTemp) {
+            variable_blocks.push(parse_variable_block(lexer, LinkageType::Internal));
+        }
+
+        let statements = parse_body_standalone(lexer);
+        PropertyAccessor {
+            kind,
+            variable_blocks,
+            statements,
+            location: lexer.source_range_factory.create_range(start..lexer.range().end),
+        }
+    })
+}
+
+/// parses an optional access modifier, returns `None` if no modifier was declared
+fn parse_access_modifier(lexer: &mut ParseSession) -> Option<AccessModifier> {
     if lexer.try_consume(KeywordAccessPublic) {
-        AccessModifier::Public
+        Some(AccessModifier::Public)
     } else if lexer.try_consume(KeywordAccessPrivate) {
-        AccessModifier::Private
+        Some(AccessModifier::Private)
     } else if lexer.try_consume(KeywordAccessProtected) {
-        AccessModifier::Protected
+        Some(AccessModifier::Protected)
     } else if lexer.try_consume(KeywordAccessInternal) {
-        AccessModifier::Internal
+        Some(AccessModifier::Internal)
     } else {
-        AccessModifier::Protected
+        None
     }
 }
 
@@ -652,6 +918,240 @@ fn parse_identifier(lexer: &mut ParseSession) -> Option<(String, SourceLocation)
     }
 }
 
+/// Parses a possibly qualified identifier, e.g. `a.b.motor`
+fn parse_qualified_identifier(lexer: &mut ParseSession) -> Option<(String, SourceLocation)> {
+    let (mut name, location) = parse_identifier(lexer)?;
+    while lexer.try_consume(KeywordDot) {
+        let (segment, _) = parse_identifier(lexer)?;
+        name = qualified_name(name, segment);
+    }
+    Some((name, location.span(&lexer.last_location())))
+}
+
+/// Qualifies the given name of a declaration with the namespace it is declared in, e.g. `a::b::motor`
+fn qualify_with_namespace(lexer: &ParseSession, name: String) -> String {
+    match &lexer.namespace {
+        Some(namespace) => namespaced_name(namespace, &name),
+        None => name,
+    }
+}
+
+/// Parses a sequence of `USING a.b, c;` directives, importing the namespaces into the POU or namespace
+/// currently parsed
+fn parse_using_directives(lexer: &mut ParseSession) -> Vec<UsingDirective> {
+    let mut usings = vec![];
+    while lexer.try_consume(KeywordUsing) {
+        loop {
+            if let Some((name, location)) = parse_qualified_identifier(lexer) {
+                usings.push(UsingDirective {
+                    namespace: name.replace('.', NAMESPACE_SEPARATOR),
+                    scope: lexer.scope.clone().or_else(|| lexer.namespace.clone()),
+                    location,
+                });
+            }
+
+            if !lexer.try_consume(KeywordComma) {
+                break;
+            }
+        }
+        lexer.try_consume_or_report(KeywordSemicolon);
+    }
+    usings
+}
+
+/// Parses a `CONFIGURATION` and its resources. Global variables declared in the configuration or in one
+/// of its resources are added to the unit's global variables
+fn parse_configuration(
+    lexer: &mut ParseSession,
+    unit: &mut CompilationUnit,
+    linkage: LinkageType,
+) -> Configuration {
+    let start = lexer.location();
+    parse_any_in_region(lexer, vec![KeywordEndConfiguration], |lexer| {
+        lexer.advance(); // CONFIGURATION
+        let (name, name_location) =
+            parse_identifier(lexer).unwrap_or_else(|| (String::new(), SourceLocation::undefined()));
+        let name = qualify_with_namespace(lexer, name);
+
+        // tasks and programs declared directly in the configuration form a single resource
+        let mut resources = vec![];
+        let mut implicit_resource = Resource {
+            name: name.clone(),
+            processor: None,
+            tasks: vec![],
+            programs: vec![],
+            location: name_location.clone(),
+            name_location: name_location.clone(),
+        };
+        while !lexer.closes_open_region(&lexer.token) {
+            match lexer.token {
+                KeywordResource => resources.push(parse_resource(lexer, unit, linkage)),
+                KeywordTask => implicit_resource.tasks.extend(parse_task(lexer)),
+                KeywordProgram => implicit_resource.programs.extend(parse_program_configuration(lexer)),
+                KeywordVarGlobal => unit.global_vars.push(parse_variable_block(lexer, linkage)),
+                KeywordVarConfig => unit.var_config.extend(parse_config_variables(lexer)),
+                KeywordVarAccess => unit.var_access.extend(parse_access_variables(lexer)),
+                _ => {
+                    lexer.accept_diagnostic(Diagnostic::unexpected_token_found(
+                        "KeywordResource",
+                        lexer.slice(),
+                        lexer.location(),
+                    ));
+                    lexer.advance();
+                }
+            }
+        }
+
+        if !implicit_resource.tasks.is_empty() || !implicit_resource.programs.is_empty() {
+            resources.insert(0, implicit_resource);
+        }
+
+        Configuration { name, resources, location: start.span(&lexer.location()), name_location }
+    })
+}
+
+/// Parses a `RESOURCE name ON processor ... END_RESOURCE` block of a configuration
+fn parse_resource(lexer: &mut ParseSession, unit: &mut CompilationUnit, linkage: LinkageType) -> Resource {
+    let start = lexer.location();
+    parse_any_in_region(lexer, vec![KeywordEndResource], |lexer| {
+        lexer.advance(); // RESOURCE
+        let (name, name_location) =
+            parse_identifier(lexer).unwrap_or_else(|| (String::new(), SourceLocation::undefined()));
+
+        // `ON` is not reserved, it is only meaningful in front of the processor type
+        let processor = if lexer.token == Identifier && lexer.slice().eq_ignore_ascii_case("ON") {
+            lexer.advance();
+            parse_identifier(lexer).map(|(processor, _)| processor)
+        } else {
+            None
+        };
+
+        let mut resource = Resource {
+            name,
+            processor,
+            tasks: vec![],
+            programs: vec![],
+            location: start.clone(),
+            name_location,
+        };
+        while !lexer.closes_open_region(&lexer.token) {
+            match lexer.token {
+                KeywordTask => resource.tasks.extend(parse_task(lexer)),
+                KeywordProgram => resource.programs.extend(parse_program_configuration(lexer)),
+                KeywordVarGlobal => unit.global_vars.push(parse_variable_block(lexer, linkage)),
+                _ => {
+                    lexer.accept_diagnostic(Diagnostic::unexpected_token_found(
+                        "KeywordTask",
+                        lexer.slice(),
+                        lexer.location(),
+                    ));
+                    lexer.advance();
+                }
+            }
+        }
+        resource.location = start.span(&lexer.location());
+        resource
+    })
+}
+
+/// Parses a `TASK name (INTERVAL := T#10ms, PRIORITY := 1);` declaration
+fn parse_task(lexer: &mut ParseSession) -> Option<Task> {
+    let start = lexer.location();
+    parse_any_in_region(lexer, vec![KeywordSemicolon], |lexer| {
+        lexer.advance(); // TASK
+        let (name, name_location) = parse_identifier(lexer)?;
+        let mut task = Task {
+            name,
+            interval: None,
+            single: None,
+            priority: None,
+            location: start.clone(),
+            name_location,
+        };
+
+        lexer.try_consume_or_report(KeywordParensOpen);
+        let properties = match lexer.token {
+            KeywordParensClose => vec![],
+            _ => match parse_expression(lexer) {
+                AstNode { stmt: AstStatement::ExpressionList(properties), .. } => properties,
+                property => vec![property],
+            },
+        };
+        lexer.try_consume_or_report(KeywordParensClose);
+        task.location = start.span(&lexer.last_location());
+
+        for property in properties {
+            let location = property.get_location();
+            let AstStatement::Assignment(Assignment { left, right }) = property.stmt else {
+                lexer.accept_diagnostic(
+                    Diagnostic::new("Expected a task property assignment, e.g. `PRIORITY := 1`")
+                        .with_error_code("E123")
+                        .with_location(location),
+                );
+                continue;
+            };
+
+            let property_name = left.get_flat_reference_name().unwrap_or_default().to_uppercase();
+            let slot = match property_name.as_str() {
+                "INTERVAL" => &mut task.interval,
+                "SINGLE" => &mut task.single,
+                "PRIORITY" => &mut task.priority,
+                _ => {
+                    lexer.accept_diagnostic(
+                        Diagnostic::new(format!(
+                            "Unknown task property `{}`, expected `INTERVAL`, `SINGLE` or `PRIORITY`",
+                            left.get_flat_reference_name().unwrap_or_default()
+                        ))
+                        .with_error_code("E123")
+                        .with_location(left.get_location()),
+                    );
+                    continue;
+                }
+            };
+
+            if property_name == "SINGLE" && right.get_flat_reference_name().is_none() {
+                lexer.accept_diagnostic(
+                    Diagnostic::new("Task property `SINGLE` expects the name of a BOOL variable")
+                        .with_error_code("E123")
+                        .with_location(right.get_location()),
+                );
+            }
+
+            if slot.is_some() {
+                lexer.accept_diagnostic(
+                    Diagnostic::new(format!("Task property `{property_name}` is assigned more than once"))
+                        .with_error_code("E123")
+                        .with_location(location),
+                );
+            }
+            *slot = Some(*right);
+        }
+
+        Some(task)
+    })
+}
+
+/// Parses a `PROGRAM instance WITH task : MyProgram;` declaration
+fn parse_program_configuration(lexer: &mut ParseSession) -> Option<ProgramConfiguration> {
+    let start = lexer.location();
+    parse_any_in_region(lexer, vec![KeywordSemicolon], |lexer| {
+        lexer.advance(); // PROGRAM
+        let (instance_name, name_location) = parse_identifier(lexer)?;
+        let task = if lexer.try_consume(KeywordWith) { parse_identifier(lexer) } else { None };
+        lexer.try_consume_or_report(KeywordColon);
+        let (program, program_location) = parse_qualified_identifier(lexer)?;
+
+        Some(ProgramConfiguration {
+            instance_name,
+            task,
+            program: program.replace('.', NAMESPACE_SEPARATOR),
+            program_location,
+            location: start.span(&lexer.last_location()),
+            name_location,
+        })
+    })
+}
+
 fn parse_implementation(
     lexer: &mut ParseSession,
     linkage: LinkageType,
@@ -662,7 +1162,11 @@ fn parse_implementation(
     name_location: SourceLocation,
 ) -> Implementation {
     let start = lexer.range().start;
-    let statements = parse_body_standalone(lexer);
+    let statements = if lexer.instruction_list {
+        il_parser::parse_instruction_list(lexer)
+    } else {
+        parse_body_standalone(lexer)
+    };
     Implementation {
         name: call_name.into(),
         type_name: type_name.into(),
@@ -700,9 +1204,11 @@ fn parse_action(
             expect_token!(lexer, Identifier, None);
 
             let name = lexer.slice_and_advance();
-            (name_or_container, name, loc.span(&lexer.last_location()))
+            (qualify_with_namespace(lexer, name_or_container), name, loc.span(&lexer.last_location()))
         };
         let call_name = qualified_name(&container, &name);
+        // actions declared within a sequential function chart separate their name with a colon
+        lexer.try_consume(KeywordColon);
 
         let implementation = parse_implementation(
             lexer,
@@ -727,12 +1233,17 @@ fn parse_action(
 
 // TYPE ... END_TYPE
 fn parse_type(lexer: &mut ParseSession) -> Vec<UserTypeDeclaration> {
+    // attributes in front of the TYPE keyword apply to all types declared in the block
+    let block_attributes = lexer.take_attributes();
     lexer.advance(); // consume the TYPE
 
     parse_any_in_region(lexer, vec![KeywordEndType], |lexer| {
         let mut declarations = vec![];
         while !lexer.closes_open_region(&lexer.token) {
+            let mut attributes = block_attributes.clone();
+            attributes.append(&mut lexer.take_attributes());
             let name = lexer.slice_and_advance();
+            let name = qualify_with_namespace(lexer, name);
             let name_location = lexer.last_location();
             lexer.try_consume_or_report(KeywordColon);
 
@@ -744,6 +1255,7 @@ fn parse_type(lexer: &mut ParseSession) -> Vec<UserTypeDeclaration> {
                     initializer,
                     location: name_location,
                     scope: lexer.scope.clone(),
+                    attributes,
                 });
             }
         }
@@ -757,7 +1269,11 @@ fn parse_full_data_type_definition(
     lexer: &mut ParseSession,
     name: Option<String>,
 ) -> Option<DataTypeWithInitializer> {
-    let end_keyword = if lexer.token == KeywordStruct { KeywordEndStruct } else { KeywordSemicolon };
+    let end_keyword = match lexer.token {
+        KeywordStruct => KeywordEndStruct,
+        KeywordUnion => KeywordEndUnion,
+        _ => KeywordSemicolon,
+    };
     let parsed_datatype = parse_any_in_region(lexer, vec![end_keyword], |lexer| {
         let sized = lexer.try_consume(PropertySized);
         if lexer.try_consume(KeywordDotDotDot) {
@@ -788,15 +1304,15 @@ fn parse_full_data_type_definition(
     });
 
     // The standard allows semicolons at the end of an `END_STRUCT` keyword, hence if we parsed
-    // a struct, try to also consume a semicolon if it exists
-    if end_keyword == KeywordEndStruct {
+    // a struct (or union), try to also consume a semicolon if it exists
+    if matches!(end_keyword, KeywordEndStruct | KeywordEndUnion) {
         lexer.try_consume(KeywordSemicolon);
     }
 
     parsed_datatype
 }
 
-// TYPE xxx : 'STRUCT' | '(' | IDENTIFIER
+// TYPE xxx : 'STRUCT' | 'UNION' | '(' | IDENTIFIER
 fn parse_data_type_definition(
     lexer: &mut ParseSession,
     name: Option<String>,
@@ -804,10 +1320,21 @@ fn parse_data_type_definition(
     let start = lexer.location();
     if lexer.try_consume(KeywordStruct) {
         // Parse struct
+        let base = parse_super_class(lexer);
         let variables = parse_variable_list(lexer);
         Some((
             DataTypeDeclaration::DataTypeDefinition {
-                data_type: DataType::StructType { name, variables },
+                data_type: DataType::StructType { name, variables, base },
+                location: start.span(&lexer.location()),
+                scope: lexer.scope.clone(),
+            },
+            None,
+        ))
+    } else if lexer.try_consume(KeywordUnion) {
+        let variables = parse_variable_list(lexer);
+        Some((
+            DataTypeDeclaration::DataTypeDefinition {
+                data_type: DataType::UnionType { name, variables },
                 location: start.span(&lexer.location()),
                 scope: lexer.scope.clone(),
             },
@@ -876,7 +1403,16 @@ fn parse_type_reference_type_definition(
 ) -> Option<(DataTypeDeclaration, Option<AstNode>)> {
     let start = lexer.range().start;
     //Subrange
-    let referenced_type = lexer.slice_and_advance();
+    let mut referenced_type = lexer.slice_and_advance();
+    // a type may be referenced by its qualified name, e.g. `a.b.motor`
+    while lexer.token == KeywordDot {
+        lexer.advance();
+        referenced_type = qualified_name(referenced_type, lexer.slice_and_advance());
+    }
+    // an instance of a generic POU, e.g. `FB_Fifo<REAL>`
+    if lexer.token == OperatorLess {
+        referenced_type = format!("{referenced_type}<{}>", parse_type_arguments(lexer).join(","));
+    }
 
     let bounds = if lexer.try_consume(KeywordParensOpen) {
         // INT (..) :=
@@ -1198,13 +1734,23 @@ fn parse_variable_block_type(lexer: &mut ParseSession) -> VariableBlockType {
 }
 
 fn parse_variable_block(lexer: &mut ParseSession, linkage: LinkageType) -> VariableBlock {
+    let attributes = lexer.take_attributes();
     let location = lexer.location();
     let variable_block_type = parse_variable_block_type(lexer);
 
     let constant = lexer.try_consume(KeywordConstant);
 
-    let retain = lexer.try_consume(KeywordRetain);
-    lexer.try_consume(KeywordNonRetain);
+    // RETAIN and PERSISTENT may be combined in any order
+    let (mut retain, mut persistent) = (false, false);
+    loop {
+        if lexer.try_consume(KeywordRetain) {
+            retain = true;
+        } else if lexer.try_consume(KeywordPersistent) {
+            persistent = true;
+        } else if !lexer.try_consume(KeywordNonRetain) {
+            break;
+        }
+    }
 
     let access = parse_access_modifier(lexer);
 
@@ -1217,7 +1763,17 @@ fn parse_variable_block(lexer: &mut ParseSession, linkage: LinkageType) -> Varia
         });
     }
 
-    VariableBlock { access, constant, retain, variables, variable_block_type, linkage, location }
+    VariableBlock {
+        access,
+        constant,
+        retain,
+        persistent,
+        variables,
+        variable_block_type,
+        linkage,
+        location,
+        attributes,
+    }
 }
 
 fn parse_variable_list(lexer: &mut ParseSession) -> Vec<Variable> {
@@ -1278,7 +1834,79 @@ fn try_parse_config_var(lexer: &mut ParseSession) -> Option<ConfigVariable> {
     })
 }
 
-fn parse_aliasing(lexer: &mut ParseSession, names: &(String, Range<usize>)) -> Option<Variable> {
+fn parse_access_variables(lexer: &mut ParseSession) -> Vec<AccessVariable> {
+    parse_any_in_region(lexer, vec![KeywordEndVar], |lexer| {
+        lexer.advance();
+        let mut variables = vec![];
+        while lexer.token == Identifier {
+            if let Some(access_var) = parse_any_in_region(lexer, vec![KeywordSemicolon], try_parse_access_var)
+            {
+                variables.push(access_var);
+            }
+        }
+        variables
+    })
+}
+
+/// Parses an access path declaration, e.g. `speed : prog1.fb.speed : REAL READ_ONLY`
+fn try_parse_access_var(lexer: &mut ParseSession) -> Option<AccessVariable> {
+    let start = lexer.location();
+    let (name, name_location) = parse_identifier(lexer)?;
+    if !lexer.try_consume(KeywordColon) {
+        lexer.accept_diagnostic(Diagnostic::missing_token(
+            format!("{KeywordColon:?}").as_str(),
+            lexer.location(),
+        ));
+    }
+
+    let reference = parse_reference(lexer);
+    if !lexer.try_consume(KeywordColon) {
+        lexer.accept_diagnostic(Diagnostic::missing_token(
+            format!("{KeywordColon:?}").as_str(),
+            lexer.location(),
+        ));
+    }
+
+    let (data_type, initializer) = parse_data_type_definition(lexer, None)?;
+    if initializer.is_some() {
+        lexer.accept_diagnostic(Diagnostic::unexpected_token_found(
+            format!("{KeywordSemicolon:?}").as_str(),
+            "Initializer",
+            lexer.last_location().span(&lexer.location()),
+        ))
+    }
+
+    // the access direction is optional and defaults to READ_ONLY
+    let access = match lexer.token {
+        Identifier if lexer.slice().eq_ignore_ascii_case("READ_ONLY") => {
+            lexer.advance();
+            AccessMode::ReadOnly
+        }
+        Identifier if lexer.slice().eq_ignore_ascii_case("READ_WRITE") => {
+            lexer.advance();
+            AccessMode::ReadWrite
+        }
+        Identifier => {
+            lexer.accept_diagnostic(Diagnostic::unexpected_token_found(
+                "READ_ONLY or READ_WRITE",
+                lexer.slice(),
+                lexer.location(),
+            ));
+            lexer.advance();
+            AccessMode::ReadOnly
+        }
+        _ => AccessMode::ReadOnly,
+    };
+
+    let location = start.span(&lexer.last_location());
+    Some(AccessVariable { name, reference, data_type, access, location, name_location })
+}
+
+fn parse_aliasing(
+    lexer: &mut ParseSession,
+    names: &(String, Range<usize>),
+    attributes: Vec<Attribute>,
+) -> Option<Variable> {
     let reference = parse_reference(lexer);
     if !lexer.try_consume(KeywordColon) {
         lexer.accept_diagnostic(Diagnostic::missing_token(
@@ -1303,6 +1931,8 @@ fn parse_aliasing(lexer: &mut ParseSession, names: &(String, Range<usize>)) -> O
             location: lexer.source_range_factory.create_range(names.1.clone()),
             initializer: Some(reference),
             address: None,
+            init_arguments: None,
+            attributes,
         });
     }
 
@@ -1310,6 +1940,9 @@ fn parse_aliasing(lexer: &mut ParseSession, names: &(String, Range<usize>)) -> O
 }
 
 fn parse_variable_line(lexer: &mut ParseSession) -> Vec<Variable> {
+    // attributes in front of the line apply to all of its variables
+    let attributes = lexer.take_attributes();
+
     // read in a comma separated list of variable names
     let mut var_names: Vec<(String, Range<usize>)> = vec![];
     while lexer.token == Identifier {
@@ -1339,7 +1972,7 @@ fn parse_variable_line(lexer: &mut ParseSession) -> Vec<Variable> {
             }
 
             Identifier => {
-                return match parse_aliasing(lexer, &var_names[0]) {
+                return match parse_aliasing(lexer, &var_names[0], attributes) {
                     Some(aliased_variable) => vec![aliased_variable],
                     None => vec![],
                 };
@@ -1376,6 +2009,7 @@ fn parse_variable_line(lexer: &mut ParseSession) -> Vec<Variable> {
     lexer.try_consume(KeywordSemicolon);
 
     if let Some((data_type, initializer)) = parse_definition_opt {
+        let (data_type, init_arguments) = take_init_arguments(data_type);
         for (name, range) in var_names {
             variables.push(Variable {
                 name,
@@ -1383,6 +2017,8 @@ fn parse_variable_line(lexer: &mut ParseSession) -> Vec<Variable> {
                 location: lexer.source_range_factory.create_range(range),
                 initializer: initializer.clone(),
                 address: address.clone(),
+                init_arguments: init_arguments.clone(),
+                attributes: attributes.clone(),
             });
         }
     }
@@ -1390,6 +2026,36 @@ fn parse_variable_line(lexer: &mut ParseSession) -> Vec<Variable> {
     variables
 }
 
+/// An instance declaration with arguments for its `FB_INIT` method (e.g. `fb : MyFb(nAxis := 3)`) is parsed like
+/// an inline subrange or enum. Unless the referenced type is an elementary type, the parenthesized expressions are
+/// taken as the instance's init arguments and the declaration is turned into a plain reference to the type.
+fn take_init_arguments(data_type: DataTypeDeclaration) -> (DataTypeDeclaration, Option<AstNode>) {
+    let is_elementary = |name: &str| {
+        typesystem::get_builtin_types().iter().any(|it| it.get_name().eq_ignore_ascii_case(name))
+    };
+
+    match data_type {
+        DataTypeDeclaration::DataTypeDefinition {
+            data_type: DataType::SubRangeType { name: None, referenced_type, bounds: Some(arguments) },
+            location,
+            ..
+        } if !is_elementary(&referenced_type)
+            && !matches!(arguments.get_stmt(), AstStatement::RangeStatement(_)) =>
+        {
+            (DataTypeDeclaration::DataTypeReference { referenced_type, location }, Some(arguments))
+        }
+        DataTypeDeclaration::DataTypeDefinition {
+            data_type: DataType::EnumType { name: None, numeric_type, elements },
+            location,
+            ..
+        } if !is_elementary(&numeric_type) => (
+            DataTypeDeclaration::DataTypeReference { referenced_type: numeric_type, location },
+            Some(elements),
+        ),
+        data_type => (data_type, None),
+    }
+}
+
 fn parse_hardware_access(
     lexer: &mut ParseSession,
     hardware_access_type: HardwareAccessType,
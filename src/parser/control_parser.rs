@@ -1,6 +1,6 @@
 use plc_ast::{
     ast::{AstFactory, AstNode, AstStatement},
-    control_statements::ConditionalBlock,
+    control_statements::{CatchBlock, ConditionalBlock},
 };
 use plc_diagnostics::diagnostics::Diagnostic;
 
@@ -21,6 +21,7 @@ pub fn parse_control_statement(lexer: &mut ParseSession) -> AstNode {
         KeywordWhile => parse_while_statement(lexer),
         KeywordRepeat => parse_repeat_statement(lexer),
         KeywordCase => parse_case_statement(lexer),
+        KeywordTry => parse_try_statement(lexer),
         KeywordReturn => parse_return_statement(lexer),
         KeywordContinue => parse_continue_statement(lexer),
         KeywordExit => parse_exit_statement(lexer),
@@ -214,3 +215,36 @@ fn parse_case_statement(lexer: &mut ParseSession) -> AstNode {
         lexer.next_id(),
     )
 }
+
+fn parse_try_statement(lexer: &mut ParseSession) -> AstNode {
+    let start = lexer.range().start;
+    lexer.advance(); // __TRY
+
+    let body = parse_body_in_region(lexer, vec![KeywordEndTry, KeywordCatch, KeywordFinally]);
+
+    let catch_block = if lexer.last_token == KeywordCatch {
+        let exception = if lexer.try_consume(KeywordParensOpen) {
+            Some(Box::new(parse_any_in_region(lexer, vec![KeywordParensClose], parse_reference)))
+        } else {
+            None
+        };
+        let body = parse_body_in_region(lexer, vec![KeywordEndTry, KeywordFinally]);
+        Some(CatchBlock { exception, body })
+    } else {
+        None
+    };
+
+    let finally_block = if lexer.last_token == KeywordFinally {
+        parse_body_in_region(lexer, vec![KeywordEndTry])
+    } else {
+        vec![]
+    };
+
+    AstFactory::create_try_statement(
+        body,
+        catch_block,
+        finally_block,
+        lexer.source_range_factory.create_range(start..lexer.last_range.end),
+        lexer.next_id(),
+    )
+}
@@ -279,6 +279,14 @@ fn parse_atomic_leaf_expression(lexer: &mut ParseSession<'_>) -> Option<AstNode>
             })
         }
         Identifier => Some(parse_identifier(lexer)),
+        KeywordThis => {
+            lexer.advance();
+            Some(AstFactory::create_this(lexer.last_location(), lexer.next_id()))
+        }
+        KeywordSuper => {
+            lexer.advance();
+            Some(AstFactory::create_super(lexer.last_location(), lexer.next_id()))
+        }
         HardwareAccess((hw_type, access_type)) => parse_hardware_access(lexer, hw_type, access_type),
         LiteralInteger => parse_literal_number(lexer, false),
         LiteralIntegerBin => parse_literal_number_with_modifier(lexer, 2, false),
@@ -409,6 +417,11 @@ pub fn parse_qualified_reference(lexer: &mut ParseSession) -> Option<AstNode> {
             // base._ -> a segment of a qualified reference, we stand right after the dot
             (Some(base), Some(KeywordDot)) => {
                 lexer.advance();
+                if lexer.token == TypeCastPrefix {
+                    // a cast to a type declared in a namespace, e.g. `a.b.Color#Red`
+                    current = Some(parse_type_cast(lexer, Some(base))?);
+                    continue;
+                }
                 let member = if lexer.token == LiteralInteger {
                     let index = parse_strict_literal_integer(lexer)?;
                     let location = index.get_location();
@@ -421,25 +434,7 @@ pub fn parse_qualified_reference(lexer: &mut ParseSession) -> Option<AstNode> {
             // CAST-Statement: INT#a.b.c
             // this means INT#(a.b.c) rather than (INT#a).b.c
             (_, Some(TypeCastPrefix)) => {
-                let location_start = lexer.range().start;
-                let location = lexer.location();
-                let mut type_name = lexer.slice_and_advance();
-                type_name.pop(); // get rid of the "#" at the end
-                let stmt = parse_atomic_leaf_expression(lexer)?;
-                let end = stmt.get_location();
-                let type_range = lexer
-                    .source_range_factory
-                    .create_range(location_start..(location_start + type_name.len()));
-                current = Some(AstFactory::create_cast_statement(
-                    AstFactory::create_member_reference(
-                        AstFactory::create_identifier(type_name.as_str(), &type_range, lexer.next_id()),
-                        None,
-                        lexer.next_id(),
-                    ),
-                    stmt,
-                    &location.span(&end),
-                    lexer.next_id(),
-                ));
+                current = Some(parse_type_cast(lexer, None)?);
             }
             (Some(base), Some(KeywordSquareParensOpen)) => {
                 lexer.advance();
@@ -471,6 +466,28 @@ pub fn parse_qualified_reference(lexer: &mut ParseSession) -> Option<AstNode> {
     }
 }
 
+/// Parses a cast like `INT#5`, where the type may be qualified by the given namespace reference
+fn parse_type_cast(lexer: &mut ParseSession, qualifier: Option<AstNode>) -> Option<AstNode> {
+    let location_start = lexer.range().start;
+    let location = qualifier.as_ref().map(|it| it.get_location()).unwrap_or_else(|| lexer.location());
+    let mut type_name = lexer.slice_and_advance();
+    type_name.pop(); // get rid of the "#" at the end
+    let stmt = parse_atomic_leaf_expression(lexer)?;
+    let end = stmt.get_location();
+    let type_range =
+        lexer.source_range_factory.create_range(location_start..(location_start + type_name.len()));
+    Some(AstFactory::create_cast_statement(
+        AstFactory::create_member_reference(
+            AstFactory::create_identifier(type_name.as_str(), &type_range, lexer.next_id()),
+            qualifier,
+            lexer.next_id(),
+        ),
+        stmt,
+        &location.span(&end),
+        lexer.next_id(),
+    ))
+}
+
 fn parse_direct_access(lexer: &mut ParseSession, access: DirectAccessType) -> Option<AstNode> {
     //Consume the direct access
     let location = lexer.location();
@@ -0,0 +1,436 @@
+//! Parses the instruction list (IL) forming the body of a POU. Every instruction is written on its own line
+//! and operates on the current result (CR). The current result is kept as an expression and only evaluated
+//! by the instructions consuming it, e.g. `LD a`, `AND b`, `ST c` are translated into `c := a AND b;`.
+//! Instructions using the current result without replacing it, e.g. `S`, `R` or `JMPC`, first store it in
+//! a hidden temporary, so it is evaluated exactly once and is not changed by writing one of its operands.
+use plc_ast::{
+    ast::{Allocation, AstFactory, AstNode, AstStatement, Operator},
+    control_statements::ConditionalBlock,
+    literals::AstLiteral,
+    mut_visitor::{AstVisitorMut, WalkerMut},
+    provider::IdProvider,
+};
+use plc_diagnostics::diagnostics::Diagnostic;
+use plc_source::source_location::SourceLocation;
+
+use crate::{lexer::Token::*, typesystem::BOOL_TYPE};
+
+use super::{expressions_parser::parse_expression, ParseSession};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Operation {
+    Load,
+    Store,
+    Set,
+    Reset,
+    Not,
+    Binary(Operator),
+    Jump,
+    Call,
+    Return,
+}
+
+/// An IL operator and its modifiers, e.g. `JMPCN` jumps if the current result is `FALSE`
+#[derive(Debug, Clone, Copy)]
+struct Instruction {
+    operation: Operation,
+    /// the `N` modifier, negating the operand or the condition of the instruction
+    negated: bool,
+    /// the `C` modifier, executing a jump, call or return only if the current result is `TRUE`
+    conditional: bool,
+}
+
+impl Instruction {
+    fn from_name(name: &str) -> Option<Instruction> {
+        let get_operation = |name: &str| {
+            let operation = match name {
+                "LD" => Operation::Load,
+                "ST" => Operation::Store,
+                "S" => Operation::Set,
+                "R" => Operation::Reset,
+                "NOT" => Operation::Not,
+                "AND" => Operation::Binary(Operator::And),
+                "OR" => Operation::Binary(Operator::Or),
+                "XOR" => Operation::Binary(Operator::Xor),
+                "ADD" => Operation::Binary(Operator::Plus),
+                "SUB" => Operation::Binary(Operator::Minus),
+                "MUL" => Operation::Binary(Operator::Multiplication),
+                "DIV" => Operation::Binary(Operator::Division),
+                "MOD" => Operation::Binary(Operator::Modulo),
+                "GT" => Operation::Binary(Operator::Greater),
+                "GE" => Operation::Binary(Operator::GreaterOrEqual),
+                "EQ" => Operation::Binary(Operator::Equal),
+                "NE" => Operation::Binary(Operator::NotEqual),
+                "LE" => Operation::Binary(Operator::LessOrEqual),
+                "LT" => Operation::Binary(Operator::Less),
+                "JMP" => Operation::Jump,
+                "CAL" => Operation::Call,
+                "RET" => Operation::Return,
+                _ => return None,
+            };
+            Some(operation)
+        };
+
+        if let Some(operation) = get_operation(name) {
+            return Some(Instruction { operation, negated: false, conditional: false });
+        }
+
+        let (name, negated) = name.strip_suffix('N').map_or((name, false), |it| (it, true));
+        let (name, conditional) = name.strip_suffix('C').map_or((name, false), |it| (it, true));
+        let operation = get_operation(name)?;
+        let is_valid = match operation {
+            Operation::Load
+            | Operation::Store
+            | Operation::Binary(Operator::And | Operator::Or | Operator::Xor) => !conditional,
+            Operation::Jump | Operation::Call | Operation::Return => conditional,
+            _ => false,
+        };
+
+        is_valid.then_some(Instruction { operation, negated, conditional })
+    }
+
+    fn requires_operand(&self) -> bool {
+        !matches!(self.operation, Operation::Not | Operation::Return)
+    }
+}
+
+/// An operator followed by a parenthesis, e.g. `AND(`, which is applied once the parenthesis is closed
+struct Deferred {
+    instruction: Instruction,
+    result: AstNode,
+    location: SourceLocation,
+}
+
+#[derive(Default)]
+struct InstructionList {
+    statements: Vec<AstNode>,
+    /// the current result, undefined at the start of the body and after a label
+    result: Option<AstNode>,
+    deferred: Vec<Deferred>,
+    /// the hidden temporaries storing the current result, allocated at the start of the body
+    temporaries: Vec<AstNode>,
+}
+
+/// Parses the instructions until the end of the containing POU or action
+pub fn parse_instruction_list(lexer: &mut ParseSession) -> Vec<AstNode> {
+    let mut list = InstructionList::default();
+    while !lexer.closes_open_region(&lexer.token) {
+        if lexer.token == Identifier && lexer.is_followed_by(":") && !lexer.is_followed_by(":=") {
+            let location = lexer.location();
+            let name = lexer.slice_and_advance();
+            lexer.advance();
+            list.statements.push(AstFactory::create_label_statement(name, location, lexer.next_id()));
+            // the current result is undefined when jumping to a label
+            list.result = None;
+            continue;
+        }
+
+        let mut line = lexer.lex_rest_of_line();
+        list.parse_instruction(&mut line);
+        if line.token != End {
+            line.accept_diagnostic(Diagnostic::unexpected_token_found(
+                "end of line",
+                line.slice(),
+                line.location(),
+            ));
+        }
+        lexer.diagnostics.append(&mut line.diagnostics);
+        lexer.skip_to(line.get_src().len());
+    }
+
+    for deferred in list.deferred.iter() {
+        lexer.accept_diagnostic(
+            Diagnostic::new("Missing `)` for the operator of this instruction")
+                .with_error_code("E128")
+                .with_location(&deferred.location),
+        );
+    }
+
+    list.temporaries.into_iter().chain(list.statements).collect()
+}
+
+impl InstructionList {
+    fn parse_instruction(&mut self, line: &mut ParseSession) {
+        let location = line.location();
+        if line.try_consume(KeywordParensClose) {
+            self.close_parenthesis(line, location);
+            return;
+        }
+
+        let name = line.slice().to_uppercase();
+        let Some(instruction) = Instruction::from_name(&name) else {
+            self.parse_function_call(line);
+            return;
+        };
+        line.advance();
+
+        let opens_parenthesis =
+            matches!(instruction.operation, Operation::Binary(_)) && line.try_consume(KeywordParensOpen);
+        let operand = (line.token != End).then(|| parse_expression(line));
+        let location = location.span(&line.last_location());
+        if opens_parenthesis {
+            if let Some(result) = self.take_result(line, &location) {
+                self.deferred.push(Deferred { instruction, result, location });
+            }
+            // the operand of `AND( b` is the first value loaded within the parenthesis
+            self.result = operand;
+            return;
+        }
+
+        match (instruction.requires_operand(), operand) {
+            (true, Some(operand)) => self.execute(line, instruction, Some(operand), location),
+            (false, None) => self.execute(line, instruction, None, location),
+            (true, None) => line.accept_diagnostic(
+                Diagnostic::new(format!("Operator '{name}' requires an operand"))
+                    .with_error_code("E128")
+                    .with_location(&location),
+            ),
+            (false, Some(operand)) => line.accept_diagnostic(
+                Diagnostic::new(format!("Operator '{name}' does not accept an operand"))
+                    .with_error_code("E128")
+                    .with_location(&operand.location),
+            ),
+        }
+    }
+
+    fn execute(
+        &mut self,
+        line: &mut ParseSession,
+        instruction: Instruction,
+        operand: Option<AstNode>,
+        location: SourceLocation,
+    ) {
+        let Instruction { operation, negated, conditional } = instruction;
+        match (operation, operand) {
+            (Operation::Load, Some(operand)) => self.result = Some(negate(operand, negated, line)),
+            (Operation::Store, Some(operand)) => {
+                let Some(result) = self.take_result(line, &location) else { return };
+                // the stored variable holds the current result from now on
+                self.result = Some(negate(copy(&operand, line), negated, line));
+                let value = negate(result, negated, line);
+                self.statements.push(AstFactory::create_assignment(operand, value, line.next_id()));
+            }
+            (Operation::Set | Operation::Reset, Some(operand)) => {
+                let Some(condition) = self.copy_result(line, &location) else { return };
+                let value = AstFactory::create_literal(
+                    AstLiteral::new_bool(operation == Operation::Set),
+                    location.clone(),
+                    line.next_id(),
+                );
+                let assignment = AstFactory::create_assignment(operand, value, line.next_id());
+                self.statements.push(create_conditional(condition, assignment, location, line));
+            }
+            (Operation::Not, None) => {
+                let Some(result) = self.take_result(line, &location) else { return };
+                self.result = Some(AstFactory::create_not_expression(result, location, line.next_id()));
+            }
+            (Operation::Binary(operator), Some(operand)) => {
+                let Some(result) = self.take_result(line, &location) else { return };
+                let operand = negate(operand, negated, line);
+                self.result =
+                    Some(AstFactory::create_binary_expression(result, operator, operand, line.next_id()));
+            }
+            (Operation::Jump, Some(target)) => {
+                let condition = if conditional {
+                    let Some(condition) = self.copy_result(line, &location) else { return };
+                    negate(condition, negated, line)
+                } else {
+                    AstFactory::create_literal(AstLiteral::new_bool(true), location.clone(), line.next_id())
+                };
+                self.statements.push(AstFactory::create_jump_statement(
+                    Box::new(condition),
+                    Box::new(target),
+                    location,
+                    line.next_id(),
+                ));
+            }
+            (Operation::Call, Some(call)) => {
+                // `CAL fb` calls the instance without any parameters
+                let call = if matches!(call.get_stmt(), AstStatement::CallStatement(_)) {
+                    call
+                } else {
+                    AstFactory::create_call_statement(call, None, line.next_id(), location.clone())
+                };
+                let statement = if conditional {
+                    let Some(condition) = self.copy_result(line, &location) else { return };
+                    create_conditional(negate(condition, negated, line), call, location, line)
+                } else {
+                    call
+                };
+                self.statements.push(statement);
+            }
+            (Operation::Return, None) => {
+                let condition = if conditional {
+                    let Some(condition) = self.copy_result(line, &location) else { return };
+                    Some(negate(condition, negated, line))
+                } else {
+                    None
+                };
+                self.statements.push(AstFactory::create_return_statement(
+                    condition,
+                    location,
+                    line.next_id(),
+                ));
+            }
+            _ => unreachable!("operands are checked before executing an instruction"),
+        }
+    }
+
+    /// Applies the operator of the innermost open parenthesis to the current result
+    fn close_parenthesis(&mut self, line: &mut ParseSession, location: SourceLocation) {
+        let Some(Deferred { instruction, result, .. }) = self.deferred.pop() else {
+            line.accept_diagnostic(
+                Diagnostic::new("Unexpected `)` without an operator opening a parenthesis")
+                    .with_error_code("E128")
+                    .with_location(&location),
+            );
+            return;
+        };
+
+        let Operation::Binary(operator) = instruction.operation else {
+            unreachable!("only binary operators open a parenthesis")
+        };
+        let Some(inner) = self.take_result(line, &location) else { return };
+        let inner = AstFactory::create_paren_expression(inner, location, line.next_id());
+        let inner = negate(inner, instruction.negated, line);
+        self.result = Some(AstFactory::create_binary_expression(result, operator, inner, line.next_id()));
+    }
+
+    /// Parses the call of a function, which is passed the current result as its first parameter, e.g.
+    /// `MAX b, c` is translated into `MAX(CR, b, c)`. A function called with a parenthesized list of
+    /// parameters, e.g. `LIMIT(MN := 0, IN := x, MX := 10)`, receives all of its parameters explicitly.
+    fn parse_function_call(&mut self, line: &mut ParseSession) {
+        if line.token != Identifier {
+            line.accept_diagnostic(
+                Diagnostic::new(format!("Expected an IL operator or a function, found '{}'", line.slice()))
+                    .with_error_code("E128")
+                    .with_location(line.location()),
+            );
+            line.advance();
+            return;
+        }
+
+        if line.is_followed_by("(") {
+            self.result = Some(parse_expression(line));
+            return;
+        }
+
+        let location = line.location();
+        let name = line.slice_and_advance();
+        let operator = AstFactory::create_member_reference(
+            AstFactory::create_identifier(&name, location.clone(), line.next_id()),
+            None,
+            line.next_id(),
+        );
+
+        let Some(result) = self.take_result(line, &location) else { return };
+        let mut parameters = vec![result];
+        if line.token != End {
+            match parse_expression(line) {
+                AstNode { stmt: AstStatement::ExpressionList(expressions), .. } => {
+                    parameters.extend(expressions)
+                }
+                expression => parameters.push(expression),
+            }
+        }
+
+        let location = location.span(&line.last_location());
+        let parameters = AstFactory::create_expression_list(parameters, location.clone(), line.next_id());
+        self.result =
+            Some(AstFactory::create_call_statement(operator, Some(parameters), line.next_id(), location));
+    }
+
+    fn take_result(&mut self, line: &mut ParseSession, location: &SourceLocation) -> Option<AstNode> {
+        let result = self.result.take();
+        if result.is_none() {
+            line.accept_diagnostic(
+                Diagnostic::new("The current result is undefined, expected a load instruction, e.g. `LD x`")
+                    .with_error_code("E128")
+                    .with_location(location),
+            );
+        }
+        result
+    }
+
+    /// Returns the current result for an instruction which leaves it unchanged. Unless the current result is
+    /// a literal or already stored, it is assigned to a new hidden temporary which becomes the current result.
+    /// Instructions leaving the current result unchanged use it as a condition, so the temporary is a `BOOL`.
+    fn copy_result(&mut self, line: &mut ParseSession, location: &SourceLocation) -> Option<AstNode> {
+        let result = self.take_result(line, location)?;
+        let is_stored = result.get_flat_reference_name().is_some_and(|name| self.is_temporary(name));
+        if is_stored || matches!(result.get_stmt(), AstStatement::Literal(_)) {
+            let copy = copy(&result, line);
+            self.result = Some(result);
+            return Some(copy);
+        }
+
+        let name = format!("__il_cr{}", line.next_id());
+        let allocation = AstNode {
+            stmt: AstStatement::AllocationStatement(Allocation {
+                name: name.clone(),
+                reference_type: BOOL_TYPE.to_string(),
+            }),
+            id: line.next_id(),
+            location: location.clone(),
+        };
+        self.temporaries.push(allocation);
+
+        let temporary = |line: &mut ParseSession| {
+            AstFactory::create_member_reference(
+                AstFactory::create_identifier(&name, location.clone(), line.next_id()),
+                None,
+                line.next_id(),
+            )
+        };
+        let target = temporary(line);
+        self.statements.push(AstFactory::create_assignment(target, result, line.next_id()));
+        self.result = Some(temporary(line));
+        Some(temporary(line))
+    }
+
+    fn is_temporary(&self, name: &str) -> bool {
+        self.temporaries.iter().any(|it| {
+            matches!(it.get_stmt(), AstStatement::AllocationStatement(Allocation { name: temporary, .. }) if temporary == name)
+        })
+    }
+}
+
+/// Copies the given node, every node of the copy is assigned a new id
+fn copy(node: &AstNode, line: &ParseSession) -> AstNode {
+    let mut copy = node.clone();
+    IdRenewer(line.id_provider.clone()).visit(&mut copy);
+    copy
+}
+
+struct IdRenewer(IdProvider);
+
+impl AstVisitorMut for IdRenewer {
+    fn visit(&mut self, node: &mut AstNode) {
+        node.id = self.0.next_id();
+        node.walk(self)
+    }
+}
+
+fn negate(node: AstNode, negated: bool, line: &mut ParseSession) -> AstNode {
+    if !negated {
+        return node;
+    }
+
+    let location = node.get_location();
+    AstFactory::create_not_expression(node, location, line.next_id())
+}
+
+/// Creates `IF condition THEN statement; END_IF`
+fn create_conditional(
+    condition: AstNode,
+    statement: AstNode,
+    location: SourceLocation,
+    line: &mut ParseSession,
+) -> AstNode {
+    AstFactory::create_if_statement(
+        vec![ConditionalBlock { condition: Box::new(condition), body: vec![statement] }],
+        vec![],
+        location,
+        line.next_id(),
+    )
+}
@@ -0,0 +1,175 @@
+//! Parses the textual representation of a sequential function chart (SFC) forming the body of a POU.
+use plc_ast::ast::{
+    ActionAssociation, ActionQualifier, Implementation, LinkageType, Sfc, SfcStep, SfcTransition,
+};
+use plc_diagnostics::diagnostics::Diagnostic;
+use plc_source::source_location::SourceLocation;
+
+use crate::lexer::Token::*;
+
+use super::{parse_action, parse_any_in_region, parse_expression, parse_identifier, ParseSession};
+
+/// Returns whether the body of the POU currently parsed is a sequential function chart
+pub fn is_sfc_body(lexer: &ParseSession) -> bool {
+    matches!(lexer.token, KeywordInitialStep | KeywordTransition | KeywordAction) || is_step(lexer)
+}
+
+/// `STEP` and `FROM` are common variable names, so they are only treated as keywords within a chart. A
+/// step is told apart from a statement by the name following the keyword, e.g. `STEP fill`.
+fn is_step(lexer: &ParseSession) -> bool {
+    is_contextual_keyword(lexer, "STEP") && lexer.is_followed_by_identifier()
+}
+
+fn is_contextual_keyword(lexer: &ParseSession, keyword: &str) -> bool {
+    lexer.token == Identifier && lexer.slice().eq_ignore_ascii_case(keyword)
+}
+
+/// Parses the steps, transitions and actions of a sequential function chart until the end of the
+/// containing POU. Actions are returned as implementations of the given container.
+pub fn parse_sfc(
+    lexer: &mut ParseSession,
+    linkage: LinkageType,
+    container: &str,
+) -> (Sfc, Vec<Implementation>) {
+    let mut sfc = Sfc::default();
+    let mut actions = vec![];
+    while !lexer.closes_open_region(&lexer.token) {
+        match lexer.token {
+            _ if lexer.token == KeywordInitialStep || is_step(lexer) => {
+                if let Some(step) = parse_step(lexer) {
+                    sfc.steps.push(step);
+                }
+            }
+            KeywordTransition => {
+                if let Some(transition) = parse_transition(lexer) {
+                    sfc.transitions.push(transition);
+                }
+            }
+            KeywordAction => {
+                if let Some(action) = parse_action(lexer, linkage, Some(container)) {
+                    actions.push(action);
+                }
+            }
+            _ => {
+                lexer.accept_diagnostic(Diagnostic::unexpected_token_found(
+                    "STEP",
+                    lexer.slice(),
+                    lexer.location(),
+                ));
+                lexer.advance();
+            }
+        }
+    }
+    (sfc, actions)
+}
+
+/// Parses a step and its action associations, e.g. `STEP fill : open_valve(N); END_STEP`
+fn parse_step(lexer: &mut ParseSession) -> Option<SfcStep> {
+    let initial = lexer.token == KeywordInitialStep;
+    let start = lexer.range().start;
+    lexer.advance();
+    parse_any_in_region(lexer, vec![KeywordEndStep], |lexer| {
+        let (name, name_location) = parse_identifier(lexer)?;
+        lexer.try_consume_or_report(KeywordColon);
+
+        let mut actions = vec![];
+        while lexer.token == Identifier {
+            actions.push(parse_action_association(lexer));
+            lexer.try_consume_or_report(KeywordSemicolon);
+        }
+
+        Some(SfcStep {
+            name,
+            initial,
+            actions,
+            location: lexer.source_range_factory.create_range(start..lexer.range().end),
+            name_location,
+        })
+    })
+}
+
+/// Parses the association of an action with a step, e.g. `open_valve(L, T#5s)`. A missing qualifier
+/// defaults to `N`.
+fn parse_action_association(lexer: &mut ParseSession) -> ActionAssociation {
+    let start = lexer.location();
+    let action = lexer.slice_and_advance();
+    lexer.try_consume_or_report(KeywordParensOpen);
+
+    let mut qualifier = ActionQualifier::NonStored;
+    if lexer.token == Identifier {
+        match ActionQualifier::from_name(lexer.slice()) {
+            Some(it) => qualifier = it,
+            None => lexer.accept_diagnostic(
+                Diagnostic::new(format!("Unknown action qualifier '{}'", lexer.slice()))
+                    .with_error_code("E125")
+                    .with_location(lexer.location()),
+            ),
+        }
+        lexer.advance();
+    }
+
+    let duration = lexer.try_consume(KeywordComma).then(|| parse_expression(lexer));
+    let location = start.span(&lexer.location());
+    lexer.try_consume_or_report(KeywordParensClose);
+
+    match (qualifier.is_timed(), &duration) {
+        (true, None) => lexer.accept_diagnostic(
+            Diagnostic::new(format!("Action qualifier '{qualifier}' requires a duration"))
+                .with_error_code("E125")
+                .with_location(location.clone()),
+        ),
+        (false, Some(duration)) => lexer.accept_diagnostic(
+            Diagnostic::new(format!("Action qualifier '{qualifier}' does not accept a duration"))
+                .with_error_code("E125")
+                .with_location(duration.get_location()),
+        ),
+        _ => {}
+    }
+
+    ActionAssociation { action, qualifier, duration, location }
+}
+
+/// Parses a transition, e.g. `TRANSITION FROM (a, b) TO c := x > 10; END_TRANSITION`
+fn parse_transition(lexer: &mut ParseSession) -> Option<SfcTransition> {
+    let start = lexer.range().start;
+    lexer.advance();
+    parse_any_in_region(lexer, vec![KeywordEndTransition], |lexer| {
+        let name = (lexer.token == Identifier && !is_contextual_keyword(lexer, "FROM"))
+            .then(|| lexer.slice_and_advance());
+        if is_contextual_keyword(lexer, "FROM") {
+            lexer.advance();
+        } else {
+            lexer.accept_diagnostic(Diagnostic::missing_token("FROM", lexer.location()));
+        }
+        let from = parse_step_names(lexer);
+        lexer.try_consume_or_report(KeywordTo);
+        let to = parse_step_names(lexer);
+        lexer.try_consume_or_report(KeywordAssignment);
+        let condition = parse_any_in_region(lexer, vec![KeywordSemicolon], parse_expression);
+
+        Some(SfcTransition {
+            name,
+            from,
+            to,
+            condition,
+            location: lexer.source_range_factory.create_range(start..lexer.range().end),
+        })
+    })
+}
+
+/// Parses a single step name or a parenthesized list of step names, e.g. `(a, b)`
+fn parse_step_names(lexer: &mut ParseSession) -> Vec<(String, SourceLocation)> {
+    if !lexer.try_consume(KeywordParensOpen) {
+        return parse_identifier(lexer).into_iter().collect();
+    }
+
+    let mut names = vec![];
+    loop {
+        names.extend(parse_identifier(lexer));
+        if !lexer.try_consume(KeywordComma) {
+            break;
+        }
+    }
+    lexer.try_consume_or_report(KeywordParensClose);
+    names
+}
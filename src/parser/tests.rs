@@ -6,17 +6,23 @@ use plc_source::source_location::SourceLocation;
 
 // Copyright (c) 2020 Ghaith Hachem and Mathias Rieder
 mod ast_visitor_tests;
+mod attribute_parser_tests;
 mod class_parser_tests;
+mod configuration_parser_tests;
 mod container_parser_tests;
 mod control_parser_tests;
 mod expressions_parser_tests;
 mod function_parser_tests;
+mod il_parser_tests;
 mod initializer_parser_tests;
 mod interface_parser_tests;
 mod misc_parser_tests;
+mod namespace_parser_tests;
 mod parse_errors;
 mod parse_generics;
 mod program_parser_tests;
+mod property_parser_tests;
+mod sfc_parser_tests;
 mod statement_parser_tests;
 mod type_parser_tests;
 mod variable_parser_tests;
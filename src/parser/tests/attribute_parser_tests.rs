@@ -0,0 +1,193 @@
+use insta::assert_debug_snapshot;
+use plc_ast::ast::{Attribute, LinkageType};
+
+use crate::test_utils::tests::parse;
+
+/// returns the names and values of the given attributes
+fn names_and_values(attributes: &[Attribute]) -> Vec<(&str, Option<&str>)> {
+    attributes.iter().map(|it| (it.name.as_str(), it.value.as_deref())).collect()
+}
+
+#[test]
+fn attributes_are_attached_to_declarations() {
+    let src = "
+    {attribute 'monitoring' := 'call'}
+    {attribute 'reflection'}
+    FUNCTION_BLOCK fb
+        {attribute 'hide'}
+        VAR
+            {attribute 'hide'}
+            a, b : INT;
+            c : INT;
+        END_VAR
+
+        {attribute 'test' := 'method'}
+        METHOD m END_METHOD
+    END_FUNCTION_BLOCK
+
+    {attribute 'qualified_only'}
+    VAR_GLOBAL
+        {attribute 'init_on_onlchange'}
+        g : DINT;
+    END_VAR
+
+    {attribute 'strict'}
+    TYPE
+        {attribute 'qualified_only'}
+        color : (red, green);
+        point : STRUCT
+            {attribute 'hide'}
+            x : DINT;
+            y : DINT;
+        END_STRUCT
+    END_TYPE
+    ";
+    let (unit, diagnostics) = parse(src);
+    assert!(diagnostics.is_empty(), "Expected no diagnostics but got {diagnostics:#?}");
+
+    let fb = &unit.units[0];
+    assert_eq!(names_and_values(&fb.attributes), vec![("monitoring", Some("call")), ("reflection", None)]);
+    assert_eq!(names_and_values(&fb.variable_blocks[0].attributes), vec![("hide", None)]);
+    let variables = &fb.variable_blocks[0].variables;
+    assert_eq!(names_and_values(&variables[0].attributes), vec![("hide", None)]);
+    assert_eq!(names_and_values(&variables[1].attributes), vec![("hide", None)]);
+    assert!(variables[2].attributes.is_empty());
+
+    let method = &unit.units[1];
+    assert_eq!(names_and_values(&method.attributes), vec![("test", Some("method"))]);
+
+    let globals = &unit.global_vars[0];
+    assert_eq!(names_and_values(&globals.attributes), vec![("qualified_only", None)]);
+    assert_eq!(names_and_values(&globals.variables[0].attributes), vec![("init_on_onlchange", None)]);
+
+    // attributes in front of TYPE apply to all types of the block
+    assert_eq!(
+        names_and_values(&unit.user_types[0].attributes),
+        vec![("strict", None), ("qualified_only", None)]
+    );
+    assert_eq!(names_and_values(&unit.user_types[1].attributes), vec![("strict", None)]);
+    let plc_ast::ast::DataType::StructType { variables, .. } = &unit.user_types[1].data_type else {
+        panic!("expected a struct")
+    };
+    assert_eq!(names_and_values(&variables[0].attributes), vec![("hide", None)]);
+    assert!(variables[1].attributes.is_empty());
+}
+
+#[test]
+fn attributes_keep_their_location() {
+    let src = "{attribute 'hide'} PROGRAM prg END_PROGRAM";
+    let (unit, _) = parse(src);
+
+    let attribute = &unit.units[0].attributes[0];
+    assert_eq!(attribute.location.to_range(), Some(0..18));
+}
+
+#[test]
+fn attributes_are_printed_in_the_ast() {
+    let src = "
+    {attribute 'monitoring' := 'call'}
+    FUNCTION foo : INT
+        VAR_INPUT
+            {attribute 'hide'}
+            x : INT;
+        END_VAR
+    END_FUNCTION
+    ";
+    let (unit, _) = parse(src);
+
+    assert_debug_snapshot!(unit.units[0], @r#"
+    POU {
+        name: "foo",
+        variable_blocks: [
+            VariableBlock {
+                variables: [
+                    Variable {
+                        name: "x",
+                        data_type: DataTypeReference {
+                            referenced_type: "INT",
+                        },
+                        attributes: [
+                            Attribute {
+                                name: "hide",
+                            },
+                        ],
+                    },
+                ],
+                variable_block_type: Input(
+                    ByVal,
+                ),
+            },
+        ],
+        pou_type: Function,
+        return_type: Some(
+            DataTypeReference {
+                referenced_type: "INT",
+            },
+        ),
+        interfaces: [],
+        attributes: [
+            Attribute {
+                name: "monitoring",
+                value: "call",
+            },
+        ],
+    }
+    "#);
+}
+
+#[test]
+fn attributes_in_front_of_other_pragmas_are_kept() {
+    let src = "
+    {attribute 'library'}
+    {external}
+    FUNCTION foo : INT END_FUNCTION
+    ";
+    let (unit, _) = parse(src);
+
+    assert_eq!(unit.units[0].linkage, LinkageType::External);
+    assert_eq!(names_and_values(&unit.units[0].attributes), vec![("library", None)]);
+}
+
+#[test]
+fn attributes_that_do_not_precede_a_declaration_are_dropped() {
+    let src = "
+    PROGRAM prg
+        VAR
+            x : INT;
+            {attribute 'dangling'}
+        END_VAR
+        {attribute 'in_body'}
+        x := 1;
+    END_PROGRAM
+
+    FUNCTION foo : INT
+        VAR
+            y : INT;
+        END_VAR
+    END_FUNCTION
+    ";
+    let (unit, diagnostics) = parse(src);
+
+    assert!(diagnostics.is_empty(), "Expected no diagnostics but got {diagnostics:#?}");
+    assert!(unit.units.iter().all(|it| it.attributes.is_empty()));
+    assert!(unit.units[1].variable_blocks[0].attributes.is_empty());
+    assert!(unit.units[1].variable_blocks[0].variables[0].attributes.is_empty());
+}
+
+#[test]
+fn malformed_attributes_and_other_pragmas_are_ignored() {
+    let src = "
+    {attribute hide}
+    {attribute ''}
+    {attribute 'a' 'b'}
+    {attribute 'c' := d}
+    {warning 'message'}
+    {ATTRIBUTE 'Upper' := ''}
+    PROGRAM prg END_PROGRAM
+    ";
+    let (unit, diagnostics) = parse(src);
+
+    assert!(diagnostics.is_empty(), "Expected no diagnostics but got {diagnostics:#?}");
+    assert_eq!(names_and_values(&unit.units[0].attributes), vec![("Upper", Some(""))]);
+    assert!(Attribute::find(&unit.units[0].attributes, "upper").is_some());
+}
@@ -77,7 +77,7 @@ fn method_with_defaults_can_be_parsed() {
     let method = &unit.implementations[0];
 
     assert_eq!(method_pou.name, "MyClass.testMethod");
-    assert_eq!(method.access, Some(AccessModifier::Protected));
+    assert_eq!(method.access, None);
     assert_eq!(method_pou.poly_mode, Some(PolymorphismMode::None));
     assert_eq!(method_pou.return_type, None);
     assert_eq!(method.overriding, false);
@@ -163,7 +163,7 @@ fn class_with_var_default_block() {
 
     assert_eq!(vblock.retain, false);
     assert_eq!(vblock.constant, false);
-    assert_eq!(vblock.access, AccessModifier::Protected);
+    assert_eq!(vblock.access, None);
     assert_eq!(vblock.variable_block_type, VariableBlockType::Local);
 }
 
@@ -183,7 +183,7 @@ fn class_with_var_non_retain_block() {
 
     assert_eq!(vblock.retain, false);
     assert_eq!(vblock.constant, true);
-    assert_eq!(vblock.access, AccessModifier::Public);
+    assert_eq!(vblock.access, Some(AccessModifier::Public));
     assert_eq!(vblock.variable_block_type, VariableBlockType::Local);
 }
 
@@ -203,7 +203,7 @@ fn class_with_var_retain_block() {
 
     assert_eq!(vblock.retain, true);
     assert_eq!(vblock.constant, false);
-    assert_eq!(vblock.access, AccessModifier::Internal);
+    assert_eq!(vblock.access, Some(AccessModifier::Internal));
     assert_eq!(vblock.variable_block_type, VariableBlockType::Local);
 }
 
@@ -223,7 +223,7 @@ fn method_with_var_block() {
 
     assert_eq!(vblock.retain, false);
     assert_eq!(vblock.constant, false);
-    assert_eq!(vblock.access, AccessModifier::Protected);
+    assert_eq!(vblock.access, None);
     assert_eq!(vblock.variable_block_type, VariableBlockType::Temp);
 }
 
@@ -312,7 +312,7 @@ fn fb_two_methods_can_be_parsed() {
 
     let method2 = &unit.implementations[1];
     assert_eq!(method2.name, "MyNewFb.otherMethod");
-    assert_eq!(method2.access, Some(AccessModifier::Protected));
+    assert_eq!(method2.access, None);
 }
 
 #[test]
@@ -389,7 +389,7 @@ fn program_two_methods_can_be_parsed() {
 
     let method2 = &unit.implementations[1];
     assert_eq!(method2.name, "prog.otherMethod");
-    assert_eq!(method2.access, Some(AccessModifier::Protected));
+    assert_eq!(method2.access, None);
 }
 
 #[test]
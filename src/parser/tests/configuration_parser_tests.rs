@@ -0,0 +1,118 @@
+use insta::{assert_debug_snapshot, assert_snapshot};
+use plc_ast::ast::{AstNode, Configuration};
+
+use crate::test_utils::tests::{parse, parse_buffered};
+
+/// returns the tasks with their properties and the program instances of every resource
+fn describe(src: &str, configuration: &Configuration) -> Vec<String> {
+    let property = |name: &str, value: &Option<AstNode>| {
+        value
+            .as_ref()
+            .map(|it| format!(" {name}={}", &src[it.get_location().to_range().unwrap()]))
+            .unwrap_or_default()
+    };
+    let mut description = vec![];
+    for resource in &configuration.resources {
+        description.push(format!("RESOURCE {} ON {:?}", resource.name, resource.processor));
+        for task in &resource.tasks {
+            description.push(format!(
+                "  TASK {}{}{}{}",
+                task.name,
+                property("INTERVAL", &task.interval),
+                property("SINGLE", &task.single),
+                property("PRIORITY", &task.priority)
+            ));
+        }
+        for program in &resource.programs {
+            description.push(format!(
+                "  PROGRAM {} WITH {:?} : {}",
+                program.instance_name,
+                program.task.as_ref().map(|(task, _)| task),
+                program.program
+            ));
+        }
+    }
+    description
+}
+
+#[test]
+fn configuration_with_resources_is_parsed() {
+    let src = "
+    CONFIGURATION Plant
+        VAR_GLOBAL
+            alarm : BOOL;
+        END_VAR
+        RESOURCE Cpu ON PLC
+            VAR_GLOBAL
+                counter : DINT;
+            END_VAR
+            TASK fast (INTERVAL := T#10ms, PRIORITY := 1);
+            TASK onAlarm (SINGLE := alarm, PRIORITY := 0);
+            PROGRAM main WITH fast : MainPrg;
+            PROGRAM alarms WITH onAlarm : vendor.AlarmPrg;
+        END_RESOURCE
+        RESOURCE Io ON Coupler
+            TASK io (INTERVAL := T#1ms, PRIORITY := 0);
+            PROGRAM io WITH io : IoPrg;
+        END_RESOURCE
+    END_CONFIGURATION
+    ";
+    let (unit, diagnostics) = parse(src);
+
+    assert!(diagnostics.is_empty(), "Expected no diagnostics but got {diagnostics:#?}");
+    assert_eq!(unit.configurations[0].name, "Plant");
+    assert_eq!(
+        unit.global_vars
+            .iter()
+            .flat_map(|it| it.variables.iter())
+            .map(|it| it.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["alarm", "counter"]
+    );
+    assert_debug_snapshot!(describe(src, &unit.configurations[0]), @r###"
+    [
+        "RESOURCE Cpu ON Some(\"PLC\")",
+        "  TASK fast INTERVAL=T#10ms PRIORITY=1",
+        "  TASK onAlarm SINGLE=alarm PRIORITY=0",
+        "  PROGRAM main WITH Some(\"fast\") : MainPrg",
+        "  PROGRAM alarms WITH Some(\"onAlarm\") : vendor::AlarmPrg",
+        "RESOURCE Io ON Some(\"Coupler\")",
+        "  TASK io INTERVAL=T#1ms PRIORITY=0",
+        "  PROGRAM io WITH Some(\"io\") : IoPrg",
+    ]
+    "###);
+}
+
+#[test]
+fn tasks_and_programs_of_a_configuration_form_a_single_resource() {
+    let src = "
+    CONFIGURATION Plant
+        TASK fast (INTERVAL := T#10ms, PRIORITY := 1);
+        PROGRAM main WITH fast : MainPrg;
+        PROGRAM background : BackgroundPrg;
+    END_CONFIGURATION
+    ";
+    let (unit, diagnostics) = parse(src);
+
+    assert!(diagnostics.is_empty(), "Expected no diagnostics but got {diagnostics:#?}");
+    assert_debug_snapshot!(describe(src, &unit.configurations[0]), @r###"
+    [
+        "RESOURCE Plant ON None",
+        "  TASK fast INTERVAL=T#10ms PRIORITY=1",
+        "  PROGRAM main WITH Some(\"fast\") : MainPrg",
+        "  PROGRAM background WITH None : BackgroundPrg",
+    ]
+    "###);
+}
+
+#[test]
+fn invalid_task_properties_are_reported() {
+    let src = "
+    CONFIGURATION Plant
+        TASK fast (INTERVAL := T#10ms, CYCLE := T#1ms, PRIORITY := 1, PRIORITY := 2, SINGLE := a OR b, 5);
+    END_CONFIGURATION
+    ";
+    let (_, diagnostics) = parse_buffered(src);
+
+    assert_snapshot!(diagnostics);
+}
@@ -573,3 +573,59 @@ fn call_stmnt_location_test() {
         assert_eq!(source[parameters_location.to_range().unwrap()].to_string(), "a:=3, b:=4");
     }
 }
+
+#[test]
+fn try_statement_with_catch_and_finally() {
+    let src = "
+        PROGRAM exp
+        __TRY
+            x := 1;
+        __CATCH(exc)
+            x := 2;
+        __FINALLY
+            x := 3;
+        __ENDTRY
+        END_PROGRAM
+        ";
+    let (result, diagnostics) = parse(src);
+    assert_eq!(diagnostics, vec![]);
+
+    let prg = &result.implementations[0];
+    let statement = &prg.statements[0];
+    assert_debug_snapshot!(statement);
+}
+
+#[test]
+fn try_statement_clauses_are_optional() {
+    let src = "
+        PROGRAM exp
+        __TRY
+            x := 1;
+        __CATCH
+            x := 2;
+        __ENDTRY
+        __TRY
+            x := 1;
+        __FINALLY
+            x := 3;
+        __ENDTRY
+        END_PROGRAM
+        ";
+    let (result, diagnostics) = parse(src);
+    assert_eq!(diagnostics, vec![]);
+
+    let prg = &result.implementations[0];
+    let AstStatement::ControlStatement(AstControlStatement::Try(catch_only)) = prg.statements[0].get_stmt()
+    else {
+        panic!("expected a try statement, found {:#?}", prg.statements[0]);
+    };
+    assert!(catch_only.catch_block.as_ref().is_some_and(|it| it.exception.is_none()));
+    assert!(catch_only.finally_block.is_empty());
+
+    let AstStatement::ControlStatement(AstControlStatement::Try(finally_only)) = prg.statements[1].get_stmt()
+    else {
+        panic!("expected a try statement, found {:#?}", prg.statements[1]);
+    };
+    assert!(finally_only.catch_block.is_none());
+    assert_eq!(finally_only.finally_block.len(), 1);
+}
@@ -1595,10 +1595,14 @@ fn sized_string_as_function_return() {
         location: SourceLocation::internal(),
         name_location: SourceLocation::internal(),
         generics: vec![],
+        generic_arguments: vec![],
         linkage: LinkageType::Internal,
         super_class: None,
         interfaces: vec![],
+        properties: vec![],
         is_const: false,
+        attributes: vec![],
+        sfc: None,
     };
 
     assert_eq!(format!("{:?}", ast.units[0]), format!("{expected:?}"));
@@ -1639,10 +1643,14 @@ fn array_type_as_function_return() {
         location: SourceLocation::internal(),
         name_location: SourceLocation::internal(),
         generics: vec![],
+        generic_arguments: vec![],
         linkage: LinkageType::Internal,
         super_class: None,
         interfaces: vec![],
+        properties: vec![],
         is_const: false,
+        attributes: vec![],
+        sfc: None,
     };
 
     assert_eq!(format!("{:?}", ast.units[0]), format!("{expected:?}"));
@@ -1755,3 +1763,17 @@ fn parenthesized_expression_span() {
     let range = array.elements().unwrap().get_location().get_span().to_range().unwrap();
     assert_eq!(&src[range.start..range.end], "(1 + 2)");
 }
+
+#[test]
+fn this_and_super_are_parsed_as_pointers() {
+    let src = "
+    FUNCTION_BLOCK fb
+        THIS^.x := SUPER^.foo();
+        this^();
+    END_FUNCTION_BLOCK
+    ";
+    let (unit, diagnostics) = parse(src);
+
+    assert!(diagnostics.is_empty(), "Expected no diagnostics but got {diagnostics:#?}");
+    assert_debug_snapshot!(unit.implementations[0].statements);
+}
@@ -1,8 +1,8 @@
 use crate::test_utils::tests::{parse, parse_and_validate_buffered, parse_buffered};
 use insta::{assert_debug_snapshot, assert_snapshot};
 use plc_ast::ast::{
-    AccessModifier, ArgumentProperty, DataType, DataTypeDeclaration, LinkageType, Pou, PouType, Variable,
-    VariableBlock, VariableBlockType,
+    ArgumentProperty, DataType, DataTypeDeclaration, LinkageType, Pou, PouType, Variable, VariableBlock,
+    VariableBlockType,
 };
 use plc_diagnostics::diagnostics::Diagnostic;
 use plc_source::source_location::SourceLocation;
@@ -198,8 +198,9 @@ fn varargs_parameters_can_be_parsed() {
         }),
         variable_blocks: vec![VariableBlock {
             constant: false,
-            access: AccessModifier::Protected,
+            access: None,
             retain: false,
+            persistent: false,
             variable_block_type: VariableBlockType::Input(ArgumentProperty::ByVal),
             location: SourceLocation::internal(),
             linkage: LinkageType::Internal,
@@ -213,7 +214,9 @@ fn varargs_parameters_can_be_parsed() {
                     },
                     initializer: None,
                     address: None,
+                    init_arguments: None,
                     location: SourceLocation::internal(),
+                    attributes: vec![],
                 },
                 Variable {
                     name: "args2".into(),
@@ -230,18 +233,25 @@ fn varargs_parameters_can_be_parsed() {
                     },
                     initializer: None,
                     address: None,
+                    init_arguments: None,
                     location: SourceLocation::internal(),
+                    attributes: vec![],
                 },
             ],
+            attributes: vec![],
         }],
         location: SourceLocation::internal(),
         name_location: SourceLocation::internal(),
         poly_mode: None,
         generics: vec![],
+        generic_arguments: vec![],
         linkage: LinkageType::Internal,
         super_class: None,
         interfaces: vec![],
+        properties: vec![],
         is_const: false,
+        attributes: vec![],
+        sfc: None,
     };
     assert_eq!(format!("{expected:#?}"), format!("{x:#?}").as_str());
 }
@@ -270,8 +280,9 @@ fn sized_varargs_parameters_can_be_parsed() {
         }),
         variable_blocks: vec![VariableBlock {
             constant: false,
-            access: AccessModifier::Protected,
+            access: None,
             retain: false,
+            persistent: false,
             variable_block_type: VariableBlockType::Input(ArgumentProperty::ByVal),
             location: SourceLocation::internal(),
             linkage: LinkageType::Internal,
@@ -285,7 +296,9 @@ fn sized_varargs_parameters_can_be_parsed() {
                     },
                     initializer: None,
                     address: None,
+                    init_arguments: None,
                     location: SourceLocation::internal(),
+                    attributes: vec![],
                 },
                 Variable {
                     name: "args2".into(),
@@ -302,18 +315,25 @@ fn sized_varargs_parameters_can_be_parsed() {
                     },
                     initializer: None,
                     address: None,
+                    init_arguments: None,
                     location: SourceLocation::internal(),
+                    attributes: vec![],
                 },
             ],
+            attributes: vec![],
         }],
         location: SourceLocation::internal(),
         name_location: SourceLocation::internal(),
         poly_mode: None,
         generics: vec![],
+        generic_arguments: vec![],
         linkage: LinkageType::Internal,
         super_class: None,
         interfaces: vec![],
+        properties: vec![],
         is_const: false,
+        attributes: vec![],
+        sfc: None,
     };
     assert_eq!(format!("{expected:#?}"), format!("{x:#?}").as_str());
 }
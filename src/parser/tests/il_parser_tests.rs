@@ -0,0 +1,168 @@
+use insta::{assert_debug_snapshot, assert_snapshot};
+use plc_ast::{ast::LinkageType, provider::IdProvider};
+use plc_diagnostics::diagnostician::Diagnostician;
+use plc_source::SourceCodeFactory;
+
+use crate::{
+    lexer::Defines,
+    parser::parse_file,
+    test_utils::tests::{parse, parse_buffered},
+};
+
+#[test]
+fn il_body_is_translated_into_statements() {
+    let src = "
+    {attribute 'language' := 'IL'}
+    FUNCTION_BLOCK interlock
+    VAR_INPUT
+        start, stop : BOOL;
+    END_VAR
+    VAR_OUTPUT
+        lamp, motor : BOOL;
+        count : INT;
+    END_VAR
+        LD start
+        ANDN stop
+        ST lamp
+        S motor
+        LDN lamp
+        R motor
+        LD count
+        ADD 1
+        ST count
+    END_FUNCTION_BLOCK
+    ";
+
+    let (unit, diagnostics) = parse(src);
+    assert!(diagnostics.is_empty(), "Expected no diagnostics but got {diagnostics:#?}");
+    assert_debug_snapshot!(unit.implementations[0].statements);
+}
+
+#[test]
+fn il_jumps_calls_and_parentheses_are_translated() {
+    let src = "
+    {attribute 'language' := 'IL'}
+    FUNCTION foo : INT
+    VAR_INPUT
+        a, b, c : INT;
+    END_VAR
+    VAR
+        timer : TON;
+    END_VAR
+        LD a
+        GT b
+        JMPCN skip
+        LD a
+        MUL( b
+        SUB c
+        )
+        ST foo
+        RET
+    skip:
+        LD a
+        MAX b, c
+        ST foo
+        CAL timer(IN := TRUE, PT := T#1s)
+    END_FUNCTION
+    ";
+
+    let (unit, diagnostics) = parse(src);
+    assert!(diagnostics.is_empty(), "Expected no diagnostics but got {diagnostics:#?}");
+    assert_debug_snapshot!(unit.implementations[0].statements);
+}
+
+#[test]
+fn current_result_is_stored_before_it_is_used_as_a_condition() {
+    let src = "
+    {attribute 'language' := 'IL'}
+    FUNCTION foo : BOOL
+    VAR_INPUT
+        x : INT;
+    END_VAR
+    VAR
+        a, b, y : BOOL;
+    END_VAR
+        LD a
+        R a
+        ST b
+        LD x
+        isReady
+        JMPC done
+        ST y
+    done:
+        RET
+    END_FUNCTION
+    ";
+
+    let (unit, diagnostics) = parse(src);
+    assert!(diagnostics.is_empty(), "Expected no diagnostics but got {diagnostics:#?}");
+    assert_debug_snapshot!(unit.implementations[0].statements);
+}
+
+#[test]
+fn language_attribute_selects_the_language_of_a_single_pou() {
+    let src = "
+    FUNCTION foo : INT
+        foo := 1;
+    END_FUNCTION
+
+    {attribute 'language' := 'IL'}
+    FUNCTION bar : INT
+        LD 2
+        ST bar
+    END_FUNCTION
+
+    FUNCTION baz : INT
+        baz := 3;
+    END_FUNCTION
+    ";
+
+    let (unit, diagnostics) = parse(src);
+    assert!(diagnostics.is_empty(), "Expected no diagnostics but got {diagnostics:#?}");
+    assert_eq!(unit.implementations.iter().map(|it| it.statements.len()).collect::<Vec<_>>(), vec![1, 1, 1]);
+}
+
+#[test]
+fn il_files_are_parsed_by_their_extension() {
+    let src = "
+    FUNCTION foo : INT
+        LD 1
+        ADD 2
+        ST foo
+    END_FUNCTION
+
+    {attribute 'language' := 'ST'}
+    FUNCTION bar : INT
+        bar := 3;
+    END_FUNCTION
+    ";
+
+    let unit = parse_file(
+        &src.create_source("test.il"),
+        LinkageType::Internal,
+        IdProvider::default(),
+        &Defines::default(),
+        &mut Diagnostician::buffered(),
+    )
+    .unwrap();
+    assert_debug_snapshot!(unit.implementations.iter().map(|it| &it.statements).collect::<Vec<_>>());
+}
+
+#[test]
+fn invalid_instructions_are_reported() {
+    let src = "
+    {attribute 'language' := 'IL'}
+    FUNCTION foo : INT
+        AND a
+        LD a b
+        ST
+        NOT 1
+        )
+        IF
+        ORN( b
+    END_FUNCTION
+    ";
+
+    let (_, diagnostics) = parse_buffered(src);
+    assert_snapshot!(diagnostics);
+}
@@ -83,6 +83,7 @@ fn initial_scalar_values_can_be_parsed() {
                 ),
             },
         ],
+        base: None,
     },
     initializer: None,
     scope: None,
@@ -15,6 +15,7 @@ fn empty_interface() {
         Interface {
             name: "myInterface",
             methods: [],
+            properties: [],
             location: SourceLocation {
                 span: Range(
                     TextLocation {
@@ -101,6 +102,7 @@ fn interface_with_single_method() {
                     interfaces: [],
                 },
             ],
+            properties: [],
             location: SourceLocation {
                 span: Range(
                     TextLocation {
@@ -235,6 +237,7 @@ fn interface_with_multiple_methods() {
                     interfaces: [],
                 },
             ],
+            properties: [],
             location: SourceLocation {
                 span: Range(
                     TextLocation {
@@ -0,0 +1,160 @@
+use insta::{assert_debug_snapshot, assert_snapshot};
+use plc_ast::ast::{DataTypeDeclaration, PouType};
+
+use crate::test_utils::tests::{parse, parse_buffered};
+
+#[test]
+fn declarations_in_namespaces_are_qualified() {
+    let src = "
+    NAMESPACE vendor.drives
+        FUNCTION_BLOCK motor
+            METHOD start END_METHOD
+        END_FUNCTION_BLOCK
+
+        ACTIONS motor
+            ACTION stop END_ACTION
+        END_ACTIONS
+
+        NAMESPACE io
+            INTERFACE ISensor END_INTERFACE
+            TYPE point : STRUCT x, y : DINT; END_STRUCT END_TYPE
+            VAR_GLOBAL
+                sensors : DINT;
+            END_VAR
+        END_NAMESPACE
+
+        FUNCTION reset END_FUNCTION
+    END_NAMESPACE
+
+    PROGRAM main END_PROGRAM
+    ";
+    let (unit, diagnostics) = parse(src);
+
+    assert!(diagnostics.is_empty(), "Expected no diagnostics but got {diagnostics:#?}");
+    assert_eq!(
+        unit.units.iter().map(|it| it.name.as_str()).collect::<Vec<_>>(),
+        vec!["vendor::drives::motor", "vendor::drives::motor.start", "vendor::drives::reset", "main"]
+    );
+    assert_eq!(unit.units[1].kind, PouType::Method { parent: "vendor::drives::motor".into() });
+    assert_eq!(
+        unit.implementations.iter().map(|it| it.name.as_str()).collect::<Vec<_>>(),
+        vec![
+            "vendor::drives::motor.start",
+            "vendor::drives::motor",
+            "vendor::drives::motor.stop",
+            "vendor::drives::reset",
+            "main"
+        ]
+    );
+    assert_eq!(unit.interfaces[0].name, "vendor::drives::io::ISensor");
+    assert_eq!(unit.user_types[0].data_type.get_name(), Some("vendor::drives::io::point"));
+    assert_eq!(unit.global_vars[0].variables[0].name, "vendor::drives::io::sensors");
+}
+
+#[test]
+fn using_directives_are_parsed() {
+    let src = "
+    USING vendor.drives, lib;
+
+    NAMESPACE app
+        USING vendor.io;
+
+        FUNCTION_BLOCK controller USING vendor.motion;
+        END_FUNCTION_BLOCK
+
+        FUNCTION run : DINT
+        USING lib.math;
+        END_FUNCTION
+    END_NAMESPACE
+    ";
+    let (unit, diagnostics) = parse(src);
+
+    assert!(diagnostics.is_empty(), "Expected no diagnostics but got {diagnostics:#?}");
+    assert_debug_snapshot!(unit
+        .usings
+        .iter()
+        .map(|it| (it.namespace.as_str(), it.scope.as_deref()))
+        .collect::<Vec<_>>(), @r###"
+    [
+        (
+            "vendor::drives",
+            None,
+        ),
+        (
+            "lib",
+            None,
+        ),
+        (
+            "vendor::io",
+            Some(
+                "app",
+            ),
+        ),
+        (
+            "vendor::motion",
+            Some(
+                "app::controller",
+            ),
+        ),
+        (
+            "lib::math",
+            Some(
+                "app::run",
+            ),
+        ),
+    ]
+    "###);
+}
+
+#[test]
+fn qualified_names_can_be_referenced() {
+    let src = "
+    FUNCTION_BLOCK motor EXTENDS vendor.drives.base IMPLEMENTS vendor.ISensor, IMotor
+    VAR
+        a : vendor.drives.point;
+        b : ARRAY[0..1] OF vendor.drives.point;
+    END_VAR
+        x := vendor.io.read();
+        y := vendor.Color#Red;
+    END_FUNCTION_BLOCK
+    ";
+    let (unit, diagnostics) = parse(src);
+
+    assert!(diagnostics.is_empty(), "Expected no diagnostics but got {diagnostics:#?}");
+    let pou = &unit.units[0];
+    assert_eq!(pou.super_class.as_deref(), Some("vendor.drives.base"));
+    assert_eq!(
+        pou.interfaces.iter().map(|it| it.name.as_str()).collect::<Vec<_>>(),
+        vec!["vendor.ISensor", "IMotor"]
+    );
+    let variables = &pou.variable_blocks[0].variables;
+    assert_eq!(variables[0].data_type_declaration.get_name(), Some("vendor.drives.point"));
+    let DataTypeDeclaration::DataTypeDefinition { data_type, .. } = &variables[1].data_type_declaration
+    else {
+        panic!("expected an inline array type");
+    };
+    assert_debug_snapshot!(data_type);
+    assert_debug_snapshot!(unit.implementations[0].statements);
+}
+
+#[test]
+fn unclosed_namespace_is_reported() {
+    let src = "
+    NAMESPACE vendor
+        FUNCTION foo END_FUNCTION
+    ";
+    let (_, diagnostics) = parse_buffered(src);
+
+    assert_snapshot!(diagnostics);
+}
+
+#[test]
+fn end_namespace_without_namespace_is_reported() {
+    let src = "
+        FUNCTION foo END_FUNCTION
+    END_NAMESPACE
+    ";
+    let (_, diagnostics) = parse_buffered(src);
+
+    assert_snapshot!(diagnostics);
+}
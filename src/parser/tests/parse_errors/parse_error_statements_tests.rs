@@ -2,8 +2,8 @@
 use crate::{parser::tests::ref_to, test_utils::tests::parse_buffered};
 use insta::{assert_debug_snapshot, assert_snapshot};
 use plc_ast::ast::{
-    AccessModifier, AstFactory, DataType, DataTypeDeclaration, LinkageType, UserTypeDeclaration, Variable,
-    VariableBlock, VariableBlockType,
+    AstFactory, DataType, DataTypeDeclaration, LinkageType, UserTypeDeclaration, Variable, VariableBlock,
+    VariableBlockType,
 };
 use plc_source::source_location::SourceLocation;
 use pretty_assertions::*;
@@ -200,8 +200,9 @@ fn invalid_variable_name_error_recovery() {
             "{:#?}",
             VariableBlock {
                 constant: false,
-                access: AccessModifier::Protected,
+                access: None,
                 retain: false,
+                persistent: false,
                 location: SourceLocation::internal(),
                 variables: vec![Variable {
                     name: "c".into(),
@@ -211,10 +212,13 @@ fn invalid_variable_name_error_recovery() {
                     },
                     initializer: None,
                     address: None,
+                    init_arguments: None,
                     location: SourceLocation::internal(),
+                    attributes: vec![],
                 },],
                 variable_block_type: VariableBlockType::Local,
                 linkage: LinkageType::Internal,
+                attributes: vec![],
             }
         )
     );
@@ -1133,6 +1137,7 @@ fn pointer_type_without_to_test() {
         location: SourceLocation::internal(),
         initializer: None,
         scope: None,
+        attributes: vec![],
     };
     assert_eq!(format!("{expected:#?}"), format!("{pointer_type:#?}").as_str());
 
@@ -1160,6 +1165,7 @@ fn pointer_type_with_wrong_keyword_to_test() {
         location: SourceLocation::internal(),
         initializer: None,
         scope: None,
+        attributes: vec![],
     };
     assert_eq!(format!("{expected:#?}"), format!("{pointer_type:#?}").as_str());
     assert_snapshot!(diagnostics);
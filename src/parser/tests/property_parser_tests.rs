@@ -0,0 +1,138 @@
+use insta::{assert_debug_snapshot, assert_snapshot};
+use plc_ast::ast::{AccessModifier, PropertyKind};
+
+use crate::test_utils::tests::{parse, parse_buffered};
+
+#[test]
+fn property_with_get_and_set_can_be_parsed() {
+    let src = "
+    FUNCTION_BLOCK fb
+        PROPERTY foo : INT
+            GET
+                VAR
+                    tmp : INT;
+                END_VAR
+                foo := tmp;
+            END_GET
+            SET
+                tmp := foo;
+            END_SET
+        END_PROPERTY
+    END_FUNCTION_BLOCK
+    ";
+    let (unit, diagnostics) = parse(src);
+
+    assert!(diagnostics.is_empty(), "Expected no diagnostics but got {diagnostics:#?}");
+    assert_debug_snapshot!(unit.units[0].properties);
+}
+
+#[test]
+fn property_accessor_keywords_are_case_insensitive() {
+    let src = "
+    CLASS cls
+        PROPERTY PRIVATE foo : INT
+            get foo := 1; end_get
+            Set END_SET
+        END_PROPERTY
+    END_CLASS
+    ";
+    let (unit, diagnostics) = parse(src);
+
+    assert!(diagnostics.is_empty(), "Expected no diagnostics but got {diagnostics:#?}");
+    let property = &unit.units[0].properties[0];
+    assert_eq!(property.name, "foo");
+    assert_eq!(property.access, Some(AccessModifier::Private));
+    assert_eq!(
+        property.accessors.iter().map(|it| it.kind).collect::<Vec<_>>(),
+        vec![PropertyKind::Get, PropertyKind::Set]
+    );
+}
+
+#[test]
+fn properties_and_methods_can_be_mixed() {
+    let src = "
+    FUNCTION_BLOCK fb
+        METHOD foo END_METHOD
+        PROPERTY bar : DINT GET END_GET END_PROPERTY
+        METHOD baz END_METHOD
+        PROPERTY qux : DINT SET END_SET END_PROPERTY
+    END_FUNCTION_BLOCK
+    ";
+    let (unit, diagnostics) = parse(src);
+
+    assert!(diagnostics.is_empty(), "Expected no diagnostics but got {diagnostics:#?}");
+    assert_eq!(
+        unit.units[0].properties.iter().map(|it| it.name.as_str()).collect::<Vec<_>>(),
+        ["bar", "qux"]
+    );
+    assert_eq!(unit.units.iter().map(|it| it.name.as_str()).collect::<Vec<_>>(), ["fb", "fb.foo", "fb.baz"]);
+}
+
+#[test]
+fn interface_property_can_be_parsed() {
+    let src = "
+    INTERFACE foo
+        PROPERTY bar : STRING
+            GET END_GET
+            SET END_SET
+        END_PROPERTY
+    END_INTERFACE
+    ";
+    let (unit, diagnostics) = parse(src);
+
+    assert!(diagnostics.is_empty(), "Expected no diagnostics but got {diagnostics:#?}");
+    assert_debug_snapshot!(unit.interfaces[0].properties);
+}
+
+#[test]
+fn interface_property_with_implementation_is_reported() {
+    let src = "
+    INTERFACE foo
+        PROPERTY bar : INT
+            GET bar := 5; END_GET
+        END_PROPERTY
+    END_INTERFACE
+    ";
+    let (_, diagnostics) = parse_buffered(src);
+
+    assert_snapshot!(diagnostics);
+}
+
+#[test]
+fn property_in_function_is_reported() {
+    let src = "
+    FUNCTION foo : INT
+        PROPERTY bar : INT GET END_GET END_PROPERTY
+        foo := 1;
+    END_FUNCTION
+    ";
+    let (_, diagnostics) = parse_buffered(src);
+
+    assert_snapshot!(diagnostics);
+}
+
+#[test]
+fn invalid_property_declarations_are_reported() {
+    let src = "
+    FUNCTION_BLOCK fb
+        PROPERTY withInitializer : INT := 5
+            GET END_GET
+        END_PROPERTY
+
+        PROPERTY withoutAccessors : INT
+        END_PROPERTY
+
+        PROPERTY duplicateAccessor : INT
+            GET END_GET
+            GET END_GET
+        END_PROPERTY
+
+        PROPERTY withoutType
+            GET END_GET
+        END_PROPERTY
+    END_FUNCTION_BLOCK
+    ";
+    let (_, diagnostics) = parse_buffered(src);
+
+    assert_snapshot!(diagnostics);
+}
@@ -0,0 +1,105 @@
+use insta::{assert_debug_snapshot, assert_snapshot};
+use plc_ast::ast::{PouType, Sfc};
+
+use crate::test_utils::tests::{parse, parse_buffered};
+
+/// returns the steps with their action associations and the transitions of the given chart
+fn describe(src: &str, sfc: &Sfc) -> Vec<String> {
+    let mut description = vec![];
+    for step in &sfc.steps {
+        let actions = step
+            .actions
+            .iter()
+            .map(|it| match &it.duration {
+                Some(duration) => {
+                    format!(
+                        "{}({}, {})",
+                        it.action,
+                        it.qualifier,
+                        &src[duration.get_location().to_range().unwrap()]
+                    )
+                }
+                None => format!("{}({})", it.action, it.qualifier),
+            })
+            .collect::<Vec<_>>();
+        let kind = if step.initial { "INITIAL_STEP" } else { "STEP" };
+        description.push(format!("{kind} {} : {}", step.name, actions.join(", ")));
+    }
+    for transition in &sfc.transitions {
+        let names =
+            |steps: &[(String, _)]| steps.iter().map(|(it, _)| it.as_str()).collect::<Vec<_>>().join(", ");
+        description.push(format!(
+            "TRANSITION {}FROM ({}) TO ({}) := {}",
+            transition.name.as_ref().map(|it| format!("{it} ")).unwrap_or_default(),
+            names(&transition.from),
+            names(&transition.to),
+            &src[transition.condition.get_location().to_range().unwrap()]
+        ));
+    }
+    description
+}
+
+#[test]
+fn sfc_body_is_parsed() {
+    let src = "
+    PROGRAM mixer
+    VAR
+        start, full : BOOL;
+    END_VAR
+        INITIAL_STEP idle : END_STEP
+        STEP fill : open_valve(); stir(S); END_STEP
+        STEP drain : stir(R); close_valve(L, T#5s); alarm(D, timeout); END_STEP
+        TRANSITION FROM idle TO fill := start; END_TRANSITION
+        TRANSITION filled FROM fill TO (drain, idle) := full AND NOT start; END_TRANSITION
+        TRANSITION FROM (drain) TO idle := drain.T > T#10s; END_TRANSITION
+    END_PROGRAM
+    ";
+    let (unit, diagnostics) = parse(src);
+
+    assert!(diagnostics.is_empty(), "Expected no diagnostics but got {diagnostics:#?}");
+    assert_debug_snapshot!(describe(src, unit.units[0].sfc.as_ref().unwrap()), @r###"
+    [
+        "INITIAL_STEP idle : ",
+        "STEP fill : open_valve(N), stir(S)",
+        "STEP drain : stir(R), close_valve(L, T#5s), alarm(D, timeout)",
+        "TRANSITION FROM (idle) TO (fill) := start",
+        "TRANSITION filled FROM (fill) TO (drain, idle) := full AND NOT start",
+        "TRANSITION FROM (drain) TO (idle) := drain.T > T#10s",
+    ]
+    "###);
+    // the body is created by the lowering
+    assert!(unit.implementations[0].statements.is_empty());
+}
+
+#[test]
+fn actions_can_be_declared_within_an_sfc_body() {
+    let src = "
+    FUNCTION_BLOCK mixer
+        INITIAL_STEP idle : stir(N); END_STEP
+        ACTION stir:
+            x := x + 1;
+        END_ACTION
+        TRANSITION FROM idle TO idle := TRUE; END_TRANSITION
+    END_FUNCTION_BLOCK
+    ";
+    let (unit, diagnostics) = parse(src);
+
+    assert!(diagnostics.is_empty(), "Expected no diagnostics but got {diagnostics:#?}");
+    assert_eq!(unit.units[0].sfc.as_ref().map(|it| (it.steps.len(), it.transitions.len())), Some((1, 1)));
+    let action = unit.implementations.iter().find(|it| it.pou_type == PouType::Action).unwrap();
+    assert_eq!(action.name, "mixer.stir");
+    assert_eq!(action.type_name, "mixer");
+    assert_eq!(action.statements.len(), 1);
+}
+
+#[test]
+fn invalid_action_associations_are_reported() {
+    let src = "
+    PROGRAM mixer
+        INITIAL_STEP idle : open_valve(X); close_valve(L); stir(N, T#1s); END_STEP
+    END_PROGRAM
+    ";
+    let (_, diagnostics) = parse_buffered(src);
+
+    assert_snapshot!(diagnostics);
+}
@@ -107,6 +107,7 @@ fn inline_struct_declaration_can_be_parsed() {
                     },
                 },
             ],
+            base: None,
         },
     },
 }"#;
@@ -143,7 +144,9 @@ fn inline_enum_declaration_can_be_parsed() {
         },
         initializer: None,
         address: None,
+        init_arguments: None,
         location: SourceLocation::internal(),
+        attributes: vec![],
     };
     let expected_ast = format!("{:#?}", &v);
     assert_eq!(ast_string, expected_ast);
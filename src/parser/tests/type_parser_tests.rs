@@ -51,7 +51,9 @@ fn simple_struct_type_can_be_parsed() {
                         },
                         initializer: None,
                         address: None,
+                        init_arguments: None,
                         location: SourceLocation::internal(),
+                        attributes: vec![],
                     },
                     Variable {
                         name: "Two".to_string(),
@@ -61,7 +63,9 @@ fn simple_struct_type_can_be_parsed() {
                         },
                         initializer: None,
                         address: None,
+                        init_arguments: None,
                         location: SourceLocation::internal(),
+                        attributes: vec![],
                     },
                     Variable {
                         name: "Three".to_string(),
@@ -71,13 +75,17 @@ fn simple_struct_type_can_be_parsed() {
                         },
                         initializer: None,
                         address: None,
+                        init_arguments: None,
                         location: SourceLocation::internal(),
+                        attributes: vec![],
                     },
                 ),
+                base: None,
             },
             initializer: None,
             location: SourceLocation::internal(),
             scope: None,
+            attributes: vec![],
         }
     );
     assert_eq!(ast_string, expected_ast);
@@ -163,6 +171,7 @@ fn type_alias_can_be_parsed() {
             initializer: None,
             location: SourceLocation::internal(),
             scope: None,
+            attributes: vec![],
         }
     );
 
@@ -233,6 +242,39 @@ fn struct_with_inline_array_can_be_parsed() {
     assert_debug_snapshot!(result.user_types[0]);
 }
 
+#[test]
+fn union_type_can_be_parsed() {
+    let (result, diagnostics) = parse(
+        r#"
+        TYPE SampleUnion :
+            UNION
+                One : DINT;
+                Two : ARRAY[0..3] OF BYTE;
+            END_UNION;
+        END_TYPE
+        "#,
+    );
+
+    assert!(diagnostics.is_empty(), "{diagnostics:#?}");
+    assert_debug_snapshot!(result.user_types[0]);
+}
+
+#[test]
+fn struct_extending_another_struct_can_be_parsed() {
+    let (result, diagnostics) = parse(
+        r#"
+        TYPE SampleStruct :
+            STRUCT EXTENDS BaseStruct
+                One : DINT;
+            END_STRUCT
+        END_TYPE
+        "#,
+    );
+
+    assert!(diagnostics.is_empty(), "{diagnostics:#?}");
+    assert_debug_snapshot!(result.user_types[0]);
+}
+
 #[test]
 fn pointer_type_test() {
     let (result, _) = parse(
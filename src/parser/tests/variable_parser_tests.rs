@@ -1,4 +1,6 @@
-use plc_ast::ast::{LinkageType, VariableBlock};
+use plc_ast::ast::{AccessMode, DataType, DataTypeDeclaration, LinkageType, VariableBlock};
+use plc_diagnostics::diagnostics::Diagnostic;
+use plc_source::source_location::SourceLocationFactory;
 
 use crate::test_utils::tests::parse;
 
@@ -402,10 +404,13 @@ fn var_config_test() {
                 },
             },
         ],
+        var_access: [],
         units: [],
         implementations: [],
         interfaces: [],
         user_types: [],
+        usings: [],
+        configurations: [],
         file_name: "test.st",
     }
     "###);
@@ -424,6 +429,80 @@ fn var_config_location() {
     assert_eq!("main.instance.foo", &src[result.var_config[0].location.to_range().unwrap()]);
 }
 
+#[test]
+fn var_access_test() {
+    let src = "
+    VAR_ACCESS
+        speed : prog1.fb.speed : REAL READ_ONLY;
+        limit : prog1.limits[2] : INT read_write;
+        count : counter : DINT;
+    END_VAR
+    ";
+    let (result, diag) = parse(src);
+
+    assert_eq!(diag, vec![]);
+    let access_paths = result
+        .var_access
+        .iter()
+        .map(|it| {
+            (
+                it.name.as_str(),
+                &src[it.reference.get_location().to_range().unwrap()],
+                it.data_type.get_name().unwrap(),
+                it.access,
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        access_paths,
+        vec![
+            ("speed", "prog1.fb.speed", "REAL", AccessMode::ReadOnly),
+            ("limit", "prog1.limits[2]", "INT", AccessMode::ReadWrite),
+            ("count", "counter", "DINT", AccessMode::ReadOnly),
+        ]
+    );
+    assert_eq!(
+        "speed : prog1.fb.speed : REAL READ_ONLY",
+        &src[result.var_access[0].location.to_range().unwrap()]
+    );
+}
+
+#[test]
+fn var_access_in_configuration() {
+    let src = "
+    CONFIGURATION plant
+        VAR_ACCESS
+            speed : prog1.speed : REAL;
+        END_VAR
+    END_CONFIGURATION
+    ";
+    let (result, diag) = parse(src);
+
+    assert_eq!(diag, vec![]);
+    assert_eq!(result.var_access.len(), 1);
+    assert_eq!(result.var_access[0].name, "speed");
+}
+
+#[test]
+fn var_access_with_unknown_access_direction() {
+    let src = "
+    VAR_ACCESS
+        speed : prog1.speed : REAL WRITE_ONLY;
+    END_VAR
+    ";
+    let (result, diag) = parse(src);
+
+    assert_eq!(
+        diag,
+        vec![Diagnostic::unexpected_token_found(
+            "READ_ONLY or READ_WRITE",
+            "WRITE_ONLY",
+            SourceLocationFactory::internal(src).create_range(51..61)
+        )]
+    );
+    assert_eq!(result.var_access[0].access, AccessMode::ReadOnly);
+}
+
 #[test]
 fn var_external() {
     let src = r#"
@@ -470,6 +549,7 @@ fn var_external() {
             },
         ],
         var_config: [],
+        var_access: [],
         units: [
             POU {
                 name: "foo",
@@ -545,6 +625,8 @@ fn var_external() {
         ],
         interfaces: [],
         user_types: [],
+        usings: [],
+        configurations: [],
         file_name: "test.st",
     }
     "###);
@@ -596,6 +678,7 @@ fn var_external_constant() {
             },
         ],
         var_config: [],
+        var_access: [],
         units: [
             POU {
                 name: "foo",
@@ -671,7 +754,191 @@ fn var_external_constant() {
         ],
         interfaces: [],
         user_types: [],
+        usings: [],
+        configurations: [],
         file_name: "test.st",
     }
     "###);
 }
+
+#[test]
+fn retain_and_persistent_blocks_can_be_parsed() {
+    let src = "
+    VAR_GLOBAL RETAIN a : INT; END_VAR
+    VAR_GLOBAL PERSISTENT b : INT; END_VAR
+    VAR_GLOBAL PERSISTENT RETAIN c : INT; END_VAR
+    VAR_GLOBAL RETAIN PERSISTENT d : INT; END_VAR
+    VAR_GLOBAL NON_RETAIN e : INT; END_VAR
+    ";
+    let (result, diag) = parse(src);
+
+    assert_eq!(diag, vec![]);
+    let retention = result.global_vars.iter().map(|it| (it.retain, it.persistent)).collect::<Vec<_>>();
+    assert_eq!(retention, vec![(true, false), (false, true), (true, true), (true, true), (false, false)]);
+}
+
+#[test]
+fn instances_can_be_declared_with_init_arguments() {
+    let src = "
+    VAR_GLOBAL
+        a : axis(nAxis := 3, speed := 1.5);
+        b, c : axis(4);
+        d : INT(0..10);
+        e : INT(red, green);
+    END_VAR
+    ";
+    let (result, diag) = parse(src);
+
+    assert_eq!(diag, vec![]);
+    let variables = &result.global_vars[0].variables;
+    insta::assert_debug_snapshot!(variables.iter().map(|it| (&it.data_type_declaration, &it.init_arguments)).collect::<Vec<_>>(), @r###"
+    [
+        (
+            DataTypeReference {
+                referenced_type: "axis",
+            },
+            Some(
+                ExpressionList {
+                    expressions: [
+                        Assignment {
+                            left: ReferenceExpr {
+                                kind: Member(
+                                    Identifier {
+                                        name: "nAxis",
+                                    },
+                                ),
+                                base: None,
+                            },
+                            right: LiteralInteger {
+                                value: 3,
+                            },
+                        },
+                        Assignment {
+                            left: ReferenceExpr {
+                                kind: Member(
+                                    Identifier {
+                                        name: "speed",
+                                    },
+                                ),
+                                base: None,
+                            },
+                            right: LiteralReal {
+                                value: "1.5",
+                            },
+                        },
+                    ],
+                },
+            ),
+        ),
+        (
+            DataTypeReference {
+                referenced_type: "axis",
+            },
+            Some(
+                LiteralInteger {
+                    value: 4,
+                },
+            ),
+        ),
+        (
+            DataTypeReference {
+                referenced_type: "axis",
+            },
+            Some(
+                LiteralInteger {
+                    value: 4,
+                },
+            ),
+        ),
+        (
+            DataTypeDefinition {
+                data_type: SubRangeType {
+                    name: None,
+                    referenced_type: "INT",
+                    bounds: Some(
+                        RangeStatement {
+                            start: LiteralInteger {
+                                value: 0,
+                            },
+                            end: LiteralInteger {
+                                value: 10,
+                            },
+                        },
+                    ),
+                },
+            },
+            None,
+        ),
+        (
+            DataTypeDefinition {
+                data_type: EnumType {
+                    name: None,
+                    numeric_type: "INT",
+                    elements: ExpressionList {
+                        expressions: [
+                            ReferenceExpr {
+                                kind: Member(
+                                    Identifier {
+                                        name: "red",
+                                    },
+                                ),
+                                base: None,
+                            },
+                            ReferenceExpr {
+                                kind: Member(
+                                    Identifier {
+                                        name: "green",
+                                    },
+                                ),
+                                base: None,
+                            },
+                        ],
+                    },
+                },
+            },
+            None,
+        ),
+    ]
+    "###);
+}
+
+#[test]
+fn instances_of_generic_pous_can_be_declared_with_type_arguments() {
+    let src = "
+    VAR_GLOBAL
+        a : FB_Fifo<REAL>;
+        b : lib.FB_Map<STRING, lib.FB_Fifo<INT>>;
+        c : REF_TO FB_Fifo<DINT> := REF(d);
+        e : FB_Fifo<1>;
+    END_VAR
+    ";
+    let (result, diag) = parse(src);
+
+    let types = result.global_vars[0]
+        .variables
+        .iter()
+        .map(|it| match &it.data_type_declaration {
+            DataTypeDeclaration::DataTypeDefinition {
+                data_type: DataType::PointerType { referenced_type, .. },
+                ..
+            } => referenced_type.get_name(),
+            declaration => declaration.get_name(),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        types,
+        vec![
+            Some("FB_Fifo<REAL>"),
+            Some("lib.FB_Map<STRING,lib.FB_Fifo<INT>>"),
+            Some("FB_Fifo<DINT>"),
+            Some("FB_Fifo<>")
+        ]
+    );
+    assert_eq!(
+        diag.iter().map(|it| it.get_message()).collect::<Vec<_>>(),
+        vec![
+            "Unexpected token: expected Type but found 1",
+            "Unexpected token: expected OperatorGreater but found '1'"
+        ]
+    );
+}
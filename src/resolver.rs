@@ -20,13 +20,19 @@ use plc_ast::{
     try_from,
 };
 use plc_source::source_location::SourceLocation;
-use plc_util::convention::internal_type_name;
+use plc_util::convention::{internal_type_name, RAISE_FUNCTION_NAME};
 
 use crate::index::{FxIndexMap, FxIndexSet};
 use crate::typesystem::VOID_INTERNAL_NAME;
 use crate::{
     builtins::{self, BuiltIn},
-    index::{ArgumentType, Index, PouIndexEntry, VariableIndexEntry, VariableType},
+    codegen::generators::{
+        retain_generator::{collect_retained_variables, declares_retained_variables},
+        type_info_generator::{get_type_info_name, TYPE_INFO_TYPE, TYPE_MEMBER_TYPE},
+    },
+    expression_path::ExpressionPathElement,
+    index::{ArgumentType, Index, PouIndexEntry, PropertyIndexEntry, VariableIndexEntry, VariableType},
+    lowering::enums::is_enum_conversion,
     typesystem::{
         self, get_bigger_type, DataTypeInformation, InternalType, StringEncoding, StructSource, BOOL_TYPE,
         BYTE_TYPE, DATE_AND_TIME_TYPE, DATE_TYPE, DINT_TYPE, DWORD_TYPE, LINT_TYPE, LREAL_TYPE, LWORD_TYPE,
@@ -162,6 +168,15 @@ impl TypeAnnotator<'_> {
             StatementAnnotation::Function { return_type, qualified_name, call_name, .. } => {
                 let name = call_name.as_ref().unwrap_or(qualified_name);
                 self.dependencies.insert(Dependency::Call(name.to_string()));
+                // parsing an enum raises an exception if the string is not the name of an element
+                if self.index.find_pou(name).is_some_and(is_enum_conversion)
+                    && self
+                        .index
+                        .find_effective_type_by_name(return_type)
+                        .is_some_and(typesystem::DataType::is_enum)
+                {
+                    self.dependencies.insert(Dependency::Call(RAISE_FUNCTION_NAME.to_string()));
+                }
                 self.dependencies.extend(self.get_datatype_dependencies(name, FxIndexSet::default()));
                 self.dependencies.extend(self.get_datatype_dependencies(return_type, FxIndexSet::default()));
             }
@@ -203,6 +218,20 @@ impl TypeAnnotator<'_> {
         self.annotation_map.annotate(s, annotation);
     }
 
+    /// Registers a call to the given POU which is not part of the AST, e.g. a call generated by a builtin
+    pub(crate) fn add_call_dependency(&mut self, name: &str) {
+        self.dependencies.insert(Dependency::Call(name.to_string()));
+        self.dependencies.extend(self.get_datatype_dependencies(name, FxIndexSet::default()));
+    }
+
+    /// Registers a reference to the type information table of the given type, e.g. by `__TYPEINFO`
+    pub(crate) fn add_type_info_dependency(&mut self, type_name: &str) {
+        self.dependencies.insert(Dependency::Variable(get_type_info_name(type_name)));
+        for name in [type_name, TYPE_INFO_TYPE, TYPE_MEMBER_TYPE] {
+            self.dependencies.extend(self.get_datatype_dependencies(name, FxIndexSet::default()));
+        }
+    }
+
     fn visit_compare_statement(&mut self, ctx: &VisitorContext, statement: &AstNode) {
         let AstStatement::BinaryExpression(BinaryExpression { operator, left, right }) = statement.get_stmt()
         else {
@@ -459,6 +488,13 @@ pub enum StatementAnnotation {
     Label {
         name: String,
     },
+    /// a reference to a property, which will be lowered into a call to one of its accessors
+    Property {
+        /// the name of the property's type (e.g. `"INT"`)
+        resulting_type: String,
+        /// the fully qualified name of the property (e.g. `"MyFB.foo"`)
+        qualified_name: String,
+    },
     #[default]
     None,
 }
@@ -595,6 +631,7 @@ pub trait AnnotationMap: ToAny {
                 .and_then(|it| self.get_type_name_for_annotation(it)),
             StatementAnnotation::Program { qualified_name } => Some(qualified_name.as_str()),
             StatementAnnotation::Type { type_name } => Some(type_name),
+            StatementAnnotation::Property { resulting_type, .. } => Some(resulting_type.as_str()),
             StatementAnnotation::Function { .. }
             | StatementAnnotation::Label { .. }
             | StatementAnnotation::None => None,
@@ -845,7 +882,9 @@ impl<'i> TypeAnnotator<'i> {
             visitor.visit_variable(ctx, global_variable);
         }
 
-        for pou in &unit.units {
+        // the conversions of an enum are only generated by the units calling them
+        let is_conversion = |name: &str| index.find_pou(name).is_some_and(is_enum_conversion);
+        for pou in unit.units.iter().filter(|it| !is_conversion(&it.name)) {
             visitor.visit_pou(ctx, pou);
         }
 
@@ -859,7 +898,7 @@ impl<'i> TypeAnnotator<'i> {
         }
 
         let body_ctx = ctx.enter_body();
-        for i in &unit.implementations {
+        for i in unit.implementations.iter().filter(|it| !is_conversion(&it.name)) {
             visitor.dependencies.extend(visitor.get_datatype_dependencies(&i.name, FxIndexSet::default()));
             i.statements.iter().for_each(|s| visitor.visit_statement(&body_ctx.with_pou(i.name.as_str()), s));
         }
@@ -868,19 +907,75 @@ impl<'i> TypeAnnotator<'i> {
             visitor.visit_statement(ctx, &config_variable.reference);
         }
 
+        for access_variable in &unit.var_access {
+            visitor.visit_statement(ctx, &access_variable.reference);
+        }
+
+        // the task table references the configured programs and the variables triggering their tasks
+        for resource in unit.configurations.iter().flat_map(|it| it.resources.iter()) {
+            for task in &resource.tasks {
+                for property in [&task.interval, &task.single, &task.priority].into_iter().flatten() {
+                    visitor.visit_statement(ctx, property);
+                }
+                if let Some(single) = task.single.as_ref().and_then(AstNode::get_flat_reference_name) {
+                    visitor.dependencies.insert(Dependency::Variable(single.to_string()));
+                }
+            }
+
+            for program in &resource.programs {
+                visitor
+                    .dependencies
+                    .extend(visitor.get_datatype_dependencies(&program.program, FxIndexSet::default()));
+            }
+        }
+
+        // the retain image references the global variables and program instances holding retained variables
+        if declares_retained_variables(unit) {
+            for variable in collect_retained_variables(index) {
+                let Some(ExpressionPathElement::Name(root)) = variable.path.get_segments().first() else {
+                    continue;
+                };
+                let type_name = match index.find_global_variable(root) {
+                    Some(global) => {
+                        visitor.dependencies.insert(Dependency::Variable(global.get_name().to_string()));
+                        global.get_type_name()
+                    }
+                    None => root,
+                };
+                visitor
+                    .dependencies
+                    .extend(visitor.get_datatype_dependencies(type_name, FxIndexSet::default()));
+            }
+        }
+
         // enum initializers may have been introduced by the visitor (indexer)
-        // so we should try to resolve and type-annotate them here as well
-        for enum_element in index.get_all_enum_variants().iter().filter(|it| it.is_in_unit(&unit.file_name)) {
+        // so we should try to resolve and type-annotate them here as well, builtin variants are annotated
+        // in every unit since none of the units declares them
+        for enum_element in index
+            .get_all_enum_variants()
+            .iter()
+            .filter(|it| it.is_in_unit(&unit.file_name) || it.is_in_unit(builtins::BUILTIN_UNIT_NAME))
+        {
+            let is_builtin = !enum_element.is_in_unit(&unit.file_name);
             //Add to dependency map
-            visitor.dependencies.insert(Dependency::Variable(enum_element.get_qualified_name().to_string()));
+            if !is_builtin {
+                visitor
+                    .dependencies
+                    .insert(Dependency::Variable(enum_element.get_qualified_name().to_string()));
+            }
             if let Some((Some(statement), scope)) =
                 enum_element.initial_value.map(|i| index.get_const_expressions().find_expression(&i))
             {
+                let dependencies = is_builtin.then(|| visitor.dependencies.clone());
                 if let Some(scope) = scope {
                     visitor.visit_statement(&ctx.with_pou(scope), statement);
                 } else {
                     visitor.visit_statement(ctx, statement);
                 }
+                // the builtin initializers are not part of this unit
+                if let Some(dependencies) = dependencies {
+                    visitor.dependencies = dependencies;
+                }
             }
         }
 
@@ -923,6 +1018,23 @@ impl<'i> TypeAnnotator<'i> {
     fn visit_pou(&mut self, ctx: &VisitorContext, pou: &'i Pou) {
         self.dependencies.insert(Dependency::Datatype(pou.name.clone()));
         //TODO dependency on super class
+        // the dispatch tables of the class hierarchy and of implemented interfaces call the implementations
+        // of their methods
+        let dispatched_methods = self.index.get_virtual_methods(&pou.name).into_iter().chain(
+            self.index
+                .get_implemented_interfaces(&pou.name)
+                .into_iter()
+                .flat_map(|interface| interface.get_methods(self.index)),
+        );
+        for method in dispatched_methods {
+            self.dependencies
+                .extend(self.get_datatype_dependencies(method.get_name(), FxIndexSet::default()));
+            if let Some(implementation) = self.index.find_method(&pou.name, method.get_flat_name()) {
+                self.dependencies.insert(Dependency::Call(implementation.get_name().to_string()));
+                self.dependencies
+                    .extend(self.get_datatype_dependencies(implementation.get_name(), FxIndexSet::default()));
+            }
+        }
         let pou_ctx = ctx.with_pou(pou.name.as_str());
         for block in &pou.variable_blocks {
             for variable in &block.variables {
@@ -1276,7 +1388,8 @@ impl<'i> TypeAnnotator<'i> {
             self.dependencies.insert(Dependency::Datatype(name.to_string()));
         }
         match data_type {
-            DataType::StructType { name: Some(name), variables, .. } => {
+            DataType::StructType { name: Some(name), variables, .. }
+            | DataType::UnionType { name: Some(name), variables, .. } => {
                 let ctx = ctx.with_qualifier(name.clone());
                 variables.iter().for_each(|v| self.visit_variable(&ctx, v))
             }
@@ -1372,6 +1485,17 @@ impl<'i> TypeAnnotator<'i> {
                         });
                         stmt.else_block.iter().for_each(|s| self.visit_statement(ctx, s));
                     }
+                    AstControlStatement::Try(stmt) => {
+                        stmt.body.iter().for_each(|s| self.visit_statement(ctx, s));
+                        if let Some(catch_block) = &stmt.catch_block {
+                            if let Some(exception) = &catch_block.exception {
+                                self.visit_statement(ctx, exception);
+                            }
+                            catch_block.body.iter().for_each(|s| self.visit_statement(ctx, s));
+                        }
+                        stmt.finally_block.iter().for_each(|s| self.visit_statement(ctx, s));
+                        self.add_call_dependency(RAISE_FUNCTION_NAME);
+                    }
                 }
             }
 
@@ -1556,6 +1680,19 @@ impl<'i> TypeAnnotator<'i> {
             AstStatement::ReferenceExpr(data, ..) => {
                 self.visit_reference_expr(&data.access, data.base.as_deref(), statement, ctx);
             }
+            AstStatement::This | AstStatement::Super => {
+                // THIS points to the current instance, SUPER to the same instance viewed as its base class
+                let pou = ctx.pou.and_then(|it| self.index.find_instance_pou(it));
+                let instance_type = match statement.get_stmt() {
+                    AstStatement::This => pou.map(PouIndexEntry::get_name),
+                    _ => pou.and_then(PouIndexEntry::get_super_class),
+                };
+                if let Some(instance_type) = instance_type {
+                    let pointer_type =
+                        add_pointer_type(&mut self.annotation_map.new_index, instance_type.to_string());
+                    self.annotate(statement, StatementAnnotation::value(pointer_type));
+                }
+            }
             AstStatement::ReturnStatement(ReturnStatement { condition }) => {
                 if let Some(condition) = condition {
                     self.visit_statement(ctx, condition)
@@ -2039,6 +2176,13 @@ fn to_pou_annotation(p: &PouIndexEntry, index: &Index) -> Option<StatementAnnota
     }
 }
 
+fn to_property_annotation(p: &PropertyIndexEntry, index: &Index) -> StatementAnnotation {
+    StatementAnnotation::Property {
+        resulting_type: index.get_effective_type_or_void_by_name(p.get_type_name()).get_name().to_string(),
+        qualified_name: p.qualified_name.clone(),
+    }
+}
+
 fn to_variable_annotation(
     v: &VariableIndexEntry,
     index: &Index,
@@ -2195,17 +2339,37 @@ impl ResolvingStrategy {
             ResolvingStrategy::Variable => {
                 if let Some(qualifier) = qualifier {
                     // look for variable, enum with name "qualifier.name"
+                    // then look for a property with name "qualifier.name"
                     scopes
                         .find_member(index, qualifier, name)
                         .or_else(|| index.find_enum_variant(qualifier, name))
                         .map(|it| to_variable_annotation(it, index, it.is_constant() || ctx.constant))
+                        .or_else(|| {
+                            index.find_property(qualifier, name).map(|it| to_property_annotation(it, index))
+                        })
                 } else {
                     // look for member variable with name "pou.name"
+                    // then try for a property of the pou's container
                     // then try fopr a global variable called "name"
                     ctx.pou
                         .and_then(|pou| scopes.find_member(index, pou, name))
-                        .or_else(|| index.find_global_variable(name))
-                        .map(|g| to_variable_annotation(g, index, g.is_constant()))
+                        .map(|m| to_variable_annotation(m, index, m.is_constant()))
+                        .or_else(|| {
+                            ctx.pou
+                                .and_then(|pou| index.find_pou(pou))
+                                .and_then(|pou| index.find_property(pou.get_container(), name))
+                                .map(|it| to_property_annotation(it, index))
+                        })
+                        .or_else(|| {
+                            // a VAR_EXTERNAL CONSTANT declaration makes the global read-only within the POU
+                            let external_constant = ctx
+                                .pou
+                                .and_then(|pou| index.find_var_external(pou, name))
+                                .is_some_and(VariableIndexEntry::is_constant);
+                            index.find_global_variable(name).map(|g| {
+                                to_variable_annotation(g, index, g.is_constant() || external_constant)
+                            })
+                        })
                 }
             }
             // try to resolve the name as POU/Action/Method
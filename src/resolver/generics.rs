@@ -71,7 +71,8 @@ impl TypeAnnotator<'_> {
     ) {
         if let Some(PouIndexEntry::Function { generics, .. }) = self.index.find_pou(implementation_name) {
             if !generics.is_empty() {
-                let generic_map = &self.derive_generic_types(generics, generics_candidates);
+                let generic_map =
+                    &self.derive_generic_types(implementation_name, generics, generics_candidates);
                 // Annotate the statement with the new function call
                 if let Some(StatementAnnotation::Function { qualified_name, return_type, .. }) =
                     self.annotation_map.get(operator)
@@ -378,9 +379,11 @@ impl TypeAnnotator<'_> {
         (call_name, annotation)
     }
 
-    /// Derives the correct type for the generic call from the list of parameters
+    /// Derives the correct type for the generic call from the list of parameters. An enum is passed as its
+    /// numeric type, unless the function is implemented for the enum itself, e.g. `TO_STRING__Color`
     pub fn derive_generic_types(
         &self,
+        implementation_name: &str,
         generics: &[GenericBinding],
         generics_candidates: FxHashMap<String, Vec<String>>,
     ) -> FxHashMap<String, GenericType> {
@@ -408,6 +411,11 @@ impl TypeAnnotator<'_> {
                                     DataTypeInformation::String {
                                         encoding: StringEncoding::Utf16, ..
                                     } => self.index.find_effective_type_info(WSTRING_TYPE).unwrap_or(it),
+                                    DataTypeInformation::Enum { name, .. }
+                                        if self.is_implemented_for(implementation_name, name) =>
+                                    {
+                                        it
+                                    }
                                     _ => self.index.find_intrinsic_type(it),
                                 }
                             });
@@ -449,6 +457,11 @@ impl TypeAnnotator<'_> {
         }
         generic_map
     }
+
+    /// Returns true if the given generic function has an implementation for the given type
+    fn is_implemented_for(&self, implementation_name: &str, type_name: &str) -> bool {
+        self.index.find_pou(&format!("{implementation_name}__{type_name}")).is_some()
+    }
 }
 
 type GenericNameResolver = fn(&str, &[GenericBinding], &FxHashMap<String, GenericType>) -> String;
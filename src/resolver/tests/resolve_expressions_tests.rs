@@ -2204,6 +2204,7 @@ fn global_enums_type_resolving() {
     let initializer_types = index
         .get_all_enum_variants()
         .iter()
+        .filter(|it| it.get_type_name() != "__EXCEPTION_CODE")
         .map(|it| {
             let const_exp = index
                 .get_const_expressions()
@@ -2228,6 +2229,7 @@ fn global_enums_type_resolving2() {
     let initalizer_types = index
         .get_all_enum_variants()
         .iter()
+        .filter(|it| it.get_type_name() != "__EXCEPTION_CODE")
         .map(|it| {
             let const_exp = index
                 .get_const_expressions()
@@ -2263,6 +2265,7 @@ fn global_lint_enums_type_resolving() {
     let initalizer_types = index
         .get_all_enum_variants()
         .iter()
+        .filter(|it| it.get_type_name() != "__EXCEPTION_CODE")
         .map(|it| {
             let const_exp = index
                 .get_const_expressions()
@@ -5786,3 +5789,135 @@ fn reference_to_alloca_nested_resolved() {
         unreachable!("Must be an assignment");
     }
 }
+
+#[test]
+fn property_references_are_resolved() {
+    let id_provider = IdProvider::default();
+    let (unit, mut index) = index_with_ids(
+        "
+        FUNCTION_BLOCK fb
+            PROPERTY foo : INT
+                GET END_GET
+                SET END_SET
+            END_PROPERTY
+
+            METHOD bar
+                foo;
+            END_METHOD
+
+            foo;
+        END_FUNCTION_BLOCK
+
+        FUNCTION_BLOCK child EXTENDS fb
+            foo;
+        END_FUNCTION_BLOCK
+
+        FUNCTION main
+            VAR
+                instance : fb;
+            END_VAR
+            instance.foo;
+        END_FUNCTION
+        ",
+        id_provider.clone(),
+    );
+    let annotations = annotate_with_ids(&unit, &mut index, id_provider);
+
+    let expected = StatementAnnotation::Property {
+        resulting_type: "INT".to_string(),
+        qualified_name: "fb.foo".to_string(),
+    };
+    for name in ["fb.bar", "fb", "child", "main"] {
+        let implementation = unit.implementations.iter().find(|it| it.name == name).unwrap();
+        assert_eq!(annotations.get(&implementation.statements[0]), Some(&expected), "in {name}");
+        assert_type_and_hint!(&annotations, &index, &implementation.statements[0], INT_TYPE, None);
+    }
+}
+
+#[test]
+fn local_variables_shadow_properties() {
+    let id_provider = IdProvider::default();
+    let (unit, mut index) = index_with_ids(
+        "
+        FUNCTION_BLOCK fb
+            PROPERTY foo : INT
+                GET END_GET
+            END_PROPERTY
+
+            METHOD bar
+                VAR
+                    foo : BOOL;
+                END_VAR
+                foo;
+            END_METHOD
+        END_FUNCTION_BLOCK
+        ",
+        id_provider.clone(),
+    );
+    let annotations = annotate_with_ids(&unit, &mut index, id_provider);
+
+    let implementation = unit.implementations.iter().find(|it| it.name == "fb.bar").unwrap();
+    assert!(matches!(
+        annotations.get(&implementation.statements[0]),
+        Some(StatementAnnotation::Variable { qualified_name, .. }) if qualified_name == "fb.bar.foo"
+    ));
+}
+
+#[test]
+fn this_and_super_are_annotated_as_pointers_to_the_instance() {
+    let id_provider = IdProvider::default();
+    let (unit, mut index) = index_with_ids(
+        "
+        FUNCTION_BLOCK base
+        VAR
+            x : DINT;
+        END_VAR
+            METHOD foo : DINT
+            END_METHOD
+        END_FUNCTION_BLOCK
+
+        FUNCTION_BLOCK derived EXTENDS base
+            METHOD OVERRIDE foo : DINT
+                THIS^.x;
+                SUPER^.x;
+                SUPER^.foo();
+            END_METHOD
+        END_FUNCTION_BLOCK
+        ",
+        id_provider.clone(),
+    );
+    let annotations = annotate_with_ids(&unit, &mut index, id_provider);
+    let statements = &unit.implementations.iter().find(|it| it.name == "derived.foo").unwrap().statements;
+
+    let get_deref = |statement: &AstNode| {
+        let AstStatement::ReferenceExpr(ReferenceExpr { base: Some(deref), .. }) = statement.get_stmt()
+        else {
+            panic!("expected a qualified reference, got {statement:#?}");
+        };
+        let AstStatement::ReferenceExpr(ReferenceExpr {
+            access: ReferenceAccess::Deref,
+            base: Some(pointer),
+        }) = deref.get_stmt()
+        else {
+            panic!("expected a dereferenced pointer, got {deref:#?}");
+        };
+        (deref.as_ref().clone(), pointer.as_ref().clone())
+    };
+
+    // THIS^ refers to the derived instance, SUPER^ to its base
+    for (statement, expected) in [(&statements[0], "derived"), (&statements[1], "base")] {
+        let (deref, pointer) = get_deref(statement);
+        let pointer_type = annotations.get_type_or_void(&pointer, &index).get_type_information();
+        assert!(
+            matches!(pointer_type, DataTypeInformation::Pointer { inner_type_name, .. } if inner_type_name == expected),
+            "unexpected type {pointer_type:#?}"
+        );
+        assert_type_and_hint!(&annotations, &index, &deref, expected, None);
+        assert_type_and_hint!(&annotations, &index, statement, DINT_TYPE, None);
+    }
+
+    let AstStatement::CallStatement(CallStatement { operator, .. }) = statements[2].get_stmt() else {
+        panic!("expected a call, got {:#?}", statements[2]);
+    };
+    assert_eq!(annotations.get_call_name(operator), Some("base.foo"));
+}
@@ -1228,3 +1228,48 @@ fn generic_function_with_aggregate_return() {
         unreachable!("This should always be a call statement.")
     }
 }
+
+#[test]
+fn generic_call_with_enum_argument_resolves_to_the_enums_implementation() {
+    let id_provider = IdProvider::default();
+    let (unit, mut index) = index_with_ids(
+        "
+        TYPE Color : (Red, Green, Blue); END_TYPE
+
+        FUNCTION TO_STRING<T: ANY> : STRING
+        VAR_INPUT   in : T; END_VAR
+        END_FUNCTION
+
+        FUNCTION myFunc<T: ANY_INT> : T
+        VAR_INPUT   in : T; END_VAR
+        END_FUNCTION
+
+        PROGRAM PRG
+            VAR
+                c : Color;
+            END_VAR
+            TO_STRING(c);
+            myFunc(c);
+        END_PROGRAM",
+        id_provider.clone(),
+    );
+
+    let annotations = annotate_with_ids(&unit, &mut index, id_provider);
+    let call_names = unit
+        .implementations
+        .iter()
+        .find(|it| it.name == "PRG")
+        .unwrap()
+        .statements
+        .iter()
+        .map(|it| match it.get_stmt() {
+            AstStatement::CallStatement(CallStatement { operator, .. }) => {
+                annotations.get_call_name(operator)
+            }
+            _ => unreachable!("This should always be a call statement."),
+        })
+        .collect::<Vec<_>>();
+
+    // the conversions of the enum implement the generic conversion, other generics take its numeric type
+    assert_eq!(call_names, [Some("TO_STRING__Color"), Some("myFunc__DINT")]);
+}
@@ -188,7 +188,10 @@ fn multiple_units_aggregate_resolved_recursive() {
     assert!(dependencies.contains(&Dependency::Datatype("__myStruct_z".into())));
     assert!(dependencies.contains(&Dependency::Datatype("INT".into())));
     assert!(dependencies.contains(&Dependency::Datatype("DINT".into())));
-    assert_eq!(dependencies.len(), 6);
+    // the `__vtable` member of `fb`
+    assert!(dependencies.contains(&Dependency::Datatype("__BYTE_POINTER".into())));
+    assert!(dependencies.contains(&Dependency::Datatype("BYTE".into())));
+    assert_eq!(dependencies.len(), 8);
 }
 
 #[test]
@@ -477,7 +480,10 @@ fn function_block_params_dependency_resolution() {
     assert!(dependencies.contains(&Dependency::Datatype("__auto_pointer_to_REAL".into())));
     assert!(dependencies.contains(&Dependency::Datatype("LREAL".into())));
     assert!(dependencies.contains(&Dependency::Datatype("WORD".into())));
-    assert_eq!(dependencies.len(), 9);
+    // the `__vtable` member of `fb`
+    assert!(dependencies.contains(&Dependency::Datatype("__BYTE_POINTER".into())));
+    assert!(dependencies.contains(&Dependency::Datatype("BYTE".into())));
+    assert_eq!(dependencies.len(), 11);
 }
 
 #[test]
@@ -571,7 +577,10 @@ fn action_dependency_resolution_with_function_block() {
     let (_, dependencies, _) = TypeAnnotator::visit_unit(&index, &unit, id_provider);
     assert!(dependencies.contains(&Dependency::Datatype("prog".into())));
     assert!(dependencies.contains(&Dependency::Datatype("fb".into())));
-    assert_eq!(dependencies.len(), 2);
+    // the `__vtable` member of `fb`
+    assert!(dependencies.contains(&Dependency::Datatype("__BYTE_POINTER".into())));
+    assert!(dependencies.contains(&Dependency::Datatype("BYTE".into())));
+    assert_eq!(dependencies.len(), 4);
 }
 
 #[test]
@@ -0,0 +1,117 @@
+use plc_diagnostics::diagnostics::Diagnostic;
+use serde::Serialize;
+
+use crate::{index::Index, ConfigFormat};
+
+/// The tasks declared in the project's `CONFIGURATION`, together with the program instances they execute
+#[derive(Debug, Serialize)]
+pub struct TaskConfiguration {
+    #[serde(rename = "TaskConfiguration")]
+    tasks: Vec<Task>,
+}
+
+#[derive(Debug, Serialize)]
+struct Task {
+    name: String,
+    resource: String,
+    /// The cycle time in nanoseconds, if the task is periodic
+    #[serde(skip_serializing_if = "Option::is_none")]
+    interval_ns: Option<i64>,
+    /// The variable triggering the task, if it is event-driven
+    #[serde(skip_serializing_if = "Option::is_none")]
+    single: Option<String>,
+    priority: u16,
+    programs: Vec<ProgramInstance>,
+}
+
+#[derive(Debug, Serialize)]
+struct ProgramInstance {
+    instance: String,
+    program: String,
+}
+
+/// Retrieves the tasks and their program instances from the configuration in the index
+pub fn collect_task_configuration(index: &Index) -> TaskConfiguration {
+    let tasks = index
+        .get_tasks()
+        .values()
+        .map(|task| Task {
+            name: task.name.clone(),
+            resource: task
+                .qualified_name
+                .rsplit_once('.')
+                .map(|(resource, _)| resource)
+                .unwrap_or_default()
+                .into(),
+            interval_ns: task.get_interval_value(index),
+            single: task.single.clone(),
+            priority: task.get_priority_value(index).unwrap_or_default(),
+            programs: index
+                .get_configured_programs()
+                .values()
+                .filter(|program| {
+                    program.task.as_deref().is_some_and(|it| it.eq_ignore_ascii_case(&task.qualified_name))
+                })
+                .map(|program| ProgramInstance {
+                    instance: program.name.clone(),
+                    program: program.program.clone(),
+                })
+                .collect(),
+        })
+        .collect();
+
+    TaskConfiguration { tasks }
+}
+
+pub fn generate_task_configuration(
+    config: &TaskConfiguration,
+    format: ConfigFormat,
+) -> Result<String, Diagnostic> {
+    match format {
+        ConfigFormat::JSON => serde_json::to_string_pretty(&config).map_err(|e| {
+            Diagnostic::new(e.to_string()).with_error_code("E002").with_internal_error(e.into())
+        }),
+        ConfigFormat::TOML => toml::ser::to_string_pretty(&config).map_err(|e| {
+            Diagnostic::new(e.to_string()).with_error_code("E002").with_internal_error(e.into())
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        resolver::const_evaluator::evaluate_constants,
+        task_configuration::{collect_task_configuration, generate_task_configuration},
+        test_utils::tests::index,
+        ConfigFormat,
+    };
+
+    #[test]
+    fn task_configuration_printed() {
+        let (_, index) = index(
+            "
+        PROGRAM MainPrg END_PROGRAM
+        PROGRAM LogPrg END_PROGRAM
+        PROGRAM AlarmPrg END_PROGRAM
+
+        CONFIGURATION Plant
+            VAR_GLOBAL
+                alarm : BOOL;
+            END_VAR
+            RESOURCE Cpu ON PLC
+                TASK fast (INTERVAL := T#10ms, PRIORITY := 1);
+                TASK onAlarm (SINGLE := alarm, PRIORITY := 0);
+                PROGRAM main WITH fast : MainPrg;
+                PROGRAM log WITH fast : LogPrg;
+                PROGRAM alarms WITH onAlarm : AlarmPrg;
+            END_RESOURCE
+        END_CONFIGURATION",
+        );
+        let (index, _) = evaluate_constants(index);
+        let config = collect_task_configuration(&index);
+        let res = generate_task_configuration(&config, ConfigFormat::JSON).unwrap();
+        insta::assert_snapshot!(res);
+        let res = generate_task_configuration(&config, ConfigFormat::TOML).unwrap();
+        insta::assert_snapshot!(res);
+    }
+}
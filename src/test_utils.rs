@@ -18,7 +18,11 @@ pub mod tests {
         codegen::{CodegenContext, GeneratedModule},
         index::{self, FxIndexSet, Index},
         lexer,
-        lowering::{calls::AggregateTypeLowerer, InitVisitor},
+        lowering::{
+            calls::AggregateTypeLowerer, enums::EnumConversionLowerer, generics::GenericsLowerer,
+            inheritance::InheritanceLowerer, namespaces::NamespaceLowerer, property::PropertyLowerer,
+            sfc::SfcLowerer, InitVisitor,
+        },
         parser,
         resolver::{
             const_evaluator::evaluate_constants, AnnotationMapImpl, AstAnnotations, Dependency,
@@ -83,6 +87,12 @@ pub mod tests {
             source_path,
         );
 
+        GenericsLowerer::new(id_provider.clone()).lower_generics(std::slice::from_mut(&mut unit));
+        SfcLowerer::new(id_provider.clone()).lower_sfcs(std::slice::from_mut(&mut unit));
+        NamespaceLowerer.lower_namespaces(std::slice::from_mut(&mut unit));
+        InheritanceLowerer.lower_inheritance(std::slice::from_mut(&mut unit));
+        PropertyLowerer::new(id_provider.clone()).lower_properties(&mut unit);
+        EnumConversionLowerer.lower_enums(std::slice::from_mut(&mut unit));
         pre_process(&mut unit, id_provider);
         index.import(index::indexer::index(&unit));
         (unit, index, diagnostics)
@@ -177,6 +187,22 @@ pub mod tests {
             })
             .collect::<Vec<_>>();
 
+        if !full_index.get_properties().is_empty() {
+            let mut property_lowerer = PropertyLowerer::new(id_provider.clone());
+            property_lowerer.index.replace(full_index);
+            property_lowerer.annotation.replace(Box::new(all_annotations));
+            units.iter_mut().for_each(|unit| {
+                property_lowerer.lower_references(unit);
+            });
+            full_index = property_lowerer.index.take().unwrap();
+            all_annotations = AnnotationMapImpl::default();
+            units.iter().for_each(|unit| {
+                let (mut annotations, ..) = TypeAnnotator::visit_unit(&full_index, unit, id_provider.clone());
+                full_index.import(std::mem::take(&mut annotations.new_index));
+                all_annotations.import(annotations);
+            });
+        }
+
         let mut aggregate_lowerer = AggregateTypeLowerer::new(id_provider.clone());
         aggregate_lowerer.index.replace(full_index);
         aggregate_lowerer.annotation.replace(Box::new(all_annotations));
@@ -239,12 +265,17 @@ pub mod tests {
             src,
             DebugLevel::None,
             OnlineChange::Enabled { file_name: "test".into(), format: crate::ConfigFormat::JSON },
+            false,
         )
         .unwrap()
     }
 
+    pub fn codegen_with_runtime_checks(src: &str) -> String {
+        codegen_debug_without_unwrap_oc(src, DebugLevel::None, OnlineChange::Disabled, true).unwrap()
+    }
+
     pub fn codegen_debug_without_unwrap(src: &str, debug_level: DebugLevel) -> Result<String, String> {
-        codegen_debug_without_unwrap_oc(src, debug_level, OnlineChange::Disabled)
+        codegen_debug_without_unwrap_oc(src, debug_level, OnlineChange::Disabled, false)
     }
 
     /// Returns either a string or an error, in addition it always returns
@@ -255,6 +286,7 @@ pub mod tests {
         src: &str,
         debug_level: DebugLevel,
         online_change: OnlineChange,
+        runtime_checks: bool,
     ) -> Result<String, String> {
         let mut reporter = Diagnostician::buffered();
         reporter.register_file("<internal>".to_string(), src.to_string());
@@ -281,6 +313,7 @@ pub mod tests {
                     crate::OptimizationLevel::None,
                     debug_level,
                     online_change.clone(),
+                    runtime_checks,
                 );
                 let llvm_index = code_generator
                     .generate_llvm_index(
@@ -368,6 +401,7 @@ pub mod tests {
                     crate::OptimizationLevel::None,
                     debug_level,
                     crate::OnlineChange::Disabled,
+                    false,
                 );
                 let got_layout = Mutex::new(HashMap::default());
 
@@ -553,30 +553,34 @@ fn generating_init_functions() {
     ";
 
     let res = codegen(src);
-    assert_snapshot!(res, @r###"
+    assert_snapshot!(res, @r#"
     ; ModuleID = '<internal>'
     source_filename = "<internal>"
 
     %myStruct = type { i8, i8 }
-    %foo = type { [81 x i8]* }
-    %bar = type { %foo }
+    %foo = type { i8*, [81 x i8]* }
+    %bar = type { i8*, %foo }
     %baz = type { %bar }
 
+    @__vtable_foo = unnamed_addr constant [0 x i8*] zeroinitializer
+    @__vtable_bar = unnamed_addr constant [0 x i8*] zeroinitializer
     @s = global %myStruct zeroinitializer
     @__myStruct__init = unnamed_addr constant %myStruct zeroinitializer
-    @__foo__init = unnamed_addr constant %foo zeroinitializer
-    @__bar__init = unnamed_addr constant %bar zeroinitializer
-    @baz_instance = global %baz zeroinitializer
+    @__foo__init = unnamed_addr constant %foo { i8* bitcast ([0 x i8*]* @__vtable_foo to i8*), [81 x i8]* null }
+    @__bar__init = unnamed_addr constant %bar { i8* bitcast ([0 x i8*]* @__vtable_bar to i8*), %foo { i8* bitcast ([0 x i8*]* @__vtable_foo to i8*), [81 x i8]* null } }
+    @baz_instance = global %baz { %bar { i8* bitcast ([0 x i8*]* @__vtable_bar to i8*), %foo { i8* bitcast ([0 x i8*]* @__vtable_foo to i8*), [81 x i8]* null } } }
 
     define void @foo(%foo* %0) {
     entry:
-      %ps = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %ps = getelementptr inbounds %foo, %foo* %0, i32 0, i32 1
       ret void
     }
 
     define void @bar(%bar* %0) {
     entry:
-      %fb = getelementptr inbounds %bar, %bar* %0, i32 0, i32 0
+      %__vtable = getelementptr inbounds %bar, %bar* %0, i32 0, i32 0
+      %fb = getelementptr inbounds %bar, %bar* %0, i32 0, i32 1
       ret void
     }
 
@@ -588,11 +592,13 @@ fn generating_init_functions() {
     ; ModuleID = '__initializers'
     source_filename = "__initializers"
 
-    %bar = type { %foo }
-    %foo = type { [81 x i8]* }
+    %bar = type { i8*, %foo }
+    %foo = type { i8*, [81 x i8]* }
     %myStruct = type { i8, i8 }
     %baz = type { %bar }
 
+    @__vtable_bar = external unnamed_addr constant [0 x i8*]
+    @__vtable_foo = external unnamed_addr constant [0 x i8*]
     @__bar__init = external global %bar
     @__foo__init = external global %foo
     @__myStruct__init = external global %myStruct
@@ -604,7 +610,7 @@ fn generating_init_functions() {
       %self = alloca %bar*, align 8
       store %bar* %0, %bar** %self, align 8
       %deref = load %bar*, %bar** %self, align 8
-      %fb = getelementptr inbounds %bar, %bar* %deref, i32 0, i32 0
+      %fb = getelementptr inbounds %bar, %bar* %deref, i32 0, i32 1
       call void @__init_foo(%foo* %fb)
       ret void
     }
@@ -625,7 +631,7 @@ fn generating_init_functions() {
       %self = alloca %foo*, align 8
       store %foo* %0, %foo** %self, align 8
       %deref = load %foo*, %foo** %self, align 8
-      %ps = getelementptr inbounds %foo, %foo* %deref, i32 0, i32 0
+      %ps = getelementptr inbounds %foo, %foo* %deref, i32 0, i32 1
       store [81 x i8]* bitcast (%myStruct* @s to [81 x i8]*), [81 x i8]** %ps, align 8
       ret void
     }
@@ -645,10 +651,12 @@ fn generating_init_functions() {
     source_filename = "__init___testproject"
 
     %baz = type { %bar }
-    %bar = type { %foo }
-    %foo = type { [81 x i8]* }
+    %bar = type { i8*, %foo }
+    %foo = type { i8*, [81 x i8]* }
     %myStruct = type { i8, i8 }
 
+    @__vtable_bar = external unnamed_addr constant [0 x i8*]
+    @__vtable_foo = external unnamed_addr constant [0 x i8*]
     @baz_instance = external global %baz
     @__bar__init = external global %bar
     @__foo__init = external global %foo
@@ -672,7 +680,7 @@ fn generating_init_functions() {
     declare void @foo(%foo*)
 
     declare void @__init_mystruct(%myStruct*)
-    "###);
+    "#);
 }
 
 /// When dealing with local stack-allocated variables (`VAR_TEMP`-blocks (in addition to `VAR` for functions)),
@@ -708,19 +716,21 @@ fn intializing_temporary_variables() {
         ";
 
     let res = codegen(src);
-    assert_snapshot!(res, @r##"
+    assert_snapshot!(res, @r#"
     ; ModuleID = '<internal>'
     source_filename = "<internal>"
 
-    %foo = type { [81 x i8]* }
+    %foo = type { i8*, [81 x i8]* }
 
+    @__vtable_foo = unnamed_addr constant [0 x i8*] zeroinitializer
     @ps = global [81 x i8] zeroinitializer
     @ps2 = global [81 x i8] zeroinitializer
-    @__foo__init = unnamed_addr constant %foo zeroinitializer
+    @__foo__init = unnamed_addr constant %foo { i8* bitcast ([0 x i8*]* @__vtable_foo to i8*), [81 x i8]* null }
 
     define void @foo(%foo* %0) {
     entry:
-      %s = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %s = getelementptr inbounds %foo, %foo* %0, i32 0, i32 1
       %s2 = alloca [81 x i8]*, align 8
       store [81 x i8]* @ps2, [81 x i8]** %s2, align 8
       store [81 x i8]* @ps2, [81 x i8]** %s2, align 8
@@ -755,8 +765,9 @@ fn intializing_temporary_variables() {
     ; ModuleID = '__initializers'
     source_filename = "__initializers"
 
-    %foo = type { [81 x i8]* }
+    %foo = type { i8*, [81 x i8]* }
 
+    @__vtable_foo = external unnamed_addr constant [0 x i8*]
     @__foo__init = external global %foo
     @ps = external global [81 x i8]
 
@@ -765,7 +776,7 @@ fn intializing_temporary_variables() {
       %self = alloca %foo*, align 8
       store %foo* %0, %foo** %self, align 8
       %deref = load %foo*, %foo** %self, align 8
-      %s = getelementptr inbounds %foo, %foo* %deref, i32 0, i32 0
+      %s = getelementptr inbounds %foo, %foo* %deref, i32 0, i32 1
       store [81 x i8]* @ps, [81 x i8]** %s, align 8
       ret void
     }
@@ -780,7 +791,7 @@ fn intializing_temporary_variables() {
     entry:
       ret void
     }
-    "##)
+    "#)
 }
 
 /// Initializing method variables behaves very similar to stack local variables from the previous example.
@@ -806,18 +817,21 @@ fn initializing_method_variables() {
     ; ModuleID = '<internal>'
     source_filename = "<internal>"
 
-    %foo = type {}
+    %foo = type { i8* }
     %foo.bar = type { i32, i32* }
 
-    @__foo__init = unnamed_addr constant %foo zeroinitializer
+    @__vtable_foo = unnamed_addr constant [1 x i8*] [i8* bitcast (void (%foo*, %foo.bar*)* @__vtable_foo.bar to i8*)]
+    @__foo__init = unnamed_addr constant %foo { i8* bitcast ([1 x i8*]* @__vtable_foo to i8*) }
 
     define void @foo(%foo* %0) {
     entry:
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
       ret void
     }
 
     define void @foo.bar(%foo* %0, %foo.bar* %1) {
     entry:
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
       %x = getelementptr inbounds %foo.bar, %foo.bar* %1, i32 0, i32 0
       %px = getelementptr inbounds %foo.bar, %foo.bar* %1, i32 0, i32 1
       store i32 10, i32* %x, align 4
@@ -825,11 +839,19 @@ fn initializing_method_variables() {
       store i32* %x, i32** %px, align 8
       ret void
     }
+
+    define internal void @__vtable_foo.bar(%foo* %0, %foo.bar* %1) {
+    entry:
+      %foo.bar_instance = alloca %foo.bar, align 8
+      call void @foo.bar(%foo* %0, %foo.bar* %foo.bar_instance)
+      ret void
+    }
     ; ModuleID = '__initializers'
     source_filename = "__initializers"
 
-    %foo = type {}
+    %foo = type { i8* }
 
+    @__vtable_foo = external unnamed_addr constant [1 x i8*]
     @__foo__init = external global %foo
 
     define void @__init_foo(%foo* %0) {
@@ -881,22 +903,25 @@ fn initializing_method_variables() {
     ; ModuleID = '<internal>'
     source_filename = "<internal>"
 
-    %foo = type { i32 }
+    %foo = type { i8*, i32 }
     %foo.bar = type { i32* }
     %foo.baz = type { i32* }
 
+    @__vtable_foo = unnamed_addr constant [2 x i8*] [i8* bitcast (void (%foo*, %foo.bar*)* @__vtable_foo.bar to i8*), i8* bitcast (void (%foo*, %foo.baz*)* @__vtable_foo.baz to i8*)]
     @y = global i32 0
-    @__foo__init = unnamed_addr constant %foo { i32 5 }
+    @__foo__init = unnamed_addr constant %foo { i8* bitcast ([2 x i8*]* @__vtable_foo to i8*), i32 5 }
 
     define void @foo(%foo* %0) {
     entry:
-      %x = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %x = getelementptr inbounds %foo, %foo* %0, i32 0, i32 1
       ret void
     }
 
     define void @foo.bar(%foo* %0, %foo.bar* %1) {
     entry:
-      %x = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %x = getelementptr inbounds %foo, %foo* %0, i32 0, i32 1
       %px = getelementptr inbounds %foo.bar, %foo.bar* %1, i32 0, i32 0
       store i32* %x, i32** %px, align 8
       store i32* %x, i32** %px, align 8
@@ -905,17 +930,33 @@ fn initializing_method_variables() {
 
     define void @foo.baz(%foo* %0, %foo.baz* %1) {
     entry:
-      %x = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %x = getelementptr inbounds %foo, %foo* %0, i32 0, i32 1
       %px = getelementptr inbounds %foo.baz, %foo.baz* %1, i32 0, i32 0
       store i32* @y, i32** %px, align 8
       store i32* @y, i32** %px, align 8
       ret void
     }
+
+    define internal void @__vtable_foo.bar(%foo* %0, %foo.bar* %1) {
+    entry:
+      %foo.bar_instance = alloca %foo.bar, align 8
+      call void @foo.bar(%foo* %0, %foo.bar* %foo.bar_instance)
+      ret void
+    }
+
+    define internal void @__vtable_foo.baz(%foo* %0, %foo.baz* %1) {
+    entry:
+      %foo.baz_instance = alloca %foo.baz, align 8
+      call void @foo.baz(%foo* %0, %foo.baz* %foo.baz_instance)
+      ret void
+    }
     ; ModuleID = '__initializers'
     source_filename = "__initializers"
 
-    %foo = type { i32 }
+    %foo = type { i8*, i32 }
 
+    @__vtable_foo = external unnamed_addr constant [2 x i8*]
     @__foo__init = external global %foo
 
     define void @__init_foo(%foo* %0) {
@@ -957,20 +998,23 @@ fn initializing_method_variables() {
     ; ModuleID = '<internal>'
     source_filename = "<internal>"
 
-    %foo = type { i32 }
+    %foo = type { i8*, i32 }
     %foo.bar = type { i32, i32* }
 
-    @__foo__init = unnamed_addr constant %foo { i32 5 }
+    @__vtable_foo = unnamed_addr constant [1 x i8*] [i8* bitcast (void (%foo*, %foo.bar*)* @__vtable_foo.bar to i8*)]
+    @__foo__init = unnamed_addr constant %foo { i8* bitcast ([1 x i8*]* @__vtable_foo to i8*), i32 5 }
 
     define void @foo(%foo* %0) {
     entry:
-      %x = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %x = getelementptr inbounds %foo, %foo* %0, i32 0, i32 1
       ret void
     }
 
     define void @foo.bar(%foo* %0, %foo.bar* %1) {
     entry:
-      %x = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %__vtable = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
+      %x = getelementptr inbounds %foo, %foo* %0, i32 0, i32 1
       %x1 = getelementptr inbounds %foo.bar, %foo.bar* %1, i32 0, i32 0
       %px = getelementptr inbounds %foo.bar, %foo.bar* %1, i32 0, i32 1
       store i32 10, i32* %x1, align 4
@@ -978,11 +1022,19 @@ fn initializing_method_variables() {
       store i32* %x1, i32** %px, align 8
       ret void
     }
+
+    define internal void @__vtable_foo.bar(%foo* %0, %foo.bar* %1) {
+    entry:
+      %foo.bar_instance = alloca %foo.bar, align 8
+      call void @foo.bar(%foo* %0, %foo.bar* %foo.bar_instance)
+      ret void
+    }
     ; ModuleID = '__initializers'
     source_filename = "__initializers"
 
-    %foo = type { i32 }
+    %foo = type { i8*, i32 }
 
+    @__vtable_foo = external unnamed_addr constant [1 x i8*]
     @__foo__init = external global %foo
 
     define void @__init_foo(%foo* %0) {
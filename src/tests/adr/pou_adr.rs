@@ -68,6 +68,9 @@ fn programs_state_is_stored_in_a_struct() {
                         ),
                         is_constant: false,
                         is_var_external: false,
+                        is_retain: false,
+                        is_persistent: false,
+                        access: None,
                         data_type_name: "INT",
                         location_in_parent: 0,
                         linkage: Internal,
@@ -86,6 +89,7 @@ fn programs_state_is_stored_in_a_struct() {
                             ),
                         },
                         varargs: None,
+                        attributes: [],
                     },
                     VariableIndexEntry {
                         name: "io",
@@ -96,6 +100,9 @@ fn programs_state_is_stored_in_a_struct() {
                         ),
                         is_constant: false,
                         is_var_external: false,
+                        is_retain: false,
+                        is_persistent: false,
+                        access: None,
                         data_type_name: "__auto_pointer_to_INT",
                         location_in_parent: 1,
                         linkage: Internal,
@@ -114,6 +121,7 @@ fn programs_state_is_stored_in_a_struct() {
                             ),
                         },
                         varargs: None,
+                        attributes: [],
                     },
                     VariableIndexEntry {
                         name: "o",
@@ -124,6 +132,9 @@ fn programs_state_is_stored_in_a_struct() {
                         ),
                         is_constant: false,
                         is_var_external: false,
+                        is_retain: false,
+                        is_persistent: false,
+                        access: None,
                         data_type_name: "INT",
                         location_in_parent: 2,
                         linkage: Internal,
@@ -142,6 +153,7 @@ fn programs_state_is_stored_in_a_struct() {
                             ),
                         },
                         varargs: None,
+                        attributes: [],
                     },
                     VariableIndexEntry {
                         name: "v",
@@ -152,6 +164,9 @@ fn programs_state_is_stored_in_a_struct() {
                         ),
                         is_constant: false,
                         is_var_external: false,
+                        is_retain: false,
+                        is_persistent: false,
+                        access: None,
                         data_type_name: "INT",
                         location_in_parent: 3,
                         linkage: Internal,
@@ -170,6 +185,7 @@ fn programs_state_is_stored_in_a_struct() {
                             ),
                         },
                         varargs: None,
+                        attributes: [],
                     },
                     VariableIndexEntry {
                         name: "vt",
@@ -180,6 +196,9 @@ fn programs_state_is_stored_in_a_struct() {
                         ),
                         is_constant: false,
                         is_var_external: false,
+                        is_retain: false,
+                        is_persistent: false,
+                        access: None,
                         data_type_name: "INT",
                         location_in_parent: 4,
                         linkage: Internal,
@@ -198,6 +217,7 @@ fn programs_state_is_stored_in_a_struct() {
                             ),
                         },
                         varargs: None,
+                        attributes: [],
                     },
                 ],
                 source: Pou(
@@ -396,20 +416,22 @@ const DEFAULT_FB: &str = r#"
 
 #[test]
 fn function_blocks_get_a_method_with_a_self_parameter() {
-    insta::assert_snapshot!(codegen(DEFAULT_FB), @r###"
+    insta::assert_snapshot!(codegen(DEFAULT_FB), @r#"
     ; ModuleID = '<internal>'
     source_filename = "<internal>"
 
-    %main_fb = type { i16, i16*, i16, i16 }
+    %main_fb = type { i8*, i16, i16*, i16, i16 }
 
-    @__main_fb__init = unnamed_addr constant %main_fb { i16 6, i16* null, i16 0, i16 1 }
+    @__vtable_main_fb = unnamed_addr constant [0 x i8*] zeroinitializer
+    @__main_fb__init = unnamed_addr constant %main_fb { i8* bitcast ([0 x i8*]* @__vtable_main_fb to i8*), i16 6, i16* null, i16 0, i16 1 }
 
     define void @main_fb(%main_fb* %0) {
     entry:
-      %i = getelementptr inbounds %main_fb, %main_fb* %0, i32 0, i32 0
-      %io = getelementptr inbounds %main_fb, %main_fb* %0, i32 0, i32 1
-      %o = getelementptr inbounds %main_fb, %main_fb* %0, i32 0, i32 2
-      %v = getelementptr inbounds %main_fb, %main_fb* %0, i32 0, i32 3
+      %__vtable = getelementptr inbounds %main_fb, %main_fb* %0, i32 0, i32 0
+      %i = getelementptr inbounds %main_fb, %main_fb* %0, i32 0, i32 1
+      %io = getelementptr inbounds %main_fb, %main_fb* %0, i32 0, i32 2
+      %o = getelementptr inbounds %main_fb, %main_fb* %0, i32 0, i32 3
+      %v = getelementptr inbounds %main_fb, %main_fb* %0, i32 0, i32 4
       %vt = alloca i16, align 2
       store i16 2, i16* %vt, align 2
       ret void
@@ -417,8 +439,9 @@ fn function_blocks_get_a_method_with_a_self_parameter() {
     ; ModuleID = '__initializers'
     source_filename = "__initializers"
 
-    %main_fb = type { i16, i16*, i16, i16 }
+    %main_fb = type { i8*, i16, i16*, i16, i16 }
 
+    @__vtable_main_fb = external unnamed_addr constant [0 x i8*]
     @__main_fb__init = external global %main_fb
 
     define void @__init_main_fb(%main_fb* %0) {
@@ -438,7 +461,7 @@ fn function_blocks_get_a_method_with_a_self_parameter() {
     entry:
       ret void
     }
-    "###);
+    "#);
 }
 
 /// Calling a function block works like this:
@@ -459,27 +482,28 @@ fn calling_a_function_block() {
         {DEFAULT_FB}
     "#
     );
-    insta::assert_snapshot!(codegen(calling_prg.as_str()), @r###"
+    insta::assert_snapshot!(codegen(calling_prg.as_str()), @r#"
     ; ModuleID = '<internal>'
     source_filename = "<internal>"
 
     %foo = type { i16, i16, %main_fb }
-    %main_fb = type { i16, i16*, i16, i16 }
+    %main_fb = type { i8*, i16, i16*, i16, i16 }
 
-    @foo_instance = global %foo { i16 0, i16 0, %main_fb { i16 6, i16* null, i16 0, i16 1 } }
-    @__main_fb__init = unnamed_addr constant %main_fb { i16 6, i16* null, i16 0, i16 1 }
+    @__vtable_main_fb = unnamed_addr constant [0 x i8*] zeroinitializer
+    @foo_instance = global %foo { i16 0, i16 0, %main_fb { i8* bitcast ([0 x i8*]* @__vtable_main_fb to i8*), i16 6, i16* null, i16 0, i16 1 } }
+    @__main_fb__init = unnamed_addr constant %main_fb { i8* bitcast ([0 x i8*]* @__vtable_main_fb to i8*), i16 6, i16* null, i16 0, i16 1 }
 
     define void @foo(%foo* %0) {
     entry:
       %x = getelementptr inbounds %foo, %foo* %0, i32 0, i32 0
       %y = getelementptr inbounds %foo, %foo* %0, i32 0, i32 1
       %fb = getelementptr inbounds %foo, %foo* %0, i32 0, i32 2
-      %1 = getelementptr inbounds %main_fb, %main_fb* %fb, i32 0, i32 0
+      %1 = getelementptr inbounds %main_fb, %main_fb* %fb, i32 0, i32 1
       store i16 1, i16* %1, align 2
-      %2 = getelementptr inbounds %main_fb, %main_fb* %fb, i32 0, i32 1
+      %2 = getelementptr inbounds %main_fb, %main_fb* %fb, i32 0, i32 2
       store i16* %y, i16** %2, align 8
       call void @main_fb(%main_fb* %fb)
-      %3 = getelementptr inbounds %main_fb, %main_fb* %fb, i32 0, i32 2
+      %3 = getelementptr inbounds %main_fb, %main_fb* %fb, i32 0, i32 3
       %4 = load i16, i16* %3, align 2
       store i16 %4, i16* %x, align 2
       ret void
@@ -487,10 +511,11 @@ fn calling_a_function_block() {
 
     define void @main_fb(%main_fb* %0) {
     entry:
-      %i = getelementptr inbounds %main_fb, %main_fb* %0, i32 0, i32 0
-      %io = getelementptr inbounds %main_fb, %main_fb* %0, i32 0, i32 1
-      %o = getelementptr inbounds %main_fb, %main_fb* %0, i32 0, i32 2
-      %v = getelementptr inbounds %main_fb, %main_fb* %0, i32 0, i32 3
+      %__vtable = getelementptr inbounds %main_fb, %main_fb* %0, i32 0, i32 0
+      %i = getelementptr inbounds %main_fb, %main_fb* %0, i32 0, i32 1
+      %io = getelementptr inbounds %main_fb, %main_fb* %0, i32 0, i32 2
+      %o = getelementptr inbounds %main_fb, %main_fb* %0, i32 0, i32 3
+      %v = getelementptr inbounds %main_fb, %main_fb* %0, i32 0, i32 4
       %vt = alloca i16, align 2
       store i16 2, i16* %vt, align 2
       ret void
@@ -499,8 +524,9 @@ fn calling_a_function_block() {
     source_filename = "__initializers"
 
     %foo = type { i16, i16, %main_fb }
-    %main_fb = type { i16, i16*, i16, i16 }
+    %main_fb = type { i8*, i16, i16*, i16, i16 }
 
+    @__vtable_main_fb = external unnamed_addr constant [0 x i8*]
     @foo_instance = external global %foo
     @__main_fb__init = external global %main_fb
 
@@ -528,8 +554,9 @@ fn calling_a_function_block() {
     source_filename = "__init___testproject"
 
     %foo = type { i16, i16, %main_fb }
-    %main_fb = type { i16, i16*, i16, i16 }
+    %main_fb = type { i8*, i16, i16*, i16, i16 }
 
+    @__vtable_main_fb = external unnamed_addr constant [0 x i8*]
     @foo_instance = external global %foo
     @__main_fb__init = external global %main_fb
     @llvm.global_ctors = appending global [1 x { i32, void ()*, i8* }] [{ i32, void ()*, i8* } { i32 0, void ()* @__init___testproject, i8* null }]
@@ -545,7 +572,7 @@ fn calling_a_function_block() {
     declare void @foo(%foo*)
 
     declare void @main_fb(%main_fb*)
-    "###);
+    "#);
 }
 
 /// # FUNCTION
@@ -44,6 +44,9 @@ fn representation() {
                     ),
                     is_constant: false,
                     is_var_external: false,
+                    is_retain: false,
+                    is_persistent: false,
+                    access: None,
                     data_type_name: "__ptr_to___arr_vla_1_dint",
                     location_in_parent: 0,
                     linkage: Internal,
@@ -52,6 +55,7 @@ fn representation() {
                         span: None,
                     },
                     varargs: None,
+                    attributes: [],
                 },
                 VariableIndexEntry {
                     name: "dimensions",
@@ -62,6 +66,9 @@ fn representation() {
                     ),
                     is_constant: false,
                     is_var_external: false,
+                    is_retain: false,
+                    is_persistent: false,
+                    access: None,
                     data_type_name: "__bounds___arr_vla_1_dint",
                     location_in_parent: 1,
                     linkage: Internal,
@@ -70,6 +77,7 @@ fn representation() {
                         span: None,
                     },
                     varargs: None,
+                    attributes: [],
                 },
             ],
             source: Internal(
@@ -160,13 +168,13 @@ fn representation() {
                 Dimension {
                     start_offset: ConstExpression(
                         Index {
-                            index: 0,
+                            index: 4,
                             generation: 0,
                         },
                     ),
                     end_offset: ConstExpression(
                         Index {
-                            index: 1,
+                            index: 5,
                             generation: 0,
                         },
                     ),
@@ -245,13 +253,13 @@ fn pass() {
                 Dimension {
                     start_offset: ConstExpression(
                         Index {
-                            index: 0,
+                            index: 4,
                             generation: 0,
                         },
                     ),
                     end_offset: ConstExpression(
                         Index {
-                            index: 1,
+                            index: 5,
                             generation: 0,
                         },
                     ),
@@ -292,6 +300,9 @@ fn pass() {
                 ),
                 is_constant: false,
                 is_var_external: false,
+                is_retain: false,
+                is_persistent: false,
+                access: None,
                 data_type_name: "__ptr_to___arr_vla_1_dint",
                 location_in_parent: 0,
                 linkage: Internal,
@@ -300,6 +311,7 @@ fn pass() {
                     span: None,
                 },
                 varargs: None,
+                attributes: [],
             },
             VariableIndexEntry {
                 name: "dimensions",
@@ -310,6 +322,9 @@ fn pass() {
                 ),
                 is_constant: false,
                 is_var_external: false,
+                is_retain: false,
+                is_persistent: false,
+                access: None,
                 data_type_name: "__bounds___arr_vla_1_dint",
                 location_in_parent: 1,
                 linkage: Internal,
@@ -318,6 +333,7 @@ fn pass() {
                     span: None,
                 },
                 varargs: None,
+                attributes: [],
             },
         ],
         source: Internal(
@@ -13,7 +13,7 @@ use plc_source::source_location::SourceLocation;
 
 use crate::{
     datalayout::{Bytes, MemoryLocation},
-    index::{const_expressions::ConstId, Index, VariableIndexEntry},
+    index::{const_expressions::ConstId, Index, VariableIndexEntry, BASE_MEMBER_NAME},
 };
 
 pub const DEFAULT_STRING_LEN: u32 = 80;
@@ -89,6 +89,7 @@ pub const WCHAR_TYPE: &str = "WCHAR";
 pub const VOID_TYPE: &str = "VOID";
 pub const VOID_INTERNAL_NAME: &str = "__VOID";
 pub const __VLA_TYPE: &str = "__VLA";
+pub const BYTE_POINTER_TYPE: &str = "__BYTE_POINTER";
 
 #[cfg(test)]
 mod tests;
@@ -349,6 +350,8 @@ impl TypeSize {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum StructSource {
     OriginalDeclaration,
+    /// A `UNION` declaration, all members share the same storage at offset 0
+    Union,
     Pou(PouType),
     Internal(InternalType),
 }
@@ -364,8 +367,14 @@ impl StructSource {
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum InternalType {
-    VariableLengthArray { inner_type_name: String, ndims: usize },
+    VariableLengthArray {
+        inner_type_name: String,
+        ndims: usize,
+    },
     __VLA, // used for error-reporting only
+    /// The type of variables declared with an interface as their type, consisting of a pointer to the
+    /// referenced instance (`__data`) and a pointer to the dispatch table of the instance's POU (`__vtable`)
+    Interface,
 }
 
 type TypeId = String;
@@ -516,6 +525,17 @@ impl DataTypeInformation {
         )
     }
 
+    pub fn is_interface(&self) -> bool {
+        matches!(
+            self,
+            DataTypeInformation::Struct { source: StructSource::Internal(InternalType::Interface), .. }
+        )
+    }
+
+    pub fn is_union(&self) -> bool {
+        matches!(self, DataTypeInformation::Struct { source: StructSource::Union, .. })
+    }
+
     pub fn is_enum(&self) -> bool {
         matches!(self, DataTypeInformation::Enum { .. })
     }
@@ -623,8 +643,18 @@ impl DataTypeInformation {
                 .map(|size| encoding.get_bytes_per_char() * size as u32)
                 .map(Bytes::new)
                 .unwrap(),
+            DataTypeInformation::Struct { members, source: StructSource::Union, .. } => {
+                // all members start at offset 0, the union is as large as its largest member
+                let size = members
+                    .iter()
+                    .map(|it| index.get_type_information_or_void(it.get_type_name()).get_size(index).value())
+                    .max()
+                    .unwrap_or_default();
+                MemoryLocation::new(size).align_to(self.get_alignment(index)).into()
+            }
             DataTypeInformation::Struct { members, .. } => members
                 .iter()
+                .filter(|it| it.is_stored_in_struct())
                 .map(|it| it.get_type_name())
                 .fold(MemoryLocation::new(0), |prev, it| {
                     let type_info = index.get_type_information_or_void(it);
@@ -655,6 +685,37 @@ impl DataTypeInformation {
         }
     }
 
+    /// Returns the offset of the given member from the start of this struct, following the layout of the
+    /// generated struct type. Members inherited from a base class are found in its `__BASE` member
+    pub fn get_member_offset(&self, member_name: &str, index: &Index) -> Option<Bytes> {
+        let DataTypeInformation::Struct { members, source, .. } = self else {
+            return None;
+        };
+
+        if *source == StructSource::Union {
+            return members
+                .iter()
+                .any(|it| it.get_name().eq_ignore_ascii_case(member_name))
+                .then_some(Bytes::new(0));
+        }
+
+        let mut offset = MemoryLocation::new(0);
+        for member in members.iter().filter(|it| it.is_stored_in_struct()) {
+            let type_info = index.get_type_information_or_void(member.get_type_name());
+            offset = offset.align_to(type_info.get_alignment(index));
+            if member.get_name().eq_ignore_ascii_case(member_name) {
+                return Some(offset.into());
+            }
+            if member.get_name() == BASE_MEMBER_NAME {
+                if let Some(inherited) = type_info.get_member_offset(member_name, index) {
+                    return Some(Bytes::from(offset) + inherited);
+                }
+            }
+            offset += type_info.get_size(index);
+        }
+        None
+    }
+
     /// Returns the String encoding's alignment (character)
     pub fn get_string_character_width(&self, index: &Index) -> Bytes {
         let type_layout = index.get_type_layout();
@@ -666,6 +727,14 @@ impl DataTypeInformation {
     }
 
     pub fn get_alignment(&self, index: &Index) -> Bytes {
+        if let DataTypeInformation::Struct { members, source: StructSource::Union, .. } = self {
+            // a union is aligned like its most strictly aligned member
+            return members
+                .iter()
+                .map(|it| index.get_type_information_or_void(it.get_type_name()).get_alignment(index))
+                .fold(Bytes::new(0), |max, it| if it > max { it } else { max });
+        }
+
         if self.get_size(index).value() == 0 {
             return Bytes::new(0);
         }
@@ -1272,6 +1341,17 @@ pub fn get_builtin_types() -> Vec<DataType> {
             nature: TypeNature::Char,
             location: SourceLocation::internal(),
         },
+        DataType {
+            name: BYTE_POINTER_TYPE.into(),
+            initial_value: None,
+            information: DataTypeInformation::Pointer {
+                name: BYTE_POINTER_TYPE.into(),
+                inner_type_name: BYTE_TYPE.into(),
+                auto_deref: None,
+            },
+            nature: TypeNature::Any,
+            location: SourceLocation::internal(),
+        },
     ]
 }
 
@@ -1345,6 +1425,31 @@ pub fn is_same_type_class(ltype: &DataTypeInformation, rtype: &DataTypeInformati
     }
 }
 
+/// Returns true if both types describe the same data type, e.g. two arrays with the same dimensions
+/// and element type, even if they were declared inline and therefore carry different names
+pub fn is_same_type(ltype: &DataTypeInformation, rtype: &DataTypeInformation, index: &Index) -> bool {
+    let effective_type = |name: &str| index.get_effective_type_or_void_by_name(name).get_type_information();
+    match (ltype, rtype) {
+        (
+            DataTypeInformation::Array { inner_type_name: linner, dimensions: ldimensions, .. },
+            DataTypeInformation::Array { inner_type_name: rinner, dimensions: rdimensions, .. },
+        ) => {
+            ldimensions.len() == rdimensions.len()
+                && ldimensions.iter().zip(rdimensions).all(|(l, r)| l.get_range(index) == r.get_range(index))
+                && is_same_type(effective_type(linner), effective_type(rinner), index)
+        }
+        (
+            DataTypeInformation::Pointer { inner_type_name: linner, auto_deref: lderef, .. },
+            DataTypeInformation::Pointer { inner_type_name: rinner, auto_deref: rderef, .. },
+        ) => lderef == rderef && is_same_type(effective_type(linner), effective_type(rinner), index),
+        (
+            DataTypeInformation::String { size: lsize, encoding: lencoding },
+            DataTypeInformation::String { size: rsize, encoding: rencoding },
+        ) => lencoding == rencoding && lsize.as_int_value(index) == rsize.as_int_value(index),
+        _ => ltype.get_name() == rtype.get_name(),
+    }
+}
+
 /// Returns the bigger of the two provided types
 pub fn get_bigger_type<'t, T: DataTypeInformationProvider<'t> + std::convert::From<&'t DataType>>(
     left_type: T,
@@ -3,8 +3,9 @@ use plc_derive::Validators;
 use plc_diagnostics::diagnostics::Diagnostic;
 use plc_index::GlobalContext;
 use plc_source::source_location::SourceLocation;
+use plc_util::convention::NAMESPACE_SEPARATOR;
 use rustc_hash::FxHashMap;
-use variable::visit_config_variable;
+use variable::{visit_access_variable, visit_config_variable};
 
 use crate::{
     expression_path::ExpressionPath,
@@ -25,6 +26,7 @@ use self::{
 };
 
 mod array;
+mod configuration;
 mod global;
 mod pou;
 mod recursive;
@@ -154,6 +156,7 @@ impl<'a> Validator<'a> {
         self.global_validator.validate(index);
         self.recursive_validator.validate(index);
         self.validate_configured_templates(index);
+        configuration::validate_configurations(self, index);
 
         // XXX: To avoid bloating up this function any further, maybe package logic into seperate module or
         //      function if another global check is introduced (including the overflow checks)?
@@ -188,6 +191,11 @@ impl<'a> Validator<'a> {
             visit_config_variable(self, variable, &context);
         }
 
+        // Validate access paths (VAR_ACCESS)
+        for variable in &unit.var_access {
+            visit_access_variable(self, variable, &context);
+        }
+
         // Validate global variables
         for gv in &unit.global_vars {
             visit_variable_block(self, None, gv, &context);
@@ -197,6 +205,20 @@ impl<'a> Validator<'a> {
         for implementation in &unit.implementations {
             visit_implementation(self, implementation, &context);
         }
+
+        // Validate the imported namespaces
+        for using in &unit.usings {
+            if !index.is_namespace(&using.namespace) {
+                self.push_diagnostic(
+                    Diagnostic::new(format!(
+                        "Unknown namespace `{}`",
+                        using.namespace.replace(NAMESPACE_SEPARATOR, ".")
+                    ))
+                    .with_error_code("E120")
+                    .with_location(&using.location),
+                );
+            }
+        }
     }
 
     pub fn validate_configured_templates(&mut self, index: &Index) {
@@ -0,0 +1,146 @@
+use plc_diagnostics::diagnostics::Diagnostic;
+use rustc_hash::FxHashSet;
+
+use crate::index::{FxIndexMap, Index, PouIndexEntry, ProgramInstanceIndexEntry, TaskIndexEntry};
+
+use super::{Validator, Validators};
+
+/// Validates the tasks and program instances declared in the project's `CONFIGURATION`
+pub fn validate_configurations(validator: &mut Validator, index: &Index) {
+    validate_single_configuration(validator, index);
+
+    for task in index.get_tasks().values() {
+        validate_task(validator, task, index);
+    }
+
+    let mut instances_per_program: FxIndexMap<String, Vec<&ProgramInstanceIndexEntry>> =
+        FxIndexMap::default();
+    for program in index.get_configured_programs().values() {
+        validate_program_instance(validator, program, index);
+        instances_per_program.entry(program.program.to_lowercase()).or_default().push(program);
+    }
+
+    // programs are singletons, every instance would share the same state
+    for instances in instances_per_program.values().filter(|it| it.len() > 1) {
+        for instance in instances.iter().skip(1) {
+            validator.push_diagnostic(
+                Diagnostic::new(format!(
+                    "Program `{}` is instantiated more than once, a program only has a single instance",
+                    instance.program
+                ))
+                .with_error_code("E124")
+                .with_location(&instance.program_location)
+                .with_secondary_location(&instances[0].program_location),
+            );
+        }
+    }
+}
+
+/// The task table is generated for a single configuration, report every other one
+fn validate_single_configuration(validator: &mut Validator, index: &Index) {
+    let mut elements = index
+        .get_tasks()
+        .values()
+        .map(|it| (&it.configuration, &it.location))
+        .chain(index.get_configured_programs().values().map(|it| (&it.configuration, &it.location)));
+    let Some((first, _)) = elements.next() else { return };
+
+    let mut reported = FxHashSet::default();
+    for (configuration, location) in elements {
+        if !first.eq_ignore_ascii_case(configuration) && reported.insert(configuration.to_lowercase()) {
+            validator.push_diagnostic(
+                Diagnostic::new(format!(
+                    "Configuration `{configuration}` conflicts with configuration `{first}`, a project may only declare a single configuration"
+                ))
+                .with_error_code("E124")
+                .with_location(location),
+            );
+        }
+    }
+}
+
+fn validate_task(validator: &mut Validator, task: &TaskIndexEntry, index: &Index) {
+    let name = &task.name;
+    if task.priority.is_none() {
+        validator.push_diagnostic(
+            Diagnostic::new(format!("Task `{name}` does not declare a `PRIORITY`"))
+                .with_error_code("E123")
+                .with_location(&task.location),
+        );
+    } else if task.get_priority_value(index).is_none() {
+        validator.push_diagnostic(
+            Diagnostic::new(format!(
+                "The `PRIORITY` of task `{name}` must be a constant integer between 0 and 65535"
+            ))
+            .with_error_code("E123")
+            .with_location(&task.location),
+        );
+    }
+
+    if task.interval.is_some() && task.get_interval_value(index).is_none_or(|it| it <= 0) {
+        validator.push_diagnostic(
+            Diagnostic::new(format!("The `INTERVAL` of task `{name}` must be a positive constant TIME"))
+                .with_error_code("E123")
+                .with_location(&task.location),
+        );
+    }
+
+    if let Some(single) = &task.single {
+        let is_bool_variable = index.find_global_variable(single).is_some_and(|it| {
+            index.get_effective_type_or_void_by_name(it.get_type_name()).get_type_information().is_bool()
+        });
+        if !is_bool_variable {
+            validator.push_diagnostic(
+                Diagnostic::new(format!(
+                    "The `SINGLE` trigger of task `{name}` must be a global BOOL variable, found `{single}`"
+                ))
+                .with_error_code("E123")
+                .with_location(&task.location),
+            );
+        }
+    }
+
+    if task.interval.is_none() && task.single.is_none() {
+        validator.push_diagnostic(
+            Diagnostic::new(format!("Task `{name}` needs an `INTERVAL` or a `SINGLE` trigger"))
+                .with_error_code("E123")
+                .with_location(&task.location),
+        );
+    }
+}
+
+fn validate_program_instance(validator: &mut Validator, program: &ProgramInstanceIndexEntry, index: &Index) {
+    match &program.task {
+        None => validator.push_diagnostic(
+            Diagnostic::new(format!("Program instance `{}` is not assigned to a task", program.name))
+                .with_error_code("E124")
+                .with_location(&program.location),
+        ),
+        Some(task) if program.find_task(index).is_none() => {
+            let task = task.rsplit('.').next().unwrap_or(task);
+            validator.push_diagnostic(
+                Diagnostic::new(format!(
+                    "Program instance `{}` is assigned to the unknown task `{task}`",
+                    program.name
+                ))
+                .with_error_code("E124")
+                .with_location(&program.location),
+            )
+        }
+        _ => {}
+    }
+
+    match index.find_pou(&program.program) {
+        Some(PouIndexEntry::Program { .. }) => {}
+        Some(pou) => validator.push_diagnostic(
+            Diagnostic::new(format!("`{}` is not a PROGRAM", pou.get_name()))
+                .with_error_code("E124")
+                .with_location(&program.program_location),
+        ),
+        None => validator.push_diagnostic(
+            Diagnostic::new(format!("Unknown program `{}`", program.program))
+                .with_error_code("E124")
+                .with_location(&program.program_location),
+        ),
+    }
+}
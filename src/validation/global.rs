@@ -79,6 +79,9 @@ impl GlobalValidator {
 
         // all interfaces
         self.validate_unique_interfaces(index);
+
+        // tasks and program instances of configurations
+        self.validate_unique_configuration_elements(index);
     }
 
     /// validates following uniqueness-clusters:
@@ -132,7 +135,10 @@ impl GlobalValidator {
     fn validate_unique_datatypes(&mut self, index: &Index) {
         let all_declared_types = index.get_types().values().filter_map(|dt| {
             let name = dt.get_name();
-            if !index.get_type_information_or_void(name).is_generic(index) {
+            // interface types are validated alongside the interfaces themselves
+            if !index.get_type_information_or_void(name).is_generic(index)
+                && !dt.get_type_information().is_interface()
+            {
                 Some((name, &dt.location))
             } else {
                 None
@@ -231,6 +237,23 @@ impl GlobalValidator {
         self.check_uniqueness_of_cluster(interfaces, Some("Ambiguous interface"));
     }
 
+    /// validates the uniqueness of the tasks and program instances within their resources and of the
+    /// access paths
+    fn validate_unique_configuration_elements(&mut self, index: &Index) {
+        let tasks = index.get_tasks().values().map(|task| (task.qualified_name.as_str(), &task.location));
+        self.check_uniqueness_of_cluster(tasks, Some("Ambiguous task."));
+
+        let programs = index
+            .get_configured_programs()
+            .values()
+            .map(|program| (program.qualified_name.as_str(), &program.location));
+        self.check_uniqueness_of_cluster(programs, Some("Ambiguous program instance."));
+
+        let access_paths =
+            index.get_access_variables().iter().map(|access| (access.name.as_str(), &access.name_location));
+        self.check_uniqueness_of_cluster(access_paths, Some("Ambiguous access path."));
+    }
+
     fn check_uniqueness_of_cluster<'a, T>(&mut self, cluster: T, additional_text: Option<&str>)
     where
         T: Iterator<Item = (&'a str, &'a SourceLocation)>,
@@ -1,11 +1,13 @@
-use plc_ast::ast::{Implementation, LinkageType, Pou, PouType, VariableBlockType};
+use plc_ast::ast::{Implementation, LinkageType, PolymorphismMode, Pou, PouType, Sfc, VariableBlockType};
 use plc_diagnostics::diagnostics::Diagnostic;
 
 use super::{
     statement::visit_statement, variable::visit_variable_block, ValidationContext, Validator, Validators,
 };
 use crate::{index::PouIndexEntry, resolver::AnnotationMap};
-use std::collections::HashMap;
+use plc_source::source_location::SourceLocation;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::collections::{BTreeSet, HashMap, VecDeque};
 
 pub fn visit_pou<T: AnnotationMap>(validator: &mut Validator, pou: &Pou, context: &ValidationContext<'_, T>) {
     if pou.linkage != LinkageType::External {
@@ -264,6 +266,8 @@ pub fn visit_implementation<T: AnnotationMap>(
     }
     if implementation.linkage != LinkageType::External {
         validate_action_container(validator, implementation);
+        validate_abstract_method(validator, implementation, context);
+        validate_method_override(validator, implementation, context);
         //Validate the label uniqueness
 
         if let Some(labels) = context.index.get_labels(&implementation.name) {
@@ -291,6 +295,129 @@ pub fn visit_implementation<T: AnnotationMap>(
     }
 }
 
+/// Validates that a method declared `ABSTRACT` only declares its interface, it is implemented by the derived POUs
+fn validate_abstract_method<T: AnnotationMap>(
+    validator: &mut Validator,
+    implementation: &Implementation,
+    context: &ValidationContext<'_, T>,
+) {
+    let is_abstract = context.index.find_pou(&implementation.name).is_some_and(PouIndexEntry::is_abstract);
+    if is_abstract && !implementation.statements.is_empty() {
+        validator.push_diagnostic(
+            Diagnostic::new(format!(
+                "Method `{}` is declared ABSTRACT and cannot have an implementation",
+                implementation.name
+            ))
+            .with_error_code("E137")
+            .with_location(&implementation.name_location),
+        );
+    }
+}
+
+/// Validates that a method declared `OVERRIDE` overrides a method of a super class with the same signature,
+/// and that methods overriding another one are declared `OVERRIDE`
+fn validate_method_override<T: AnnotationMap>(
+    validator: &mut Validator,
+    implementation: &Implementation,
+    context: &ValidationContext<'_, T>,
+) {
+    let PouType::Method { parent } = &implementation.pou_type else {
+        return;
+    };
+    let (Some(method), Some((_, method_name))) =
+        (context.index.find_pou(&implementation.name), implementation.name.rsplit_once('.'))
+    else {
+        return;
+    };
+
+    if method.is_lifecycle_method() {
+        // every POU of a hierarchy declares its own lifecycle methods, they are called one after another
+        return validate_lifecycle_method(validator, implementation, parent, context);
+    }
+
+    let overridden = context
+        .index
+        .find_pou(parent)
+        .and_then(PouIndexEntry::get_super_class)
+        .and_then(|super_class| context.index.find_method(super_class, method_name));
+
+    match overridden {
+        None if implementation.overriding => validator.push_diagnostic(
+            Diagnostic::new(format!(
+                "Method `{}` is declared OVERRIDE, but `{parent}` does not inherit a method `{method_name}`",
+                implementation.name
+            ))
+            .with_error_code("E116")
+            .with_location(&implementation.name_location),
+        ),
+        None => {}
+        Some(overridden) => {
+            if overridden.is_final() {
+                validator.push_diagnostic(
+                    Diagnostic::new(format!(
+                        "Method `{}` cannot override the FINAL method `{}`",
+                        implementation.name,
+                        overridden.get_name()
+                    ))
+                    .with_error_code("E140")
+                    .with_location(&implementation.name_location)
+                    .with_secondary_location(overridden.get_location()),
+                );
+            }
+
+            // property accessors override each other implicitly
+            if !implementation.overriding && !method_name.starts_with("__") {
+                validator.push_diagnostic(
+                    Diagnostic::new(format!(
+                        "Method `{}` overrides `{}` but is not declared OVERRIDE",
+                        implementation.name,
+                        overridden.get_name()
+                    ))
+                    .with_error_code("E117")
+                    .with_location(&implementation.name_location),
+                );
+            }
+
+            let diagnostics = validate_method_signature(context, overridden, method);
+            if !diagnostics.is_empty() {
+                validator.push_diagnostic(
+                    Diagnostic::new(format!(
+                        "Method `{}` does not match the signature of the overridden method `{}`",
+                        implementation.name,
+                        overridden.get_name()
+                    ))
+                    .with_error_code("E118")
+                    .with_location(&implementation.name_location)
+                    .with_secondary_location(overridden.get_location())
+                    .with_sub_diagnostics(diagnostics),
+                );
+            }
+        }
+    }
+}
+
+/// Validates that the lifecycle methods `FB_INIT`, `FB_REINIT` and `FB_EXIT` are declared by function blocks or
+/// classes, the only POUs whose instances call them implicitly
+fn validate_lifecycle_method<T: AnnotationMap>(
+    validator: &mut Validator,
+    implementation: &Implementation,
+    parent: &str,
+    context: &ValidationContext<'_, T>,
+) {
+    if context.index.find_pou(parent).is_some_and(|it| it.is_function_block() || it.is_class()) {
+        return;
+    }
+
+    validator.push_diagnostic(
+        Diagnostic::new(format!(
+            "Method `{}` is never called implicitly, lifecycle methods are only supported in FUNCTION_BLOCKs and CLASSes",
+            implementation.name
+        ))
+        .with_error_code("E142")
+        .with_location(&implementation.name_location),
+    );
+}
+
 fn validate_pou<T: AnnotationMap>(validator: &mut Validator, pou: &Pou, context: &ValidationContext<'_, T>) {
     if pou.kind == PouType::Function {
         validate_function(validator, pou);
@@ -301,6 +428,39 @@ fn validate_pou<T: AnnotationMap>(validator: &mut Validator, pou: &Pou, context:
     if pou.kind == PouType::Program {
         validate_program(validator, pou);
     }
+    if matches!(pou.kind, PouType::FunctionBlock | PouType::Class) {
+        validate_polymorphism(validator, pou, context);
+        validate_generic_arguments(validator, pou, context);
+    }
+    if let Some(sfc) = &pou.sfc {
+        validate_sfc(validator, pou, sfc);
+    }
+}
+
+/// Validates that the type arguments of an instantiated generic function block or class satisfy the
+/// natures of their generic symbols. Unknown types are reported by the variables referencing the instance.
+fn validate_generic_arguments<T: AnnotationMap>(
+    validator: &mut Validator,
+    pou: &Pou,
+    context: &ValidationContext<'_, T>,
+) {
+    for argument in &pou.generic_arguments {
+        let Some(data_type) = context.index.find_effective_type_by_name(&argument.type_name) else {
+            continue;
+        };
+
+        if !data_type.has_nature(argument.binding.nature, context.index) {
+            validator.push_diagnostic(
+                Diagnostic::new(format!(
+                    "Invalid type nature for generic argument. {} is no {}",
+                    data_type.get_name(),
+                    argument.binding.nature
+                ))
+                .with_error_code("E062")
+                .with_location(&argument.location),
+            );
+        }
+    }
 }
 
 fn validate_class<T: AnnotationMap>(validator: &mut Validator, pou: &Pou, context: &ValidationContext<T>) {
@@ -329,6 +489,46 @@ fn validate_class<T: AnnotationMap>(validator: &mut Validator, pou: &Pou, contex
     }
 }
 
+/// Validates that a function block or class does not extend a `FINAL` POU, and that it implements all inherited
+/// `ABSTRACT` methods unless it is declared `ABSTRACT` itself
+fn validate_polymorphism<T: AnnotationMap>(
+    validator: &mut Validator,
+    pou: &Pou,
+    context: &ValidationContext<'_, T>,
+) {
+    if let Some(super_class) = pou.super_class.as_ref().and_then(|it| context.index.find_pou(it)) {
+        if super_class.is_final() {
+            validator.push_diagnostic(
+                Diagnostic::new(format!(
+                    "`{}` cannot extend `{}`, it is declared FINAL",
+                    pou.name,
+                    super_class.get_name()
+                ))
+                .with_error_code("E139")
+                .with_location(&pou.name_location)
+                .with_secondary_location(super_class.get_location()),
+            );
+        }
+    }
+
+    if pou.poly_mode == Some(PolymorphismMode::Abstract) {
+        return;
+    }
+
+    for method in context.index.get_inherited_methods(&pou.name).into_iter().filter(|it| it.is_abstract()) {
+        validator.push_diagnostic(
+            Diagnostic::new(format!(
+                "`{}` must implement the ABSTRACT method `{}` or be declared ABSTRACT itself",
+                pou.name,
+                method.get_name()
+            ))
+            .with_error_code("E138")
+            .with_location(&pou.name_location)
+            .with_secondary_location(method.get_location()),
+        );
+    }
+}
+
 fn validate_function(validator: &mut Validator, pou: &Pou) {
     // functions cannot use EXTENDS
     if pou.super_class.is_some() {
@@ -351,6 +551,130 @@ fn validate_program(validator: &mut Validator, pou: &Pou) {
     }
 }
 
+fn validate_sfc(validator: &mut Validator, pou: &Pou, sfc: &Sfc) {
+    if !matches!(pou.kind, PouType::Program | PouType::FunctionBlock) {
+        validator.push_diagnostic(
+            Diagnostic::new(format!(
+                "Only programs and function blocks can be implemented as a sequential function chart, found '{}'",
+                pou.kind
+            ))
+            .with_error_code("E125")
+            .with_location(&pou.name_location),
+        );
+        return;
+    }
+
+    if !sfc.steps.iter().any(|it| it.initial) {
+        validator.push_diagnostic(
+            Diagnostic::new(format!("Sequential function chart of '{}' has no INITIAL_STEP", pou.name))
+                .with_error_code("E125")
+                .with_location(&pou.name_location),
+        );
+    }
+
+    let mut steps = FxHashMap::default();
+    for step in sfc.steps.iter() {
+        if let Some(first) = steps.insert(step.name.to_lowercase(), step) {
+            validator.push_diagnostic(
+                Diagnostic::new(format!("Step '{}' is declared more than once", step.name))
+                    .with_error_code("E125")
+                    .with_location(&step.name_location)
+                    .with_secondary_location(&first.name_location),
+            );
+        }
+    }
+
+    let mut is_well_formed = steps.len() == sfc.steps.len() && steps.values().any(|it| it.initial);
+    for (name, location) in sfc.transitions.iter().flat_map(|it| it.from.iter().chain(it.to.iter())) {
+        if !steps.contains_key(&name.to_lowercase()) {
+            is_well_formed = false;
+            validator.push_diagnostic(
+                Diagnostic::new(format!("Transition refers to unknown step '{name}'"))
+                    .with_error_code("E125")
+                    .with_location(location),
+            );
+        }
+    }
+
+    if is_well_formed {
+        validate_sfc_sequences(validator, sfc);
+    }
+}
+
+/// The maximum number of markings explored when analysing the sequences of a chart
+const MAX_SFC_MARKINGS: usize = 4096;
+
+/// Explores all markings, i.e. sets of simultaneously active steps, reachable from the initial steps of the
+/// chart. A transition activating a step which may still be active from another branch is reported as unsafe,
+/// a step which is never active is reported as unreachable. Transition conditions are not evaluated, every
+/// transition whose steps are active is assumed to fire eventually
+fn validate_sfc_sequences(validator: &mut Validator, sfc: &Sfc) {
+    let ids: FxHashMap<String, usize> =
+        sfc.steps.iter().enumerate().map(|(id, step)| (step.name.to_lowercase(), id)).collect();
+    let get_ids = |steps: &[(String, SourceLocation)]| {
+        let mut ids = steps.iter().map(|(name, _)| ids[&name.to_lowercase()]).collect::<Vec<_>>();
+        ids.sort_unstable();
+        ids.dedup();
+        ids
+    };
+    let transitions =
+        sfc.transitions.iter().map(|it| (it, get_ids(&it.from), get_ids(&it.to))).collect::<Vec<_>>();
+
+    let initial = sfc.steps.iter().enumerate().filter(|(_, it)| it.initial).map(|(id, _)| id).collect();
+    let mut markings: FxHashSet<BTreeSet<usize>> = FxHashSet::default();
+    let mut pending = VecDeque::from([initial]);
+    let mut reached = vec![false; sfc.steps.len()];
+    let mut unsafe_transitions = FxHashSet::default();
+    while let Some(marking) = pending.pop_front() {
+        if markings.contains(&marking) {
+            continue;
+        }
+        if markings.len() == MAX_SFC_MARKINGS {
+            // the chart is too large to be explored completely, unreached steps may still be reachable
+            return;
+        }
+
+        marking.iter().for_each(|it| reached[*it] = true);
+        for (index, (_, from, to)) in transitions.iter().enumerate() {
+            if !from.iter().all(|it| marking.contains(it)) {
+                continue;
+            }
+
+            let mut next = marking.clone();
+            from.iter().for_each(|it| {
+                next.remove(it);
+            });
+            for step in to {
+                if !next.insert(*step) {
+                    unsafe_transitions.insert(index);
+                }
+            }
+            pending.push_back(next);
+        }
+        markings.insert(marking);
+    }
+
+    for (index, (transition, ..)) in transitions.iter().enumerate() {
+        if unsafe_transitions.contains(&index) {
+            validator.push_diagnostic(
+                Diagnostic::new(
+                    "Transition activates a step which may still be active from a parallel branch",
+                )
+                .with_error_code("E126")
+                .with_location(&transition.location),
+            );
+        }
+    }
+
+    for (step, _) in sfc.steps.iter().zip(reached).filter(|(_, reached)| !reached) {
+        validator.push_diagnostic(
+            Diagnostic::new(format!("Step '{}' is unreachable", step.name))
+                .with_error_code("E127")
+                .with_location(&step.name_location),
+        );
+    }
+}
+
 pub fn validate_action_container(validator: &mut Validator, implementation: &Implementation) {
     if implementation.pou_type == PouType::Action && implementation.type_name == "__unknown__" {
         validator.push_diagnostic(
@@ -5,11 +5,14 @@ use plc_ast::ast::Assignment;
 use plc_ast::control_statements::ForLoopStatement;
 use plc_ast::{
     ast::{
-        flatten_expression_list, AstNode, AstStatement, BinaryExpression, CallStatement, DirectAccess,
-        DirectAccessType, JumpStatement, Operator, ReferenceAccess, UnaryExpression,
+        flatten_expression_list, AccessModifier, AstNode, AstStatement, BinaryExpression, CallStatement,
+        DirectAccess, DirectAccessType, JumpStatement, Operator, PropertyKind, ReferenceAccess,
+        ReferenceExpr, UnaryExpression,
     },
-    control_statements::{AstControlStatement, ConditionalBlock},
+    control_statements::{AstControlStatement, ConditionalBlock, ReturnStatement, TryStatement},
     literals::{Array, AstLiteral, StringValue},
+    try_from,
+    visitor::{AstVisitor, Walker},
 };
 use plc_diagnostics::diagnostics::Diagnostic;
 use plc_source::source_location::SourceLocation;
@@ -83,7 +86,23 @@ pub fn visit_statement<T: AnnotationMap>(
             visit_all_statements!(validator, context, &data.start, &data.end);
         }
         AstStatement::Assignment(data) => {
-            visit_statement(validator, &data.left, context);
+            if let Some(StatementAnnotation::Property { qualified_name, .. }) =
+                context.annotations.get(&data.left)
+            {
+                // writing to a property, only its base is read
+                if let Some(base) = try_from!(data.left, ReferenceExpr).and_then(|it| it.base.as_deref()) {
+                    visit_statement(validator, base, context);
+                }
+                validate_property_access(
+                    validator,
+                    context,
+                    qualified_name,
+                    PropertyKind::Set,
+                    data.left.as_ref(),
+                );
+            } else {
+                visit_statement(validator, &data.left, context);
+            }
             visit_statement(validator, &data.right, context);
 
             validate_assignment(validator, &data.right, Some(&data.left), &statement.location, context);
@@ -132,11 +151,40 @@ pub fn visit_statement<T: AnnotationMap>(
         // AstStatement::ReturnStatement { location, id } => (),
         // AstStatement::LiteralNull { location, id } => (),
         AstStatement::ParenExpression(expr) => visit_statement(validator, expr, context),
+        AstStatement::This | AstStatement::Super => {
+            validate_instance_reference(validator, statement, context)
+        }
         _ => {}
     }
     validate_type_nature(validator, statement, context);
 }
 
+/// Validates that `THIS` is only used within a FUNCTION_BLOCK or CLASS and `SUPER` only within one
+/// extending another FUNCTION_BLOCK or CLASS
+fn validate_instance_reference<T: AnnotationMap>(
+    validator: &mut Validator,
+    statement: &AstNode,
+    context: &ValidationContext<T>,
+) {
+    let instance = context.qualifier.and_then(|it| context.index.find_instance_pou(it));
+    match (statement.get_stmt(), instance) {
+        (AstStatement::This, None) => validator.push_diagnostic(
+            Diagnostic::new("`THIS` can only be used within a FUNCTION_BLOCK or CLASS")
+                .with_error_code("E119")
+                .with_location(statement),
+        ),
+        (AstStatement::Super, _) if instance.and_then(PouIndexEntry::get_super_class).is_none() => validator
+            .push_diagnostic(
+                Diagnostic::new(
+                    "`SUPER` can only be used within a FUNCTION_BLOCK or CLASS extending another POU",
+                )
+                .with_error_code("E119")
+                .with_location(statement),
+            ),
+        _ => {}
+    }
+}
+
 fn validate_reference_expression<T: AnnotationMap>(
     access: &ReferenceAccess,
     validator: &mut Validator,
@@ -321,6 +369,91 @@ fn validate_control_statement<T: AnnotationMap>(
         AstControlStatement::Case(stmt) => {
             validate_case_statement(validator, &stmt.selector, &stmt.case_blocks, &stmt.else_block, context);
         }
+        AstControlStatement::Try(stmt) => {
+            validate_try_statement(validator, stmt, context);
+            stmt.body.iter().for_each(|s| visit_statement(validator, s, context));
+            if let Some(catch_block) = &stmt.catch_block {
+                catch_block.body.iter().for_each(|s| visit_statement(validator, s, context));
+            }
+            stmt.finally_block.iter().for_each(|s| visit_statement(validator, s, context));
+        }
+    }
+}
+
+/// Validates the exception variable of a `__CATCH` clause and reports the statements leaving the `__TRY`
+/// block, which would leave its exception frame behind
+fn validate_try_statement<T: AnnotationMap>(
+    validator: &mut Validator,
+    stmt: &TryStatement,
+    context: &ValidationContext<T>,
+) {
+    if let Some(exception) = stmt.catch_block.as_ref().and_then(|it| it.exception.as_ref()) {
+        let exception_type = context.annotations.get_type_or_void(exception, context.index);
+        let is_variable =
+            matches!(context.annotations.get(exception), Some(StatementAnnotation::Variable { .. }));
+        if !is_variable
+            || !context
+                .index
+                .get_intrinsic_type_by_name(exception_type.get_name())
+                .get_type_information()
+                .is_int()
+        {
+            validator.push_diagnostic(
+                Diagnostic::new(format!(
+                    "Expected a variable of type __EXCEPTION_CODE to receive the exception, found {}",
+                    validator.context.slice(&exception.get_location())
+                ))
+                .with_error_code("E130")
+                .with_location(exception.as_ref()),
+            );
+        }
+    }
+
+    let mut exits = TryBlockExits::default();
+    stmt.body.iter().for_each(|s| exits.visit(s));
+    for (keyword, location) in exits.exits {
+        validator.push_diagnostic(
+            Diagnostic::new(format!("{keyword} cannot leave a __TRY block"))
+                .with_error_code("E130")
+                .with_location(location),
+        );
+    }
+}
+
+/// Collects the statements leaving a `__TRY` block: `RETURN` as well as `EXIT` and `CONTINUE` of an enclosing loop
+#[derive(Default)]
+struct TryBlockExits {
+    loop_depth: usize,
+    exits: Vec<(&'static str, SourceLocation)>,
+}
+
+impl AstVisitor for TryBlockExits {
+    fn visit_control_statement(&mut self, stmt: &AstControlStatement, _node: &AstNode) {
+        let is_loop = matches!(
+            stmt,
+            AstControlStatement::ForLoop(_)
+                | AstControlStatement::WhileLoop(_)
+                | AstControlStatement::RepeatLoop(_)
+        );
+        self.loop_depth += usize::from(is_loop);
+        stmt.walk(self);
+        self.loop_depth -= usize::from(is_loop);
+    }
+
+    fn visit_exit_statement(&mut self, node: &AstNode) {
+        if self.loop_depth == 0 {
+            self.exits.push(("EXIT", node.get_location()));
+        }
+    }
+
+    fn visit_continue_statement(&mut self, node: &AstNode) {
+        if self.loop_depth == 0 {
+            self.exits.push(("CONTINUE", node.get_location()));
+        }
+    }
+
+    fn visit_return_statement(&mut self, _stmt: &ReturnStatement, node: &AstNode) {
+        self.exits.push(("RETURN", node.get_location()));
     }
 }
 
@@ -484,9 +617,17 @@ fn validate_reference<T: AnnotationMap>(
 
     match context.annotations.get(statement) {
         Some(StatementAnnotation::Variable { qualified_name, argument_type, .. }) => {
+            let access = context
+                .index
+                .find_fully_qualified_variable(qualified_name)
+                .and_then(VariableIndexEntry::get_access);
+            if let Some((access, (owner, _))) = access.zip(qualified_name.rsplit_once('.')) {
+                // members of a block with an access modifier are restricted by the modifier instead
+                validate_access_modifier(validator, context, owner, qualified_name, access, location);
+            }
             // check if we're accessing a private variable AND the variable's qualifier is not the
             // POU we're accessing it from
-            if argument_type.is_private()
+            else if argument_type.is_private()
                 && context
                     .qualifier
                     .and_then(|qualifier| context.index.find_pou(qualifier))
@@ -495,6 +636,10 @@ fn validate_reference<T: AnnotationMap>(
                         !qualified_name.starts_with(pou)
                             && !qualified_name.starts_with(container)
                             && !context.index.is_init_function(pou)
+                            // members of a base class are accessible within the derived classes
+                            && !qualified_name
+                                .rsplit_once('.')
+                                .is_some_and(|(owner, _)| context.index.is_derived_from(container, owner))
                     })
             {
                 validator.push_diagnostic(
@@ -520,10 +665,112 @@ fn validate_reference<T: AnnotationMap>(
                 );
             }
         }
+        Some(StatementAnnotation::Function { qualified_name, .. }) => {
+            let Some(method) = context.index.find_pou(qualified_name).filter(|it| it.is_method()) else {
+                return;
+            };
+            if let Some(access) = method.find_implementation(context.index).and_then(|it| it.get_access()) {
+                validate_access_modifier(
+                    validator,
+                    context,
+                    method.get_container(),
+                    qualified_name,
+                    access,
+                    location,
+                );
+            }
+        }
+        Some(StatementAnnotation::Property { qualified_name, .. }) => {
+            validate_property_access(validator, context, qualified_name, PropertyKind::Get, location);
+        }
         _ => (),
     }
 }
 
+/// Validates that a member declared with an access modifier in `owner` may be accessed from the current POU.
+/// `PRIVATE` members are only accessible within `owner`, `PROTECTED` members also within POUs derived from it.
+/// `INTERNAL` members of a POU linked from a library cannot be accessed by the project using it
+fn validate_access_modifier<T: AnnotationMap>(
+    validator: &mut Validator,
+    context: &ValidationContext<T>,
+    owner: &str,
+    member: &str,
+    access: &AccessModifier,
+    location: &SourceLocation,
+) {
+    let Some(pou) = context.qualifier.and_then(|it| context.index.find_pou(it)) else {
+        return;
+    };
+    let container = pou.get_container();
+    let is_owner = container.eq_ignore_ascii_case(owner) || context.index.is_init_function(pou.get_name());
+    let is_accessible = match access {
+        AccessModifier::Public => true,
+        AccessModifier::Private => is_owner,
+        AccessModifier::Protected => is_owner || context.index.is_derived_from(container, owner),
+        AccessModifier::Internal => {
+            is_owner || context.index.find_pou(owner).is_none_or(|it| is_same_library(pou, it))
+        }
+    };
+
+    if !is_accessible {
+        validator.push_diagnostic(
+            Diagnostic::new(format!("Illegal access to {access} member `{member}` from `{container}`"))
+                .with_error_code("E134")
+                .with_location(location),
+        );
+    }
+}
+
+/// Returns whether both POUs belong to the same library or project. The POUs of the project are not external,
+/// those of a library are declared external in the library's own unit (e.g. its header).
+fn is_same_library(pou: &PouIndexEntry, other: &PouIndexEntry) -> bool {
+    match (pou.is_external(), other.is_external()) {
+        (false, false) => true,
+        (true, true) => pou.get_location().get_file_name() == other.get_location().get_file_name(),
+        _ => false,
+    }
+}
+
+/// Validates that the referenced property defines the accessor needed to read or write it
+fn validate_property_access<T: AnnotationMap>(
+    validator: &mut Validator,
+    context: &ValidationContext<T>,
+    qualified_name: &str,
+    kind: PropertyKind,
+    location: impl Into<SourceLocation>,
+) {
+    let Some(property) = context.index.find_qualified_property(qualified_name) else {
+        return;
+    };
+
+    let location = location.into();
+    if let Some(accessor) = property.find_accessor(context.index, kind) {
+        if let Some(access) = accessor.find_implementation(context.index).and_then(|it| it.get_access()) {
+            validate_access_modifier(
+                validator,
+                context,
+                &property.parent_name,
+                &property.qualified_name,
+                access,
+                &location,
+            );
+        }
+    } else {
+        let access = match kind {
+            PropertyKind::Get => "read",
+            PropertyKind::Set => "written",
+        };
+        validator.push_diagnostic(
+            Diagnostic::new(format!(
+                "Property `{}` cannot be {access}, it does not define a {kind} accessor",
+                property.get_name()
+            ))
+            .with_error_code("E115")
+            .with_location(location),
+        );
+    }
+}
+
 fn visit_array_access<T: AnnotationMap>(
     validator: &mut Validator,
     reference: &AstNode,
@@ -965,6 +1212,7 @@ fn validate_assignment<T: AnnotationMap>(
     if let (Some(right_type), Some(left_type)) = (right_type, left_type) {
         // implicit call parameter assignments are annotated to auto_deref pointers for ´ByRef` parameters
         // we need the inner type
+        let is_by_ref = left_type.get_type_information().is_auto_deref();
         let left_type = if let DataTypeInformation::Pointer { inner_type_name, auto_deref: Some(_), .. } =
             left_type.get_type_information()
         {
@@ -973,6 +1221,20 @@ fn validate_assignment<T: AnnotationMap>(
             left_type
         };
 
+        // interface <- instance assignments are valid if the instance's POU implements the interface
+        if left_type.get_type_information().is_interface()
+            && !right_type.get_type_information().is_interface()
+        {
+            validate_interface_assignment(validator, context, location, left_type, right_type, is_by_ref);
+            return;
+        }
+
+        // base <- derived assignments are valid for references, since a derived instance starts with the
+        // instance of its base
+        if is_base_reference_assignment(context.index, left_type, right_type, is_by_ref) {
+            return;
+        }
+
         // VLA <- ARRAY assignments are valid when the array is passed to a function expecting a VLA, but
         // are no longer allowed inside a POU body
         if left_type.is_vla() && right_type.is_array() && context.is_call() {
@@ -1091,6 +1353,66 @@ pub(crate) fn validate_enum_variant_assignment<T: AnnotationMap>(
     };
 }
 
+fn validate_interface_assignment<T: AnnotationMap>(
+    validator: &mut Validator,
+    context: &ValidationContext<T>,
+    location: &SourceLocation,
+    left_type: &DataType,
+    right_type: &DataType,
+    is_by_ref: bool,
+) {
+    let is_implemented = right_type.get_type_information().is_struct()
+        && context
+            .index
+            .get_implemented_interfaces(right_type.get_name())
+            .iter()
+            .any(|it| it.name.eq_ignore_ascii_case(left_type.get_name()));
+
+    if !is_implemented {
+        validator.push_diagnostic(
+            Diagnostic::new(format!(
+                "Invalid assignment: `{}` does not implement interface `{}`",
+                validator.get_type_name_or_slice(right_type),
+                left_type.get_name()
+            ))
+            .with_error_code("E037")
+            .with_location(location),
+        );
+    } else if is_by_ref {
+        // the reference to the instance only exists for the duration of the call, it can't be written to
+        validator.push_diagnostic(
+            Diagnostic::new(format!(
+                "Invalid assignment: `{}` cannot be passed by reference as interface `{}`",
+                validator.get_type_name_or_slice(right_type),
+                left_type.get_name()
+            ))
+            .with_error_code("E037")
+            .with_location(location),
+        );
+    }
+}
+
+/// Returns true if a reference to an instance of `right_type` is assigned to a reference to one of its base
+/// classes, e.g. a derived instance passed to a `VAR_IN_OUT` of its base type or `REF(derived)` assigned
+/// to a `REF_TO base`
+fn is_base_reference_assignment(
+    index: &Index,
+    left_type: &DataType,
+    right_type: &DataType,
+    is_by_ref: bool,
+) -> bool {
+    let (left, right) = match (left_type.get_type_information(), right_type.get_type_information()) {
+        (
+            DataTypeInformation::Pointer { inner_type_name: left, .. },
+            DataTypeInformation::Pointer { inner_type_name: right, .. },
+        ) => (left.as_str(), right.as_str()),
+        _ if is_by_ref => (left_type.get_name(), right_type.get_name()),
+        _ => return false,
+    };
+
+    index.is_derived_from(right, left)
+}
+
 fn validate_variable_length_array_assignment<T: AnnotationMap>(
     validator: &mut Validator,
     context: &ValidationContext<T>,
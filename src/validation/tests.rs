@@ -1,17 +1,23 @@
 // Copyright (c) 2020 Ghaith Hachem and Mathias Rieder
+mod access_modifier_validation_tests;
 mod array_validation_test;
 mod assignment_validation_tests;
 mod bitaccess_validation_test;
 mod builtin_validation_tests;
+mod configuration_validation_tests;
 mod duplicates_validation_test;
 mod enum_validation_test;
 mod generic_validation_tests;
+mod inheritance_validation_tests;
 mod interface_validation_tests;
 mod literals_validation_tests;
+mod namespace_validation_tests;
 mod naming_validation_test;
 mod pou_validation_tests;
+mod property_validation_tests;
 mod recursive_validation_tests;
 mod reference_resolve_tests;
+mod sfc_validation_tests;
 mod statement_validation_tests;
 mod variable_length_array_test;
 mod variable_validation_tests;
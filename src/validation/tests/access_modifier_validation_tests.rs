@@ -0,0 +1,315 @@
+use insta::assert_snapshot;
+
+use crate::test_utils::tests::parse_and_validate_buffered;
+
+#[test]
+fn private_methods_can_only_be_called_from_the_declaring_pou() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        FUNCTION_BLOCK fb
+            METHOD PRIVATE secret : INT
+                secret := 1;
+            END_METHOD
+
+            METHOD PUBLIC reveal : INT
+                reveal := secret();
+            END_METHOD
+
+            secret();
+        END_FUNCTION_BLOCK
+
+        FUNCTION_BLOCK derived EXTENDS fb
+            METHOD PUBLIC peek : INT
+                peek := secret();
+            END_METHOD
+        END_FUNCTION_BLOCK
+
+        PROGRAM mainProg
+            VAR
+                instance : fb;
+                x : INT;
+            END_VAR
+            x := instance.reveal();
+            x := instance.secret();
+        END_PROGRAM
+        ",
+    );
+
+    assert_snapshot!(diagnostics, @r###"
+    error[E134]: Illegal access to PRIVATE member `fb.secret` from `derived`
+       ┌─ <internal>:16:25
+       │
+    16 │                 peek := secret();
+       │                         ^^^^^^ Illegal access to PRIVATE member `fb.secret` from `derived`
+
+    error[E134]: Illegal access to PRIVATE member `fb.secret` from `mainProg`
+       ┌─ <internal>:26:27
+       │
+    26 │             x := instance.secret();
+       │                           ^^^^^^ Illegal access to PRIVATE member `fb.secret` from `mainProg`
+    "###);
+}
+
+#[test]
+fn protected_methods_can_only_be_called_within_the_inheritance_chain() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        CLASS base
+            METHOD PROTECTED helper : INT
+                helper := 1;
+            END_METHOD
+        END_CLASS
+
+        CLASS derived EXTENDS base
+            METHOD PUBLIC run : INT
+                run := helper() + SUPER^.helper();
+            END_METHOD
+        END_CLASS
+
+        CLASS grandchild EXTENDS derived
+            METHOD PUBLIC check : INT
+                check := THIS^.helper();
+            END_METHOD
+        END_CLASS
+
+        PROGRAM mainProg
+            VAR
+                instance : derived;
+                x : INT;
+            END_VAR
+            x := instance.run();
+            x := instance.helper();
+        END_PROGRAM
+        ",
+    );
+
+    assert_snapshot!(diagnostics, @r###"
+    error[E134]: Illegal access to PROTECTED member `base.helper` from `mainProg`
+       ┌─ <internal>:26:27
+       │
+    26 │             x := instance.helper();
+       │                           ^^^^^^ Illegal access to PROTECTED member `base.helper` from `mainProg`
+    "###);
+}
+
+#[test]
+fn methods_without_access_modifier_are_not_restricted() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        FUNCTION_BLOCK fb
+            METHOD foo : INT
+                foo := 1;
+            END_METHOD
+        END_FUNCTION_BLOCK
+
+        FUNCTION main : INT
+            VAR
+                instance : fb;
+            END_VAR
+            main := instance.foo();
+        END_FUNCTION
+        ",
+    );
+
+    assert!(diagnostics.is_empty(), "Expected no diagnostics but got {diagnostics}");
+}
+
+#[test]
+fn internal_methods_of_library_pous_cannot_be_called_from_the_project() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        {external}
+        FUNCTION_BLOCK libFb
+            METHOD INTERNAL internalHelper : INT
+            END_METHOD
+
+            METHOD PUBLIC api : INT
+            END_METHOD
+        END_FUNCTION_BLOCK
+
+        FUNCTION_BLOCK projectFb
+            METHOD INTERNAL projectHelper : INT
+                projectHelper := 1;
+            END_METHOD
+        END_FUNCTION_BLOCK
+
+        PROGRAM mainProg
+            VAR
+                lib : libFb;
+                project : projectFb;
+                x : INT;
+            END_VAR
+            x := lib.api();
+            x := lib.internalHelper();
+            x := project.projectHelper();
+        END_PROGRAM
+        ",
+    );
+
+    assert_snapshot!(diagnostics, @r###"
+    error[E134]: Illegal access to INTERNAL member `libFb.internalHelper` from `mainProg`
+       ┌─ <internal>:24:22
+       │
+    24 │             x := lib.internalHelper();
+       │                      ^^^^^^^^^^^^^^ Illegal access to INTERNAL member `libFb.internalHelper` from `mainProg`
+    "###);
+}
+
+#[test]
+fn class_variables_are_restricted_by_the_access_modifier_of_their_block() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        CLASS base
+            VAR PUBLIC
+                visible : INT;
+            END_VAR
+            VAR PROTECTED
+                shared : INT;
+            END_VAR
+            VAR PRIVATE
+                hidden : INT;
+            END_VAR
+
+            METHOD PUBLIC touch
+                visible := shared + hidden;
+            END_METHOD
+        END_CLASS
+
+        CLASS derived EXTENDS base
+            METHOD PUBLIC update
+                visible := shared;
+                hidden := 1;
+            END_METHOD
+        END_CLASS
+
+        PROGRAM mainProg
+            VAR
+                instance : derived;
+            END_VAR
+            instance.visible := 1;
+            instance.shared := 2;
+            instance.hidden := 3;
+        END_PROGRAM
+        ",
+    );
+
+    assert_snapshot!(diagnostics, @r###"
+    error[E134]: Illegal access to PRIVATE member `base.hidden` from `derived`
+       ┌─ <internal>:21:17
+       │
+    21 │                 hidden := 1;
+       │                 ^^^^^^ Illegal access to PRIVATE member `base.hidden` from `derived`
+
+    error[E134]: Illegal access to PROTECTED member `base.shared` from `mainProg`
+       ┌─ <internal>:30:22
+       │
+    30 │             instance.shared := 2;
+       │                      ^^^^^^ Illegal access to PROTECTED member `base.shared` from `mainProg`
+
+    error[E134]: Illegal access to PRIVATE member `base.hidden` from `mainProg`
+       ┌─ <internal>:31:22
+       │
+    31 │             instance.hidden := 3;
+       │                      ^^^^^^ Illegal access to PRIVATE member `base.hidden` from `mainProg`
+    "###);
+}
+
+#[test]
+fn property_accessors_are_restricted_by_the_access_modifier_of_their_property() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        FUNCTION_BLOCK fb
+            VAR
+                value : INT;
+            END_VAR
+
+            PROPERTY PRIVATE secret : INT
+                GET
+                    secret := value;
+                END_GET
+                SET
+                    value := secret;
+                END_SET
+            END_PROPERTY
+
+            PROPERTY PUBLIC visible : INT
+                GET
+                    visible := secret;
+                END_GET
+            END_PROPERTY
+        END_FUNCTION_BLOCK
+
+        FUNCTION main
+            VAR
+                instance : fb;
+                x : INT;
+            END_VAR
+            x := instance.visible;
+            x := instance.secret;
+            instance.secret := 5;
+        END_FUNCTION
+        ",
+    );
+
+    assert_snapshot!(diagnostics, @r###"
+    error[E134]: Illegal access to PRIVATE member `fb.secret` from `main`
+       ┌─ <internal>:29:27
+       │
+    29 │             x := instance.secret;
+       │                           ^^^^^^ Illegal access to PRIVATE member `fb.secret` from `main`
+
+    error[E134]: Illegal access to PRIVATE member `fb.secret` from `main`
+       ┌─ <internal>:30:13
+       │
+    30 │             instance.secret := 5;
+       │             ^^^^^^^^^^^^^^^ Illegal access to PRIVATE member `fb.secret` from `main`
+    "###);
+}
+
+#[test]
+fn access_modifiers_are_only_allowed_on_var_blocks_of_classes() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        FUNCTION_BLOCK fb
+            VAR PUBLIC
+                x : INT;
+            END_VAR
+            VAR_INPUT PRIVATE
+                y : INT;
+            END_VAR
+        END_FUNCTION_BLOCK
+
+        CLASS cls
+            VAR PRIVATE
+                z : INT;
+            END_VAR
+
+            METHOD PUBLIC foo
+                VAR PROTECTED
+                    w : INT;
+                END_VAR
+            END_METHOD
+        END_CLASS
+        ",
+    );
+
+    assert_snapshot!(diagnostics, @r###"
+    error[E135]: The access modifier PUBLIC is only allowed on VAR blocks of classes
+      ┌─ <internal>:3:13
+      │
+    3 │             VAR PUBLIC
+      │             ^^^ The access modifier PUBLIC is only allowed on VAR blocks of classes
+
+    error[E135]: The access modifier PRIVATE is only allowed on VAR blocks of classes
+      ┌─ <internal>:6:13
+      │
+    6 │             VAR_INPUT PRIVATE
+      │             ^^^^^^^^^ The access modifier PRIVATE is only allowed on VAR blocks of classes
+
+    error[E135]: The access modifier PROTECTED is only allowed on VAR blocks of classes
+       ┌─ <internal>:17:17
+       │
+    17 │                 VAR PROTECTED
+       │                 ^^^ The access modifier PROTECTED is only allowed on VAR blocks of classes
+    "###);
+}
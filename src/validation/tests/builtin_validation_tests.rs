@@ -103,3 +103,62 @@ fn comparison_builtins_called_with_invalid_param_count() {
 
     assert_snapshot!(&diagnostics);
 }
+
+#[test]
+fn dynamic_memory_builtins_called_with_invalid_arguments() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        TYPE Point : STRUCT
+            x, y : DINT;
+        END_STRUCT END_TYPE
+
+        FUNCTION main : DINT
+        VAR
+            x : DINT;
+            p : REF_TO Point;
+        END_VAR
+            p := __NEW(Point);      // OK
+            p := __NEW(Point, x);   // OK
+            __DELETE(p);            // OK
+            p := __NEW(x);
+            p := __NEW(Point, 2.5);
+            p := __NEW();
+            __DELETE(x);
+        END_FUNCTION
+       ",
+    );
+
+    assert_snapshot!(&diagnostics);
+}
+
+#[test]
+fn reflection_builtins_called_with_invalid_arguments() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        TYPE Point : STRUCT
+            x, y : DINT;
+        END_STRUCT END_TYPE
+
+        FUNCTION main : DINT
+        VAR
+            x : DINT;
+            p : Point;
+            info : REF_TO __TYPE_INFO;
+            member : REF_TO __TYPE_MEMBER;
+            id : UDINT;
+        END_VAR
+            info := __TYPEINFO(p);          // OK
+            info := __TYPEINFO(Point);      // OK
+            member := __MEMBER_INFO(p, x);  // OK
+            main := __MEMBER_COUNT(x);      // OK
+            info := __TYPEINFO(x);
+            member := __MEMBER_INFO(p);
+            member := __MEMBER_INFO(x, 1);
+            main := __MEMBER_COUNT(p, x);
+            id := __TYPEID();
+        END_FUNCTION
+       ",
+    );
+
+    assert_snapshot!(&diagnostics);
+}
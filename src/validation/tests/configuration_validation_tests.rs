@@ -0,0 +1,128 @@
+use insta::assert_snapshot;
+
+use crate::test_utils::tests::parse_and_validate_buffered;
+
+#[test]
+fn valid_configuration_does_not_report() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        PROGRAM MainPrg END_PROGRAM
+        PROGRAM AlarmPrg END_PROGRAM
+
+        VAR_GLOBAL CONSTANT
+            cycle : TIME := T#10ms;
+            highest : UINT := 1;
+        END_VAR
+
+        CONFIGURATION Plant
+            VAR_GLOBAL
+                alarm : BOOL;
+            END_VAR
+            RESOURCE Cpu ON PLC
+                TASK fast (INTERVAL := cycle, PRIORITY := highest);
+                TASK onAlarm (SINGLE := alarm, PRIORITY := 0);
+                PROGRAM main WITH fast : MainPrg;
+                PROGRAM alarms WITH onAlarm : AlarmPrg;
+            END_RESOURCE
+        END_CONFIGURATION
+        ",
+    );
+
+    assert!(diagnostics.is_empty(), "Expected no diagnostics but got {diagnostics}");
+}
+
+#[test]
+fn invalid_task_declarations_are_reported() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        VAR_GLOBAL
+            counter : INT;
+            cycle : TIME;
+        END_VAR
+
+        CONFIGURATION Plant
+            TASK noPriority (INTERVAL := T#10ms);
+            TASK intInterval (INTERVAL := 10, PRIORITY := 1);
+            TASK variableInterval (INTERVAL := cycle, PRIORITY := 1);
+            TASK negativePriority (INTERVAL := T#10ms, PRIORITY := -1);
+            TASK intTrigger (SINGLE := counter, PRIORITY := 1);
+            TASK unknownTrigger (SINGLE := unknown, PRIORITY := 1);
+            TASK untriggered (PRIORITY := 1);
+        END_CONFIGURATION
+        ",
+    );
+
+    assert_snapshot!(diagnostics);
+}
+
+#[test]
+fn invalid_program_configurations_are_reported() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        PROGRAM MainPrg END_PROGRAM
+        FUNCTION_BLOCK Fb END_FUNCTION_BLOCK
+
+        CONFIGURATION Plant
+            RESOURCE Cpu ON PLC
+                TASK fast (INTERVAL := T#10ms, PRIORITY := 1);
+                PROGRAM main WITH fast : MainPrg;
+                PROGRAM backup WITH fast : MainPrg;
+                PROGRAM unassigned : MainPrg;
+                PROGRAM fb WITH fast : Fb;
+                PROGRAM unknown WITH fast : Unknown;
+            END_RESOURCE
+            RESOURCE Io ON Coupler
+                PROGRAM io WITH fast : MainPrg;
+            END_RESOURCE
+        END_CONFIGURATION
+        ",
+    );
+
+    assert_snapshot!(diagnostics);
+}
+
+#[test]
+fn duplicate_tasks_and_program_instances_are_reported() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        PROGRAM MainPrg END_PROGRAM
+        PROGRAM OtherPrg END_PROGRAM
+
+        CONFIGURATION Plant
+            RESOURCE Cpu ON PLC
+                TASK fast (INTERVAL := T#10ms, PRIORITY := 1);
+                TASK fast (INTERVAL := T#20ms, PRIORITY := 2);
+                PROGRAM main WITH fast : MainPrg;
+                PROGRAM main WITH fast : OtherPrg;
+            END_RESOURCE
+            RESOURCE Io ON Coupler
+                TASK fast (INTERVAL := T#1ms, PRIORITY := 0);
+            END_RESOURCE
+        END_CONFIGURATION
+        ",
+    );
+
+    assert_snapshot!(diagnostics);
+}
+
+#[test]
+fn only_a_single_configuration_is_allowed() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        PROGRAM MainPrg END_PROGRAM
+        PROGRAM OtherPrg END_PROGRAM
+
+        CONFIGURATION Plant
+            TASK fast (INTERVAL := T#10ms, PRIORITY := 1);
+            PROGRAM main WITH fast : MainPrg;
+        END_CONFIGURATION
+
+        CONFIGURATION Line
+            TASK fast (INTERVAL := T#10ms, PRIORITY := 1);
+            PROGRAM other WITH fast : OtherPrg;
+        END_CONFIGURATION
+        ",
+    );
+
+    assert_snapshot!(diagnostics);
+}
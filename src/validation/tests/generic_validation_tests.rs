@@ -1629,3 +1629,25 @@ fn generic_call_with_formal_parameter() {
     let diagnostics = parse_and_validate_buffered(src);
     insta::assert_snapshot!(diagnostics);
 }
+
+#[test]
+fn generic_function_block_type_arguments_are_validated() {
+    let src = "
+    FUNCTION_BLOCK FB_Average<T: ANY_REAL>
+    VAR
+        sum : T;
+    END_VAR
+    END_FUNCTION_BLOCK
+
+    PROGRAM main
+    VAR
+        a : FB_Average<LREAL>; // okay
+        b : FB_Average<STRING>; // invalid type nature
+        c : FB_Average<REAL, INT>; // wrong number of type arguments
+    END_VAR
+    END_PROGRAM
+    ";
+
+    let diagnostics = parse_and_validate_buffered(src);
+    assert_snapshot!(&diagnostics);
+}
@@ -0,0 +1,401 @@
+use insta::assert_snapshot;
+
+use crate::test_utils::tests::parse_and_validate_buffered;
+
+#[test]
+fn overriding_methods_with_matching_signatures_are_valid() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        FUNCTION_BLOCK base
+            METHOD foo : DINT
+            VAR_INPUT
+                a : DINT;
+            END_VAR
+                foo := a;
+            END_METHOD
+        END_FUNCTION_BLOCK
+
+        FUNCTION_BLOCK derived EXTENDS base
+            METHOD OVERRIDE foo : DINT
+            VAR_INPUT
+                a : DINT;
+            END_VAR
+                foo := SUPER^.foo(a) + THIS^.foo(a);
+            END_METHOD
+        END_FUNCTION_BLOCK
+
+        FUNCTION main
+            VAR
+                instance : derived;
+                reference : REF_TO base;
+            END_VAR
+            reference := REF(instance);
+            reference^.foo(1);
+        END_FUNCTION
+        ",
+    );
+
+    assert!(diagnostics.is_empty(), "Expected no diagnostics but got {diagnostics}");
+}
+
+#[test]
+fn inherited_members_are_accessible_in_derived_pous() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        FUNCTION_BLOCK base
+        VAR
+            x : DINT;
+        END_VAR
+        END_FUNCTION_BLOCK
+
+        FUNCTION_BLOCK derived EXTENDS base
+            x := THIS^.x + SUPER^.x;
+        END_FUNCTION_BLOCK
+
+        FUNCTION main
+            VAR
+                instance : derived;
+            END_VAR
+            instance.x := 1;
+        END_FUNCTION
+        ",
+    );
+
+    assert_snapshot!(diagnostics);
+}
+
+#[test]
+fn override_without_overridden_method_is_reported() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        FUNCTION_BLOCK base
+        END_FUNCTION_BLOCK
+
+        FUNCTION_BLOCK derived EXTENDS base
+            METHOD OVERRIDE foo
+            END_METHOD
+        END_FUNCTION_BLOCK
+
+        CLASS standalone
+            METHOD OVERRIDE bar
+            END_METHOD
+        END_CLASS
+        ",
+    );
+
+    assert_snapshot!(diagnostics);
+}
+
+#[test]
+fn overriding_method_without_override_is_reported() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        CLASS base
+            METHOD foo
+            END_METHOD
+        END_CLASS
+
+        CLASS derived EXTENDS base
+        END_CLASS
+
+        CLASS grandchild EXTENDS derived
+            METHOD foo
+            END_METHOD
+        END_CLASS
+        ",
+    );
+
+    assert_snapshot!(diagnostics);
+}
+
+#[test]
+fn override_signature_mismatch_is_reported() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        FUNCTION_BLOCK base
+            METHOD foo : INT
+            VAR_INPUT
+                a : INT;
+            END_VAR
+            END_METHOD
+        END_FUNCTION_BLOCK
+
+        FUNCTION_BLOCK derived EXTENDS base
+            METHOD OVERRIDE foo : DINT
+            VAR_INPUT
+                a : DINT;
+                b : DINT;
+            END_VAR
+            END_METHOD
+        END_FUNCTION_BLOCK
+        ",
+    );
+
+    assert_snapshot!(diagnostics);
+}
+
+#[test]
+fn this_and_super_outside_of_derived_pous_are_reported() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        FUNCTION_BLOCK fb
+        VAR
+            x : DINT;
+        END_VAR
+            THIS^.x := 1;
+            SUPER^.x := 1;
+        END_FUNCTION_BLOCK
+
+        FUNCTION foo : DINT
+            foo := THIS^.x;
+        END_FUNCTION
+        ",
+    );
+
+    assert_snapshot!(diagnostics);
+}
+
+#[test]
+fn derived_instances_are_passed_as_base_references() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        FUNCTION_BLOCK base
+        END_FUNCTION_BLOCK
+
+        FUNCTION_BLOCK derived EXTENDS base
+        END_FUNCTION_BLOCK
+
+        FUNCTION_BLOCK unrelated
+        END_FUNCTION_BLOCK
+
+        FUNCTION foo
+        VAR_IN_OUT
+            instance : base;
+        END_VAR
+        END_FUNCTION
+
+        FUNCTION main
+            VAR
+                b : base;
+                d : derived;
+                u : unrelated;
+                reference : REF_TO derived;
+            END_VAR
+            foo(d);
+            foo(u);
+            reference := REF(b);
+        END_FUNCTION
+        ",
+    );
+
+    assert_snapshot!(diagnostics);
+}
+
+#[test]
+fn abstract_pous_cannot_be_instantiated() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        FUNCTION_BLOCK ABSTRACT shape
+        END_FUNCTION_BLOCK
+
+        CLASS ABSTRACT base
+        END_CLASS
+
+        FUNCTION_BLOCK square EXTENDS shape
+        END_FUNCTION_BLOCK
+
+        FUNCTION area
+        VAR_IN_OUT
+            s : shape;
+        END_VAR
+        END_FUNCTION
+
+        VAR_GLOBAL
+            globalShape : shape;
+        END_VAR
+
+        PROGRAM main
+            VAR
+                a : shape;
+                b : base;
+                shapes : ARRAY[0..2] OF shape;
+                reference : REF_TO shape;
+                s : square;
+            END_VAR
+            area(s);
+        END_PROGRAM
+        ",
+    );
+
+    assert_snapshot!(diagnostics);
+}
+
+#[test]
+fn abstract_methods_cannot_have_an_implementation() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        FUNCTION_BLOCK ABSTRACT shape
+            METHOD ABSTRACT area : REAL
+                area := 0.0;
+            END_METHOD
+
+            METHOD ABSTRACT perimeter : REAL
+            END_METHOD
+        END_FUNCTION_BLOCK
+        ",
+    );
+
+    assert_snapshot!(diagnostics);
+}
+
+#[test]
+fn abstract_methods_must_be_implemented_by_concrete_pous() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        FUNCTION_BLOCK ABSTRACT shape
+            METHOD ABSTRACT area : REAL
+            END_METHOD
+
+            METHOD ABSTRACT perimeter : REAL
+            END_METHOD
+        END_FUNCTION_BLOCK
+
+        FUNCTION_BLOCK ABSTRACT polygon EXTENDS shape
+            METHOD OVERRIDE perimeter : REAL
+            END_METHOD
+        END_FUNCTION_BLOCK
+
+        FUNCTION_BLOCK square EXTENDS polygon
+            METHOD OVERRIDE area : REAL
+            END_METHOD
+        END_FUNCTION_BLOCK
+
+        FUNCTION_BLOCK triangle EXTENDS polygon
+        END_FUNCTION_BLOCK
+
+        CLASS incomplete
+            METHOD ABSTRACT foo
+            END_METHOD
+        END_CLASS
+        ",
+    );
+
+    assert_snapshot!(diagnostics);
+}
+
+#[test]
+fn final_pous_cannot_be_extended() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        FUNCTION_BLOCK FINAL sealed
+        END_FUNCTION_BLOCK
+
+        FUNCTION_BLOCK derived EXTENDS sealed
+        END_FUNCTION_BLOCK
+
+        CLASS FINAL sealedClass
+        END_CLASS
+
+        CLASS derivedClass EXTENDS sealedClass
+        END_CLASS
+        ",
+    );
+
+    assert_snapshot!(diagnostics);
+}
+
+#[test]
+fn final_methods_cannot_be_overridden() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        FUNCTION_BLOCK base
+            METHOD FINAL foo
+            END_METHOD
+
+            METHOD bar
+            END_METHOD
+        END_FUNCTION_BLOCK
+
+        FUNCTION_BLOCK derived EXTENDS base
+            METHOD OVERRIDE foo
+            END_METHOD
+
+            METHOD FINAL OVERRIDE bar
+            END_METHOD
+        END_FUNCTION_BLOCK
+
+        FUNCTION_BLOCK leaf EXTENDS derived
+            METHOD OVERRIDE bar
+            END_METHOD
+        END_FUNCTION_BLOCK
+        ",
+    );
+
+    assert_snapshot!(diagnostics);
+}
+
+#[test]
+fn derived_structs_are_passed_as_base_references() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        TYPE header : STRUCT
+            id : INT;
+        END_STRUCT END_TYPE
+
+        TYPE telegram : STRUCT EXTENDS header
+            payload : DINT;
+        END_STRUCT END_TYPE
+
+        TYPE unrelated : STRUCT
+            id : INT;
+        END_STRUCT END_TYPE
+
+        FUNCTION foo
+        VAR_IN_OUT
+            instance : header;
+        END_VAR
+        END_FUNCTION
+
+        FUNCTION main
+            VAR
+                h : header;
+                t : telegram := (id := 1, payload := 2);
+                u : unrelated;
+                base_reference : REF_TO header;
+                reference : REF_TO telegram;
+            END_VAR
+            t.payload := t.id;
+            foo(t);
+            foo(u);
+            base_reference := REF(t);
+            reference := REF(h);
+        END_FUNCTION
+        ",
+    );
+
+    assert_snapshot!(diagnostics);
+}
+
+#[test]
+fn structs_can_only_extend_structs() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        FUNCTION_BLOCK fb
+        END_FUNCTION_BLOCK
+
+        TYPE extends_fb : STRUCT EXTENDS fb
+            a : INT;
+        END_STRUCT END_TYPE
+
+        TYPE extends_int : STRUCT EXTENDS INT
+            a : INT;
+        END_STRUCT END_TYPE
+
+        TYPE extends_unknown : STRUCT EXTENDS unknown
+            a : INT;
+        END_STRUCT END_TYPE
+        ",
+    );
+
+    assert_snapshot!(diagnostics);
+}
@@ -543,3 +543,50 @@ fn interfaces_with_same_method_name_but_different_signatures_parameter_list_decl
 
     "###);
 }
+
+#[test]
+fn assigning_instances_to_interface_variables() {
+    let source = r"
+    INTERFACE interfaceA
+        METHOD foo : INT
+        END_METHOD
+    END_INTERFACE
+
+    INTERFACE interfaceB
+    END_INTERFACE
+
+    FUNCTION_BLOCK fbA IMPLEMENTS interfaceA
+        METHOD foo : INT
+        END_METHOD
+    END_FUNCTION_BLOCK
+
+    FUNCTION_BLOCK fbB IMPLEMENTS interfaceB
+    END_FUNCTION_BLOCK
+
+    FUNCTION bar
+    VAR_INPUT
+        in : interfaceA;
+    END_VAR
+    VAR_IN_OUT
+        inOut : interfaceA;
+    END_VAR
+    END_FUNCTION
+
+    FUNCTION main
+    VAR
+        a : fbA;
+        b : fbB;
+        x : DINT;
+        itf : interfaceA;
+    END_VAR
+        itf := a;       // valid
+        bar(a, itf);    // valid
+        itf := b;
+        itf := x;
+        bar(b, a);
+    END_FUNCTION
+    ";
+
+    let diagnostics = parse_and_validate_buffered(source);
+    insta::assert_snapshot!(diagnostics);
+}
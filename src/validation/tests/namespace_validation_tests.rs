@@ -0,0 +1,89 @@
+use insta::assert_snapshot;
+
+use crate::test_utils::tests::parse_and_validate_buffered;
+
+#[test]
+fn equally_named_declarations_of_different_namespaces_do_not_collide() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        NAMESPACE vendorA
+            FUNCTION_BLOCK FB_Motor END_FUNCTION_BLOCK
+            TYPE speed : DINT; END_TYPE
+        END_NAMESPACE
+
+        NAMESPACE vendorB
+            FUNCTION_BLOCK FB_Motor END_FUNCTION_BLOCK
+            TYPE speed : DINT; END_TYPE
+        END_NAMESPACE
+
+        FUNCTION_BLOCK FB_Motor END_FUNCTION_BLOCK
+
+        FUNCTION main
+        VAR
+            a : vendorA.FB_Motor;
+            b : vendorB.FB_Motor;
+            c : FB_Motor;
+            d : vendorA.speed;
+        END_VAR
+        END_FUNCTION
+        ",
+    );
+
+    assert!(diagnostics.is_empty(), "Expected no diagnostics but got {diagnostics}");
+}
+
+#[test]
+fn equally_named_declarations_of_the_same_namespace_collide() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        NAMESPACE vendor
+            FUNCTION_BLOCK FB_Motor END_FUNCTION_BLOCK
+        END_NAMESPACE
+
+        NAMESPACE vendor
+            FUNCTION_BLOCK FB_Motor END_FUNCTION_BLOCK
+        END_NAMESPACE
+        ",
+    );
+
+    assert_snapshot!(diagnostics);
+}
+
+#[test]
+fn unknown_namespaces_cannot_be_imported() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        NAMESPACE vendor.drives
+            FUNCTION_BLOCK FB_Motor END_FUNCTION_BLOCK
+        END_NAMESPACE
+
+        USING vendor, vendor.drives, vendor.io;
+
+        FUNCTION main
+        USING drives;
+        END_FUNCTION
+        ",
+    );
+
+    assert_snapshot!(diagnostics);
+}
+
+#[test]
+fn unresolvable_qualified_references_are_reported() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        NAMESPACE vendor
+            FUNCTION_BLOCK FB_Motor END_FUNCTION_BLOCK
+        END_NAMESPACE
+
+        FUNCTION main
+        VAR
+            a : vendor.FB_Pump;
+            b : FB_Motor;
+        END_VAR
+        END_FUNCTION
+        ",
+    );
+
+    assert_snapshot!(diagnostics);
+}
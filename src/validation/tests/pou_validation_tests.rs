@@ -352,3 +352,95 @@ fn method_inout_arguments_are_not_optional() {
 
     "###);
 }
+
+#[test]
+fn init_arguments_require_an_fb_init_method() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        FUNCTION_BLOCK base
+            METHOD FB_INIT
+            VAR_INPUT
+                nAxis : DINT;
+            END_VAR
+            END_METHOD
+        END_FUNCTION_BLOCK
+
+        FUNCTION_BLOCK derived EXTENDS base
+        END_FUNCTION_BLOCK
+
+        PROGRAM main
+        VAR
+            a : base(nAxis := 3);
+            b : derived(nAxis := 3);
+        END_VAR
+        END_PROGRAM
+        ",
+    );
+
+    assert_snapshot!(diagnostics, @r###"
+    error[E141]: `b` cannot be declared with init arguments, `derived` does not declare an FB_INIT method
+       ┌─ <internal>:16:25
+       │
+    16 │             b : derived(nAxis := 3);
+       │                         ^^^^^^^^^^ `b` cannot be declared with init arguments, `derived` does not declare an FB_INIT method
+    "###);
+}
+
+#[test]
+fn lifecycle_methods_do_not_override_each_other() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        FUNCTION_BLOCK base
+            METHOD FB_INIT
+            END_METHOD
+            METHOD FB_EXIT : BOOL
+            END_METHOD
+        END_FUNCTION_BLOCK
+
+        FUNCTION_BLOCK derived EXTENDS base
+            METHOD FB_INIT
+            VAR_INPUT
+                nAxis : DINT;
+            END_VAR
+            END_METHOD
+            METHOD FB_EXIT
+            END_METHOD
+        END_FUNCTION_BLOCK
+        ",
+    );
+
+    assert_snapshot!(diagnostics, @"");
+}
+
+#[test]
+fn lifecycle_methods_outside_of_function_blocks_and_classes_are_never_called() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        PROGRAM main
+            METHOD FB_INIT
+            END_METHOD
+            METHOD FB_EXIT
+            END_METHOD
+        END_PROGRAM
+
+        CLASS cls
+            METHOD FB_REINIT
+            END_METHOD
+        END_CLASS
+        ",
+    );
+
+    assert_snapshot!(diagnostics, @r###"
+    warning[E142]: Method `main.FB_INIT` is never called implicitly, lifecycle methods are only supported in FUNCTION_BLOCKs and CLASSes
+      ┌─ <internal>:3:20
+      │
+    3 │             METHOD FB_INIT
+      │                    ^^^^^^^ Method `main.FB_INIT` is never called implicitly, lifecycle methods are only supported in FUNCTION_BLOCKs and CLASSes
+
+    warning[E142]: Method `main.FB_EXIT` is never called implicitly, lifecycle methods are only supported in FUNCTION_BLOCKs and CLASSes
+      ┌─ <internal>:5:20
+      │
+    5 │             METHOD FB_EXIT
+      │                    ^^^^^^^ Method `main.FB_EXIT` is never called implicitly, lifecycle methods are only supported in FUNCTION_BLOCKs and CLASSes
+    "###);
+}
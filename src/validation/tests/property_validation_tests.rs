@@ -0,0 +1,92 @@
+use insta::assert_snapshot;
+
+use crate::test_utils::tests::parse_and_validate_buffered;
+
+#[test]
+fn properties_with_accessors_can_be_read_and_written() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        FUNCTION_BLOCK fb
+            VAR
+                value : INT;
+            END_VAR
+
+            PROPERTY foo : INT
+                GET
+                    foo := value;
+                END_GET
+                SET
+                    value := foo;
+                END_SET
+            END_PROPERTY
+
+            foo := foo + 1;
+        END_FUNCTION_BLOCK
+
+        FUNCTION main
+            VAR
+                instance : fb;
+                x : INT;
+            END_VAR
+            instance.foo := 5;
+            x := instance.foo;
+        END_FUNCTION
+        ",
+    );
+
+    assert!(diagnostics.is_empty(), "Expected no diagnostics but got {diagnostics}");
+}
+
+#[test]
+fn missing_property_accessors_are_reported() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        FUNCTION_BLOCK fb
+            PROPERTY readOnly : INT
+                GET END_GET
+            END_PROPERTY
+
+            PROPERTY writeOnly : INT
+                SET END_SET
+            END_PROPERTY
+
+            readOnly := writeOnly;
+        END_FUNCTION_BLOCK
+
+        FUNCTION main
+            VAR
+                instance : fb;
+            END_VAR
+            instance.readOnly := instance.writeOnly;
+            instance.writeOnly := instance.readOnly;
+        END_FUNCTION
+        ",
+    );
+
+    assert_snapshot!(diagnostics);
+}
+
+#[test]
+fn property_assignments_are_type_checked() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        FUNCTION_BLOCK fb
+            PROPERTY foo : INT
+                GET END_GET
+                SET END_SET
+            END_PROPERTY
+        END_FUNCTION_BLOCK
+
+        FUNCTION main
+            VAR
+                instance : fb;
+                s : STRING;
+            END_VAR
+            instance.foo := s;
+            s := instance.foo;
+        END_FUNCTION
+        ",
+    );
+
+    assert_snapshot!(diagnostics);
+}
@@ -302,6 +302,40 @@ mod structs {
 
         assert_snapshot!(&diagnostics);
     }
+
+    #[test]
+    fn one_cycle_through_base_struct() {
+        let diagnostics = parse_and_validate_buffered(
+            "
+            TYPE A : STRUCT EXTENDS B
+                a_int : INT;
+            END_STRUCT END_TYPE
+
+            TYPE B : STRUCT
+                a : A;
+            END_STRUCT END_TYPE
+            ",
+        );
+
+        assert_snapshot!(&diagnostics);
+    }
+
+    #[test]
+    fn cyclic_struct_inheritance() {
+        let diagnostics = parse_and_validate_buffered(
+            "
+            TYPE A : STRUCT EXTENDS B
+                a_int : INT;
+            END_STRUCT END_TYPE
+
+            TYPE B : STRUCT EXTENDS A
+                b_int : INT;
+            END_STRUCT END_TYPE
+            ",
+        );
+
+        assert_snapshot!(&diagnostics);
+    }
 }
 
 mod arrays {
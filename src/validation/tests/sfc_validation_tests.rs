@@ -0,0 +1,110 @@
+use insta::assert_snapshot;
+
+use crate::test_utils::tests::parse_and_validate_buffered;
+
+const TON: &str = "
+    FUNCTION_BLOCK TON
+    VAR_INPUT
+        IN : BOOL;
+        PT : TIME;
+    END_VAR
+    VAR_OUTPUT
+        Q : BOOL;
+        ET : TIME;
+    END_VAR
+    END_FUNCTION_BLOCK
+";
+
+#[test]
+fn valid_sfc_produces_no_diagnostics() {
+    let diagnostics = parse_and_validate_buffered(&format!(
+        "
+        {TON}
+        FUNCTION_BLOCK mixer
+        VAR
+            start, filling : BOOL;
+        END_VAR
+            INITIAL_STEP idle : END_STEP
+            STEP fill : stir(N); filling(S); END_STEP
+            TRANSITION FROM idle TO fill := start; END_TRANSITION
+            TRANSITION FROM fill TO idle := fill.T > T#5s AND idle.X = FALSE; END_TRANSITION
+            ACTION stir:
+            END_ACTION
+        END_FUNCTION_BLOCK
+        "
+    ));
+
+    assert!(diagnostics.is_empty(), "Expected no diagnostics but got {diagnostics}");
+}
+
+#[test]
+fn invalid_sfc_networks_are_reported() {
+    let diagnostics = parse_and_validate_buffered(&format!(
+        "
+        {TON}
+        PROGRAM mixer
+            STEP fill : END_STEP
+            STEP fill : END_STEP
+            TRANSITION FROM fill TO drain := TRUE; END_TRANSITION
+        END_PROGRAM
+
+        FUNCTION foo : INT
+            INITIAL_STEP idle : END_STEP
+        END_FUNCTION
+        "
+    ));
+
+    assert_snapshot!(diagnostics);
+}
+
+#[test]
+fn unknown_actions_and_non_boolean_conditions_are_reported() {
+    let diagnostics = parse_and_validate_buffered(&format!(
+        "
+        {TON}
+        PROGRAM mixer
+        VAR
+            x : DINT;
+        END_VAR
+            INITIAL_STEP idle : stir(N); END_STEP
+            TRANSITION FROM idle TO idle := x; END_TRANSITION
+        END_PROGRAM
+        "
+    ));
+
+    assert_snapshot!(diagnostics);
+}
+
+#[test]
+fn unsafe_sequences_and_unreachable_steps_are_reported() {
+    let diagnostics = parse_and_validate_buffered(&format!(
+        "
+        {TON}
+        PROGRAM mixer
+        VAR
+            start, full, done : BOOL;
+        END_VAR
+            INITIAL_STEP idle : END_STEP
+            STEP fill : END_STEP
+            STEP stir : END_STEP
+            STEP drain : END_STEP
+            TRANSITION FROM idle TO (fill, stir) := start; END_TRANSITION
+            TRANSITION FROM fill TO idle := full; END_TRANSITION
+            TRANSITION FROM stir TO idle := done; END_TRANSITION
+        END_PROGRAM
+
+        PROGRAM joined
+        VAR
+            start, full, done : BOOL;
+        END_VAR
+            INITIAL_STEP idle : END_STEP
+            STEP fill : END_STEP
+            STEP stir : END_STEP
+            TRANSITION FROM idle TO (fill, stir) := start; END_TRANSITION
+            TRANSITION FROM (fill, stir) TO idle := full AND done; END_TRANSITION
+        END_PROGRAM
+        "
+    ));
+
+    assert_snapshot!(diagnostics);
+}
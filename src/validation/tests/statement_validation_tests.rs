@@ -1968,3 +1968,54 @@ fn builtin_math_functions_with_incompatible_literal_types() {
 
     "###);
 }
+
+#[test]
+fn catch_clause_expects_an_exception_code_variable() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        FUNCTION main : DINT
+        VAR
+            exc : __EXCEPTION_CODE;
+            code : DINT;
+            text : STRING;
+        END_VAR
+            __TRY __CATCH(exc) __ENDTRY
+            __TRY __CATCH(code) __ENDTRY
+            __TRY __CATCH(text) __ENDTRY
+            __TRY __CATCH(EXCEPTION_NULL_POINTER) __ENDTRY
+        END_FUNCTION
+        ",
+    );
+
+    assert_snapshot!(diagnostics);
+}
+
+#[test]
+fn control_statements_cannot_leave_a_try_block() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        FUNCTION main : DINT
+        VAR
+            i : DINT;
+        END_VAR
+            FOR i := 1 TO 10 DO
+                __TRY
+                    FOR i := 1 TO 10 DO
+                        EXIT;
+                        CONTINUE;
+                    END_FOR
+                    EXIT;
+                    CONTINUE;
+                    RETURN;
+                __CATCH
+                    EXIT;
+                __FINALLY
+                    RETURN;
+                __ENDTRY
+            END_FOR
+        END_FUNCTION
+        ",
+    );
+
+    assert_snapshot!(diagnostics);
+}
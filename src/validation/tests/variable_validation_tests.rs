@@ -359,6 +359,30 @@ mod overflows {
     }
 }
 
+#[test]
+fn union_initializers_are_reported() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        TYPE MyUnion : UNION
+            a : DINT := 5;
+            b : REAL;
+        END_UNION END_TYPE
+
+        TYPE EmptyUnion : UNION
+        END_UNION END_TYPE
+
+        PROGRAM prg
+        VAR
+            u : MyUnion := (b := 1.5);
+            v : MyUnion;
+        END_VAR
+        END_PROGRAM
+        ",
+    );
+
+    assert_snapshot!(diagnostics);
+}
+
 #[test]
 fn type_initializers_in_structs_are_validated() {
     let diagnostics = parse_and_validate_buffered(
@@ -1143,7 +1167,7 @@ fn array_access_with_non_integer_literal_causes_error() {
 }
 
 #[test]
-fn use_of_var_external_block_gives_a_warning() {
+fn var_external_refers_to_the_matching_global_variable() {
     let diagnostics = parse_and_validate_buffered(
         r#"
         VAR_GLOBAL
@@ -1157,18 +1181,11 @@ fn use_of_var_external_block_gives_a_warning() {
         "#,
     );
 
-    assert_snapshot!(diagnostics, @r###"
-    warning[E106]: VAR_EXTERNAL blocks have no effect
-      ┌─ <internal>:6:13
-      │
-    6 │             VAR_EXTERNAL
-      │             ^^^^^^^^^^^^ VAR_EXTERNAL blocks have no effect
-
-    "###);
+    assert_snapshot!(diagnostics, @"");
 }
 
 #[test]
-fn unresolved_var_external_reference_does_not_lead_to_errors() {
+fn var_external_without_matching_global_is_an_error() {
     let diagnostics = parse_and_validate_buffered(
         r#"
         VAR_GLOBAL
@@ -1183,12 +1200,11 @@ fn unresolved_var_external_reference_does_not_lead_to_errors() {
     );
 
     assert_snapshot!(diagnostics, @r###"
-    warning[E106]: VAR_EXTERNAL blocks have no effect
-      ┌─ <internal>:6:13
+    error[E149]: Could not find a global variable `b` for the VAR_EXTERNAL declaration
+      ┌─ <internal>:7:17
       │
-    6 │             VAR_EXTERNAL
-      │             ^^^^^^^^^^^^ VAR_EXTERNAL blocks have no effect
-
+    7 │                 b : BOOL;
+      │                 ^ Could not find a global variable `b` for the VAR_EXTERNAL declaration
     "###);
 }
 
@@ -1207,14 +1223,7 @@ fn var_external_with_initializer_does_not_err() {
         "#,
     );
 
-    assert_snapshot!(diagnostics, @r###"
-    warning[E106]: VAR_EXTERNAL blocks have no effect
-      ┌─ <internal>:6:13
-      │
-    6 │             VAR_EXTERNAL
-      │             ^^^^^^^^^^^^ VAR_EXTERNAL blocks have no effect
-
-    "###);
+    assert_snapshot!(diagnostics, @"");
 }
 
 #[test]
@@ -1232,21 +1241,248 @@ fn using_var_external_variable_without_matching_global_will_not_resolve() {
     );
 
     assert_snapshot!(diagnostics, @r###"
-    warning[E106]: VAR_EXTERNAL blocks have no effect
-      ┌─ <internal>:3:13
+    error[E149]: Could not find a global variable `b` for the VAR_EXTERNAL declaration
+      ┌─ <internal>:4:17
       │
-    3 │             VAR_EXTERNAL
-      │             ^^^^^^^^^^^^ VAR_EXTERNAL blocks have no effect
+    4 │                 b : BOOL := TRUE;
+      │                 ^ Could not find a global variable `b` for the VAR_EXTERNAL declaration
 
     error[E048]: Could not resolve reference to b
       ┌─ <internal>:7:13
       │
     7 │             b := FALSE;
       │             ^ Could not resolve reference to b
-
     "###);
 }
 
+#[test]
+fn var_external_type_must_match_the_global_variable() {
+    let diagnostics = parse_and_validate_buffered(
+        r#"
+        VAR_GLOBAL
+            arr : ARRAY[0..10] OF INT;
+            str : STRING[20];
+            i : INT;
+        END_VAR
+        FUNCTION_BLOCK foo_fb
+            VAR_EXTERNAL
+                arr : ARRAY[0..10] OF INT;
+                str : STRING[20];
+                i : INT;
+            END_VAR
+        END_FUNCTION_BLOCK
+        FUNCTION_BLOCK bar_fb
+            VAR_EXTERNAL
+                arr : ARRAY[1..10] OF INT;
+                str : STRING;
+                i : DINT;
+            END_VAR
+        END_FUNCTION_BLOCK
+        "#,
+    );
+
+    assert_snapshot!(diagnostics);
+}
+
+#[test]
+fn var_external_of_a_constant_global_must_be_constant() {
+    let diagnostics = parse_and_validate_buffered(
+        r#"
+        VAR_GLOBAL CONSTANT
+            limit : INT := 10;
+        END_VAR
+        FUNCTION foo
+            VAR_EXTERNAL CONSTANT
+                limit : INT;
+            END_VAR
+        END_FUNCTION
+        FUNCTION bar
+            VAR_EXTERNAL
+                limit : INT;
+            END_VAR
+        END_FUNCTION
+        "#,
+    );
+
+    assert_snapshot!(diagnostics);
+}
+
+#[test]
+fn writing_to_a_var_external_constant_is_an_error() {
+    let diagnostics = parse_and_validate_buffered(
+        r#"
+        VAR_GLOBAL
+            counter : INT;
+        END_VAR
+        FUNCTION_BLOCK foo_fb
+            VAR_EXTERNAL CONSTANT
+                counter : INT;
+            END_VAR
+            METHOD increment
+                counter := counter + 1;
+            END_METHOD
+            counter := 1;
+        END_FUNCTION_BLOCK
+        FUNCTION bar
+            VAR_EXTERNAL
+                counter : INT;
+            END_VAR
+            counter := 2;
+        END_FUNCTION
+        "#,
+    );
+
+    assert_snapshot!(diagnostics);
+}
+
+#[test]
+fn access_paths_refer_to_variables_of_programs_and_globals() {
+    let diagnostics = parse_and_validate_buffered(
+        r#"
+        FUNCTION_BLOCK fb
+            VAR
+                speed : REAL;
+                limits : ARRAY[1..3] OF INT;
+            END_VAR
+        END_FUNCTION_BLOCK
+        PROGRAM prog
+            VAR
+                drive : fb;
+            END_VAR
+        END_PROGRAM
+        VAR_GLOBAL
+            counter : DINT;
+        END_VAR
+        VAR_ACCESS
+            speed : prog.drive.speed : REAL READ_WRITE;
+            limit : prog.drive.limits[2] : INT;
+            limits : prog.drive.limits : ARRAY[1..3] OF INT;
+            counter : counter : DINT READ_ONLY;
+        END_VAR
+        "#,
+    );
+
+    assert_snapshot!(diagnostics, @"");
+}
+
+#[test]
+fn access_path_must_refer_to_a_variable() {
+    let diagnostics = parse_and_validate_buffered(
+        r#"
+        PROGRAM prog
+            VAR
+                speed : REAL;
+            END_VAR
+        END_PROGRAM
+        VAR_ACCESS
+            speed : prog.velocity : REAL;
+            other : unknown.speed : REAL;
+        END_VAR
+        "#,
+    );
+
+    assert_snapshot!(diagnostics);
+}
+
+#[test]
+fn access_path_type_must_match_the_variable() {
+    let diagnostics = parse_and_validate_buffered(
+        r#"
+        PROGRAM prog
+            VAR
+                speed : REAL;
+                limits : ARRAY[1..3] OF INT;
+            END_VAR
+        END_PROGRAM
+        VAR_ACCESS
+            speed : prog.speed : INT;
+            limits : prog.limits : ARRAY[0..2] OF INT;
+        END_VAR
+        "#,
+    );
+
+    assert_snapshot!(diagnostics);
+}
+
+#[test]
+fn access_path_to_a_constant_must_be_read_only() {
+    let diagnostics = parse_and_validate_buffered(
+        r#"
+        PROGRAM prog
+            VAR CONSTANT
+                limit : INT := 100;
+            END_VAR
+        END_PROGRAM
+        VAR_GLOBAL CONSTANT
+            max : INT := 200;
+        END_VAR
+        VAR_ACCESS
+            limit : prog.limit : INT READ_WRITE;
+            max : max : INT READ_WRITE;
+            maxValue : max : INT READ_ONLY;
+        END_VAR
+        "#,
+    );
+
+    assert_snapshot!(diagnostics);
+}
+
+#[test]
+fn access_path_must_refer_to_a_variable_at_a_fixed_location() {
+    let diagnostics = parse_and_validate_buffered(
+        r#"
+        FUNCTION_BLOCK fb
+            VAR_IN_OUT
+                speed : REAL;
+            END_VAR
+        END_FUNCTION_BLOCK
+        PROGRAM prog
+            VAR
+                drive : fb;
+                input AT %IX1.0 : BOOL;
+                values : ARRAY[0..2] OF INT;
+            END_VAR
+            VAR_TEMP
+                temp : INT;
+            END_VAR
+        END_PROGRAM
+        FUNCTION foo : INT
+            VAR
+                x : INT;
+            END_VAR
+        END_FUNCTION
+        VAR_ACCESS
+            speed : prog.drive.speed : REAL;
+            input : prog.input : BOOL;
+            temp : prog.temp : INT;
+            value : prog.values[5] : INT;
+            x : foo.x : INT;
+        END_VAR
+        "#,
+    );
+
+    assert_snapshot!(diagnostics);
+}
+
+#[test]
+fn access_path_names_must_be_unique() {
+    let diagnostics = parse_and_validate_buffered(
+        r#"
+        PROGRAM prog
+            VAR
+                a, b : INT;
+            END_VAR
+        END_PROGRAM
+        VAR_ACCESS
+            value : prog.a : INT;
+            value : prog.b : INT;
+        END_VAR
+        "#,
+    );
+
+    assert_snapshot!(diagnostics);
+}
+
 #[test]
 fn assigning_a_temp_reference_to_stateful_var_is_error() {
     let diagnostics = parse_and_validate_buffered(
@@ -1308,3 +1544,127 @@ fn assigning_a_temp_reference_to_stateful_var_is_error() {
       │                                             ^^ Cannot assign address of temporary variable to a member-variable
     "###)
 }
+
+#[test]
+fn retained_variables_in_stateful_blocks_are_valid() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        VAR_GLOBAL RETAIN
+            a : INT;
+        END_VAR
+
+        FUNCTION_BLOCK fb
+            VAR_INPUT PERSISTENT
+                b : INT;
+            END_VAR
+            VAR_OUTPUT RETAIN PERSISTENT
+                c : INT;
+            END_VAR
+        END_FUNCTION_BLOCK
+
+        PROGRAM prog
+            VAR RETAIN
+                d : INT;
+                instance : fb;
+            END_VAR
+        END_PROGRAM
+        ",
+    );
+
+    assert_snapshot!(diagnostics, @"");
+}
+
+#[test]
+fn retained_variables_in_invalid_blocks() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        VAR_GLOBAL CONSTANT RETAIN
+            a : INT := 1;
+        END_VAR
+
+        FUNCTION foo : INT
+            VAR RETAIN
+                b : INT;
+            END_VAR
+        END_FUNCTION
+
+        FUNCTION_BLOCK fb
+            VAR_TEMP PERSISTENT
+                c : INT;
+            END_VAR
+            VAR_IN_OUT RETAIN
+                d : INT;
+            END_VAR
+        END_FUNCTION_BLOCK
+        ",
+    );
+
+    assert_snapshot!(diagnostics, @r###"
+    error[E132]: This variable block does not support the RETAIN modifier
+      ┌─ <internal>:7:13
+      │
+    7 │             VAR RETAIN
+      │             ^^^ This variable block does not support the RETAIN modifier
+
+    error[E132]: This variable block does not support the PERSISTENT modifier
+       ┌─ <internal>:13:13
+       │
+    13 │             VAR_TEMP PERSISTENT
+       │             ^^^^^^^^ This variable block does not support the PERSISTENT modifier
+
+    error[E132]: This variable block does not support the RETAIN modifier
+       ┌─ <internal>:16:13
+       │
+    16 │             VAR_IN_OUT RETAIN
+       │             ^^^^^^^^^^ This variable block does not support the RETAIN modifier
+
+    error[E132]: A CONSTANT variable block cannot be RETAIN
+      ┌─ <internal>:2:9
+      │
+    2 │         VAR_GLOBAL CONSTANT RETAIN
+      │         ^^^^^^^^^^ A CONSTANT variable block cannot be RETAIN
+    "###);
+}
+
+#[test]
+fn retained_pointers_are_reported() {
+    let diagnostics = parse_and_validate_buffered(
+        "
+        TYPE node : STRUCT
+            value : INT;
+            next : REF_TO node;
+        END_STRUCT END_TYPE
+
+        VAR_GLOBAL
+            value : INT;
+        END_VAR
+
+        VAR_GLOBAL RETAIN
+            ptr : REF_TO INT;
+            nodes : ARRAY[0..1] OF node;
+            input AT %IX1.0 : BOOL;
+            plain : ARRAY[0..1] OF INT;
+        END_VAR
+        ",
+    );
+
+    assert_snapshot!(diagnostics, @r###"
+    warning[E133]: Retained variable `ptr` holds a pointer, the pointer is not valid after a restart
+       ┌─ <internal>:12:13
+       │
+    12 │             ptr : REF_TO INT;
+       │             ^^^ Retained variable `ptr` holds a pointer, the pointer is not valid after a restart
+
+    warning[E133]: Retained variable `nodes` holds a pointer, the pointer is not valid after a restart
+       ┌─ <internal>:13:13
+       │
+    13 │             nodes : ARRAY[0..1] OF node;
+       │             ^^^^^ Retained variable `nodes` holds a pointer, the pointer is not valid after a restart
+
+    warning[E133]: Retained variable `input` holds a pointer, the pointer is not valid after a restart
+       ┌─ <internal>:14:13
+       │
+    14 │             input AT %IX1.0 : BOOL;
+       │             ^^^^^ Retained variable `input` holds a pointer, the pointer is not valid after a restart
+    "###);
+}
@@ -35,10 +35,13 @@ pub fn visit_data_type<T: AnnotationMap>(
     context: &ValidationContext<T>,
 ) {
     validate_data_type(validator, data_type, location);
+    if let DataType::StructType { name: Some(name), base: Some(base), .. } = data_type {
+        validate_struct_base(validator, name, base, location, context.index);
+    }
 
     let context = &context.with_optional_qualifier(data_type.get_name());
     match data_type {
-        DataType::StructType { variables, .. } => {
+        DataType::StructType { variables, .. } | DataType::UnionType { variables, .. } => {
             variables.iter().for_each(|v| visit_variable(validator, v, context))
         }
         DataType::ArrayType { referenced_type, .. } => {
@@ -56,7 +59,7 @@ pub fn visit_data_type<T: AnnotationMap>(
 
 fn validate_data_type(validator: &mut Validator, data_type: &DataType, location: &SourceLocation) {
     match data_type {
-        DataType::StructType { variables, .. } => {
+        DataType::StructType { variables, .. } | DataType::UnionType { variables, .. } => {
             if variables.is_empty() {
                 validator.push_diagnostic(
                     Diagnostic::new("Variable block is empty")
@@ -82,6 +85,32 @@ fn validate_data_type(validator: &mut Validator, data_type: &DataType, location:
     }
 }
 
+/// Validates that a STRUCT only `EXTENDS` another STRUCT, unknown types are reported with the `__BASE` member
+fn validate_struct_base(
+    validator: &mut Validator,
+    name: &str,
+    base: &str,
+    location: &SourceLocation,
+    index: &Index,
+) {
+    if index.find_effective_type_by_name(base).is_none() {
+        return;
+    }
+
+    if !matches!(
+        index.find_type(base).map(crate::typesystem::DataType::get_type_information),
+        Some(DataTypeInformation::Struct { source: StructSource::OriginalDeclaration, .. })
+    ) {
+        validator.push_diagnostic(
+            Diagnostic::new(format!(
+                "`{name}` cannot extend `{base}`, a STRUCT can only extend another STRUCT"
+            ))
+            .with_error_code("E143")
+            .with_location(location),
+        );
+    }
+}
+
 pub fn visit_user_type_declaration<T: AnnotationMap>(
     validator: &mut Validator,
     user_type: &UserTypeDeclaration,
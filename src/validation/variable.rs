@@ -1,8 +1,9 @@
 use plc_ast::ast::{
-    ArgumentProperty, AstNode, AstStatement, CallStatement, ConfigVariable, Pou, PouType, Variable,
-    VariableBlock, VariableBlockType,
+    AccessMode, AccessVariable, ArgumentProperty, AstNode, AstStatement, CallStatement, ConfigVariable, Pou,
+    PouType, ReferenceAccess, ReferenceExpr, Variable, VariableBlock, VariableBlockType,
 };
 use plc_diagnostics::diagnostics::Diagnostic;
+use plc_util::convention::qualified_name;
 
 use super::{
     array::validate_array_assignment,
@@ -10,10 +11,17 @@ use super::{
     types::{data_type_is_fb_or_class_instance, visit_data_type_declaration},
     ValidationContext, Validator, Validators,
 };
-use crate::{index::const_expressions::ConstExpression, resolver::AnnotationMap};
-use crate::{index::const_expressions::UnresolvableKind, typesystem::DataTypeInformation};
+use crate::{
+    expression_path::ExpressionPath, index::const_expressions::ConstExpression, resolver::AnnotationMap,
+};
 use crate::{index::PouIndexEntry, validation::statement::validate_enum_variant_assignment};
 use crate::{index::VariableIndexEntry, resolver::StatementAnnotation};
+use crate::{
+    index::{
+        const_expressions::UnresolvableKind, Index, BASE_MEMBER_NAME, FB_INIT_METHOD_NAME, VTABLE_MEMBER_NAME,
+    },
+    typesystem::{self, DataTypeInformation, StructSource},
+};
 
 pub fn visit_config_variable<T: AnnotationMap>(
     validator: &mut Validator,
@@ -114,17 +122,115 @@ pub fn visit_config_variable<T: AnnotationMap>(
     }
 }
 
+pub fn visit_access_variable<T: AnnotationMap>(
+    validator: &mut Validator,
+    var_access: &AccessVariable,
+    context: &ValidationContext<T>,
+) {
+    visit_data_type_declaration(validator, &var_access.data_type, context);
+
+    // the variable containing the published element, e.g. `prog.values` for `prog.values[2]`
+    let mut variable = &var_access.reference;
+    while let AstStatement::ReferenceExpr(ReferenceExpr {
+        access: ReferenceAccess::Index(_),
+        base: Some(base),
+    }) = &variable.stmt
+    {
+        variable = base;
+    }
+
+    let target = match context.annotations.get(variable) {
+        Some(StatementAnnotation::Variable { qualified_name, .. }) => {
+            context.index.find_fully_qualified_variable(qualified_name)
+        }
+        _ => None,
+    };
+    let (Some(target), Some(target_type)) =
+        (target, context.annotations.get_type(&var_access.reference, context.index))
+    else {
+        validator.push_diagnostic(
+            Diagnostic::new(format!("Access path `{}` does not refer to a variable", var_access.name))
+                .with_error_code("E131")
+                .with_location(&var_access.reference.location),
+        );
+        return;
+    };
+
+    // the published variable must be stored at a fixed offset of a global variable or program instance
+    match ExpressionPath::try_from(var_access) {
+        Ok(path) if path.get_offset(context.index).is_some() => {}
+        Ok(_) => {
+            validator.push_diagnostic(
+                Diagnostic::new(format!(
+                    "Access path `{}` must refer to a variable at a fixed location in a global variable or program",
+                    var_access.name
+                ))
+                .with_error_code("E131")
+                .with_location(&var_access.reference.location),
+            );
+            return;
+        }
+        Err(diagnostics) => {
+            diagnostics.into_iter().for_each(|it| validator.push_diagnostic(it.with_error_code("E131")));
+            return;
+        }
+    }
+
+    if let Some(declared_type) =
+        var_access.data_type.get_name().and_then(|it| context.index.find_effective_type_by_name(it))
+    {
+        let target_type = context.index.get_effective_type_or_void_by_name(target_type.get_name());
+        if !typesystem::is_same_type(
+            declared_type.get_type_information(),
+            target_type.get_type_information(),
+            context.index,
+        ) {
+            validator.push_diagnostic(
+                Diagnostic::new(format!(
+                    "The type of access path `{}` does not match the type of the referenced variable ({} and {})",
+                    var_access.name,
+                    validator.get_type_name_or_slice(declared_type),
+                    validator.get_type_name_or_slice(target_type)
+                ))
+                .with_error_code("E131")
+                .with_location(var_access.data_type.get_location())
+                .with_secondary_location(&target.source_location),
+            );
+        }
+    }
+
+    if var_access.access == AccessMode::ReadWrite && target.is_constant() {
+        validator.push_diagnostic(
+            Diagnostic::new(format!(
+                "The referenced variable is CONSTANT, access path `{}` must be READ_ONLY",
+                var_access.name
+            ))
+            .with_error_code("E131")
+            .with_location(&var_access.location)
+            .with_secondary_location(&target.source_location),
+        );
+    }
+}
+
 pub fn visit_variable_block<T: AnnotationMap>(
     validator: &mut Validator,
     pou: Option<&Pou>,
     block: &VariableBlock,
     context: &ValidationContext<T>,
 ) {
-    validate_variable_block(validator, block);
+    validate_variable_block(validator, pou, block);
 
     for variable in &block.variables {
         visit_variable(validator, variable, context);
 
+        if block.retain || block.persistent {
+            validate_retained_variable(validator, variable, context);
+        }
+
+        if let (VariableBlockType::External, Some(pou)) = (block.variable_block_type, pou) {
+            validate_external_variable(validator, pou, variable, context);
+        }
+
         if let Some(referenced_type) = variable.data_type_declaration.get_referenced_type() {
             if context.index.get_type_information_or_void(&referenced_type).is_vla() {
                 validate_vla(validator, pou, block, variable);
@@ -133,13 +239,9 @@ pub fn visit_variable_block<T: AnnotationMap>(
     }
 }
 
-fn validate_variable_block(validator: &mut Validator, block: &VariableBlock) {
-    if matches!(block.variable_block_type, VariableBlockType::External) {
-        validator.push_diagnostic(
-            Diagnostic::new("VAR_EXTERNAL blocks have no effect")
-                .with_error_code("E106")
-                .with_location(&block.location),
-        );
+fn validate_variable_block(validator: &mut Validator, pou: Option<&Pou>, block: &VariableBlock) {
+    if block.retain || block.persistent {
+        validate_retention(validator, pou, block);
     }
 
     if block.constant
@@ -154,6 +256,131 @@ fn validate_variable_block(validator: &mut Validator, block: &VariableBlock) {
                 .with_location(&block.location),
         )
     }
+
+    if let Some(access) = &block.access {
+        let is_class_member = pou.is_some_and(|it| it.kind == PouType::Class)
+            && block.variable_block_type == VariableBlockType::Local;
+        if !is_class_member {
+            validator.push_diagnostic(
+                Diagnostic::new(format!(
+                    "The access modifier {access} is only allowed on VAR blocks of classes"
+                ))
+                .with_error_code("E135")
+                .with_location(&block.location),
+            )
+        }
+    }
+}
+
+/// Validates that a `RETAIN` or `PERSISTENT` block declares variables which keep their values between calls
+fn validate_retention(validator: &mut Validator, pou: Option<&Pou>, block: &VariableBlock) {
+    let modifier = if block.retain { "RETAIN" } else { "PERSISTENT" };
+    let is_stateful =
+        pou.is_none_or(|it| matches!(it.kind, PouType::Program | PouType::FunctionBlock | PouType::Class));
+    if !is_stateful
+        || !matches!(
+            block.variable_block_type,
+            VariableBlockType::Global
+                | VariableBlockType::Local
+                | VariableBlockType::Input(_)
+                | VariableBlockType::Output
+        )
+    {
+        validator.push_diagnostic(
+            Diagnostic::new(format!("This variable block does not support the {modifier} modifier"))
+                .with_error_code("E132")
+                .with_location(&block.location),
+        );
+    } else if block.constant {
+        validator.push_diagnostic(
+            Diagnostic::new(format!("A CONSTANT variable block cannot be {modifier}"))
+                .with_error_code("E132")
+                .with_location(&block.location),
+        );
+    }
+}
+
+/// Warns about retained variables holding pointers, the addresses stored in the retain image are not valid
+/// after a restart. The `__vtable` of a retained instance is not restored and therefore not reported
+fn validate_retained_variable<T: AnnotationMap>(
+    validator: &mut Validator,
+    variable: &Variable,
+    context: &ValidationContext<T>,
+) {
+    fn contains_pointer(type_name: &str, context: &ValidationContext<impl AnnotationMap>) -> bool {
+        match context.index.get_effective_type_or_void_by_name(type_name).get_type_information() {
+            DataTypeInformation::Pointer { .. } => true,
+            DataTypeInformation::Struct { members, .. } => members
+                .iter()
+                .filter(|it| it.is_stored_in_struct() && it.get_name() != VTABLE_MEMBER_NAME)
+                .any(|it| contains_pointer(it.get_type_name(), context)),
+            DataTypeInformation::Array { inner_type_name, .. } => contains_pointer(inner_type_name, context),
+            _ => false,
+        }
+    }
+
+    let Some(type_name) = variable.data_type_declaration.get_name() else { return };
+    if variable.address.is_some() || contains_pointer(type_name, context) {
+        validator.push_diagnostic(
+            Diagnostic::new(format!(
+                "Retained variable `{}` holds a pointer, the pointer is not valid after a restart",
+                variable.name
+            ))
+            .with_error_code("E133")
+            .with_location(&variable.location),
+        );
+    }
+}
+
+/// Validates that a `VAR_EXTERNAL` declaration matches the global variable it refers to
+fn validate_external_variable<T: AnnotationMap>(
+    validator: &mut Validator,
+    pou: &Pou,
+    variable: &Variable,
+    context: &ValidationContext<T>,
+) {
+    let Some(external) = context.index.find_local_member(&pou.name, &variable.name) else { return };
+    let Some(global) = context.index.find_global_variable(&variable.name) else {
+        validator.push_diagnostic(
+            Diagnostic::new(format!(
+                "Could not find a global variable `{}` for the VAR_EXTERNAL declaration",
+                variable.name
+            ))
+            .with_error_code("E149")
+            .with_location(&variable.location),
+        );
+        return;
+    };
+
+    let external_type = context.index.get_effective_type_or_void_by_name(external.get_type_name());
+    let global_type = context.index.get_effective_type_or_void_by_name(global.get_type_name());
+    if !typesystem::is_same_type(
+        external_type.get_type_information(),
+        global_type.get_type_information(),
+        context.index,
+    ) {
+        validator.push_diagnostic(
+            Diagnostic::new(format!(
+                "The type of VAR_EXTERNAL `{}` does not match the type of the global variable",
+                variable.name
+            ))
+            .with_error_code("E149")
+            .with_location(&variable.location)
+            .with_secondary_location(&global.source_location),
+        );
+    }
+
+    if global.is_constant() && !external.is_constant() {
+        validator.push_diagnostic(
+            Diagnostic::new(format!(
+                "The global variable `{}` is CONSTANT, its VAR_EXTERNAL declaration must be CONSTANT as well",
+                variable.name
+            ))
+            .with_error_code("E149")
+            .with_location(&variable.location)
+            .with_secondary_location(&global.source_location),
+        );
+    }
 }
 
 pub fn visit_variable<T: AnnotationMap>(
@@ -260,6 +487,8 @@ fn validate_variable<T: AnnotationMap>(
         validate_reference_to_declaration(validator, context, variable, v_entry);
 
         if let Some(initializer) = &variable.initializer {
+            validate_union_initializer(validator, context, v_entry, initializer);
+
             // Assume `foo : ARRAY[1..5] OF DINT := [...]`, here the first function call validates the
             // assignment as a whole whereas the second function call (`visit_statement`) validates the
             // initializer in case it has further sub-assignments.
@@ -351,6 +580,74 @@ fn validate_variable<T: AnnotationMap>(
                 .with_location(&variable.location),
             );
         }
+
+        // abstract POUs can only be referenced, the inherited base of a derived POU is no instance of its own
+        if !v_entry.is_var_external() && v_entry.get_name() != BASE_MEMBER_NAME {
+            if let Some(pou) = find_instantiated_abstract_pou(v_entry.get_type_name(), context.index) {
+                validator.push_diagnostic(
+                    Diagnostic::new(format!(
+                        "`{}` cannot be declared as an instance of `{}`, it is declared ABSTRACT",
+                        v_entry.get_name(),
+                        pou.get_name()
+                    ))
+                    .with_error_code("E136")
+                    .with_location(&variable.location),
+                );
+            }
+        }
+
+        if let Some(arguments) = &variable.init_arguments {
+            let type_name = v_entry.get_type_name();
+            if context.index.find_pou(&qualified_name(type_name, FB_INIT_METHOD_NAME)).is_none() {
+                validator.push_diagnostic(
+                    Diagnostic::new(format!(
+                        "`{}` cannot be declared with init arguments, `{type_name}` does not declare an {FB_INIT_METHOD_NAME} method",
+                        v_entry.get_name()
+                    ))
+                    .with_error_code("E141")
+                    .with_location(arguments),
+                );
+            }
+        }
+    }
+}
+
+/// Returns the `ABSTRACT` function block or class instantiated by a variable of the given type, including the
+/// elements of an array
+fn find_instantiated_abstract_pou<'idx>(type_name: &str, index: &'idx Index) -> Option<&'idx PouIndexEntry> {
+    match index.get_effective_type_or_void_by_name(type_name).get_type_information() {
+        DataTypeInformation::Struct {
+            name,
+            source: StructSource::Pou(PouType::FunctionBlock | PouType::Class),
+            ..
+        } => index.find_pou(name).filter(|it| it.is_abstract()),
+        DataTypeInformation::Array { inner_type_name, .. } => {
+            find_instantiated_abstract_pou(inner_type_name, index)
+        }
+        _ => None,
+    }
+}
+
+/// all members of a union share the same storage, hence neither the members nor variables of a union type
+/// can be given an initial value
+fn validate_union_initializer<T: AnnotationMap>(
+    validator: &mut Validator,
+    context: &ValidationContext<T>,
+    v_entry: &VariableIndexEntry,
+    initializer: &AstNode,
+) {
+    let union = [context.qualifier, Some(v_entry.get_type_name())]
+        .into_iter()
+        .flatten()
+        .filter_map(|it| context.index.find_effective_type_info(it))
+        .find(|it| it.is_union());
+
+    if let Some(union) = union {
+        validator.push_diagnostic(
+            Diagnostic::new(format!("Initial values are not supported for union `{}`", union.get_name()))
+                .with_error_code("E121")
+                .with_location(initializer),
+        );
     }
 }
 
@@ -18,7 +18,7 @@ fn class_reference_in_pou() {
     }
 
     let source = "
-        CLASS MyClass
+        CLASS FINAL MyClass
             VAR
                 x, y : INT;
             END_VAR
@@ -1,6 +1,11 @@
 // Copyright (c) 2020 Ghaith Hachem and Mathias Rieder
 
 use crate::compile_and_run;
+use driver::{
+    runner::{compile_and_run_no_params, compile_with_options},
+    CompileOptions,
+};
+use rusty::codegen::CodegenContext;
 
 macro_rules! permutate_conditionals {
     ($code: tt, $condition : tt) => {{
@@ -811,3 +816,81 @@ fn case_statement() {
     let _: i32 = compile_and_run(function.to_string(), p);
     assert_eq!(p.ret, 7);
 }
+
+#[test]
+fn try_catches_a_division_by_zero_raised_in_a_called_function() {
+    let src = r#"
+    FUNCTION divide : DINT
+    VAR_INPUT
+        a, b : DINT;
+    END_VAR
+        divide := a / b;
+    END_FUNCTION
+
+    FUNCTION main : DINT
+    VAR
+        exc : __EXCEPTION_CODE;
+        divisor : DINT := 5;
+        i : DINT;
+    END_VAR
+        __TRY
+            main := divide(10, divisor);
+        __CATCH(exc)
+            main := -1;
+        __ENDTRY
+
+        divisor := 0;
+        __TRY
+            main := divide(10, divisor);
+        __CATCH(exc)
+            main := main * 100 + exc;
+        __FINALLY
+            main := main + 1000;
+        __ENDTRY
+
+        FOR i := 1 TO 3 DO
+            __TRY
+                __RAISE(EXCEPTION_NULL_POINTER);
+            __CATCH
+                main := main + 10;
+            __ENDTRY
+        END_FOR
+    END_FUNCTION
+    "#;
+
+    let options = CompileOptions {
+        optimization: rusty::OptimizationLevel::None,
+        debug_level: rusty::DebugLevel::None,
+        runtime_checks: true,
+        ..Default::default()
+    };
+    let context = CodegenContext::create();
+    let module = compile_with_options(&context, src, &options);
+    let res: i32 = module.run_no_param("main");
+    assert_eq!(res, 1231);
+}
+
+#[test]
+fn exceptions_pass_through_finally_clauses_to_the_enclosing_try() {
+    let src = r#"
+    FUNCTION main : DINT
+    VAR
+        exc : __EXCEPTION_CODE;
+    END_VAR
+        __TRY
+            __TRY
+                __RAISE(7);
+                main := 1000;
+            __FINALLY
+                main := main + 1;
+            __ENDTRY
+            main := main + 1000;
+        __CATCH(exc)
+            main := main * 100 + exc;
+        __ENDTRY
+    END_FUNCTION
+    "#;
+
+    let res: i32 = compile_and_run_no_params(src);
+    assert_eq!(res, 107);
+}
@@ -1,5 +1,6 @@
 // Copyright (c) 2020 Ghaith Hachem and Mathias Rieder
 use crate::*;
+use driver::runner::compile_and_run_no_params;
 
 #[test]
 fn using_structs() {
@@ -340,3 +341,97 @@ fn using_arrays() {
         assert_eq!(i as i32, *j);
     }
 }
+
+#[test]
+fn using_unions() {
+    let testcode = r#"
+    TYPE Bytes4 : STRUCT
+        b0, b1, b2, b3 : BYTE;
+    END_STRUCT
+    END_TYPE
+
+    TYPE Overlay : UNION
+        raw : DWORD;
+        parts : Bytes4;
+        arr : ARRAY[0..3] OF BYTE;
+    END_UNION
+    END_TYPE
+
+    FUNCTION main : DINT
+    VAR
+        u : Overlay;
+    END_VAR
+        u.raw := 16#11223344;
+        u.parts.b1 := 16#55;
+        u.arr[3] := 16#66;
+        main := u.raw;
+    END_FUNCTION
+    "#;
+
+    let res: i32 = compile_and_run_no_params(testcode);
+    assert_eq!(0x66225544, res);
+}
+
+#[test]
+fn unions_in_structs_and_arrays_use_c_layout() {
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    union Number {
+        small: u32,
+        large: u64,
+    }
+
+    #[repr(C)]
+    struct Outer {
+        a: u8,
+        n: Number,
+        b: u8,
+    }
+
+    #[repr(C)]
+    struct MainType {
+        o: Outer,
+        arr: [Number; 2],
+    }
+
+    let mut main_data = MainType {
+        o: Outer { a: 0, n: Number { large: 0 }, b: 0 },
+        arr: [Number { large: 0 }, Number { large: 0 }],
+    };
+
+    let testcode = r#"
+    TYPE Number : UNION
+        small : UDINT;
+        large : ULINT;
+    END_UNION
+    END_TYPE
+
+    TYPE Outer : STRUCT
+        a : BYTE;
+        n : Number;
+        b : BYTE;
+    END_STRUCT
+    END_TYPE
+
+    PROGRAM main
+    VAR
+        o : Outer;
+        arr : ARRAY[0..1] OF Number;
+    END_VAR
+        o.a := 1;
+        o.n.large := 16#1122334455667788;
+        o.b := 2;
+        arr[0].small := 3;
+        arr[1].large := 16#0000000100000004;
+    END_PROGRAM
+    "#;
+
+    let _: i32 = compile_and_run(testcode, &mut main_data);
+    assert_eq!(1, main_data.o.a);
+    assert_eq!(0x1122334455667788, unsafe { main_data.o.n.large });
+    assert_eq!(0x55667788, unsafe { main_data.o.n.small });
+    assert_eq!(2, main_data.o.b);
+    assert_eq!(3, unsafe { main_data.arr[0].small });
+    assert_eq!(4, unsafe { main_data.arr[1].small });
+    assert_eq!(0x100000004, unsafe { main_data.arr[1].large });
+}
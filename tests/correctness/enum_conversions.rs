@@ -0,0 +1,157 @@
+use crate::compile_and_run;
+use driver::runner::compile_and_run_no_params;
+use std::ffi::CStr;
+
+const ENUMS: &str = "
+    TYPE Color : (Red, Green := 5, Blue); END_TYPE
+
+    {attribute 'strict'}
+    TYPE Mode : BYTE (Off, Automatic := 10, Manual := 20); END_TYPE
+
+    // the generic conversions of the standard library
+    FUNCTION TO_STRING<T: ANY> : STRING
+    VAR_INPUT
+        in : T;
+    END_VAR
+    END_FUNCTION
+
+    FUNCTION TO_WSTRING<T: ANY> : WSTRING
+    VAR_INPUT
+        in : T;
+    END_VAR
+    END_FUNCTION
+";
+
+fn to_str(bytes: &[u8]) -> &str {
+    CStr::from_bytes_until_nul(bytes).unwrap().to_str().unwrap()
+}
+
+fn to_string(chars: &[u16]) -> String {
+    String::from_utf16_lossy(chars).trim_end_matches('\0').to_string()
+}
+
+#[test]
+fn enum_elements_are_converted_to_their_names() {
+    #[repr(C)]
+    struct MainType {
+        green: [u8; 81],
+        blue: [u8; 81],
+        manual: [u8; 81],
+        unknown: [u8; 81],
+        automatic: [u16; 81],
+        red: [u16; 81],
+    }
+
+    let source = format!(
+        "{ENUMS}
+        PROGRAM main
+        VAR
+            green, blue, manual, unknown : STRING;
+            automatic, red : WSTRING;
+        END_VAR
+        VAR_TEMP
+            color : Color := Color#Blue;
+            mode : Mode := Mode#Manual;
+        END_VAR
+            green := Color_TO_STRING(Color#Green);
+            blue := TO_STRING(color);
+            manual := TO_STRING(mode);
+            unknown := Color_TO_STRING(3);
+            automatic := Mode_TO_WSTRING(Mode#Automatic);
+            red := TO_WSTRING(Color#Red);
+        END_PROGRAM
+        "
+    );
+
+    let mut main = MainType {
+        green: [0; 81],
+        blue: [0; 81],
+        manual: [0; 81],
+        unknown: [b'x'; 81],
+        automatic: [0; 81],
+        red: [0; 81],
+    };
+    let _: i32 = compile_and_run(source, &mut main);
+    assert_eq!(to_str(&main.green), "Green");
+    assert_eq!(to_str(&main.blue), "Blue");
+    assert_eq!(to_str(&main.manual), "Manual");
+    assert_eq!(to_str(&main.unknown), "");
+    assert_eq!(to_string(&main.automatic), "Automatic");
+    assert_eq!(to_string(&main.red), "Red");
+}
+
+#[test]
+fn names_are_converted_to_enum_elements() {
+    #[repr(C)]
+    struct MainType {
+        green: i32,
+        blue: i32,
+        manual: u8,
+        automatic: u8,
+        round_trip: i32,
+    }
+
+    let source = format!(
+        "{ENUMS}
+        PROGRAM main
+        VAR
+            green, blue : Color;
+            manual, automatic : Mode;
+            round_trip : Color;
+        END_VAR
+        VAR_TEMP
+            name : STRING := 'Manual';
+        END_VAR
+            green := STRING_TO_Color('Green');
+            blue := STRING_TO_Color('bLUE');
+            manual := STRING_TO_Mode(name);
+            automatic := WSTRING_TO_Mode(\"automatic\");
+            round_trip := STRING_TO_Color(Color_TO_STRING(Color#Green));
+        END_PROGRAM
+        "
+    );
+
+    let mut main = MainType { green: 0, blue: 0, manual: 0, automatic: 0, round_trip: 0 };
+    let _: i32 = compile_and_run(source, &mut main);
+    assert_eq!(main.green, 5);
+    assert_eq!(main.blue, 6);
+    assert_eq!(main.manual, 20);
+    assert_eq!(main.automatic, 10);
+    assert_eq!(main.round_trip, 5);
+}
+
+#[test]
+fn unknown_names_raise_an_exception() {
+    let source = format!(
+        "{ENUMS}
+        FUNCTION main : DINT
+        VAR
+            color : Color := Color#Blue;
+            code : __EXCEPTION_CODE;
+        END_VAR
+            __TRY
+                // a prefix or an extension of a name is not a name
+                color := STRING_TO_Color('Gree');
+            __CATCH(code)
+                main := code;
+            __ENDTRY
+            __TRY
+                color := STRING_TO_Color('Greens');
+            __CATCH(code)
+                main := main * 10 + code;
+            __ENDTRY
+            __TRY
+                color := STRING_TO_Color('');
+            __CATCH(code)
+                main := main * 10 + code;
+            __ENDTRY
+            IF color = Color#Blue THEN
+                main := main * 10 + 1;
+            END_IF
+        END_FUNCTION
+        "
+    );
+
+    let res: i32 = compile_and_run_no_params(source);
+    assert_eq!(res, 3331);
+}
@@ -236,7 +236,7 @@ fn function_block_instances_save_state_per_instance() {
         j: FooType,
     }
     let function = r#"
-    FUNCTION_BLOCK foo
+    FUNCTION_BLOCK FINAL foo
     VAR_INPUT
         i : INT;
     END_VAR
@@ -379,14 +379,14 @@ fn function_block_instances_save_state_per_instance_2() {
         j: FooType,
     }
     let function = r#"
-    FUNCTION_BLOCK Baz
+    FUNCTION_BLOCK FINAL Baz
     VAR_INPUT
         i : INT;
     END_VAR
     i := i+1;
     END_FUNCTION_BLOCK
 
-    FUNCTION_BLOCK foo
+    FUNCTION_BLOCK FINAL foo
     VAR_INPUT
         i : INT;
         baz: Baz;
@@ -697,7 +697,7 @@ fn direct_call_on_function_block_array_access() {
     }
 
     let function = r#"
-    FUNCTION_BLOCK foo
+    FUNCTION_BLOCK FINAL foo
     VAR_INPUT
         i : INT;
     END_VAR
@@ -1205,7 +1205,7 @@ fn sizeof_test() {
     let function = r#"
         CLASS MyClass
         VAR
-            x, y : INT; // 4 bytes
+            x, y : INT; // 4 bytes after the 8 byte __vtable, padded to 16 bytes
         END_VAR
         END_CLASS
         TYPE MyStruct : STRUCT
@@ -1250,7 +1250,7 @@ fn sizeof_test() {
     let module = compile(&context, function);
     let _: i32 = module.run("main", &mut maintype);
 
-    let expected = MainType { s1: 1, s2: 2, s3: 8, s4: 24, s5: 8, s6: 81, s7: 2, s8: 4 };
+    let expected = MainType { s1: 1, s2: 2, s3: 8, s4: 24, s5: 8, s6: 81, s7: 2, s8: 16 };
 
     assert_eq!(expected, maintype);
 }
@@ -0,0 +1,108 @@
+use driver::runner::compile_and_run_no_params;
+
+const FIFO: &str = "
+    FUNCTION_BLOCK FB_Fifo<T: ANY>
+    VAR
+        items : ARRAY[0..9] OF T;
+        head, count : DINT;
+    END_VAR
+        METHOD push
+        VAR_INPUT
+            item : T;
+        END_VAR
+            items[(head + count) MOD 10] := item;
+            count := count + 1;
+        END_METHOD
+
+        METHOD pop : T
+            pop := items[head];
+            head := (head + 1) MOD 10;
+            count := count - 1;
+        END_METHOD
+
+        METHOD size : DINT
+            size := count;
+        END_METHOD
+    END_FUNCTION_BLOCK
+";
+
+#[test]
+fn generic_function_blocks_are_instantiated_per_type_argument() {
+    let source = format!(
+        "{FIFO}
+        FUNCTION main : DINT
+        VAR
+            reals : FB_Fifo<REAL>;
+            dints : FB_Fifo<DINT>;
+        END_VAR
+            reals.push(1.5);
+            reals.push(2.25);
+            dints.push(40);
+            dints.push(2);
+            IF reals.pop() + reals.pop() = 3.75 THEN
+                main := 1000;
+            END_IF
+            main := main + dints.pop() * 10 + dints.pop() + dints.size();
+        END_FUNCTION
+        "
+    );
+
+    let res: i32 = compile_and_run_no_params(source);
+    assert_eq!(res, 1402);
+}
+
+#[test]
+fn generic_classes_can_instantiate_other_generic_pous() {
+    let source = format!(
+        "{FIFO}
+        CLASS Pair<K: ANY_INT, V: ANY>
+        VAR PUBLIC
+            key : K;
+            value : V;
+        END_VAR
+        END_CLASS
+
+        CLASS Dictionary<V: ANY>
+        VAR
+            entries : FB_Fifo<Pair<INT, V>>;
+        END_VAR
+            METHOD add
+            VAR_INPUT
+                key : INT;
+                value : V;
+            END_VAR
+            VAR
+                entry : Pair<INT, V>;
+            END_VAR
+                entry.key := key;
+                entry.value := value;
+                entries.push(entry);
+            END_METHOD
+
+            METHOD first : V
+            VAR
+                entry : Pair<INT, V>;
+            END_VAR
+                entry := entries.pop();
+                first := entry.value;
+            END_METHOD
+
+            METHOD size : DINT
+                size := entries.size();
+            END_METHOD
+        END_CLASS
+
+        FUNCTION main : DINT
+        VAR
+            words : Dictionary<LINT>;
+        END_VAR
+            words.add(1, 10000000000);
+            words.add(2, 20);
+            main := words.first() / 1000 + words.size();
+        END_FUNCTION
+        "
+    );
+
+    let res: i32 = compile_and_run_no_params(source);
+    assert_eq!(res, 10000001);
+}
@@ -0,0 +1,183 @@
+use driver::runner::compile_and_run_no_params;
+
+#[test]
+fn overridden_method_is_called_from_base_implementation() {
+    let source = "
+        FUNCTION_BLOCK Base
+            METHOD step : DINT
+                step := 1;
+            END_METHOD
+            METHOD run : DINT
+                run := step() * 10 + THIS^.step();
+            END_METHOD
+        END_FUNCTION_BLOCK
+
+        FUNCTION_BLOCK Derived EXTENDS Base
+            METHOD OVERRIDE step : DINT
+                step := 2;
+            END_METHOD
+        END_FUNCTION_BLOCK
+
+        FUNCTION main : DINT
+        VAR
+            b : Base;
+            d : Derived;
+        END_VAR
+            main := b.run() * 100 + d.run();
+        END_FUNCTION
+        ";
+
+    let res: i32 = compile_and_run_no_params(source);
+    assert_eq!(res, 1122);
+}
+
+#[test]
+fn super_calls_the_base_implementation() {
+    let source = "
+        CLASS A
+            METHOD name : DINT
+                name := 1;
+            END_METHOD
+        END_CLASS
+
+        CLASS B EXTENDS A
+            METHOD OVERRIDE name : DINT
+                name := SUPER^.name() * 10 + 2;
+            END_METHOD
+        END_CLASS
+
+        CLASS C EXTENDS B
+            METHOD OVERRIDE name : DINT
+                name := SUPER^.name() * 10 + 3;
+            END_METHOD
+        END_CLASS
+
+        FUNCTION main : DINT
+        VAR
+            c : C;
+        END_VAR
+            main := c.name();
+        END_FUNCTION
+        ";
+
+    let res: i32 = compile_and_run_no_params(source);
+    assert_eq!(res, 123);
+}
+
+#[test]
+fn methods_are_dispatched_through_base_typed_references() {
+    let source = "
+        FUNCTION_BLOCK Shape
+            METHOD area : DINT
+            VAR_INPUT
+                scale : DINT;
+            END_VAR
+                area := 0;
+            END_METHOD
+        END_FUNCTION_BLOCK
+
+        FUNCTION_BLOCK Square EXTENDS Shape
+        VAR
+            side : DINT := 3;
+        END_VAR
+            METHOD OVERRIDE area : DINT
+            VAR_INPUT
+                scale : DINT;
+            END_VAR
+                area := side * side * scale;
+            END_METHOD
+        END_FUNCTION_BLOCK
+
+        FUNCTION areaOf : DINT
+        VAR_IN_OUT
+            shape : Shape;
+        END_VAR
+            areaOf := shape.area(scale := 2);
+        END_FUNCTION
+
+        FUNCTION main : DINT
+        VAR
+            square : Square;
+            shape : REF_TO Shape;
+        END_VAR
+            shape := REF(square);
+            main := shape^.area(1) * 100 + areaOf(square);
+        END_FUNCTION
+        ";
+
+    let res: i32 = compile_and_run_no_params(source);
+    assert_eq!(res, 918);
+}
+
+#[test]
+fn inherited_members_are_accessible_in_derived_pous() {
+    let source = "
+        FUNCTION_BLOCK Counter
+        VAR
+            count : DINT := 10;
+        END_VAR
+            METHOD increment
+                count := count + 1;
+            END_METHOD
+        END_FUNCTION_BLOCK
+
+        FUNCTION_BLOCK DoubleCounter EXTENDS Counter
+        VAR_OUTPUT
+            doubled : DINT;
+        END_VAR
+            increment();
+            THIS^.increment();
+            SUPER^.count := SUPER^.count + 1;
+            doubled := count * 2;
+        END_FUNCTION_BLOCK
+
+        FUNCTION main : DINT
+        VAR
+            counter : DoubleCounter;
+        END_VAR
+            counter();
+            counter.increment();
+            main := counter.doubled * 100 + counter.doubled;
+        END_FUNCTION
+        ";
+
+    let res: i32 = compile_and_run_no_params(source);
+    assert_eq!(res, 2626);
+}
+
+#[test]
+fn methods_of_derived_instances_in_arrays_are_dispatched() {
+    let source = "
+        CLASS Animal
+            METHOD legs : DINT
+                legs := 0;
+            END_METHOD
+        END_CLASS
+
+        CLASS Dog EXTENDS Animal
+            METHOD OVERRIDE legs : DINT
+                legs := 4;
+            END_METHOD
+        END_CLASS
+
+        FUNCTION countLegs : DINT
+        VAR_IN_OUT
+            animal : Animal;
+        END_VAR
+            countLegs := animal.legs();
+        END_FUNCTION
+
+        FUNCTION main : DINT
+        VAR
+            dogs : ARRAY[0..2] OF Dog;
+            i : DINT;
+        END_VAR
+            FOR i := 0 TO 2 DO
+                main := main + countLegs(dogs[i]);
+            END_FOR
+        END_FUNCTION
+        ";
+
+    let res: i32 = compile_and_run_no_params(source);
+    assert_eq!(res, 12);
+}
@@ -0,0 +1,120 @@
+use crate::compile_and_run;
+
+#[derive(Default)]
+#[repr(C)]
+struct MainType {
+    start: bool,
+    stop: bool,
+    motor: bool,
+    count: i32,
+    limited: i32,
+}
+
+#[test]
+fn il_instructions_are_executed() {
+    let source = "
+        FUNCTION clamp : DINT
+        VAR_INPUT
+            value, low, high : DINT;
+        END_VAR
+            clamp := value;
+            IF value < low THEN clamp := low; END_IF
+            IF value > high THEN clamp := high; END_IF
+        END_FUNCTION
+
+        {attribute 'language' := 'IL'}
+        PROGRAM main
+        VAR_INPUT
+            start, stop : BOOL;
+        END_VAR
+        VAR_OUTPUT
+            motor : BOOL;
+            count, limited : DINT;
+        END_VAR
+            LD start
+            ANDN stop
+            S motor
+            LD stop
+            R motor
+            LD motor
+            JMPCN done
+            LD count
+            ADD( 2
+            MUL 3
+            )
+            ST count
+            clamp 0, 20
+            ST limited
+        done:
+            RET
+        END_PROGRAM
+    ";
+
+    let mut main = MainType { start: true, ..Default::default() };
+    let _: i32 = compile_and_run(source, &mut main);
+    assert_eq!((main.motor, main.count, main.limited), (true, 6, 6));
+
+    let _: i32 = compile_and_run(source, &mut main);
+    assert_eq!((main.motor, main.count, main.limited), (true, 12, 12));
+
+    let _: i32 = compile_and_run(source, &mut main);
+    assert_eq!((main.motor, main.count, main.limited), (true, 18, 18));
+
+    let _: i32 = compile_and_run(source, &mut main);
+    assert_eq!((main.motor, main.count, main.limited), (true, 24, 20));
+
+    // stop resets the motor, so the counter is skipped
+    main.stop = true;
+    let _: i32 = compile_and_run(source, &mut main);
+    assert_eq!((main.motor, main.count, main.limited), (false, 24, 20));
+}
+
+#[test]
+fn current_result_is_evaluated_once() {
+    #[derive(Default)]
+    #[repr(C)]
+    struct MainType {
+        a: bool,
+        b: bool,
+        y: bool,
+        calls: i32,
+    }
+
+    let source = "
+        FUNCTION count : BOOL
+        VAR_INPUT
+            value : BOOL;
+        END_VAR
+        VAR_IN_OUT
+            calls : DINT;
+        END_VAR
+            calls := calls + 1;
+            count := value;
+        END_FUNCTION
+
+        {attribute 'language' := 'IL'}
+        PROGRAM main
+        VAR_INPUT
+            a : BOOL;
+        END_VAR
+        VAR_OUTPUT
+            b, y : BOOL;
+            calls : DINT;
+        END_VAR
+            LD a
+            R a
+            ST b
+            LD TRUE
+            count calls
+            JMPC done
+            ST y
+        done:
+            RET
+        END_PROGRAM
+    ";
+
+    let mut main = MainType { a: true, ..Default::default() };
+    let _: i32 = compile_and_run(source, &mut main);
+    // b holds the value of a before the reset, the function is called once
+    assert_eq!((main.a, main.b, main.y, main.calls), (false, true, false, 1));
+}
@@ -0,0 +1,214 @@
+use crate::*;
+
+#[test]
+fn interface_method_calls_are_dispatched_to_the_assigned_instance() {
+    #[derive(Default)]
+    #[repr(C)]
+    struct MainType {
+        square: i32,
+        circle: i32,
+        shape: [usize; 2],
+        a: i32,
+        b: i32,
+    }
+
+    let source = "
+        INTERFACE IShape
+            METHOD area : DINT
+            VAR_INPUT
+                scale : DINT;
+            END_VAR
+            END_METHOD
+        END_INTERFACE
+
+        FUNCTION_BLOCK FINAL Square IMPLEMENTS IShape
+            VAR
+                side : DINT;
+            END_VAR
+            METHOD area : DINT
+            VAR_INPUT
+                scale : DINT;
+            END_VAR
+                area := side * side * scale;
+            END_METHOD
+        END_FUNCTION_BLOCK
+
+        FUNCTION_BLOCK FINAL Circle IMPLEMENTS IShape
+            VAR
+                radius : DINT;
+            END_VAR
+            METHOD area : DINT
+            VAR_INPUT
+                scale : DINT;
+            END_VAR
+                area := 3 * radius * radius * scale;
+            END_METHOD
+        END_FUNCTION_BLOCK
+
+        PROGRAM main
+        VAR
+            square : Square;
+            circle : Circle;
+            shape : IShape;
+        END_VAR
+        VAR_OUTPUT
+            a : DINT;
+            b : DINT;
+        END_VAR
+            shape := square;
+            a := shape.area(1);
+            shape := circle;
+            b := shape.area(scale := 2);
+        END_PROGRAM
+        ";
+
+    let mut main = MainType { square: 4, circle: 2, ..Default::default() };
+    let _: i32 = compile_and_run(source, &mut main);
+    assert_eq!(main.a, 16);
+    assert_eq!(main.b, 24);
+}
+
+#[test]
+fn interface_method_with_output_and_inout_parameters() {
+    #[derive(Default)]
+    #[repr(C)]
+    struct MainType {
+        counter: i32,
+        itf: [usize; 2],
+        total: i32,
+        x: i32,
+    }
+
+    let source = "
+        INTERFACE ICounter
+            METHOD add
+            VAR_INPUT
+                value : DINT;
+            END_VAR
+            VAR_OUTPUT
+                total : DINT;
+            END_VAR
+            VAR_IN_OUT
+                calls : DINT;
+            END_VAR
+            END_METHOD
+        END_INTERFACE
+
+        CLASS FINAL Counter IMPLEMENTS ICounter
+            VAR
+                count : DINT;
+            END_VAR
+            METHOD add
+            VAR_INPUT
+                value : DINT;
+            END_VAR
+            VAR_OUTPUT
+                total : DINT;
+            END_VAR
+            VAR_IN_OUT
+                calls : DINT;
+            END_VAR
+                count := count + value;
+                total := count;
+                calls := calls + 1;
+            END_METHOD
+        END_CLASS
+
+        PROGRAM main
+        VAR
+            counter : Counter;
+            itf : ICounter;
+        END_VAR
+        VAR_OUTPUT
+            total : DINT;
+            x : DINT;
+        END_VAR
+            itf := counter;
+            itf.add(value := 5, calls := x);
+            itf.add(value := 7, total => total, calls := x);
+        END_PROGRAM
+        ";
+
+    let mut main = MainType::default();
+    let _: i32 = compile_and_run(source, &mut main);
+    assert_eq!(main.counter, 12);
+    assert_eq!(main.total, 12);
+    assert_eq!(main.x, 2);
+}
+
+#[test]
+fn function_block_instance_is_passed_as_interface_parameter() {
+    #[repr(C)]
+    struct MainType {
+        first: i32,
+        second: i32,
+        x: i32,
+        y: [u8; 81],
+    }
+
+    let source = "
+        INTERFACE INamed
+            METHOD name : STRING
+            END_METHOD
+            METHOD id : DINT
+            END_METHOD
+        END_INTERFACE
+
+        FUNCTION_BLOCK FINAL First IMPLEMENTS INamed
+            VAR
+                value : DINT;
+            END_VAR
+            METHOD name : STRING
+                name := 'first';
+            END_METHOD
+            METHOD id : DINT
+                id := value;
+            END_METHOD
+        END_FUNCTION_BLOCK
+
+        FUNCTION_BLOCK FINAL Second IMPLEMENTS INamed
+            VAR
+                value : DINT;
+            END_VAR
+            METHOD name : STRING
+                name := 'second';
+            END_METHOD
+            METHOD id : DINT
+                id := value * 10;
+            END_METHOD
+        END_FUNCTION_BLOCK
+
+        FUNCTION sumOfIds : DINT
+        VAR_INPUT
+            a : INamed;
+            b : INamed;
+        END_VAR
+            sumOfIds := a.id() + b.id();
+        END_FUNCTION
+
+        FUNCTION nameOf : STRING
+        VAR_INPUT
+            named : INamed;
+        END_VAR
+            nameOf := named.name();
+        END_FUNCTION
+
+        PROGRAM main
+        VAR
+            first : First;
+            second : Second;
+        END_VAR
+        VAR_OUTPUT
+            x : DINT;
+            y : STRING;
+        END_VAR
+            x := sumOfIds(first, second);
+            y := nameOf(second);
+        END_PROGRAM
+        ";
+
+    let mut main = MainType { first: 3, second: 4, x: 0, y: [0; 81] };
+    let _: i32 = compile_and_run(source, &mut main);
+    assert_eq!(main.x, 43);
+    assert_eq!(&main.y[..7], "second\0".as_bytes());
+}
@@ -0,0 +1,141 @@
+use driver::runner::compile_and_run_no_params;
+
+const LOG: &str = "
+    VAR_GLOBAL
+        log : DINT;
+    END_VAR
+
+    FUNCTION write_log
+    VAR_INPUT
+        id : DINT;
+    END_VAR
+        log := log * 10 + id;
+    END_FUNCTION
+";
+
+#[test]
+fn fb_init_is_called_with_the_declared_arguments() {
+    let source = "
+        FUNCTION_BLOCK Axis
+        VAR
+            nr : DINT;
+            initialized : BOOL;
+        END_VAR
+            METHOD FB_INIT
+            VAR_INPUT
+                nAxis : DINT;
+            END_VAR
+                nr := nAxis;
+                initialized := TRUE;
+            END_METHOD
+        END_FUNCTION_BLOCK
+
+        FUNCTION main : DINT
+        VAR
+            a : Axis(nAxis := 3);
+            b : Axis(4);
+        END_VAR
+            IF a.initialized AND b.initialized THEN
+                main := a.nr * 10 + b.nr;
+            END_IF
+        END_FUNCTION
+        ";
+
+    let res: i32 = compile_and_run_no_params(source);
+    assert_eq!(res, 34);
+}
+
+#[test]
+fn fb_init_runs_for_the_base_class_and_members_before_the_container() {
+    let source = format!(
+        "
+        {LOG}
+
+        FUNCTION_BLOCK Base
+            METHOD FB_INIT
+                write_log(1);
+            END_METHOD
+        END_FUNCTION_BLOCK
+
+        FUNCTION_BLOCK Member
+            METHOD FB_INIT
+            VAR_INPUT
+                id : DINT;
+            END_VAR
+                write_log(id);
+            END_METHOD
+        END_FUNCTION_BLOCK
+
+        FUNCTION_BLOCK Derived EXTENDS Base
+        VAR
+            first : Member(id := 2);
+            second : Member(id := 3);
+        END_VAR
+            METHOD FB_INIT
+                write_log(4);
+            END_METHOD
+        END_FUNCTION_BLOCK
+
+        FUNCTION main : DINT
+        VAR
+            d : Derived;
+        END_VAR
+            main := log;
+        END_FUNCTION
+        "
+    );
+
+    let res: i32 = compile_and_run_no_params(source);
+    assert_eq!(res, 1234);
+}
+
+#[test]
+fn fb_reinit_and_fb_exit_are_called_by_the_project_entry_points() {
+    let source = format!(
+        "
+        {LOG}
+
+        CLASS Base
+            METHOD FB_REINIT
+                write_log(1);
+            END_METHOD
+            METHOD FB_EXIT
+                write_log(1);
+            END_METHOD
+        END_CLASS
+
+        CLASS Member
+            METHOD FB_EXIT
+                write_log(2);
+            END_METHOD
+        END_CLASS
+
+        CLASS Derived EXTENDS Base
+        VAR
+            m : Member;
+        END_VAR
+            METHOD FB_REINIT
+                write_log(3);
+            END_METHOD
+            METHOD FB_EXIT
+                write_log(3);
+            END_METHOD
+        END_CLASS
+
+        PROGRAM prog
+        VAR
+            d : Derived;
+        END_VAR
+        END_PROGRAM
+
+        FUNCTION main : DINT
+            __reinit___TestProject();
+            __deinit___TestProject();
+            main := log;
+        END_FUNCTION
+        "
+    );
+
+    let res: i32 = compile_and_run_no_params(source);
+    assert_eq!(res, 13_321);
+}
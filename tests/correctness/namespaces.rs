@@ -0,0 +1,129 @@
+use driver::runner::compile_and_run_no_params;
+
+#[test]
+fn equally_named_function_blocks_of_different_namespaces_do_not_collide() {
+    let source = "
+        NAMESPACE VendorA.Drives
+            FUNCTION_BLOCK FB_Motor
+            VAR_OUTPUT
+                speed : DINT;
+            END_VAR
+                speed := 10;
+            END_FUNCTION_BLOCK
+        END_NAMESPACE
+
+        NAMESPACE VendorB
+            FUNCTION_BLOCK FB_Motor
+            VAR_OUTPUT
+                speed : DINT;
+            END_VAR
+                speed := 20;
+            END_FUNCTION_BLOCK
+        END_NAMESPACE
+
+        FUNCTION main : DINT
+        VAR
+            a : VendorA.Drives.FB_Motor;
+            b : VendorB.FB_Motor;
+        END_VAR
+            a();
+            b();
+            main := a.speed + b.speed * 100;
+        END_FUNCTION
+        ";
+
+    let res: i32 = compile_and_run_no_params(source);
+    assert_eq!(res, 2010);
+}
+
+#[test]
+fn using_directives_import_namespaces() {
+    let source = "
+        NAMESPACE Lib
+            TYPE Color : (Red, Green, Blue); END_TYPE
+
+            VAR_GLOBAL CONSTANT
+                Offset : DINT := 100;
+            END_VAR
+
+            FUNCTION Double : DINT
+            VAR_INPUT
+                value : DINT;
+            END_VAR
+                Double := value * 2;
+            END_FUNCTION
+
+            FUNCTION Quadruple : DINT
+            VAR_INPUT
+                value : DINT;
+            END_VAR
+                // declarations of the enclosing namespace are found without qualification
+                Quadruple := Double(value := Double(value));
+            END_FUNCTION
+        END_NAMESPACE
+
+        USING Lib;
+
+        FUNCTION main : DINT
+        VAR
+            color : Color := Color#Blue;
+        END_VAR
+            main := Quadruple(3) + Lib.Double(5) + Offset + Lib.Offset;
+            IF color = Lib.Color#Blue THEN
+                main := main + 1000;
+            END_IF
+        END_FUNCTION
+        ";
+
+    let res: i32 = compile_and_run_no_params(source);
+    assert_eq!(res, 1222);
+}
+
+#[test]
+fn pous_of_nested_namespaces_extend_and_implement_declarations_of_other_namespaces() {
+    let source = "
+        NAMESPACE Shapes
+            INTERFACE IShape
+                METHOD area : DINT
+                END_METHOD
+            END_INTERFACE
+
+            CLASS Rectangle IMPLEMENTS IShape
+            VAR
+                width : DINT := 2;
+                height : DINT := 3;
+            END_VAR
+                METHOD area : DINT
+                    area := width * height;
+                END_METHOD
+            END_CLASS
+
+            NAMESPACE Special
+                CLASS Square EXTENDS Rectangle
+                    METHOD OVERRIDE area : DINT
+                        area := width * width;
+                    END_METHOD
+                END_CLASS
+            END_NAMESPACE
+        END_NAMESPACE
+
+        FUNCTION areaOf : DINT
+        USING Shapes;
+        VAR_INPUT
+            shape : IShape;
+        END_VAR
+            areaOf := shape.area();
+        END_FUNCTION
+
+        FUNCTION main : DINT
+        VAR
+            rectangle : Shapes.Rectangle;
+            square : Shapes.Special.Square;
+        END_VAR
+            main := areaOf(rectangle) * 10 + areaOf(square);
+        END_FUNCTION
+        ";
+
+    let res: i32 = compile_and_run_no_params(source);
+    assert_eq!(res, 64);
+}
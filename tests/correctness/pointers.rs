@@ -1,5 +1,6 @@
 // Copyright (c) 2020 Ghaith Hachem and Mathias Rieder
 use crate::{compile_and_run, MainType};
+use driver::runner::compile_and_run_no_params;
 
 mod references;
 
@@ -242,3 +243,121 @@ fn value_behind_function_block_pointer_is_assigned_to_correctly() {
     assert!(!maintype.a);
     assert!(maintype.b);
 }
+
+#[test]
+fn new_allocates_initialized_instances_and_delete_resets_the_pointer() {
+    let src = r#"
+        TYPE Point : STRUCT
+            x : DINT := 3;
+            y : DINT := 4;
+        END_STRUCT END_TYPE
+
+        FUNCTION_BLOCK Counter
+        VAR_OUTPUT
+            count : DINT := 10;
+            current : REF_TO DINT := REF(count);
+        END_VAR
+            count := count + 1;
+        END_FUNCTION_BLOCK
+
+        FUNCTION main : DINT
+        VAR
+            p : REF_TO Point;
+            counters : REF_TO Counter;
+            second : REF_TO Counter;
+        END_VAR
+            p := __NEW(Point);
+            counters := __NEW(Counter, 3);
+            second := counters + 1;
+            second^();
+            main := p^.x * 100 + p^.y * 10 + second^.current^ - counters^.current^;
+            __DELETE(p);
+            __DELETE(counters);
+            IF p = 0 AND counters = 0 THEN
+                main := main + 1000;
+            END_IF
+        END_FUNCTION
+        "#;
+
+    let res: i32 = compile_and_run_no_params(src);
+    assert_eq!(1341, res);
+}
+
+#[test]
+fn new_and_delete_use_an_allocator_declared_in_the_project() {
+    let src = r#"
+        VAR_GLOBAL
+            pool : ARRAY[0..63] OF BYTE;
+            used : ULINT;
+            released : DINT;
+        END_VAR
+
+        FUNCTION __plc_alloc : REF_TO BYTE
+        VAR_INPUT
+            size : ULINT;
+        END_VAR
+            __plc_alloc := REF(pool[used]);
+            used := used + size;
+        END_FUNCTION
+
+        FUNCTION __plc_free
+        VAR_INPUT
+            memory : REF_TO BYTE;
+        END_VAR
+            released := released + 1;
+        END_FUNCTION
+
+        FUNCTION main : DINT
+        VAR
+            values : REF_TO DINT;
+        END_VAR
+            values := __NEW(DINT, 4);
+            values^ := 7;
+            main := used;
+            main := main * 10 + pool[0];
+            __DELETE(values);
+            main := main + released * 1000;
+        END_FUNCTION
+        "#;
+
+    let res: i32 = compile_and_run_no_params(src);
+    assert_eq!(1167, res);
+}
+
+#[test]
+fn new_returns_null_without_allocating_if_the_size_overflows() {
+    let src = r#"
+        VAR_GLOBAL
+            allocations : DINT;
+        END_VAR
+
+        FUNCTION __plc_alloc : REF_TO BYTE
+        VAR_INPUT
+            size : ULINT;
+        END_VAR
+            allocations := allocations + 1;
+        END_FUNCTION
+
+        FUNCTION __plc_free
+        VAR_INPUT
+            memory : REF_TO BYTE;
+        END_VAR
+        END_FUNCTION
+
+        FUNCTION main : DINT
+        VAR
+            values : REF_TO LINT;
+            count : ULINT := 16#2000_0000_0000_0001;
+        END_VAR
+            // 8 bytes times count wraps around to 8 bytes
+            values := __NEW(LINT, count);
+            IF values = 0 THEN
+                main := 10;
+            END_IF
+            main := main + allocations;
+        END_FUNCTION
+        "#;
+
+    let res: i32 = compile_and_run_no_params(src);
+    assert_eq!(10, res);
+}
@@ -46,7 +46,7 @@ fn new() -> MainType {
 fn reference_call() {
     let function = r"
 
-    FUNCTION_BLOCK fbTest
+    FUNCTION_BLOCK FINAL fbTest
         VAR_INPUT
             reference : REF_TO BOOL;  (* REF_TO *)
             p : POINTER TO BOOL;
@@ -196,7 +196,7 @@ fn reference_call_struct() {
     END_STRUCT
     END_TYPE
 
-    FUNCTION_BLOCK FbTestStruct
+    FUNCTION_BLOCK FINAL FbTestStruct
         VAR_INPUT
             reference : REF_TO MyStruct; (* REF_TO *)
             p : POINTER TO MyStruct;
@@ -335,7 +335,7 @@ struct MainTypeWithArray {
 #[test]
 fn reference_call_array() {
     let function = r"
-    FUNCTION_BLOCK FbTestArray
+    FUNCTION_BLOCK FINAL FbTestArray
         VAR_INPUT
             reference : REF_TO ARRAY[1..2] OF INT; (* REF_TO *)
             p : POINTER TO ARRAY[1..2] OF INT;
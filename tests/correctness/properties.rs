@@ -0,0 +1,189 @@
+use crate::*;
+
+#[test]
+fn property_of_function_block_can_be_read_and_written() {
+    #[derive(Default)]
+    #[repr(C)]
+    struct FbType {
+        value: i32,
+        writes: i32,
+    }
+
+    #[derive(Default)]
+    #[repr(C)]
+    struct MainType {
+        instance: FbType,
+        x: i32,
+        y: i32,
+    }
+
+    let source = "
+        FUNCTION_BLOCK FINAL fb
+            VAR
+                value : DINT;
+                writes : DINT;
+            END_VAR
+
+            PROPERTY prop : DINT
+                GET
+                    prop := value * 2;
+                END_GET
+                SET
+                    value := prop;
+                    writes := writes + 1;
+                END_SET
+            END_PROPERTY
+        END_FUNCTION_BLOCK
+
+        PROGRAM main
+        VAR
+            instance : fb;
+        END_VAR
+        VAR_OUTPUT
+            x : DINT;
+            y : DINT;
+        END_VAR
+            instance.prop := 21;
+            x := instance.prop;
+            instance.prop := instance.prop + 1;
+            y := instance.writes;
+        END_PROGRAM
+        ";
+
+    let mut main = MainType::default();
+    let _: i32 = compile_and_run(source, &mut main);
+    assert_eq!(main.x, 42);
+    assert_eq!(main.y, 2);
+    assert_eq!(main.instance.value, 43);
+}
+
+#[test]
+fn property_can_be_accessed_unqualified_within_its_function_block() {
+    #[derive(Default)]
+    #[repr(C)]
+    struct MainType {
+        instance: i16,
+        x: i16,
+    }
+
+    let source = "
+        FUNCTION_BLOCK FINAL fb
+            VAR
+                value : INT;
+            END_VAR
+
+            PROPERTY prop : INT
+                GET
+                    prop := value;
+                END_GET
+                SET
+                    value := prop + 1;
+                END_SET
+            END_PROPERTY
+
+            METHOD increment
+                prop := prop;
+            END_METHOD
+
+            prop := 10;
+            increment();
+        END_FUNCTION_BLOCK
+
+        PROGRAM main
+        VAR
+            instance : fb;
+        END_VAR
+        VAR_OUTPUT
+            x : INT;
+        END_VAR
+            instance();
+            x := instance.prop;
+        END_PROGRAM
+        ";
+
+    let mut main = MainType::default();
+    let _: i32 = compile_and_run(source, &mut main);
+    assert_eq!(main.x, 12);
+}
+
+#[test]
+fn property_of_class_with_string_type() {
+    #[repr(C)]
+    struct MainType {
+        instance: [u8; 81],
+        x: [u8; 81],
+    }
+
+    let source = "
+        CLASS FINAL cls
+            VAR
+                greeting : STRING;
+            END_VAR
+
+            PROPERTY name : STRING
+                GET
+                    name := greeting;
+                END_GET
+                SET
+                    greeting := name;
+                END_SET
+            END_PROPERTY
+        END_CLASS
+
+        PROGRAM main
+        VAR
+            instance : cls;
+        END_VAR
+        VAR_OUTPUT
+            x : STRING;
+        END_VAR
+            instance.name := 'world';
+            x := instance.name;
+        END_PROGRAM
+        ";
+
+    let mut main = MainType { instance: [0; 81], x: [0; 81] };
+    let _: i32 = compile_and_run(source, &mut main);
+    assert_eq!(&main.x[..6], "world\0".as_bytes());
+}
+
+#[test]
+fn read_only_property_with_array_type() {
+    #[derive(Default)]
+    #[repr(C)]
+    struct MainType {
+        instance: [i32; 3],
+        copy: [i32; 3],
+        x: i32,
+    }
+
+    let source = "
+        FUNCTION_BLOCK FINAL fb
+            VAR
+                data : ARRAY[0..2] OF DINT;
+            END_VAR
+
+            PROPERTY values : ARRAY[0..2] OF DINT
+                GET
+                    values := data;
+                END_GET
+            END_PROPERTY
+        END_FUNCTION_BLOCK
+
+        PROGRAM main
+        VAR
+            instance : fb;
+            copy : ARRAY[0..2] OF DINT;
+        END_VAR
+        VAR_OUTPUT
+            x : DINT;
+        END_VAR
+            copy := instance.values;
+            x := copy[0] + copy[1] + copy[2];
+        END_PROGRAM
+        ";
+
+    let mut main = MainType { instance: [1, 2, 3], ..Default::default() };
+    let _: i32 = compile_and_run(source, &mut main);
+    assert_eq!(main.x, 6);
+}
@@ -0,0 +1,171 @@
+use crate::compile_and_run;
+use driver::runner::compile_and_run_no_params;
+use std::ffi::CStr;
+
+const POINTS: &str = "
+    TYPE Point : STRUCT
+        x : REAL;
+        y : DINT;
+        label : STRING[10];
+    END_STRUCT END_TYPE
+
+    TYPE Point3 : STRUCT EXTENDS Point
+        z : LINT;
+    END_STRUCT END_TYPE
+
+    FUNCTION_BLOCK Counter
+    VAR_INPUT
+        step : INT;
+    END_VAR
+    VAR_OUTPUT
+        value : LREAL;
+    END_VAR
+    END_FUNCTION_BLOCK
+";
+
+fn to_str(bytes: &[u8]) -> &str {
+    CStr::from_bytes_until_nul(bytes).unwrap().to_str().unwrap()
+}
+
+#[test]
+fn type_names_and_classes_are_reported_for_values_and_types() {
+    #[repr(C)]
+    struct MainType {
+        point: [u8; 81],
+        list: [u8; 81],
+        label: [u8; 81],
+        struct_class: [u8; 81],
+        fb_class: [u8; 81],
+        int_class: [u8; 81],
+        real_class: [u8; 81],
+    }
+
+    let source = format!(
+        "{POINTS}
+        PROGRAM main
+        VAR
+            point, list, label, struct_class, fb_class, int_class, real_class : STRING;
+        END_VAR
+        VAR_TEMP
+            p : Point3;
+            values : ARRAY[1..3] OF INT;
+        END_VAR
+            point := __TYPEOF(p);
+            list := __TYPEOF(values);
+            label := __TYPEOF(p.label);
+            struct_class := __TYPECLASS(Point3);
+            fb_class := __TYPECLASS(Counter);
+            int_class := __TYPECLASS(values[1]);
+            real_class := __TYPECLASS(p.x);
+        END_PROGRAM
+        "
+    );
+
+    let mut main = MainType {
+        point: [0; 81],
+        list: [0; 81],
+        label: [0; 81],
+        struct_class: [0; 81],
+        fb_class: [0; 81],
+        int_class: [0; 81],
+        real_class: [0; 81],
+    };
+    let _: i32 = compile_and_run(source, &mut main);
+    assert_eq!(to_str(&main.point), "Point3");
+    assert_eq!(to_str(&main.list), "ARRAY[1..3] OF INT");
+    assert_eq!(to_str(&main.label), "STRING[10]");
+    assert_eq!(to_str(&main.struct_class), "STRUCT");
+    assert_eq!(to_str(&main.fb_class), "FUNCTION_BLOCK");
+    assert_eq!(to_str(&main.int_class), "ANY_INT");
+    assert_eq!(to_str(&main.real_class), "ANY_REAL");
+}
+
+#[test]
+fn member_tables_list_the_members_of_a_struct_and_its_bases() {
+    #[repr(C)]
+    struct MainType {
+        names: [[u8; 81]; 4],
+        matches: i32,
+    }
+
+    let source = format!(
+        "{POINTS}
+        PROGRAM main
+        VAR
+            names : ARRAY[1..4] OF STRING;
+            matches : DINT;
+        END_VAR
+        VAR_TEMP
+            p : Point3;
+            info : REF_TO __TYPE_INFO;
+            member : REF_TO __TYPE_MEMBER;
+            i : DINT;
+            base, address : LWORD;
+            type_id : UDINT;
+        END_VAR
+            info := __TYPEINFO(p);
+            IF info^.member_count = __MEMBER_COUNT(Point3) AND info^.size = SIZEOF(p) THEN
+                matches := matches + 1;
+            END_IF
+            IF info^.type_id = __TYPEID(p) THEN
+                matches := matches + 1;
+            END_IF
+
+            base := ADR(p);
+            FOR i := 1 TO info^.member_count DO
+                member := __MEMBER_INFO(p, i);
+                names[i] := member^.name;
+                CASE i OF
+                    1: address := ADR(p.x); type_id := __TYPEID(p.x);
+                    2: address := ADR(p.y); type_id := __TYPEID(p.y);
+                    3: address := ADR(p.label); type_id := __TYPEID(p.label);
+                    4: address := ADR(p.z); type_id := __TYPEID(p.z);
+                END_CASE
+                IF member^.offset = address - base AND member^.type_id = type_id THEN
+                    matches := matches + 1;
+                END_IF
+            END_FOR
+
+            IF __MEMBER_INFO(p, 0) = 0 AND __MEMBER_INFO(p, 5) = 0 THEN
+                matches := matches + 1;
+            END_IF
+            member := __MEMBER_INFO(p, 3);
+            IF member^.size = SIZEOF(p.label) THEN
+                matches := matches + 1;
+            END_IF
+        END_PROGRAM
+        "
+    );
+
+    let mut main = MainType { names: [[0; 81]; 4], matches: 0 };
+    let _: i32 = compile_and_run(source, &mut main);
+    assert_eq!(main.names.iter().map(|it| to_str(it)).collect::<Vec<_>>(), ["x", "y", "label", "z"]);
+    assert_eq!(main.matches, 8);
+}
+
+#[test]
+fn member_tables_of_function_blocks_list_their_variables() {
+    let source = format!(
+        "{POINTS}
+        FUNCTION main : DINT
+        VAR
+            counter : Counter;
+            member : REF_TO __TYPE_MEMBER;
+            base, address : LWORD;
+        END_VAR
+            base := ADR(counter);
+            address := ADR(counter.value);
+            member := __MEMBER_INFO(counter, 2);
+            IF member^.offset = address - base THEN
+                main := __MEMBER_COUNT(counter) * 10 + 1;
+            END_IF
+            IF __MEMBER_COUNT(main) = 0 THEN
+                main := main * 10 + 2;
+            END_IF
+        END_FUNCTION
+        "
+    );
+
+    let res: i32 = compile_and_run_no_params(source);
+    assert_eq!(res, 212);
+}
@@ -0,0 +1,98 @@
+use crate::*;
+
+#[derive(Default)]
+#[repr(C)]
+struct MainType {
+    counter: i32,
+    total: f64,
+}
+
+const RETAIN_FUNCTIONS: &str = r"
+    {external}
+    FUNCTION __retain_save END_FUNCTION
+
+    {external}
+    FUNCTION __retain_restore END_FUNCTION
+";
+
+#[test]
+fn retained_variables_are_restored_from_the_retain_image() {
+    let src = format!(
+        r"
+        {RETAIN_FUNCTIONS}
+
+        VAR_GLOBAL RETAIN
+            gCounter : DINT;
+        END_VAR
+
+        VAR_GLOBAL
+            gInstance : fb;
+        END_VAR
+
+        FUNCTION_BLOCK fb
+        VAR PERSISTENT
+            total : LREAL;
+        END_VAR
+        END_FUNCTION_BLOCK
+
+        PROGRAM main
+        VAR
+            counter : DINT;
+            total : LREAL;
+        END_VAR
+            gCounter := 42;
+            gInstance.total := 1.5;
+            __retain_save();
+
+            gCounter := 0;
+            gInstance.total := 0.0;
+            __retain_restore();
+
+            counter := gCounter;
+            total := gInstance.total;
+        END_PROGRAM
+        "
+    );
+
+    let mut main = MainType::default();
+    let _: i32 = compile_and_run(src, &mut main);
+    assert_eq!(main.counter, 42);
+    assert_eq!(main.total, 1.5);
+}
+
+#[test]
+fn plain_variables_are_not_part_of_the_retain_image() {
+    let src = format!(
+        r"
+        {RETAIN_FUNCTIONS}
+
+        VAR_GLOBAL RETAIN
+            gRetained : DINT;
+        END_VAR
+
+        VAR_GLOBAL
+            gPlain : DINT;
+        END_VAR
+
+        PROGRAM main
+        VAR
+            counter : DINT;
+            total : LREAL;
+        END_VAR
+            gRetained := 1;
+            gPlain := 2;
+            __retain_save();
+
+            gRetained := 10;
+            gPlain := 20;
+            __retain_restore();
+
+            counter := gRetained + gPlain;
+        END_PROGRAM
+        "
+    );
+
+    let mut main = MainType::default();
+    let _: i32 = compile_and_run(src, &mut main);
+    assert_eq!(main.counter, 21);
+}
@@ -0,0 +1,108 @@
+use crate::*;
+use pretty_assertions::assert_eq;
+use rusty::codegen::CodegenContext;
+
+/// a timer counting the number of cycles its input is set in seconds, standing in for the standard
+/// library's `TON`
+const TON: &str = "
+    FUNCTION_BLOCK TON
+    VAR_INPUT
+        IN : BOOL;
+        PT : TIME;
+    END_VAR
+    VAR_OUTPUT
+        Q : BOOL;
+        ET : TIME;
+    END_VAR
+        IF IN THEN
+            ET := ET + T#1s;
+        ELSE
+            ET := T#0s;
+        END_IF
+        Q := ET >= PT;
+    END_FUNCTION_BLOCK
+";
+
+#[derive(Default)]
+#[repr(C)]
+struct MainType {
+    start: bool,
+    fills: i32,
+    pulses: i32,
+    stored: i32,
+    filling: bool,
+}
+
+#[test]
+fn sfc_steps_and_actions_are_executed_cycle_by_cycle() {
+    let source = format!(
+        "
+        {TON}
+
+        FUNCTION_BLOCK mixer
+        VAR_INPUT
+            start : BOOL;
+        END_VAR
+        VAR_OUTPUT
+            fills, pulses, stored : DINT;
+            filling : BOOL;
+        END_VAR
+            INITIAL_STEP idle : END_STEP
+            STEP fill : count_fill(N); pulse(P); keep(S); filling(N); END_STEP
+            STEP done : keep(R); END_STEP
+
+            TRANSITION FROM idle TO fill := start; END_TRANSITION
+            TRANSITION FROM fill TO done := fill.T >= T#3s; END_TRANSITION
+            TRANSITION FROM done TO idle := NOT start; END_TRANSITION
+
+            ACTION count_fill:
+                fills := fills + 1;
+            END_ACTION
+            ACTION pulse:
+                pulses := pulses + 1;
+            END_ACTION
+            ACTION keep:
+                stored := stored + 1;
+            END_ACTION
+        END_FUNCTION_BLOCK
+
+        VAR_GLOBAL
+            instance : mixer;
+        END_VAR
+
+        PROGRAM main
+        VAR_INPUT
+            start : BOOL;
+        END_VAR
+        VAR_OUTPUT
+            fills, pulses, stored : DINT;
+            filling : BOOL;
+        END_VAR
+            instance(start := start, fills => fills, pulses => pulses, stored => stored, filling => filling);
+        END_PROGRAM
+        "
+    );
+
+    let context = CodegenContext::create();
+    let module = compile(&context, source);
+    let mut main = MainType::default();
+    let mut cycle = |main: &mut MainType, start: bool| {
+        main.start = start;
+        let _: i32 = module.run("main", main);
+        (main.fills, main.pulses, main.stored, main.filling)
+    };
+
+    // the initial step is kept until `start` is set, which activates `fill` at the end of the cycle
+    assert_eq!(cycle(&mut main, false), (0, 0, 0, false));
+    assert_eq!(cycle(&mut main, true), (0, 0, 0, false));
+    // `fill` is active for three cycles, its pulse action is only executed once
+    assert_eq!(cycle(&mut main, true), (1, 1, 1, true));
+    assert_eq!(cycle(&mut main, true), (2, 1, 2, true));
+    assert_eq!(cycle(&mut main, true), (3, 1, 3, true));
+    // `done` resets the stored action
+    assert_eq!(cycle(&mut main, true), (3, 1, 3, false));
+    assert_eq!(cycle(&mut main, false), (3, 1, 3, false));
+    // back in `idle`, so the chart starts over
+    assert_eq!(cycle(&mut main, true), (3, 1, 3, false));
+    assert_eq!(cycle(&mut main, true), (4, 2, 4, true));
+}
@@ -0,0 +1,92 @@
+use driver::runner::compile_and_run_no_params;
+
+const TELEGRAMS: &str = "
+    TYPE Header : STRUCT
+        id : INT := 7;
+        length : DINT;
+    END_STRUCT END_TYPE
+
+    TYPE Telegram : STRUCT EXTENDS Header
+        payload : DINT := 100;
+    END_STRUCT END_TYPE
+
+    TYPE StatusTelegram : STRUCT EXTENDS Telegram
+        status : BOOL;
+    END_STRUCT END_TYPE
+";
+
+#[test]
+fn inherited_struct_members_can_be_accessed() {
+    let source = format!(
+        "{TELEGRAMS}
+        FUNCTION main : DINT
+        VAR
+            t : StatusTelegram;
+        END_VAR
+            t.length := 20;
+            t.payload := t.payload + 3;
+            t.status := TRUE;
+            IF t.status THEN
+                main := t.id * 1000 + t.length * 10 + t.payload;
+            END_IF
+        END_FUNCTION
+        "
+    );
+
+    let res: i32 = compile_and_run_no_params(source);
+    assert_eq!(res, 7303);
+}
+
+#[test]
+fn struct_initializers_can_assign_inherited_members() {
+    let source = format!(
+        "{TELEGRAMS}
+        VAR_GLOBAL
+            g : StatusTelegram := (length := 3);
+        END_VAR
+
+        FUNCTION main : DINT
+        VAR
+            t : StatusTelegram := (id := 2, length := 4, status := TRUE);
+            u : Telegram := (payload := 5);
+        END_VAR
+            IF t.status THEN
+                main := t.id * 1000 + t.length * 100 + t.payload + u.id * 10 + u.payload + g.length;
+            END_IF
+            u := (id := 1, length := 2);
+            main := main * 1000 + u.id * 100 + u.length * 10 + u.payload / 100;
+        END_FUNCTION
+        "
+    );
+
+    let res: i32 = compile_and_run_no_params(source);
+    assert_eq!(res, 2578121);
+}
+
+#[test]
+fn derived_structs_can_be_passed_as_their_base_by_reference() {
+    let source = format!(
+        "{TELEGRAMS}
+        FUNCTION set_length
+        VAR_IN_OUT
+            header : Header;
+        END_VAR
+            header.length := header.id + 1;
+        END_FUNCTION
+
+        FUNCTION main : DINT
+        VAR
+            t : StatusTelegram;
+            header : REF_TO Header;
+        END_VAR
+            set_length(t);
+            header := REF(t);
+            header^.id := 3;
+            main := t.id * 10 + t.length;
+        END_FUNCTION
+        "
+    );
+
+    let res: i32 = compile_and_run_no_params(source);
+    assert_eq!(res, 38);
+}
@@ -198,6 +198,38 @@ fn actions_called_correctly() {
     assert_eq!(main.b, 2);
 }
 
+#[test]
+fn project_with_types_global_variables_and_textual_bodies() {
+    // GIVEN a PLCopen project declaring data types, global variables, a configuration and POUs with ST and FBD bodies
+    let file = get_test_file("cfc/project.xml");
+    // WHEN calling `main`, which sums up two points, scales them by a global constant and passes them through the FBD
+    let res: i32 = compile_and_run(vec![file], &mut {});
+    // THEN the declarations of all POUs, types and variables were imported
+    assert_eq!(res, 120);
+}
+
+#[test]
+fn ladder_diagram_with_contacts_coils_and_blocks() {
+    // GIVEN a LD program with edge-detecting, negated and parallel contacts, set/reset coils and an enabled block
+    let st_file = get_test_file("cfc/ladder.st");
+    let ld_file = get_test_file("cfc/ladder.cfc");
+    // WHEN calling it three times with changing inputs
+    let res: i32 = compile_and_run(vec![st_file, ld_file], &mut {});
+    // THEN every rung stored the expected power flow
+    assert_eq!(res, 1311111);
+}
+
+#[test]
+fn sequential_function_chart_with_parallel_branches() {
+    // GIVEN a SFC function block splitting into two parallel steps, joined by a declared transition
+    let st_file = get_test_file("cfc/sfc.st");
+    let sfc_file = get_test_file("cfc/sfc.cfc");
+    // WHEN calling it with changing inputs
+    let res: i32 = compile_and_run(vec![st_file, sfc_file], &mut {});
+    // THEN both branches are active until they are joined
+    assert_eq!(res, 2111);
+}
+
 // TODO(volsa): Remove this once our `test_utils.rs` file has been polished to also support CFC.
 // More specifically transform the following tests into simple codegen ones.
 #[cfg(test)]
@@ -62,6 +62,51 @@ fn hardware_conf_full_pass_toml() {
     let _foo = fs::remove_file("toml");
 }
 
+#[test]
+fn task_conf_full_pass_json() {
+    let file = get_test_file("tasks.st");
+
+    let dir = tempfile::tempdir().unwrap();
+    let output = dir.path().join("tasks.ll");
+    let task_config = dir.path().join("tasks.json");
+    compile(&[
+        "plc",
+        file.as_str(),
+        "-o",
+        &output.to_string_lossy(),
+        "--ir",
+        "--task-conf",
+        &task_config.to_string_lossy(),
+    ])
+    .unwrap();
+
+    let content = fs::read_to_string(task_config).expect("the task configuration should have been generated");
+    assert_snapshot!(content);
+}
+
+#[test]
+fn access_paths_are_written_to_the_hardware_conf() {
+    let file = get_test_file("access_paths.st");
+
+    let dir = tempfile::tempdir().unwrap();
+    let output = dir.path().join("access_paths.ll");
+    let hardware_config = dir.path().join("hardware.json");
+    compile(&[
+        "plc",
+        file.as_str(),
+        "-o",
+        &output.to_string_lossy(),
+        "--ir",
+        "--hardware-conf",
+        &hardware_config.to_string_lossy(),
+    ])
+    .unwrap();
+
+    let content =
+        fs::read_to_string(hardware_config).expect("the hardware configuration should have been generated");
+    assert_snapshot!(content);
+}
+
 #[test]
 fn stdlib_string_function_headers_compile_to_ir() {
     let mut path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"));
@@ -104,3 +149,47 @@ fn generate_got_file() {
     // clean up
     let _foo = fs::remove_file(data_path);
 }
+
+#[test]
+fn defines_select_conditionally_compiled_regions() {
+    let file = get_test_file("conditional.st");
+    let compile_with = |defines: &[&str]| {
+        let temp_file = tempfile::NamedTempFile::new().unwrap();
+        let path = temp_file.path().to_string_lossy();
+        let mut args = vec!["plc", file.as_str(), "-o", &path, "--ir"];
+        args.extend(defines);
+        compile(&args).unwrap();
+        fs::read_to_string(temp_file.path()).unwrap()
+    };
+
+    assert!(compile_with(&["-D", "VARIANT_A"]).contains("store i32 100"));
+    assert!(compile_with(&["-D", "axes=2"]).contains("store i32 200"));
+    assert!(compile_with(&["--define", "AXES=3"]).contains("store i32 300"));
+}
+
+#[test]
+fn exception_handling_is_rejected_for_unsupported_targets() {
+    let file = get_test_file("exceptions.st");
+    let compile_for = |target: &str| {
+        let temp_file = tempfile::NamedTempFile::new().unwrap();
+        let path = temp_file.path().to_string_lossy();
+        compile(&["plc", file.as_str(), "-o", &path, "-c", "--target", target]).map_err(|it| it.to_string())
+    };
+
+    assert!(compile_for("x86_64-unknown-linux-gnu").is_ok());
+    let error = compile_for("x86_64-pc-windows-msvc").unwrap_err();
+    assert!(error.contains("Exception handling is not supported for target"), "{error}");
+}
+
+#[test]
+fn internal_members_are_only_accessible_from_the_declaring_library() {
+    let project_fb = get_test_file("access/project_fb.st");
+    let project_main = get_test_file("access/project_main.st");
+    let library = get_test_file("access/library.st");
+    let library_main = get_test_file("access/library_main.st");
+
+    // the units of the project share their INTERNAL members
+    assert!(compile(&["plc", project_fb.as_str(), project_main.as_str(), "--check"]).is_ok());
+    // the INTERNAL members of an included library are not accessible
+    assert!(compile(&["plc", library_main.as_str(), "-i", library.as_str(), "--check"]).is_err());
+}
@@ -12,6 +12,17 @@ fn sources_accross_multiple_files_compiled() {
     assert_eq!(42, res);
 }
 
+#[test]
+fn instruction_list_and_structured_text_call_each_other() {
+    // GIVEN an IL function block calling an ST function, which is called from ST
+    let il_file = get_test_file("multi/interlock.il");
+    let st_file = get_test_file("multi/interlock.st");
+    // WHEN running main
+    let res: i32 = compile_and_run(vec![st_file, il_file], &mut ());
+    // THEN the motor stays on until stopped and the level was scaled by the ST function
+    assert_eq!(41, res);
+}
+
 fn concat_date(y: i16, m: i16, d: i16) -> i64 {
     (y + m + d) as i64
 }
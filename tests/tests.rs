@@ -16,15 +16,27 @@ mod correctness {
     mod control_flow;
     mod custom_datatypes;
     mod datatypes;
+    mod enum_conversions;
     mod expressions;
     mod external_functions;
     mod functions;
     mod generic_functions;
+    mod generic_pous;
     mod global_variables;
+    mod inheritance;
     mod initial_values;
+    mod instruction_list;
+    mod lifecycle_methods;
+    mod interfaces;
     mod methods;
+    mod namespaces;
     mod pointers;
+    mod properties;
+    mod reflection;
+    mod retain;
+    mod sfc;
     mod strings;
+    mod struct_inheritance;
     mod sub_range_types;
     mod math_operators {
         pub(super) mod addition;